@@ -18,9 +18,11 @@ use manta_backend_dispatcher::{
     commands::CommandsTrait,
     console::ConsoleTrait,
     get_images_and_details::GetImagesAndDetailsTrait,
+    health::HealthTrait,
     hsm::{
-      component::ComponentTrait, group::GroupTrait,
-      hardware_inventory::HardwareInventory,
+      component::ComponentTrait, ethernet_interfaces::EthernetInterfaceTrait,
+      group::GroupTrait, hardware_inventory::HardwareInventory,
+      lock::LockTrait, node_map::NodeMapTrait, partition::PartitionTrait,
       redfish_endpoint::RedfishEndpointTrait,
     },
     ims::ImsTrait,
@@ -32,19 +34,38 @@ use manta_backend_dispatcher::{
     bos::{session::BosSession, session_template::BosSessionTemplate},
     bss::BootParameters,
     bss::BootParameters as FrontEndBootParameters,
+    capability::Capability,
     cfs::{
+      cfs_configuration_derivatives::CfsConfigurationDerivatives,
       cfs_configuration_details::LayerDetails,
       cfs_configuration_request::CfsConfigurationRequest,
       cfs_configuration_response::{CfsConfigurationResponse, Layer},
       session::{CfsSessionGetResponse, CfsSessionPostRequest},
     },
-    hsm::inventory::RedfishEndpointArray as FrontEndRedfishEndpointArray,
+    gc::{GcItem, GcPolicy, GcReport},
+    hsm::inventory::{
+      EthernetInterface as FrontEndEthernetInterface,
+      HWInvHistArray as FrontEndHWInvHistArray,
+      IpAddressMapping as FrontEndIpAddressMapping,
+      RedfishEndpointArray as FrontEndRedfishEndpointArray,
+    },
+    hsm::lock::{
+      ComponentLockStatus as FrontEndComponentLockStatus,
+      LockResult as FrontEndLockResult,
+    },
     ims::Image as FrontEndImage,
+    orphan::OrphanReport,
+    pcs::power_cap::types::{
+      PowerCapPatch as FrontEndPowerCapPatch,
+      PowerCapTaskInfo as FrontEndPowerCapTaskInfo,
+      PowerCapTaskList as FrontEndPowerCapTaskList,
+    },
     pcs::power_status::types::PowerStatusAll as FrontEndPowerStatusAll,
     Component, ComponentArrayPostArray as FrontEndComponentArrayPostArray,
-    Group as FrontEndGroup,
-    HWInventoryByLocationList as FrontEndHWInventoryByLocationList, K8sAuth,
-    K8sDetails, NodeMetadataArray,
+    ComponentFlag, Group as FrontEndGroup,
+    HWInventoryByFRU as FrontEndHWInventoryByFRU,
+    HWInventoryByLocationList as FrontEndHWInventoryByLocationList, K8sDetails,
+    NodeMetadataArray,
   },
 };
 use regex::Regex;
@@ -53,14 +74,14 @@ use tokio::io::{AsyncRead, AsyncWrite};
 
 use crate::{
   bos, bss,
-  common::{
-    authentication, kubernetes,
-    vault::http_client::fetch_shasta_k8s_secrets_from_vault,
-  },
+  common::{authentication, kubernetes},
   hsm::{
-    self, component::types::ComponentArrayPostArray, group::types::Member,
+    self,
+    component::types::{ComponentArrayPatch, ComponentArrayPostArray},
+    group::types::Member,
+    lock::types::{LockRequest, LockStatusRequest, ProcessingModel},
   },
-  node::console,
+  node::{self, console},
   pcs::{self},
 };
 
@@ -77,6 +98,17 @@ impl Csm {
       root_cert: root_cert.to_vec(),
     }
   }
+
+  // FIXME: this is not nice but authentication/authorization will potentially move out to an
+  // external crate since this is type of logic is external to each site ...
+  fn keycloak_base_url(&self) -> String {
+    let base_url = self
+      .base_url
+      .strip_suffix("/apis")
+      .unwrap_or(&self.base_url);
+
+    base_url.to_string() + "/keycloak"
+  }
 }
 
 impl GroupTrait for Csm {
@@ -87,7 +119,7 @@ impl GroupTrait for Csm {
     let mut group_vec = self
       .get_all_groups(auth_token)
       .await
-      .map_err(|e| Error::Message(e.to_string()))?;
+      .map_err(Error::from)?;
 
     let available_groups_name =
       self.get_group_name_available(auth_token).await?;
@@ -107,7 +139,7 @@ impl GroupTrait for Csm {
       &self.root_cert,
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
   }
 
   async fn add_group(
@@ -122,7 +154,7 @@ impl GroupTrait for Csm {
       group.clone().into(),
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))?;
+    .map_err(Error::from)?;
 
     // let group: FrontEndGroup = group_csm.into();
     log::info!("Group created: {}", group_csm);
@@ -144,7 +176,7 @@ impl GroupTrait for Csm {
       &hsm_group_name_vec,
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
   }
 
   async fn get_group_map_and_filter_by_group_vec(
@@ -159,7 +191,7 @@ impl GroupTrait for Csm {
       hsm_name_vec,
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
   }
 
   async fn get_group_map_and_filter_by_member_vec(
@@ -174,7 +206,7 @@ impl GroupTrait for Csm {
       member_vec,
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
   }
 
   async fn get_all_groups(
@@ -188,7 +220,7 @@ impl GroupTrait for Csm {
       &self.root_cert,
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))?;
+    .map_err(Error::from)?;
 
     // Convert all HSM groups from mesa to infra
     let hsm_group_vec = hsm_group_backend_vec
@@ -213,7 +245,7 @@ impl GroupTrait for Csm {
       None,
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))?;
+    .map_err(Error::from)?;
 
     // Error if more than one HSM group found
     if hsm_group_backend_vec.len() > 1 {
@@ -223,7 +255,10 @@ impl GroupTrait for Csm {
       )));
     }
 
-    let hsm_group_backend = hsm_group_backend_vec.first().unwrap().to_owned();
+    let hsm_group_backend =
+      hsm_group_backend_vec.into_iter().next().ok_or_else(|| {
+        Error::from(crate::error::Error::GroupNotFound(hsm_name.to_string()))
+      })?;
 
     let hsm_group: FrontEndGroup = hsm_group_backend.into();
 
@@ -244,7 +279,7 @@ impl GroupTrait for Csm {
       None,
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))?;
+    .map_err(Error::from)?;
 
     // Convert from HsmGroup (silla) to HsmGroup (infra)
     let mut hsm_group_vec = Vec::new();
@@ -268,7 +303,7 @@ impl GroupTrait for Csm {
       &label.to_string(),
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
   }
 
   async fn get_hsm_map_and_filter_by_hsm_name_vec(
@@ -283,7 +318,7 @@ impl GroupTrait for Csm {
       hsm_name_vec,
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
   }
 
   async fn post_member(
@@ -304,7 +339,7 @@ impl GroupTrait for Csm {
       member,
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
   }
 
   async fn add_members_to_group(
@@ -324,7 +359,7 @@ impl GroupTrait for Csm {
         new_member,
       )
       .await
-      .map_err(|e| Error::Message(e.to_string()))?;
+      .map_err(Error::from)?;
     }
 
     Ok(sol)
@@ -344,7 +379,7 @@ impl GroupTrait for Csm {
       xname,
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
   }
 
   async fn update_group_members(
@@ -363,7 +398,7 @@ impl GroupTrait for Csm {
       members_to_add,
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
   }
 
   // HSM/GROUP
@@ -384,7 +419,7 @@ impl GroupTrait for Csm {
       true,
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
   }
 }
 
@@ -401,10 +436,10 @@ impl HardwareInventory for Csm {
       xname,
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
     .and_then(|hw_inventory| {
       serde_json::to_value(hw_inventory)
-        .map_err(|e| Error::Message(e.to_string()))
+        .map_err(Error::from)
     })
   }
 
@@ -425,7 +460,7 @@ impl HardwareInventory for Csm {
       xname,
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
   }
 
   async fn post_inventory_hardware(
@@ -440,7 +475,58 @@ impl HardwareInventory for Csm {
       hw_inventory.into(),
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
+  }
+
+  async fn get_inventory_hardware_by_fru(
+    &self,
+    auth_token: &str,
+    fru_id: Option<&str>,
+    r#type: Option<&str>,
+    manufacturer: Option<&str>,
+    part_number: Option<&str>,
+    serial_number: Option<&str>,
+  ) -> Result<Vec<FrontEndHWInventoryByFRU>, Error> {
+    let hw_inventory_by_fru_vec =
+      hsm::hw_inventory::hardware_by_fru::http_client::get(
+        auth_token,
+        &self.base_url,
+        &self.root_cert,
+        fru_id,
+        r#type,
+        manufacturer,
+        part_number,
+        serial_number,
+      )
+      .await
+      .map_err(Error::from)?;
+
+    Ok(
+      hw_inventory_by_fru_vec
+        .into_iter()
+        .map(|hw_inventory_by_fru| hw_inventory_by_fru.into())
+        .collect(),
+    )
+  }
+
+  async fn get_inventory_hardware_history_by_fru(
+    &self,
+    auth_token: &str,
+    fru_id: &str,
+    start_time: Option<&str>,
+    end_time: Option<&str>,
+  ) -> Result<FrontEndHWInvHistArray, Error> {
+    hsm::hw_inventory::hardware_by_fru::http_client::get_history(
+      auth_token,
+      &self.base_url,
+      &self.root_cert,
+      fru_id,
+      start_time,
+      end_time,
+    )
+    .await
+    .map(|hw_inv_hist_array| hw_inv_hist_array.into())
+    .map_err(Error::from)
   }
 }
 
@@ -477,7 +563,7 @@ impl ComponentTrait for Csm {
     )
     .await
     .map(|c| c.into())
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
   }
 
   async fn get_node_metadata_available(
@@ -487,7 +573,7 @@ impl ComponentTrait for Csm {
     let xname_available_vec: Vec<String> = self
       .get_group_available(auth_token)
       .await
-      .map_err(|e| Error::Message(e.to_string()))?
+      .map_err(Error::from)?
       .iter()
       .flat_map(|group| group.get_members())
       .collect();
@@ -561,7 +647,7 @@ impl ComponentTrait for Csm {
     )
     .await
     .map(|c| c.into())
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
   }
 
   async fn post_nodes(
@@ -578,7 +664,7 @@ impl ComponentTrait for Csm {
       component_backend,
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
   }
 
   async fn delete_node(
@@ -593,7 +679,180 @@ impl ComponentTrait for Csm {
       id,
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
+  }
+
+  async fn get_node_details(
+    &self,
+    auth_token: &str,
+    xname_vec: &[String],
+  ) -> Result<Value, Error> {
+    let node_details_vec = node::utils::get_node_details(
+      auth_token,
+      &self.base_url,
+      &self.root_cert,
+      xname_vec.to_vec(),
+    )
+    .await
+    .map_err(Error::from)?;
+
+    serde_json::to_value(node_details_vec)
+      .map_err(Error::from)
+  }
+
+  async fn update_components_enabled(
+    &self,
+    auth_token: &str,
+    xname_vec: &[String],
+    enabled: bool,
+  ) -> Result<(), Error> {
+    hsm::component::http_client::patch_bulk(
+      &self.base_url,
+      auth_token,
+      &self.root_cert,
+      "BulkEnabled",
+      ComponentArrayPatch {
+        component_ids: xname_vec.to_vec(),
+        enabled: Some(enabled),
+        ..Default::default()
+      },
+    )
+    .await
+    .map_err(Error::from)
+  }
+
+  async fn update_components_flag(
+    &self,
+    auth_token: &str,
+    xname_vec: &[String],
+    flag: ComponentFlag,
+  ) -> Result<(), Error> {
+    hsm::component::http_client::patch_bulk(
+      &self.base_url,
+      auth_token,
+      &self.root_cert,
+      "BulkFlagOnly",
+      ComponentArrayPatch {
+        component_ids: xname_vec.to_vec(),
+        flag: Some(flag.to_string()),
+        ..Default::default()
+      },
+    )
+    .await
+    .map_err(Error::from)
+  }
+
+  async fn update_components_software_status(
+    &self,
+    auth_token: &str,
+    xname_vec: &[String],
+    software_status: &str,
+  ) -> Result<(), Error> {
+    hsm::component::http_client::patch_bulk(
+      &self.base_url,
+      auth_token,
+      &self.root_cert,
+      "BulkSoftwareStatus",
+      ComponentArrayPatch {
+        component_ids: xname_vec.to_vec(),
+        software_status: Some(software_status.to_string()),
+        ..Default::default()
+      },
+    )
+    .await
+    .map_err(Error::from)
+  }
+
+  async fn update_components_role(
+    &self,
+    auth_token: &str,
+    xname_vec: &[String],
+    role: &str,
+    sub_role: Option<&str>,
+  ) -> Result<(), Error> {
+    hsm::component::http_client::patch_bulk(
+      &self.base_url,
+      auth_token,
+      &self.root_cert,
+      "BulkRole",
+      ComponentArrayPatch {
+        component_ids: xname_vec.to_vec(),
+        role: Some(role.to_string()),
+        sub_role: sub_role.map(str::to_string),
+        ..Default::default()
+      },
+    )
+    .await
+    .map_err(Error::from)
+  }
+}
+
+// NOTE: HSM partitions are not used in CSM sites, tenants are managed through HSM groups
+impl PartitionTrait for Csm {}
+
+impl NodeMapTrait for Csm {}
+
+impl LockTrait for Csm {
+  async fn get_locks_status(
+    &self,
+    auth_token: &str,
+    xname_vec: &[String],
+  ) -> Result<Vec<FrontEndComponentLockStatus>, Error> {
+    hsm::lock::http_client::get_status(
+      &self.base_url,
+      auth_token,
+      &self.root_cert,
+      LockStatusRequest {
+        component_ids: xname_vec.to_vec(),
+      },
+    )
+    .await
+    .map(|component_lock_status_array| {
+      component_lock_status_array
+        .components
+        .into_iter()
+        .map(|component_lock_status| component_lock_status.into())
+        .collect()
+    })
+    .map_err(Error::from)
+  }
+
+  async fn lock_components(
+    &self,
+    auth_token: &str,
+    xname_vec: &[String],
+  ) -> Result<FrontEndLockResult, Error> {
+    hsm::lock::http_client::lock(
+      &self.base_url,
+      auth_token,
+      &self.root_cert,
+      LockRequest {
+        component_ids: xname_vec.to_vec(),
+        processing_model: ProcessingModel::Rigid,
+      },
+    )
+    .await
+    .map(|lock_response| lock_response.into())
+    .map_err(Error::from)
+  }
+
+  async fn unlock_components(
+    &self,
+    auth_token: &str,
+    xname_vec: &[String],
+  ) -> Result<FrontEndLockResult, Error> {
+    hsm::lock::http_client::unlock(
+      &self.base_url,
+      auth_token,
+      &self.root_cert,
+      LockRequest {
+        component_ids: xname_vec.to_vec(),
+        processing_model: ProcessingModel::Flexible,
+      },
+    )
+    .await
+    .map(|lock_response| lock_response.into())
+    .map_err(Error::from)
   }
 }
 
@@ -613,7 +872,7 @@ impl PCSTrait for Csm {
       &nodes.to_vec(),
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
   }
 
   async fn power_off_sync(
@@ -632,7 +891,7 @@ impl PCSTrait for Csm {
       &nodes.to_vec(),
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
   }
 
   async fn power_reset_sync(
@@ -655,7 +914,7 @@ impl PCSTrait for Csm {
       &nodes.to_vec(),
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
   }
 
   async fn power_status(
@@ -681,10 +940,88 @@ impl PCSTrait for Csm {
     )
     .await
     .map(|status| {
-      println!("return value from async fn power_status : {:?}", status);
+      log::debug!("Power status:\n{:#?}", status);
       status.into()
     })
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
+  }
+
+  async fn get_power_cap_task_vec(
+    &self,
+    auth_token: &str,
+  ) -> Result<FrontEndPowerCapTaskList, Error> {
+    pcs::power_cap::http_client::get(
+      &self.base_url,
+      auth_token,
+      &self.root_cert,
+    )
+    .await
+    .map(|power_cap_task_list| power_cap_task_list.into())
+    .map_err(Error::from)
+  }
+
+  async fn get_power_cap_task(
+    &self,
+    auth_token: &str,
+    task_id: &str,
+  ) -> Result<FrontEndPowerCapTaskInfo, Error> {
+    pcs::power_cap::http_client::get_task_id(
+      &self.base_url,
+      auth_token,
+      &self.root_cert,
+      task_id,
+    )
+    .await
+    .map(|power_cap_task| power_cap_task.into())
+    .map_err(Error::from)
+  }
+
+  async fn power_cap_snapshot(
+    &self,
+    auth_token: &str,
+    nodes: &[String],
+  ) -> Result<FrontEndPowerCapTaskInfo, Error> {
+    pcs::power_cap::http_client::post_snapshot(
+      &self.base_url,
+      auth_token,
+      &self.root_cert,
+      nodes.iter().map(|node| node.as_str()).collect(),
+    )
+    .await
+    .map(|power_cap_task| power_cap_task.into())
+    .map_err(Error::from)
+  }
+
+  async fn power_cap_snapshot_sync(
+    &self,
+    auth_token: &str,
+    nodes: &[String],
+  ) -> Result<FrontEndPowerCapTaskInfo, Error> {
+    pcs::power_cap::http_client::post_snapshot_block(
+      &self.base_url,
+      auth_token,
+      &self.root_cert,
+      nodes.iter().map(|node| node.as_str()).collect(),
+    )
+    .await
+    .map(|power_cap_task| power_cap_task.into())
+    .map_err(Error::from)
+  }
+
+  async fn power_cap_patch_sync(
+    &self,
+    auth_token: &str,
+    power_cap: &FrontEndPowerCapPatch,
+  ) -> Result<FrontEndPowerCapTaskInfo, Error> {
+    pcs::power_cap::http_client::patch_block(
+      &self.base_url,
+      auth_token,
+      &self.root_cert,
+      power_cap.clone().into(),
+    )
+    .await
+    .map(|power_cap_task| power_cap_task.into())
+    .map_err(Error::from)
   }
 }
 
@@ -696,7 +1033,7 @@ impl BootParametersTrait for Csm {
     let boot_parameter_vec =
       bss::http_client::get_all(auth_token, &self.base_url, &self.root_cert)
         .await
-        .map_err(|e| Error::Message(e.to_string()))?;
+        .map_err(Error::from)?;
 
     let boot_parameter_infra_vec = boot_parameter_vec
       .into_iter()
@@ -718,7 +1055,7 @@ impl BootParametersTrait for Csm {
       nodes,
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))?;
+    .map_err(Error::from)?;
 
     let boot_parameter_infra_vec = boot_parameter_vec
       .into_iter()
@@ -739,7 +1076,7 @@ impl BootParametersTrait for Csm {
       &self.root_cert,
       boot_parameters.clone().into(),
     )
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
   }
 
   async fn update_bootparameters(
@@ -754,7 +1091,7 @@ impl BootParametersTrait for Csm {
       &boot_parameter.clone().into(),
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
   }
 
   async fn delete_bootparameters(
@@ -762,19 +1099,125 @@ impl BootParametersTrait for Csm {
     _auth_token: &str,
     _boot_parameters: &FrontEndBootParameters,
   ) -> Result<String, Error> {
-    Err(Error::Message(
+    Err(Error::UnsupportedCapability(
       "Delete boot parameters command not implemented for this backend"
         .to_string(),
     ))
   }
 }
 
+impl EthernetInterfaceTrait for Csm {
+  async fn get_ethernet_interfaces(
+    &self,
+    auth_token: &str,
+    mac_address: Option<&str>,
+    ip_address: Option<&str>,
+    network: Option<&str>,
+    component_id: Option<&str>,
+    r#type: Option<&str>,
+  ) -> Result<Vec<FrontEndEthernetInterface>, Error> {
+    hsm::hw_inventory::ethernet_interfaces::http_client::get(
+      auth_token,
+      &self.base_url,
+      &self.root_cert,
+      mac_address,
+      ip_address,
+      network,
+      component_id,
+      r#type,
+      None,
+      None,
+    )
+    .await
+    .map(|eth_interface_vec| {
+      eth_interface_vec
+        .into_iter()
+        .map(|eth_interface| eth_interface.into())
+        .collect()
+    })
+    .map_err(Error::from)
+  }
+
+  async fn get_ethernet_interface(
+    &self,
+    auth_token: &str,
+    eth_interface_id: &str,
+  ) -> Result<FrontEndEthernetInterface, Error> {
+    hsm::hw_inventory::ethernet_interfaces::http_client::get_one(
+      auth_token,
+      &self.base_url,
+      &self.root_cert,
+      eth_interface_id,
+    )
+    .await
+    .map(|eth_interface| eth_interface.into())
+    .map_err(Error::from)
+  }
+
+  async fn add_ethernet_interface(
+    &self,
+    auth_token: &str,
+    ethernet_interface: &FrontEndEthernetInterface,
+  ) -> Result<(), Error> {
+    hsm::hw_inventory::ethernet_interfaces::http_client::post(
+      auth_token,
+      &self.base_url,
+      &self.root_cert,
+      &ethernet_interface.clone().into(),
+    )
+    .await
+    .map_err(Error::from)
+  }
+
+  async fn update_ethernet_interface(
+    &self,
+    auth_token: &str,
+    eth_interface_id: &str,
+    description: Option<&str>,
+    component_id: Option<&str>,
+    ip_address_vec_opt: Option<&[FrontEndIpAddressMapping]>,
+  ) -> Result<(), Error> {
+    hsm::hw_inventory::ethernet_interfaces::http_client::patch(
+      auth_token,
+      &self.base_url,
+      &self.root_cert,
+      eth_interface_id,
+      description,
+      component_id,
+      ip_address_vec_opt.map(|ip_address_vec| {
+        ip_address_vec
+          .iter()
+          .cloned()
+          .map(|ip_address_mapping| ip_address_mapping.into())
+          .collect()
+      }),
+    )
+    .await
+    .map_err(Error::from)
+  }
+
+  async fn delete_ethernet_interface(
+    &self,
+    auth_token: &str,
+    eth_interface_id: &str,
+  ) -> Result<(), Error> {
+    hsm::hw_inventory::ethernet_interfaces::http_client::delete_one(
+      auth_token,
+      &self.base_url,
+      &self.root_cert,
+      eth_interface_id,
+    )
+    .await
+    .map_err(Error::from)
+  }
+}
+
 impl RedfishEndpointTrait for Csm {
   async fn get_all_redfish_endpoints(
     &self,
     _auth_token: &str,
   ) -> Result<FrontEndRedfishEndpointArray, Error> {
-    Err(Error::Message(
+    Err(Error::UnsupportedCapability(
       "Get all redfish endpoint command not implemented for this backend"
         .to_string(),
     ))
@@ -790,7 +1233,7 @@ impl RedfishEndpointTrait for Csm {
     _ip_address: Option<&str>,
     _last_status: Option<&str>,
   ) -> Result<FrontEndRedfishEndpointArray, Error> {
-    Err(Error::Message(
+    Err(Error::UnsupportedCapability(
       "Get redfish endpoint command not implemented for this backend"
         .to_string(),
     ))
@@ -801,7 +1244,7 @@ impl RedfishEndpointTrait for Csm {
     _auth_token: &str,
     _redfish_endpoint: &manta_backend_dispatcher::types::hsm::inventory::RedfishEndpointArray,
   ) -> Result<(), Error> {
-    Err(Error::Message(
+    Err(Error::UnsupportedCapability(
       "Add redfish endpoint command not implemented for this backend"
         .to_string(),
     ))
@@ -812,7 +1255,7 @@ impl RedfishEndpointTrait for Csm {
     _auth_token: &str,
     _redfish_endpoint: &manta_backend_dispatcher::types::hsm::inventory::RedfishEndpoint,
   ) -> Result<(), Error> {
-    Err(Error::Message(
+    Err(Error::UnsupportedCapability(
       "Update redfish endpoint command not implemented for this backend"
         .to_string(),
     ))
@@ -823,7 +1266,7 @@ impl RedfishEndpointTrait for Csm {
     _auth_token: &str,
     _id: &str,
   ) -> Result<Value, Error> {
-    Err(Error::Message(
+    Err(Error::UnsupportedCapability(
       "Delete redfish endpoint command not implemented for this backend"
         .to_string(),
     ))
@@ -836,24 +1279,64 @@ impl BackendTrait for Csm {
     "in mesa backend".to_string()
   }
 
-  async fn get_api_token(&self, site_name: &str) -> Result<String, Error> {
-    // FIXME: this is not nice but authentication/authorization will potentially move out to an
-    // external crate since this is type of logic is external to each site ...
-    let base_url = self
-      .base_url
-      .strip_suffix("/apis")
-      .unwrap_or(&self.base_url);
-
-    let keycloak_base_url = base_url.to_string() + "/keycloak";
+  fn get_capabilities(&self) -> Vec<Capability> {
+    vec![
+      Capability::Groups,
+      Capability::Components,
+      Capability::ComponentState,
+      Capability::NodeDetails,
+      Capability::HardwareInventory,
+      Capability::FruInventory,
+      Capability::EthernetInterfaces,
+      Capability::Locks,
+      Capability::BootParameters,
+      Capability::PowerManagement,
+      Capability::PowerCapping,
+      Capability::Configurations,
+      Capability::ConfigurationsDelete,
+      Capability::Sessions,
+      Capability::RuntimeConfiguration,
+      Capability::Images,
+      Capability::ImagesDelete,
+      Capability::ClusterTemplates,
+      Capability::ClusterSessions,
+      Capability::SatFile,
+      Capability::ApplySession,
+      Capability::HwClusterPin,
+      Capability::MigrateBackup,
+      Capability::MigrateRestore,
+      Capability::GarbageCollection,
+      Capability::Orphans,
+      Capability::Console,
+      Capability::ConsoleReadOnly,
+      Capability::ServiceHealth,
+    ]
+  }
 
+  async fn get_api_token(&self, site_name: &str) -> Result<String, Error> {
     authentication::get_api_token(
       &self.base_url,
       &self.root_cert,
-      &keycloak_base_url,
+      &self.keycloak_base_url(),
       site_name,
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
+  }
+
+  async fn authenticate(
+    &self,
+    username: &str,
+    password: &str,
+  ) -> Result<String, Error> {
+    authentication::get_token_from_shasta_endpoint(
+      &self.keycloak_base_url(),
+      &self.root_cert,
+      username,
+      password,
+    )
+    .await
+    .map_err(Error::from)
   }
 
   /// Get list of xnames from NIDs
@@ -884,7 +1367,7 @@ impl BackendTrait for Csm {
         Some("true"),
       )
       .await
-      .map_err(|e| Error::Message(e.to_string()))?
+      .map_err(Error::from)?
       .components
       .unwrap_or_default();
 
@@ -976,7 +1459,7 @@ impl BackendTrait for Csm {
         Some("true"),
       )
       .await
-      .map_err(|e| Error::Message(e.to_string()))?;
+      .map_err(Error::from)?;
 
       // Get list of xnames from HSM components
       let xname_vec: Vec<String> = hsm_components
@@ -1004,27 +1487,9 @@ impl CfsTrait for Csm {
     // k8s_api_url: &str,
     k8s: &K8sDetails,
   ) -> Result<Pin<Box<dyn AsyncBufRead + Send>>, Error> {
-    let shasta_k8s_secrets = match &k8s.authentication {
-      K8sAuth::Native {
-        certificate_authority_data,
-        client_certificate_data,
-        client_key_data,
-      } => {
-        serde_json::json!({ "certificate-authority-data": certificate_authority_data, "client-certificate-data": client_certificate_data, "client-key-data": client_key_data })
-      }
-      K8sAuth::Vault { base_url } => {
-        fetch_shasta_k8s_secrets_from_vault(&base_url, shasta_token, &site_name)
-          .await
-          .map_err(|e| Error::Message(format!("{e}")))?
-      }
-    };
-
-    let client = kubernetes::get_k8s_client_programmatically(
-      &k8s.api_url,
-      shasta_k8s_secrets,
-    )
-    .await
-    .map_err(|e| Error::Message(format!("{e}")))?;
+    let client = kubernetes::get_k8s_client(shasta_token, site_name, k8s)
+      .await
+      .map_err(|e| Error::Message(format!("{e}")))?;
 
     let log_stream_git_clone =
       kubernetes::get_cfs_session_init_container_git_clone_logs_stream(
@@ -1078,7 +1543,7 @@ impl CfsTrait for Csm {
       None,
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))?;
+    .map_err(Error::from)?;
 
     crate::cfs::session::utils::filter_by_xname(
       auth_token,
@@ -1090,7 +1555,7 @@ impl CfsTrait for Csm {
       true,
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))?;
+    .map_err(Error::from)?;
 
     if session_vec.is_empty() {
       return Err(Error::Message(format!(
@@ -1113,7 +1578,7 @@ impl CfsTrait for Csm {
   async fn get_cfs_health(&self) -> Result<(), Error> {
     crate::cfs::health::test_connectivity_to_backend(self.base_url.as_str())
       .await
-      .map_err(|e| Error::Message(e.to_string()))
+      .map_err(Error::from)
   }
 
   async fn post_session(
@@ -1131,7 +1596,7 @@ impl CfsTrait for Csm {
     )
     .await
     .map(|cfs_session| cfs_session.into())
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
   }
 
   /// Fetch CFS sessions ref --> https://apidocs.svc.cscs.ch/paas/cfs/operation/get_sessions/
@@ -1175,7 +1640,7 @@ impl CfsTrait for Csm {
           .map(|cfs_session| cfs_session.into())
           .collect::<Vec<CfsSessionGetResponse>>()
       })
-      .map_err(|e| Error::Message(e.to_string()));
+      .map_err(Error::from);
 
     border_session_vec
   }
@@ -1219,7 +1684,7 @@ impl CfsTrait for Csm {
           true,
         )
         .await
-        .map_err(|e| Error::Message(e.to_string()))?;
+        .map_err(Error::from)?;
       }
     }
 
@@ -1234,7 +1699,7 @@ impl CfsTrait for Csm {
         true,
       )
       .await
-      .map_err(|e| Error::Message(e.to_string()))?;
+      .map_err(Error::from)?;
     }
 
     if cfs_session_vec.is_empty() {
@@ -1394,7 +1859,7 @@ impl CfsTrait for Csm {
       dry_run,
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
   }
 
   async fn create_configuration_from_repos(
@@ -1413,7 +1878,7 @@ impl CfsTrait for Csm {
             repo_name_vec,
             local_git_commit_vec,
             playbook_file_name_opt,
-        ).await.map_err(|e| Error::Message(e.to_string()))?.into())
+        ).await.map_err(Error::from)?.into())
   }
 
   async fn get_configuration(
@@ -1431,7 +1896,7 @@ impl CfsTrait for Csm {
         configuration_name_opt.map(|elem| elem.as_str()),
       )
       .await
-      .map_err(|e| Error::Message(e.to_string()));
+      .map_err(Error::from);
 
     cfs_configuration_vec
       .map(|config_vec| config_vec.into_iter().map(|c| c.into()).collect())
@@ -1462,7 +1927,7 @@ impl CfsTrait for Csm {
     )
     .await
     .map(|config_vec| config_vec.into_iter().map(|c| c.into()).collect())
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
   }
 
   async fn get_configuration_layer_details(
@@ -1482,7 +1947,7 @@ impl CfsTrait for Csm {
     )
     .await
     .map(|layer_details| layer_details.into())
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
   }
 
   /// Create a new CFS configuration
@@ -1505,7 +1970,7 @@ impl CfsTrait for Csm {
     )
     .await
     .map(|cfs_configuration| cfs_configuration.into())
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
   }
 
   /// Fetch CFS sessions ref --> https://apidocs.svc.cscs.ch/paas/cfs/operation/get_sessions/
@@ -1536,7 +2001,7 @@ impl CfsTrait for Csm {
       is_succeded_opt,
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))?;
+    .map_err(Error::from)?;
 
     crate::cfs::session::utils::filter_by_xname(
       shasta_token,
@@ -1548,7 +2013,7 @@ impl CfsTrait for Csm {
       true,
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))?;
+    .map_err(Error::from)?;
 
     // Convert to manta session
     let border_session_vec = local_cfs_session_vec
@@ -1577,7 +2042,7 @@ impl CfsTrait for Csm {
       enabled,
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
   }
 
   // Get all CFS sessions, IMS images and BOS sessiontemplates related to a CFS configuration
@@ -1621,7 +2086,7 @@ impl CfsTrait for Csm {
         }),
       )
     })
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
   }
 
   async fn get_cfs_components(
@@ -1651,7 +2116,7 @@ impl CfsTrait for Csm {
         .map(|component| component.into())
         .collect()
     })
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
   }
 }
 
@@ -1661,10 +2126,8 @@ impl SatTrait for Csm {
     shasta_token: &str,
     shasta_base_url: &str,
     shasta_root_cert: &[u8],
-    vault_base_url: &str,
     site_name: &str,
-    k8s_api_url: &str,
-    shasta_k8s_secrets: serde_json::Value,
+    k8s: &K8sDetails,
     sat_template_file_yaml: serde_yaml::Value,
     hsm_group_available_vec: &Vec<String>,
     ansible_verbosity_opt: Option<u8>,
@@ -1681,10 +2144,8 @@ impl SatTrait for Csm {
       shasta_token,
       shasta_base_url,
       shasta_root_cert,
-      vault_base_url,
       site_name,
-      k8s_api_url,
-      shasta_k8s_secrets,
+      k8s,
       sat_template_file_yaml,
       hsm_group_available_vec,
       ansible_verbosity_opt,
@@ -1698,7 +2159,7 @@ impl SatTrait for Csm {
       dry_run,
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
   }
 }
 
@@ -1727,7 +2188,7 @@ impl ApplyHwClusterPin for Csm {
       delete_empty_parent_hsm_group,
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
   }
 }
 
@@ -1747,7 +2208,7 @@ impl ImsTrait for Csm {
     )
     .await
     .map(|image_vec| image_vec.into_iter().map(|image| image.into()).collect())
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
   }
 
   async fn get_all_images(
@@ -1763,7 +2224,7 @@ impl ImsTrait for Csm {
     )
     .await
     .map(|image_vec| image_vec.into_iter().map(|image| image.into()).collect())
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
   }
 
   fn filter_images(
@@ -1792,7 +2253,7 @@ impl ImsTrait for Csm {
       image_id,
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
   }
 }
 
@@ -1837,7 +2298,7 @@ impl ApplySessionTrait for Csm {
       k8s, */
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
   }
 }
 
@@ -1847,24 +2308,24 @@ impl MigrateRestoreTrait for Csm {
     shasta_token: &str,
     shasta_base_url: &str,
     shasta_root_cert: &[u8],
-    bos_file: Option<&String>,
-    cfs_file: Option<&String>,
-    hsm_file: Option<&String>,
-    ims_file: Option<&String>,
-    image_dir: Option<&String>,
+    backup_dir: &str,
+    bos_sessiontemplate_name_vec: &[String],
+    concurrency: usize,
+    assume_yes: bool,
+    overwrite_groups: bool,
   ) -> Result<(), Error> {
-    crate::commands::migrate_restore::exec(
+    crate::commands::migrate_restore::command::exec(
       shasta_token,
       shasta_base_url,
       shasta_root_cert,
-      bos_file,
-      cfs_file,
-      hsm_file,
-      ims_file,
-      image_dir,
+      backup_dir,
+      bos_sessiontemplate_name_vec,
+      concurrency,
+      assume_yes,
+      overwrite_groups,
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
   }
 }
 
@@ -1874,18 +2335,22 @@ impl MigrateBackupTrait for Csm {
     shasta_token: &str,
     shasta_base_url: &str,
     shasta_root_cert: &[u8],
-    bos: Option<&String>,
-    destination: Option<&String>,
+    bos_sessiontemplate_name_vec: &[String],
+    hsm_group_name_vec: &[String],
+    destination: &str,
+    concurrency: usize,
   ) -> Result<(), Error> {
-    crate::commands::migrate_backup::exec(
+    crate::commands::migrate_backup::command::exec(
       shasta_token,
       shasta_base_url,
       shasta_root_cert,
-      bos,
+      bos_sessiontemplate_name_vec,
+      hsm_group_name_vec,
       destination,
+      concurrency,
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
   }
 }
 
@@ -1924,7 +2389,7 @@ impl GetImagesAndDetailsTrait for Csm {
         .map(|(image, x, y, z)| (image.into(), x, y, z))
         .collect()
     })
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
   }
 }
 
@@ -1944,7 +2409,7 @@ impl ClusterSessionTrait for Csm {
     )
     .await
     .map(|bos_session| bos_session.into())
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
   }
 }
 
@@ -1969,7 +2434,7 @@ impl ClusterTemplateTrait for Csm {
         .map(|template| template.into())
         .collect::<Vec<BosSessionTemplate>>()
     })
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
   }
 
   async fn get_and_filter_templates(
@@ -1989,7 +2454,7 @@ impl ClusterTemplateTrait for Csm {
       bos_sessiontemplate_name_opt.map(|value| value.as_str()),
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))?;
+    .map_err(Error::from)?;
 
     bos::template::utils::filter(
       &mut bos_sessiontemplate_vec,
@@ -2024,7 +2489,7 @@ impl ClusterTemplateTrait for Csm {
         .map(|template| template.into())
         .collect::<Vec<BosSessionTemplate>>()
     })
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
   }
 
   async fn put_template(
@@ -2044,7 +2509,7 @@ impl ClusterTemplateTrait for Csm {
     )
     .await
     .map(|bos_session_template| bos_session_template.into())
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
   }
 
   async fn delete_template(
@@ -2061,12 +2526,12 @@ impl ClusterTemplateTrait for Csm {
       bos_template_id,
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
   }
 }
 
 impl CommandsTrait for Csm {
-  async fn i_delete_data_related_to_cfs_configuration(
+  async fn get_data_related_to_cfs_configuration(
     &self,
     shasta_token: &str,
     shasta_base_url: &str,
@@ -2076,9 +2541,8 @@ impl CommandsTrait for Csm {
     configuration_name_pattern: Option<&String>,
     since_opt: Option<NaiveDateTime>,
     until_opt: Option<NaiveDateTime>,
-    assume_yes: bool,
-  ) -> Result<(), Error> {
-    crate::commands::i_delete_data_related_to_cfs_configuration::exec(
+  ) -> Result<CfsConfigurationDerivatives, Error> {
+    crate::commands::i_delete_data_related_to_cfs_configuration::get_data_to_delete(
       shasta_token,
       shasta_base_url,
       shasta_root_cert,
@@ -2087,16 +2551,136 @@ impl CommandsTrait for Csm {
       configuration_name_pattern,
       since_opt,
       until_opt,
-      assume_yes,
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
+  }
+
+  async fn delete_data_related_to_cfs_configuration(
+    &self,
+    shasta_token: &str,
+    shasta_base_url: &str,
+    shasta_root_cert: &[u8],
+    cfs_configuration_derivatives: &CfsConfigurationDerivatives,
+  ) -> Result<(), Error> {
+    crate::commands::i_delete_data_related_to_cfs_configuration::delete(
+      shasta_token,
+      shasta_base_url,
+      shasta_root_cert,
+      cfs_configuration_derivatives
+        .cfs_configuration_vec
+        .iter()
+        .map(|(name, _)| name.as_str())
+        .collect(),
+      cfs_configuration_derivatives
+        .image_id_vec
+        .iter()
+        .map(String::as_str)
+        .collect(),
+      cfs_configuration_derivatives
+        .cfs_session_vec
+        .iter()
+        .map(|(name, _, _)| name.as_str())
+        .collect(),
+      cfs_configuration_derivatives
+        .bos_sessiontemplate_vec
+        .iter()
+        .map(|(name, _, _)| name.as_str())
+        .collect(),
+    )
+    .await;
+
+    Ok(())
+  }
+
+  async fn garbage_collect(
+    &self,
+    shasta_token: &str,
+    shasta_base_url: &str,
+    shasta_root_cert: &[u8],
+    hsm_group_name_vec: &[String],
+    gc_policy: &GcPolicy,
+    dry_run: bool,
+  ) -> Result<GcReport, Error> {
+    crate::commands::garbage_collect::command::exec(
+      shasta_token,
+      shasta_base_url,
+      shasta_root_cert,
+      hsm_group_name_vec,
+      gc_policy,
+      dry_run,
+    )
+    .await
+    .map_err(Error::from)
+  }
+
+  async fn delete_garbage(
+    &self,
+    shasta_token: &str,
+    shasta_base_url: &str,
+    shasta_root_cert: &[u8],
+    gc_item_vec: &[GcItem],
+  ) -> Result<GcReport, Error> {
+    Ok(
+      crate::commands::garbage_collect::command::delete(
+        shasta_token,
+        shasta_base_url,
+        shasta_root_cert,
+        gc_item_vec.to_vec(),
+      )
+      .await,
+    )
+  }
+
+  async fn get_orphans(
+    &self,
+    shasta_token: &str,
+    shasta_base_url: &str,
+    shasta_root_cert: &[u8],
+  ) -> Result<OrphanReport, Error> {
+    crate::commands::get_orphans::command::exec(
+      shasta_token,
+      shasta_base_url,
+      shasta_root_cert,
+    )
+    .await
+    .map_err(Error::from)
+  }
+}
+
+impl HealthTrait for Csm {
+  async fn get_service_health(
+    &self,
+    auth_token: &str,
+    service: &str,
+  ) -> Result<Value, Error> {
+    match service {
+      "cfs" => {
+        crate::cfs::common::health_check(
+          auth_token,
+          &self.base_url,
+          &self.root_cert,
+        )
+        .await
+      }
+      "bos" => {
+        bos::health_check::get(auth_token, &self.base_url, &self.root_cert)
+          .await
+      }
+      _ => {
+        return Err(Error::Message(format!(
+          "Invalid service name '{}'",
+          service
+        )));
+      }
+    }
+    .map_err(Error::from)
   }
 }
 
 impl ConsoleTrait for Csm {
-  type T = Box<dyn AsyncWrite + Unpin>;
-  type U = Box<dyn AsyncRead + Unpin>;
+  type T = Box<dyn AsyncWrite + Unpin + Send>;
+  type U = Box<dyn AsyncRead + Unpin + Send>;
 
   async fn attach_to_console(
     &self,
@@ -2105,35 +2689,20 @@ impl ConsoleTrait for Csm {
     xname: &str,
     term_width: u16,
     term_height: u16,
-    k8s: &K8sDetails,
-  ) -> Result<(Box<dyn AsyncWrite + Unpin>, Box<dyn AsyncRead + Unpin>), Error>
-  {
-    let shasta_k8s_secrets = match &k8s.authentication {
-      K8sAuth::Native {
-        certificate_authority_data,
-        client_certificate_data,
-        client_key_data,
-      } => {
-        serde_json::json!({ "certificate-authority-data": certificate_authority_data, "client-certificate-data": client_certificate_data, "client-key-data": client_key_data })
-      }
-      K8sAuth::Vault {
-        base_url,
-        // secret_path: _secret_path,
-      } => {
-        fetch_shasta_k8s_secrets_from_vault(&base_url, shasta_token, &site_name)
-          .await
-          .map_err(|e| Error::Message(e.to_string()))?
-      }
-    };
+    k8s_opt: Option<&K8sDetails>,
+  ) -> Result<(Self::T, Self::U), Error> {
+    let k8s = k8s_opt.ok_or_else(|| {
+      Error::Message("k8s section not found in site configuration".to_string())
+    })?;
 
-    let mut attached: AttachedProcess =
-      console::get_container_attachment_to_conman(
-        &xname.to_string(),
-        &k8s.api_url,
-        shasta_k8s_secrets,
-      )
+    let client = kubernetes::get_k8s_client(shasta_token, site_name, k8s)
       .await
-      .map_err(|e| Error::Message(e.to_string()))?;
+      .map_err(Error::from)?;
+
+    let mut attached: AttachedProcess =
+      console::get_container_attachment_to_conman(client, &xname.to_string())
+        .await
+        .map_err(Error::from)?;
 
     let mut terminal_size_writer: Sender<TerminalSize> =
       attached.terminal_size().unwrap();
@@ -2155,4 +2724,30 @@ impl ConsoleTrait for Csm {
       Box::new(attached.stdout().unwrap()),
     ))
   }
+
+  async fn attach_to_console_read_only(
+    &self,
+    shasta_token: &str,
+    site_name: &str,
+    xname: &str,
+    k8s_opt: Option<&K8sDetails>,
+  ) -> Result<Self::U, Error> {
+    let k8s = k8s_opt.ok_or_else(|| {
+      Error::Message("k8s section not found in site configuration".to_string())
+    })?;
+
+    let client = kubernetes::get_k8s_client(shasta_token, site_name, k8s)
+      .await
+      .map_err(Error::from)?;
+
+    let attached =
+      console::get_container_attachment_to_conman_read_only(client, xname)
+        .await
+        .map_err(Error::from)?;
+
+    let console_output = console::ConsoleOutput::new(attached)
+      .map_err(Error::from)?;
+
+    Ok(Box::new(console_output))
+  }
 }
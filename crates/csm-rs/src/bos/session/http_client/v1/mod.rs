@@ -1,3 +1,4 @@
+use manta_backend_dispatcher::error::Service;
 use serde_json::{json, Value};
 
 use crate::error::Error;
@@ -50,11 +51,17 @@ pub async fn post(
       .await
       .map_err(|error| Error::NetError(error))
   } else {
+    let status = response.status().as_u16();
     let payload = response
       .json::<Value>()
       .await
       .map_err(|error| Error::NetError(error))?;
 
-    Err(Error::CsmError(payload))
+    Err(Error::HttpError {
+      status,
+      service: Service::Bos,
+      resource_id: Some(bos_template_name.to_string()),
+      payload: payload.to_string(),
+    })
   }
 }
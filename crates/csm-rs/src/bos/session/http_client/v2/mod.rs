@@ -1,5 +1,6 @@
 pub mod types;
 
+use manta_backend_dispatcher::error::Service;
 use serde_json::Value;
 use types::BosSession;
 
@@ -55,12 +56,18 @@ pub async fn post(
     );
     Ok(bos_session)
   } else {
+    let status = response.status().as_u16();
     let payload = response
       .json::<Value>()
       .await
       .map_err(|error| Error::NetError(error))?;
 
-    Err(Error::CsmError(payload))
+    Err(Error::HttpError {
+      status,
+      service: Service::Bos,
+      resource_id: None,
+      payload: payload.to_string(),
+    })
   }
 }
 
@@ -117,12 +124,18 @@ pub async fn get(
         .map_err(|error| Error::NetError(error))
     }
   } else {
+    let status = response.status().as_u16();
     let payload = response
       .json::<Value>()
       .await
       .map_err(|error| Error::NetError(error))?;
 
-    Err(Error::CsmError(payload))
+    Err(Error::HttpError {
+      status,
+      service: Service::Bos,
+      resource_id: id_opt.map(str::to_string),
+      payload: payload.to_string(),
+    })
   }
 }
 
@@ -161,11 +174,17 @@ pub async fn delete(
   if response.status().is_success() {
     Ok(())
   } else {
+    let status = response.status().as_u16();
     let payload = response
       .json::<Value>()
       .await
       .map_err(|error| Error::NetError(error))?;
 
-    Err(Error::CsmError(payload))
+    Err(Error::HttpError {
+      status,
+      service: Service::Bos,
+      resource_id: Some(bos_session_id.to_string()),
+      payload: payload.to_string(),
+    })
   }
 }
@@ -1,5 +1,8 @@
 pub mod types;
 
+use manta_backend_dispatcher::error::Service;
+use serde_json::Value;
+
 use crate::{
   bos::template::http_client::v1::types::BosSessionTemplate, error::Error,
 };
@@ -102,10 +105,16 @@ pub async fn post(
         .map_err(|error| Error::NetError(error))?,
     )
   } else {
+    let status = response.status().as_u16();
     let payload = response
-      .json()
+      .json::<Value>()
       .await
       .map_err(|error| Error::NetError(error))?;
-    Err(Error::CsmError(payload))
+    Err(Error::HttpError {
+      status,
+      service: Service::Bos,
+      resource_id: None,
+      payload: payload.to_string(),
+    })
   }
 }
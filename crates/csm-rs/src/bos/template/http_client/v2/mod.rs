@@ -1,5 +1,6 @@
 pub mod types;
 
+use manta_backend_dispatcher::error::Service;
 use serde_json::Value;
 
 use crate::{
@@ -60,11 +61,17 @@ pub async fn get(
         .map_err(|error| Error::NetError(error))
     }
   } else {
+    let status = response.status().as_u16();
     let payload = response
       .json::<Value>()
       .await
       .map_err(|error| Error::NetError(error))?;
-    Err(Error::CsmError(payload))
+    Err(Error::HttpError {
+      status,
+      service: Service::Bos,
+      resource_id: bos_session_template_id_opt.map(str::to_string),
+      payload: payload.to_string(),
+    })
   }
 }
 
@@ -121,8 +128,14 @@ pub async fn put(
   if response.status().is_success() {
     response.json().await.map_err(Error::NetError)
   } else {
+    let status = response.status().as_u16();
     let payload = response.json::<Value>().await.map_err(Error::NetError)?;
-    Err(Error::CsmError(payload))
+    Err(Error::HttpError {
+      status,
+      service: Service::Bos,
+      resource_id: Some(bos_template_name.to_string()),
+      payload: payload.to_string(),
+    })
   }
 }
 
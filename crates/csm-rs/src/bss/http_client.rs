@@ -1,6 +1,7 @@
 use tokio::sync::Semaphore;
 
 use core::result::Result;
+use manta_backend_dispatcher::error::Service;
 use std::{sync::Arc, time::Instant};
 
 use crate::error::Error;
@@ -51,11 +52,17 @@ pub async fn get(
       .await
       .map_err(|error| Error::NetError(error))
   } else {
+    let status = response.status().as_u16();
     let payload = response
       .text()
       .await
       .map_err(|error| Error::NetError(error))?;
-    Err(Error::Message(payload))
+    Err(Error::HttpError {
+      status,
+      service: Service::Bss,
+      resource_id: None,
+      payload,
+    })
   }
 }
 
@@ -152,7 +159,12 @@ pub fn post(
   if response.status().is_success() {
     Ok(())
   } else {
-    Err(Error::Message(response.text()?))
+    Err(Error::HttpError {
+      status: response.status().as_u16(),
+      service: Service::Bss,
+      resource_id: None,
+      payload: response.text()?,
+    })
   }
 }
 
@@ -198,7 +210,12 @@ pub async fn put(
   if response.status().is_success() {
     Ok(response.json().await?)
   } else {
-    Err(Error::Message(response.text().await?))
+    Err(Error::HttpError {
+      status: response.status().as_u16(),
+      service: Service::Bss,
+      resource_id: None,
+      payload: response.text().await?,
+    })
   }
 }
 
@@ -238,6 +255,11 @@ pub async fn patch(
   if response.status().is_success() {
     Ok(())
   } else {
-    Err(Error::Message(response.text().await?))
+    Err(Error::HttpError {
+      status: response.status().as_u16(),
+      service: Service::Bss,
+      resource_id: None,
+      payload: response.text().await?,
+    })
   }
 }
@@ -2,6 +2,7 @@ pub mod types;
 
 use std::{sync::Arc, time::Instant};
 
+use manta_backend_dispatcher::error::Service;
 use serde_json::Value;
 use tokio::sync::Semaphore;
 use types::{Component, ComponentVec};
@@ -47,11 +48,17 @@ pub async fn get(
       .await
       .map_err(|error| Error::NetError(error))
   } else {
+    let status = response.status().as_u16();
     let payload = response
       .text()
       .await
       .map_err(|error| Error::NetError(error))?;
-    Err(Error::Message(payload))
+    Err(Error::HttpError {
+      status,
+      service: Service::Cfs,
+      resource_id: None,
+      payload,
+    })
   }
 }
 
@@ -100,11 +107,17 @@ pub async fn get_single_component(
       .await
       .map_err(|error| Error::NetError(error))
   } else {
+    let status = response.status().as_u16();
     let payload = response
       .text()
       .await
       .map_err(|error| Error::NetError(error))?;
-    Err(Error::Message(payload))
+    Err(Error::HttpError {
+      status,
+      service: Service::Cfs,
+      resource_id: Some(component_id.to_string()),
+      payload,
+    })
   }
 }
 
@@ -311,11 +324,17 @@ pub async fn get_query(
       .map(|component_vec: ComponentVec| component_vec.components)
       .map_err(|error| Error::NetError(error))
   } else {
+    let status = response.status().as_u16();
     let payload = response
       .text()
       .await
       .map_err(|error| Error::NetError(error))?;
-    Err(Error::Message(payload))
+    Err(Error::HttpError {
+      status,
+      service: Service::Cfs,
+      resource_id: None,
+      payload,
+    })
   }
 }
 
@@ -358,11 +377,17 @@ pub async fn put_component(
       .await
       .map_err(|error| Error::NetError(error))
   } else {
+    let status = response.status().as_u16();
     let payload = response
       .json::<Value>()
       .await
       .map_err(|error| Error::NetError(error))?;
-    Err(Error::CsmError(payload))
+    Err(Error::HttpError {
+      status,
+      service: Service::Cfs,
+      resource_id: None,
+      payload: payload.to_string(),
+    })
   }
 }
 
@@ -422,10 +447,16 @@ pub async fn delete_single_component(
       .await
       .map_err(|error| Error::NetError(error))
   } else {
+    let status = response.status().as_u16();
     let payload = response
       .text()
       .await
       .map_err(|error| Error::NetError(error))?;
-    Err(Error::Message(payload))
+    Err(Error::HttpError {
+      status,
+      service: Service::Cfs,
+      resource_id: Some(component_id.to_string()),
+      payload,
+    })
   }
 }
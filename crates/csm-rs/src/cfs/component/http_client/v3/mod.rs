@@ -2,6 +2,7 @@ pub mod types;
 
 use std::{sync::Arc, time::Instant};
 
+use manta_backend_dispatcher::error::Service;
 use serde_json::Value;
 use tokio::sync::Semaphore;
 use types::ComponentVec;
@@ -45,11 +46,17 @@ pub async fn get_options(
       .await
       .map_err(|error| Error::NetError(error))
   } else {
+    let status = response.status().as_u16();
     let payload = response
       .text()
       .await
       .map_err(|error| Error::NetError(error))?;
-    Err(Error::Message(payload))
+    Err(Error::HttpError {
+      status,
+      service: Service::Cfs,
+      resource_id: None,
+      payload,
+    })
   }
 }
 
@@ -92,11 +99,17 @@ pub async fn get(
       .map(|component_vec| component_vec.components)
       .map_err(|e| Error::NetError(e))
   } else {
+    let status = response.status().as_u16();
     let payload = response
       .text()
       .await
       .map_err(|error| Error::NetError(error))?;
-    Err(Error::Message(payload))
+    Err(Error::HttpError {
+      status,
+      service: Service::Cfs,
+      resource_id: None,
+      payload,
+    })
   }
 }
 
@@ -137,11 +150,17 @@ pub async fn get_single_by_id(
       .await
       .map_err(|error| Error::NetError(error))
   } else {
+    let status = response.status().as_u16();
     let payload = response
       .text()
       .await
       .map_err(|error| Error::NetError(error))?;
-    Err(Error::Message(payload))
+    Err(Error::HttpError {
+      status,
+      service: Service::Cfs,
+      resource_id: Some(component_id.to_string()),
+      payload,
+    })
   }
 }
 
@@ -267,11 +286,17 @@ pub async fn get_query(
       .map(|component_vec: ComponentVec| component_vec.components)
       .map_err(|error| Error::NetError(error))
   } else {
+    let status = response.status().as_u16();
     let payload = response
       .text()
       .await
       .map_err(|error| Error::NetError(error))?;
-    Err(Error::Message(payload))
+    Err(Error::HttpError {
+      status,
+      service: Service::Cfs,
+      resource_id: None,
+      payload,
+    })
   }
 }
 
@@ -314,11 +339,17 @@ pub async fn patch_component(
       .await
       .map_err(|error| Error::NetError(error))
   } else {
+    let status = response.status().as_u16();
     let payload = response
       .text()
       .await
       .map_err(|error| Error::NetError(error))?;
-    Err(Error::Message(payload))
+    Err(Error::HttpError {
+      status,
+      service: Service::Cfs,
+      resource_id: None,
+      payload,
+    })
   }
 }
 
@@ -356,11 +387,17 @@ pub async fn patch_component_list(
   if response.status().is_success() {
     Ok(())
   } else {
+    let status = response.status().as_u16();
     let payload = response
       .text()
       .await
       .map_err(|error| Error::NetError(error))?;
-    Err(Error::Message(payload))
+    Err(Error::HttpError {
+      status,
+      service: Service::Cfs,
+      resource_id: None,
+      payload,
+    })
   }
 }
 
@@ -403,11 +440,17 @@ pub async fn put_component(
       .await
       .map_err(|error| Error::NetError(error))
   } else {
+    let status = response.status().as_u16();
     let payload = response
-      .json()
+      .json::<Value>()
       .await
       .map_err(|error| Error::NetError(error))?;
-    Err(Error::CsmError(payload))
+    Err(Error::HttpError {
+      status,
+      service: Service::Cfs,
+      resource_id: None,
+      payload: payload.to_string(),
+    })
   }
 }
 
@@ -467,10 +510,16 @@ pub async fn delete_single_component(
       .await
       .map_err(|error| Error::NetError(error))
   } else {
+    let status = response.status().as_u16();
     let payload = response
       .text()
       .await
       .map_err(|error| Error::NetError(error))?;
-    Err(Error::Message(payload))
+    Err(Error::HttpError {
+      status,
+      service: Service::Cfs,
+      resource_id: Some(component_id.to_string()),
+      payload,
+    })
   }
 }
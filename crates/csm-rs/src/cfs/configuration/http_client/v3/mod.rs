@@ -1,5 +1,6 @@
 pub mod types;
 
+use manta_backend_dispatcher::error::Service;
 use serde_json::Value;
 
 use crate::{
@@ -70,12 +71,18 @@ pub async fn get(
       Ok(payload.configurations)
     }
   } else {
+    let status = response.status().as_u16();
     let payload = response
       .text()
       .await
       .map_err(|error| Error::NetError(error))?;
 
-    Err(Error::Message(payload))
+    Err(Error::HttpError {
+      status,
+      service: Service::Cfs,
+      resource_id: configuration_name_opt.map(str::to_string),
+      payload,
+    })
   }
 }
 
@@ -158,12 +165,18 @@ pub async fn put(
         .map_err(|error| Error::NetError(error))?,
     )
   } else {
+    let status = response.status().as_u16();
     let payload = response
       .text()
       .await
       .map_err(|error| Error::NetError(error))?;
 
-    Err(Error::Message(payload))
+    Err(Error::HttpError {
+      status,
+      service: Service::Cfs,
+      resource_id: Some(configuration_name.to_string()),
+      payload,
+    })
   }
 }
 
@@ -203,11 +216,17 @@ pub async fn delete(
   if response.status().is_success() {
     Ok(())
   } else {
+    let status = response.status().as_u16();
     let payload = response
       .json::<Value>()
       .await
       .map_err(|error| Error::NetError(error))?;
 
-    Err(Error::CsmError(payload))
+    Err(Error::HttpError {
+      status,
+      service: Service::Cfs,
+      resource_id: Some(configuration_id.to_string()),
+      payload: payload.to_string(),
+    })
   }
 }
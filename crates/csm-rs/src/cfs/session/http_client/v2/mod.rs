@@ -1,5 +1,6 @@
 pub mod types;
 
+use manta_backend_dispatcher::error::Service;
 use serde_json::Value;
 
 use crate::error::Error;
@@ -86,11 +87,17 @@ pub async fn get(
         .map_err(|error| Error::NetError(error))
     }
   } else {
+    let status = response.status().as_u16();
     let payload = response
       .json::<Value>()
       .await
       .map_err(|error| Error::NetError(error))?;
-    Err(Error::CsmError(payload))
+    Err(Error::HttpError {
+      status,
+      service: Service::Cfs,
+      resource_id: session_name_opt.cloned(),
+      payload: payload.to_string(),
+    })
   }
 }
 
@@ -153,11 +160,17 @@ pub async fn post(
         .map_err(|error| Error::NetError(error))?,
     )
   } else {
+    let status = response.status().as_u16();
     let payload = response
       .json::<Value>()
       .await
       .map_err(|error| Error::NetError(error))?;
-    Err(Error::CsmError(payload))
+    Err(Error::HttpError {
+      status,
+      service: Service::Cfs,
+      resource_id: None,
+      payload: payload.to_string(),
+    })
   }
 }
 
@@ -198,10 +211,16 @@ pub async fn delete(
   if response.status().is_success() {
     Ok(())
   } else {
+    let status = response.status().as_u16();
     let payload = response
       .json::<Value>()
       .await
       .map_err(|error| Error::NetError(error))?;
-    Err(Error::CsmError(payload))
+    Err(Error::HttpError {
+      status,
+      service: Service::Cfs,
+      resource_id: Some(session_name.to_string()),
+      payload: payload.to_string(),
+    })
   }
 }
@@ -3,12 +3,10 @@ pub mod utils;
 
 use crate::cfs;
 use http_client::v2::types::{CfsSessionGetResponse, CfsSessionPostRequest};
+use manta_backend_dispatcher::types::K8sDetails;
 
 use crate::{
-  common::{
-    kubernetes::{self, print_cfs_session_logs},
-    vault::http_client::fetch_shasta_k8s_secrets_from_vault,
-  },
+  common::kubernetes::{self, print_cfs_session_logs},
   error::Error,
 };
 
@@ -88,9 +86,8 @@ pub async fn post_sync(
   shasta_token: &str,
   shasta_base_url: &str,
   shasta_root_cert: &[u8],
-  vault_base_url: &str,
   site_name: &str,
-  k8s_api_url: &str,
+  k8s: &K8sDetails,
   session: &CfsSessionPostRequest,
   watch_logs: bool,
 ) -> Result<CfsSessionGetResponse, Error> {
@@ -110,19 +107,8 @@ pub async fn post_sync(
   // `manta logs`
   if watch_logs {
     log::info!("Fetching logs form CFS session {} ...", session.name);
-    let shasta_k8s_secrets = fetch_shasta_k8s_secrets_from_vault(
-      vault_base_url,
-      shasta_token,
-      site_name,
-    )
-    .await?;
-
-    let client = kubernetes::get_k8s_client_programmatically(
-      k8s_api_url,
-      shasta_k8s_secrets,
-    )
-    .await
-    .unwrap();
+    let client =
+      kubernetes::get_k8s_client(shasta_token, site_name, k8s).await?;
 
     let _ = print_cfs_session_logs(client, &cfs_session_name).await;
   }
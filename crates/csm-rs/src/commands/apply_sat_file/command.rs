@@ -11,15 +11,14 @@ use crate::{
   hsm::group::utils::update_hsm_group_members,
   ims,
 };
+use manta_backend_dispatcher::types::K8sDetails;
 
 pub async fn exec(
   shasta_token: &str,
   shasta_base_url: &str,
   shasta_root_cert: &[u8],
-  vault_base_url: &str,
   site_name: &str,
-  k8s_api_url: &str,
-  shasta_k8s_secrets: serde_json::Value,
+  k8s: &K8sDetails,
   sat_template_file_yaml: serde_yaml::Value,
   hsm_group_available_vec: &Vec<String>,
   ansible_verbosity_opt: Option<u8>,
@@ -52,12 +51,8 @@ pub async fn exec(
     sat_template_file_yaml["session_templates"].as_sequence();
 
   // Get k8s credentials needed to check HPE/Cray product catalog in k8s
-  let kube_client = kubernetes::get_k8s_client_programmatically(
-    k8s_api_url,
-    shasta_k8s_secrets,
-  )
-  .await
-  .unwrap();
+  let kube_client =
+    kubernetes::get_k8s_client(shasta_token, site_name, k8s).await?;
 
   // Get HPE product catalog from k8s
   let cray_product_catalog =
@@ -267,11 +262,8 @@ pub async fn exec(
       shasta_token,
       shasta_base_url,
       shasta_root_cert,
-      vault_base_url,
       site_name,
-      // vault_secret_path,
-      // vault_role_id,
-      k8s_api_url,
+      k8s,
       &mut ref_name_processed_hashmap,
       image_yaml_vec_opt.unwrap_or(&Vec::new()).to_vec(),
       &cray_product_catalog,
@@ -21,6 +21,7 @@ use crate::{
   node::utils::validate_target_hsm_members,
 };
 use image::Image;
+use manta_backend_dispatcher::types::K8sDetails;
 use serde::{Deserialize, Serialize};
 use serde_json::Map;
 use serde_yaml::Value;
@@ -804,11 +805,8 @@ pub async fn import_images_section_in_sat_file(
   shasta_token: &str,
   shasta_base_url: &str,
   shasta_root_cert: &[u8],
-  vault_base_url: &str,
   site_name: &str,
-  // vault_secret_path: &str,
-  // vault_role_id: &str,
-  k8s_api_url: &str,
+  k8s: &K8sDetails,
   ref_name_processed_hashmap: &mut HashMap<String, String>,
   image_yaml_vec: Vec<serde_yaml::Value>,
   cray_product_catalog: &BTreeMap<String, String>,
@@ -844,11 +842,8 @@ pub async fn import_images_section_in_sat_file(
       shasta_token,
       shasta_base_url,
       shasta_root_cert,
-      vault_base_url,
       site_name,
-      // vault_secret_path,
-      // vault_role_id,
-      k8s_api_url,
+      k8s,
       image_yaml,
       cray_product_catalog,
       ansible_verbosity_opt,
@@ -883,9 +878,8 @@ pub async fn create_image_from_sat_file_serde_yaml(
   shasta_token: &str,
   shasta_base_url: &str,
   shasta_root_cert: &[u8],
-  vault_base_url: &str,
   site_name: &str,
-  k8s_api_url: &str,
+  k8s: &K8sDetails,
   image_yaml: &serde_yaml::Value, // NOTE: image may be an IMS job or a CFS session
   cray_product_catalog: &BTreeMap<String, String>,
   ansible_verbosity_opt: Option<u8>,
@@ -1133,11 +1127,8 @@ pub async fn create_image_from_sat_file_serde_yaml(
         shasta_token,
         shasta_base_url,
         shasta_root_cert,
-        vault_base_url,
         site_name,
-        // vault_secret_path,
-        // vault_role_id,
-        k8s_api_url,
+        k8s,
         &cfs_session,
         watch_logs,
       )
@@ -0,0 +1,488 @@
+use std::collections::{HashMap, HashSet};
+
+use manta_backend_dispatcher::types::gc::{
+  GcItem, GcItemKind, GcPolicy, GcReport,
+};
+
+use crate::{
+  bos, bss,
+  cfs::{self, session::http_client::v2::types::CfsSessionGetResponse},
+  commands::i_delete_data_related_to_cfs_configuration::get_node_vec_booting_image,
+  error::Error,
+  hsm, ims,
+};
+
+/// Deletes the CFS configurations and images related to the groups which are
+/// not among the most recent ones the policy keeps, and the failed CFS
+/// sessions older than the policy age. CFS configurations used as desired
+/// configuration by any node and images used to boot any node are kept, as
+/// well as anything referenced by a BOS session template.
+/// Deletion errors do not stop the process, they are returned in the report
+pub async fn exec(
+  shasta_token: &str,
+  shasta_base_url: &str,
+  shasta_root_cert: &[u8],
+  hsm_group_name_vec: &[String],
+  gc_policy: &GcPolicy,
+  dry_run: bool,
+) -> Result<GcReport, Error> {
+  log::info!("Fetching data from the backend...");
+  let (
+    cfs_component_vec,
+    cfs_configuration_vec,
+    cfs_session_vec,
+    bos_sessiontemplate_vec,
+    bss_bootparameters_vec,
+    image_vec,
+    hsm_group_vec,
+  ) = tokio::try_join!(
+    cfs::component::http_client::v2::get_all(
+      shasta_token,
+      shasta_base_url,
+      shasta_root_cert
+    ),
+    cfs::configuration::http_client::v2::get_all(
+      shasta_token,
+      shasta_base_url,
+      shasta_root_cert
+    ),
+    cfs::session::http_client::v2::get_all(
+      shasta_token,
+      shasta_base_url,
+      shasta_root_cert
+    ),
+    bos::template::http_client::v2::get_all(
+      shasta_token,
+      shasta_base_url,
+      shasta_root_cert
+    ),
+    bss::http_client::get_all(shasta_token, shasta_base_url, shasta_root_cert),
+    ims::image::http_client::get_all(
+      shasta_token,
+      shasta_base_url,
+      shasta_root_cert
+    ),
+    hsm::group::http_client::get_all(
+      shasta_token,
+      shasta_base_url,
+      shasta_root_cert
+    ),
+  )?;
+
+  let hsm_group_name_all_vec: Vec<&str> = hsm_group_vec
+    .iter()
+    .map(|hsm_group| hsm_group.label.as_str())
+    .collect();
+
+  let target_hsm_group_name_vec: Vec<&str> =
+    hsm_group_name_vec.iter().map(String::as_str).collect();
+
+  let mut gc_report = GcReport::default();
+
+  // CFS CONFIGURATIONS
+  if let Some(keep_configurations) = gc_policy.keep_configurations {
+    // Configurations related to a group are the ones named after the group or
+    // used by CFS sessions targeting the group. All groups are checked since
+    // a configuration is kept if it is recent for any group using it
+    let mut group_configuration_map: HashMap<&str, Vec<(&str, &str)>> =
+      HashMap::new();
+
+    for &hsm_group_name in &hsm_group_name_all_vec {
+      let configuration_in_session_vec: Vec<&str> = cfs_session_vec
+        .iter()
+        .filter(|cfs_session| {
+          is_session_targeting_group(cfs_session, hsm_group_name)
+        })
+        .filter_map(|cfs_session| cfs_session.configuration_namen())
+        .collect();
+
+      group_configuration_map.insert(
+        hsm_group_name,
+        cfs_configuration_vec
+          .iter()
+          .filter(|cfs_configuration| {
+            is_name_related_to_group(
+              &cfs_configuration.name,
+              hsm_group_name,
+              &hsm_group_name_all_vec,
+            ) || configuration_in_session_vec
+              .contains(&cfs_configuration.name.as_str())
+          })
+          .map(|cfs_configuration| {
+            (
+              cfs_configuration.name.as_str(),
+              cfs_configuration.last_updated.as_str(),
+            )
+          })
+          .collect(),
+      );
+    }
+
+    for configuration_name in get_items_to_delete(
+      &group_configuration_map,
+      &target_hsm_group_name_vec,
+      keep_configurations,
+    ) {
+      let date = cfs_configuration_vec
+        .iter()
+        .find(|cfs_configuration| cfs_configuration.name == configuration_name)
+        .map(|cfs_configuration| cfs_configuration.last_updated.clone());
+
+      let mut xname_vec: Vec<&str> = cfs_component_vec
+        .iter()
+        .filter(|cfs_component| {
+          cfs_component.desired_config.as_deref() == Some(configuration_name)
+        })
+        .filter_map(|cfs_component| cfs_component.id.as_deref())
+        .collect();
+      xname_vec.sort();
+
+      let bos_sessiontemplate_name_vec: Vec<&str> = bos_sessiontemplate_vec
+        .iter()
+        .filter(|bos_sessiontemplate| {
+          bos_sessiontemplate.get_configuration() == Some(configuration_name)
+        })
+        .filter_map(|bos_sessiontemplate| bos_sessiontemplate.name.as_deref())
+        .collect();
+
+      let (reason, keep) = if !xname_vec.is_empty() {
+        (
+          format!("Desired configuration of nodes: {}", xname_vec.join(", ")),
+          true,
+        )
+      } else if !bos_sessiontemplate_name_vec.is_empty() {
+        (
+          format!(
+            "Used by BOS session templates: {}",
+            bos_sessiontemplate_name_vec.join(", ")
+          ),
+          true,
+        )
+      } else {
+        (
+          format!(
+            "Not in the {} most recent configurations of its groups",
+            keep_configurations
+          ),
+          false,
+        )
+      };
+
+      let gc_item = GcItem {
+        kind: GcItemKind::Configuration,
+        id: configuration_name.to_string(),
+        name: None,
+        date,
+        reason,
+      };
+
+      if keep {
+        gc_report.keep.push(gc_item);
+      } else {
+        gc_report.delete.push(gc_item);
+      }
+    }
+  }
+
+  // IMAGES
+  if let Some(keep_images) = gc_policy.keep_images {
+    // Images related to a group are the ones named after the group or built by
+    // CFS sessions targeting the group. All groups are checked since an image
+    // is kept if it is recent for any group using it
+    let mut group_image_map: HashMap<&str, Vec<(&str, &str)>> = HashMap::new();
+
+    for &hsm_group_name in &hsm_group_name_all_vec {
+      let image_in_session_vec: Vec<&str> = cfs_session_vec
+        .iter()
+        .filter(|cfs_session| {
+          is_session_targeting_group(cfs_session, hsm_group_name)
+        })
+        .flat_map(|cfs_session| cfs_session.results_id())
+        .collect();
+
+      group_image_map.insert(
+        hsm_group_name,
+        image_vec
+          .iter()
+          .filter_map(|image| image.id.as_deref().map(|id| (id, image)))
+          .filter(|(image_id, image)| {
+            is_name_related_to_group(
+              &image.name,
+              hsm_group_name,
+              &hsm_group_name_all_vec,
+            ) || image_in_session_vec.contains(image_id)
+          })
+          .map(|(image_id, image)| {
+            (image_id, image.created.as_deref().unwrap_or_default())
+          })
+          .collect(),
+      );
+    }
+
+    for image_id in get_items_to_delete(
+      &group_image_map,
+      &target_hsm_group_name_vec,
+      keep_images,
+    ) {
+      let image_opt = image_vec
+        .iter()
+        .find(|image| image.id.as_deref() == Some(image_id));
+
+      let xname_vec =
+        get_node_vec_booting_image(image_id, &bss_bootparameters_vec);
+
+      let bos_sessiontemplate_name_vec: Vec<&str> = bos_sessiontemplate_vec
+        .iter()
+        .filter(|bos_sessiontemplate| {
+          bos_sessiontemplate.images_id().any(|id| id == image_id)
+        })
+        .filter_map(|bos_sessiontemplate| bos_sessiontemplate.name.as_deref())
+        .collect();
+
+      let (reason, keep) = if !xname_vec.is_empty() {
+        (
+          format!("Boot image of nodes: {}", xname_vec.join(", ")),
+          true,
+        )
+      } else if !bos_sessiontemplate_name_vec.is_empty() {
+        (
+          format!(
+            "Used by BOS session templates: {}",
+            bos_sessiontemplate_name_vec.join(", ")
+          ),
+          true,
+        )
+      } else {
+        (
+          format!(
+            "Not in the {} most recent images of its groups",
+            keep_images
+          ),
+          false,
+        )
+      };
+
+      let gc_item = GcItem {
+        kind: GcItemKind::Image,
+        id: image_id.to_string(),
+        name: image_opt.map(|image| image.name.clone()),
+        date: image_opt.and_then(|image| image.created.clone()),
+        reason,
+      };
+
+      if keep {
+        gc_report.keep.push(gc_item);
+      } else {
+        gc_report.delete.push(gc_item);
+      }
+    }
+  }
+
+  // FAILED CFS SESSIONS
+  if let Some(failed_session_min_age) = &gc_policy.failed_session_min_age {
+    let xname_vec = hsm::group::utils::get_member_vec_from_hsm_name_vec(
+      shasta_token,
+      shasta_base_url,
+      shasta_root_cert,
+      hsm_group_name_vec,
+    )
+    .await?;
+
+    let mut cfs_session_vec = cfs::session::http_client::v2::get(
+      shasta_token,
+      shasta_base_url,
+      shasta_root_cert,
+      Some(failed_session_min_age),
+      None,
+      Some(&"complete".to_string()),
+      None,
+      None,
+    )
+    .await?;
+
+    cfs_session_vec.retain(|cfs_session| {
+      is_session_failed(cfs_session)
+        && (hsm_group_name_vec.iter().any(|hsm_group_name| {
+          is_session_targeting_group(cfs_session, hsm_group_name)
+        }) || cfs_session.get_target_xname().is_some_and(
+          |target_xname_vec| {
+            !target_xname_vec.is_empty()
+              && target_xname_vec
+                .iter()
+                .all(|target_xname| xname_vec.contains(target_xname))
+          },
+        ))
+    });
+
+    for cfs_session in cfs_session_vec {
+      gc_report.delete.push(GcItem {
+        kind: GcItemKind::Session,
+        id: cfs_session.name.clone().unwrap_or_default(),
+        name: None,
+        date: cfs_session.get_start_time(),
+        reason: format!("Failed session older than {}", failed_session_min_age),
+      });
+    }
+  }
+
+  if dry_run {
+    return Ok(gc_report);
+  }
+
+  let gc_item_to_delete_vec = std::mem::take(&mut gc_report.delete);
+  let deletion_report = delete(
+    shasta_token,
+    shasta_base_url,
+    shasta_root_cert,
+    gc_item_to_delete_vec,
+  )
+  .await;
+
+  gc_report.delete = deletion_report.delete;
+  gc_report.failed = deletion_report.failed;
+
+  Ok(gc_report)
+}
+
+/// Deletes the items to garbage collect. Sessions first, then images and
+/// configurations since sessions reference them. Deletion errors do not stop
+/// the process, items which could not be deleted are returned as failed
+pub async fn delete(
+  shasta_token: &str,
+  shasta_base_url: &str,
+  shasta_root_cert: &[u8],
+  mut gc_item_to_delete_vec: Vec<GcItem>,
+) -> GcReport {
+  let mut gc_report = GcReport::default();
+
+  gc_item_to_delete_vec.sort_by_key(|gc_item| match gc_item.kind {
+    GcItemKind::Session => 0,
+    GcItemKind::Image => 1,
+    GcItemKind::Configuration => 2,
+  });
+
+  for mut gc_item in gc_item_to_delete_vec {
+    log::info!("Deleting {} '{}'", gc_item.kind, gc_item.id);
+
+    let deletion_rslt = match gc_item.kind {
+      GcItemKind::Session => {
+        cfs::session::http_client::v3::delete(
+          shasta_token,
+          shasta_base_url,
+          shasta_root_cert,
+          &gc_item.id,
+        )
+        .await
+      }
+      GcItemKind::Image => {
+        ims::image::http_client::delete(
+          shasta_token,
+          shasta_base_url,
+          shasta_root_cert,
+          &gc_item.id,
+        )
+        .await
+      }
+      GcItemKind::Configuration => {
+        cfs::configuration::http_client::v3::delete(
+          shasta_token,
+          shasta_base_url,
+          shasta_root_cert,
+          &gc_item.id,
+        )
+        .await
+      }
+    };
+
+    match deletion_rslt {
+      Ok(_) => gc_report.delete.push(gc_item),
+      Err(e) => {
+        log::warn!("Could not delete {} '{}'", gc_item.kind, gc_item.id);
+        gc_item.reason = e.to_string();
+        gc_report.failed.push(gc_item);
+      }
+    }
+  }
+
+  gc_report
+}
+
+/// Given the items (id and date) related to each group, returns the ids of
+/// the items of the target groups which are not among the `keep` most recent
+/// of any group, target or not. Dates are compared as strings, therefore they
+/// must be in the same format (eg RFC 3339)
+pub fn get_items_to_delete<'a>(
+  group_item_map: &HashMap<&str, Vec<(&'a str, &'a str)>>,
+  target_hsm_group_name_vec: &[&str],
+  keep: usize,
+) -> Vec<&'a str> {
+  let mut item_to_keep_set: HashSet<&str> = HashSet::new();
+  let mut item_to_delete_set: HashSet<&str> = HashSet::new();
+
+  for (hsm_group_name, item_vec) in group_item_map {
+    let is_target = target_hsm_group_name_vec.contains(hsm_group_name);
+
+    let mut item_vec = item_vec.clone();
+
+    // Sort by date DESC
+    item_vec.sort_by(|(_, date_1), (_, date_2)| date_2.cmp(date_1));
+
+    for (index, (id, _)) in item_vec.into_iter().enumerate() {
+      if index < keep {
+        item_to_keep_set.insert(id);
+      } else if is_target {
+        item_to_delete_set.insert(id);
+      }
+    }
+  }
+
+  let mut item_to_delete_vec: Vec<&str> = item_to_delete_set
+    .difference(&item_to_keep_set)
+    .copied()
+    .collect();
+
+  item_to_delete_vec.sort();
+
+  item_to_delete_vec
+}
+
+/// A name is related to a group if it is the group name or starts with it
+/// followed by '-' or '_' (eg 'zinal-cos-config' for group 'zinal'). Names
+/// also starting with a longer group name belong to that group instead (eg
+/// 'zinal-test-cos-config' belongs to 'zinal-test', not 'zinal')
+pub fn is_name_related_to_group(
+  name: &str,
+  hsm_group_name: &str,
+  hsm_group_name_all_vec: &[&str],
+) -> bool {
+  let is_named_after = |group_name: &str| {
+    name
+      .strip_prefix(group_name)
+      .is_some_and(|suffix| suffix.is_empty() || suffix.starts_with(['-', '_']))
+  };
+
+  is_named_after(hsm_group_name)
+    && !hsm_group_name_all_vec.iter().any(|group_name| {
+      group_name.len() > hsm_group_name.len() && is_named_after(group_name)
+    })
+}
+
+fn is_session_targeting_group(
+  cfs_session: &CfsSessionGetResponse,
+  hsm_group_name: &str,
+) -> bool {
+  cfs_session.get_target_hsm().is_some_and(|target_hsm_vec| {
+    target_hsm_vec
+      .iter()
+      .any(|target_hsm| target_hsm == hsm_group_name)
+  })
+}
+
+fn is_session_failed(cfs_session: &CfsSessionGetResponse) -> bool {
+  cfs_session
+    .status
+    .as_ref()
+    .and_then(|status| status.session.as_ref())
+    .and_then(|session| session.succeeded.as_deref())
+    == Some("false")
+}
@@ -0,0 +1,119 @@
+use std::collections::HashMap;
+
+use super::command::{get_items_to_delete, is_name_related_to_group};
+
+/// Only the items older than the most recent ones of each group are deleted
+#[test]
+fn test_get_items_to_delete_keeps_most_recent_per_group() {
+  let group_item_map = HashMap::from([
+    (
+      "zinal",
+      vec![
+        ("zinal-3", "2025-03-01T00:00:00Z"),
+        ("zinal-1", "2025-01-01T00:00:00Z"),
+        ("zinal-2", "2025-02-01T00:00:00Z"),
+      ],
+    ),
+    ("alps", vec![("alps-1", "2024-01-01T00:00:00Z")]),
+  ]);
+
+  assert_eq!(
+    get_items_to_delete(&group_item_map, &["zinal", "alps"], 2),
+    vec!["zinal-1"]
+  );
+  assert_eq!(
+    get_items_to_delete(&group_item_map, &["zinal", "alps"], 0),
+    vec!["alps-1", "zinal-1", "zinal-2", "zinal-3"]
+  );
+}
+
+/// An item related to several groups is kept if it is among the most recent
+/// ones of any of them
+#[test]
+fn test_get_items_to_delete_item_shared_by_groups() {
+  let group_item_map = HashMap::from([
+    (
+      "zinal",
+      vec![
+        ("shared", "2025-01-01T00:00:00Z"),
+        ("zinal-2", "2025-02-01T00:00:00Z"),
+      ],
+    ),
+    ("alps", vec![("shared", "2025-01-01T00:00:00Z")]),
+  ]);
+
+  assert!(
+    get_items_to_delete(&group_item_map, &["zinal", "alps"], 1).is_empty()
+  );
+}
+
+/// Items are only deleted for the target groups, and items still among the
+/// most recent ones of a group which is not a target are kept
+#[test]
+fn test_get_items_to_delete_keeps_items_recent_for_other_groups() {
+  let group_item_map = HashMap::from([
+    (
+      "zinal",
+      vec![
+        ("shared", "2025-01-01T00:00:00Z"),
+        ("zinal-2", "2025-02-01T00:00:00Z"),
+        ("zinal-3", "2025-03-01T00:00:00Z"),
+      ],
+    ),
+    (
+      "alps",
+      vec![
+        ("alps-1", "2024-01-01T00:00:00Z"),
+        ("shared", "2025-01-01T00:00:00Z"),
+      ],
+    ),
+  ]);
+
+  assert_eq!(
+    get_items_to_delete(&group_item_map, &["zinal"], 1),
+    vec!["zinal-2"]
+  );
+}
+
+/// Names must start with the group name followed by a delimiter, names of a
+/// more specific group are not related to the shorter one
+#[test]
+fn test_is_name_related_to_group() {
+  let hsm_group_name_all_vec = ["zinal", "zinal-test", "alps"];
+
+  assert!(is_name_related_to_group(
+    "zinal-cos-config",
+    "zinal",
+    &hsm_group_name_all_vec
+  ));
+  assert!(is_name_related_to_group(
+    "zinal",
+    "zinal",
+    &hsm_group_name_all_vec
+  ));
+  assert!(is_name_related_to_group(
+    "zinal_uan",
+    "zinal",
+    &hsm_group_name_all_vec
+  ));
+  assert!(!is_name_related_to_group(
+    "zinal-test-cos-config",
+    "zinal",
+    &hsm_group_name_all_vec
+  ));
+  assert!(is_name_related_to_group(
+    "zinal-test-cos-config",
+    "zinal-test",
+    &hsm_group_name_all_vec
+  ));
+  assert!(!is_name_related_to_group(
+    "zinalx-cos-config",
+    "zinal",
+    &hsm_group_name_all_vec
+  ));
+  assert!(!is_name_related_to_group(
+    "alps-zinal-cos-config",
+    "zinal",
+    &hsm_group_name_all_vec
+  ));
+}
@@ -0,0 +1,254 @@
+use std::collections::HashSet;
+
+use manta_backend_dispatcher::types::orphan::{
+  Orphan, OrphanKind, OrphanReport,
+};
+
+use crate::{
+  bos::{self, template::http_client::v2::types::BosSessionTemplate},
+  bss,
+  cfs::{
+    self,
+    configuration::http_client::v2::types::cfs_configuration_response::CfsConfigurationResponse,
+  },
+  error::Error,
+  ims::{self, image::http_client::types::Image},
+};
+
+/// S3 bucket where IMS stores the boot images, one directory per image id
+pub const BOOT_IMAGES_BUCKET: &str = "boot-images";
+
+/// Cross references IMS, S3, BOS, CFS and BSS data and returns:
+///  - IMS images not used to boot any node, nor referenced by any BOS session
+///    template or CFS session
+///  - images in S3 without an IMS record
+///  - BOS session templates pointing to images which do not exist in IMS
+///  - CFS configurations not used by any node, CFS session or BOS session
+///    template
+pub async fn exec(
+  shasta_token: &str,
+  shasta_base_url: &str,
+  shasta_root_cert: &[u8],
+) -> Result<OrphanReport, Error> {
+  log::info!("Fetching data from the backend...");
+  let (
+    cfs_component_vec,
+    cfs_configuration_vec,
+    cfs_session_vec,
+    bos_sessiontemplate_vec,
+    bss_bootparameters_vec,
+    image_vec,
+    sts_value,
+  ) = tokio::try_join!(
+    cfs::component::http_client::v2::get_all(
+      shasta_token,
+      shasta_base_url,
+      shasta_root_cert
+    ),
+    cfs::configuration::http_client::v2::get_all(
+      shasta_token,
+      shasta_base_url,
+      shasta_root_cert
+    ),
+    cfs::session::http_client::v2::get_all(
+      shasta_token,
+      shasta_base_url,
+      shasta_root_cert
+    ),
+    bos::template::http_client::v2::get_all(
+      shasta_token,
+      shasta_base_url,
+      shasta_root_cert
+    ),
+    bss::http_client::get_all(shasta_token, shasta_base_url, shasta_root_cert),
+    ims::image::http_client::get_all(
+      shasta_token,
+      shasta_base_url,
+      shasta_root_cert
+    ),
+    ims::s3_client::s3_auth(shasta_token, shasta_base_url, shasta_root_cert),
+  )?;
+
+  let s3_image_id_vec =
+    ims::s3_client::s3_list_prefixes(&sts_value, BOOT_IMAGES_BUCKET).await?;
+
+  // Images referenced by BSS boot parameters, BOS session templates and CFS
+  // sessions
+  let boot_image_id_vec: Vec<String> = bss_bootparameters_vec
+    .iter()
+    .map(|boot_parameters| boot_parameters.get_boot_image())
+    .collect();
+
+  let mut referenced_image_id_set: HashSet<&str> =
+    boot_image_id_vec.iter().map(String::as_str).collect();
+
+  referenced_image_id_set.extend(
+    bos_sessiontemplate_vec
+      .iter()
+      .flat_map(|bos_sessiontemplate| bos_sessiontemplate.images_id()),
+  );
+
+  referenced_image_id_set.extend(
+    cfs_session_vec
+      .iter()
+      .flat_map(|cfs_session| cfs_session.results_id()),
+  );
+
+  // CFS configurations referenced by CFS components, CFS sessions and BOS
+  // session templates
+  let mut referenced_configuration_set: HashSet<&str> = cfs_component_vec
+    .iter()
+    .filter_map(|cfs_component| cfs_component.desired_config.as_deref())
+    .collect();
+
+  referenced_configuration_set.extend(
+    cfs_session_vec
+      .iter()
+      .filter_map(|cfs_session| cfs_session.configuration_namen()),
+  );
+
+  referenced_configuration_set.extend(
+    bos_sessiontemplate_vec
+      .iter()
+      .filter_map(|bos_sessiontemplate| {
+        bos_sessiontemplate.get_configuration()
+      }),
+  );
+
+  Ok(OrphanReport {
+    images: get_orphan_images(&image_vec, &referenced_image_id_set),
+    s3_boot_images: get_orphan_s3_boot_images(&s3_image_id_vec, &image_vec),
+    session_templates: get_orphan_session_templates(
+      &bos_sessiontemplate_vec,
+      &image_vec,
+    ),
+    configurations: get_orphan_configurations(
+      &cfs_configuration_vec,
+      &referenced_configuration_set,
+    ),
+  })
+}
+
+/// IMS images whose id is not referenced
+pub fn get_orphan_images(
+  image_vec: &[Image],
+  referenced_image_id_set: &HashSet<&str>,
+) -> Vec<Orphan> {
+  let mut orphan_vec: Vec<Orphan> = image_vec
+    .iter()
+    .filter_map(|image| image.id.as_deref().map(|id| (id, image)))
+    .filter(|(image_id, _)| !referenced_image_id_set.contains(image_id))
+    .map(|(image_id, image)| Orphan {
+      kind: OrphanKind::Image,
+      id: image_id.to_string(),
+      name: Some(image.name.clone()),
+      date: image.created.clone(),
+      reason:
+        "Not used by any BSS boot parameters, BOS session template or CFS session"
+          .to_string(),
+    })
+    .collect();
+
+  orphan_vec.sort_by(|orphan_1, orphan_2| orphan_1.date.cmp(&orphan_2.date));
+
+  orphan_vec
+}
+
+/// Image ids in the S3 'boot-images' bucket without an IMS record
+pub fn get_orphan_s3_boot_images(
+  s3_image_id_vec: &[String],
+  image_vec: &[Image],
+) -> Vec<Orphan> {
+  let image_id_set: HashSet<&str> = image_vec
+    .iter()
+    .filter_map(|image| image.id.as_deref())
+    .collect();
+
+  let mut orphan_vec: Vec<Orphan> = s3_image_id_vec
+    .iter()
+    .filter(|s3_image_id| !image_id_set.contains(s3_image_id.as_str()))
+    .map(|s3_image_id| Orphan {
+      kind: OrphanKind::S3BootImage,
+      id: s3_image_id.clone(),
+      name: None,
+      date: None,
+      reason: format!(
+        "'s3://{}/{}' has no IMS record",
+        BOOT_IMAGES_BUCKET, s3_image_id
+      ),
+    })
+    .collect();
+
+  orphan_vec.sort_by(|orphan_1, orphan_2| orphan_1.id.cmp(&orphan_2.id));
+
+  orphan_vec
+}
+
+/// BOS session templates with boot sets pointing to images which do not exist
+/// in IMS
+pub fn get_orphan_session_templates(
+  bos_sessiontemplate_vec: &[BosSessionTemplate],
+  image_vec: &[Image],
+) -> Vec<Orphan> {
+  let image_id_set: HashSet<&str> = image_vec
+    .iter()
+    .filter_map(|image| image.id.as_deref())
+    .collect();
+
+  let mut orphan_vec: Vec<Orphan> = bos_sessiontemplate_vec
+    .iter()
+    .filter_map(|bos_sessiontemplate| {
+      let mut missing_image_id_vec: Vec<&str> = bos_sessiontemplate
+        .images_id()
+        .filter(|image_id| !image_id_set.contains(image_id))
+        .collect();
+
+      if missing_image_id_vec.is_empty() {
+        return None;
+      }
+
+      missing_image_id_vec.sort();
+      missing_image_id_vec.dedup();
+
+      Some(Orphan {
+        kind: OrphanKind::SessionTemplate,
+        id: bos_sessiontemplate.name.clone().unwrap_or_default(),
+        name: None,
+        date: None,
+        reason: format!(
+          "Images not found in IMS: {}",
+          missing_image_id_vec.join(", ")
+        ),
+      })
+    })
+    .collect();
+
+  orphan_vec.sort_by(|orphan_1, orphan_2| orphan_1.id.cmp(&orphan_2.id));
+
+  orphan_vec
+}
+
+/// CFS configurations whose name is not referenced
+pub fn get_orphan_configurations(
+  cfs_configuration_vec: &[CfsConfigurationResponse],
+  referenced_configuration_set: &HashSet<&str>,
+) -> Vec<Orphan> {
+  let mut orphan_vec: Vec<Orphan> = cfs_configuration_vec
+    .iter()
+    .filter(|cfs_configuration| {
+      !referenced_configuration_set.contains(cfs_configuration.name.as_str())
+    })
+    .map(|cfs_configuration| Orphan {
+      kind: OrphanKind::Configuration,
+      id: cfs_configuration.name.clone(),
+      name: None,
+      date: Some(cfs_configuration.last_updated.clone()),
+      reason: "Not used by any node, CFS session or BOS session template"
+        .to_string(),
+    })
+    .collect();
+
+  orphan_vec.sort_by(|orphan_1, orphan_2| orphan_1.date.cmp(&orphan_2.date));
+
+  orphan_vec
+}
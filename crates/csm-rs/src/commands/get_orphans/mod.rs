@@ -0,0 +1,4 @@
+pub mod command;
+// ---- TEST
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,89 @@
+use std::collections::HashSet;
+
+use crate::{
+  bos::template::http_client::v2::types::BosSessionTemplate,
+  ims::image::http_client::types::Image,
+};
+
+use super::command::{
+  get_orphan_images, get_orphan_s3_boot_images, get_orphan_session_templates,
+};
+
+fn image(id: &str, name: &str, created: &str) -> Image {
+  Image {
+    id: Some(id.to_string()),
+    created: Some(created.to_string()),
+    name: name.to_string(),
+    link: None,
+    arch: None,
+  }
+}
+
+fn image_vec() -> Vec<Image> {
+  vec![
+    image("image-2", "zinal-cos-2", "2025-02-01T00:00:00Z"),
+    image("image-1", "zinal-cos-1", "2025-01-01T00:00:00Z"),
+    image("image-3", "zinal-cos-3", "2025-03-01T00:00:00Z"),
+  ]
+}
+
+/// Images not referenced are returned sorted by creation date
+#[test]
+fn test_get_orphan_images() {
+  let referenced_image_id_set = HashSet::from(["image-2"]);
+
+  let orphan_id_vec: Vec<String> =
+    get_orphan_images(&image_vec(), &referenced_image_id_set)
+      .into_iter()
+      .map(|orphan| orphan.id)
+      .collect();
+
+  assert_eq!(orphan_id_vec, vec!["image-1", "image-3"]);
+}
+
+#[test]
+fn test_get_orphan_s3_boot_images() {
+  let s3_image_id_vec = vec![
+    "image-4".to_string(),
+    "image-1".to_string(),
+    "image-0".to_string(),
+  ];
+
+  let orphan_id_vec: Vec<String> =
+    get_orphan_s3_boot_images(&s3_image_id_vec, &image_vec())
+      .into_iter()
+      .map(|orphan| orphan.id)
+      .collect();
+
+  assert_eq!(orphan_id_vec, vec!["image-0", "image-4"]);
+}
+
+/// Only session templates with a boot set pointing to a missing image are
+/// orphans
+#[test]
+fn test_get_orphan_session_templates() {
+  let bos_sessiontemplate_vec: Vec<BosSessionTemplate> =
+    serde_json::from_value(serde_json::json!([
+      {
+        "name": "zinal-cos-template",
+        "boot_sets": {
+          "compute": { "path": "s3://boot-images/image-1/manifest.json" }
+        }
+      },
+      {
+        "name": "zinal-uan-template",
+        "boot_sets": {
+          "compute": { "path": "s3://boot-images/image-1/manifest.json" },
+          "uan": { "path": "s3://boot-images/image-9/manifest.json" }
+        }
+      }
+    ]))
+    .unwrap();
+
+  let orphan_vec =
+    get_orphan_session_templates(&bos_sessiontemplate_vec, &image_vec());
+
+  assert_eq!(orphan_vec.len(), 1);
+  assert_eq!(orphan_vec[0].id, "zinal-uan-template");
+  assert_eq!(orphan_vec[0].reason, "Images not found in IMS: image-9");
+}
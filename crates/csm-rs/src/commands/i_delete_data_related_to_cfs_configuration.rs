@@ -3,8 +3,7 @@ use std::collections::HashMap;
 use std::time::Instant;
 
 use chrono::NaiveDateTime;
-use comfy_table::Table;
-use dialoguer::{theme::ColorfulTheme, Confirm};
+use manta_backend_dispatcher::types::cfs::cfs_configuration_derivatives::CfsConfigurationDerivatives;
 
 use crate::{
   bos::{self},
@@ -14,7 +13,10 @@ use crate::{
   ims,
 };
 
-pub async fn exec(
+/// CFS configurations related to the groups, name or name pattern together
+/// with the CFS sessions, BOS sessiontemplates and images built from them.
+/// Fails if any of them is used to configure or boot nodes
+pub async fn get_data_to_delete(
   shasta_token: &str,
   shasta_base_url: &str,
   shasta_root_cert: &[u8],
@@ -23,8 +25,7 @@ pub async fn exec(
   configuration_name_pattern: Option<&String>,
   since_opt: Option<NaiveDateTime>,
   until_opt: Option<NaiveDateTime>,
-  assume_yes: bool,
-) -> Result<(), Error> {
+) -> Result<CfsConfigurationDerivatives, Error> {
   // COLLECT SITE WIDE DATA FOR VALIDATION
   //
   let xname_from_groups_vec =
@@ -351,114 +352,39 @@ pub async fn exec(
     }
   }
 
-  // PRINT SUMMARY/DATA TO DELETE
-  //
-  println!("CFS sessions to delete:");
-
-  let mut cfs_session_table = Table::new();
-
-  cfs_session_table.set_header(vec!["Name", "Configuration", "Image ID"]);
-
-  for cfs_session in &cfs_session_vec {
-    cfs_session_table.add_row(vec![
-      cfs_session.name.as_ref().unwrap_or(&"".to_string()),
-      &cfs_session
-        .configuration_namen()
-        .unwrap_or_default()
-        .to_string(),
-      &cfs_session
-        .first_result_id()
-        .unwrap_or_default()
-        .to_string(),
-    ]);
-  }
-
-  println!("{cfs_session_table}");
-
-  println!("BOS sessiontemplates to delete:");
-
-  let mut bos_sessiontemplate_table = Table::new();
-
-  bos_sessiontemplate_table.set_header(vec![
-    "Name",
-    "Configuration",
-    "Image ID",
-  ]);
-
-  for bos_sessiontemplate_tuple in
-    &bos_sessiontemplate_cfs_configuration_image_id_tuple_filtered_vec
-  {
-    bos_sessiontemplate_table.add_row(vec![
-      bos_sessiontemplate_tuple.0,
-      bos_sessiontemplate_tuple.1,
-      bos_sessiontemplate_tuple.2,
-    ]);
-  }
-
-  println!("{bos_sessiontemplate_table}");
-
-  println!("CFS configurations to delete:");
-
-  let mut cfs_configuration_table = Table::new();
-
-  cfs_configuration_table.set_header(vec!["Name", "Last Update"]);
-
-  for cfs_configuration_value in cfs_configuration_vec {
-    cfs_configuration_table.add_row(vec![
-      cfs_configuration_value.name,
-      cfs_configuration_value.last_updated,
-    ]);
-  }
-
-  println!("{cfs_configuration_table}");
-
-  println!("Images to delete:");
-
-  let mut image_id_table = Table::new();
-
-  image_id_table.set_header(vec!["Image ID"]);
-
-  for image_id in &image_id_vec {
-    image_id_table.add_row(vec![image_id]);
-  }
-
-  println!("{image_id_table}");
-
-  // ASK USER FOR CONFIRMATION
-  //
-  if !assume_yes {
-    if Confirm::with_theme(&ColorfulTheme::default())
-      .with_prompt("Please revew the data above and confirm to delete:")
-      .interact()
-      .unwrap()
-    {
-      println!("Continue");
-    } else {
-      println!("Cancelled by user. Aborting.");
-      std::process::exit(0);
-    }
-  }
-
-  // DELETE DATA
-  //
-  delete(
-    shasta_token,
-    shasta_base_url,
-    shasta_root_cert,
-    cfs_configuration_name_vec,
-    image_id_vec,
-    cfs_session_cfs_configuration_image_id_tuple_filtered_vec
+  Ok(CfsConfigurationDerivatives {
+    cfs_configuration_vec: cfs_configuration_vec
       .into_iter()
-      .map(|(session, _, _)| session)
+      .map(|cfs_configuration| {
+        (cfs_configuration.name, cfs_configuration.last_updated)
+      })
       .collect(),
-    bos_sessiontemplate_cfs_configuration_image_id_tuple_filtered_vec
+    cfs_session_vec: cfs_session_cfs_configuration_image_id_tuple_filtered_vec
       .into_iter()
-      .map(|(sessiontemplate, _, _)| sessiontemplate)
+      .map(|(name, configuration, image_id)| {
+        (
+          name.to_string(),
+          configuration.to_string(),
+          image_id.to_string(),
+        )
+      })
       .collect(),
-  )
-  .await;
-
-  Ok(())
+    bos_sessiontemplate_vec:
+      bos_sessiontemplate_cfs_configuration_image_id_tuple_filtered_vec
+        .into_iter()
+        .map(|(name, configuration, image_id)| {
+          (
+            name.to_string(),
+            configuration.to_string(),
+            image_id.to_string(),
+          )
+        })
+        .collect(),
+    image_id_vec: image_id_vec
+      .into_iter()
+      .map(|image_id| image_id.to_string())
+      .collect(),
+  })
 }
 
 /// Deletes CFS configuration, CFS session, BOS sessiontemplate, BOS session and images related to
@@ -0,0 +1,457 @@
+use std::{
+  collections::{HashMap, HashSet},
+  path::{Path, PathBuf},
+  sync::Arc,
+};
+
+use chrono::Local;
+use humansize::DECIMAL;
+use serde_json::Value;
+use tokio::sync::Semaphore;
+
+use crate::{
+  bos, cfs,
+  error::Error,
+  hsm,
+  ims::{self, image::http_client::types::Image},
+};
+
+use super::utils::{
+  get_artifact_key, get_session_templates_to_backup, is_file_valid,
+  BackupArtifact, BackupImage, BackupManifest, BackupSessionTemplate,
+  ImageManifest, BOOT_IMAGES_BUCKET, TRANSFER_ATTEMPTS,
+};
+
+/// Backs up BOS session templates together with their CFS configuration,
+/// HSM groups, IMS records and image artifacts. Image artifacts are
+/// downloaded concurrently and verified against the checksums in the IMS
+/// manifest. Artifacts already downloaded and valid are not downloaded again,
+/// therefore an interrupted backup can be resumed by running it again with
+/// the same destination
+pub async fn exec(
+  shasta_token: &str,
+  shasta_base_url: &str,
+  shasta_root_cert: &[u8],
+  bos_sessiontemplate_name_vec: &[String],
+  hsm_group_name_vec: &[String],
+  destination: &str,
+  concurrency: usize,
+) -> Result<(), Error> {
+  if bos_sessiontemplate_name_vec.is_empty() && hsm_group_name_vec.is_empty() {
+    return Err(Error::Message(
+      "Please provide BOS session templates or HSM groups to backup"
+        .to_string(),
+    ));
+  }
+
+  let dest_path = Path::new(destination);
+
+  std::fs::create_dir_all(dest_path).map_err(|e| {
+    Error::Message(format!(
+      "Unable to create directory '{}'. Reason:\n{}",
+      destination, e
+    ))
+  })?;
+
+  let bos_sessiontemplate_vec = bos::template::http_client::v2::get(
+    shasta_token,
+    shasta_base_url,
+    shasta_root_cert,
+    None,
+  )
+  .await?;
+
+  let bos_sessiontemplate_vec = get_session_templates_to_backup(
+    &bos_sessiontemplate_vec,
+    bos_sessiontemplate_name_vec,
+    hsm_group_name_vec,
+  )?;
+
+  if bos_sessiontemplate_vec.is_empty() {
+    return Err(Error::Message(
+      "No BOS session template found to backup".to_string(),
+    ));
+  }
+
+  let mut backup_session_template_vec = Vec::new();
+  let mut cfs_configuration_name_backed_up_set: HashSet<String> =
+    HashSet::new();
+  let mut image_id_vec: Vec<String> = Vec::new();
+
+  for bos_sessiontemplate in &bos_sessiontemplate_vec {
+    let bos_sessiontemplate_name =
+      bos_sessiontemplate.name.clone().unwrap_or_default();
+
+    // BOS
+    let bos_file = format!("{}.json", bos_sessiontemplate_name);
+    write_json(dest_path, &bos_file, bos_sessiontemplate)?;
+    println!(
+      "BOS session template '{}' saved to '{}'",
+      bos_sessiontemplate_name, bos_file
+    );
+
+    // CFS
+    let cfs_file = match bos_sessiontemplate.get_configuration() {
+      Some(cfs_configuration_name) => {
+        let cfs_file = format!("{}.json", cfs_configuration_name);
+
+        if cfs_configuration_name_backed_up_set
+          .insert(cfs_configuration_name.to_string())
+        {
+          let cfs_configuration_vec = cfs::configuration::http_client::v3::get(
+            shasta_token,
+            shasta_base_url,
+            shasta_root_cert,
+            Some(cfs_configuration_name),
+          )
+          .await?;
+
+          let cfs_configuration =
+            cfs_configuration_vec.first().ok_or_else(|| {
+              Error::Message(format!(
+                "CFS configuration '{}' used by BOS session template '{}' not found",
+                cfs_configuration_name, bos_sessiontemplate_name
+              ))
+            })?;
+
+          write_json(dest_path, &cfs_file, cfs_configuration)?;
+          println!(
+            "CFS configuration '{}' saved to '{}'",
+            cfs_configuration_name, cfs_file
+          );
+        }
+
+        Some(cfs_file)
+      }
+      None => None,
+    };
+
+    // HSM
+    let hsm_group_name_target_vec = bos_sessiontemplate.get_target_hsm();
+
+    let hsm_file = if hsm_group_name_target_vec.is_empty() {
+      None
+    } else {
+      let hsm_file = format!("{}-hsm.json", bos_sessiontemplate_name);
+
+      let hsm_group_vec = hsm::group::http_client::get(
+        shasta_token,
+        shasta_base_url,
+        shasta_root_cert,
+        Some(
+          &hsm_group_name_target_vec
+            .iter()
+            .map(String::as_str)
+            .collect::<Vec<&str>>(),
+        ),
+        None,
+      )
+      .await?;
+
+      write_json(dest_path, &hsm_file, &hsm_group_vec)?;
+      println!(
+        "HSM group(s) '{}' saved to '{}'",
+        hsm_group_name_target_vec.join(", "),
+        hsm_file
+      );
+
+      Some(hsm_file)
+    };
+
+    let bos_sessiontemplate_image_id_vec: Vec<String> = bos_sessiontemplate
+      .images_id()
+      .map(str::to_string)
+      .collect();
+
+    for image_id in &bos_sessiontemplate_image_id_vec {
+      if !image_id_vec.contains(image_id) {
+        image_id_vec.push(image_id.clone());
+      }
+    }
+
+    backup_session_template_vec.push(BackupSessionTemplate {
+      name: bos_sessiontemplate_name,
+      bos_file,
+      cfs_file,
+      hsm_file,
+      image_ids: bos_sessiontemplate_image_id_vec,
+    });
+  }
+
+  // IMS
+  let sts_value =
+    ims::s3_client::s3_auth(shasta_token, shasta_base_url, shasta_root_cert)
+      .await?;
+
+  let mut backup_image_vec = Vec::new();
+  let mut image_manifest_map: HashMap<String, ImageManifest> = HashMap::new();
+
+  for image_id in &image_id_vec {
+    let image_vec: Vec<Image> = ims::image::http_client::get(
+      shasta_token,
+      shasta_base_url,
+      shasta_root_cert,
+      Some(image_id),
+    )
+    .await
+    .map_err(|e| {
+      Error::Message(format!(
+        "Image '{}' not found in IMS. Reason:\n{}",
+        image_id, e
+      ))
+    })?;
+
+    let image_name = image_vec
+      .first()
+      .map(|image| image.name.clone())
+      .unwrap_or_default();
+
+    let ims_file = format!("{}-ims.json", image_id);
+    write_json(dest_path, &ims_file, &image_vec)?;
+    println!("IMS record of image '{}' saved to '{}'", image_id, ims_file);
+
+    // The IMS manifest is small and provides the artifacts checksums, always
+    // download it again
+    let manifest_file = format!("{}/manifest.json", image_id);
+
+    download_with_retries(
+      &sts_value,
+      &manifest_file,
+      &dest_path.join(image_id),
+    )
+    .await?;
+
+    let image_manifest: ImageManifest = serde_json::from_str(
+      &std::fs::read_to_string(dest_path.join(&manifest_file))?,
+    )
+    .map_err(|e| {
+      Error::Message(format!(
+        "Could not parse IMS manifest of image '{}'. Reason:\n{}",
+        image_id, e
+      ))
+    })?;
+
+    backup_image_vec.push(BackupImage {
+      id: image_id.clone(),
+      name: image_name,
+      ims_file,
+      manifest_file,
+      artifacts: Vec::new(),
+    });
+
+    image_manifest_map.insert(image_id.clone(), image_manifest);
+  }
+
+  // Image artifacts
+  let mut tasks = tokio::task::JoinSet::new();
+
+  let sem = Arc::new(Semaphore::new(concurrency.max(1)));
+
+  for (image_id, image_manifest) in &image_manifest_map {
+    for artifact in &image_manifest.artifacts {
+      let sts_value = sts_value.clone();
+      let image_id = image_id.clone();
+      let artifact_key = get_artifact_key(artifact);
+      let artifact_type = artifact.r#type.clone();
+      let md5 = artifact.md5.clone();
+      let image_dest_path = dest_path.join(&image_id);
+
+      let permit = sem.clone().acquire_owned().await.unwrap();
+
+      tasks.spawn(async move {
+        let _permit = permit; // Wait semaphore to allow new tasks https://github.com/tokio-rs/tokio/discussions/2648#discussioncomment-34885
+
+        backup_artifact(&sts_value, &artifact_key, &md5, &image_dest_path)
+          .await
+          .map(|(size, md5)| {
+            (
+              image_id,
+              BackupArtifact {
+                file: artifact_key,
+                r#type: artifact_type,
+                size,
+                md5,
+              },
+            )
+          })
+      });
+    }
+  }
+
+  let mut failed_vec = Vec::new();
+
+  while let Some(message) = tasks.join_next().await {
+    match message.map_err(|e| Error::Message(e.to_string()))? {
+      Ok((image_id, backup_artifact)) => {
+        if let Some(backup_image) = backup_image_vec
+          .iter_mut()
+          .find(|backup_image| backup_image.id == image_id)
+        {
+          backup_image.artifacts.push(backup_artifact);
+        }
+      }
+      Err(e) => {
+        eprintln!("ERROR - {}", e);
+        failed_vec.push(e.to_string());
+      }
+    }
+  }
+
+  if !failed_vec.is_empty() {
+    return Err(Error::Message(format!(
+      "{} image artifact(s) could not be backed up. Run the same command again to resume the backup",
+      failed_vec.len()
+    )));
+  }
+
+  for backup_image in backup_image_vec.iter_mut() {
+    backup_image
+      .artifacts
+      .sort_by(|artifact_1, artifact_2| artifact_1.file.cmp(&artifact_2.file));
+  }
+
+  let backup_manifest = BackupManifest {
+    created: Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+    session_templates: backup_session_template_vec,
+    images: backup_image_vec,
+  };
+
+  backup_manifest.write(dest_path)?;
+
+  println!("\nDone, the following BOS session templates were backed up:");
+  for backup_session_template in &backup_manifest.session_templates {
+    println!(
+      "\t{} (images: {})",
+      backup_session_template.name,
+      backup_session_template.image_ids.join(", ")
+    );
+  }
+
+  Ok(())
+}
+
+/// Downloads an image artifact unless a valid copy already exists locally.
+/// Returns the size and md5sum of the artifact
+async fn backup_artifact(
+  sts_value: &Value,
+  artifact_key: &str,
+  md5: &str,
+  image_dest_path: &Path,
+) -> Result<(u64, String), Error> {
+  let file_name = Path::new(artifact_key)
+    .file_name()
+    .map(|file_name| file_name.to_string_lossy().to_string())
+    .unwrap_or_default();
+
+  let file_path = image_dest_path.join(&file_name);
+
+  let size = ims::s3_client::s3_get_object_size(
+    sts_value,
+    artifact_key,
+    BOOT_IMAGES_BUCKET,
+  )
+  .await? as u64;
+
+  if verify_file(file_path.clone(), size, md5.to_string()).await? {
+    println!("Artifact '{}' already downloaded, skipping", artifact_key);
+    return Ok((size, get_md5(file_path, md5).await?));
+  }
+
+  for attempt in 1..=TRANSFER_ATTEMPTS {
+    println!(
+      "Downloading artifact '{}' ({}) [attempt {}/{}]",
+      artifact_key,
+      humansize::format_size(size, DECIMAL),
+      attempt,
+      TRANSFER_ATTEMPTS
+    );
+
+    match ims::s3_client::s3_download_object(
+      sts_value,
+      artifact_key,
+      BOOT_IMAGES_BUCKET,
+      &image_dest_path.to_string_lossy(),
+    )
+    .await
+    {
+      Ok(_) => {
+        if verify_file(file_path.clone(), size, md5.to_string()).await? {
+          println!("Artifact '{}' downloaded and verified", artifact_key);
+          return Ok((size, get_md5(file_path, md5).await?));
+        }
+
+        log::warn!("Artifact '{}' checksum mismatch", artifact_key);
+      }
+      Err(e) => {
+        log::warn!("Download of artifact '{}' failed: {}", artifact_key, e)
+      }
+    }
+  }
+
+  Err(Error::Message(format!(
+    "Could not download artifact '{}' after {} attempts",
+    artifact_key, TRANSFER_ATTEMPTS
+  )))
+}
+
+async fn download_with_retries(
+  sts_value: &Value,
+  object_key: &str,
+  dest_path: &Path,
+) -> Result<(), Error> {
+  let mut last_error = None;
+
+  for _ in 0..TRANSFER_ATTEMPTS {
+    match ims::s3_client::s3_download_object(
+      sts_value,
+      object_key,
+      BOOT_IMAGES_BUCKET,
+      &dest_path.to_string_lossy(),
+    )
+    .await
+    {
+      Ok(_) => return Ok(()),
+      Err(e) => {
+        log::warn!("Download of '{}' failed: {}", object_key, e);
+        last_error = Some(e);
+      }
+    }
+  }
+
+  Err(last_error.unwrap())
+}
+
+/// Checksums are calculated in a blocking thread to not stall the transfers
+async fn verify_file(
+  file_path: PathBuf,
+  size: u64,
+  md5: String,
+) -> Result<bool, Error> {
+  tokio::task::spawn_blocking(move || is_file_valid(&file_path, size, &md5))
+    .await
+    .map_err(|e| Error::Message(e.to_string()))?
+}
+
+/// Returns the md5 provided by the IMS manifest or calculates it if missing
+async fn get_md5(file_path: PathBuf, md5: &str) -> Result<String, Error> {
+  if !md5.is_empty() {
+    return Ok(md5.to_string());
+  }
+
+  tokio::task::spawn_blocking(move || super::utils::file_md5sum(&file_path))
+    .await
+    .map_err(|e| Error::Message(e.to_string()))?
+}
+
+fn write_json<T: serde::Serialize>(
+  dest_path: &Path,
+  file_name: &str,
+  value: &T,
+) -> Result<(), Error> {
+  std::fs::write(
+    dest_path.join(file_name),
+    serde_json::to_string_pretty(value)?,
+  )?;
+
+  Ok(())
+}
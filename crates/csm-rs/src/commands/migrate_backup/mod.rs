@@ -0,0 +1,5 @@
+pub mod command;
+// ---- TEST
+#[cfg(test)]
+mod tests;
+pub mod utils;
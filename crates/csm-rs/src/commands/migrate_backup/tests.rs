@@ -0,0 +1,86 @@
+use crate::bos::template::http_client::v2::types::BosSessionTemplate;
+
+use super::utils::{
+  file_md5sum, get_session_templates_to_backup, is_file_valid,
+};
+
+fn bos_sessiontemplate_vec() -> Vec<BosSessionTemplate> {
+  serde_json::from_value(serde_json::json!([
+    {
+      "name": "zinal-cos-template",
+      "boot_sets": {
+        "compute": {
+          "path": "s3://boot-images/image-1/manifest.json",
+          "node_groups": ["zinal"]
+        }
+      }
+    },
+    {
+      "name": "zinal-uan-template",
+      "boot_sets": {
+        "uan": {
+          "path": "s3://boot-images/image-2/manifest.json",
+          "node_groups": ["zinal_uan"]
+        }
+      }
+    },
+    {
+      "name": "gele-cos-template",
+      "boot_sets": {
+        "compute": {
+          "path": "s3://boot-images/image-3/manifest.json",
+          "node_list": ["x1001c7s1b0n0"]
+        }
+      }
+    }
+  ]))
+  .unwrap()
+}
+
+/// BOS session templates requested by name and the ones targeting the HSM
+/// groups requested are backed up
+#[test]
+fn test_get_session_templates_to_backup() {
+  let name_vec: Vec<String> = get_session_templates_to_backup(
+    &bos_sessiontemplate_vec(),
+    &["gele-cos-template".to_string()],
+    &["zinal".to_string()],
+  )
+  .unwrap()
+  .into_iter()
+  .filter_map(|bos_sessiontemplate| bos_sessiontemplate.name)
+  .collect();
+
+  assert_eq!(name_vec, vec!["zinal-cos-template", "gele-cos-template"]);
+}
+
+#[test]
+fn test_get_session_templates_to_backup_missing_template() {
+  assert!(get_session_templates_to_backup(
+    &bos_sessiontemplate_vec(),
+    &["missing-template".to_string()],
+    &[],
+  )
+  .is_err());
+}
+
+/// Partially downloaded or corrupted files are not valid
+#[test]
+fn test_is_file_valid() {
+  let file_path = std::env::temp_dir()
+    .join(format!("csm-rs-migrate-backup-{}", std::process::id()));
+
+  std::fs::write(&file_path, b"rootfs").unwrap();
+
+  let md5 = file_md5sum(&file_path).unwrap();
+
+  assert_eq!(md5, format!("{:x}", md5::compute(b"rootfs")));
+  assert!(is_file_valid(&file_path, 6, &md5).unwrap());
+  assert!(is_file_valid(&file_path, 6, "").unwrap());
+  assert!(!is_file_valid(&file_path, 3, &md5).unwrap());
+  assert!(!is_file_valid(&file_path, 6, "0123456789abcdef").unwrap());
+
+  std::fs::remove_file(&file_path).unwrap();
+
+  assert!(!is_file_valid(&file_path, 6, &md5).unwrap());
+}
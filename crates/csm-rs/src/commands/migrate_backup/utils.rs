@@ -0,0 +1,218 @@
+use std::{
+  collections::HashSet,
+  fs::File,
+  io::{BufRead, BufReader},
+  path::Path,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+  bos::template::http_client::v2::types::BosSessionTemplate, error::Error,
+  ims::image::http_client::types::Link,
+};
+
+/// File in the backup directory describing its content. 'migrate restore'
+/// only restores what is listed in here
+pub const BACKUP_MANIFEST_FILE: &str = "backup-manifest.json";
+
+/// Bucket where IMS stores the image artifacts
+pub const BOOT_IMAGES_BUCKET: &str = "boot-images";
+
+/// Number of times a S3 transfer is tried before giving up
+pub const TRANSFER_ATTEMPTS: usize = 3;
+
+// As per https://cray-hpe.github.io/docs-csm/en-13/operations/image_management/import_external_image_to_ims/
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Artifact {
+  pub link: Link,
+  #[serde(default)]
+  pub md5: String,
+  #[serde(rename = "type")]
+  pub r#type: String,
+}
+
+/// Content of the 'manifest.json' file IMS stores next to the image artifacts
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ImageManifest {
+  pub created: String,
+  #[serde(default = "default_version")]
+  pub version: String,
+  pub artifacts: Vec<Artifact>,
+}
+
+fn default_version() -> String {
+  "1.0".to_string()
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BackupManifest {
+  pub created: String,
+  pub session_templates: Vec<BackupSessionTemplate>,
+  pub images: Vec<BackupImage>,
+}
+
+/// Files are relative to the backup directory
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BackupSessionTemplate {
+  pub name: String,
+  pub bos_file: String,
+  pub cfs_file: Option<String>,
+  /// HSM groups the BOS session template targets
+  pub hsm_file: Option<String>,
+  pub image_ids: Vec<String>,
+}
+
+/// Files are relative to the backup directory
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BackupImage {
+  pub id: String,
+  pub name: String,
+  pub ims_file: String,
+  /// IMS manifest as stored in S3
+  pub manifest_file: String,
+  pub artifacts: Vec<BackupArtifact>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BackupArtifact {
+  pub file: String,
+  #[serde(rename = "type")]
+  pub r#type: String,
+  pub size: u64,
+  pub md5: String,
+}
+
+impl BackupManifest {
+  pub fn read(backup_dir: &Path) -> Result<Self, Error> {
+    let backup_manifest_path = backup_dir.join(BACKUP_MANIFEST_FILE);
+
+    let backup_manifest_file =
+      File::open(&backup_manifest_path).map_err(|e| {
+        Error::Message(format!(
+          "Could not open backup manifest '{}'. Reason:\n{}",
+          backup_manifest_path.display(),
+          e
+        ))
+      })?;
+
+    serde_json::from_reader(BufReader::new(backup_manifest_file)).map_err(|e| {
+      Error::Message(format!(
+        "Could not parse backup manifest '{}'. Reason:\n{}",
+        backup_manifest_path.display(),
+        e
+      ))
+    })
+  }
+
+  pub fn write(&self, backup_dir: &Path) -> Result<(), Error> {
+    std::fs::write(
+      backup_dir.join(BACKUP_MANIFEST_FILE),
+      serde_json::to_string_pretty(self)?,
+    )?;
+
+    Ok(())
+  }
+
+  pub fn get_image(&self, image_id: &str) -> Option<&BackupImage> {
+    self.images.iter().find(|image| image.id == image_id)
+  }
+}
+
+/// Returns the BOS session templates to backup, these are the ones requested
+/// by name plus the ones targeting any of the HSM groups requested.
+/// Fails if a BOS session template requested by name does not exist
+pub fn get_session_templates_to_backup(
+  bos_sessiontemplate_vec: &[BosSessionTemplate],
+  bos_sessiontemplate_name_vec: &[String],
+  hsm_group_name_vec: &[String],
+) -> Result<Vec<BosSessionTemplate>, Error> {
+  let bos_sessiontemplate_name_available_vec: HashSet<&str> =
+    bos_sessiontemplate_vec
+      .iter()
+      .filter_map(|bos_sessiontemplate| bos_sessiontemplate.name.as_deref())
+      .collect();
+
+  let bos_sessiontemplate_name_missing_vec: Vec<&str> =
+    bos_sessiontemplate_name_vec
+      .iter()
+      .map(String::as_str)
+      .filter(|name| !bos_sessiontemplate_name_available_vec.contains(name))
+      .collect();
+
+  if !bos_sessiontemplate_name_missing_vec.is_empty() {
+    return Err(Error::Message(format!(
+      "BOS session template(s) '{}' not found",
+      bos_sessiontemplate_name_missing_vec.join(", ")
+    )));
+  }
+
+  Ok(
+    bos_sessiontemplate_vec
+      .iter()
+      .filter(|bos_sessiontemplate| {
+        bos_sessiontemplate
+          .name
+          .as_ref()
+          .is_some_and(|name| bos_sessiontemplate_name_vec.contains(name))
+          || bos_sessiontemplate.boot_sets.is_some()
+            && bos_sessiontemplate
+              .get_target_hsm()
+              .iter()
+              .any(|hsm_group| hsm_group_name_vec.contains(hsm_group))
+      })
+      .cloned()
+      .collect(),
+  )
+}
+
+/// Returns the key of an artifact within its bucket eg
+/// 's3://boot-images/<image id>/rootfs' -> '<image id>/rootfs'
+pub fn get_artifact_key(artifact: &Artifact) -> String {
+  artifact
+    .link
+    .path
+    .trim_start_matches("s3://")
+    .split_once('/')
+    .map(|(_, key)| key.to_string())
+    .unwrap_or_default()
+}
+
+/// Returns the md5sum of a file as an hexadecimal string
+pub fn file_md5sum(file_path: &Path) -> Result<String, Error> {
+  let file = File::open(file_path)?;
+  let mut buf = BufReader::with_capacity(8 * 1024 * 1024, file);
+  let mut context = md5::Context::new();
+
+  loop {
+    let part = buf.fill_buf()?;
+    if part.is_empty() {
+      break;
+    }
+    context.consume(part);
+    let part_len = part.len();
+    buf.consume(part_len);
+  }
+
+  Ok(format!("{:x}", context.compute()))
+}
+
+/// Checks a local file matches the size and checksum expected. Size is checked
+/// first to avoid reading files which were partially transferred. An empty
+/// md5 means the checksum is not known, then only the size is checked
+pub fn is_file_valid(
+  file_path: &Path,
+  size: u64,
+  md5: &str,
+) -> Result<bool, Error> {
+  match std::fs::metadata(file_path) {
+    Ok(metadata) if metadata.len() == size => {}
+    _ => return Ok(false),
+  }
+
+  if md5.is_empty() {
+    return Ok(true);
+  }
+
+  Ok(file_md5sum(file_path)? == md5)
+}
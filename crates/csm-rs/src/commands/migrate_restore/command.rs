@@ -0,0 +1,865 @@
+use std::{
+  collections::{BTreeSet, HashMap},
+  path::{Path, PathBuf},
+  sync::Arc,
+};
+
+use chrono::Local;
+use dialoguer::Confirm;
+use humansize::DECIMAL;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tokio::sync::Semaphore;
+
+use crate::{
+  bos::{self, template::http_client::v2::types::BosSessionTemplate},
+  cfs::{
+    self,
+    configuration::http_client::v3::types::{
+      cfs_configuration_request::CfsConfigurationRequest,
+      cfs_configuration_response::CfsConfigurationResponse,
+    },
+  },
+  commands::migrate_backup::utils::{
+    is_file_valid, BackupImage, BackupManifest, BackupSessionTemplate,
+    ImageManifest, BOOT_IMAGES_BUCKET, TRANSFER_ATTEMPTS,
+  },
+  error::Error,
+  hsm::{self, group::http_client::delete_group, group::types::Group},
+  ims::{
+    self,
+    image::{
+      http_client::types::{Image, ImsImageRecord2Update, Link},
+      utils::get_by_name,
+    },
+  },
+};
+
+/// File in the backup directory tracking what has already been restored so
+/// an interrupted restore can be resumed
+pub const RESTORE_STATE_FILE: &str = "restore-state.json";
+
+/// Files bigger than this are uploaded using S3 multipart uploads
+const MULTIPART_UPLOAD_THRESHOLD: u64 = 1024 * 1024 * 5;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct RestoreState {
+  /// Site the backup is being restored to. Progress restoring a backup to a
+  /// different site is ignored
+  pub shasta_base_url: String,
+  /// Backed up image id -> id of the image registered in IMS
+  pub image_id_map: HashMap<String, String>,
+  /// Objects already restored eg 'artifact:<image id>/rootfs' or
+  /// 'template:<BOS session template name>'
+  pub restored: BTreeSet<String>,
+}
+
+impl RestoreState {
+  pub fn read(backup_dir: &Path, shasta_base_url: &str) -> Result<Self, Error> {
+    if let Ok(restore_state_content) =
+      std::fs::read_to_string(backup_dir.join(RESTORE_STATE_FILE))
+    {
+      let restore_state: RestoreState =
+        serde_json::from_str(&restore_state_content)?;
+
+      if restore_state.shasta_base_url == shasta_base_url {
+        return Ok(restore_state);
+      }
+    }
+
+    Ok(RestoreState {
+      shasta_base_url: shasta_base_url.to_string(),
+      ..Default::default()
+    })
+  }
+
+  pub fn write(&self, backup_dir: &Path) -> Result<(), Error> {
+    std::fs::write(
+      backup_dir.join(RESTORE_STATE_FILE),
+      serde_json::to_string_pretty(self)?,
+    )?;
+
+    Ok(())
+  }
+
+  pub fn is_restored(&self, key: &str) -> bool {
+    self.restored.contains(key)
+  }
+}
+
+/// Restores a backup created with 'migrate backup'. Image artifacts are
+/// verified against the backup manifest before anything is changed in the
+/// system and then uploaded concurrently. Progress is saved in the backup
+/// directory, running the same command again after an interruption resumes
+/// the restore
+pub async fn exec(
+  shasta_token: &str,
+  shasta_base_url: &str,
+  shasta_root_cert: &[u8],
+  backup_dir: &str,
+  bos_sessiontemplate_name_vec: &[String],
+  concurrency: usize,
+  assume_yes: bool,
+  overwrite_groups: bool,
+) -> Result<(), Error> {
+  let backup_dir_path = Path::new(backup_dir);
+
+  let backup_manifest = BackupManifest::read(backup_dir_path)?;
+
+  let backup_session_template_vec = get_session_templates_to_restore(
+    &backup_manifest,
+    bos_sessiontemplate_name_vec,
+  )?;
+
+  let mut backup_image_vec: Vec<&BackupImage> = Vec::new();
+
+  for backup_session_template in &backup_session_template_vec {
+    for image_id in &backup_session_template.image_ids {
+      let backup_image =
+        backup_manifest.get_image(image_id).ok_or_else(|| {
+          Error::Message(format!(
+            "Image '{}' used by BOS session template '{}' not found in backup manifest",
+            image_id, backup_session_template.name
+          ))
+        })?;
+
+      if !backup_image_vec
+        .iter()
+        .any(|image| image.id == backup_image.id)
+      {
+        backup_image_vec.push(backup_image);
+      }
+    }
+  }
+
+  let mut restore_state = RestoreState::read(backup_dir_path, shasta_base_url)?;
+
+  // Check the backup is complete and not corrupted before changing anything
+  println!("Verifying image artifacts...");
+  verify_backup_images(backup_dir_path, &backup_image_vec, concurrency).await?;
+  println!("Ok");
+
+  // IMS
+  for backup_image in &backup_image_vec {
+    if let Some(image_id) = restore_state.image_id_map.get(&backup_image.id) {
+      println!(
+        "Image '{}' already registered in IMS with id '{}'",
+        backup_image.name, image_id
+      );
+      continue;
+    }
+
+    let image_id = ims_register_image(
+      shasta_token,
+      shasta_base_url,
+      shasta_root_cert,
+      &backup_image.name,
+      assume_yes,
+    )
+    .await?;
+
+    println!(
+      "Image '{}' registered in IMS with id '{}'",
+      backup_image.name, image_id
+    );
+
+    restore_state
+      .image_id_map
+      .insert(backup_image.id.clone(), image_id);
+    restore_state.write(backup_dir_path)?;
+  }
+
+  // Image artifacts
+  let sts_value =
+    ims::s3_client::s3_auth(shasta_token, shasta_base_url, shasta_root_cert)
+      .await?;
+
+  let mut tasks = tokio::task::JoinSet::new();
+
+  let sem = Arc::new(Semaphore::new(concurrency.max(1)));
+
+  for backup_image in &backup_image_vec {
+    let image_id = restore_state.image_id_map[&backup_image.id].clone();
+
+    for backup_artifact in &backup_image.artifacts {
+      let restore_key = format!("artifact:{}", backup_artifact.file);
+
+      if restore_state.is_restored(&restore_key) {
+        println!(
+          "Artifact '{}' already uploaded, skipping",
+          backup_artifact.file
+        );
+        continue;
+      }
+
+      let sts_value = sts_value.clone();
+      let file_path = backup_dir_path.join(&backup_artifact.file);
+      let object_key = format!(
+        "{}/{}",
+        image_id,
+        file_path
+          .file_name()
+          .map(|file_name| file_name.to_string_lossy().to_string())
+          .unwrap_or_default()
+      );
+
+      let permit = sem.clone().acquire_owned().await.unwrap();
+
+      tasks.spawn(async move {
+        let _permit = permit; // Wait semaphore to allow new tasks https://github.com/tokio-rs/tokio/discussions/2648#discussioncomment-34885
+
+        upload_with_retries(&sts_value, &file_path, &object_key)
+          .await
+          .map(|_| restore_key)
+      });
+    }
+  }
+
+  let mut failed_vec = Vec::new();
+
+  while let Some(message) = tasks.join_next().await {
+    match message.map_err(|e| Error::Message(e.to_string()))? {
+      Ok(restore_key) => {
+        restore_state.restored.insert(restore_key);
+        restore_state.write(backup_dir_path)?;
+      }
+      Err(e) => {
+        eprintln!("ERROR - {}", e);
+        failed_vec.push(e.to_string());
+      }
+    }
+  }
+
+  if !failed_vec.is_empty() {
+    return Err(Error::Message(format!(
+      "{} image artifact(s) could not be uploaded. Run the same command again to resume the restore",
+      failed_vec.len()
+    )));
+  }
+
+  // Image manifests
+  for backup_image in &backup_image_vec {
+    let restore_key = format!("image:{}", backup_image.id);
+
+    if restore_state.is_restored(&restore_key) {
+      continue;
+    }
+
+    let image_id = &restore_state.image_id_map[&backup_image.id];
+
+    let mut image_manifest: ImageManifest =
+      serde_json::from_str(&std::fs::read_to_string(
+        backup_dir_path.join(&backup_image.manifest_file),
+      )?)?;
+
+    update_image_manifest(&mut image_manifest, &backup_image.id, image_id);
+
+    let new_manifest_file_path = backup_dir_path
+      .join(&backup_image.id)
+      .join("new-manifest.json");
+
+    std::fs::write(
+      &new_manifest_file_path,
+      serde_json::to_string_pretty(&image_manifest)?,
+    )?;
+
+    upload_with_retries(
+      &sts_value,
+      &new_manifest_file_path,
+      &format!("{}/manifest.json", image_id),
+    )
+    .await?;
+
+    ims_update_image_add_manifest(
+      shasta_token,
+      shasta_base_url,
+      shasta_root_cert,
+      image_id,
+    )
+    .await?;
+
+    println!(
+      "Image '{}' restored with id '{}'",
+      backup_image.name, image_id
+    );
+
+    restore_state.restored.insert(restore_key);
+    restore_state.write(backup_dir_path)?;
+  }
+
+  // HSM groups, CFS configurations and BOS session templates
+  for backup_session_template in &backup_session_template_vec {
+    if let Some(hsm_file) = &backup_session_template.hsm_file {
+      let restore_key = format!("hsm:{}", hsm_file);
+
+      if !restore_state.is_restored(&restore_key) {
+        println!("\nCreating HSM group(s) in '{}'...", hsm_file);
+        create_hsm_group_from_file(
+          shasta_token,
+          shasta_base_url,
+          shasta_root_cert,
+          &backup_dir_path.join(hsm_file),
+          assume_yes,
+          overwrite_groups,
+        )
+        .await?;
+
+        restore_state.restored.insert(restore_key);
+        restore_state.write(backup_dir_path)?;
+      }
+    }
+
+    if let Some(cfs_file) = &backup_session_template.cfs_file {
+      let restore_key = format!("configuration:{}", cfs_file);
+
+      if !restore_state.is_restored(&restore_key) {
+        println!("\nUploading CFS configuration in '{}'...", cfs_file);
+        create_cfs_config(
+          shasta_token,
+          shasta_base_url,
+          shasta_root_cert,
+          &backup_dir_path.join(cfs_file),
+          assume_yes,
+        )
+        .await?;
+
+        restore_state.restored.insert(restore_key);
+        restore_state.write(backup_dir_path)?;
+      }
+    }
+
+    let restore_key = format!("template:{}", backup_session_template.name);
+
+    if !restore_state.is_restored(&restore_key) {
+      println!(
+        "\nUploading BOS session template '{}'...",
+        backup_session_template.name
+      );
+      create_bos_sessiontemplate(
+        shasta_token,
+        shasta_base_url,
+        shasta_root_cert,
+        &backup_dir_path.join(&backup_session_template.bos_file),
+        &restore_state.image_id_map,
+        assume_yes,
+      )
+      .await?;
+
+      restore_state.restored.insert(restore_key);
+      restore_state.write(backup_dir_path)?;
+    }
+  }
+
+  println!("\nDone, the images, HSM groups, CFS configurations and BOS session templates have been restored.");
+
+  Ok(())
+}
+
+/// Returns the BOS session templates in the backup to restore, all of them if
+/// no name is provided
+pub fn get_session_templates_to_restore<'a>(
+  backup_manifest: &'a BackupManifest,
+  bos_sessiontemplate_name_vec: &[String],
+) -> Result<Vec<&'a BackupSessionTemplate>, Error> {
+  if bos_sessiontemplate_name_vec.is_empty() {
+    return Ok(backup_manifest.session_templates.iter().collect());
+  }
+
+  bos_sessiontemplate_name_vec
+    .iter()
+    .map(|bos_sessiontemplate_name| {
+      backup_manifest
+        .session_templates
+        .iter()
+        .find(|backup_session_template| {
+          &backup_session_template.name == bos_sessiontemplate_name
+        })
+        .ok_or_else(|| {
+          Error::Message(format!(
+            "BOS session template '{}' not found in backup",
+            bos_sessiontemplate_name
+          ))
+        })
+    })
+    .collect()
+}
+
+/// Points the artifacts in an IMS manifest to the image registered in the
+/// target system
+pub fn update_image_manifest(
+  image_manifest: &mut ImageManifest,
+  backup_image_id: &str,
+  image_id: &str,
+) {
+  image_manifest.created = Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+  for artifact in image_manifest.artifacts.iter_mut() {
+    artifact.link.path = artifact.link.path.replace(
+      &format!("/{}/", backup_image_id),
+      &format!("/{}/", image_id),
+    );
+    artifact.link.etag = None;
+  }
+}
+
+/// Points the boot sets in a BOS session template to the images registered in
+/// the target system
+pub fn update_boot_set_image_paths(
+  bos_sessiontemplate: &mut BosSessionTemplate,
+  image_id_map: &HashMap<String, String>,
+) {
+  for boot_set in bos_sessiontemplate
+    .boot_sets
+    .iter_mut()
+    .flat_map(|boot_sets| boot_sets.values_mut())
+  {
+    if let Some(path) = boot_set.path.as_mut() {
+      let backup_image_id = path
+        .trim_start_matches("s3://boot-images/")
+        .trim_end_matches("/manifest.json");
+
+      if let Some(image_id) = image_id_map.get(backup_image_id) {
+        *path =
+          format!("s3://{}/{}/manifest.json", BOOT_IMAGES_BUCKET, image_id);
+      }
+    }
+  }
+}
+
+/// Checks all image artifacts in the backup exist and match the size and
+/// checksum in the backup manifest
+async fn verify_backup_images(
+  backup_dir_path: &Path,
+  backup_image_vec: &[&BackupImage],
+  concurrency: usize,
+) -> Result<(), Error> {
+  let mut tasks = tokio::task::JoinSet::new();
+
+  let sem = Arc::new(Semaphore::new(concurrency.max(1)));
+
+  for backup_image in backup_image_vec {
+    for backup_artifact in &backup_image.artifacts {
+      let file_path = backup_dir_path.join(&backup_artifact.file);
+      let size = backup_artifact.size;
+      let md5 = backup_artifact.md5.clone();
+
+      let permit = sem.clone().acquire_owned().await.unwrap();
+
+      tasks.spawn_blocking(move || {
+        let _permit = permit;
+
+        is_file_valid(&file_path, size, &md5).map(|valid| (file_path, valid))
+      });
+    }
+  }
+
+  let mut invalid_file_vec = Vec::new();
+
+  while let Some(message) = tasks.join_next().await {
+    let (file_path, valid) =
+      message.map_err(|e| Error::Message(e.to_string()))??;
+
+    if !valid {
+      invalid_file_vec.push(file_path.to_string_lossy().to_string());
+    }
+  }
+
+  if !invalid_file_vec.is_empty() {
+    return Err(Error::Message(format!(
+      "The following image artifacts are missing or do not match the checksum in the backup manifest, please run the backup again:\n{}",
+      invalid_file_vec.join("\n")
+    )));
+  }
+
+  Ok(())
+}
+
+async fn upload_with_retries(
+  sts_value: &Value,
+  file_path: &Path,
+  object_key: &str,
+) -> Result<(), Error> {
+  let file_size = std::fs::metadata(file_path)?.len();
+  let file_path_str = file_path.to_string_lossy();
+
+  for attempt in 1..=TRANSFER_ATTEMPTS {
+    println!(
+      "Uploading '{}' ({}) to s3://{}/{} [attempt {}/{}]",
+      file_path_str,
+      humansize::format_size(file_size, DECIMAL),
+      BOOT_IMAGES_BUCKET,
+      object_key,
+      attempt,
+      TRANSFER_ATTEMPTS
+    );
+
+    let upload_rslt = if file_size > MULTIPART_UPLOAD_THRESHOLD {
+      ims::s3_client::s3_multipart_upload_object(
+        sts_value,
+        object_key,
+        BOOT_IMAGES_BUCKET,
+        &file_path_str,
+      )
+      .await
+    } else {
+      ims::s3_client::s3_upload_object(
+        sts_value,
+        object_key,
+        BOOT_IMAGES_BUCKET,
+        &file_path_str,
+      )
+      .await
+    };
+
+    match upload_rslt {
+      Ok(_) => return Ok(()),
+      Err(e) => {
+        log::warn!("Upload of '{}' failed: {}", file_path_str, e)
+      }
+    }
+  }
+
+  Err(Error::Message(format!(
+    "Could not upload '{}' after {} attempts",
+    file_path_str, TRANSFER_ATTEMPTS
+  )))
+}
+
+fn confirm(prompt: &str, assume_yes: bool) -> Result<(), Error> {
+  if assume_yes
+    || Confirm::new()
+      .with_prompt(prompt)
+      .interact()
+      .map_err(|e| Error::Message(e.to_string()))?
+  {
+    Ok(())
+  } else {
+    Err(Error::Message("Cancelled by user".to_string()))
+  }
+}
+
+fn read_json<T: serde::de::DeserializeOwned>(
+  file_path: &PathBuf,
+) -> Result<T, Error> {
+  let file_content = std::fs::read_to_string(file_path).map_err(|e| {
+    Error::Message(format!(
+      "Unable to read file '{}'. Reason:\n{}",
+      file_path.display(),
+      e
+    ))
+  })?;
+
+  serde_json::from_str(&file_content).map_err(|e| {
+    Error::Message(format!(
+      "File '{}' does not have correct format. Reason:\n{}",
+      file_path.display(),
+      e
+    ))
+  })
+}
+
+/// Creates a BOS session template based on the BOS file generated by
+/// 'migrate backup', the boot sets are pointed to the images restored
+async fn create_bos_sessiontemplate(
+  shasta_token: &str,
+  shasta_base_url: &str,
+  shasta_root_cert: &[u8],
+  bos_file: &PathBuf,
+  image_id_map: &HashMap<String, String>,
+  assume_yes: bool,
+) -> Result<(), Error> {
+  let mut bos_sessiontemplate: BosSessionTemplate = read_json(bos_file)?;
+
+  let bos_sessiontemplate_name =
+    bos_sessiontemplate.name.clone().unwrap_or_default();
+
+  let bos_sessiontemplate_vec = bos::template::http_client::v2::get(
+    shasta_token,
+    shasta_base_url,
+    shasta_root_cert,
+    Some(&bos_sessiontemplate_name),
+  )
+  .await?;
+
+  if !bos_sessiontemplate_vec.is_empty() {
+    println!("There already exists a BOS sessiontemplate with name '{}'. It can be replaced, but it's dangerous.", &bos_sessiontemplate_name);
+    confirm("Do you want to overwrite it?", assume_yes)?;
+
+    bos::template::http_client::v2::delete(
+      shasta_token,
+      shasta_base_url,
+      shasta_root_cert,
+      &bos_sessiontemplate_name,
+    )
+    .await?;
+
+    log::debug!(
+      "Ok BOS session template {}, deleted.",
+      &bos_sessiontemplate_name
+    );
+  }
+
+  update_boot_set_image_paths(&mut bos_sessiontemplate, image_id_map);
+
+  log::debug!("BOS sessiontemplate modified:\n{:#?}", &bos_sessiontemplate);
+
+  bos::template::http_client::v2::put(
+    shasta_token,
+    shasta_base_url,
+    shasta_root_cert,
+    &bos_sessiontemplate,
+    &bos_sessiontemplate_name,
+  )
+  .await?;
+
+  println!(
+    "Ok, BOS session template {} created successfully.",
+    &bos_sessiontemplate_name
+  );
+
+  Ok(())
+}
+
+/// Creates a CFS config on the current CSM system, based on the CFS file
+/// generated by 'migrate backup'
+async fn create_cfs_config(
+  shasta_token: &str,
+  shasta_base_url: &str,
+  shasta_root_cert: &[u8],
+  cfs_file: &PathBuf,
+  assume_yes: bool,
+) -> Result<(), Error> {
+  // The file contains the CFS configuration as returned by the API, fields
+  // like 'lastUpdated' are not part of the request
+  let cfs_config_name = read_json::<CfsConfigurationResponse>(cfs_file)?.name;
+  let cfs_configuration: CfsConfigurationRequest = read_json(cfs_file)?;
+
+  let cfs_config_vec = cfs::configuration::http_client::v3::get(
+    shasta_token,
+    shasta_base_url,
+    shasta_root_cert,
+    Some(&cfs_config_name),
+  )
+  .await?;
+
+  if !cfs_config_vec.is_empty() {
+    println!("There already exists a CFS configuration with name {}. It can be replaced, but it's dangerous as it can trigger automated node reconfiguration.", &cfs_config_name);
+    confirm("Do you want to overwrite it?", assume_yes)?;
+
+    cfs::configuration::http_client::v3::delete(
+      shasta_token,
+      shasta_base_url,
+      shasta_root_cert,
+      &cfs_config_name,
+    )
+    .await?;
+
+    log::debug!("Ok CFS configuration {}, deleted.", cfs_config_name);
+  }
+
+  log::debug!("CFS config:\n{:#?}", &cfs_configuration);
+
+  cfs::configuration::http_client::v3::put(
+    shasta_token,
+    shasta_base_url,
+    shasta_root_cert,
+    &cfs_configuration,
+    &cfs_config_name,
+  )
+  .await?;
+
+  println!(
+    "Ok, CFS configuration {} created successfully.",
+    &cfs_config_name
+  );
+
+  Ok(())
+}
+
+/// Add the image manifest field to an IMS image record
+/// the manifest field will be: s3://boot-images/{ims_image_id}/manifest.json
+async fn ims_update_image_add_manifest(
+  shasta_token: &str,
+  shasta_base_url: &str,
+  shasta_root_cert: &[u8],
+  ims_image_id: &str,
+) -> Result<(), Error> {
+  // arch is not on CSM 1.3
+  let rec = ImsImageRecord2Update {
+    link: Link {
+      etag: None,
+      path: format!(
+        "s3://{}/{}/manifest.json",
+        BOOT_IMAGES_BUCKET, ims_image_id
+      ),
+      r#type: "s3".to_string(),
+    },
+    arch: None,
+  };
+
+  let returned = ims::image::http_client::patch(
+    shasta_token,
+    shasta_base_url,
+    shasta_root_cert,
+    &ims_image_id.to_string(),
+    &rec,
+  )
+  .await?;
+
+  log::debug!("Returned json: {}", returned);
+
+  Ok(())
+}
+
+/// Registers in IMS a new image and returns the new id to pass to s3
+async fn ims_register_image(
+  shasta_token: &str,
+  shasta_base_url: &str,
+  shasta_root_cert: &[u8],
+  ims_image_name: &str,
+  assume_yes: bool,
+) -> Result<String, Error> {
+  let ims_record = Image {
+    name: ims_image_name.to_string(),
+    id: None,
+    created: None,
+    link: None,
+    arch: None,
+  };
+
+  let list_images_with_same_name = get_by_name(
+    shasta_token,
+    shasta_base_url,
+    shasta_root_cert,
+    &["".to_string()], // hsm_group_name
+    Some(ims_image_name),
+    None,
+  )
+  .await?;
+
+  if !list_images_with_same_name.is_empty() {
+    println!("There is already at least one record for image name {} in IMS do you want to create a new one (the previous one will not be deleted).", &ims_image_name);
+    println!("Current IMS record(s): {:?}", &list_images_with_same_name);
+    confirm("Do you want to create a new record?", assume_yes)?;
+  }
+
+  let json_response = ims::image::http_client::post(
+    shasta_token,
+    shasta_base_url,
+    shasta_root_cert,
+    &ims_record,
+  )
+  .await?;
+
+  Ok(json_response["id"].to_string().replace('"', ""))
+}
+
+/// Creates the HSM groups in the HSM file generated by 'migrate backup'.
+/// Existing groups with the same members are left as they are, the ones with
+/// different members are shown and only replaced after confirmation or if
+/// `overwrite_groups` is set
+async fn create_hsm_group_from_file(
+  shasta_token: &str,
+  shasta_base_url: &str,
+  shasta_root_cert: &[u8],
+  hsm_file: &PathBuf,
+  assume_yes: bool,
+  overwrite_groups: bool,
+) -> Result<(), Error> {
+  // The file looks like this: [{"label":"gele","members":{"ids":["x1001c7s1b1n1","x1001c7s1b0n0"]}}]
+  let group_vec: Vec<Group> = read_json(hsm_file)?;
+
+  for group in group_vec {
+    match hsm::group::http_client::post(
+      shasta_token,
+      shasta_base_url,
+      shasta_root_cert,
+      group.clone(),
+    )
+    .await
+    {
+      Ok(_) => {
+        println!(
+          "The HSM group {} has been created successfully.",
+          &group.label
+        );
+      }
+      Err(Error::HttpError { status: 409, .. }) => {
+        let existing_group = hsm::group::http_client::get(
+          shasta_token,
+          shasta_base_url,
+          shasta_root_cert,
+          Some(&[group.label.as_str()]),
+          None,
+        )
+        .await?
+        .into_iter()
+        .next()
+        .ok_or_else(|| {
+          Error::Message(format!(
+            "HSM group {} already exists but could not be fetched",
+            &group.label
+          ))
+        })?;
+
+        let mut existing_member_vec = existing_group.get_members();
+        existing_member_vec.sort();
+        let mut member_vec = group.get_members();
+        member_vec.sort();
+
+        if existing_member_vec == member_vec {
+          println!(
+            "The HSM group {} already exists with the same members, leaving it as it is.",
+            &group.label
+          );
+          continue;
+        }
+
+        println!(
+          "The HSM group {} already exists with different members, recreating it is a dangerous operation\n Current members: {}\n Members in the backup: {}",
+          &group.label,
+          existing_member_vec.join(", "),
+          member_vec.join(", ")
+        );
+
+        if !overwrite_groups {
+          if assume_yes {
+            return Err(Error::Message(format!(
+              "HSM group {} already exists with different members. Use '--overwrite-groups' to replace it",
+              &group.label
+            )));
+          }
+
+          confirm("Do you want to recreate it?", false)?;
+        }
+
+        delete_group(
+          shasta_token,
+          shasta_base_url,
+          shasta_root_cert,
+          &group.label,
+        )
+        .await?;
+
+        hsm::group::http_client::post(
+          shasta_token,
+          shasta_base_url,
+          shasta_root_cert,
+          group.clone(),
+        )
+        .await?;
+
+        println!(
+          "The HSM group {} has been created successfully.",
+          &group.label
+        );
+      }
+      Err(Error::HttpError { status: 400, .. }) => {
+        return Err(Error::Message(format!("Unable to create the group {}, the API returned code 400. This usually means the HSM file is malformed, or has incorrect xnames for this site in it.", &group.label)));
+      }
+      Err(error) => return Err(error),
+    }
+  }
+
+  Ok(())
+}
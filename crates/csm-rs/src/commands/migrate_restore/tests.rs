@@ -0,0 +1,118 @@
+use std::collections::HashMap;
+
+use crate::{
+  bos::template::http_client::v2::types::BosSessionTemplate,
+  commands::migrate_backup::utils::{BackupManifest, ImageManifest},
+};
+
+use super::command::{
+  get_session_templates_to_restore, update_boot_set_image_paths,
+  update_image_manifest,
+};
+
+/// Boot sets are pointed to the images registered in the target system
+#[test]
+fn test_update_boot_set_image_paths() {
+  let mut bos_sessiontemplate: BosSessionTemplate =
+    serde_json::from_value(serde_json::json!({
+      "name": "zinal-cos-template",
+      "boot_sets": {
+        "compute": { "path": "s3://boot-images/image-1/manifest.json" },
+        "uan": { "path": "s3://boot-images/image-2/manifest.json" }
+      }
+    }))
+    .unwrap();
+
+  let image_id_map =
+    HashMap::from([("image-1".to_string(), "image-10".to_string())]);
+
+  update_boot_set_image_paths(&mut bos_sessiontemplate, &image_id_map);
+
+  let boot_sets = bos_sessiontemplate.boot_sets.unwrap();
+
+  assert_eq!(
+    boot_sets["compute"].path.as_deref(),
+    Some("s3://boot-images/image-10/manifest.json")
+  );
+  assert_eq!(
+    boot_sets["uan"].path.as_deref(),
+    Some("s3://boot-images/image-2/manifest.json")
+  );
+}
+
+#[test]
+fn test_update_image_manifest() {
+  let mut image_manifest: ImageManifest =
+    serde_json::from_value(serde_json::json!({
+      "created": "2025-01-01 00:00:00",
+      "version": "1.0",
+      "artifacts": [
+        {
+          "link": {
+            "path": "s3://boot-images/image-1/rootfs",
+            "etag": "d1f2a80c4725dc0d42b809dabcc065d8",
+            "type": "s3"
+          },
+          "md5": "9b1b1d2b1f3c4e5a",
+          "type": "application/vnd.cray.image.rootfs.squashfs"
+        }
+      ]
+    }))
+    .unwrap();
+
+  update_image_manifest(&mut image_manifest, "image-1", "image-10");
+
+  assert_eq!(
+    image_manifest.artifacts[0].link.path,
+    "s3://boot-images/image-10/rootfs"
+  );
+  assert!(image_manifest.artifacts[0].link.etag.is_none());
+  assert_eq!(image_manifest.artifacts[0].md5, "9b1b1d2b1f3c4e5a");
+}
+
+#[test]
+fn test_get_session_templates_to_restore() {
+  let backup_manifest: BackupManifest =
+    serde_json::from_value(serde_json::json!({
+      "created": "2025-01-01 00:00:00",
+      "session_templates": [
+        {
+          "name": "zinal-cos-template",
+          "bos_file": "zinal-cos-template.json",
+          "cfs_file": null,
+          "hsm_file": null,
+          "image_ids": ["image-1"]
+        },
+        {
+          "name": "zinal-uan-template",
+          "bos_file": "zinal-uan-template.json",
+          "cfs_file": null,
+          "hsm_file": null,
+          "image_ids": ["image-2"]
+        }
+      ],
+      "images": []
+    }))
+    .unwrap();
+
+  assert_eq!(
+    get_session_templates_to_restore(&backup_manifest, &[])
+      .unwrap()
+      .len(),
+    2
+  );
+  assert_eq!(
+    get_session_templates_to_restore(
+      &backup_manifest,
+      &["zinal-uan-template".to_string()]
+    )
+    .unwrap()[0]
+      .name,
+    "zinal-uan-template"
+  );
+  assert!(get_session_templates_to_restore(
+    &backup_manifest,
+    &["missing-template".to_string()]
+  )
+  .is_err());
+}
@@ -2,7 +2,9 @@ pub mod apply_hw_cluster_pin;
 pub mod apply_sat_file;
 pub mod apply_session;
 pub mod delete_and_cancel_session;
+pub mod garbage_collect;
 pub mod get_images_and_details;
+pub mod get_orphans;
 pub mod i_delete_data_related_to_cfs_configuration;
 pub mod migrate_backup;
 pub mod migrate_restore;
@@ -19,52 +19,36 @@ use kube::{
 };
 
 use secrecy::SecretString;
-use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use termion::color;
 
+use manta_backend_dispatcher::types::{K8sAuth, K8sDetails};
+
 use crate::common::vault::http_client::fetch_shasta_k8s_secrets_from_vault;
 use crate::error::Error;
 
-#[derive(Serialize, Deserialize, Debug)]
-pub enum K8sAuth {
-  Native {
-    certificate_authority_data: String,
-    client_certificate_data: String,
-    client_key_data: String,
-  },
-  Vault {
-    base_url: String,
-    secret_path: String,
-    role_id: String,
-  },
-}
-
-#[derive(Serialize, Deserialize, Debug)]
-pub struct K8sDetails {
-  pub api_url: String,
-  pub authentication: K8sAuth,
-}
-
+/// Builds a k8s client authenticated with the client certificate in the k8s
+/// secrets. The API server certificate is always verified against the
+/// 'certificate-authority-data' in the k8s secrets, the client is not created
+/// if it is missing or invalid
 pub async fn get_k8s_client_programmatically(
   k8s_api_url: &str,
   shasta_k8s_secrets: Value,
+  tls_server_name_opt: Option<&str>,
 ) -> Result<kube::Client, Error> {
-  let certificate_authority_data = shasta_k8s_secrets
-    ["certificate-authority-data"]
-    .as_str()
-    .unwrap();
-  let client_certificate_data = shasta_k8s_secrets["client-certificate-data"]
-    .as_str()
-    .unwrap();
-  let client_key_data = shasta_k8s_secrets["client-key-data"].as_str().unwrap();
+  let certificate_authority_data =
+    get_k8s_secret(&shasta_k8s_secrets, "certificate-authority-data")?;
+  let client_certificate_data =
+    get_k8s_secret(&shasta_k8s_secrets, "client-certificate-data")?;
+  let client_key_data = get_k8s_secret(&shasta_k8s_secrets, "client-key-data")?;
 
   let shasta_cluster = Cluster {
     server: Some(k8s_api_url.to_string()),
-    tls_server_name: Some("kube-apiserver".to_string()), // The value "kube-apiserver" has been taken from the
-    // Subject: CN value in the Shasta certificate running
-    // this command echo | openssl s_client -showcerts -servername 10.252.1.12 -connect 10.252.1.12:6442 2>/dev/null | openssl x509 -inform pem -noout -text
-    insecure_skip_tls_verify: Some(true),
+    // Sites reaching the API server through an address not in its
+    // certificate (eg a tunnel) need to set one of the names in the
+    // certificate, 'kube-apiserver' in CSM
+    tls_server_name: tls_server_name_opt.map(str::to_string),
+    insecure_skip_tls_verify: Some(false),
     certificate_authority: None,
     certificate_authority_data: Some(String::from(certificate_authority_data)),
     proxy_url: None,
@@ -130,6 +114,92 @@ pub async fn get_k8s_client_programmatically(
       .await
       .map_err(|e| Error::K8sError(e.to_string()))?;
 
+  get_k8s_client_from_config(config)
+}
+
+/// Builds a k8s client for a site using the authentication method in its
+/// configuration
+pub async fn get_k8s_client(
+  shasta_token: &str,
+  site_name: &str,
+  k8s: &K8sDetails,
+) -> Result<kube::Client, Error> {
+  let tls_server_name_opt = k8s.tls_server_name.as_deref();
+
+  match &k8s.authentication {
+    K8sAuth::Native {
+      certificate_authority_data,
+      client_certificate_data,
+      client_key_data,
+    } => {
+      let shasta_k8s_secrets = serde_json::json!({
+        "certificate-authority-data": certificate_authority_data,
+        "client-certificate-data": client_certificate_data,
+        "client-key-data": client_key_data
+      });
+
+      get_k8s_client_programmatically(
+        &k8s.api_url,
+        shasta_k8s_secrets,
+        tls_server_name_opt,
+      )
+      .await
+    }
+    K8sAuth::Vault { base_url } => {
+      let shasta_k8s_secrets =
+        fetch_shasta_k8s_secrets_from_vault(base_url, shasta_token, site_name)
+          .await?;
+
+      get_k8s_client_programmatically(
+        &k8s.api_url,
+        shasta_k8s_secrets,
+        tls_server_name_opt,
+      )
+      .await
+    }
+    K8sAuth::Kubeconfig { path, context } => {
+      let kube_config = Kubeconfig::read_from(path).map_err(|e| {
+        Error::K8sError(format!(
+          "Could not read kubeconfig file '{}'. Reason:\n{}",
+          path, e
+        ))
+      })?;
+
+      let kube_config_options = KubeConfigOptions {
+        context: context.clone(),
+        cluster: None,
+        user: None,
+      };
+
+      let mut config =
+        kube::Config::from_custom_kubeconfig(kube_config, &kube_config_options)
+          .await
+          .map_err(|e| Error::K8sError(e.to_string()))?;
+
+      if let Some(tls_server_name) = tls_server_name_opt {
+        config.tls_server_name = Some(tls_server_name.to_string());
+      }
+
+      get_k8s_client_from_config(config)
+    }
+    K8sAuth::InCluster => {
+      let mut config = kube::Config::incluster()
+        .map_err(|e| Error::K8sError(e.to_string()))?;
+
+      if let Some(tls_server_name) = tls_server_name_opt {
+        config.tls_server_name = Some(tls_server_name.to_string());
+      }
+
+      get_k8s_client_from_config(config)
+    }
+  }
+}
+
+/// Builds a k8s client from a kube config, through the SOCKS5 proxy if set.
+/// Fails if the config does not verify the API server certificate
+fn get_k8s_client_from_config(
+  config: kube::Config,
+) -> Result<kube::Client, Error> {
   // OPTION 1 --> Native TLS - WORKING
   /* let client = if std::env::var("SOCKS5").is_ok() {
       log::debug!("SOCKS5 enabled");
@@ -200,6 +270,18 @@ pub async fn get_k8s_client_programmatically(
       kube::Client::new(service, config.default_namespace)
   }; */
 
+  if config.accept_invalid_certs {
+    return Err(Error::K8sError(
+      "k8s API server certificate verification can't be disabled".to_string(),
+    ));
+  }
+
+  // Clients authenticated with a client certificate have no auth layer,
+  // service account or kubeconfig tokens need it
+  let auth_layer_opt = config
+    .auth_layer()
+    .map_err(|e| Error::K8sError(e.to_string()))?;
+
   let client = if std::env::var("SOCKS5").is_ok() {
     log::debug!("SOCKS5 enabled");
     let mut http_connector = hyper::client::HttpConnector::new();
@@ -210,84 +292,26 @@ pub async fn get_k8s_client_programmatically(
       connector: http_connector.clone(),
     };
 
-    // HttpsConnector following https://github.com/rustls/hyper-rustls/blob/main/examples/client.rs
-    // Get CA root cert
-    let mut ca_root_cert_pem_decoded: &[u8] = &base64::decode(
-            shasta_k8s_secrets
-                .get("certificate-authority-data")
-                .ok_or_else(|| {
-                    Error::K8sError(
-                        "ERROR - field 'certificate-authority-data' missing in Vault secrets"
-                            .to_string(),
-                    )
-                })?
-                .as_str()
-                .unwrap(),
-        )
-        .map_err(|e| Error::K8sError(e.to_string()))?;
-
-    let ca_root_cert = rustls_pemfile::certs(&mut ca_root_cert_pem_decoded)?;
-
-    // Import CA cert into rustls ROOT certificate store
-    let mut root_cert_store = tokio_rustls::rustls::RootCertStore::empty();
-
-    root_cert_store.add_parsable_certificates(&ca_root_cert);
-
-    // Prepare client authentication https://github.com/rustls/rustls/blob/0018e7586c2dc689eb9e1ba8e0283c0f24b9fe8c/examples/src/bin/tlsclient-mio.rs#L414-L426
-    // Get client cert
-    let mut client_cert_pem_decoded: &[u8] = &base64::decode(
-      shasta_k8s_secrets["client-certificate-data"]
-        .as_str()
-        .unwrap(),
-    )
-    .map_err(|e| Error::K8sError(e.to_string()))?;
-
-    let client_certs = rustls_pemfile::certs(&mut client_cert_pem_decoded)
-      .unwrap()
-      .iter()
-      .map(|cert| tokio_rustls::rustls::Certificate(cert.clone()))
-      .collect();
-
-    // Get client key
-    let mut client_key_decoded: &[u8] = &base64::decode(
-            shasta_k8s_secrets
-                .get("client-key-data")
-                .ok_or_else(|| {
-                    Error::K8sError(
-                        "ERROR - field 'certificate-authority-data' missing in Vault secrets"
-                            .to_string(),
-                    )
-                })?
-                .as_str()
-                .unwrap(),
-        )
-        .map_err(|e| Error::K8sError(e.to_string()))?;
-
-    let client_key = match rustls_pemfile::read_one(&mut client_key_decoded)? {
-      Some(rustls_pemfile::Item::RSAKey(key)) => {
-        tokio_rustls::rustls::PrivateKey(key)
-      }
-      Some(rustls_pemfile::Item::PKCS8Key(key)) => {
-        tokio_rustls::rustls::PrivateKey(key)
-      }
-      Some(rustls_pemfile::Item::ECKey(key)) => {
-        tokio_rustls::rustls::PrivateKey(key)
-      }
-      _ => tokio_rustls::rustls::PrivateKey(Vec::new()),
-    };
-
-    // Create HTTPS connector
-    let rustls_config = tokio_rustls::rustls::ClientConfig::builder()
-      .with_safe_defaults()
-      .with_root_certificates(root_cert_store)
-      // .with_no_client_auth();
-      .with_client_auth_cert(client_certs, client_key)
+    // Same TLS settings as the client without proxy, the API server
+    // certificate is verified against the CA and TLS server name in the
+    // kubeconfig
+    let rustls_config = config
+      .rustls_client_config()
       .map_err(|e| Error::K8sError(e.to_string()))?;
 
-    let rustls_config = std::sync::Arc::new(rustls_config);
+    let mut https_connector_builder =
+      hyper_rustls::HttpsConnectorBuilder::new()
+        .with_tls_config(rustls_config)
+        .https_only();
+
+    if let Some(tls_server_name) = &config.tls_server_name {
+      https_connector_builder =
+        https_connector_builder.with_server_name(tls_server_name.clone());
+    }
 
-    let args = (socks_http_connector, rustls_config);
-    let https_socks_http_connector = hyper_rustls::HttpsConnector::from(args);
+    let https_socks_http_connector = https_connector_builder
+      .enable_http1()
+      .wrap_connector(socks_http_connector);
 
     /* let https_socks_http_connector = socks_http_connector
     .with_rustls_root_cert_store(root_cert_store); */
@@ -298,6 +322,7 @@ pub async fn get_k8s_client_programmatically(
 
     let service = tower::ServiceBuilder::new()
       .layer(config.base_uri_layer())
+      .option_layer(auth_layer_opt)
       .service(hyper_client);
 
     kube::Client::new(service, config.default_namespace)
@@ -307,6 +332,7 @@ pub async fn get_k8s_client_programmatically(
       .map_err(|e| Error::K8sError(e.to_string()))?;
     let service = tower::ServiceBuilder::new()
       .layer(config.base_uri_layer())
+      .option_layer(auth_layer_opt)
       .service(hyper::Client::builder().build(https));
     kube::Client::new(service, config.default_namespace)
   };
@@ -314,6 +340,21 @@ pub async fn get_k8s_client_programmatically(
   Ok(client)
 }
 
+/// Value of a field in the k8s secrets. Fails if the field is missing or
+/// empty
+fn get_k8s_secret<'a>(
+  shasta_k8s_secrets: &'a Value,
+  field: &str,
+) -> Result<&'a str, Error> {
+  shasta_k8s_secrets
+    .get(field)
+    .and_then(Value::as_str)
+    .filter(|value| !value.is_empty())
+    .ok_or_else(|| {
+      Error::K8sError(format!("field '{}' missing in k8s secrets", field))
+    })
+}
+
 #[deprecated(
   since = "v0.42.3-beta.71",
   note = "please use migrate this functionality to cli client since writting output to filesystem is not a good practice in library"
@@ -1224,22 +1265,11 @@ pub async fn get_output(mut attached: AttachedProcess) -> String {
 
 pub async fn delete_session_pod(
   shasta_token: &str,
-  vault_base_url: &str,
   site_name: &str,
-  // vault_role_id: &str,
-  k8s_api_url: &str,
+  k8s: &K8sDetails,
   cfs_session_name: &str,
 ) -> Result<(), Error> {
-  let shasta_k8s_secrets = fetch_shasta_k8s_secrets_from_vault(
-    vault_base_url,
-    shasta_token,
-    site_name,
-    // vault_role_id,
-  )
-  .await?;
-
-  let client =
-    get_k8s_client_programmatically(k8s_api_url, shasta_k8s_secrets).await?;
+  let client = get_k8s_client(shasta_token, site_name, k8s).await?;
 
   let pods_api: kube::Api<Pod> = kube::Api::namespaced(client, "services");
 
@@ -1,5 +1,8 @@
 use std::io;
 
+use manta_backend_dispatcher::error::{
+  Error as FrontEndError, Service, ServiceError,
+};
 use serde_json::Value;
 
 #[derive(thiserror::Error, Debug)]
@@ -20,6 +23,13 @@ pub enum Error {
   },
   #[error("CSM-RS > CSM: {0}")]
   CsmError(Value),
+  #[error("CSM-RS > {service} (HTTP {status}): {payload}")]
+  HttpError {
+    status: u16,
+    service: Service,
+    resource_id: Option<String>,
+    payload: String,
+  },
   #[error("CSM-RS > Console: {0}")]
   ConsoleError(String),
   #[error("CSM-RS > K8s: {0}")]
@@ -35,3 +45,50 @@ pub enum Error {
   #[error("ERROR - CFS Configuration used as a runtime configuration for a cluster and/or used to build an image used to boot node(s)")]
   ConfigurationUsedAsRuntimeConfigurationOrUsedToBuildBootImageUsed,
 }
+
+impl From<Error> for FrontEndError {
+  fn from(error: Error) -> Self {
+    match error {
+      Error::HttpError {
+        status,
+        service,
+        resource_id,
+        payload,
+      } => FrontEndError::from_http_status(
+        status,
+        service,
+        resource_id.as_deref(),
+        payload,
+      ),
+      Error::ImageNotFound(image_id) => {
+        FrontEndError::NotFound(ServiceError::new(
+          Some(404),
+          Service::Ims,
+          Some(&image_id),
+          "image not found",
+        ))
+      }
+      Error::GroupNotFound(group_name) => {
+        FrontEndError::NotFound(ServiceError::new(
+          Some(404),
+          Service::Hsm,
+          Some(&group_name),
+          "group not found",
+        ))
+      }
+      Error::ConfigurationAlreadyExists(configuration_name) => {
+        FrontEndError::ConfigurationAlreadyExistsError(configuration_name)
+      }
+      Error::Message(message) => FrontEndError::Message(message),
+      Error::IoError(e) => FrontEndError::IoError(e),
+      Error::SerdeError(e) => FrontEndError::SerdeError(e),
+      Error::NetError(e) => FrontEndError::NetError(e),
+      Error::RequestError { response, payload } => {
+        FrontEndError::RequestError { response, payload }
+      }
+      Error::CsmError(payload) => FrontEndError::CsmError(payload),
+      Error::ConsoleError(message) => FrontEndError::ConsoleError(message),
+      _ => FrontEndError::Message(error.to_string()),
+    }
+  }
+}
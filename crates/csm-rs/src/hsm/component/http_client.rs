@@ -1,10 +1,11 @@
+use manta_backend_dispatcher::error::Service;
 use serde_json::Value;
 
 use crate::{error::Error, hsm::component::types::Component};
 
 use super::types::{
-  ComponentArray, ComponentArrayPostArray, ComponentArrayPostByNidQuery,
-  ComponentArrayPostQuery, ComponentPut,
+  ComponentArray, ComponentArrayPatch, ComponentArrayPostArray,
+  ComponentArrayPostByNidQuery, ComponentArrayPostQuery, ComponentPut,
 };
 
 pub async fn get_all(
@@ -145,21 +146,15 @@ pub async fn get(
     .await?;
 
   if !response.status().is_success() {
-    match response.status() {
-      reqwest::StatusCode::UNAUTHORIZED => {
-        let error_payload = response.text().await?;
-        let error = Error::Message(error_payload);
-        return Err(error);
-
-        // return Err(Error::Message(response.text().await?));
-      }
-      _ => {
-        let error_payload = response.text().await?;
-        let error = Error::Message(error_payload);
-        return Err(error);
-        // return Err(Error::CsmError(response.json::<Value>().await?));
-      }
-    }
+    let status = response.status().as_u16();
+    let payload = response.text().await?;
+
+    return Err(Error::HttpError {
+      status,
+      service: Service::Hsm,
+      resource_id: None,
+      payload,
+    });
   }
 
   response
@@ -195,24 +190,15 @@ pub async fn get_one(
   let response = client.get(api_url).bearer_auth(auth_token).send().await?;
 
   if !response.status().is_success() {
-    match response.status() {
-      reqwest::StatusCode::UNAUTHORIZED => {
-        /* let error_payload = response.text().await?;
-        let error = Error::RequestError {
-            response: e,
-            payload: error_payload,
-        };
-        return Err(error); */
-
-        return Err(Error::Message(response.text().await?));
-      }
-      _ => {
-        /* let error_payload = response.json::<Value>().await?;
-        let error = Error::CsmError(error_payload);
-        return Err(error); */
-        return Err(Error::CsmError(response.json::<Value>().await?));
-      }
-    }
+    let status = response.status().as_u16();
+    let payload = response.text().await?;
+
+    return Err(Error::HttpError {
+      status,
+      service: Service::Hsm,
+      resource_id: Some(xname.to_string()),
+      payload,
+    });
   }
 
   response
@@ -253,24 +239,15 @@ pub async fn post(
     .await?;
 
   if !response.status().is_success() {
-    match response.status() {
-      reqwest::StatusCode::UNAUTHORIZED => {
-        /* let error_payload = response.text().await?;
-        let error = Error::RequestError {
-            response: e,
-            payload: error_payload,
-        };
-        return Err(error); */
-
-        return Err(Error::Message(response.text().await?));
-      }
-      _ => {
-        /* let error_payload = response.json::<Value>().await?;
-        let error = Error::CsmError(error_payload);
-        return Err(error); */
-        return Err(Error::CsmError(response.json::<Value>().await?));
-      }
-    }
+    let status = response.status().as_u16();
+    let payload = response.text().await?;
+
+    return Err(Error::HttpError {
+      status,
+      service: Service::Hsm,
+      resource_id: None,
+      payload,
+    });
   }
 
   /* response
@@ -312,24 +289,15 @@ pub async fn post_query(
     .await?;
 
   if !response.status().is_success() {
-    match response.status() {
-      reqwest::StatusCode::UNAUTHORIZED => {
-        /* let error_payload = response.text().await?;
-        let error = Error::RequestError {
-            response: e,
-            payload: error_payload,
-        };
-        return Err(error); */
-
-        return Err(Error::Message(response.text().await?));
-      }
-      _ => {
-        /* let error_payload = response.json::<Value>().await?;
-        let error = Error::CsmError(error_payload);
-        return Err(error); */
-        return Err(Error::CsmError(response.json::<Value>().await?));
-      }
-    }
+    let status = response.status().as_u16();
+    let payload = response.text().await?;
+
+    return Err(Error::HttpError {
+      status,
+      service: Service::Hsm,
+      resource_id: None,
+      payload,
+    });
   }
 
   response
@@ -370,24 +338,15 @@ pub async fn post_bynid_query(
     .await?;
 
   if !response.status().is_success() {
-    match response.status() {
-      reqwest::StatusCode::UNAUTHORIZED => {
-        /* let error_payload = response.text().await?;
-        let error = Error::RequestError {
-            response: e,
-            payload: error_payload,
-        };
-        return Err(error); */
-
-        return Err(Error::Message(response.text().await?));
-      }
-      _ => {
-        /* let error_payload = response.json::<Value>().await?;
-        let error = Error::CsmError(error_payload);
-        return Err(error); */
-        return Err(Error::CsmError(response.json::<Value>().await?));
-      }
-    }
+    let status = response.status().as_u16();
+    let payload = response.text().await?;
+
+    return Err(Error::HttpError {
+      status,
+      service: Service::Hsm,
+      resource_id: None,
+      payload,
+    });
   }
 
   response
@@ -429,24 +388,15 @@ pub async fn put(
     .await?;
 
   if !response.status().is_success() {
-    match response.status() {
-      reqwest::StatusCode::UNAUTHORIZED => {
-        /* let error_payload = response.text().await?;
-        let error = Error::RequestError {
-            response: e,
-            payload: error_payload,
-        };
-        return Err(error); */
-
-        return Err(Error::Message(response.text().await?));
-      }
-      _ => {
-        /* let error_payload = response.json::<Value>().await?;
-        let error = Error::CsmError(error_payload);
-        return Err(error); */
-        return Err(Error::CsmError(response.json::<Value>().await?));
-      }
-    }
+    let status = response.status().as_u16();
+    let payload = response.text().await?;
+
+    return Err(Error::HttpError {
+      status,
+      service: Service::Hsm,
+      resource_id: Some(xname.to_string()),
+      payload,
+    });
   }
 
   response
@@ -455,6 +405,55 @@ pub async fn put(
     .map_err(|error| Error::NetError(error))
 }
 
+/// Updates a field of a list of components. `bulk_endpoint` is one of
+/// 'BulkEnabled', 'BulkFlagOnly', 'BulkSoftwareStatus' or 'BulkRole'
+pub async fn patch_bulk(
+  base_url: &str,
+  auth_token: &str,
+  root_cert: &[u8],
+  bulk_endpoint: &str,
+  component_patch: ComponentArrayPatch,
+) -> Result<(), Error> {
+  let client_builder = reqwest::Client::builder()
+    .add_root_certificate(reqwest::Certificate::from_pem(root_cert)?);
+
+  // Build client
+  let client = if let Ok(socks5_env) = std::env::var("SOCKS5") {
+    // socks5 proxy
+    log::debug!("SOCKS5 enabled");
+    let socks5proxy = reqwest::Proxy::all(socks5_env)?;
+
+    // rest client to authenticate
+    client_builder.proxy(socks5proxy).build()?
+  } else {
+    client_builder.build()?
+  };
+
+  let api_url: String =
+    format!("{}/smd/hsm/v2/State/Components/{}", base_url, bulk_endpoint);
+
+  let response = client
+    .patch(api_url)
+    .bearer_auth(auth_token)
+    .json(&component_patch)
+    .send()
+    .await?;
+
+  if !response.status().is_success() {
+    let status = response.status().as_u16();
+    let payload = response.text().await?;
+
+    return Err(Error::HttpError {
+      status,
+      service: Service::Hsm,
+      resource_id: None,
+      payload,
+    });
+  }
+
+  Ok(())
+}
+
 pub async fn delete_one(
   base_url: &str,
   auth_token: &str,
@@ -486,24 +485,15 @@ pub async fn delete_one(
     .await?;
 
   if !response.status().is_success() {
-    match response.status() {
-      reqwest::StatusCode::UNAUTHORIZED => {
-        /* let error_payload = response.text().await?;
-        let error = Error::RequestError {
-            response: e,
-            payload: error_payload,
-        };
-        return Err(error); */
-
-        return Err(Error::Message(response.text().await?));
-      }
-      _ => {
-        /* let error_payload = response.json::<Value>().await?;
-        let error = Error::CsmError(error_payload);
-        return Err(error); */
-        return Err(Error::CsmError(response.json::<Value>().await?));
-      }
-    }
+    let status = response.status().as_u16();
+    let payload = response.text().await?;
+
+    return Err(Error::HttpError {
+      status,
+      service: Service::Hsm,
+      resource_id: Some(xname.to_string()),
+      payload,
+    });
   }
 
   response
@@ -541,24 +531,15 @@ pub async fn delete(
     .await?;
 
   if !response.status().is_success() {
-    match response.status() {
-      reqwest::StatusCode::UNAUTHORIZED => {
-        /* let error_payload = response.text().await?;
-        let error = Error::RequestError {
-            response: e,
-            payload: error_payload,
-        };
-        return Err(error); */
-
-        return Err(Error::Message(response.text().await?));
-      }
-      _ => {
-        /* let error_payload = response.json::<Value>().await?;
-        let error = Error::CsmError(error_payload);
-        return Err(error); */
-        return Err(Error::CsmError(response.json::<Value>().await?));
-      }
-    }
+    let status = response.status().as_u16();
+    let payload = response.text().await?;
+
+    return Err(Error::HttpError {
+      status,
+      service: Service::Hsm,
+      resource_id: None,
+      payload,
+    });
   }
 
   response
@@ -1,3 +1,4 @@
+use manta_backend_dispatcher::error::Service;
 use reqwest::Url;
 use serde_json::Value;
 
@@ -52,11 +53,17 @@ pub async fn get_raw(
         .clone(),
     )
   } else {
+    let status = response.status().as_u16();
     let payload = response
       .json::<Value>()
       .await
       .map_err(|error| Error::NetError(error))?;
-    Err(Error::CsmError(payload))
+    Err(Error::HttpError {
+      status,
+      service: Service::Hsm,
+      resource_id: None,
+      payload: payload.to_string(),
+    })
   }
 }
 
@@ -1,3 +1,4 @@
+use manta_backend_dispatcher::error::Service;
 use serde_json::Value;
 
 use crate::{
@@ -88,22 +89,16 @@ pub async fn get(
     .send()
     .await?;
 
-  if let Err(e) = response.error_for_status_ref() {
-    match response.status() {
-      reqwest::StatusCode::UNAUTHORIZED => {
-        let error_payload = response.text().await?;
-        let error = Error::RequestError {
-          response: e,
-          payload: error_payload,
-        };
-        return Err(error);
-      }
-      _ => {
-        let error_payload = response.text().await?;
-        let error = Error::Message(error_payload);
-        return Err(error);
-      }
-    }
+  if !response.status().is_success() {
+    let status = response.status().as_u16();
+    let payload = response.text().await?;
+
+    return Err(Error::HttpError {
+      status,
+      service: Service::Hsm,
+      resource_id: None,
+      payload,
+    });
   }
 
   response
@@ -180,22 +175,16 @@ pub async fn post(
 
   log::debug!("Response:\n{:#?}", response);
 
-  if let Err(e) = response.error_for_status_ref() {
-    match response.status() {
-      reqwest::StatusCode::UNAUTHORIZED => {
-        let error_payload = response.text().await?;
-        let error = Error::RequestError {
-          response: e,
-          payload: error_payload,
-        };
-        return Err(error);
-      }
-      _ => {
-        let error_payload = response.json().await?;
-        let error = Error::Message(error_payload);
-        return Err(error);
-      }
-    }
+  if !response.status().is_success() {
+    let status = response.status().as_u16();
+    let payload = response.text().await?;
+
+    return Err(Error::HttpError {
+      status,
+      service: Service::Hsm,
+      resource_id: None,
+      payload,
+    });
   }
 
   response
@@ -302,11 +291,17 @@ pub async fn delete_group(
       .await
       .map_err(|error| Error::NetError(error))
   } else {
+    let status = response.status().as_u16();
     let payload = response
       .text()
       .await
       .map_err(|error| Error::NetError(error))?;
-    Err(Error::Message(payload))
+    Err(Error::HttpError {
+      status,
+      service: Service::Hsm,
+      resource_id: Some(hsm_group_name.to_string()),
+      payload,
+    })
   }
 }
 
@@ -345,22 +340,16 @@ pub async fn post_member(
     .send()
     .await?;
 
-  if let Err(e) = response.error_for_status_ref() {
-    match response.status() {
-      reqwest::StatusCode::UNAUTHORIZED => {
-        let error_payload = response.text().await?;
-        let error = Error::RequestError {
-          response: e,
-          payload: error_payload,
-        };
-        return Err(error);
-      }
-      _ => {
-        let error_payload = response.text().await?;
-        let error = Error::Message(error_payload);
-        return Err(error);
-      }
-    }
+  if !response.status().is_success() {
+    let status = response.status().as_u16();
+    let payload = response.text().await?;
+
+    return Err(Error::HttpError {
+      status,
+      service: Service::Hsm,
+      resource_id: Some(hsm_group_name.to_string()),
+      payload,
+    });
   }
 
   response
@@ -410,10 +399,16 @@ pub async fn delete_member(
   if response.status().is_success() {
     Ok(())
   } else {
+    let status = response.status().as_u16();
     let payload = response
       .text()
       .await
       .map_err(|error| Error::NetError(error))?;
-    Err(Error::Message(payload))
+    Err(Error::HttpError {
+      status,
+      service: Service::Hsm,
+      resource_id: Some(hsm_group_name.to_string()),
+      payload,
+    })
   }
 }
@@ -13,7 +13,7 @@ pub struct Group {
   #[serde(skip_serializing_if = "Option::is_none")]
   pub members: Option<Members>,
   #[serde(skip_serializing_if = "Option::is_none")]
-  #[serde(rename(serialize = "exclusiveGroup"))]
+  #[serde(rename(serialize = "exclusiveGroup"), alias = "exclusiveGroup")]
   pub exclusive_group: Option<String>,
 }
 
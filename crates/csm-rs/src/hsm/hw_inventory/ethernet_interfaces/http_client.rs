@@ -1,6 +1,10 @@
+use manta_backend_dispatcher::error::Service;
+
 use crate::error::Error;
 
-use super::types::{ComponentEthernetInterface, IpAddressMapping};
+use super::types::{
+  ComponentEthernetInterface, EthernetInterface, IpAddressMapping,
+};
 
 // Get list of network interfaces
 // ref --> https://csm12-apidocs.svc.cscs.ch/iaas/hardware-state-manager/operation/doCompEthInterfacesGetV2/
@@ -8,14 +12,14 @@ pub async fn get(
   shasta_token: &str,
   shasta_base_url: &str,
   shasta_root_cert: &[u8],
-  mac_address: &str,
-  ip_address: &str,
-  network: &str,
-  component_id: &str, // Node's xname
-  r#type: &str,
-  olther_than: &str,
-  newer_than: &str,
-) -> Result<reqwest::Response, Error> {
+  mac_address: Option<&str>,
+  ip_address: Option<&str>,
+  network: Option<&str>,
+  component_id: Option<&str>, // Node's xname
+  r#type: Option<&str>,
+  olther_than: Option<&str>,
+  newer_than: Option<&str>,
+) -> Result<Vec<EthernetInterface>, Error> {
   let client_builder = reqwest::Client::builder()
     .add_root_certificate(reqwest::Certificate::from_pem(shasta_root_cert)?);
 
@@ -34,43 +38,149 @@ pub async fn get(
   let api_url: String =
     shasta_base_url.to_owned() + "/smd/hsm/v2/Inventory/EthernetInterfaces";
 
-  client
+  let query: Vec<(&str, &str)> = [
+    ("MACAddress", mac_address),
+    ("IPAddress", ip_address),
+    ("Network", network),
+    ("ComponentID", component_id),
+    ("Type", r#type),
+    ("OlderThan", olther_than),
+    ("NewerThan", newer_than),
+  ]
+  .into_iter()
+  .filter_map(|(key, value_opt)| value_opt.map(|value| (key, value)))
+  .collect();
+
+  let response = client
     .get(api_url)
-    .query(&[
-      ("MACAddress", mac_address),
-      ("IPAddress", ip_address),
-      ("Network", network),
-      ("ComponentID", component_id),
-      ("Type", r#type),
-      ("OlderThan", olther_than),
-      ("NewerThan", newer_than),
-    ])
+    .query(&query)
+    .bearer_auth(shasta_token)
+    .send()
+    .await?;
+
+  if !response.status().is_success() {
+    let status = response.status().as_u16();
+    let payload = response.text().await?;
+
+    return Err(Error::HttpError {
+      status,
+      service: Service::Hsm,
+      resource_id: None,
+      payload,
+    });
+  }
+
+  response.json().await.map_err(Error::NetError)
+}
+
+// Get network interface by id
+// ref --> https://csm12-apidocs.svc.cscs.ch/iaas/hardware-state-manager/operation/doCompEthInterfaceGetV2/
+pub async fn get_one(
+  shasta_token: &str,
+  shasta_base_url: &str,
+  shasta_root_cert: &[u8],
+  eth_interface_id: &str,
+) -> Result<EthernetInterface, Error> {
+  let client_builder = reqwest::Client::builder()
+    .add_root_certificate(reqwest::Certificate::from_pem(shasta_root_cert)?);
+
+  // Build client
+  let client = if let Ok(socks5_env) = std::env::var("SOCKS5") {
+    // socks5 proxy
+    log::debug!("SOCKS5 enabled");
+    let socks5proxy = reqwest::Proxy::all(socks5_env)?;
+
+    // rest client to authenticate
+    client_builder.proxy(socks5proxy).build()?
+  } else {
+    client_builder.build()?
+  };
+
+  let api_url: String = format!(
+    "{}/smd/hsm/v2/Inventory/EthernetInterfaces/{}",
+    shasta_base_url, eth_interface_id
+  );
+
+  let response = client.get(api_url).bearer_auth(shasta_token).send().await?;
+
+  if !response.status().is_success() {
+    let status = response.status().as_u16();
+    let payload = response.text().await?;
+
+    return Err(Error::HttpError {
+      status,
+      service: Service::Hsm,
+      resource_id: Some(eth_interface_id.to_string()),
+      payload,
+    });
+  }
+
+  response.json().await.map_err(Error::NetError)
+}
+
+// Create network interface
+// ref --> https://csm12-apidocs.svc.cscs.ch/iaas/hardware-state-manager/operation/doCompEthInterfacePostV2/
+pub async fn post(
+  shasta_token: &str,
+  shasta_base_url: &str,
+  shasta_root_cert: &[u8],
+  eth_interface: &EthernetInterface,
+) -> Result<(), Error> {
+  let client_builder = reqwest::Client::builder()
+    .add_root_certificate(reqwest::Certificate::from_pem(shasta_root_cert)?);
+
+  // Build client
+  let client = if let Ok(socks5_env) = std::env::var("SOCKS5") {
+    // socks5 proxy
+    log::debug!("SOCKS5 enabled");
+    let socks5proxy = reqwest::Proxy::all(socks5_env)?;
+
+    // rest client to authenticate
+    client_builder.proxy(socks5proxy).build()?
+  } else {
+    client_builder.build()?
+  };
+
+  let api_url: String =
+    shasta_base_url.to_owned() + "/smd/hsm/v2/Inventory/EthernetInterfaces";
+
+  let response = client
+    .post(api_url)
     .bearer_auth(shasta_token)
+    .json(eth_interface)
     .send()
-    .await?
-    .error_for_status()
-    .map_err(Error::NetError)
+    .await?;
+
+  if !response.status().is_success() {
+    let status = response.status().as_u16();
+    let payload = response.text().await?;
+
+    return Err(Error::HttpError {
+      status,
+      service: Service::Hsm,
+      resource_id: None,
+      payload,
+    });
+  }
+
+  Ok(())
 }
 
+// Update network interface
+// ref --> https://csm12-apidocs.svc.cscs.ch/iaas/hardware-state-manager/operation/doCompEthInterfacePatchV2/
 pub async fn patch(
   shasta_token: &str,
   shasta_base_url: &str,
   shasta_root_cert: &[u8],
   eth_interface_id: &str,
   description: Option<&str>,
-  component_id: &str,
-  ip_address_mapping: (&str, &str), // [(<ip address>, <network>), ...], examle
-                                    // [("192.168.1.10", "HMN"), ...]
-) -> Result<reqwest::Response, Error> {
-  let ip_address = ip_address_mapping.0;
-  let network = ip_address_mapping.1;
+  component_id: Option<&str>,
+  ip_address_vec_opt: Option<Vec<IpAddressMapping>>,
+) -> Result<(), Error> {
   let cei = ComponentEthernetInterface {
     description: description.map(|value| value.to_string()),
-    ip_addresses: vec![IpAddressMapping {
-      ip_address: ip_address.to_string(),
-      network: Some(network.to_string()),
-    }],
-    component_id: Some(component_id.to_string()),
+    ip_addresses: ip_address_vec_opt,
+    component_id: component_id.map(|value| value.to_string()),
   };
 
   let client_builder = reqwest::Client::builder()
@@ -93,14 +203,73 @@ pub async fn patch(
     shasta_base_url, eth_interface_id
   );
 
-  client
+  let response = client
     .patch(api_url)
-    .query(&[("ethInterfaceID", ip_address), ("ipAddress", ip_address)])
     .bearer_auth(shasta_token)
     .json(&cei)
     .send()
-    .await
-    .map_err(Error::NetError)?
-    .error_for_status()
-    .map_err(Error::NetError)
+    .await?;
+
+  if !response.status().is_success() {
+    let status = response.status().as_u16();
+    let payload = response.text().await?;
+
+    return Err(Error::HttpError {
+      status,
+      service: Service::Hsm,
+      resource_id: Some(eth_interface_id.to_string()),
+      payload,
+    });
+  }
+
+  Ok(())
+}
+
+// Delete network interface
+// ref --> https://csm12-apidocs.svc.cscs.ch/iaas/hardware-state-manager/operation/doCompEthInterfaceDeleteV2/
+pub async fn delete_one(
+  shasta_token: &str,
+  shasta_base_url: &str,
+  shasta_root_cert: &[u8],
+  eth_interface_id: &str,
+) -> Result<(), Error> {
+  let client_builder = reqwest::Client::builder()
+    .add_root_certificate(reqwest::Certificate::from_pem(shasta_root_cert)?);
+
+  // Build client
+  let client = if let Ok(socks5_env) = std::env::var("SOCKS5") {
+    // socks5 proxy
+    log::debug!("SOCKS5 enabled");
+    let socks5proxy = reqwest::Proxy::all(socks5_env)?;
+
+    // rest client to authenticate
+    client_builder.proxy(socks5proxy).build()?
+  } else {
+    client_builder.build()?
+  };
+
+  let api_url: String = format!(
+    "{}/smd/hsm/v2/Inventory/EthernetInterfaces/{}",
+    shasta_base_url, eth_interface_id
+  );
+
+  let response = client
+    .delete(api_url)
+    .bearer_auth(shasta_token)
+    .send()
+    .await?;
+
+  if !response.status().is_success() {
+    let status = response.status().as_u16();
+    let payload = response.text().await?;
+
+    return Err(Error::HttpError {
+      status,
+      service: Service::Hsm,
+      resource_id: Some(eth_interface_id.to_string()),
+      payload,
+    });
+  }
+
+  Ok(())
 }
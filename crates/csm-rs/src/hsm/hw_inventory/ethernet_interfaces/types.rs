@@ -1,17 +1,46 @@
+use manta_backend_dispatcher::types::hsm::inventory::{
+  EthernetInterface as FrontEndEthernetInterface,
+  IpAddressMapping as FrontEndIpAddressMapping,
+};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Default, Serialize, Deserialize)]
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
 pub struct IpAddressMapping {
+  #[serde(rename = "IPAddress")]
   pub ip_address: String,
+  #[serde(rename = "Network")]
   #[serde(skip_serializing_if = "Option::is_none")]
   pub network: Option<String>,
 }
 
+impl From<FrontEndIpAddressMapping> for IpAddressMapping {
+  fn from(ip_address_mapping: FrontEndIpAddressMapping) -> Self {
+    IpAddressMapping {
+      ip_address: ip_address_mapping.ip_address,
+      network: ip_address_mapping.network,
+    }
+  }
+}
+
+impl Into<FrontEndIpAddressMapping> for IpAddressMapping {
+  fn into(self) -> FrontEndIpAddressMapping {
+    FrontEndIpAddressMapping {
+      ip_address: self.ip_address,
+      network: self.network,
+    }
+  }
+}
+
+/// Payload used to update an ethernet interface
 #[derive(Debug, Default, Serialize, Deserialize)]
 pub struct ComponentEthernetInterface {
+  #[serde(rename = "Description")]
   #[serde(skip_serializing_if = "Option::is_none")]
   pub description: Option<String>,
-  pub ip_addresses: Vec<IpAddressMapping>,
+  #[serde(rename = "IPAddresses")]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub ip_addresses: Option<Vec<IpAddressMapping>>,
+  #[serde(rename = "ComponentID")]
   #[serde(skip_serializing_if = "Option::is_none")]
   pub component_id: Option<String>,
 }
@@ -53,19 +82,62 @@ pub enum ComponentType {
   INVALID,
 }
 
-#[derive(Debug, Default, Serialize, Deserialize)]
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
 pub struct EthernetInterface {
+  #[serde(rename = "ID")]
   #[serde(skip_serializing_if = "Option::is_none")]
-  id: Option<String>,
+  pub id: Option<String>,
+  #[serde(rename = "Description")]
   #[serde(skip_serializing_if = "Option::is_none")]
-  description: Option<String>,
-  mac_address: String,
-  #[serde(skip_serializing_if = "Option::is_none")]
-  ip_address: Option<String>,
+  pub description: Option<String>,
+  #[serde(rename = "MACAddress")]
+  pub mac_address: String,
+  #[serde(rename = "LastUpdate")]
   #[serde(skip_serializing_if = "Option::is_none")]
-  last_update: Option<String>,
+  pub last_update: Option<String>,
+  #[serde(rename = "ComponentID")]
   #[serde(skip_serializing_if = "Option::is_none")]
-  component_id: Option<String>,
+  pub component_id: Option<String>,
+  #[serde(rename = "Type")]
   #[serde(skip_serializing_if = "Option::is_none")]
-  r#type: Option<ComponentType>,
+  pub r#type: Option<String>,
+  #[serde(rename = "IPAddresses")]
+  #[serde(default)]
+  pub ip_addresses: Vec<IpAddressMapping>,
+}
+
+impl From<FrontEndEthernetInterface> for EthernetInterface {
+  fn from(ethernet_interface: FrontEndEthernetInterface) -> Self {
+    EthernetInterface {
+      id: ethernet_interface.id,
+      description: ethernet_interface.description,
+      mac_address: ethernet_interface.mac_address,
+      last_update: ethernet_interface.last_update,
+      component_id: ethernet_interface.component_id,
+      r#type: ethernet_interface.r#type,
+      ip_addresses: ethernet_interface
+        .ip_addresses
+        .into_iter()
+        .map(IpAddressMapping::from)
+        .collect(),
+    }
+  }
+}
+
+impl Into<FrontEndEthernetInterface> for EthernetInterface {
+  fn into(self) -> FrontEndEthernetInterface {
+    FrontEndEthernetInterface {
+      id: self.id,
+      description: self.description,
+      mac_address: self.mac_address,
+      last_update: self.last_update,
+      component_id: self.component_id,
+      r#type: self.r#type,
+      ip_addresses: self
+        .ip_addresses
+        .into_iter()
+        .map(|ip_address_mapping| ip_address_mapping.into())
+        .collect(),
+    }
+  }
 }
@@ -0,0 +1,130 @@
+use manta_backend_dispatcher::error::Service;
+
+use crate::error::Error;
+
+use super::types::{HWInvHistArray, HWInventoryByFRU};
+
+// Get list of FRUs
+pub async fn get(
+  shasta_token: &str,
+  shasta_base_url: &str,
+  shasta_root_cert: &[u8],
+  fru_id: Option<&str>,
+  r#type: Option<&str>,
+  manufacturer: Option<&str>,
+  part_number: Option<&str>,
+  serial_number: Option<&str>,
+) -> Result<Vec<HWInventoryByFRU>, Error> {
+  let client_builder = reqwest::Client::builder()
+    .add_root_certificate(reqwest::Certificate::from_pem(shasta_root_cert)?);
+
+  // Build client
+  let client = if let Ok(socks5_env) = std::env::var("SOCKS5") {
+    // socks5 proxy
+    log::debug!("SOCKS5 enabled");
+    let socks5proxy = reqwest::Proxy::all(socks5_env)?;
+
+    // rest client to authenticate
+    client_builder.proxy(socks5proxy).build()?
+  } else {
+    client_builder.build()?
+  };
+
+  let api_url: String =
+    shasta_base_url.to_owned() + "/smd/hsm/v2/Inventory/HardwareByFRU";
+
+  let query: Vec<(&str, &str)> = [
+    ("fruid", fru_id),
+    ("type", r#type),
+    ("manufacturer", manufacturer),
+    ("partnumber", part_number),
+    ("serialnumber", serial_number),
+  ]
+  .into_iter()
+  .filter_map(|(key, value_opt)| value_opt.map(|value| (key, value)))
+  .collect();
+
+  let response = client
+    .get(api_url)
+    .query(&query)
+    .bearer_auth(shasta_token)
+    .send()
+    .await?;
+
+  if !response.status().is_success() {
+    let status = response.status().as_u16();
+    let payload = response.text().await?;
+
+    return Err(Error::HttpError {
+      status,
+      service: Service::Hsm,
+      resource_id: fru_id.map(str::to_string),
+      payload,
+    });
+  }
+
+  response
+    .json()
+    .await
+    .map_err(|error| Error::NetError(error))
+}
+
+// Get events of a FRU
+pub async fn get_history(
+  shasta_token: &str,
+  shasta_base_url: &str,
+  shasta_root_cert: &[u8],
+  fru_id: &str,
+  start_time: Option<&str>,
+  end_time: Option<&str>,
+) -> Result<HWInvHistArray, Error> {
+  let client_builder = reqwest::Client::builder()
+    .add_root_certificate(reqwest::Certificate::from_pem(shasta_root_cert)?);
+
+  // Build client
+  let client = if let Ok(socks5_env) = std::env::var("SOCKS5") {
+    // socks5 proxy
+    log::debug!("SOCKS5 enabled");
+    let socks5proxy = reqwest::Proxy::all(socks5_env)?;
+
+    // rest client to authenticate
+    client_builder.proxy(socks5proxy).build()?
+  } else {
+    client_builder.build()?
+  };
+
+  let api_url: String = format!(
+    "{}/smd/hsm/v2/Inventory/HardwareByFRU/History/{}",
+    shasta_base_url, fru_id
+  );
+
+  let query: Vec<(&str, &str)> =
+    [("starttime", start_time), ("endtime", end_time)]
+      .into_iter()
+      .filter_map(|(key, value_opt)| value_opt.map(|value| (key, value)))
+      .collect();
+
+  let response = client
+    .get(api_url)
+    .query(&query)
+    .bearer_auth(shasta_token)
+    .send()
+    .await?;
+
+  if !response.status().is_success() {
+    let status = response.status().as_u16();
+    let payload = response.text().await?;
+
+    return Err(Error::HttpError {
+      status,
+      service: Service::Hsm,
+      resource_id: Some(fru_id.to_string()),
+      payload,
+    });
+  }
+
+  response
+    .json()
+    .await
+    .map_err(|error| Error::NetError(error))
+}
@@ -0,0 +1,86 @@
+use std::collections::HashMap;
+
+use manta_backend_dispatcher::types::{
+  hsm::inventory::{
+    HWInvHist as FrontEndHWInvHist, HWInvHistArray as FrontEndHWInvHistArray,
+  },
+  HWInventoryByFRU as FrontEndHWInventoryByFRU,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// FRU as returned by HSM 'Inventory/HardwareByFRU'
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct HWInventoryByFRU {
+  #[serde(rename = "FRUID")]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub fru_id: Option<String>,
+  #[serde(rename = "Type")]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub r#type: Option<String>,
+  #[serde(rename = "FRUSubtype")]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub fru_sub_type: Option<String>,
+  #[serde(rename = "HWInventoryByFRUType")]
+  pub hw_inventory_by_fru_type: String,
+  /// FRU details, eg 'MemoryFRUInfo' or 'ProcessorFRUInfo'
+  #[serde(flatten)]
+  pub fru_info: HashMap<String, Value>,
+}
+
+impl Into<FrontEndHWInventoryByFRU> for HWInventoryByFRU {
+  fn into(self) -> FrontEndHWInventoryByFRU {
+    FrontEndHWInventoryByFRU {
+      fru_id: self.fru_id,
+      r#type: self.r#type,
+      fru_sub_type: self.fru_sub_type,
+      hw_inventory_by_fru_type: self.hw_inventory_by_fru_type,
+      fru_info: self.fru_info,
+    }
+  }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct HWInvHist {
+  #[serde(rename = "ID")]
+  pub id: String,
+  #[serde(rename = "FRUID")]
+  pub fru_id: String,
+  #[serde(rename = "Timestamp")]
+  pub timestamp: String,
+  #[serde(rename = "EventType")]
+  pub event_type: String,
+}
+
+impl Into<FrontEndHWInvHist> for HWInvHist {
+  fn into(self) -> FrontEndHWInvHist {
+    FrontEndHWInvHist {
+      id: self.id,
+      fru_id: self.fru_id,
+      timestamp: self.timestamp,
+      event_type: self.event_type,
+    }
+  }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct HWInvHistArray {
+  #[serde(rename = "ID")]
+  pub id: String,
+  #[serde(rename = "History")]
+  #[serde(default)]
+  pub history: Vec<HWInvHist>,
+}
+
+impl Into<FrontEndHWInvHistArray> for HWInvHistArray {
+  fn into(self) -> FrontEndHWInvHistArray {
+    FrontEndHWInvHistArray {
+      id: self.id,
+      history: self
+        .history
+        .into_iter()
+        .map(|hw_inv_hist| hw_inv_hist.into())
+        .collect(),
+    }
+  }
+}
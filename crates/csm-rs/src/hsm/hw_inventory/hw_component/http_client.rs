@@ -1,3 +1,4 @@
+use manta_backend_dispatcher::error::Service;
 use serde_json::Value;
 
 use crate::error::Error;
@@ -100,12 +101,18 @@ pub async fn get_query(
       .await
       .map_err(|error| Error::NetError(error))
   } else {
+    let status = response.status().as_u16();
     let payload = response
       .json::<Value>()
       .await
       .map_err(|error| Error::NetError(error))?;
 
-    Err(Error::CsmError(payload))
+    Err(Error::HttpError {
+      status,
+      service: Service::Hsm,
+      resource_id: Some(xname.to_string()),
+      payload: payload.to_string(),
+    })
   }
 }
 
@@ -140,22 +147,16 @@ pub async fn post(
     .send()
     .await?;
 
-  if let Err(e) = response.error_for_status_ref() {
-    match response.status() {
-      reqwest::StatusCode::UNAUTHORIZED => {
-        let error_payload = response.text().await?;
-        let error = Error::RequestError {
-          response: e,
-          payload: error_payload,
-        };
-        return Err(error);
-      }
-      _ => {
-        let error_payload = response.json::<Value>().await?;
-        let error = Error::CsmError(error_payload);
-        return Err(error);
-      }
-    }
+  if !response.status().is_success() {
+    let status = response.status().as_u16();
+    let payload = response.text().await?;
+
+    return Err(Error::HttpError {
+      status,
+      service: Service::Hsm,
+      resource_id: None,
+      payload,
+    });
   }
 
   response
@@ -1,3 +1,4 @@
 pub mod ethernet_interfaces;
+pub mod hardware_by_fru;
 pub mod hw_component;
 pub mod redfish_endpoint;
@@ -1,3 +1,4 @@
+use manta_backend_dispatcher::error::Service;
 use serde_json::Value;
 
 use crate::error::Error;
@@ -37,22 +38,16 @@ pub async fn get_query(
     .send()
     .await?;
 
-  if let Err(e) = response.error_for_status_ref() {
-    match response.status() {
-      reqwest::StatusCode::UNAUTHORIZED => {
-        let error_payload = response.text().await?;
-        let error = Error::RequestError {
-          response: e,
-          payload: error_payload,
-        };
-        return Err(error);
-      }
-      _ => {
-        let error_payload = response.json::<Value>().await?;
-        let error = Error::CsmError(error_payload);
-        return Err(error);
-      }
-    }
+  if !response.status().is_success() {
+    let status = response.status().as_u16();
+    let payload = response.text().await?;
+
+    return Err(Error::HttpError {
+      status,
+      service: Service::Hsm,
+      resource_id: Some(xname.to_string()),
+      payload,
+    });
   }
 
   response
@@ -98,22 +93,16 @@ pub async fn get(
     .send()
     .await?;
 
-  if let Err(e) = response.error_for_status_ref() {
-    match response.status() {
-      reqwest::StatusCode::UNAUTHORIZED => {
-        let error_payload = response.text().await?;
-        let error = Error::RequestError {
-          response: e,
-          payload: error_payload,
-        };
-        return Err(error);
-      }
-      _ => {
-        let error_payload = response.json::<Value>().await?;
-        let error = Error::CsmError(error_payload);
-        return Err(error);
-      }
-    }
+  if !response.status().is_success() {
+    let status = response.status().as_u16();
+    let payload = response.text().await?;
+
+    return Err(Error::HttpError {
+      status,
+      service: Service::Hsm,
+      resource_id: None,
+      payload,
+    });
   }
 
   response
@@ -150,22 +139,16 @@ pub async fn get_one(
 
   let response = client.get(api_url).bearer_auth(auth_token).send().await?;
 
-  if let Err(e) = response.error_for_status_ref() {
-    match response.status() {
-      reqwest::StatusCode::UNAUTHORIZED => {
-        let error_payload = response.text().await?;
-        let error = Error::RequestError {
-          response: e,
-          payload: error_payload,
-        };
-        return Err(error);
-      }
-      _ => {
-        let error_payload = response.json::<Value>().await?;
-        let error = Error::CsmError(error_payload);
-        return Err(error);
-      }
-    }
+  if !response.status().is_success() {
+    let status = response.status().as_u16();
+    let payload = response.text().await?;
+
+    return Err(Error::HttpError {
+      status,
+      service: Service::Hsm,
+      resource_id: Some(xname.to_string()),
+      payload,
+    });
   }
 
   response
@@ -205,22 +188,16 @@ pub async fn post(
     .send()
     .await?;
 
-  if let Err(e) = response.error_for_status_ref() {
-    match response.status() {
-      reqwest::StatusCode::UNAUTHORIZED => {
-        let error_payload = response.text().await?;
-        let error = Error::RequestError {
-          response: e,
-          payload: error_payload,
-        };
-        return Err(error);
-      }
-      _ => {
-        let error_payload = response.json::<Value>().await?;
-        let error = Error::CsmError(error_payload);
-        return Err(error);
-      }
-    }
+  if !response.status().is_success() {
+    let status = response.status().as_u16();
+    let payload = response.text().await?;
+
+    return Err(Error::HttpError {
+      status,
+      service: Service::Hsm,
+      resource_id: None,
+      payload,
+    });
   }
 
   response
@@ -262,22 +239,16 @@ pub async fn put(
     .send()
     .await?;
 
-  if let Err(e) = response.error_for_status_ref() {
-    match response.status() {
-      reqwest::StatusCode::UNAUTHORIZED => {
-        let error_payload = response.text().await?;
-        let error = Error::RequestError {
-          response: e,
-          payload: error_payload,
-        };
-        return Err(error);
-      }
-      _ => {
-        let error_payload = response.json::<Value>().await?;
-        let error = Error::CsmError(error_payload);
-        return Err(error);
-      }
-    }
+  if !response.status().is_success() {
+    let status = response.status().as_u16();
+    let payload = response.text().await?;
+
+    return Err(Error::HttpError {
+      status,
+      service: Service::Hsm,
+      resource_id: Some(xname.to_string()),
+      payload,
+    });
   }
 
   response.json().await.map_err(|e| Error::NetError(e))
@@ -312,22 +283,16 @@ pub async fn delete_all(
     .send()
     .await?;
 
-  if let Err(e) = response.error_for_status_ref() {
-    match response.status() {
-      reqwest::StatusCode::UNAUTHORIZED => {
-        let error_payload = response.text().await?;
-        let error = Error::RequestError {
-          response: e,
-          payload: error_payload,
-        };
-        return Err(error);
-      }
-      _ => {
-        let error_payload = response.json::<Value>().await?;
-        let error = Error::CsmError(error_payload);
-        return Err(error);
-      }
-    }
+  if !response.status().is_success() {
+    let status = response.status().as_u16();
+    let payload = response.text().await?;
+
+    return Err(Error::HttpError {
+      status,
+      service: Service::Hsm,
+      resource_id: None,
+      payload,
+    });
   }
 
   response
@@ -368,22 +333,16 @@ pub async fn delete_one(
     .send()
     .await?;
 
-  if let Err(e) = response.error_for_status_ref() {
-    match response.status() {
-      reqwest::StatusCode::UNAUTHORIZED => {
-        let error_payload = response.text().await?;
-        let error = Error::RequestError {
-          response: e,
-          payload: error_payload,
-        };
-        return Err(error);
-      }
-      _ => {
-        let error_payload = response.json::<Value>().await?;
-        let error = Error::CsmError(error_payload);
-        return Err(error);
-      }
-    }
+  if !response.status().is_success() {
+    let status = response.status().as_u16();
+    let payload = response.text().await?;
+
+    return Err(Error::HttpError {
+      status,
+      service: Service::Hsm,
+      resource_id: Some(xname.to_string()),
+      payload,
+    });
   }
 
   response
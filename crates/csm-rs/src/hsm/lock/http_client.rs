@@ -0,0 +1,154 @@
+use manta_backend_dispatcher::error::Service;
+
+use crate::error::Error;
+
+use super::types::{
+  ComponentLockStatusArray, LockRequest, LockResponse, LockStatusRequest,
+};
+
+/// Lock and reservation status of a list of components
+pub async fn get_status(
+  base_url: &str,
+  auth_token: &str,
+  root_cert: &[u8],
+  lock_status_request: LockStatusRequest,
+) -> Result<ComponentLockStatusArray, Error> {
+  let client_builder = reqwest::Client::builder()
+    .add_root_certificate(reqwest::Certificate::from_pem(root_cert)?);
+
+  // Build client
+  let client = if let Ok(socks5_env) = std::env::var("SOCKS5") {
+    // socks5 proxy
+    log::debug!("SOCKS5 enabled");
+    let socks5proxy = reqwest::Proxy::all(socks5_env)?;
+
+    // rest client to authenticate
+    client_builder.proxy(socks5proxy).build()?
+  } else {
+    client_builder.build()?
+  };
+
+  let api_url: String = format!("{}/smd/hsm/v2/locks/status", base_url);
+
+  let response = client
+    .post(api_url)
+    .bearer_auth(auth_token)
+    .json(&lock_status_request)
+    .send()
+    .await?;
+
+  if !response.status().is_success() {
+    let status = response.status().as_u16();
+    let payload = response.text().await?;
+
+    return Err(Error::HttpError {
+      status,
+      service: Service::Hsm,
+      resource_id: None,
+      payload,
+    });
+  }
+
+  response
+    .json()
+    .await
+    .map_err(|error| Error::NetError(error))
+}
+
+/// Locks components so they can not be reserved
+pub async fn lock(
+  base_url: &str,
+  auth_token: &str,
+  root_cert: &[u8],
+  lock_request: LockRequest,
+) -> Result<LockResponse, Error> {
+  let client_builder = reqwest::Client::builder()
+    .add_root_certificate(reqwest::Certificate::from_pem(root_cert)?);
+
+  // Build client
+  let client = if let Ok(socks5_env) = std::env::var("SOCKS5") {
+    // socks5 proxy
+    log::debug!("SOCKS5 enabled");
+    let socks5proxy = reqwest::Proxy::all(socks5_env)?;
+
+    // rest client to authenticate
+    client_builder.proxy(socks5proxy).build()?
+  } else {
+    client_builder.build()?
+  };
+
+  let api_url: String = format!("{}/smd/hsm/v2/locks/lock", base_url);
+
+  let response = client
+    .post(api_url)
+    .bearer_auth(auth_token)
+    .json(&lock_request)
+    .send()
+    .await?;
+
+  if !response.status().is_success() {
+    let status = response.status().as_u16();
+    let payload = response.text().await?;
+
+    return Err(Error::HttpError {
+      status,
+      service: Service::Hsm,
+      resource_id: None,
+      payload,
+    });
+  }
+
+  response
+    .json()
+    .await
+    .map_err(|error| Error::NetError(error))
+}
+
+/// Unlocks components
+pub async fn unlock(
+  base_url: &str,
+  auth_token: &str,
+  root_cert: &[u8],
+  lock_request: LockRequest,
+) -> Result<LockResponse, Error> {
+  let client_builder = reqwest::Client::builder()
+    .add_root_certificate(reqwest::Certificate::from_pem(root_cert)?);
+
+  // Build client
+  let client = if let Ok(socks5_env) = std::env::var("SOCKS5") {
+    // socks5 proxy
+    log::debug!("SOCKS5 enabled");
+    let socks5proxy = reqwest::Proxy::all(socks5_env)?;
+
+    // rest client to authenticate
+    client_builder.proxy(socks5proxy).build()?
+  } else {
+    client_builder.build()?
+  };
+
+  let api_url: String = format!("{}/smd/hsm/v2/locks/unlock", base_url);
+
+  let response = client
+    .post(api_url)
+    .bearer_auth(auth_token)
+    .json(&lock_request)
+    .send()
+    .await?;
+
+  if !response.status().is_success() {
+    let status = response.status().as_u16();
+    let payload = response.text().await?;
+
+    return Err(Error::HttpError {
+      status,
+      service: Service::Hsm,
+      resource_id: None,
+      payload,
+    });
+  }
+
+  response
+    .json()
+    .await
+    .map_err(|error| Error::NetError(error))
+}
@@ -0,0 +1,2 @@
+pub mod http_client;
+pub mod types;
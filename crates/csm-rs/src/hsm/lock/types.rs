@@ -0,0 +1,130 @@
+use serde::{Deserialize, Serialize};
+
+use manta_backend_dispatcher::types::hsm::lock::{
+  ComponentLockStatus as FrontEndComponentLockStatus,
+  LockFailure as FrontEndLockFailure, LockResult as FrontEndLockResult,
+};
+
+/// Whether a lock operation fails for all components if it fails for any of
+/// them ('rigid') or not ('flexible')
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum ProcessingModel {
+  Rigid,
+  Flexible,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct LockRequest {
+  #[serde(rename = "ComponentIDs")]
+  pub component_ids: Vec<String>,
+  #[serde(rename = "ProcessingModel")]
+  pub processing_model: ProcessingModel,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct LockStatusRequest {
+  #[serde(rename = "ComponentIDs")]
+  pub component_ids: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ComponentLockStatus {
+  #[serde(rename = "ID")]
+  pub id: String,
+  #[serde(rename = "Locked")]
+  pub locked: bool,
+  #[serde(rename = "Reserved")]
+  pub reserved: bool,
+  #[serde(rename = "ReservationDisabled")]
+  pub reservation_disabled: bool,
+  #[serde(rename = "CreationTime")]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub creation_time: Option<String>,
+  #[serde(rename = "ExpirationTime")]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub expiration_time: Option<String>,
+}
+
+impl Into<FrontEndComponentLockStatus> for ComponentLockStatus {
+  fn into(self) -> FrontEndComponentLockStatus {
+    FrontEndComponentLockStatus {
+      id: self.id,
+      locked: self.locked,
+      reserved: self.reserved,
+      reservation_disabled: self.reservation_disabled,
+      creation_time: self.creation_time,
+      expiration_time: self.expiration_time,
+    }
+  }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ComponentLockStatusArray {
+  #[serde(rename = "Components")]
+  #[serde(default)]
+  pub components: Vec<ComponentLockStatus>,
+  #[serde(rename = "NotFound")]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub not_found: Option<Vec<String>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct LockCounts {
+  #[serde(rename = "Total")]
+  pub total: usize,
+  #[serde(rename = "Success")]
+  pub success: usize,
+  #[serde(rename = "Failure")]
+  pub failure: usize,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct LockSuccess {
+  #[serde(rename = "ComponentIDs")]
+  #[serde(default)]
+  pub component_ids: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct LockFailure {
+  #[serde(rename = "ID")]
+  pub id: String,
+  #[serde(rename = "Reason")]
+  pub reason: String,
+}
+
+impl Into<FrontEndLockFailure> for LockFailure {
+  fn into(self) -> FrontEndLockFailure {
+    FrontEndLockFailure {
+      id: self.id,
+      reason: self.reason,
+    }
+  }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct LockResponse {
+  #[serde(rename = "Counts")]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub counts: Option<LockCounts>,
+  #[serde(rename = "Success")]
+  #[serde(default)]
+  pub success: LockSuccess,
+  #[serde(rename = "Failure")]
+  #[serde(default)]
+  pub failure: Vec<LockFailure>,
+}
+
+impl Into<FrontEndLockResult> for LockResponse {
+  fn into(self) -> FrontEndLockResult {
+    FrontEndLockResult {
+      success: self.success.component_ids,
+      failure: self
+        .failure
+        .into_iter()
+        .map(|failure| failure.into())
+        .collect(),
+    }
+  }
+}
@@ -1,3 +1,4 @@
+use manta_backend_dispatcher::error::Service;
 use serde_json::Value;
 
 use crate::error::Error;
@@ -42,11 +43,17 @@ pub async fn get_all(
         .unwrap(),
     )
   } else {
+    let status = response.status().as_u16();
     let payload = response
       .json::<Value>()
       .await
       .map_err(|error| Error::NetError(error))?;
-    Err(Error::CsmError(payload))
+    Err(Error::HttpError {
+      status,
+      service: Service::Hsm,
+      resource_id: None,
+      payload: payload.to_string(),
+    })
   }
 }
 
@@ -90,10 +97,16 @@ pub async fn get_xname(
         .unwrap(),
     )
   } else {
+    let status = response.status().as_u16();
     let payload = response
       .json::<Value>()
       .await
       .map_err(|error| Error::NetError(error))?;
-    Err(Error::CsmError(payload))
+    Err(Error::HttpError {
+      status,
+      service: Service::Hsm,
+      resource_id: Some(xname.to_string()),
+      payload: payload.to_string(),
+    })
   }
 }
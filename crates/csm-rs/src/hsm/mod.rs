@@ -2,5 +2,6 @@ pub mod component;
 pub mod component_status;
 pub mod group;
 pub mod hw_inventory;
+pub mod lock;
 pub mod memberships;
 pub mod service;
@@ -1,5 +1,6 @@
 pub mod types;
 
+use manta_backend_dispatcher::error::Service;
 use serde_json::Value;
 
 use types::{Image, ImsImageRecord2Update};
@@ -49,7 +50,12 @@ pub async fn get(
       Some(reqwest::StatusCode::NOT_FOUND) => {
         Error::ImageNotFound(image_id_opt.unwrap().to_string())
       }
-      Some(_) => Error::NetError(e),
+      Some(status) => Error::HttpError {
+        status: status.as_u16(),
+        service: Service::Ims,
+        resource_id: image_id_opt.map(str::to_string),
+        payload: e.to_string(),
+      },
       None => Error::Message(format!(
         "ERROR - Http response with no status code?.\nReason:\n{}",
         e.to_string()
@@ -159,7 +165,12 @@ pub async fn delete(
       Some(reqwest::StatusCode::NOT_FOUND) => {
         Error::ImageNotFound(image_id.to_string())
       }
-      Some(_) => Error::NetError(e),
+      Some(status) => Error::HttpError {
+        status: status.as_u16(),
+        service: Service::Ims,
+        resource_id: Some(image_id.to_string()),
+        payload: e.to_string(),
+      },
       None => Error::Message(format!(
         "ERROR - Http response with no status code?.\nReason:\n{}",
         e.to_string()
@@ -182,7 +193,12 @@ pub async fn delete(
       Some(reqwest::StatusCode::NOT_FOUND) => {
         Error::ImageNotFound(image_id.to_string())
       }
-      Some(_) => Error::NetError(e),
+      Some(status) => Error::HttpError {
+        status: status.as_u16(),
+        service: Service::Ims,
+        resource_id: Some(image_id.to_string()),
+        payload: e.to_string(),
+      },
       None => Error::Message(format!(
         "ERROR - Http response with no status code?.\nReason:\n{}",
         e.to_string()
@@ -1,3 +1,4 @@
+use manta_backend_dispatcher::error::Service;
 use serde_json::Value;
 
 use crate::error::Error;
@@ -81,12 +82,18 @@ pub async fn post_customize(
       .await
       .map_err(|error| Error::NetError(error))
   } else {
+    let status = response.status().as_u16();
     let payload = response
       .json::<Value>()
       .await
       .map_err(|error| Error::NetError(error))?;
 
-    Err(Error::CsmError(payload))
+    Err(Error::HttpError {
+      status,
+      service: Service::Ims,
+      resource_id: None,
+      payload: payload.to_string(),
+    })
   }
 }
 
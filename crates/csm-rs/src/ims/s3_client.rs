@@ -218,8 +218,8 @@ pub async fn s3_download_object(
       e.to_string()
     ))
   })? {
-    let bytes = file.write(&bytes)?;
-    bar.inc(bytes as u64);
+    file.write_all(&bytes)?;
+    bar.inc(bytes.len() as u64);
   }
   bar.finish();
   Ok(file_path.to_string_lossy().to_string())
@@ -298,6 +298,57 @@ pub async fn s3_remove_object(
   }
 }
 
+/// Lists the top level prefixes ("directories") of a bucket
+///
+/// # Needs
+/// - `sts_value` the temporary S3 token obtained from STS via `s3_auth()`
+/// - `bucket` bucket to list e.g. `boot-images`
+/// # Returns
+///   * Vec<String>: prefixes without the trailing '/' e.g. image ids in the `boot-images` bucket OR
+///   * Error: descriptive error if not possible to list the bucket
+pub async fn s3_list_prefixes(
+  sts_value: &Value,
+  bucket: &str,
+) -> Result<Vec<String>, Error> {
+  let client = setup_client(sts_value).await;
+
+  let mut prefix_vec = Vec::new();
+  let mut continuation_token_opt: Option<String> = None;
+
+  loop {
+    let response = client
+      .list_objects_v2()
+      .bucket(bucket)
+      .delimiter("/")
+      .set_continuation_token(continuation_token_opt.clone())
+      .send()
+      .await
+      .map_err(|e| {
+        Error::Message(format!(
+          "ERROR - could not list S3 bucket '{}'.\nReason:\n{}",
+          bucket, e
+        ))
+      })?;
+
+    prefix_vec.extend(response.common_prefixes().iter().filter_map(
+      |common_prefix| {
+        common_prefix
+          .prefix()
+          .map(|prefix| prefix.trim_end_matches('/').to_string())
+      },
+    ));
+
+    match response.next_continuation_token() {
+      Some(continuation_token) => {
+        continuation_token_opt = Some(continuation_token.to_string())
+      }
+      None => break,
+    }
+  }
+
+  Ok(prefix_vec)
+}
+
 /// Uploads an object to S3 using the multipart method
 ///
 /// # Needs
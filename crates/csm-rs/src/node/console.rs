@@ -1,4 +1,8 @@
 use core::time;
+use std::{
+  pin::Pin,
+  task::{Context, Poll},
+};
 
 use k8s_openapi::api::core::v1::Pod;
 use kube::{
@@ -6,56 +10,21 @@ use kube::{
   Api,
 };
 use serde_json::Value;
+use tokio::io::{AsyncRead, ReadBuf};
 use tokio_stream::StreamExt;
 use tokio_util::io::ReaderStream;
 
-use crate::{
-  common::kubernetes::{self, get_k8s_client_programmatically},
-  error::Error,
-};
+use crate::{common::kubernetes, error::Error};
 
 pub async fn get_container_attachment_to_conman(
+  client: kube::Client,
   xname: &String,
-  k8s_api_url: &str,
-  shasta_k8s_secrets: Value,
 ) -> Result<AttachedProcess, Error> {
   log::info!("xname: {}", xname);
 
-  let client = get_k8s_client_programmatically(k8s_api_url, shasta_k8s_secrets)
-    .await
-    .unwrap();
-
   let pods_fabric: Api<Pod> = Api::namespaced(client, "services");
 
-  let params = kube::api::ListParams::default()
-    .limit(1)
-    .labels("app.kubernetes.io/name=cray-console-operator");
-
-  let pods_objects = pods_fabric.list(&params).await.unwrap();
-
-  let console_operator_pod = &pods_objects.items[0];
-  let console_operator_pod_name =
-    console_operator_pod.metadata.name.clone().unwrap();
-
-  log::info!("Console operator pod name '{}'", console_operator_pod_name);
-
-  let mut attached = pods_fabric
-    .exec(
-      &console_operator_pod_name,
-      vec!["sh", "-c", &format!("/app/get-node {}", xname)],
-      &AttachParams::default()
-        .container("cray-console-operator")
-        .stderr(false),
-    )
-    .await
-    .unwrap();
-
-  let mut stdout_stream = ReaderStream::new(attached.stdout().unwrap());
-  let next_stdout = stdout_stream.next().await.unwrap().unwrap();
-  let stdout_str = std::str::from_utf8(&next_stdout).unwrap();
-  let output_json: Value = serde_json::from_str(stdout_str).unwrap();
-
-  let console_pod_name = output_json["podname"].as_str().unwrap();
+  let console_pod_name = get_console_pod_name(&pods_fabric, xname).await?;
 
   let command = vec!["conman", "-j", xname]; // Enter the container and open conman to access node's console
                                              // let command = vec!["bash"]; // Enter the container and open bash to start an interactive
@@ -67,7 +36,7 @@ pub async fn get_container_attachment_to_conman(
 
   pods_fabric
         .exec(
-            console_pod_name,
+            &console_pod_name,
             command,
             &AttachParams::default()
                 .container("cray-console-node")
@@ -85,14 +54,134 @@ pub async fn get_container_attachment_to_conman(
         })
 }
 
+/// Attaches to a node's console in monitor mode, conman does not forward
+/// anything written to stdin to the node. stdin is attached anyway because
+/// conman exits when it is closed
+pub async fn get_container_attachment_to_conman_read_only(
+  client: kube::Client,
+  xname: &str,
+) -> Result<AttachedProcess, Error> {
+  let pods_fabric: Api<Pod> = Api::namespaced(client, "services");
+
+  let console_pod_name = get_console_pod_name(&pods_fabric, xname).await?;
+
+  log::info!(
+    "Connecting read-only to console {} in pod '{}'",
+    xname,
+    console_pod_name
+  );
+
+  pods_fabric
+    .exec(
+      &console_pod_name,
+      vec!["conman", "-m", xname],
+      &AttachParams::default()
+        .container("cray-console-node")
+        .stdin(true)
+        .stdout(true)
+        .stderr(false)
+        .tty(true),
+    )
+    .await
+    .map_err(|e| {
+      Error::ConsoleError(format!(
+        "Error attaching to container 'cray-console-node' in pod '{}'. Reason:\n{}. Exit",
+        console_pod_name, e
+      ))
+    })
+}
+
+/// Output of a console attachment. Owns the attachment so its stdin is kept
+/// open while the output is read
+pub struct ConsoleOutput {
+  stdout: Box<dyn AsyncRead + Unpin + Send>,
+  _attached: AttachedProcess,
+}
+
+impl ConsoleOutput {
+  pub fn new(mut attached: AttachedProcess) -> Result<Self, Error> {
+    let stdout = attached.stdout().ok_or_else(|| {
+      Error::ConsoleError("Console stdout is not attached".to_string())
+    })?;
+
+    Ok(Self {
+      stdout: Box::new(stdout),
+      _attached: attached,
+    })
+  }
+}
+
+impl AsyncRead for ConsoleOutput {
+  fn poll_read(
+    mut self: Pin<&mut Self>,
+    cx: &mut Context<'_>,
+    buf: &mut ReadBuf<'_>,
+  ) -> Poll<std::io::Result<()>> {
+    Pin::new(&mut self.stdout).poll_read(cx, buf)
+  }
+}
+
+/// Name of the 'cray-console-node' pod conman runs in for a node
+async fn get_console_pod_name(
+  pods_fabric: &Api<Pod>,
+  xname: &str,
+) -> Result<String, Error> {
+  let params = kube::api::ListParams::default()
+    .limit(1)
+    .labels("app.kubernetes.io/name=cray-console-operator");
+
+  let pods_objects = pods_fabric
+    .list(&params)
+    .await
+    .map_err(|e| Error::K8sError(e.to_string()))?;
+
+  let console_operator_pod_name = pods_objects
+    .items
+    .first()
+    .and_then(|pod| pod.metadata.name.clone())
+    .ok_or_else(|| {
+      Error::ConsoleError("Console operator pod not found".to_string())
+    })?;
+
+  log::info!("Console operator pod name '{}'", console_operator_pod_name);
+
+  let mut attached = pods_fabric
+    .exec(
+      &console_operator_pod_name,
+      vec!["sh", "-c", &format!("/app/get-node {}", xname)],
+      &AttachParams::default()
+        .container("cray-console-operator")
+        .stderr(false),
+    )
+    .await
+    .map_err(|e| Error::K8sError(e.to_string()))?;
+
+  let mut stdout_stream = ReaderStream::new(attached.stdout().unwrap());
+  let next_stdout = stdout_stream
+    .next()
+    .await
+    .and_then(|stdout_rslt| stdout_rslt.ok())
+    .unwrap_or_default();
+  let output_json: Value =
+    serde_json::from_slice(&next_stdout).map_err(|e| {
+      Error::ConsoleError(format!(
+        "Could not get the console pod of node '{}'. Reason:\n{}",
+        xname, e
+      ))
+    })?;
+
+  output_json["podname"]
+    .as_str()
+    .map(str::to_string)
+    .ok_or_else(|| {
+      Error::ConsoleError(format!("Console pod of node '{}' not found", xname))
+    })
+}
+
 pub async fn get_container_attachment_to_cfs_session_image_target(
+  client: kube::Client,
   cfs_session_name: &str,
-  k8s_api_url: &str,
-  shasta_k8s_secrets: Value,
 ) -> Result<AttachedProcess, Error> {
-  let client =
-    get_k8s_client_programmatically(k8s_api_url, shasta_k8s_secrets).await?;
-
   let pods_fabric: Api<Pod> = Api::namespaced(client.clone(), "services");
 
   let params = kube::api::ListParams::default()
@@ -1,14 +1,17 @@
+use std::time;
+
+use manta_backend_dispatcher::error::Service;
 use serde_json::Value;
 
 use crate::error::Error;
 
-use super::types::{PowerCapComponent, PowerCapTaskInfo};
+use super::types::{PowerCapPatch, PowerCapTaskInfo, PowerCapTaskList};
 
 pub async fn get(
   shasta_base_url: &str,
   shasta_token: &str,
   shasta_root_cert: &[u8],
-) -> Result<PowerCapTaskInfo, Error> {
+) -> Result<PowerCapTaskList, Error> {
   let client;
 
   let client_builder = reqwest::Client::builder()
@@ -41,12 +44,18 @@ pub async fn get(
       .await
       .map_err(|error| Error::NetError(error))
   } else {
+    let status = response.status().as_u16();
     let payload = response
       .json::<Value>()
       .await
       .map_err(|error| Error::NetError(error))?;
 
-    Err(Error::CsmError(payload))
+    Err(Error::HttpError {
+      status,
+      service: Service::Pcs,
+      resource_id: None,
+      payload: payload.to_string(),
+    })
   }
 }
 
@@ -89,12 +98,18 @@ pub async fn get_task_id(
       .await
       .map_err(|error| Error::NetError(error))
   } else {
+    let status = response.status().as_u16();
     let payload = response
       .json::<Value>()
       .await
       .map_err(|error| Error::NetError(error))?;
 
-    Err(Error::CsmError(payload))
+    Err(Error::HttpError {
+      status,
+      service: Service::Pcs,
+      resource_id: Some(task_id.to_string()),
+      payload: payload.to_string(),
+    })
   }
 }
 
@@ -126,7 +141,7 @@ pub async fn post_snapshot(
     shasta_base_url.to_owned() + "/power-control/v1/power-cap/snapshot";
 
   let response = client
-    .put(api_url)
+    .post(api_url)
     .json(&serde_json::json!({
         "xnames": xname_vec
     }))
@@ -138,12 +153,18 @@ pub async fn post_snapshot(
   if response.status().is_success() {
     Ok(response.json().await.map_err(|e| Error::NetError(e))?)
   } else {
+    let status = response.status().as_u16();
     let payload = response
       .json::<Value>()
       .await
       .map_err(|e| Error::NetError(e))?;
 
-    Err(Error::CsmError(payload))
+    Err(Error::HttpError {
+      status,
+      service: Service::Pcs,
+      resource_id: None,
+      payload: payload.to_string(),
+    })
   }
 }
 
@@ -151,7 +172,7 @@ pub async fn patch(
   shasta_base_url: &str,
   shasta_token: &str,
   shasta_root_cert: &[u8],
-  power_cap: Vec<PowerCapComponent>,
+  power_cap: PowerCapPatch,
 ) -> Result<PowerCapTaskInfo, Error> {
   log::info!("Create PCS power cap:\n{:#?}", power_cap);
   log::debug!("Create PCS power cap:\n{:#?}", power_cap);
@@ -171,11 +192,10 @@ pub async fn patch(
     client_builder.build()?
   };
 
-  let api_url =
-    shasta_base_url.to_owned() + "/power-control/v1/power-cap/snapshot";
+  let api_url = shasta_base_url.to_owned() + "/power-control/v1/power-cap";
 
   let response = client
-    .put(api_url)
+    .patch(api_url)
     .json(&power_cap)
     .bearer_auth(shasta_token)
     .send()
@@ -185,11 +205,104 @@ pub async fn patch(
   if response.status().is_success() {
     Ok(response.json().await.map_err(|e| Error::NetError(e))?)
   } else {
+    let status = response.status().as_u16();
     let payload = response
       .json::<Value>()
       .await
       .map_err(|e| Error::NetError(e))?;
 
-    Err(Error::CsmError(payload))
+    Err(Error::HttpError {
+      status,
+      service: Service::Pcs,
+      resource_id: None,
+      payload: payload.to_string(),
+    })
+  }
+}
+
+pub async fn post_snapshot_block(
+  shasta_base_url: &str,
+  shasta_token: &str,
+  shasta_root_cert: &[u8],
+  xname_vec: Vec<&str>,
+) -> Result<PowerCapTaskInfo, Error> {
+  let power_cap_task =
+    post_snapshot(shasta_base_url, shasta_token, shasta_root_cert, xname_vec)
+      .await?;
+
+  let task_id = power_cap_task.task_id.ok_or_else(|| {
+    Error::Message(
+      "PCS power cap snapshot did not return a task ID".to_string(),
+    )
+  })?;
+
+  log::info!("PCS power cap task ID: {}", task_id);
+
+  wait_to_complete(shasta_base_url, shasta_token, shasta_root_cert, &task_id)
+    .await
+}
+
+pub async fn patch_block(
+  shasta_base_url: &str,
+  shasta_token: &str,
+  shasta_root_cert: &[u8],
+  power_cap: PowerCapPatch,
+) -> Result<PowerCapTaskInfo, Error> {
+  let power_cap_task =
+    patch(shasta_base_url, shasta_token, shasta_root_cert, power_cap).await?;
+
+  let task_id = power_cap_task.task_id.ok_or_else(|| {
+    Error::Message("PCS power cap patch did not return a task ID".to_string())
+  })?;
+
+  log::info!("PCS power cap task ID: {}", task_id);
+
+  wait_to_complete(shasta_base_url, shasta_token, shasta_root_cert, &task_id)
+    .await
+}
+
+pub async fn wait_to_complete(
+  shasta_base_url: &str,
+  shasta_token: &str,
+  shasta_root_cert: &[u8],
+  task_id: &str,
+) -> Result<PowerCapTaskInfo, Error> {
+  let mut i = 1;
+  let max_attempt = 300;
+
+  loop {
+    let power_cap_task =
+      get_task_id(shasta_base_url, shasta_token, shasta_root_cert, task_id)
+        .await?;
+
+    let task_status = power_cap_task.task_status.clone().unwrap_or_default();
+
+    if let Some(task_counts) = &power_cap_task.task_counts {
+      eprintln!(
+        "Power cap '{}' summary - status: {}, failed: {}, in-progress: {}, succeeded: {}, total: {}. Attempt {} of {}",
+        power_cap_task.r#type.as_deref().unwrap_or("task"),
+        task_status,
+        task_counts.failed,
+        task_counts.in_progress,
+        task_counts.succeeded,
+        task_counts.total,
+        i,
+        max_attempt
+      );
+    }
+
+    if task_status == "completed" {
+      return Ok(power_cap_task);
+    }
+
+    if i >= max_attempt {
+      return Err(Error::Message(format!(
+        "PCS power cap task '{}' did not complete after {} attempts",
+        task_id, max_attempt
+      )));
+    }
+
+    tokio::time::sleep(time::Duration::from_secs(3)).await;
+    i += 1;
   }
 }
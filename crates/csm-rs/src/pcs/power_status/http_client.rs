@@ -1,3 +1,4 @@
+use manta_backend_dispatcher::error::Service;
 use serde_json::{json, Value};
 
 use crate::error::Error;
@@ -58,11 +59,17 @@ pub async fn post(
     })
   } else {
     println!("Response is failure");
+    let status = response.status().as_u16();
     let payload = response.json::<Value>().await.map_err(|error| {
       println!("{:?}", error);
       Error::NetError(error)
     })?;
 
-    Err(Error::CsmError(payload))
+    Err(Error::HttpError {
+      status,
+      service: Service::Pcs,
+      resource_id: None,
+      payload: payload.to_string(),
+    })
   }
 }
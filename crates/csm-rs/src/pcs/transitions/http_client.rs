@@ -1,5 +1,6 @@
 use std::time;
 
+use manta_backend_dispatcher::error::Service;
 use serde_json::Value;
 
 use crate::{
@@ -49,12 +50,18 @@ pub async fn get(
     serde_json::from_value::<Vec<Value>>(resp_payload["transitions"].clone())
       .map_err(|error| Error::SerdeError(error))
   } else {
+    let status = response.status().as_u16();
     let payload = response
       .json::<Value>()
       .await
       .map_err(|error| Error::NetError(error))?;
 
-    Err(Error::CsmError(payload))
+    Err(Error::HttpError {
+      status,
+      service: Service::Pcs,
+      resource_id: None,
+      payload: payload.to_string(),
+    })
   }
 }
 
@@ -101,12 +108,18 @@ pub async fn get_by_id(
 
     payload
   } else {
+    let status = response.status().as_u16();
     let payload = response
       .json::<Value>()
       .await
       .map_err(|error| Error::NetError(error))?;
 
-    Err(Error::CsmError(payload))
+    Err(Error::HttpError {
+      status,
+      service: Service::Pcs,
+      resource_id: Some(id.to_string()),
+      payload: payload.to_string(),
+    })
   }
 }
 
@@ -169,9 +182,18 @@ pub async fn post(
   if response.status().is_success() {
     Ok(response.json::<Value>().await.unwrap())
   } else {
-    let payload = response.json().await.map_err(|e| Error::NetError(e))?;
-
-    Err(Error::CsmError(payload))
+    let status = response.status().as_u16();
+    let payload = response
+      .json::<Value>()
+      .await
+      .map_err(|e| Error::NetError(e))?;
+
+    Err(Error::HttpError {
+      status,
+      service: Service::Pcs,
+      resource_id: None,
+      payload: payload.to_string(),
+    })
   }
 }
 
@@ -1,13 +1,147 @@
-use std::future::Future;
+use std::{fmt::Display, future::Future, time::Duration};
 
+use chrono::{SecondsFormat, Utc};
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
-use crate::{error::Error, types::kafka::Kafka};
+use crate::{
+  error::Error,
+  types::{
+    audit_file::AuditFile, kafka::Kafka, syslog::Syslog, webhook::Webhook,
+  },
+};
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+/// Audit sinks. Any combination of them can be enabled, each message is sent
+/// to all of them
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
 pub struct Auditor {
   pub kafka: Option<Kafka>,
-  // pub syslog: Option<Syslog>,
+  pub syslog: Option<Syslog>,
+  pub file: Option<AuditFile>,
+  pub webhook: Option<Webhook>,
+}
+
+impl Auditor {
+  pub fn is_empty(&self) -> bool {
+    self.kafka.is_none()
+      && self.syslog.is_none()
+      && self.file.is_none()
+      && self.webhook.is_none()
+  }
+}
+
+/// Version of the audit event schema. Bump it on any breaking change to
+/// `AuditEvent` so consumers can keep parsing older events
+pub const AUDIT_EVENT_VERSION: u32 = 1;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct AuditActor {
+  /// Username (JWT 'preferred_username' claim)
+  pub id: String,
+  /// Full name (JWT 'name' claim)
+  pub name: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct AuditTargets {
+  pub xnames: Vec<String>,
+  pub groups: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum AuditOutcome {
+  Success,
+  Failure,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct AuditResult {
+  pub outcome: AuditOutcome,
+  /// Error message if the operation failed
+  pub error: Option<String>,
+}
+
+/// Audit event produced by every operation changing the state of a site
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AuditEvent {
+  pub version: u32,
+  /// RFC 3339 UTC timestamp
+  pub timestamp: String,
+  pub actor: AuditActor,
+  pub site: String,
+  pub backend: String,
+  /// Dot separated '<resource>.<action>', eg 'power.off' or 'group.delete'
+  pub operation: String,
+  pub targets: AuditTargets,
+  pub parameters: Value,
+  pub result: AuditResult,
+  pub duration_ms: u64,
+}
+
+impl AuditEvent {
+  pub fn new(
+    actor: AuditActor,
+    site: &str,
+    backend: &str,
+    operation: &str,
+  ) -> Self {
+    Self {
+      version: AUDIT_EVENT_VERSION,
+      timestamp: Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true),
+      actor,
+      site: site.to_string(),
+      backend: backend.to_string(),
+      operation: operation.to_string(),
+      targets: AuditTargets::default(),
+      parameters: Value::Object(Default::default()),
+      result: AuditResult {
+        outcome: AuditOutcome::Success,
+        error: None,
+      },
+      duration_ms: 0,
+    }
+  }
+
+  pub fn with_xnames<S: AsRef<str>>(mut self, xnames: &[S]) -> Self {
+    self
+      .targets
+      .xnames
+      .extend(xnames.iter().map(|xname| xname.as_ref().to_string()));
+    self
+  }
+
+  pub fn with_groups<S: AsRef<str>>(mut self, groups: &[S]) -> Self {
+    self
+      .targets
+      .groups
+      .extend(groups.iter().map(|group| group.as_ref().to_string()));
+    self
+  }
+
+  pub fn with_parameters(mut self, parameters: Value) -> Self {
+    self.parameters = parameters;
+    self
+  }
+
+  pub fn with_result<T, E: Display>(mut self, result: &Result<T, E>) -> Self {
+    self.result = match result {
+      Ok(_) => AuditResult {
+        outcome: AuditOutcome::Success,
+        error: None,
+      },
+      Err(e) => AuditResult {
+        outcome: AuditOutcome::Failure,
+        error: Some(e.to_string()),
+      },
+    };
+    self
+  }
+
+  pub fn with_duration(mut self, duration: Duration) -> Self {
+    self.duration_ms = duration.as_millis() as u64;
+    self
+  }
 }
 
 pub trait Audit {
@@ -15,4 +149,57 @@ pub trait Audit {
     &self,
     data: &[u8],
   ) -> impl Future<Output = Result<(), Error>> + Send;
+
+  fn produce_event(
+    &self,
+    event: &AuditEvent,
+  ) -> impl Future<Output = Result<(), Error>> + Send
+  where
+    Self: Sync,
+  {
+    async move {
+      let data = serde_json::to_vec(event)?;
+      self.produce_message(&data).await
+    }
+  }
+}
+
+impl Audit for Auditor {
+  async fn produce_message(&self, data: &[u8]) -> Result<(), Error> {
+    // A failing sink must not prevent the message from reaching the others
+    let mut error_vec = Vec::new();
+
+    if let Some(kafka) = &self.kafka {
+      if let Err(e) = kafka.produce_message(data).await {
+        error_vec.push(format!("kafka: {}", e));
+      }
+    }
+
+    if let Some(syslog) = &self.syslog {
+      if let Err(e) = syslog.produce_message(data).await {
+        error_vec.push(format!("syslog: {}", e));
+      }
+    }
+
+    if let Some(file) = &self.file {
+      if let Err(e) = file.produce_message(data).await {
+        error_vec.push(format!("file: {}", e));
+      }
+    }
+
+    if let Some(webhook) = &self.webhook {
+      if let Err(e) = webhook.produce_message(data).await {
+        error_vec.push(format!("webhook: {}", e));
+      }
+    }
+
+    if error_vec.is_empty() {
+      Ok(())
+    } else {
+      Err(Error::Message(format!(
+        "Could not send audit message to: {}",
+        error_vec.join(", ")
+      )))
+    }
+  }
 }
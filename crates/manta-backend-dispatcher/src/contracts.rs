@@ -1,14 +1,35 @@
-use crate::error::Error;
+use crate::{error::Error, types::capability::Capability};
 
 pub trait BackendTrait {
   fn test_backend_trait(&self) -> String;
 
+  /// List of operations supported by this backend
+  fn get_capabilities(&self) -> Vec<Capability>;
+
+  /// Checks whether an operation is supported by this backend
+  fn is_supported(&self, capability: &Capability) -> bool {
+    self.get_capabilities().contains(capability)
+  }
+
   // AUTHORIZATION
   fn get_api_token(
     &self,
     _site_name: &str,
   ) -> impl std::future::Future<Output = Result<String, Error>> + Send;
 
+  /// Get an auth token for a user
+  fn authenticate(
+    &self,
+    _username: &str,
+    _password: &str,
+  ) -> impl std::future::Future<Output = Result<String, Error>> + Send {
+    async {
+      Err(Error::UnsupportedCapability(
+        "Authenticate command not implemented for this backend".to_string(),
+      ))
+    }
+  }
+
   /// Get list of xnames from NIDs
   /// The list of NIDs can be:
   ///     - comma separated list of NIDs (eg: nid000001,nid000002,nid000003)
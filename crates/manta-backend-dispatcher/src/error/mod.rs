@@ -0,0 +1,274 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use strum_macros::{AsRefStr, Display, EnumString};
+
+/// Backend service a request was sent to
+#[derive(
+  Debug,
+  EnumString,
+  AsRefStr,
+  Display,
+  Serialize,
+  Deserialize,
+  Clone,
+  Copy,
+  PartialEq,
+  Eq,
+)]
+pub enum Service {
+  #[strum(serialize = "HSM")]
+  Hsm,
+  #[strum(serialize = "CFS")]
+  Cfs,
+  #[strum(serialize = "BOS")]
+  Bos,
+  #[strum(serialize = "BSS")]
+  Bss,
+  #[strum(serialize = "IMS")]
+  Ims,
+  #[strum(serialize = "PCS")]
+  Pcs,
+  #[strum(serialize = "cloud-init")]
+  CloudInit,
+}
+
+/// Details of a failed request to a backend service
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServiceError {
+  /// HTTP status returned by the service. None if no response was received
+  pub status: Option<u16>,
+  pub service: Service,
+  /// Id of the resource the request was about (xname, group name, image id,
+  /// etc.)
+  pub resource_id: Option<String>,
+  pub message: String,
+}
+
+impl ServiceError {
+  pub fn new(
+    status: Option<u16>,
+    service: Service,
+    resource_id: Option<&str>,
+    message: impl Into<String>,
+  ) -> Self {
+    Self {
+      status,
+      service,
+      resource_id: resource_id.map(str::to_string),
+      message: message.into(),
+    }
+  }
+}
+
+impl fmt::Display for ServiceError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}", self.service)?;
+
+    if let Some(resource_id) = &self.resource_id {
+      write!(f, " '{}'", resource_id)?;
+    }
+
+    if let Some(status) = self.status {
+      write!(f, " (HTTP {})", status)?;
+    }
+
+    write!(f, ": {}", self.message)
+  }
+}
+
+/// Category of an error, independent of the backend it comes from. Frontends
+/// use it to pick exit codes or HTTP status codes
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+  NotFound,
+  Unauthorized,
+  Forbidden,
+  Conflict,
+  Validation,
+  Timeout,
+  Unavailable,
+  Unsupported,
+  Other,
+}
+
+impl ErrorKind {
+  pub fn from_http_status(status: u16) -> Self {
+    match status {
+      400 | 422 => ErrorKind::Validation,
+      401 => ErrorKind::Unauthorized,
+      403 => ErrorKind::Forbidden,
+      404 => ErrorKind::NotFound,
+      409 => ErrorKind::Conflict,
+      408 | 504 => ErrorKind::Timeout,
+      429 | 500 | 502 | 503 => ErrorKind::Unavailable,
+      _ => ErrorKind::Other,
+    }
+  }
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+  #[error("ERROR - Message: {0}")]
+  Message(String),
+  #[error("ERROR - IO: {0}")]
+  IoError(#[from] std::io::Error),
+  #[error("ERROR - Serde: {0}")]
+  SerdeError(#[from] serde_json::Error),
+  #[error("ERROR - http client: {0}")]
+  NetError(#[from] reqwest::Error),
+  #[error("ERROR - http request:\nresponse: {response}\npayload: {payload}")]
+  RequestError {
+    response: reqwest::Error,
+    payload: String, // NOTE: CSM/OCHAMI Apis either returns plain text or a json therefore, we
+                     // will just return a String
+  },
+  #[error("ERROR - Backend: {0}")]
+  CsmError(Value),
+  #[error("ERROR - Console: {0}")]
+  ConsoleError(String),
+  #[error("ERROR - CFS Configuration already exists: {0}")]
+  ConfigurationAlreadyExistsError(String),
+  #[error("ERROR - CFS Configuration not found: {0}")]
+  ConfigurationNotFound(String),
+  #[error("ERROR - Unsupported capability: {0}")]
+  UnsupportedCapability(String),
+  #[error("ERROR - Not found: {0}")]
+  NotFound(ServiceError),
+  #[error("ERROR - Unauthorized: {0}")]
+  Unauthorized(ServiceError),
+  #[error("ERROR - Forbidden: {0}")]
+  Forbidden(ServiceError),
+  #[error("ERROR - Conflict: {0}")]
+  Conflict(ServiceError),
+  #[error("ERROR - Validation: {0}")]
+  Validation(ServiceError),
+  #[error("ERROR - Timeout: {0}")]
+  Timeout(ServiceError),
+  #[error("ERROR - Service unavailable: {0}")]
+  Unavailable(ServiceError),
+  #[error("ERROR - Service: {0}")]
+  Other(ServiceError),
+}
+
+impl Error {
+  /// Builds the typed error matching the HTTP status returned by a backend
+  /// service
+  pub fn from_http_status(
+    status: u16,
+    service: Service,
+    resource_id: Option<&str>,
+    message: impl Into<String>,
+  ) -> Self {
+    let service_error =
+      ServiceError::new(Some(status), service, resource_id, message);
+
+    match ErrorKind::from_http_status(status) {
+      ErrorKind::Validation => Error::Validation(service_error),
+      ErrorKind::Unauthorized => Error::Unauthorized(service_error),
+      ErrorKind::Forbidden => Error::Forbidden(service_error),
+      ErrorKind::NotFound => Error::NotFound(service_error),
+      ErrorKind::Conflict => Error::Conflict(service_error),
+      ErrorKind::Timeout => Error::Timeout(service_error),
+      ErrorKind::Unavailable => Error::Unavailable(service_error),
+      _ => Error::Other(service_error),
+    }
+  }
+
+  /// Builds the typed error for a request which did not get a response from a
+  /// backend service
+  pub fn from_net_error(
+    error: reqwest::Error,
+    service: Service,
+    resource_id: Option<&str>,
+  ) -> Self {
+    if let Some(status) = error.status() {
+      return Self::from_http_status(
+        status.as_u16(),
+        service,
+        resource_id,
+        error.to_string(),
+      );
+    }
+
+    let service_error =
+      ServiceError::new(None, service, resource_id, error.to_string());
+
+    if error.is_timeout() {
+      Error::Timeout(service_error)
+    } else if error.is_connect() {
+      Error::Unavailable(service_error)
+    } else {
+      Error::Other(service_error)
+    }
+  }
+
+  /// Details of the backend service request, if the error comes from one
+  pub fn service_error(&self) -> Option<&ServiceError> {
+    match self {
+      Error::NotFound(e)
+      | Error::Unauthorized(e)
+      | Error::Forbidden(e)
+      | Error::Conflict(e)
+      | Error::Validation(e)
+      | Error::Timeout(e)
+      | Error::Unavailable(e)
+      | Error::Other(e) => Some(e),
+      _ => None,
+    }
+  }
+
+  /// HTTP status returned by the backend service, if any
+  pub fn http_status(&self) -> Option<u16> {
+    match self {
+      Error::NetError(e) => e.status().map(|status| status.as_u16()),
+      Error::RequestError { response, .. } => {
+        response.status().map(|status| status.as_u16())
+      }
+      _ => self.service_error().and_then(|e| e.status),
+    }
+  }
+
+  /// Backend service the error comes from, if known
+  pub fn service(&self) -> Option<Service> {
+    self.service_error().map(|e| e.service)
+  }
+
+  /// Id of the resource the failed request was about, if known
+  pub fn resource_id(&self) -> Option<&str> {
+    self.service_error().and_then(|e| e.resource_id.as_deref())
+  }
+
+  /// Category of the error
+  pub fn kind(&self) -> ErrorKind {
+    match self {
+      Error::NotFound(_) | Error::ConfigurationNotFound(_) => {
+        ErrorKind::NotFound
+      }
+      Error::Unauthorized(_) => ErrorKind::Unauthorized,
+      Error::Forbidden(_) => ErrorKind::Forbidden,
+      Error::Conflict(_) | Error::ConfigurationAlreadyExistsError(_) => {
+        ErrorKind::Conflict
+      }
+      Error::Validation(_) => ErrorKind::Validation,
+      Error::Timeout(_) => ErrorKind::Timeout,
+      Error::Unavailable(_) => ErrorKind::Unavailable,
+      Error::UnsupportedCapability(_) => ErrorKind::Unsupported,
+      Error::NetError(e) if e.is_timeout() => ErrorKind::Timeout,
+      Error::NetError(e) if e.is_connect() => ErrorKind::Unavailable,
+      _ => self
+        .http_status()
+        .map(ErrorKind::from_http_status)
+        .unwrap_or(ErrorKind::Other),
+    }
+  }
+
+  /// Whether repeating the same request later may succeed
+  pub fn is_retryable(&self) -> bool {
+    matches!(self.kind(), ErrorKind::Timeout | ErrorKind::Unavailable)
+  }
+}
+
+#[cfg(test)]
+mod tests;
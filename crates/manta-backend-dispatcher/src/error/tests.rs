@@ -0,0 +1,62 @@
+use super::{Error, ErrorKind, Service};
+
+#[test]
+fn test_error_kind_from_http_status() {
+  assert_eq!(ErrorKind::from_http_status(400), ErrorKind::Validation);
+  assert_eq!(ErrorKind::from_http_status(422), ErrorKind::Validation);
+  assert_eq!(ErrorKind::from_http_status(401), ErrorKind::Unauthorized);
+  assert_eq!(ErrorKind::from_http_status(403), ErrorKind::Forbidden);
+  assert_eq!(ErrorKind::from_http_status(404), ErrorKind::NotFound);
+  assert_eq!(ErrorKind::from_http_status(409), ErrorKind::Conflict);
+  assert_eq!(ErrorKind::from_http_status(408), ErrorKind::Timeout);
+  assert_eq!(ErrorKind::from_http_status(504), ErrorKind::Timeout);
+  assert_eq!(ErrorKind::from_http_status(429), ErrorKind::Unavailable);
+  assert_eq!(ErrorKind::from_http_status(500), ErrorKind::Unavailable);
+  assert_eq!(ErrorKind::from_http_status(502), ErrorKind::Unavailable);
+  assert_eq!(ErrorKind::from_http_status(503), ErrorKind::Unavailable);
+  assert_eq!(ErrorKind::from_http_status(418), ErrorKind::Other);
+  assert_eq!(ErrorKind::from_http_status(501), ErrorKind::Other);
+}
+
+#[test]
+fn test_error_from_http_status_keeps_request_details() {
+  let error =
+    Error::from_http_status(404, Service::Hsm, Some("tenant-a"), "not found");
+
+  assert!(matches!(error, Error::NotFound(_)));
+  assert_eq!(error.kind(), ErrorKind::NotFound);
+  assert_eq!(error.http_status(), Some(404));
+  assert_eq!(error.service(), Some(Service::Hsm));
+  assert_eq!(error.resource_id(), Some("tenant-a"));
+  assert!(!error.is_retryable());
+
+  let error = Error::from_http_status(503, Service::Cfs, None, "unavailable");
+
+  assert_eq!(error.kind(), ErrorKind::Unavailable);
+  assert!(error.is_retryable());
+
+  let error = Error::from_http_status(418, Service::Bss, None, "teapot");
+
+  assert!(matches!(error, Error::Other(_)));
+  assert_eq!(error.kind(), ErrorKind::Other);
+}
+
+#[test]
+fn test_error_kind_of_untyped_errors() {
+  assert_eq!(
+    Error::Message("message".to_string()).kind(),
+    ErrorKind::Other
+  );
+  assert_eq!(
+    Error::ConfigurationNotFound("configuration".to_string()).kind(),
+    ErrorKind::NotFound
+  );
+  assert_eq!(
+    Error::ConfigurationAlreadyExistsError("configuration".to_string()).kind(),
+    ErrorKind::Conflict
+  );
+  assert_eq!(
+    Error::UnsupportedCapability("capability".to_string()).kind(),
+    ErrorKind::Unsupported
+  );
+}
@@ -16,7 +16,7 @@ pub trait ApplyHwClusterPin {
     _delete_empty_parent_hsm_group: bool,
   ) -> impl Future<Output = Result<(), Error>> + Send {
     async {
-      Err(Error::Message(
+      Err(Error::UnsupportedCapability(
         "Apply hardware cluster pin command not implemented for this backend"
           .to_string(),
       ))
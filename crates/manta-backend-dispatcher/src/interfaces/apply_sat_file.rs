@@ -1,6 +1,6 @@
 use std::future::Future;
 
-use crate::error::Error;
+use crate::{error::Error, types::K8sDetails};
 
 pub trait SatTrait {
   fn apply_sat_file(
@@ -8,10 +8,8 @@ pub trait SatTrait {
     _shasta_token: &str,
     _shasta_base_url: &str,
     _shasta_root_cert: &[u8],
-    _vault_base_url: &str,
     _vault_secret_path: &str,
-    _k8s_api_url: &str,
-    _shasta_k8s_secrets: serde_json::Value,
+    _k8s: &K8sDetails,
     _sat_template_file_yaml: serde_yaml::Value,
     _hsm_group_available_vec: &Vec<String>,
     _ansible_verbosity_opt: Option<u8>,
@@ -25,7 +23,7 @@ pub trait SatTrait {
     _dry_run: bool,
   ) -> impl Future<Output = Result<(), Error>> + Send {
     async {
-      Err(Error::Message(
+      Err(Error::UnsupportedCapability(
         "Apply SAT file command not implemented for this backend".to_string(),
       ))
     }
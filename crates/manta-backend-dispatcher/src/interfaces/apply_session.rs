@@ -22,7 +22,7 @@ pub trait ApplySessionTrait {
     // _watch_logs: bool,
   ) -> impl Future<Output = Result<(String, String), Error>> + Send {
     async {
-      Err(Error::Message(
+      Err(Error::UnsupportedCapability(
         "Apply session command not implemented for this backend".to_string(),
       ))
     }
@@ -16,7 +16,7 @@ pub trait ClusterTemplateTrait {
     _bos_session_template_id_opt: Option<&str>,
   ) -> impl Future<Output = Result<Vec<BosSessionTemplate>, Error>> + Send {
     async {
-      Err(Error::Message(
+      Err(Error::UnsupportedCapability(
         "Get template command not implemented for this backend".to_string(),
       ))
     }
@@ -33,7 +33,7 @@ pub trait ClusterTemplateTrait {
     _limit_number_opt: Option<&u8>,
   ) -> impl Future<Output = Result<Vec<BosSessionTemplate>, Error>> + Send {
     async {
-      Err(Error::Message(
+      Err(Error::UnsupportedCapability(
         "Get templates command not implemented for this backend".to_string(),
       ))
     }
@@ -46,7 +46,7 @@ pub trait ClusterTemplateTrait {
     _shasta_root_cert: &[u8],
   ) -> impl Future<Output = Result<Vec<BosSessionTemplate>, Error>> + Send {
     async {
-      Err(Error::Message(
+      Err(Error::UnsupportedCapability(
         "Get all templates command not implemented for this backend"
           .to_string(),
       ))
@@ -62,7 +62,7 @@ pub trait ClusterTemplateTrait {
     _bos_template_name: &str,
   ) -> impl Future<Output = Result<BosSessionTemplate, Error>> + Send {
     async {
-      Err(Error::Message(
+      Err(Error::UnsupportedCapability(
         "Put template command not implemented for this backend".to_string(),
       ))
     }
@@ -76,7 +76,7 @@ pub trait ClusterTemplateTrait {
     _bos_template_id: &str,
   ) -> impl Future<Output = Result<(), Error>> + Send {
     async {
-      Err(Error::Message(
+      Err(Error::UnsupportedCapability(
         "Delete template command not implemented for this backend".to_string(),
       ))
     }
@@ -92,7 +92,7 @@ pub trait ClusterSessionTrait {
     _bos_session: BosSession,
   ) -> impl Future<Output = Result<BosSession, Error>> + Send {
     async {
-      Err(Error::Message(
+      Err(Error::UnsupportedCapability(
         "Create session template command not implemented for this backend"
           .to_string(),
       ))
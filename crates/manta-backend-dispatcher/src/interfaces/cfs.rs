@@ -25,7 +25,7 @@ pub trait CfsTrait {
     _k8s: &K8sDetails,
   ) -> impl Future<Output = Result<Self::T, Error>> {
     async {
-      Err(Error::Message(
+      Err(Error::UnsupportedCapability(
         "Get session logs stream command not implemented for this backend"
           .to_string(),
       ))
@@ -40,7 +40,7 @@ pub trait CfsTrait {
     _k8s: &K8sDetails,
   ) -> impl Future<Output = Result<Self::T, Error>> {
     async {
-      Err(Error::Message(
+      Err(Error::UnsupportedCapability(
                 "Get session logs stream by xname command not implemented for this backend"
                     .to_string(),
             ))
@@ -49,7 +49,7 @@ pub trait CfsTrait {
 
   fn get_cfs_health(&self) -> impl Future<Output = Result<(), Error>> {
     async {
-      Err(Error::Message(
+      Err(Error::UnsupportedCapability(
         "Get CFS health command not implemented for this backend".to_string(),
       ))
     }
@@ -63,7 +63,7 @@ pub trait CfsTrait {
     _session: &CfsSessionPostRequest,
   ) -> impl Future<Output = Result<CfsSessionGetResponse, Error>> + Send {
     async {
-      Err(Error::Message(
+      Err(Error::UnsupportedCapability(
         "Post session command not implemented for this backend".to_string(),
       ))
     }
@@ -86,7 +86,7 @@ pub trait CfsTrait {
   ) -> impl Future<Output = Result<Vec<CfsSessionGetResponse>, Error>> + Send
   {
     async {
-      Err(Error::Message(
+      Err(Error::UnsupportedCapability(
         "Get sessions command not implemented for this backend".to_string(),
       ))
     }
@@ -109,7 +109,7 @@ pub trait CfsTrait {
   ) -> impl Future<Output = Result<Vec<CfsSessionGetResponse>, Error>> + Send
   {
     async {
-      Err(Error::Message(
+      Err(Error::UnsupportedCapability(
         "Get sessions by xname command not implemented for this backend"
           .to_string(),
       ))
@@ -132,7 +132,7 @@ pub trait CfsTrait {
   ) -> impl Future<Output = Result<Vec<CfsSessionGetResponse>, Error>> + Send
   {
     async {
-      Err(Error::Message(
+      Err(Error::UnsupportedCapability(
         "Get and filter sessions command not implemented for this backend"
           .to_string(),
       ))
@@ -148,7 +148,7 @@ pub trait CfsTrait {
   ) -> impl Future<Output = Result<Vec<CfsConfigurationResponse>, Error>> + Send
   {
     async {
-      Err(Error::Message(
+      Err(Error::UnsupportedCapability(
         "Get configuration command not implemented for this backend"
           .to_string(),
       ))
@@ -169,7 +169,7 @@ pub trait CfsTrait {
   ) -> impl Future<Output = Result<Vec<CfsConfigurationResponse>, Error>> + Send
   {
     async {
-      Err(Error::Message(
+      Err(Error::UnsupportedCapability(
         "Get and filter configuration command not implemented for this backend"
           .to_string(),
       ))
@@ -185,7 +185,7 @@ pub trait CfsTrait {
     _site_name: &str, // FIXME: Should we move 'site_name' as Self.site_name?
   ) -> impl Future<Output = Result<LayerDetails, Error>> + Send {
     async {
-      Err(Error::Message(
+      Err(Error::UnsupportedCapability(
                 "Get configuration layer details command not implemented for this backend"
                     .to_string(),
             ))
@@ -202,7 +202,7 @@ pub trait CfsTrait {
     _playbook_file_name_opt: Option<&String>,
   ) -> impl Future<Output = Result<CfsConfigurationRequest, Error>> {
     async {
-      Err(Error::Message(
+      Err(Error::UnsupportedCapability(
                 "Create configuration from repos command not implemented for this backend"
                     .to_string(),
             ))
@@ -222,7 +222,7 @@ pub trait CfsTrait {
     _overwrite: bool,
   ) -> impl Future<Output = Result<CfsConfigurationResponse, Error>> + Send {
     async {
-      Err(Error::Message(
+      Err(Error::UnsupportedCapability(
         "Put configuration command not implemented for this backend"
           .to_string(),
       ))
@@ -239,7 +239,7 @@ pub trait CfsTrait {
     _enabled: bool,
   ) -> impl Future<Output = Result<(), Error>> + Send {
     async {
-      Err(Error::Message(
+      Err(Error::UnsupportedCapability(
         "Update runtime configuration command not implemented for this backend"
           .to_string(),
       ))
@@ -264,7 +264,7 @@ pub trait CfsTrait {
     >,
   > + Send {
     async {
-      Err(Error::Message(
+      Err(Error::UnsupportedCapability(
         "Get derivatives command not implemented for this backend".to_string(),
       ))
     }
@@ -280,7 +280,7 @@ pub trait CfsTrait {
     _status: Option<&str>,
   ) -> impl Future<Output = Result<Vec<Component>, Error>> + Send {
     async {
-      Err(Error::Message(
+      Err(Error::UnsupportedCapability(
         "Get CFS conponents command not implemented for this backend"
           .to_string(),
       ))
@@ -299,7 +299,7 @@ pub trait CfsTrait {
     _dry_run: bool,
   ) -> impl Future<Output = Result<(), Error>> + Send {
     async {
-      Err(Error::Message(
+      Err(Error::UnsupportedCapability(
         "Delete and cancel session command not implemented for this backend"
           .to_string(),
       ))
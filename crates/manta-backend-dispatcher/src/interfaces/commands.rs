@@ -2,10 +2,20 @@ use std::future::Future;
 
 use chrono::NaiveDateTime;
 
-use crate::error::Error;
+use crate::{
+  error::Error,
+  types::{
+    cfs::cfs_configuration_derivatives::CfsConfigurationDerivatives,
+    gc::{GcItem, GcPolicy, GcReport},
+    orphan::OrphanReport,
+  },
+};
 
 pub trait CommandsTrait {
-  fn i_delete_data_related_to_cfs_configuration(
+  /// CFS configurations related to the groups, name or name pattern together
+  /// with the CFS sessions, BOS session templates and images built from them.
+  /// Fails if any of them is used to configure or boot nodes
+  fn get_data_related_to_cfs_configuration(
     &self,
     _shasta_token: &str,
     _shasta_base_url: &str,
@@ -15,13 +25,82 @@ pub trait CommandsTrait {
     _configuration_name_pattern: Option<&String>,
     _since_opt: Option<NaiveDateTime>,
     _until_opt: Option<NaiveDateTime>,
-    _assume_yes: bool,
+  ) -> impl Future<Output = Result<CfsConfigurationDerivatives, Error>> + Send
+  {
+    async {
+      Err(Error::UnsupportedCapability(
+        "Get data related to CFS configuration command not implemented for this backend"
+          .to_string(),
+      ))
+    }
+  }
+
+  /// Deletes the CFS configurations and the data built from them returned by
+  /// `get_data_related_to_cfs_configuration`
+  fn delete_data_related_to_cfs_configuration(
+    &self,
+    _shasta_token: &str,
+    _shasta_base_url: &str,
+    _shasta_root_cert: &[u8],
+    _cfs_configuration_derivatives: &CfsConfigurationDerivatives,
   ) -> impl Future<Output = Result<(), Error>> + Send {
     async {
-      Err(Error::Message(
-                "Delete data related to CFS configuration command not implemented for this backend"
-                    .to_string(),
-            ))
+      Err(Error::UnsupportedCapability(
+        "Delete data related to CFS configuration command not implemented for this backend"
+          .to_string(),
+      ))
+    }
+  }
+
+  /// Deletes the CFS configurations, images and CFS sessions related to the
+  /// groups which the policy does not keep. Anything used by nodes or BOS
+  /// session templates is kept. Runs non-interactively
+  fn garbage_collect(
+    &self,
+    _shasta_token: &str,
+    _shasta_base_url: &str,
+    _shasta_root_cert: &[u8],
+    _hsm_group_name_vec: &[String],
+    _gc_policy: &GcPolicy,
+    _dry_run: bool,
+  ) -> impl Future<Output = Result<GcReport, Error>> + Send {
+    async {
+      Err(Error::UnsupportedCapability(
+        "Garbage collection command not implemented for this backend"
+          .to_string(),
+      ))
+    }
+  }
+
+  /// Deletes the items a garbage collection report lists to delete. Items
+  /// which could not be deleted are returned as failed
+  fn delete_garbage(
+    &self,
+    _shasta_token: &str,
+    _shasta_base_url: &str,
+    _shasta_root_cert: &[u8],
+    _gc_item_vec: &[GcItem],
+  ) -> impl Future<Output = Result<GcReport, Error>> + Send {
+    async {
+      Err(Error::UnsupportedCapability(
+        "Garbage collection command not implemented for this backend"
+          .to_string(),
+      ))
+    }
+  }
+
+  /// Cross references IMS, S3, BOS, CFS and BSS data and returns the items
+  /// nothing refers to or which refer to data that does not exist anymore
+  fn get_orphans(
+    &self,
+    _shasta_token: &str,
+    _shasta_base_url: &str,
+    _shasta_root_cert: &[u8],
+  ) -> impl Future<Output = Result<OrphanReport, Error>> + Send {
+    async {
+      Err(Error::UnsupportedCapability(
+        "Get orphans command not implemented for this backend".to_string(),
+      ))
     }
   }
 }
@@ -9,6 +9,8 @@ pub trait ConsoleTrait {
   type T: AsyncWrite + Unpin;
   type U: AsyncRead + Unpin;
 
+  /// The k8s details of the site are only needed by backends reaching the
+  /// consoles through k8s
   fn attach_to_console(
     &self,
     _shasta_token: &str,
@@ -16,13 +18,30 @@ pub trait ConsoleTrait {
     _xname: &str,
     _term_width: u16,
     _term_height: u16,
-    _k8s: &K8sDetails,
+    _k8s_opt: Option<&K8sDetails>,
   ) -> impl Future<Output = Result<(Self::T, Self::U), Error>> + Send {
     async {
-      Err(Error::Message(
+      Err(Error::UnsupportedCapability(
         "Attach to console command not implemented for this backend"
           .to_string(),
       ))
     }
   }
+
+  /// Output of a node's console. Nothing can be sent to the node, other users
+  /// can be attached to the same console at the same time
+  fn attach_to_console_read_only(
+    &self,
+    _shasta_token: &str,
+    _site_name: &str,
+    _xname: &str,
+    _k8s_opt: Option<&K8sDetails>,
+  ) -> impl Future<Output = Result<Self::U, Error>> + Send {
+    async {
+      Err(Error::UnsupportedCapability(
+        "Read-only attach to console command not implemented for this backend"
+          .to_string(),
+      ))
+    }
+  }
 }
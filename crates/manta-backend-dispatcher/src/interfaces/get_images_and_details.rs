@@ -24,7 +24,7 @@ pub trait GetImagesAndDetailsTrait {
   ) -> impl Future<Output = Result<Vec<(Image, String, String, bool)>, Error>> + Send
   {
     async {
-      Err(Error::Message(
+      Err(Error::UnsupportedCapability(
         "Get images and details command not implemented for this backend"
           .to_string(),
       ))
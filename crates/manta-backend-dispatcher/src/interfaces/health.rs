@@ -0,0 +1,20 @@
+use std::future::Future;
+
+use serde_json::Value;
+
+use crate::error::Error;
+
+pub trait HealthTrait {
+  /// Health status of a backend service, eg 'cfs' or 'bos'
+  fn get_service_health(
+    &self,
+    _auth_token: &str,
+    _service: &str,
+  ) -> impl Future<Output = Result<Value, Error>> + Send {
+    async {
+      Err(Error::UnsupportedCapability(
+        "Service health command not implemented for this backend".to_string(),
+      ))
+    }
+  }
+}
@@ -1,6 +1,8 @@
 use crate::{
   error::Error,
-  types::{Component, ComponentArrayPostArray, NodeMetadataArray},
+  types::{
+    Component, ComponentArrayPostArray, ComponentFlag, NodeMetadataArray,
+  },
 };
 use serde_json::Value;
 
@@ -54,4 +56,76 @@ pub trait ComponentTrait {
     auth_token: &str,
     id: &str,
   ) -> impl std::future::Future<Output = Result<Value, Error>> + Send;
+
+  /// Status, configuration and boot image details of a list of nodes
+  fn get_node_details(
+    &self,
+    _auth_token: &str,
+    _xname_vec: &[String],
+  ) -> impl std::future::Future<Output = Result<Value, Error>> + Send {
+    async {
+      Err(Error::UnsupportedCapability(
+        "Node details command not implemented for this backend".to_string(),
+      ))
+    }
+  }
+
+  /// Enables or disables a list of components
+  fn update_components_enabled(
+    &self,
+    _auth_token: &str,
+    _xname_vec: &[String],
+    _enabled: bool,
+  ) -> impl std::future::Future<Output = Result<(), Error>> + Send {
+    async {
+      Err(Error::UnsupportedCapability(
+        "Update component enabled command not implemented for this backend"
+          .to_string(),
+      ))
+    }
+  }
+
+  /// Sets the flag of a list of components without changing their state
+  fn update_components_flag(
+    &self,
+    _auth_token: &str,
+    _xname_vec: &[String],
+    _flag: ComponentFlag,
+  ) -> impl std::future::Future<Output = Result<(), Error>> + Send {
+    async {
+      Err(Error::UnsupportedCapability(
+        "Update component flag command not implemented for this backend"
+          .to_string(),
+      ))
+    }
+  }
+
+  fn update_components_software_status(
+    &self,
+    _auth_token: &str,
+    _xname_vec: &[String],
+    _software_status: &str,
+  ) -> impl std::future::Future<Output = Result<(), Error>> + Send {
+    async {
+      Err(Error::UnsupportedCapability(
+        "Update component software status command not implemented for this backend"
+          .to_string(),
+      ))
+    }
+  }
+
+  fn update_components_role(
+    &self,
+    _auth_token: &str,
+    _xname_vec: &[String],
+    _role: &str,
+    _sub_role: Option<&str>,
+  ) -> impl std::future::Future<Output = Result<(), Error>> + Send {
+    async {
+      Err(Error::UnsupportedCapability(
+        "Update component role command not implemented for this backend"
+          .to_string(),
+      ))
+    }
+  }
 }
@@ -0,0 +1,84 @@
+use std::future::Future;
+
+use crate::{
+  error::Error,
+  types::hsm::inventory::{EthernetInterface, IpAddressMapping},
+};
+
+pub trait EthernetInterfaceTrait {
+  /// Get ethernet interfaces filtered by MAC address, IP address, network,
+  /// component (xname) and/or component type
+  fn get_ethernet_interfaces(
+    &self,
+    _auth_token: &str,
+    _mac_address: Option<&str>,
+    _ip_address: Option<&str>,
+    _network: Option<&str>,
+    _component_id: Option<&str>,
+    _type: Option<&str>,
+  ) -> impl Future<Output = Result<Vec<EthernetInterface>, Error>> + Send {
+    async {
+      Err(Error::UnsupportedCapability(
+        "Get ethernet interfaces command not implemented for this backend"
+          .to_string(),
+      ))
+    }
+  }
+
+  fn get_ethernet_interface(
+    &self,
+    _auth_token: &str,
+    _eth_interface_id: &str,
+  ) -> impl Future<Output = Result<EthernetInterface, Error>> + Send {
+    async {
+      Err(Error::UnsupportedCapability(
+        "Get ethernet interface command not implemented for this backend"
+          .to_string(),
+      ))
+    }
+  }
+
+  fn add_ethernet_interface(
+    &self,
+    _auth_token: &str,
+    _ethernet_interface: &EthernetInterface,
+  ) -> impl Future<Output = Result<(), Error>> + Send {
+    async {
+      Err(Error::UnsupportedCapability(
+        "Add ethernet interface command not implemented for this backend"
+          .to_string(),
+      ))
+    }
+  }
+
+  /// Update description, component and/or IP addresses of an ethernet
+  /// interface. Fields set to None are left untouched
+  fn update_ethernet_interface(
+    &self,
+    _auth_token: &str,
+    _eth_interface_id: &str,
+    _description: Option<&str>,
+    _component_id: Option<&str>,
+    _ip_address_vec_opt: Option<&[IpAddressMapping]>,
+  ) -> impl Future<Output = Result<(), Error>> + Send {
+    async {
+      Err(Error::UnsupportedCapability(
+        "Update ethernet interface command not implemented for this backend"
+          .to_string(),
+      ))
+    }
+  }
+
+  fn delete_ethernet_interface(
+    &self,
+    _auth_token: &str,
+    _eth_interface_id: &str,
+  ) -> impl Future<Output = Result<(), Error>> + Send {
+    async {
+      Err(Error::UnsupportedCapability(
+        "Delete ethernet interface command not implemented for this backend"
+          .to_string(),
+      ))
+    }
+  }
+}
@@ -1,6 +1,11 @@
 use serde_json::Value;
 
-use crate::{error::Error, types::HWInventoryByLocationList};
+use crate::{
+  error::Error,
+  types::{
+    hsm::inventory::HWInvHistArray, HWInventoryByFRU, HWInventoryByLocationList,
+  },
+};
 
 pub trait HardwareInventory {
   fn post_inventory_hardware(
@@ -25,4 +30,40 @@ pub trait HardwareInventory {
     partition: Option<&str>,
     format: Option<&str>,
   ) -> impl std::future::Future<Output = Result<Value, Error>> + Send;
+
+  /// FRUs filtered by FRU id, type, manufacturer, part number or serial
+  /// number
+  fn get_inventory_hardware_by_fru(
+    &self,
+    _auth_token: &str,
+    _fru_id: Option<&str>,
+    _type: Option<&str>,
+    _manufacturer: Option<&str>,
+    _part_number: Option<&str>,
+    _serial_number: Option<&str>,
+  ) -> impl std::future::Future<Output = Result<Vec<HWInventoryByFRU>, Error>> + Send
+  {
+    async {
+      Err(Error::UnsupportedCapability(
+        "Get hardware FRU command not implemented for this backend".to_string(),
+      ))
+    }
+  }
+
+  /// Events of a FRU, each event has the xname the FRU was in at the time
+  fn get_inventory_hardware_history_by_fru(
+    &self,
+    _auth_token: &str,
+    _fru_id: &str,
+    _start_time: Option<&str>,
+    _end_time: Option<&str>,
+  ) -> impl std::future::Future<Output = Result<HWInvHistArray, Error>> + Send
+  {
+    async {
+      Err(Error::UnsupportedCapability(
+        "Get hardware FRU history command not implemented for this backend"
+          .to_string(),
+      ))
+    }
+  }
 }
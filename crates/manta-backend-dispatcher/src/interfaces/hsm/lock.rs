@@ -0,0 +1,48 @@
+use std::future::Future;
+
+use crate::{
+  error::Error,
+  types::hsm::lock::{ComponentLockStatus, LockResult},
+};
+
+pub trait LockTrait {
+  /// Lock and reservation status of a list of components
+  fn get_locks_status(
+    &self,
+    _auth_token: &str,
+    _xname_vec: &[String],
+  ) -> impl Future<Output = Result<Vec<ComponentLockStatus>, Error>> + Send {
+    async {
+      Err(Error::UnsupportedCapability(
+        "Get locks command not implemented for this backend".to_string(),
+      ))
+    }
+  }
+
+  /// Locks a list of components. Either all components get locked or none
+  fn lock_components(
+    &self,
+    _auth_token: &str,
+    _xname_vec: &[String],
+  ) -> impl Future<Output = Result<LockResult, Error>> + Send {
+    async {
+      Err(Error::UnsupportedCapability(
+        "Lock command not implemented for this backend".to_string(),
+      ))
+    }
+  }
+
+  /// Unlocks a list of components. Components not locked are reported as
+  /// failures and do not stop the others from being unlocked
+  fn unlock_components(
+    &self,
+    _auth_token: &str,
+    _xname_vec: &[String],
+  ) -> impl Future<Output = Result<LockResult, Error>> + Send {
+    async {
+      Err(Error::UnsupportedCapability(
+        "Unlock command not implemented for this backend".to_string(),
+      ))
+    }
+  }
+}
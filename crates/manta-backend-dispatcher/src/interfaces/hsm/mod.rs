@@ -1,4 +1,8 @@
 pub mod component;
+pub mod ethernet_interfaces;
 pub mod group;
 pub mod hardware_inventory;
+pub mod lock;
+pub mod node_map;
+pub mod partition;
 pub mod redfish_endpoint;
@@ -0,0 +1,53 @@
+use std::future::Future;
+
+use crate::{error::Error, types::hsm::node_map::NodeMap};
+
+pub trait NodeMapTrait {
+  fn get_node_maps(
+    &self,
+    _auth_token: &str,
+  ) -> impl Future<Output = Result<Vec<NodeMap>, Error>> + Send {
+    async {
+      Err(Error::UnsupportedCapability(
+        "Get node maps command not implemented for this backend".to_string(),
+      ))
+    }
+  }
+
+  fn get_node_map(
+    &self,
+    _auth_token: &str,
+    _xname: &str,
+  ) -> impl Future<Output = Result<NodeMap, Error>> + Send {
+    async {
+      Err(Error::UnsupportedCapability(
+        "Get node map command not implemented for this backend".to_string(),
+      ))
+    }
+  }
+
+  /// Creates or updates a list of node maps
+  fn add_node_maps(
+    &self,
+    _auth_token: &str,
+    _node_map_vec: &[NodeMap],
+  ) -> impl Future<Output = Result<(), Error>> + Send {
+    async {
+      Err(Error::UnsupportedCapability(
+        "Add node maps command not implemented for this backend".to_string(),
+      ))
+    }
+  }
+
+  fn delete_node_map(
+    &self,
+    _auth_token: &str,
+    _xname: &str,
+  ) -> impl Future<Output = Result<(), Error>> + Send {
+    async {
+      Err(Error::UnsupportedCapability(
+        "Delete node map command not implemented for this backend".to_string(),
+      ))
+    }
+  }
+}
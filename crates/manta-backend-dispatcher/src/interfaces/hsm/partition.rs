@@ -0,0 +1,83 @@
+use std::future::Future;
+
+use crate::{error::Error, types::hsm::partition::Partition};
+
+pub trait PartitionTrait {
+  fn get_partitions(
+    &self,
+    _auth_token: &str,
+    _name: Option<&str>,
+    _tag: Option<&str>,
+  ) -> impl Future<Output = Result<Vec<Partition>, Error>> + Send {
+    async {
+      Err(Error::UnsupportedCapability(
+        "Get partitions command not implemented for this backend".to_string(),
+      ))
+    }
+  }
+
+  fn get_partition(
+    &self,
+    _auth_token: &str,
+    _name: &str,
+  ) -> impl Future<Output = Result<Partition, Error>> + Send {
+    async {
+      Err(Error::UnsupportedCapability(
+        "Get partition command not implemented for this backend".to_string(),
+      ))
+    }
+  }
+
+  fn add_partition(
+    &self,
+    _auth_token: &str,
+    _partition: &Partition,
+  ) -> impl Future<Output = Result<(), Error>> + Send {
+    async {
+      Err(Error::UnsupportedCapability(
+        "Add partition command not implemented for this backend".to_string(),
+      ))
+    }
+  }
+
+  fn delete_partition(
+    &self,
+    _auth_token: &str,
+    _name: &str,
+  ) -> impl Future<Output = Result<(), Error>> + Send {
+    async {
+      Err(Error::UnsupportedCapability(
+        "Delete partition command not implemented for this backend".to_string(),
+      ))
+    }
+  }
+
+  /// Add a list of xnames to a partition. Returns the list of xnames added
+  fn add_members_to_partition(
+    &self,
+    _auth_token: &str,
+    _name: &str,
+    _members: &[String],
+  ) -> impl Future<Output = Result<Vec<String>, Error>> + Send {
+    async {
+      Err(Error::UnsupportedCapability(
+        "Add members to partition command not implemented for this backend"
+          .to_string(),
+      ))
+    }
+  }
+
+  fn delete_member_from_partition(
+    &self,
+    _auth_token: &str,
+    _name: &str,
+    _xname: &str,
+  ) -> impl Future<Output = Result<(), Error>> + Send {
+    async {
+      Err(Error::UnsupportedCapability(
+        "Delete member from partition command not implemented for this backend"
+          .to_string(),
+      ))
+    }
+  }
+}
@@ -11,7 +11,7 @@ pub trait ImsTrait {
     _image_id_opt: Option<&str>,
   ) -> impl Future<Output = Result<Vec<Image>, Error>> + Send {
     async {
-      Err(Error::Message(
+      Err(Error::UnsupportedCapability(
         "Get images command not implemented for this backend".to_string(),
       ))
     }
@@ -24,14 +24,14 @@ pub trait ImsTrait {
     _shasta_root_cert: &[u8],
   ) -> impl Future<Output = Result<Vec<Image>, Error>> + Send {
     async {
-      Err(Error::Message(
+      Err(Error::UnsupportedCapability(
         "Get all images command not implemented for this backend".to_string(),
       ))
     }
   }
 
   fn filter_images(&self, _image_vec: &mut Vec<Image>) -> Result<(), Error> {
-    Err(Error::Message(
+    Err(Error::UnsupportedCapability(
       "Filter images command not implemented for this backend".to_string(),
     ))
   }
@@ -44,7 +44,7 @@ pub trait ImsTrait {
     _image_id: &str,
   ) -> impl Future<Output = Result<(), Error>> + Send {
     async {
-      Err(Error::Message(
+      Err(Error::UnsupportedCapability(
         "Delete image command not implemented for this backend".to_string(),
       ))
     }
@@ -8,11 +8,13 @@ pub trait MigrateBackupTrait {
     _shasta_token: &str,
     _shasta_base_url: &str,
     _shasta_root_cert: &[u8],
-    _bos: Option<&String>,
-    _destination: Option<&String>,
+    _bos_sessiontemplate_name_vec: &[String],
+    _hsm_group_name_vec: &[String],
+    _destination: &str,
+    _concurrency: usize,
   ) -> impl Future<Output = Result<(), Error>> + Send {
     async {
-      Err(Error::Message(
+      Err(Error::UnsupportedCapability(
         "Migrate/backup command not implemented for this backend".to_string(),
       ))
     }
@@ -3,19 +3,21 @@ use std::future::Future;
 use crate::error::Error;
 
 pub trait MigrateRestoreTrait {
+  /// Existing HSM groups with different members than the backup are only
+  /// replaced if `overwrite_groups` is set, even if `assume_yes` is
   fn migrate_restore(
     &self,
     _shasta_token: &str,
     _shasta_base_url: &str,
     _shasta_root_cert: &[u8],
-    _bos_file: Option<&String>,
-    _cfs_file: Option<&String>,
-    _hsm_file: Option<&String>,
-    _ims_file: Option<&String>,
-    _image_dir: Option<&String>,
+    _backup_dir: &str,
+    _bos_sessiontemplate_name_vec: &[String],
+    _concurrency: usize,
+    _assume_yes: bool,
+    _overwrite_groups: bool,
   ) -> impl Future<Output = Result<(), Error>> + Send {
     async {
-      Err(Error::Message(
+      Err(Error::UnsupportedCapability(
         "Migrate/restore command not implemented for this backend".to_string(),
       ))
     }
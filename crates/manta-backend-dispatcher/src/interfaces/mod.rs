@@ -7,6 +7,7 @@ pub mod cfs;
 pub mod commands;
 pub mod console;
 pub mod get_images_and_details;
+pub mod health;
 pub mod hsm;
 pub mod ims;
 pub mod migrate_backup;
@@ -1,6 +1,9 @@
 use serde_json::Value;
 
 use crate::error::Error;
+use crate::types::pcs::power_cap::types::{
+  PowerCapPatch, PowerCapTaskInfo, PowerCapTaskList,
+};
 use crate::types::pcs::power_status::types::PowerStatusAll;
 
 pub trait PCSTrait {
@@ -35,9 +38,84 @@ pub trait PCSTrait {
   ) -> impl std::future::Future<Output = Result<PowerStatusAll, Error>> + Send
   {
     async {
-      Err(Error::Message(
+      Err(Error::UnsupportedCapability(
         "Power status command not implemented for this backend".to_string(),
       ))
     }
   }
+
+  /// Get list of power cap tasks
+  fn get_power_cap_task_vec(
+    &self,
+    _auth_token: &str,
+  ) -> impl std::future::Future<Output = Result<PowerCapTaskList, Error>> + Send
+  {
+    async {
+      Err(Error::UnsupportedCapability(
+        "Get power cap tasks command not implemented for this backend"
+          .to_string(),
+      ))
+    }
+  }
+
+  /// Get a power cap task by id
+  fn get_power_cap_task(
+    &self,
+    _auth_token: &str,
+    _task_id: &str,
+  ) -> impl std::future::Future<Output = Result<PowerCapTaskInfo, Error>> + Send
+  {
+    async {
+      Err(Error::UnsupportedCapability(
+        "Get power cap task command not implemented for this backend"
+          .to_string(),
+      ))
+    }
+  }
+
+  /// Request a snapshot of the power cap values of a list of nodes. Returns the power cap task
+  /// without waiting for it to complete
+  fn power_cap_snapshot(
+    &self,
+    _auth_token: &str,
+    _nodes: &[String],
+  ) -> impl std::future::Future<Output = Result<PowerCapTaskInfo, Error>> + Send
+  {
+    async {
+      Err(Error::UnsupportedCapability(
+        "Power cap snapshot command not implemented for this backend"
+          .to_string(),
+      ))
+    }
+  }
+
+  /// Request a snapshot of the power cap values of a list of nodes and wait for the task to
+  /// complete
+  fn power_cap_snapshot_sync(
+    &self,
+    _auth_token: &str,
+    _nodes: &[String],
+  ) -> impl std::future::Future<Output = Result<PowerCapTaskInfo, Error>> + Send
+  {
+    async {
+      Err(Error::UnsupportedCapability(
+        "Power cap snapshot command not implemented for this backend"
+          .to_string(),
+      ))
+    }
+  }
+
+  /// Apply power cap values to a list of nodes and wait for the task to complete
+  fn power_cap_patch_sync(
+    &self,
+    _auth_token: &str,
+    _power_cap: &PowerCapPatch,
+  ) -> impl std::future::Future<Output = Result<PowerCapTaskInfo, Error>> + Send
+  {
+    async {
+      Err(Error::UnsupportedCapability(
+        "Power cap set command not implemented for this backend".to_string(),
+      ))
+    }
+  }
 }
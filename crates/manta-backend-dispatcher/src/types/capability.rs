@@ -0,0 +1,67 @@
+use serde::{Deserialize, Serialize};
+use strum_macros::{AsRefStr, Display, EnumIter, EnumString, IntoStaticStr};
+
+/// Operations a backend may or may not support. Frontends query
+/// `BackendTrait::get_capabilities` to find out which ones are available
+/// before calling into the backend.
+#[derive(
+  Debug,
+  EnumIter,
+  EnumString,
+  IntoStaticStr,
+  AsRefStr,
+  Display,
+  Serialize,
+  Deserialize,
+  Clone,
+  Copy,
+  PartialEq,
+  Eq,
+  Hash,
+)]
+#[strum(serialize_all = "kebab-case")]
+#[serde(rename_all = "kebab-case")]
+pub enum Capability {
+  // HSM
+  Groups,
+  Components,
+  NodeDetails,
+  ComponentState,
+  HardwareInventory,
+  FruInventory,
+  Partitions,
+  EthernetInterfaces,
+  RedfishEndpoints,
+  NodeMaps,
+  Locks,
+  // BSS
+  BootParameters,
+  BootParametersDelete,
+  // PCS
+  PowerManagement,
+  PowerCapping,
+  // CFS
+  Configurations,
+  ConfigurationsDelete,
+  Sessions,
+  RuntimeConfiguration,
+  // IMS
+  Images,
+  ImagesDelete,
+  // BOS
+  ClusterTemplates,
+  ClusterSessions,
+  // Workflows
+  SatFile,
+  ApplySession,
+  HwClusterPin,
+  MigrateBackup,
+  MigrateRestore,
+  GarbageCollection,
+  Orphans,
+  // Console
+  Console,
+  ConsoleReadOnly,
+  // Health
+  ServiceHealth,
+}
@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+
+/// CFS configurations to delete together with the data built from them
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct CfsConfigurationDerivatives {
+  /// Name and last update of the CFS configurations
+  pub cfs_configuration_vec: Vec<(String, String)>,
+  /// Name, CFS configuration and image id of the CFS sessions which built an
+  /// image
+  pub cfs_session_vec: Vec<(String, String, String)>,
+  /// Name, CFS configuration and image id of the BOS session templates
+  pub bos_sessiontemplate_vec: Vec<(String, String, String)>,
+  pub image_id_vec: Vec<String>,
+}
+
+impl CfsConfigurationDerivatives {
+  pub fn is_empty(&self) -> bool {
+    self.cfs_configuration_vec.is_empty()
+      && self.cfs_session_vec.is_empty()
+      && self.bos_sessiontemplate_vec.is_empty()
+      && self.image_id_vec.is_empty()
+  }
+}
@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use super::cfs_configuration_response::CfsConfigurationResponse;
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Layer {
   #[serde(skip_serializing_if = "Option::is_none")]
@@ -87,3 +89,49 @@ impl CfsConfigurationRequest {
     }
   }
 }
+
+/// Request to create a CFS configuration identical to an existing one
+impl From<CfsConfigurationResponse> for CfsConfigurationRequest {
+  fn from(cfs_configuration: CfsConfigurationResponse) -> Self {
+    let layers = cfs_configuration
+      .layers
+      .into_iter()
+      .map(|layer| {
+        // Layers pinned to a commit are restored to the same commit even if
+        // they were created from a branch
+        let branch = if layer.commit.is_some() {
+          None
+        } else {
+          layer.branch
+        };
+
+        Layer::new(
+          Some(layer.name),
+          Some(layer.clone_url).filter(|clone_url| !clone_url.is_empty()),
+          layer.source,
+          layer.playbook,
+          layer.commit,
+          branch,
+          None,
+        )
+      })
+      .collect();
+
+    let additional_inventory =
+      cfs_configuration
+        .additional_inventory
+        .map(|additional_inventory| AdditionalInventory {
+          name: Some(additional_inventory.name),
+          clone_url: additional_inventory.clone_url,
+          source: None,
+          commit: additional_inventory.commit,
+          branch: additional_inventory.branch,
+        });
+
+    Self {
+      description: None,
+      layers: Some(layers),
+      additional_inventory,
+    }
+  }
+}
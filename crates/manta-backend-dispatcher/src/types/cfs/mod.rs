@@ -1,3 +1,4 @@
+pub mod cfs_configuration_derivatives;
 pub mod cfs_configuration_details;
 pub mod cfs_configuration_request;
 pub mod cfs_configuration_response;
@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+use strum_macros::Display;
+
+/// Garbage collection policy. Rules not set are not applied
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(deny_unknown_fields)]
+pub struct GcPolicy {
+  /// Number of most recent CFS configurations to keep per group
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub keep_configurations: Option<usize>,
+  /// Number of most recent images to keep per group
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub keep_images: Option<usize>,
+  /// Failed CFS sessions older than this are deleted. Same format as CFS
+  /// 'min_age', eg '1d' or '6h'
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub failed_session_min_age: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Display)]
+pub enum GcItemKind {
+  Configuration,
+  Image,
+  Session,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct GcItem {
+  pub kind: GcItemKind,
+  /// Configuration or session name, or image id
+  pub id: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub name: Option<String>,
+  /// Last update for configurations, creation date for images and start time
+  /// for sessions
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub date: Option<String>,
+  /// Why the item is deleted, kept or could not be deleted
+  pub reason: String,
+}
+
+/// Result of a garbage collection. With dry-run `delete` are the items that
+/// would be deleted
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct GcReport {
+  pub delete: Vec<GcItem>,
+  /// Items the policy would delete but are still in use
+  pub keep: Vec<GcItem>,
+  pub failed: Vec<GcItem>,
+}
@@ -73,3 +73,61 @@ pub struct RedfishEndpointArray {
   #[serde(skip_serializing_if = "Option::is_none")]
   pub redfish_endpoints: Option<Vec<RedfishEndpoint>>,
 }
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct IpAddressMapping {
+  #[serde(rename = "IPAddress")]
+  pub ip_address: String,
+  #[serde(rename = "Network")]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub network: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct EthernetInterface {
+  #[serde(rename = "ID")]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub id: Option<String>,
+  #[serde(rename = "Description")]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub description: Option<String>,
+  #[serde(rename = "MACAddress")]
+  pub mac_address: String,
+  #[serde(rename = "LastUpdate")]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub last_update: Option<String>,
+  #[serde(rename = "ComponentID")]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub component_id: Option<String>,
+  #[serde(rename = "Type")]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub r#type: Option<String>,
+  #[serde(rename = "IPAddresses")]
+  #[serde(default)]
+  pub ip_addresses: Vec<IpAddressMapping>,
+}
+
+/// Event in the history of a FRU or a location, eg a DIMM 'Added' to a node
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct HWInvHist {
+  /// xname of the location
+  #[serde(rename = "ID")]
+  pub id: String,
+  #[serde(rename = "FRUID")]
+  pub fru_id: String,
+  #[serde(rename = "Timestamp")]
+  pub timestamp: String,
+  /// 'Added', 'Removed', 'Scanned' or 'Detected'
+  #[serde(rename = "EventType")]
+  pub event_type: String,
+}
+
+/// History of a FRU (or of a location), `id` is the FRU id (or the xname)
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct HWInvHistArray {
+  #[serde(rename = "ID")]
+  pub id: String,
+  #[serde(rename = "History")]
+  #[serde(default)]
+  pub history: Vec<HWInvHist>,
+}
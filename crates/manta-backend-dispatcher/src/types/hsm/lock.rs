@@ -0,0 +1,30 @@
+use serde::{Deserialize, Serialize};
+
+// From CSM API
+/// Lock and reservation status of a component
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ComponentLockStatus {
+  /// xname of the component
+  pub id: String,
+  pub locked: bool,
+  pub reserved: bool,
+  pub reservation_disabled: bool,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub creation_time: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub expiration_time: Option<String>,
+}
+
+/// Components locked or unlocked and the ones that failed
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct LockResult {
+  pub success: Vec<String>,
+  pub failure: Vec<LockFailure>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct LockFailure {
+  /// xname of the component
+  pub id: String,
+  pub reason: String,
+}
@@ -1 +1,4 @@
 pub mod inventory;
+pub mod lock;
+pub mod node_map;
+pub mod partition;
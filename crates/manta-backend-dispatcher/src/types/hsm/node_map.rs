@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+// From OCHAMI API
+/// Default NID and role HSM assigns to a node when it is discovered
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct NodeMap {
+  /// xname of the node
+  pub id: String,
+  pub nid: u32,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub role: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub sub_role: Option<String>,
+}
+
+impl NodeMap {
+  /// Constructor
+  pub fn new(
+    id: &str,
+    nid: u32,
+    role: Option<String>,
+    sub_role: Option<String>,
+  ) -> Self {
+    Self {
+      id: id.to_string(),
+      nid,
+      role,
+      sub_role,
+    }
+  }
+}
@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+
+use crate::types::Member;
+
+// From OCHAMI API
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Partition {
+  pub name: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub description: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub tags: Option<Vec<String>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub members: Option<Member>,
+}
+
+impl Partition {
+  /// Constructor
+  pub fn new(
+    name: &str,
+    description: Option<String>,
+    member_vec_opt: Option<Vec<String>>,
+    tag_vec_opt: Option<Vec<String>>,
+  ) -> Self {
+    let members_opt = member_vec_opt.map(|member_vec| Member {
+      ids: Some(member_vec),
+    });
+
+    Self {
+      name: name.to_string(),
+      description,
+      tags: tag_vec_opt,
+      members: members_opt,
+    }
+  }
+
+  /// Get partition members
+  pub fn get_members(&self) -> Vec<String> {
+    self
+      .members
+      .as_ref()
+      .and_then(|members| members.ids.clone())
+      .unwrap_or_default()
+  }
+}
@@ -1,18 +1,23 @@
+pub mod audit_file;
 pub mod bos;
 pub mod bss;
+pub mod capability;
 pub mod cfs;
+pub mod gc;
 pub mod hsm;
 pub mod ims;
 pub mod kafka;
+pub mod orphan;
 pub mod pcs;
+pub mod syslog;
+pub mod webhook;
 
-use std::str::FromStr;
+use std::{collections::HashMap, str::FromStr};
 
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use strum_macros::{AsRefStr, Display, EnumIter, EnumString, IntoStaticStr};
 
-
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub enum K8sAuth {
   #[serde(rename = "native")]
@@ -23,11 +28,29 @@ pub enum K8sAuth {
   },
   #[serde(rename = "vault")]
   Vault { base_url: String },
+  /// Context in a kubeconfig file, the current context of the file if not
+  /// set. The API URL is taken from the kubeconfig file
+  #[serde(rename = "kubeconfig")]
+  Kubeconfig {
+    path: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    context: Option<String>,
+  },
+  /// Service account of the pod manta runs in. The API URL is taken from the
+  /// pod environment
+  #[serde(rename = "in_cluster")]
+  InCluster,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct K8sDetails {
+  /// Not used with 'kubeconfig' and 'in_cluster' authentication
+  #[serde(default)]
   pub api_url: String,
+  /// Name the k8s API server certificate is checked against. Defaults to the
+  /// host in 'api_url'
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub tls_server_name: Option<String>,
   pub authentication: K8sAuth,
 }
 
@@ -883,6 +906,27 @@ pub struct ComponentPut {
   pub force: Option<bool>,
 }
 
+/// Flag of a component in HSM
+#[derive(
+  Debug,
+  EnumIter,
+  EnumString,
+  IntoStaticStr,
+  AsRefStr,
+  Display,
+  Serialize,
+  Deserialize,
+  Clone,
+  Copy,
+  PartialEq,
+)]
+pub enum ComponentFlag {
+  OK,
+  Warning,
+  Alert,
+  Locked,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub enum ComponentType {
   CDU,
@@ -1095,6 +1139,33 @@ pub struct HWInventoryByFRU {
   pub fru_sub_type: Option<String>,
   #[serde(rename = "HWInventoryByFRUType")]
   pub hw_inventory_by_fru_type: String,
+  /// FRU details. The key depends on the FRU type, eg 'MemoryFRUInfo' or
+  /// 'ProcessorFRUInfo'
+  #[serde(flatten)]
+  pub fru_info: HashMap<String, Value>,
+}
+
+impl HWInventoryByFRU {
+  /// Field from the FRU details, eg 'SerialNumber' or 'PartNumber'
+  pub fn get_fru_info_field(&self, field: &str) -> Option<&str> {
+    self
+      .fru_info
+      .iter()
+      .filter(|(key, _)| key.ends_with("FRUInfo"))
+      .find_map(|(_, fru_info)| fru_info.get(field)?.as_str())
+  }
+
+  pub fn get_serial_number(&self) -> Option<&str> {
+    self.get_fru_info_field("SerialNumber")
+  }
+
+  pub fn get_part_number(&self) -> Option<&str> {
+    self.get_fru_info_field("PartNumber")
+  }
+
+  pub fn get_manufacturer(&self) -> Option<&str> {
+    self.get_fru_info_field("Manufacturer")
+  }
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
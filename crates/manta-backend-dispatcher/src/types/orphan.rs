@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+use strum_macros::Display;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Display)]
+pub enum OrphanKind {
+  Image,
+  S3BootImage,
+  SessionTemplate,
+  Configuration,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Orphan {
+  pub kind: OrphanKind,
+  /// Image id, BOS session template name or CFS configuration name
+  pub id: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub name: Option<String>,
+  /// Creation date for images and last update for configurations
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub date: Option<String>,
+  /// Why the item is an orphan
+  pub reason: String,
+}
+
+/// Data across IMS, S3, BOS, CFS and BSS nothing else refers to or which
+/// refers to data that does not exist anymore
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct OrphanReport {
+  /// IMS images not referenced by any BSS boot parameters, BOS session
+  /// template or CFS session
+  pub images: Vec<Orphan>,
+  /// Images in the S3 'boot-images' bucket without an IMS record
+  pub s3_boot_images: Vec<Orphan>,
+  /// BOS session templates pointing to images which do not exist in IMS
+  pub session_templates: Vec<Orphan>,
+  /// CFS configurations not used by any node, CFS session or BOS session
+  /// template
+  pub configurations: Vec<Orphan>,
+}
+
+impl OrphanReport {
+  pub fn is_empty(&self) -> bool {
+    self.images.is_empty()
+      && self.s3_boot_images.is_empty()
+      && self.session_templates.is_empty()
+      && self.configurations.is_empty()
+  }
+}
@@ -1,4 +1,3 @@
+pub mod power_cap;
 pub mod power_status;
 pub mod transitions;
-
-
@@ -0,0 +1,102 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PowerCapTaskList {
+  pub tasks: Vec<PowerCapTaskInfo>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct TaskCounts {
+  pub total: usize,
+  pub new: usize,
+  #[serde(rename = "in-progress")]
+  pub in_progress: usize,
+  pub failed: usize,
+  pub succeeded: usize,
+  #[serde(rename = "un-supported")]
+  pub un_supported: usize,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Limit {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  #[serde(rename = "hostLimitMax")]
+  pub host_limit_max: Option<usize>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  #[serde(rename = "hostLimitMin")]
+  pub host_limit_min: Option<usize>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  #[serde(rename = "powerupPower")]
+  pub powerup_power: Option<usize>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PowerCapLimit {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub name: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  #[serde(rename = "currentValue")]
+  pub current_value: Option<usize>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  #[serde(rename = "maximumValue")]
+  pub maximum_value: Option<usize>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  #[serde(rename = "minimumValue")]
+  pub minimum_value: Option<usize>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PowerCapComponent {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub xname: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub error: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub limits: Option<Limit>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  #[serde(rename = "powerCapLimits")]
+  pub power_cap_limits: Option<Vec<PowerCapLimit>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PowerCapTaskInfo {
+  #[serde(skip_serializing_if = "Option::is_none")]
+  #[serde(rename = "taskID", alias = "taskId")]
+  pub task_id: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub r#type: Option<String>, // TODO: convert to enum. Valid values are `snapshot` and `patch`
+  #[serde(skip_serializing_if = "Option::is_none")]
+  #[serde(rename = "taskCreateTime")]
+  pub task_create_time: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  #[serde(rename = "automaticExpirationTime")]
+  pub automatic_expiration_time: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  #[serde(rename = "taskStatus")]
+  pub task_status: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  #[serde(rename = "taskCounts")]
+  pub task_counts: Option<TaskCounts>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub components: Option<Vec<PowerCapComponent>>,
+}
+
+/// Power cap value to apply to a control (eg 'Node Power Limit') of a component
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PowerCapControl {
+  pub name: String,
+  pub value: usize,
+}
+
+/// Power cap values to apply to a component (node)
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PowerCapPatchComponent {
+  pub xname: String,
+  pub controls: Vec<PowerCapControl>,
+}
+
+/// Payload for PCS 'PATCH /power-cap'
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PowerCapPatch {
+  pub components: Vec<PowerCapPatchComponent>,
+}
@@ -0,0 +1,81 @@
+use chrono::{SecondsFormat, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::{
+  io::AsyncWriteExt,
+  net::{TcpStream, UdpSocket},
+};
+
+use crate::{audit::Audit, error::Error};
+
+// Facility 13 (log audit) and severity 6 (informational) -> 13 * 8 + 6
+const SYSLOG_PRI: u8 = 110;
+
+#[derive(
+  Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum SyslogProtocol {
+  #[default]
+  Udp,
+  Tcp,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Syslog {
+  pub server: String,
+  pub port: u16,
+  #[serde(default)]
+  pub protocol: SyslogProtocol,
+  pub app_name: Option<String>,
+}
+
+impl Syslog {
+  /// Builds a RFC 5424 message with the audit data as free form message
+  fn format_message(&self, data: &[u8]) -> String {
+    let hostname = std::env::var("HOSTNAME")
+      .ok()
+      .or_else(|| {
+        std::fs::read_to_string("/etc/hostname")
+          .ok()
+          .map(|hostname| hostname.trim().to_string())
+      })
+      .filter(|hostname| !hostname.is_empty())
+      .unwrap_or("-".to_string());
+
+    format!(
+      "<{}>1 {} {} {} {} - - {}",
+      SYSLOG_PRI,
+      Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true),
+      hostname,
+      self.app_name.as_deref().unwrap_or("manta"),
+      std::process::id(),
+      String::from_utf8_lossy(data)
+    )
+  }
+}
+
+impl Audit for Syslog {
+  async fn produce_message(&self, data: &[u8]) -> Result<(), Error> {
+    let address = format!("{}:{}", self.server, self.port);
+    let message = self.format_message(data);
+
+    match self.protocol {
+      SyslogProtocol::Udp => {
+        let socket = UdpSocket::bind("0.0.0.0:0").await?;
+        socket.send_to(message.as_bytes(), &address).await?;
+      }
+      SyslogProtocol::Tcp => {
+        // RFC 6587 octet counting framing
+        let mut stream = TcpStream::connect(&address).await?;
+        stream
+          .write_all(format!("{} {}", message.len(), message).as_bytes())
+          .await?;
+        stream.flush().await?;
+      }
+    }
+
+    log::info!("Audit message sent to syslog server {}", address);
+
+    Ok(())
+  }
+}
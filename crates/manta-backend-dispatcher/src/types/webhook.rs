@@ -0,0 +1,55 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{audit::Audit, error::Error};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Webhook {
+  pub url: String,
+  /// Sent as 'Authorization: Bearer <token>' if defined
+  pub token: Option<String>,
+  /// PEM file with the CA to validate the webhook server certificate
+  pub root_ca_cert_file: Option<String>,
+}
+
+impl Audit for Webhook {
+  async fn produce_message(&self, data: &[u8]) -> Result<(), Error> {
+    let mut client_builder = reqwest::Client::builder()
+      .use_rustls_tls()
+      .https_only(true)
+      .timeout(Duration::from_secs(10));
+
+    if let Some(root_ca_cert_file) = &self.root_ca_cert_file {
+      let root_cert = std::fs::read(root_ca_cert_file)?;
+      client_builder = client_builder
+        .add_root_certificate(reqwest::Certificate::from_pem(&root_cert)?);
+    }
+
+    let client = client_builder.build()?;
+
+    let mut request = client
+      .post(&self.url)
+      .header(reqwest::header::CONTENT_TYPE, "application/json")
+      .body(data.to_vec());
+
+    if let Some(token) = &self.token {
+      request = request.bearer_auth(token);
+    }
+
+    let response = request.send().await?;
+
+    if !response.status().is_success() {
+      return Err(Error::Message(format!(
+        "Audit webhook '{}' returned HTTP {}: {}",
+        self.url,
+        response.status(),
+        response.text().await.unwrap_or_default()
+      )));
+    }
+
+    log::info!("Audit message sent to webhook {}", self.url);
+
+    Ok(())
+  }
+}
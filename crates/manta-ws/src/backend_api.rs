@@ -3,4 +3,6 @@ mod mesa_lib;
 
 pub use crate::backend_api::backend_config::ReqCfg;
 pub use crate::backend_api::backend_config::get_req_cfg;
+pub use crate::backend_api::backend_config::get_site_cfg;
+pub use crate::backend_api::backend_config::route_site_from_path;
 pub use crate::backend_api::mesa_lib::get_kernel_parameters_from_mesa;
@@ -1,35 +1,64 @@
 use axum::http::HeaderMap;
-use axum::{http::StatusCode, response::Response};
-use std::{fmt::Display, fs::File, io::Read, path::PathBuf};
-
-use config::Config;
-use directories::ProjectDirs;
-
+use axum::{
+  extract::Request,
+  http::{HeaderValue, StatusCode, Uri},
+  middleware::Next,
+  response::Response,
+};
+use manta_backend_dispatcher::types::K8sDetails;
+use serde_json::Value;
+use std::fmt::Display;
+
+use crate::common::{audit::Auditor, config::types::MantaConfiguration};
 use crate::http_response::*;
+use crate::jwt_utils::verify_jwt_token;
 use crate::log::*;
 
+/// Header used to choose the site a request is for. Requests without it go to
+/// the default site in the configuration file
+pub const SITE_HEADER: &str = "x-manta-site";
+
+/// Path prefix used to choose the site a request is for, eg
+/// '/sites/alps/group'
+const SITE_PATH_PREFIX: &str = "/sites/";
+
 pub struct SiteCfg {
   pub site: String,
+  pub backend: String,
   pub shasta_base_url: String,
   pub shasta_root_cert: Vec<u8>,
+  pub keycloak_base_url: Option<String>,
+  pub jwt_audience: Option<String>,
+  pub jwt_issuer: Option<String>,
+  pub k8s: Option<K8sDetails>,
 }
 
 pub struct ReqCfg {
   pub auth_token: String,
+  /// Claims of the auth token, already verified
+  pub claims: Value,
   pub site_cfg: SiteCfg,
+  pub auditor: Option<Auditor>,
 }
 
-pub fn get_req_cfg(
+/// Site and auth token for a request. The token is verified against the
+/// Keycloak of the site. `site_opt` takes precedence over the site header
+pub async fn get_req_cfg(
   headers: &HeaderMap,
-  site: String,
+  site_opt: Option<String>,
 ) -> Result<ReqCfg, Response> {
-  let site_cfg = match get_site_cfg(site) {
+  let configuration = match get_manta_configuration().await {
     Ok(good) => good,
     Err(e) => {
       return Err(bad_config(&e));
     }
   };
 
+  let site_cfg = get_site_cfg_from_configuration(
+    &configuration,
+    get_site_name(headers, site_opt, &configuration),
+  )?;
+
   let auth_token = match get_auth_token(headers) {
     Ok(good) => good,
     Err(e) => {
@@ -37,14 +66,107 @@ pub fn get_req_cfg(
     }
   };
 
+  let keycloak_base_url = match &site_cfg.keycloak_base_url {
+    Some(good) => good,
+    None => {
+      let e =
+        format!("keycloak_base_url for site {} not found.", site_cfg.site);
+      return Err(bad_config(&e));
+    }
+  };
+
+  let claims = match verify_jwt_token(
+    &auth_token,
+    keycloak_base_url,
+    &site_cfg.shasta_root_cert,
+    site_cfg.jwt_issuer.as_deref(),
+    site_cfg.jwt_audience.as_deref(),
+  )
+  .await
+  {
+    Ok(good) => good,
+    Err(e) => {
+      return Err(unauthorized_access(&e));
+    }
+  };
+
   let req_cfg = ReqCfg {
     auth_token,
+    claims,
     site_cfg,
+    auditor: configuration.auditor,
   };
 
   Ok(req_cfg)
 }
 
+/// Site a request is for, without checking any credentials
+pub async fn get_site_cfg(
+  headers: &HeaderMap,
+  site_opt: Option<String>,
+) -> Result<SiteCfg, Response> {
+  let configuration = match get_manta_configuration().await {
+    Ok(good) => good,
+    Err(e) => {
+      return Err(bad_config(&e));
+    }
+  };
+
+  get_site_cfg_from_configuration(
+    &configuration,
+    get_site_name(headers, site_opt, &configuration),
+  )
+}
+
+/// Middleware routing '/sites/{site}/<path>' to '/<path>' with the site in
+/// the site header. Needs to wrap the router since it changes the request URI
+pub async fn route_site_from_path(mut req: Request, next: Next) -> Response {
+  if let Some(site_path) = req.uri().path().strip_prefix(SITE_PATH_PREFIX) {
+    let (site, path) = match site_path.split_once('/') {
+      Some((site, path)) => (site.to_string(), format!("/{path}")),
+      None => (site_path.to_string(), "/".to_string()),
+    };
+
+    let path_and_query = match req.uri().query() {
+      Some(query) => format!("{path}?{query}"),
+      None => path,
+    };
+
+    match path_and_query.parse::<Uri>() {
+      Ok(good) => *req.uri_mut() = good,
+      Err(e) => {
+        return error_respond(StatusCode::BAD_REQUEST, e.to_string());
+      }
+    }
+
+    match HeaderValue::from_str(&site) {
+      Ok(good) => {
+        req.headers_mut().insert(SITE_HEADER, good);
+      }
+      Err(e) => {
+        return error_respond(StatusCode::BAD_REQUEST, e.to_string());
+      }
+    }
+  }
+
+  next.run(req).await
+}
+
+fn get_site_name(
+  headers: &HeaderMap,
+  site_opt: Option<String>,
+  configuration: &MantaConfiguration,
+) -> String {
+  site_opt
+    .or_else(|| {
+      headers
+        .get(SITE_HEADER)
+        .and_then(|site| site.to_str().ok())
+        .map(str::to_string)
+    })
+    .unwrap_or_else(|| configuration.site.clone())
+}
+
 fn get_auth_token(headers: &HeaderMap) -> Result<String, String> {
   let auth_field = match headers.get("authorization") {
     Some(good) => good,
@@ -72,110 +194,53 @@ fn get_auth_token(headers: &HeaderMap) -> Result<String, String> {
   Ok(auth_token)
 }
 
-fn get_site_cfg(site: String) -> Result<SiteCfg, String> {
-  let settings = get_configuration()?;
-  let sites_table = match settings.get_table("sites") {
-    Ok(good) => good,
-    Err(e) => {
-      return Err(e.to_string());
-    }
-  };
-
-  let site_value = match sites_table.get(&site) {
-    Some(good) => good.clone().into_table(),
+fn get_site_cfg_from_configuration(
+  configuration: &MantaConfiguration,
+  site: String,
+) -> Result<SiteCfg, Response> {
+  let site_detail = match configuration.sites.get(&site) {
+    Some(good) => good,
     None => {
       let e = format!("site {site} not found.");
-      return Err(e.to_string());
+      return Err(site_not_found(&e));
     }
   };
 
-  let site_table = match site_value {
+  let shasta_root_cert = match crate::common::config::get_csm_root_cert_content(
+    &site_detail.root_ca_cert_file,
+  ) {
     Ok(good) => good,
     Err(e) => {
-      return Err(e.to_string());
-    }
-  };
-
-  let shasta_base_url = match site_table.get("shasta_base_url") {
-    Some(good) => good.to_string(),
-    None => {
-      let e = format!("shasta_base_url for site {site} not found.");
-      return Err(e.to_string());
+      return Err(bad_config(&e));
     }
   };
 
-  let shasta_root_cert = get_csm_root_cert_content(&site)?;
-
   let site_cfg = SiteCfg {
     site,
-    shasta_base_url: shasta_base_url.to_string(),
+    backend: site_detail.backend.clone(),
+    shasta_base_url: site_detail.shasta_base_url.clone(),
     shasta_root_cert,
+    keycloak_base_url: site_detail.keycloak_base_url.clone(),
+    jwt_audience: site_detail.jwt_audience.clone(),
+    jwt_issuer: site_detail.jwt_issuer.clone(),
+    k8s: site_detail.k8s.clone(),
   };
 
   Ok(site_cfg)
 }
 
-fn get_csm_root_cert_content(site: &str) -> Result<Vec<u8>, String> {
-  let mut config_path = get_configuration_file_path()?;
-
-  config_path.push(site.to_string() + "_root_cert.pem");
-
-  let mut buf = Vec::new();
-  let root_cert_file_rslt = File::open(config_path);
-
-  let _ = match root_cert_file_rslt {
-    Ok(mut file) => file.read_to_end(&mut buf),
-    Err(_) => {
-      return Err("Root cert file for CSM not found.".to_string());
-    }
-  };
-
-  Ok(buf)
-}
-
-fn get_configuration_file_path() -> Result<PathBuf, String> {
-  // XDG Base Directory Specification
-  let project_dirs = ProjectDirs::from(
-    "local", /*qualifier*/
-    "cscs",  /*organization*/
-    "manta", /*application*/
-  );
-
-  let dirs = match project_dirs {
-    Some(good_dirs) => good_dirs,
-    None => {
-      let reason = "cannot find a valid home directory";
-      return Err(reason.to_string());
-    }
-  };
-
-  let ret = PathBuf::from(dirs.config_dir());
-  Ok(ret)
-}
-
-/// Reads configuration parameters related to manta from environment variables or file. If both
-/// defiend, then environment variables takes preference
-fn get_configuration() -> Result<Config, String> {
-  let mut config_path = get_configuration_file_path()?;
-  config_path.push("config.toml"); // ~/.config/manta/config is the file
-
-  let built_config = ::config::Config::builder()
-    .add_source(::config::File::from(config_path))
-    .add_source(
-      ::config::Environment::with_prefix("MANTA")
-        .try_parsing(true)
-        .prefix_separator("_"),
-    )
-    .build();
-
-  let config = match built_config {
-    Ok(good_config) => good_config,
-    Err(reason) => {
-      return Err(reason.to_string());
+async fn get_manta_configuration() -> Result<MantaConfiguration, String> {
+  let settings = match crate::common::config::get_configuration().await {
+    Ok(good) => good,
+    Err(e) => {
+      return Err(e.to_string());
     }
   };
 
-  Ok(config)
+  match settings.try_deserialize::<MantaConfiguration>() {
+    Ok(good) => Ok(good),
+    Err(e) => Err(e.to_string()),
+  }
 }
 
 fn bad_config(e: &impl Display) -> Response {
@@ -189,3 +254,8 @@ fn unauthorized_access(e: &impl Display) -> Response {
   let error_message = "Unauthorized access".to_string();
   return error_respond(StatusCode::UNAUTHORIZED, error_message);
 }
+
+fn site_not_found(e: &impl Display) -> Response {
+  log(format!("ERROR {e}"));
+  return error_respond(StatusCode::NOT_FOUND, e.to_string());
+}
@@ -1,24 +1,28 @@
 use axum::http::StatusCode;
 use std::collections::HashMap;
 
-use csm_rs::bss::{
-  http_client::get as boot_parameters_get, types::BootParameters,
+use manta_backend_dispatcher::{
+  interfaces::bss::BootParametersTrait, types::bss::BootParameters,
 };
 
 use crate::backend_api::*;
+use crate::manta_backend_dispatcher::StaticBackendDispatcher;
 
 pub async fn get_kernel_parameters_from_mesa(
   config: ReqCfg,
   xnames: &[String],
 ) -> Result<HashMap<String, String>, (StatusCode, String)> {
-  let boot_param_vec: Vec<BootParameters> = boot_parameters_get(
-    config.auth_token.as_str(),
-    config.site_cfg.shasta_base_url.as_str(),
+  // Backend
+  let backend = StaticBackendDispatcher::new(
+    &config.site_cfg.backend,
+    &config.site_cfg.shasta_base_url,
     &config.site_cfg.shasta_root_cert,
-    xnames,
-  )
-  .await
-  .unwrap();
+  );
+
+  let boot_param_vec: Vec<BootParameters> = backend
+    .get_bootparameters(config.auth_token.as_str(), xnames)
+    .await
+    .map_err(|e| (crate::error::status_code(&e), e.to_string()))?;
 
   let mut rmap: HashMap<String, String> = HashMap::new();
   for bp in boot_param_vec {
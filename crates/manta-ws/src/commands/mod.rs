@@ -1,5 +1,7 @@
+use std::time::Instant;
+
 use crate::{
-  common::{self, config::types::MantaConfiguration},
+  backend_api::get_req_cfg, common,
   manta_backend_dispatcher::StaticBackendDispatcher,
 };
 use axum::{
@@ -14,44 +16,23 @@ use manta_backend_dispatcher::{
 };
 
 pub async fn get_all_redfish(headers: HeaderMap) -> Response {
-  // Configuration
-  let settings = common::config::get_configuration().await.unwrap();
-
-  let configuration: MantaConfiguration = settings.try_deserialize().unwrap();
-
-  let site_name: String = configuration.site;
-  let site_detail_value_opt = configuration.sites.get(&site_name);
-
-  let site = match site_detail_value_opt {
-    Some(site_detail_value) => site_detail_value,
-    None => {
-      return (
-        StatusCode::INTERNAL_SERVER_ERROR,
-        format!("ERROR - Site '{}' not found in configuration", site_name),
-      )
-        .into_response();
-    }
+  let req_cfg = match get_req_cfg(&headers, None).await {
+    Ok(req_cfg) => req_cfg,
+    Err(e) => return e,
   };
 
-  let backend_tech = &site.backend;
-  let shasta_base_url = &site.shasta_base_url;
-
-  let root_ca_cert_file = &site.root_ca_cert_file;
-
-  let shasta_root_cert =
-    common::config::get_csm_root_cert_content(&root_ca_cert_file).unwrap();
+  let backend_tech = &req_cfg.site_cfg.backend;
+  let shasta_base_url = &req_cfg.site_cfg.shasta_base_url;
+  let shasta_root_cert = &req_cfg.site_cfg.shasta_root_cert;
+  let auth_token = req_cfg.auth_token.as_str();
 
   // Backend
   let backend = StaticBackendDispatcher::new(
-    &backend_tech,
-    &shasta_base_url,
-    &shasta_root_cert,
+    backend_tech,
+    shasta_base_url,
+    shasta_root_cert,
   );
 
-  // Get auth token
-  let auth_header = headers.get("authorization").unwrap().to_str().unwrap();
-  let auth_token = auth_header.split(" ").nth(1).unwrap();
-
   let boot_parameters_rslt =
     backend.get_all_redfish_endpoints(auth_token).await;
 
@@ -60,7 +41,7 @@ pub async fn get_all_redfish(headers: HeaderMap) -> Response {
       return (StatusCode::OK, Json(boot_parameters_vec)).into_response();
     }
     Err(e) => {
-      return (StatusCode::INTERNAL_SERVER_ERROR, Json(e.to_string()))
+      return (crate::error::status_code(&e), Json(e.to_string()))
         .into_response();
     }
   }
@@ -71,41 +52,23 @@ pub async fn get_redfish(
   headers: HeaderMap,
   Path(xname): Path<String>,
 ) -> Response {
-  // Configuration
-  let settings = common::config::get_configuration().await.unwrap();
-
-  let configuration: MantaConfiguration = settings.try_deserialize().unwrap();
-
-  let site_name: String = configuration.site;
-  let site_detail_value_opt = configuration.sites.get(&site_name);
-
-  let site = match site_detail_value_opt {
-    Some(site_detail_value) => site_detail_value,
-    None => {
-      eprintln!("ERROR - Site '{}' not found in configuration", site_name);
-      std::process::exit(1);
-    }
+  let req_cfg = match get_req_cfg(&headers, None).await {
+    Ok(req_cfg) => req_cfg,
+    Err(e) => return e,
   };
 
-  let backend_tech = &site.backend;
-  let shasta_base_url = &site.shasta_base_url;
-
-  let root_ca_cert_file = &site.root_ca_cert_file;
-
-  let shasta_root_cert =
-    common::config::get_csm_root_cert_content(&root_ca_cert_file).unwrap();
+  let backend_tech = &req_cfg.site_cfg.backend;
+  let shasta_base_url = &req_cfg.site_cfg.shasta_base_url;
+  let shasta_root_cert = &req_cfg.site_cfg.shasta_root_cert;
+  let auth_token = req_cfg.auth_token.as_str();
 
   // Backend
   let backend = StaticBackendDispatcher::new(
-    &backend_tech,
-    &shasta_base_url,
-    &shasta_root_cert,
+    backend_tech,
+    shasta_base_url,
+    shasta_root_cert,
   );
 
-  // Get auth token
-  let auth_header = headers.get("authorization").unwrap().to_str().unwrap();
-  let auth_token = auth_header.split(" ").nth(1).unwrap();
-
   let boot_parameters_rslt = backend
     .get_redfish_endpoints(
       auth_token,
@@ -124,7 +87,7 @@ pub async fn get_redfish(
       return (StatusCode::OK, Json(boot_parameters_vec)).into_response();
     }
     Err(e) => {
-      return (StatusCode::INTERNAL_SERVER_ERROR, Json(e.to_string()))
+      return (crate::error::status_code(&e), Json(e.to_string()))
         .into_response();
     }
   }
@@ -135,51 +98,56 @@ pub async fn post_redfish(
   headers: HeaderMap,
   Json(redfish_endpoint): Json<RedfishEndpointArray>,
 ) -> Response {
-  // Configuration
-  let settings = common::config::get_configuration().await.unwrap();
-
-  let configuration: MantaConfiguration = settings.try_deserialize().unwrap();
-
-  let site_name: String = configuration.site;
-  let site_detail_value_opt = configuration.sites.get(&site_name);
-
-  let site = match site_detail_value_opt {
-    Some(site_detail_value) => site_detail_value,
-    None => {
-      eprintln!("ERROR - Site '{}' not found in configuration", site_name);
-      std::process::exit(1);
-    }
+  let req_cfg = match get_req_cfg(&headers, None).await {
+    Ok(req_cfg) => req_cfg,
+    Err(e) => return e,
   };
 
-  let backend_tech = &site.backend;
-  let shasta_base_url = &site.shasta_base_url;
-
-  let root_ca_cert_file = &site.root_ca_cert_file;
-
-  let shasta_root_cert =
-    common::config::get_csm_root_cert_content(&root_ca_cert_file).unwrap();
+  let site_name = &req_cfg.site_cfg.site;
+  let backend_tech = &req_cfg.site_cfg.backend;
+  let shasta_base_url = &req_cfg.site_cfg.shasta_base_url;
+  let shasta_root_cert = &req_cfg.site_cfg.shasta_root_cert;
+  let auth_token = req_cfg.auth_token.as_str();
 
   // Backend
   let backend = StaticBackendDispatcher::new(
-    &backend_tech,
-    &shasta_base_url,
-    &shasta_root_cert,
+    backend_tech,
+    shasta_base_url,
+    shasta_root_cert,
   );
 
-  // Get auth token
-  let auth_header = headers.get("authorization").unwrap().to_str().unwrap();
-  let auth_token = auth_header.split(" ").nth(1).unwrap();
+  let start = Instant::now();
 
   let boot_parameters_rslt = backend
     .add_redfish_endpoint(auth_token, &redfish_endpoint)
     .await;
 
+  // Audit
+  let audit_event = common::audit::new_event(
+    auth_token,
+    site_name,
+    backend_tech,
+    "redfish-endpoint.add",
+  )
+  .with_xnames(
+    &redfish_endpoint
+      .redfish_endpoints
+      .iter()
+      .flatten()
+      .map(|redfish_endpoint| redfish_endpoint.id.as_str())
+      .collect::<Vec<_>>(),
+  )
+  .with_result(&boot_parameters_rslt)
+  .with_duration(start.elapsed());
+
+  common::audit::send_event(req_cfg.auditor.as_ref(), &audit_event).await;
+
   match boot_parameters_rslt {
     Ok(boot_parameters_vec) => {
       return (StatusCode::OK, Json(boot_parameters_vec)).into_response();
     }
     Err(e) => {
-      return (StatusCode::INTERNAL_SERVER_ERROR, Json(e.to_string()))
+      return (crate::error::status_code(&e), Json(e.to_string()))
         .into_response();
     }
   }
@@ -190,50 +158,48 @@ pub async fn delete_redfish(
   headers: HeaderMap,
   Path(xname): Path<String>,
 ) -> Response {
-  // Configuration
-  let settings = common::config::get_configuration().await.unwrap();
-
-  let configuration: MantaConfiguration = settings.try_deserialize().unwrap();
-
-  let site_name: String = configuration.site;
-  let site_detail_value_opt = configuration.sites.get(&site_name);
-
-  let site = match site_detail_value_opt {
-    Some(site_detail_value) => site_detail_value,
-    None => {
-      eprintln!("ERROR - Site '{}' not found in configuration", site_name);
-      std::process::exit(1);
-    }
+  let req_cfg = match get_req_cfg(&headers, None).await {
+    Ok(req_cfg) => req_cfg,
+    Err(e) => return e,
   };
 
-  let backend_tech = &site.backend;
-  let shasta_base_url = &site.shasta_base_url;
-
-  let root_ca_cert_file = &site.root_ca_cert_file;
-
-  let shasta_root_cert =
-    common::config::get_csm_root_cert_content(&root_ca_cert_file).unwrap();
+  let site_name = &req_cfg.site_cfg.site;
+  let backend_tech = &req_cfg.site_cfg.backend;
+  let shasta_base_url = &req_cfg.site_cfg.shasta_base_url;
+  let shasta_root_cert = &req_cfg.site_cfg.shasta_root_cert;
+  let auth_token = req_cfg.auth_token.as_str();
 
   // Backend
   let backend = StaticBackendDispatcher::new(
-    &backend_tech,
-    &shasta_base_url,
-    &shasta_root_cert,
+    backend_tech,
+    shasta_base_url,
+    shasta_root_cert,
   );
 
-  // Get auth token
-  let auth_header = headers.get("authorization").unwrap().to_str().unwrap();
-  let auth_token = auth_header.split(" ").nth(1).unwrap();
+  let start = Instant::now();
 
   let boot_parameters_rslt =
     backend.delete_redfish_endpoint(auth_token, &xname).await;
 
+  // Audit
+  let audit_event = common::audit::new_event(
+    auth_token,
+    site_name,
+    backend_tech,
+    "redfish-endpoint.delete",
+  )
+  .with_xnames(&[&xname])
+  .with_result(&boot_parameters_rslt)
+  .with_duration(start.elapsed());
+
+  common::audit::send_event(req_cfg.auditor.as_ref(), &audit_event).await;
+
   match boot_parameters_rslt {
     Ok(boot_parameters_vec) => {
       return (StatusCode::OK, Json(boot_parameters_vec)).into_response();
     }
     Err(e) => {
-      return (StatusCode::INTERNAL_SERVER_ERROR, Json(e.to_string()))
+      return (crate::error::status_code(&e), Json(e.to_string()))
         .into_response();
     }
   }
@@ -1,14 +1,36 @@
-use anyhow::Result;
-use serde::{Deserialize, Serialize};
+use manta_backend_dispatcher::audit::{Audit, AuditActor, AuditEvent};
 
-use super::kafka::Kafka;
+use crate::jwt_utils::get_claims_from_jwt_token;
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct Auditor {
-  pub kafka: Kafka,
-  // pub syslog: Option<Syslog>,
+pub use manta_backend_dispatcher::audit::Auditor;
+
+/// Creates an audit event for an operation requested by the owner of the
+/// token
+pub fn new_event(
+  auth_token: &str,
+  site_name: &str,
+  backend_tech: &str,
+  operation: &str,
+) -> AuditEvent {
+  let claims = get_claims_from_jwt_token(auth_token).unwrap_or_default();
+
+  let actor = AuditActor {
+    id: claims["preferred_username"]
+      .as_str()
+      .unwrap_or_default()
+      .to_string(),
+    name: claims["name"].as_str().unwrap_or_default().to_string(),
+  };
+
+  AuditEvent::new(actor, site_name, backend_tech, operation)
 }
 
-pub trait Audit {
-  async fn produce_message(&self, data: &[u8]) -> Result<()>;
+/// Sends an audit event to all sinks configured. Failures are logged but
+/// never fail the request being audited
+pub async fn send_event(auditor_opt: Option<&Auditor>, event: &AuditEvent) {
+  if let Some(auditor) = auditor_opt {
+    if let Err(e) = auditor.produce_event(event).await {
+      tracing::warn!("Failed producing audit event: {}", e);
+    }
+  }
 }
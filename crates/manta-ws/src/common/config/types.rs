@@ -56,6 +56,13 @@ pub struct Site {
   pub vault_secret_path: Option<String>,
   // pub vault_role_id: Option<String>,
   pub root_ca_cert_file: String,
+  /// Keycloak publishing the keys used to verify JWT tokens for this site
+  pub keycloak_base_url: Option<String>,
+  /// Expected 'aud' claim in JWT tokens. Audience is not checked if missing
+  pub jwt_audience: Option<String>,
+  /// Expected 'iss' claim in JWT tokens. Defaults to the 'shasta' realm of
+  /// `keycloak_base_url`
+  pub jwt_issuer: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
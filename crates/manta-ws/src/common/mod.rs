@@ -1,3 +1,2 @@
 pub mod audit;
 pub mod config;
-pub mod kafka;
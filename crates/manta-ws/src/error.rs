@@ -1,33 +1,17 @@
-use axum::{
-  body::Body,
-  response::{IntoResponse, Response},
-};
-use hyper::{StatusCode, header};
+use hyper::StatusCode;
+use manta_backend_dispatcher::error::{Error as BackendError, ErrorKind};
 
- struct Error(manta_backend_dispatcher::error::Error);
-
- impl IntoResponse for manta_backend_dispatcher::error::Error {
-  fn into_response(self) -> Response {
-    // self.to_string()
-    let status = StatusCode::INTERNAL_SERVER_ERROR;
-    let body = self.to_string();
-    Response::builder()
-      .status(status)
-      .header(header::CONTENT_TYPE, "text/plain")
-      .body(Body::from(body))
-      .unwrap()
-  }
-} 
-
- impl IntoResponse for axum::Error {
-  fn into_response(self) -> Response {
-    // self.to_string()
-    let status = StatusCode::INTERNAL_SERVER_ERROR;
-    let body = self.to_string();
-    Response::builder()
-      .status(status)
-      .header(header::CONTENT_TYPE, "text/plain")
-      .body(Body::from(body))
-      .unwrap()
+/// HTTP status code to reply with when a backend operation fails
+pub fn status_code(error: &BackendError) -> StatusCode {
+  match error.kind() {
+    ErrorKind::NotFound => StatusCode::NOT_FOUND,
+    ErrorKind::Unauthorized => StatusCode::UNAUTHORIZED,
+    ErrorKind::Forbidden => StatusCode::FORBIDDEN,
+    ErrorKind::Conflict => StatusCode::CONFLICT,
+    ErrorKind::Validation => StatusCode::BAD_REQUEST,
+    ErrorKind::Timeout => StatusCode::GATEWAY_TIMEOUT,
+    ErrorKind::Unavailable => StatusCode::SERVICE_UNAVAILABLE,
+    ErrorKind::Unsupported => StatusCode::NOT_IMPLEMENTED,
+    ErrorKind::Other => StatusCode::INTERNAL_SERVER_ERROR,
   }
-} 
+}
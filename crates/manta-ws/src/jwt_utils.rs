@@ -1,15 +1,181 @@
-use std::error::Error;
+use std::{
+  collections::HashMap,
+  error::Error,
+  sync::LazyLock,
+  time::{Duration, Instant},
+};
 
 use base64::decode;
+use jsonwebtoken::{
+  Algorithm, DecodingKey, Validation, decode as decode_jwt, decode_header,
+  jwk::{JwkSet, KeyAlgorithm},
+};
 use serde_json::Value;
+use tokio::sync::RwLock;
 
+/// How long a JWKS is kept in cache if Keycloak does not say otherwise
+const JWKS_DEFAULT_TTL: Duration = Duration::from_secs(300);
+
+/// Minimum time between two JWKS fetches triggered by unknown signing keys,
+/// so tokens with made up 'kid' can't make us hammer Keycloak
+const JWKS_MIN_REFRESH_INTERVAL: Duration = Duration::from_secs(30);
+
+struct CachedJwks {
+  jwk_set: JwkSet,
+  fetched_at: Instant,
+  expires_at: Instant,
+}
+
+/// JWKS per Keycloak certs endpoint, so a single instance can serve several
+/// sites
+static JWKS_CACHE: LazyLock<RwLock<HashMap<String, CachedJwks>>> =
+  LazyLock::new(Default::default);
+
+/// Decodes the claims of a JWT token WITHOUT verifying its signature. Only use
+/// it on tokens already validated with `verify_jwt_token`
 pub fn get_claims_from_jwt_token(token: &str) -> Result<Value, Box<dyn Error>> {
-  let base64_claims = token.split(".").nth(1).expect("JWT Token not valid");
+  let base64_claims = token
+    .split('.')
+    .nth(1)
+    .ok_or("JWT token not valid, claims not found")?;
+
+  let claims_u8 = decode(base64_claims)
+    .map_err(|e| format!("Could not decode JWT token claims. Reason: {}", e))?;
+
+  let claims_str = std::str::from_utf8(&claims_u8)
+    .map_err(|e| format!("JWT token claims are not UTF-8. Reason: {}", e))?;
+
+  serde_json::from_str::<Value>(claims_str).map_err(|e| {
+    format!("JWT token claims are not a JSON object. Reason: {}", e).into()
+  })
+}
+
+/// Verifies the signature, expiry, issuer and (if configured) audience of a
+/// JWT token against the keys published by Keycloak. Returns the token claims
+pub async fn verify_jwt_token(
+  token: &str,
+  keycloak_base_url: &str,
+  root_cert: &[u8],
+  issuer_opt: Option<&str>,
+  audience_opt: Option<&str>,
+) -> Result<Value, String> {
+  let header = decode_header(token).map_err(|e| e.to_string())?;
+
+  let kid = header
+    .kid
+    .ok_or_else(|| "JWT token header has no 'kid'".to_string())?;
+
+  let realm_url = format!("{}/realms/shasta", keycloak_base_url);
+  let jwks_url = format!("{}/protocol/openid-connect/certs", realm_url);
+
+  // Keys not in cache may have been rotated, refresh the JWKS once
+  let jwk = match get_jwks(&jwks_url, root_cert, false).await?.find(&kid) {
+    Some(jwk) => jwk.clone(),
+    None => get_jwks(&jwks_url, root_cert, true)
+      .await?
+      .find(&kid)
+      .cloned()
+      .ok_or_else(|| format!("JWT signing key '{}' not found", kid))?,
+  };
+
+  // Algorithm comes from the signing key, never from the token header
+  let algorithm = match jwk.common.key_algorithm {
+    Some(KeyAlgorithm::RS256) | None => Algorithm::RS256,
+    Some(key_algorithm) => {
+      return Err(format!(
+        "JWT signing key '{}' algorithm '{}' not supported",
+        kid, key_algorithm
+      ));
+    }
+  };
+
+  if header.alg != algorithm {
+    return Err(format!(
+      "JWT token algorithm '{:?}' does not match signing key '{}' algorithm '{:?}'",
+      header.alg, kid, algorithm
+    ));
+  }
 
-  let claims_u8 = decode(base64_claims).unwrap();
+  let decoding_key = DecodingKey::from_jwk(&jwk).map_err(|e| e.to_string())?;
+
+  let mut validation = Validation::new(algorithm);
+  validation.set_issuer(&[issuer_opt.unwrap_or(&realm_url)]);
+  validation.set_required_spec_claims(&["exp", "iss"]);
+  match audience_opt {
+    Some(audience) => validation.set_audience(&[audience]),
+    None => validation.validate_aud = false,
+  }
+
+  decode_jwt::<Value>(token, &decoding_key, &validation)
+    .map(|token_data| token_data.claims)
+    .map_err(|e| e.to_string())
+}
+
+async fn get_jwks(
+  jwks_url: &str,
+  root_cert: &[u8],
+  force_refresh: bool,
+) -> Result<JwkSet, String> {
+  if let Some(cached_jwks) = JWKS_CACHE.read().await.get(jwks_url) {
+    let fresh = if force_refresh {
+      cached_jwks.fetched_at.elapsed() < JWKS_MIN_REFRESH_INTERVAL
+    } else {
+      cached_jwks.expires_at > Instant::now()
+    };
+
+    if fresh {
+      return Ok(cached_jwks.jwk_set.clone());
+    }
+  }
+
+  tracing::debug!("Fetching JWKS from {}", jwks_url);
+
+  let client_builder = reqwest::Client::builder().add_root_certificate(
+    reqwest::Certificate::from_pem(root_cert).map_err(|e| e.to_string())?,
+  );
+
+  let client = if let Ok(socks5_env) = std::env::var("SOCKS5") {
+    let socks5proxy =
+      reqwest::Proxy::all(socks5_env).map_err(|e| e.to_string())?;
+    client_builder.proxy(socks5proxy).build()
+  } else {
+    client_builder.build()
+  }
+  .map_err(|e| e.to_string())?;
+
+  let response = client
+    .get(jwks_url)
+    .send()
+    .await
+    .and_then(|response| response.error_for_status())
+    .map_err(|e| e.to_string())?;
+
+  let ttl = response
+    .headers()
+    .get(reqwest::header::CACHE_CONTROL)
+    .and_then(|cache_control| cache_control.to_str().ok())
+    .and_then(get_max_age)
+    .unwrap_or(JWKS_DEFAULT_TTL);
+
+  let jwk_set: JwkSet = response.json().await.map_err(|e| e.to_string())?;
+
+  JWKS_CACHE.write().await.insert(
+    jwks_url.to_string(),
+    CachedJwks {
+      jwk_set: jwk_set.clone(),
+      fetched_at: Instant::now(),
+      expires_at: Instant::now() + ttl,
+    },
+  );
+
+  Ok(jwk_set)
+}
 
-  Ok(
-    serde_json::from_str::<Value>(std::str::from_utf8(&claims_u8).unwrap())
-      .unwrap(),
-  )
+/// Reads 'max-age' from a 'Cache-Control' header value
+fn get_max_age(cache_control: &str) -> Option<Duration> {
+  cache_control
+    .split(',')
+    .filter_map(|directive| directive.trim().strip_prefix("max-age="))
+    .find_map(|max_age| max_age.parse::<u64>().ok())
+    .map(Duration::from_secs)
 }
@@ -9,39 +9,42 @@ mod log;
 mod manta_backend_dispatcher;
 
 use ::manta_backend_dispatcher::{
+  contracts::BackendTrait,
+  error::Error,
   interfaces::{
-    bss::BootParametersTrait, cfs::CfsTrait, hsm::group::GroupTrait,
+    bss::BootParametersTrait,
+    cfs::CfsTrait,
+    console::ConsoleTrait,
+    health::HealthTrait,
+    hsm::{
+      component::ComponentTrait, group::GroupTrait,
+      hardware_inventory::HardwareInventory,
+    },
     pcs::PCSTrait,
   },
-  types::{BootParameters, K8sAuth, K8sDetails},
+  types::{Group, K8sDetails, bss::BootParameters},
 };
 use axum::{
-  Json, Router, debug_handler,
+  Json, Router, ServiceExt, debug_handler,
   extract::{
-    ConnectInfo, Path, Query, WebSocketUpgrade,
+    ConnectInfo, Path, Query, Request, WebSocketUpgrade,
     ws::{Message, Utf8Bytes, WebSocket},
   },
   http::{HeaderMap, StatusCode},
+  middleware,
   response::{IntoResponse, Response},
   routing::{delete, get, post, put},
 };
 use axum_extra::{TypedHeader, headers};
 use bytes::Bytes;
-use common::config::types::MantaConfiguration;
-use config::Config;
-use csm_rs::{
-  common::vault::http_client::fetch_shasta_k8s_secrets_from_vault,
-  hsm::hw_inventory::hw_component::types::NodeSummary,
-};
-use directories::ProjectDirs;
 use futures::{AsyncBufReadExt, SinkExt, StreamExt, TryStreamExt};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::{
-  fs::File, io::Read, net::SocketAddr, ops::ControlFlow, path::PathBuf,
-  sync::Arc,
+  net::SocketAddr, ops::ControlFlow, path::PathBuf, sync::Arc, time::Instant,
 };
 use tokio::{io::AsyncWriteExt, sync::Semaphore};
+use tower::Layer;
 use tower_http::{
   cors::CorsLayer,
   services::ServeDir,
@@ -51,12 +54,11 @@ use tracing_subscriber::{
   prelude::__tracing_subscriber_SubscriberExt, util::SubscriberInitExt,
 };
 
-use crate::jwt_utils::get_claims_from_jwt_token;
-
 use tokio_util::io::ReaderStream;
 
-use anyhow::{Result, bail};
-
+use crate::backend_api::{
+  ReqCfg, get_req_cfg, get_site_cfg, route_site_from_path,
+};
 use crate::handlers::*;
 
 use manta_backend_dispatcher::StaticBackendDispatcher;
@@ -64,6 +66,10 @@ use manta_backend_dispatcher::StaticBackendDispatcher;
 use commands::{delete_redfish, get_all_redfish, get_redfish, post_redfish};
 use utoipa::{OpenApi, ToSchema, openapi::OpenApi as OpenApiDoc};
 
+/// Terminal size requested to the backend when attaching to a node console
+const CONSOLE_WIDTH: u16 = 80;
+const CONSOLE_HEIGHT: u16 = 24;
+
 #[derive(OpenApi)]
 #[openapi(
   info(
@@ -144,9 +150,18 @@ async fn main() {
   //        .serve(app.into_make_service_with_connect_info::<SocketAddr>())
   //        .await
   //        .unwrap();
-  axum::serve(tokio::net::TcpListener::bind(addr).await.unwrap(), app)
-    .await
-    .unwrap()
+  // '/sites/{site}/...' requests are routed to the same handlers with the
+  // site taken from the path
+  let app = middleware::from_fn(route_site_from_path).layer(app);
+
+  axum::serve(
+    tokio::net::TcpListener::bind(addr).await.unwrap(),
+    ServiceExt::<Request>::into_make_service_with_connect_info::<SocketAddr>(
+      app,
+    ),
+  )
+  .await
+  .unwrap()
 }
 
 // the input to our `create_user` handler
@@ -194,18 +209,14 @@ async fn get_version() -> &'static str {
     )
 )]
 async fn test_whoami(headers: HeaderMap) -> Result<String, StatusCode> {
-  // Get auth token
-  let auth_token = if let Some(auth_header) = headers.get("authorization") {
-    auth_header.to_str().unwrap().split(" ").nth(1).unwrap()
-  } else {
-    return Err(StatusCode::UNAUTHORIZED);
+  let req_cfg = match get_req_cfg(&headers, None).await {
+    Ok(req_cfg) => req_cfg,
+    Err(e) => return Err(e.status()),
   };
 
-  let claims_json = get_claims_from_jwt_token(auth_token).unwrap();
-
   Ok(format!(
     "Hello {}!!!",
-    claims_json["name"].as_str().unwrap()
+    req_cfg.claims["name"].as_str().unwrap_or_default()
   ))
 }
 
@@ -290,44 +301,23 @@ async fn get_cfs_session(
   headers: HeaderMap,
   Path(cfs_session_name): Path<String>,
 ) -> Result<Json<Value>, StatusCode> {
-  // Configuration
-  let settings = common::config::get_configuration().await.unwrap();
-
-  let configuration: MantaConfiguration = settings.try_deserialize().unwrap();
-
-  let site_name: String = configuration.site;
-  let site_detail_value_opt = configuration.sites.get(&site_name);
-
-  let site = match site_detail_value_opt {
-    Some(site_detail_value) => site_detail_value,
-    None => {
-      eprintln!("ERROR - Site '{}' not found in configuration", site_name);
-      std::process::exit(1);
-    }
+  let req_cfg = match get_req_cfg(&headers, None).await {
+    Ok(req_cfg) => req_cfg,
+    Err(e) => return Err(e.status()),
   };
 
-  let backend_tech = &site.backend;
-  let shasta_base_url = &site.shasta_base_url;
-
-  let root_ca_cert_file = &site.root_ca_cert_file;
-
-  let shasta_root_cert =
-    common::config::get_csm_root_cert_content(&root_ca_cert_file).unwrap();
+  let backend_tech = &req_cfg.site_cfg.backend;
+  let shasta_base_url = &req_cfg.site_cfg.shasta_base_url;
+  let shasta_root_cert = &req_cfg.site_cfg.shasta_root_cert;
+  let auth_token = req_cfg.auth_token.as_str();
 
   // Backend
   let backend = StaticBackendDispatcher::new(
-    &backend_tech,
-    &shasta_base_url,
-    &shasta_root_cert,
+    backend_tech,
+    shasta_base_url,
+    shasta_root_cert,
   );
 
-  // Get auth token
-  let auth_token = if let Some(auth_header) = headers.get("authorization") {
-    auth_header.to_str().unwrap().split(" ").nth(1).unwrap()
-  } else {
-    return Err(StatusCode::UNAUTHORIZED);
-  };
-
   let hsm_group_available_vec: Vec<String> =
     backend.get_group_name_available(&auth_token).await.unwrap();
 
@@ -360,45 +350,34 @@ async fn ws_cfs_session_logs(
   ws: WebSocketUpgrade,
   user_agent: Option<TypedHeader<headers::UserAgent>>,
   ConnectInfo(addr): ConnectInfo<SocketAddr>,
-) -> impl IntoResponse {
-  // Configuration
-  let settings = common::config::get_configuration().await.unwrap();
-
-  let configuration: MantaConfiguration = settings.try_deserialize().unwrap();
-
-  let site_name: String = configuration.site.clone();
-  let site_detail_value_opt = configuration.sites.get(&site_name).cloned();
-
-  let site = site_detail_value_opt.unwrap();
-
-  let k8s_details = site
-    .k8s
-    .expect("ERROR - k8s section not found in configuration");
-
-  let backend_tech = &site.backend;
-  let shasta_base_url = &site.shasta_base_url;
+) -> Response {
+  let req_cfg = match get_req_cfg(&headers, None).await {
+    Ok(req_cfg) => req_cfg,
+    Err(e) => return e,
+  };
 
-  let root_ca_cert_file = &site.root_ca_cert_file;
+  let site_cfg = req_cfg.site_cfg;
 
-  let shasta_root_cert =
-    common::config::get_csm_root_cert_content(&root_ca_cert_file).unwrap();
+  let k8s_details = match site_cfg.k8s {
+    Some(k8s_details) => k8s_details,
+    None => {
+      return (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        format!("ERROR - k8s section not found for site '{}'", site_cfg.site),
+      )
+        .into_response();
+    }
+  };
 
   // Backend
   let backend = StaticBackendDispatcher::new(
-    &backend_tech,
-    &shasta_base_url,
-    &shasta_root_cert,
+    &site_cfg.backend,
+    &site_cfg.shasta_base_url,
+    &site_cfg.shasta_root_cert,
   );
 
-  // Get auth token
-  let auth_header = headers.get("authorization").unwrap();
-  let auth_token = auth_header
-    .to_str()
-    .unwrap()
-    .split(" ")
-    .nth(1)
-    .unwrap()
-    .to_string();
+  let auth_token = req_cfg.auth_token;
+  let site_name = site_cfg.site;
 
   let user_agent = if let Some(TypedHeader(user_agent)) = user_agent {
     user_agent.to_string()
@@ -455,76 +434,18 @@ async fn get_cfs_session_logs(
   }
 }
 
-pub fn get_configuration_file_path() -> PathBuf {
-  // XDG Base Directory Specification
-  let project_dirs = ProjectDirs::from(
-    "local", /*qualifier*/
-    "cscs",  /*organization*/
-    "manta", /*application*/
-  );
-
-  PathBuf::from(project_dirs.unwrap().config_dir())
-}
-
-/// Reads configuration parameters related to manta from environment variables or file. If both
-/// defiend, then environment variables takes preference
-pub fn get_configuration() -> Config {
-  let mut config_path = get_configuration_file_path();
-  config_path.push("config.toml"); // ~/.config/manta/config is the file
-
-  ::config::Config::builder()
-    .add_source(::config::File::from(config_path))
-    .add_source(
-      ::config::Environment::with_prefix("MANTA")
-        .try_parsing(true)
-        .prefix_separator("_"),
-    )
-    .build()
-    .unwrap()
-}
-
-pub fn get_csm_root_cert_content(site: &str) -> Vec<u8> {
-  let mut config_path = get_configuration_file_path();
-  config_path.push(site.to_string() + "_root_cert.pem");
-
-  let mut buf = Vec::new();
-  let root_cert_file_rslt = File::open(config_path);
-
-  let _ = match root_cert_file_rslt {
-    Ok(mut file) => file.read_to_end(&mut buf),
-    Err(_) => {
-      eprintln!("Root cert file for CSM not found. Exit");
-      std::process::exit(1);
-    }
-  };
-
-  buf
-}
-
 async fn authenticate(headers: HeaderMap) -> Result<String, StatusCode> {
-  let settings = get_configuration();
-
-  let site_detail_hashmap = settings.get_table("sites").unwrap();
-  let site_detail_value = site_detail_hashmap
-    .get("alps")
-    .unwrap()
-    .clone()
-    .into_table()
-    .unwrap();
-
-  /* let shasta_base_url = site_detail_value
-  .get("shasta_base_url")
-  .unwrap()
-  .to_string(); */
-  let keycloak_base_url = site_detail_value
-    .get("keycloak_base_url")
-    .unwrap()
-    .to_string();
-  // let k8s_api_url = site_detail_value.get("k8s_api_url").unwrap().to_string();
-
-  // let settings_hsm_group_name_opt = settings.get_string("hsm_group").ok();
+  let site_cfg = match get_site_cfg(&headers, None).await {
+    Ok(site_cfg) => site_cfg,
+    Err(e) => return Err(e.status()),
+  };
 
-  let shasta_root_cert = get_csm_root_cert_content("alps");
+  // Backend
+  let backend = StaticBackendDispatcher::new(
+    &site_cfg.backend,
+    &site_cfg.shasta_base_url,
+    &site_cfg.shasta_root_cert,
+  );
 
   let base64_user_credentials =
     if let Some(usercredentials) = headers.get("authorization") {
@@ -543,19 +464,14 @@ async fn authenticate(headers: HeaderMap) -> Result<String, StatusCode> {
   let username = user_credentials.next().unwrap();
   let password = user_credentials.next().unwrap();
 
-  let auth_token_result =
-    csm_rs::common::authentication::get_token_from_shasta_endpoint(
-      &keycloak_base_url,
-      &shasta_root_cert,
-      username,
-      password,
-    )
-    .await;
-
-  println!("DEBUG - TEST 2");
+  let auth_token_result = backend.authenticate(username, password).await;
 
   match auth_token_result {
     Ok(auth_token) => Ok(auth_token),
+    Err(Error::UnsupportedCapability(e)) => {
+      eprintln!("ERROR - {}", e);
+      Err(StatusCode::NOT_IMPLEMENTED)
+    }
     Err(error) => {
       eprintln!("ERROR - Authentication failed. Reason:\n{:#?}", error);
       Err(StatusCode::FORBIDDEN)
@@ -573,7 +489,13 @@ async fn ws_console(
   Path(xname): Path<String>,
   ws: WebSocketUpgrade,
   user_agent: Option<TypedHeader<headers::UserAgent>>,
-) -> impl IntoResponse {
+) -> Response {
+  // Token is verified before upgrading the connection
+  let req_cfg = match get_req_cfg(&headers, None).await {
+    Ok(req_cfg) => req_cfg,
+    Err(e) => return e,
+  };
+
   let user_agent = if let Some(TypedHeader(user_agent)) = user_agent {
     user_agent.to_string()
   } else {
@@ -583,69 +505,25 @@ async fn ws_console(
   println!("`{user_agent}` connected.");
   // finalize the upgrade process by returning upgrade callback.
   // we can customize the callback by sending additional info such as address.
-  ws.on_upgrade(move |socket| handle_socket(headers, socket, xname))
+  ws.on_upgrade(move |socket| handle_socket(req_cfg, socket, xname))
 }
 
 /// Actual websocket statemachine (one will be spawned per connection)
-async fn handle_socket(headers: HeaderMap, socket: WebSocket, xname: String) {
-  // Configuration
-  let settings = common::config::get_configuration().await.unwrap();
-
-  let configuration: MantaConfiguration = settings.try_deserialize().unwrap();
-
-  let site_name: String = configuration.site;
-  let site_detail_value_opt = configuration.sites.get(&site_name);
-
-  let site = match site_detail_value_opt {
-    Some(site_detail_value) => site_detail_value,
-    None => {
-      eprintln!("ERROR - Site '{}' not found in configuration", site_name);
-      std::process::exit(1);
-    }
-  };
-
-  let k8s_details = site
-    .k8s
-    .as_ref()
-    .expect("ERROR - k8s section not found in configuration");
-
-  // let backend_tech = &site.backend;
-  // let shasta_base_url = &site.shasta_base_url;
+async fn handle_socket(req_cfg: ReqCfg, socket: WebSocket, xname: String) {
+  let site_name = &req_cfg.site_cfg.site;
+  let backend_tech = &req_cfg.site_cfg.backend;
+  let shasta_base_url = &req_cfg.site_cfg.shasta_base_url;
+  let shasta_root_cert = &req_cfg.site_cfg.shasta_root_cert;
+  let auth_token = req_cfg.auth_token.as_str();
 
-  // let root_ca_cert_file = &site.root_ca_cert_file;
-
-  /* let shasta_root_cert =
-  common::config::get_csm_root_cert_content(&root_ca_cert_file).unwrap(); */
+  let k8s_details_opt = req_cfg.site_cfg.k8s.as_ref();
 
   // Backend
-  /* let backend = StaticBackendDispatcher::new(
-    &backend_tech,
-    &shasta_base_url,
-    &shasta_root_cert,
-  ); */
-
-  // Get auth token
-  let auth_header = headers.get("authorization").unwrap().to_str().unwrap();
-  let auth_token = auth_header.split(" ").nth(1).unwrap().to_string();
-
-  let shasta_k8s_secrets = match &k8s_details.authentication {
-    K8sAuth::Native {
-      certificate_authority_data,
-      client_certificate_data,
-      client_key_data,
-    } => {
-      serde_json::json!({ "certificate-authority-data": certificate_authority_data, "client-certificate-data": client_certificate_data, "client-key-data": client_key_data })
-    }
-    K8sAuth::Vault {
-      base_url: vault_base_url,
-    } => fetch_shasta_k8s_secrets_from_vault(
-      &vault_base_url,
-      &auth_token,
-      &site_name,
-    )
-    .await
-    .unwrap(),
-  };
+  let backend = StaticBackendDispatcher::new(
+    backend_tech,
+    shasta_base_url,
+    shasta_root_cert,
+  );
 
   // By splitting socket we can send and receive at the same time. In this example we will send
   // unsolicited messages to client based on some sort of server's internal event (i.e .timer).
@@ -653,18 +531,20 @@ async fn handle_socket(headers: HeaderMap, socket: WebSocket, xname: String) {
 
   // CONSOLE
 
-  let mut attached = csm_rs::node::console::get_container_attachment_to_conman(
-    &xname.to_string(),
-    &k8s_details.api_url,
-    shasta_k8s_secrets,
-  )
-  .await
-  .expect("ERROR - Unable to attach to container");
+  let (mut stdin_writer, stdout) = backend
+    .attach_to_console(
+      auth_token,
+      site_name,
+      &xname,
+      CONSOLE_WIDTH,
+      CONSOLE_HEIGHT,
+      k8s_details_opt,
+    )
+    .await
+    .expect("ERROR - Unable to attach to container");
 
   // Hook stream from k8s conman container to the websocket
-  let stdout_stream = ReaderStream::new(attached.stdout().unwrap());
-
-  let mut stdin_writer = attached.stdin().unwrap();
+  let stdout_stream = ReaderStream::new(stdout);
 
   // This task will receive messages from the conman container and send them to the client
   let _send_task = tokio::spawn(async move {
@@ -750,128 +630,65 @@ fn process_message(msg: Message, who: SocketAddr) -> ControlFlow<(), ()> {
   ControlFlow::Continue(())
 }
 
-async fn get_service_health(
-  headers: HeaderMap,
-  service: &str,
-) -> Result<Json<serde_json::Value>> {
-  // Configuration
-  let settings = common::config::get_configuration().await?;
-
-  let configuration: MantaConfiguration = settings.try_deserialize()?;
-
-  let site_name: String = configuration.site;
-  let site_detail_value_opt = configuration.sites.get(&site_name);
-
-  let site = match site_detail_value_opt {
-    Some(site_detail_value) => site_detail_value,
-    None => bail!("ERROR - Site '{}' not found in configuration", site_name),
-  };
-
-  let backend_tech = &site.backend;
-  let shasta_base_url = &site.shasta_base_url;
-
-  let root_ca_cert_file = &site.root_ca_cert_file;
-
-  let shasta_root_cert =
-    common::config::get_csm_root_cert_content(&root_ca_cert_file)?;
+async fn get_service_health(req_cfg: &ReqCfg, service: &str) -> Response {
+  let backend_tech = &req_cfg.site_cfg.backend;
+  let shasta_base_url = &req_cfg.site_cfg.shasta_base_url;
+  let shasta_root_cert = &req_cfg.site_cfg.shasta_root_cert;
+  let auth_token = req_cfg.auth_token.as_str();
 
   // Backend
   let backend = StaticBackendDispatcher::new(
-    &backend_tech,
-    &shasta_base_url,
-    &shasta_root_cert,
+    backend_tech,
+    shasta_base_url,
+    shasta_root_cert,
   );
 
-  // Get auth token
-  let auth_header = headers.get("authorization").unwrap().to_str().unwrap();
-  let auth_token = auth_header.split(" ").nth(1).unwrap().to_string();
-
-  let response: Value = match service {
-    // NOTE: sending always 500 error is a BAD practice, we
-    // should do proper error handling by making mesa to return the right error code,
-    // then create the right HTTP status code based on it
-    "cfs" => {
-      csm_rs::cfs::common::health_check(
-        &auth_token,
-        &shasta_base_url,
-        &shasta_root_cert,
-      )
-      .await?
-    }
-    "bos" => {
-      csm_rs::bos::health_check::get(
-        &auth_token,
-        &shasta_base_url,
-        &shasta_root_cert,
-      )
-      .await?
-    }
-    _ => bail!("Invalid service name"),
-  };
-
-  Ok(Json(response))
-}
-
-async fn get_cfs_health_check(headers: HeaderMap) -> Response {
-  let response_rslt = get_service_health(headers, "cfs").await;
+  let response_rslt = backend.get_service_health(auth_token, service).await;
 
   match response_rslt {
-    Ok(response) => return response.into_response(),
+    Ok(response) => return (StatusCode::OK, Json(response)).into_response(),
     Err(e) => {
-      return (StatusCode::INTERNAL_SERVER_ERROR, Json(e.to_string()))
-        .into_response();
+      return (error::status_code(&e), Json(e.to_string())).into_response();
     }
   }
 }
 
-async fn get_bos_health_check(headers: HeaderMap) -> Response {
-  let response_rslt = get_service_health(headers, "bos").await;
+async fn get_cfs_health_check(headers: HeaderMap) -> Response {
+  let req_cfg = match get_req_cfg(&headers, None).await {
+    Ok(req_cfg) => req_cfg,
+    Err(e) => return e,
+  };
 
-  match response_rslt {
-    Ok(response) => return response.into_response(),
-    Err(e) => {
-      return (StatusCode::INTERNAL_SERVER_ERROR, Json(e.to_string()))
-        .into_response();
-    }
-  }
+  get_service_health(&req_cfg, "cfs").await
 }
 
-async fn get_all_bss_boot_parameters(headers: HeaderMap) -> Response {
-  // Configuration
-  let settings = common::config::get_configuration().await.unwrap();
-
-  let configuration: MantaConfiguration = settings.try_deserialize().unwrap();
-
-  let site_name: String = configuration.site;
-  let site_detail_value_opt = configuration.sites.get(&site_name);
-
-  let site = match site_detail_value_opt {
-    Some(site_detail_value) => site_detail_value,
-    None => {
-      eprintln!("ERROR - Site '{}' not found in configuration", site_name);
-      std::process::exit(1);
-    }
+async fn get_bos_health_check(headers: HeaderMap) -> Response {
+  let req_cfg = match get_req_cfg(&headers, None).await {
+    Ok(req_cfg) => req_cfg,
+    Err(e) => return e,
   };
 
-  let backend_tech = &site.backend;
-  let shasta_base_url = &site.shasta_base_url;
+  get_service_health(&req_cfg, "bos").await
+}
 
-  let root_ca_cert_file = &site.root_ca_cert_file;
+async fn get_all_bss_boot_parameters(headers: HeaderMap) -> Response {
+  let req_cfg = match get_req_cfg(&headers, None).await {
+    Ok(req_cfg) => req_cfg,
+    Err(e) => return e,
+  };
 
-  let shasta_root_cert =
-    common::config::get_csm_root_cert_content(&root_ca_cert_file).unwrap();
+  let backend_tech = &req_cfg.site_cfg.backend;
+  let shasta_base_url = &req_cfg.site_cfg.shasta_base_url;
+  let shasta_root_cert = &req_cfg.site_cfg.shasta_root_cert;
+  let auth_token = req_cfg.auth_token.as_str();
 
   // Backend
   let backend = StaticBackendDispatcher::new(
-    &backend_tech,
-    &shasta_base_url,
-    &shasta_root_cert,
+    backend_tech,
+    shasta_base_url,
+    shasta_root_cert,
   );
 
-  // Get auth token
-  let auth_header = headers.get("authorization").unwrap().to_str().unwrap();
-  let auth_token = auth_header.split(" ").nth(1).unwrap();
-
   let boot_parameters_rslt = backend.get_all_bootparameters(auth_token).await;
 
   match boot_parameters_rslt {
@@ -879,8 +696,7 @@ async fn get_all_bss_boot_parameters(headers: HeaderMap) -> Response {
       return (StatusCode::OK, Json(boot_parameters_vec)).into_response();
     }
     Err(e) => {
-      return (StatusCode::INTERNAL_SERVER_ERROR, Json(e.to_string()))
-        .into_response();
+      return (error::status_code(&e), Json(e.to_string())).into_response();
     }
   }
 }
@@ -889,49 +705,30 @@ async fn get_bss_boot_parameters(
   headers: HeaderMap,
   Path(xname): Path<String>,
 ) -> Response {
-  // Configuration
-  let settings = common::config::get_configuration().await.unwrap();
-
-  let configuration: MantaConfiguration = settings.try_deserialize().unwrap();
-
-  let site_name: String = configuration.site;
-  let site_detail_value_opt = configuration.sites.get(&site_name);
-
-  let site = match site_detail_value_opt {
-    Some(site_detail_value) => site_detail_value,
-    None => {
-      eprintln!("ERROR - Site '{}' not found in configuration", site_name);
-      std::process::exit(1);
-    }
+  let req_cfg = match get_req_cfg(&headers, None).await {
+    Ok(req_cfg) => req_cfg,
+    Err(e) => return e,
   };
 
-  let backend_tech = &site.backend;
-  let shasta_base_url = &site.shasta_base_url;
-
-  let root_ca_cert_file = &site.root_ca_cert_file;
-
-  let shasta_root_cert =
-    common::config::get_csm_root_cert_content(&root_ca_cert_file).unwrap();
+  let backend_tech = &req_cfg.site_cfg.backend;
+  let shasta_base_url = &req_cfg.site_cfg.shasta_base_url;
+  let shasta_root_cert = &req_cfg.site_cfg.shasta_root_cert;
+  let auth_token = req_cfg.auth_token.as_str();
 
   // Backend
   let backend = StaticBackendDispatcher::new(
-    &backend_tech,
-    &shasta_base_url,
-    &shasta_root_cert,
+    backend_tech,
+    shasta_base_url,
+    shasta_root_cert,
   );
 
-  // Get auth token
-  let auth_header = headers.get("authorization").unwrap().to_str().unwrap();
-  let auth_token = auth_header.split(" ").nth(1).unwrap();
-
   let boot_parameters_rslt =
     backend.get_bootparameters(auth_token, &[xname]).await;
 
   match boot_parameters_rslt {
     Ok(response) => return (StatusCode::OK, Json(response)).into_response(),
     Err(e) => {
-      return (StatusCode::INTERNAL_SERVER_ERROR, Json(e.to_string()))
-        .into_response();
+      return (error::status_code(&e), Json(e.to_string())).into_response();
     }
   }
 }
@@ -940,50 +737,48 @@ async fn post_bss_boot_parameters(
   headers: HeaderMap,
   Json(boot_parameters): Json<BootParameters>,
 ) -> Response {
-  // Configuration
-  let settings = common::config::get_configuration().await.unwrap();
-
-  let configuration: MantaConfiguration = settings.try_deserialize().unwrap();
-
-  let site_name: String = configuration.site;
-  let site_detail_value_opt = configuration.sites.get(&site_name);
-
-  let site = match site_detail_value_opt {
-    Some(site_detail_value) => site_detail_value,
-    None => {
-      eprintln!("ERROR - Site '{}' not found in configuration", site_name);
-      std::process::exit(1);
-    }
+  let req_cfg = match get_req_cfg(&headers, None).await {
+    Ok(req_cfg) => req_cfg,
+    Err(e) => return e,
   };
 
-  let backend_tech = &site.backend;
-  let shasta_base_url = &site.shasta_base_url;
-
-  let root_ca_cert_file = &site.root_ca_cert_file;
-
-  let shasta_root_cert =
-    common::config::get_csm_root_cert_content(&root_ca_cert_file).unwrap();
+  let site_name = &req_cfg.site_cfg.site;
+  let backend_tech = &req_cfg.site_cfg.backend;
+  let shasta_base_url = &req_cfg.site_cfg.shasta_base_url;
+  let shasta_root_cert = &req_cfg.site_cfg.shasta_root_cert;
+  let auth_token = req_cfg.auth_token.as_str();
 
   // Backend
   let backend = StaticBackendDispatcher::new(
-    &backend_tech,
-    &shasta_base_url,
-    &shasta_root_cert,
+    backend_tech,
+    shasta_base_url,
+    shasta_root_cert,
   );
 
-  // Get auth token
-  let auth_header = headers.get("authorization").unwrap().to_str().unwrap();
-  let auth_token = auth_header.split(" ").nth(1).unwrap();
+  let start = Instant::now();
 
   let bss_boot_parameters_rslt = backend
     .add_bootparameters(auth_token, &boot_parameters)
     .await;
 
+  // Audit
+  let audit_event = common::audit::new_event(
+    auth_token,
+    site_name,
+    backend_tech,
+    "boot-parameters.add",
+  )
+  .with_xnames(&boot_parameters.hosts)
+  .with_parameters(serde_json::to_value(&boot_parameters).unwrap_or_default())
+  .with_result(&bss_boot_parameters_rslt)
+  .with_duration(start.elapsed());
+
+  common::audit::send_event(req_cfg.auditor.as_ref(), &audit_event).await;
+
   match bss_boot_parameters_rslt {
     Ok(response) => return (StatusCode::OK, Json(response)).into_response(),
     Err(e) => {
-      return (StatusCode::INTERNAL_SERVER_ERROR, Json(e.to_string()))
-        .into_response();
+      return (error::status_code(&e), Json(e.to_string())).into_response();
     }
   }
 }
@@ -992,90 +787,69 @@ async fn delete_bss_boot_parameters(
   headers: HeaderMap,
   Json(boot_parameters): Json<BootParameters>,
 ) -> Response {
-  // Configuration
-  let settings = common::config::get_configuration().await.unwrap();
-
-  let configuration: MantaConfiguration = settings.try_deserialize().unwrap();
-
-  let site_name: String = configuration.site;
-  let site_detail_value_opt = configuration.sites.get(&site_name);
-
-  let site = match site_detail_value_opt {
-    Some(site_detail_value) => site_detail_value,
-    None => {
-      eprintln!("ERROR - Site '{}' not found in configuration", site_name);
-      std::process::exit(1);
-    }
+  let req_cfg = match get_req_cfg(&headers, None).await {
+    Ok(req_cfg) => req_cfg,
+    Err(e) => return e,
   };
 
-  let backend_tech = &site.backend;
-  let shasta_base_url = &site.shasta_base_url;
-
-  let root_ca_cert_file = &site.root_ca_cert_file;
-
-  let shasta_root_cert =
-    common::config::get_csm_root_cert_content(&root_ca_cert_file).unwrap();
+  let site_name = &req_cfg.site_cfg.site;
+  let backend_tech = &req_cfg.site_cfg.backend;
+  let shasta_base_url = &req_cfg.site_cfg.shasta_base_url;
+  let shasta_root_cert = &req_cfg.site_cfg.shasta_root_cert;
+  let auth_token = req_cfg.auth_token.as_str();
 
   // Backend
   let backend = StaticBackendDispatcher::new(
-    &backend_tech,
-    &shasta_base_url,
-    &shasta_root_cert,
+    backend_tech,
+    shasta_base_url,
+    shasta_root_cert,
   );
 
-  // Get auth token
-  let auth_header = headers.get("authorization").unwrap().to_str().unwrap();
-  let auth_token = auth_header.split(" ").nth(1).unwrap();
+  let start = Instant::now();
 
   let bss_boot_parameters_rslt = backend
     .delete_bootparameters(auth_token, &boot_parameters)
     .await;
 
+  // Audit
+  let audit_event = common::audit::new_event(
+    auth_token,
+    site_name,
+    backend_tech,
+    "boot-parameters.delete",
+  )
+  .with_xnames(&boot_parameters.hosts)
+  .with_result(&bss_boot_parameters_rslt)
+  .with_duration(start.elapsed());
+
+  common::audit::send_event(req_cfg.auditor.as_ref(), &audit_event).await;
+
   match bss_boot_parameters_rslt {
     Ok(response) => return (StatusCode::OK, Json(response)).into_response(),
     Err(e) => {
-      return (StatusCode::INTERNAL_SERVER_ERROR, Json(e.to_string()))
-        .into_response();
+      return (error::status_code(&e), Json(e.to_string())).into_response();
     }
   }
 }
 
 async fn get_all_groups(headers: HeaderMap) -> Response {
-  // Configuration
-  let settings = common::config::get_configuration().await.unwrap();
-
-  let configuration: MantaConfiguration = settings.try_deserialize().unwrap();
-
-  let site_name: String = configuration.site;
-  let site_detail_value_opt = configuration.sites.get(&site_name);
-
-  let site = match site_detail_value_opt {
-    Some(site_detail_value) => site_detail_value,
-    None => {
-      eprintln!("ERROR - Site '{}' not found in configuration", site_name);
-      std::process::exit(1);
-    }
+  let req_cfg = match get_req_cfg(&headers, None).await {
+    Ok(req_cfg) => req_cfg,
+    Err(e) => return e,
   };
 
-  let backend_tech = &site.backend;
-  let shasta_base_url = &site.shasta_base_url;
-
-  let root_ca_cert_file = &site.root_ca_cert_file;
-
-  let shasta_root_cert =
-    common::config::get_csm_root_cert_content(&root_ca_cert_file).unwrap();
+  let backend_tech = &req_cfg.site_cfg.backend;
+  let shasta_base_url = &req_cfg.site_cfg.shasta_base_url;
+  let shasta_root_cert = &req_cfg.site_cfg.shasta_root_cert;
+  let auth_token = req_cfg.auth_token.as_str();
 
   // Backend
   let backend = StaticBackendDispatcher::new(
-    &backend_tech,
-    &shasta_base_url,
-    &shasta_root_cert,
+    backend_tech,
+    shasta_base_url,
+    shasta_root_cert,
   );
 
-  // Get auth token
-  let auth_header = headers.get("authorization").unwrap().to_str().unwrap();
-  let auth_token = auth_header.split(" ").nth(1).unwrap();
-
   let hsm_group_available_name_vec = backend
     .get_group_available(auth_token)
     .await
@@ -1097,8 +871,7 @@ async fn get_all_groups(headers: HeaderMap) -> Response {
       return (StatusCode::OK, Json(response)).into_response();
     }
     Err(e) => {
-      return (StatusCode::INTERNAL_SERVER_ERROR, Json(e.to_string()))
-        .into_response();
+      return (error::status_code(&e), Json(e.to_string())).into_response();
     }
   }
 }
@@ -1107,60 +880,42 @@ async fn get_group_details(
   Path(group): Path<String>,
   headers: HeaderMap,
 ) -> Response {
-  // Configuration
-  let settings = common::config::get_configuration().await.unwrap();
-
-  let configuration: MantaConfiguration = settings.try_deserialize().unwrap();
-
-  let site_name: String = configuration.site;
-  let site_detail_value_opt = configuration.sites.get(&site_name);
-
-  let site = match site_detail_value_opt {
-    Some(site_detail_value) => site_detail_value,
-    None => {
-      eprintln!("ERROR - Site '{}' not found in configuration", site_name);
-      std::process::exit(1);
-    }
+  let req_cfg = match get_req_cfg(&headers, None).await {
+    Ok(req_cfg) => req_cfg,
+    Err(e) => return e,
   };
 
-  let backend_tech = &site.backend;
-  let shasta_base_url = &site.shasta_base_url;
-
-  let root_ca_cert_file = &site.root_ca_cert_file;
-
-  let shasta_root_cert =
-    common::config::get_csm_root_cert_content(&root_ca_cert_file).unwrap();
+  let backend_tech = &req_cfg.site_cfg.backend;
+  let shasta_base_url = &req_cfg.site_cfg.shasta_base_url;
+  let shasta_root_cert = &req_cfg.site_cfg.shasta_root_cert;
+  let auth_token = req_cfg.auth_token.as_str();
 
   // Backend
   let backend = StaticBackendDispatcher::new(
-    &backend_tech,
-    &shasta_base_url,
-    &shasta_root_cert,
+    backend_tech,
+    shasta_base_url,
+    shasta_root_cert,
   );
 
-  // Get auth token
-  let auth_header = headers.get("authorization").unwrap().to_str().unwrap();
-  let auth_token = auth_header.split(" ").nth(1).unwrap();
-
-  let group = backend.get_group(&auth_token, &group).await.unwrap();
+  let group = match backend.get_group(auth_token, &group).await {
+    Ok(group) => group,
+    Err(e) => {
+      return (error::status_code(&e), Json(e.to_string())).into_response();
+    }
+  };
 
   let hsm_groups_node_list = group.get_members();
 
-  let response_rslt = csm_rs::node::utils::get_node_details(
-    &auth_token,
-    &shasta_base_url,
-    &shasta_root_cert,
-    hsm_groups_node_list,
-  )
-  .await;
+  let response_rslt = backend
+    .get_node_details(auth_token, &hsm_groups_node_list)
+    .await;
 
   match response_rslt {
     Ok(response) => {
       return (StatusCode::OK, Json(response)).into_response();
     }
     Err(e) => {
-      return (StatusCode::INTERNAL_SERVER_ERROR, Json(e.to_string()))
-        .into_response();
+      return (error::status_code(&e), Json(e.to_string())).into_response();
     }
   }
 }
@@ -1169,57 +924,33 @@ async fn get_hsm_hardware(
   headers: HeaderMap,
   Path(group): Path<String>,
 ) -> Response {
-  // Configuration
-  let settings = common::config::get_configuration().await.unwrap();
-
-  let configuration: MantaConfiguration = settings.try_deserialize().unwrap();
-
-  let site_name: String = configuration.site;
-  let site_detail_value_opt = configuration.sites.get(&site_name);
-
-  let site = match site_detail_value_opt {
-    Some(site_detail_value) => site_detail_value,
-    None => {
-      eprintln!("ERROR - Site '{}' not found in configuration", site_name);
-      std::process::exit(1);
-    }
+  let req_cfg = match get_req_cfg(&headers, None).await {
+    Ok(req_cfg) => req_cfg,
+    Err(e) => return e,
   };
 
-  let backend_tech = &site.backend;
-  let shasta_base_url = &site.shasta_base_url;
-
-  let root_ca_cert_file = &site.root_ca_cert_file;
-
-  let shasta_root_cert =
-    common::config::get_csm_root_cert_content(&root_ca_cert_file).unwrap();
+  let backend_tech = &req_cfg.site_cfg.backend;
+  let shasta_base_url = &req_cfg.site_cfg.shasta_base_url;
+  let shasta_root_cert = &req_cfg.site_cfg.shasta_root_cert;
+  let auth_token = req_cfg.auth_token.as_str();
 
   // Backend
   let backend = StaticBackendDispatcher::new(
-    &backend_tech,
-    &shasta_base_url,
-    &shasta_root_cert,
+    backend_tech,
+    shasta_base_url,
+    shasta_root_cert,
   );
 
-  // Get auth token
-  let auth_header = headers.get("authorization").unwrap().to_str().unwrap();
-  let auth_token = auth_header.split(" ").nth(1).unwrap();
-
-  let hsm_group = csm_rs::hsm::group::http_client::get(
-    &auth_token,
-    &shasta_base_url,
-    &shasta_root_cert,
-    Some(&[&group]),
-    None,
-  )
-  .await
-  .unwrap();
+  let hsm_group = match backend.get_group(auth_token, &group).await {
+    Ok(hsm_group) => hsm_group,
+    Err(e) => {
+      return (error::status_code(&e), Json(e.to_string())).into_response();
+    }
+  };
 
-  let hsm_group_target_members =
-    csm_rs::hsm::group::utils::get_member_vec_from_hsm_group(
-      &hsm_group.first().unwrap(),
-    );
+  let hsm_group_target_members = hsm_group.get_members();
 
-  let mut hsm_summary: Vec<NodeSummary> = Vec::new();
+  let mut hsm_summary: Vec<Value> = Vec::new();
 
   let mut tasks = tokio::task::JoinSet::new();
 
@@ -1228,9 +959,8 @@ async fn get_hsm_hardware(
 
   // Get HW inventory details for target HSM group
   for hsm_member in hsm_group_target_members.clone() {
+    let backend_cloned = backend.clone();
     let shasta_token_string = auth_token.to_string(); // TODO: make it static
-    let shasta_base_url_string = shasta_base_url.to_string(); // TODO: make it static
-    let shasta_root_cert_vec = shasta_root_cert.to_vec();
     let hsm_member_string = hsm_member.to_string(); // TODO: make it static
     //
     let permit = Arc::clone(&sem).acquire_owned().await;
@@ -1239,22 +969,21 @@ async fn get_hsm_hardware(
 
     tasks.spawn(async move {
       let _permit = permit; // Wait semaphore to allow new tasks https://github.com/tokio-rs/tokio/discussions/2648#discussioncomment-34885
-      csm_rs::hsm::hw_inventory::hw_component::http_client::get(
-        &shasta_token_string,
-        &shasta_base_url_string,
-        &shasta_root_cert_vec,
-        &hsm_member_string,
-      )
-      .await
-      .unwrap()
+      backend_cloned
+        .get_inventory_hardware(&shasta_token_string, &hsm_member_string)
+        .await
     });
   }
 
   while let Some(message_rslt) = tasks.join_next().await {
     match message_rslt {
-      Ok(node_summary) => {
+      Ok(Ok(node_summary)) => {
         hsm_summary.push(node_summary);
       }
+      Ok(Err(e)) => {
+        tracing::error!("Failed fetching node hw information");
+        return (error::status_code(&e), Json(e.to_string())).into_response();
+      }
       Err(e) => {
         tracing::error!("Failed procesing/fetching node hw information");
         return (StatusCode::INTERNAL_SERVER_ERROR, Json(e.to_string()))
@@ -1274,48 +1003,44 @@ async fn power_off_node(
 ) -> Response {
   tracing::info!("Power OFF node {}", node);
 
-  // Configuration
-  let settings = common::config::get_configuration().await.unwrap();
-
-  let configuration: MantaConfiguration = settings.try_deserialize().unwrap();
-
-  let site_name: String = configuration.site;
-  let site_detail_value_opt = configuration.sites.get(&site_name);
-
-  let site = match site_detail_value_opt {
-    Some(site_detail_value) => site_detail_value,
-    None => {
-      eprintln!("ERROR - Site '{}' not found in configuration", site_name);
-      std::process::exit(1);
-    }
+  let req_cfg = match get_req_cfg(&headers, None).await {
+    Ok(req_cfg) => req_cfg,
+    Err(e) => return e,
   };
 
-  let backend_tech = &site.backend;
-  let shasta_base_url = &site.shasta_base_url;
-
-  let root_ca_cert_file = &site.root_ca_cert_file;
-
-  let shasta_root_cert =
-    common::config::get_csm_root_cert_content(&root_ca_cert_file).unwrap();
+  let site_name = &req_cfg.site_cfg.site;
+  let backend_tech = &req_cfg.site_cfg.backend;
+  let shasta_base_url = &req_cfg.site_cfg.shasta_base_url;
+  let shasta_root_cert = &req_cfg.site_cfg.shasta_root_cert;
+  let auth_token = req_cfg.auth_token.as_str();
 
   // Backend
   let backend = StaticBackendDispatcher::new(
-    &backend_tech,
-    &shasta_base_url,
-    &shasta_root_cert,
+    backend_tech,
+    shasta_base_url,
+    shasta_root_cert,
   );
 
-  // Get auth token
-  let auth_header = headers.get("authorization").unwrap().to_str().unwrap();
-  let auth_token = auth_header.split(" ").nth(1).unwrap();
+  let start = Instant::now();
+
+  let response_rslt = backend
+    .power_off_sync(auth_token, &[node.clone()], true)
+    .await;
 
-  let response_rslt = backend.power_off_sync(auth_token, &[node], true).await;
+  // Audit
+  let audit_event =
+    common::audit::new_event(auth_token, site_name, backend_tech, "power.off")
+      .with_xnames(&[&node])
+      .with_parameters(serde_json::json!({ "force": true }))
+      .with_result(&response_rslt)
+      .with_duration(start.elapsed());
+
+  common::audit::send_event(req_cfg.auditor.as_ref(), &audit_event).await;
 
   match response_rslt {
     Ok(_) => return (StatusCode::OK, ()).into_response(),
     Err(e) => {
-      return (StatusCode::INTERNAL_SERVER_ERROR, Json(e.to_string()))
-        .into_response();
+      return (error::status_code(&e), Json(e.to_string())).into_response();
     }
   }
 }
@@ -1327,48 +1052,41 @@ async fn power_on_node(
 ) -> Response {
   tracing::info!("Power ON node {}", node);
 
-  // Configuration
-  let settings = common::config::get_configuration().await.unwrap();
-
-  let configuration: MantaConfiguration = settings.try_deserialize().unwrap();
-
-  let site_name: String = configuration.site;
-  let site_detail_value_opt = configuration.sites.get(&site_name);
-
-  let site = match site_detail_value_opt {
-    Some(site_detail_value) => site_detail_value,
-    None => {
-      eprintln!("ERROR - Site '{}' not found in configuration", site_name);
-      std::process::exit(1);
-    }
+  let req_cfg = match get_req_cfg(&headers, None).await {
+    Ok(req_cfg) => req_cfg,
+    Err(e) => return e,
   };
 
-  let backend_tech = &site.backend;
-  let shasta_base_url = &site.shasta_base_url;
-
-  let root_ca_cert_file = &site.root_ca_cert_file;
-
-  let shasta_root_cert =
-    common::config::get_csm_root_cert_content(&root_ca_cert_file).unwrap();
+  let site_name = &req_cfg.site_cfg.site;
+  let backend_tech = &req_cfg.site_cfg.backend;
+  let shasta_base_url = &req_cfg.site_cfg.shasta_base_url;
+  let shasta_root_cert = &req_cfg.site_cfg.shasta_root_cert;
+  let auth_token = req_cfg.auth_token.as_str();
 
   // Backend
   let backend = StaticBackendDispatcher::new(
-    &backend_tech,
-    &shasta_base_url,
-    &shasta_root_cert,
+    backend_tech,
+    shasta_base_url,
+    shasta_root_cert,
   );
 
-  // Get auth token
-  let auth_header = headers.get("authorization").unwrap().to_str().unwrap();
-  let auth_token = auth_header.split(" ").nth(1).unwrap();
+  let start = Instant::now();
+
+  let response_rslt = backend.power_on_sync(auth_token, &[node.clone()]).await;
+
+  // Audit
+  let audit_event =
+    common::audit::new_event(auth_token, site_name, backend_tech, "power.on")
+      .with_xnames(&[&node])
+      .with_result(&response_rslt)
+      .with_duration(start.elapsed());
 
-  let response_rslt = backend.power_on_sync(auth_token, &[node]).await;
+  common::audit::send_event(req_cfg.auditor.as_ref(), &audit_event).await;
 
   match response_rslt {
     Ok(_) => return (StatusCode::OK, ()).into_response(),
     Err(e) => {
-      return (StatusCode::INTERNAL_SERVER_ERROR, Json(e.to_string()))
-        .into_response();
+      return (error::status_code(&e), Json(e.to_string())).into_response();
     }
   }
 }
@@ -1379,48 +1097,48 @@ async fn power_reset_node(
 ) -> Response {
   tracing::debug!("Power RESET node {}", node);
 
-  // Configuration
-  let settings = common::config::get_configuration().await.unwrap();
-
-  let configuration: MantaConfiguration = settings.try_deserialize().unwrap();
-
-  let site_name: String = configuration.site;
-  let site_detail_value_opt = configuration.sites.get(&site_name);
-
-  let site = match site_detail_value_opt {
-    Some(site_detail_value) => site_detail_value,
-    None => {
-      eprintln!("ERROR - Site '{}' not found in configuration", site_name);
-      std::process::exit(1);
-    }
+  let req_cfg = match get_req_cfg(&headers, None).await {
+    Ok(req_cfg) => req_cfg,
+    Err(e) => return e,
   };
 
-  let backend_tech = &site.backend;
-  let shasta_base_url = &site.shasta_base_url;
-
-  let root_ca_cert_file = &site.root_ca_cert_file;
-
-  let shasta_root_cert =
-    common::config::get_csm_root_cert_content(&root_ca_cert_file).unwrap();
+  let site_name = &req_cfg.site_cfg.site;
+  let backend_tech = &req_cfg.site_cfg.backend;
+  let shasta_base_url = &req_cfg.site_cfg.shasta_base_url;
+  let shasta_root_cert = &req_cfg.site_cfg.shasta_root_cert;
+  let auth_token = req_cfg.auth_token.as_str();
 
   // Backend
   let backend = StaticBackendDispatcher::new(
-    &backend_tech,
-    &shasta_base_url,
-    &shasta_root_cert,
+    backend_tech,
+    shasta_base_url,
+    shasta_root_cert,
   );
 
-  // Get auth token
-  let auth_header = headers.get("authorization").unwrap().to_str().unwrap();
-  let auth_token = auth_header.split(" ").nth(1).unwrap();
+  let start = Instant::now();
 
-  let response_rslt = backend.power_on_sync(auth_token, &[node]).await;
+  let response_rslt = backend
+    .power_reset_sync(auth_token, &[node.clone()], true)
+    .await;
+
+  // Audit
+  let audit_event = common::audit::new_event(
+    auth_token,
+    site_name,
+    backend_tech,
+    "power.reset",
+  )
+  .with_xnames(&[&node])
+  .with_parameters(serde_json::json!({ "force": true }))
+  .with_result(&response_rslt)
+  .with_duration(start.elapsed());
+
+  common::audit::send_event(req_cfg.auditor.as_ref(), &audit_event).await;
 
   match response_rslt {
     Ok(_) => return (StatusCode::OK, ()).into_response(),
     Err(e) => {
-      return (StatusCode::INTERNAL_SERVER_ERROR, Json(e.to_string()))
-        .into_response();
+      return (error::status_code(&e), Json(e.to_string())).into_response();
     }
   }
 }
@@ -1439,41 +1157,23 @@ async fn power_status_node(
 ) -> Result<impl IntoResponse, impl IntoResponse> {
   tracing::debug!("Power STATUS node {}", node);
 
-  // Configuration
-  let settings = common::config::get_configuration().await.unwrap();
-
-  let configuration: MantaConfiguration = settings.try_deserialize().unwrap();
-
-  let site_name: String = configuration.site;
-  let site_detail_value_opt = configuration.sites.get(&site_name);
-
-  let site = match site_detail_value_opt {
-    Some(site_detail_value) => site_detail_value,
-    None => {
-      eprintln!("ERROR - Site '{}' not found in configuration", site_name);
-      std::process::exit(1);
-    }
+  let req_cfg = match get_req_cfg(&headers, None).await {
+    Ok(req_cfg) => req_cfg,
+    Err(e) => return Err(e.status()),
   };
 
-  let backend_tech = &site.backend;
-  let shasta_base_url = &site.shasta_base_url;
-
-  let root_ca_cert_file = &site.root_ca_cert_file;
-
-  let shasta_root_cert =
-    common::config::get_csm_root_cert_content(&root_ca_cert_file).unwrap();
+  let backend_tech = &req_cfg.site_cfg.backend;
+  let shasta_base_url = &req_cfg.site_cfg.shasta_base_url;
+  let shasta_root_cert = &req_cfg.site_cfg.shasta_root_cert;
+  let auth_token = req_cfg.auth_token.as_str();
 
   // Backend
   let backend = StaticBackendDispatcher::new(
-    &backend_tech,
-    &shasta_base_url,
-    &shasta_root_cert,
+    backend_tech,
+    shasta_base_url,
+    shasta_root_cert,
   );
 
-  // Get auth token
-  let auth_header = headers.get("authorization").unwrap().to_str().unwrap();
-  let auth_token = auth_header.split(" ").nth(1).unwrap();
-
   let response = backend
     .power_status(
       auth_token,
@@ -1513,45 +1213,30 @@ async fn node_migration(
   let ids = query_param.ids;
   let create_hsm_group = query_param.create_hsm_group;
 
-  let settings = get_configuration();
-
-  let site_detail_hashmap = settings.get_table("sites").unwrap();
-  let site_detail_value = site_detail_hashmap
-    .get("alps")
-    .unwrap()
-    .clone()
-    .into_table()
-    .unwrap();
-
-  let shasta_base_url = site_detail_value
-    .get("shasta_base_url")
-    .unwrap()
-    .to_string();
+  let req_cfg = match get_req_cfg(&headers, None).await {
+    Ok(req_cfg) => req_cfg,
+    Err(e) => return e,
+  };
 
-  let shasta_root_cert = get_csm_root_cert_content("alps");
+  let site_name = &req_cfg.site_cfg.site;
+  let backend_tech = &req_cfg.site_cfg.backend;
+  let shasta_base_url = &req_cfg.site_cfg.shasta_base_url;
+  let shasta_root_cert = &req_cfg.site_cfg.shasta_root_cert;
+  let auth_token = req_cfg.auth_token.as_str();
 
-  // Get auth token
-  let auth_token = if let Some(auth_header) = headers.get("authorization") {
-    auth_header.to_str().unwrap().split(" ").nth(1).unwrap()
-  } else {
-    return (StatusCode::UNAUTHORIZED).into_response();
-  };
+  // Backend
+  let backend = StaticBackendDispatcher::new(
+    backend_tech,
+    shasta_base_url,
+    shasta_root_cert,
+  );
 
   let new_target_hsm_members = ids
     .split(',')
     .map(|xname| xname.trim())
     .collect::<Vec<&str>>();
 
-  if csm_rs::hsm::group::http_client::get(
-    auth_token,
-    &shasta_base_url,
-    &shasta_root_cert,
-    Some(&[&target]),
-    None,
-  )
-  .await
-  .is_ok()
-  {
+  if backend.get_group(auth_token, &target).await.is_ok() {
     tracing::debug!("The HSM group {} exists, good.", target);
   } else {
     if create_hsm_group {
@@ -1559,18 +1244,12 @@ async fn node_migration(
         "HSM group {} does not exist, but the option to create the group has been selected, creating it now.",
         target.to_string()
       );
-      csm_rs::hsm::group::http_client::create_new_group(
-        auth_token,
-        &shasta_base_url,
-        &shasta_root_cert,
-        &target,
-        &[],
-        "false",
-        "",
-        &[],
-      )
-      .await
-      .expect("Unable to create new HSM group");
+      let new_group = Group::new(&target, None, None, None, None);
+
+      if let Err(e) = backend.add_group(auth_token, new_group).await {
+        tracing::error!("Unable to create new HSM group. Reason: {}", e);
+        return (error::status_code(&e), Json(e.to_string())).into_response();
+      }
     } else {
       tracing::error!(
         "HSM group {} does not exist, but the option to create the group was NOT specificied, cannot continue.",
@@ -1580,16 +1259,38 @@ async fn node_migration(
     }
   }
 
-  let _ = csm_rs::hsm::group::utils::migrate_hsm_members(
+  let start = Instant::now();
+
+  let migration_rslt = backend
+    .migrate_group_members(
+      auth_token,
+      &target,
+      &parent,
+      new_target_hsm_members.clone(),
+    )
+    .await;
+
+  // Audit
+  let audit_event = common::audit::new_event(
     auth_token,
-    &shasta_base_url,
-    &shasta_root_cert,
-    &target,
-    &parent,
-    new_target_hsm_members,
-    true,
+    site_name,
+    backend_tech,
+    "group.migrate-members",
   )
-  .await;
-
-  return ().into_response();
+  .with_xnames(&new_target_hsm_members)
+  .with_groups(&[&target, &parent])
+  .with_parameters(serde_json::json!({
+    "create_hsm_group": create_hsm_group,
+  }))
+  .with_result(&migration_rslt)
+  .with_duration(start.elapsed());
+
+  common::audit::send_event(req_cfg.auditor.as_ref(), &audit_event).await;
+
+  match migration_rslt {
+    Ok(_) => return ().into_response(),
+    Err(e) => {
+      return (error::status_code(&e), Json(e.to_string())).into_response();
+    }
+  }
 }
@@ -18,6 +18,8 @@ use manta_backend_dispatcher::{
     apply_session::ApplySessionTrait,
     bss::BootParametersTrait,
     cfs::CfsTrait,
+    console::ConsoleTrait,
+    health::HealthTrait,
     hsm::{
       component::ComponentTrait, group::GroupTrait,
       hardware_inventory::HardwareInventory,
@@ -32,17 +34,16 @@ use manta_backend_dispatcher::{
     BootParameters, Component, ComponentArrayPostArray, Group,
     HWInventoryByLocationList, K8sDetails, NodeMetadataArray,
     bos::session_template::BosSessionTemplate,
+    capability::Capability,
     cfs::{
       cfs_configuration_details::LayerDetails,
       cfs_configuration_request::CfsConfigurationRequest,
       cfs_configuration_response::{CfsConfigurationResponse, Layer},
       session::{CfsSessionGetResponse, CfsSessionPostRequest},
     },
-    pcs::{
-        power_status::types::PowerStatusAll as FrontEndPowerStatusAll
-    },
     hsm::inventory::{RedfishEndpoint, RedfishEndpointArray},
     ims::Image,
+    pcs::power_status::types::PowerStatusAll as FrontEndPowerStatusAll,
   },
 };
 
@@ -52,6 +53,7 @@ use futures::AsyncBufRead;
 use csm_rs::backend_connector::Csm;
 use ochami_rs::backend_connector::Ochami;
 use serde_json::Value;
+use tokio::io::{AsyncRead, AsyncWrite};
 
 #[derive(Clone)]
 pub enum StaticBackendDispatcher {
@@ -503,6 +505,17 @@ impl ComponentTrait for StaticBackendDispatcher {
       OCHAMI(b) => b.delete_node(auth_token, id).await,
     }
   }
+
+  async fn get_node_details(
+    &self,
+    auth_token: &str,
+    xname_vec: &[String],
+  ) -> Result<Value, Error> {
+    match self {
+      CSM(b) => b.get_node_details(auth_token, xname_vec).await,
+      OCHAMI(b) => b.get_node_details(auth_token, xname_vec).await,
+    }
+  }
 }
 
 impl PCSTrait for StaticBackendDispatcher {
@@ -547,11 +560,26 @@ impl PCSTrait for StaticBackendDispatcher {
     nodes: &[String],
     power_status_filter: Option<&str>,
     management_state_filter: Option<&str>,
-
   ) -> Result<FrontEndPowerStatusAll, Error> {
     match self {
-      CSM(b) => b.power_status(auth_token, nodes, power_status_filter, management_state_filter).await,
-      OCHAMI(b) => b.power_status(auth_token, nodes, power_status_filter, management_state_filter).await,
+      CSM(b) => {
+        b.power_status(
+          auth_token,
+          nodes,
+          power_status_filter,
+          management_state_filter,
+        )
+        .await
+      }
+      OCHAMI(b) => {
+        b.power_status(
+          auth_token,
+          nodes,
+          power_status_filter,
+          management_state_filter,
+        )
+        .await
+      }
     }
   }
 }
@@ -710,6 +738,13 @@ impl BackendTrait for StaticBackendDispatcher {
     "in manta backend".to_string()
   }
 
+  fn get_capabilities(&self) -> Vec<Capability> {
+    match self {
+      CSM(b) => b.get_capabilities(),
+      OCHAMI(b) => b.get_capabilities(),
+    }
+  }
+
   // AUTHENTICATION
   async fn get_api_token(&self, site_name: &str) -> Result<String, Error> {
     match self {
@@ -718,6 +753,17 @@ impl BackendTrait for StaticBackendDispatcher {
     }
   }
 
+  async fn authenticate(
+    &self,
+    username: &str,
+    password: &str,
+  ) -> Result<String, Error> {
+    match self {
+      CSM(b) => b.authenticate(username, password).await,
+      OCHAMI(b) => b.authenticate(username, password).await,
+    }
+  }
+
   async fn nid_to_xname(
     &self,
     auth_token: &str,
@@ -1195,10 +1241,8 @@ impl SatTrait for StaticBackendDispatcher {
     shasta_token: &str,
     shasta_base_url: &str,
     shasta_root_cert: &[u8],
-    vault_base_url: &str,
     vault_secret_path: &str,
-    k8s_api_url: &str,
-    shasta_k8s_secrets: serde_json::Value,
+    k8s: &K8sDetails,
     sat_template_file_yaml: serde_yaml::Value,
     hsm_group_available_vec: &Vec<String>,
     ansible_verbosity_opt: Option<u8>,
@@ -1216,10 +1260,8 @@ impl SatTrait for StaticBackendDispatcher {
           shasta_token,
           shasta_base_url,
           shasta_root_cert,
-          vault_base_url,
           vault_secret_path,
-          k8s_api_url,
-          shasta_k8s_secrets,
+          k8s,
           sat_template_file_yaml,
           hsm_group_available_vec,
           ansible_verbosity_opt,
@@ -1238,10 +1280,8 @@ impl SatTrait for StaticBackendDispatcher {
           shasta_token,
           shasta_base_url,
           shasta_root_cert,
-          vault_base_url,
           vault_secret_path,
-          k8s_api_url,
-          shasta_k8s_secrets,
+          k8s,
           sat_template_file_yaml,
           hsm_group_available_vec,
           ansible_verbosity_opt,
@@ -1409,11 +1449,11 @@ impl MigrateRestoreTrait for StaticBackendDispatcher {
     shasta_token: &str,
     shasta_base_url: &str,
     shasta_root_cert: &[u8],
-    bos_file: Option<&String>,
-    cfs_file: Option<&String>,
-    hsm_file: Option<&String>,
-    ims_file: Option<&String>,
-    image_dir: Option<&String>,
+    backup_dir: &str,
+    bos_sessiontemplate_name_vec: &[String],
+    concurrency: usize,
+    assume_yes: bool,
+    overwrite_groups: bool,
   ) -> Result<(), Error> {
     match self {
       CSM(b) => {
@@ -1421,11 +1461,11 @@ impl MigrateRestoreTrait for StaticBackendDispatcher {
           shasta_token,
           shasta_base_url,
           shasta_root_cert,
-          bos_file,
-          cfs_file,
-          hsm_file,
-          ims_file,
-          image_dir,
+          backup_dir,
+          bos_sessiontemplate_name_vec,
+          concurrency,
+          assume_yes,
+          overwrite_groups,
         )
         .await
       }
@@ -1434,11 +1474,11 @@ impl MigrateRestoreTrait for StaticBackendDispatcher {
           shasta_token,
           shasta_base_url,
           shasta_root_cert,
-          bos_file,
-          cfs_file,
-          hsm_file,
-          ims_file,
-          image_dir,
+          backup_dir,
+          bos_sessiontemplate_name_vec,
+          concurrency,
+          assume_yes,
+          overwrite_groups,
         )
         .await
       }
@@ -1452,8 +1492,10 @@ impl MigrateBackupTrait for StaticBackendDispatcher {
     shasta_token: &str,
     shasta_base_url: &str,
     shasta_root_cert: &[u8],
-    bos: Option<&String>,
-    destination: Option<&String>,
+    bos_sessiontemplate_name_vec: &[String],
+    hsm_group_name_vec: &[String],
+    destination: &str,
+    concurrency: usize,
   ) -> Result<(), Error> {
     match self {
       CSM(b) => {
@@ -1461,8 +1503,10 @@ impl MigrateBackupTrait for StaticBackendDispatcher {
           shasta_token,
           shasta_base_url,
           shasta_root_cert,
-          bos,
+          bos_sessiontemplate_name_vec,
+          hsm_group_name_vec,
           destination,
+          concurrency,
         )
         .await
       }
@@ -1471,11 +1515,85 @@ impl MigrateBackupTrait for StaticBackendDispatcher {
           shasta_token,
           shasta_base_url,
           shasta_root_cert,
-          bos,
+          bos_sessiontemplate_name_vec,
+          hsm_group_name_vec,
           destination,
+          concurrency,
         )
         .await
       }
     }
   }
 }
+
+impl ConsoleTrait for StaticBackendDispatcher {
+  type T = Box<dyn AsyncWrite + Unpin + Send>;
+  type U = Box<dyn AsyncRead + Unpin + Send>;
+
+  async fn attach_to_console(
+    &self,
+    shasta_token: &str,
+    site_name: &str,
+    xname: &str,
+    width: u16,
+    height: u16,
+    k8s_opt: Option<&K8sDetails>,
+  ) -> Result<(Self::T, Self::U), Error> {
+    match self {
+      CSM(b) => {
+        b.attach_to_console(
+          shasta_token,
+          site_name,
+          xname,
+          width,
+          height,
+          k8s_opt,
+        )
+        .await
+      }
+      OCHAMI(b) => {
+        b.attach_to_console(
+          shasta_token,
+          site_name,
+          xname,
+          width,
+          height,
+          k8s_opt,
+        )
+        .await
+      }
+    }
+  }
+
+  async fn attach_to_console_read_only(
+    &self,
+    shasta_token: &str,
+    site_name: &str,
+    xname: &str,
+    k8s_opt: Option<&K8sDetails>,
+  ) -> Result<Self::U, Error> {
+    match self {
+      CSM(b) => {
+        b.attach_to_console_read_only(shasta_token, site_name, xname, k8s_opt)
+          .await
+      }
+      OCHAMI(b) => {
+        b.attach_to_console_read_only(shasta_token, site_name, xname, k8s_opt)
+          .await
+      }
+    }
+  }
+}
+
+impl HealthTrait for StaticBackendDispatcher {
+  async fn get_service_health(
+    &self,
+    auth_token: &str,
+    service: &str,
+  ) -> Result<Value, Error> {
+    match self {
+      CSM(b) => b.get_service_health(auth_token, service).await,
+      OCHAMI(b) => b.get_service_health(auth_token, service).await,
+    }
+  }
+}
@@ -17,11 +17,17 @@ pub fn build_cli() -> Command {
     .subcommand(subcommand_delete())
     .subcommand(subcommand_migrate())
     .subcommand(subcommand_power())
+    .subcommand(subcommand_lock())
+    .subcommand(subcommand_unlock())
+    .subcommand(subcommand_undo())
+    .subcommand(subcommand_promote())
     .subcommand(subcommand_log())
     .subcommand(subcommand_console())
     .subcommand(subcommand_validate_local_repo())
     .subcommand(subcommand_add_nodes_to_groups())
     .subcommand(subcommand_remove_nodes_from_groups())
+    .subcommand(subcommand_add_nodes_to_partition())
+    .subcommand(subcommand_remove_nodes_from_partition())
   /* .subcommand(subcommand_download_boot_image())
   .subcommand(subcommand_upload_bood_image()) */
 }
@@ -75,7 +81,11 @@ pub fn subcommand_config() -> Command {
     // .visible_alias("C")
     .arg_required_else_help(true)
     .about("Manta's configuration")
-    .subcommand(Command::new("show").about("Show config values"))
+    .subcommand(
+      Command::new("show")
+        .about("Show config values")
+        .arg(arg!(-c --capabilities "Show the operations supported by the backend of each site configured").action(ArgAction::SetTrue)),
+    )
     .subcommand(
       Command::new("set")
         .arg_required_else_help(true)
@@ -114,10 +124,14 @@ pub fn subcommand_delete() -> Command {
     .arg_required_else_help(true)
     .about("Deletes data")
     .subcommand(subcommand_delete_group())
+    .subcommand(subcommand_delete_partition())
+    .subcommand(subcommand_delete_interface())
     .subcommand(subcommand_delete_node())
+    .subcommand(subcommand_delete_nodemap())
     .subcommand(subcommand_delete_kernel_parameter())
     .subcommand(subcommand_delete_boot_parameter())
     .subcommand(subcommand_delete_configuration())
+    .subcommand(subcommand_delete_garbage())
     .subcommand(subcommand_delete_session())
     .subcommand(subcommand_delete_image())
     .subcommand(subcommand_delete_hw_component())
@@ -134,6 +148,30 @@ pub fn subcommand_delete_group() -> Command {
     .arg(arg!(<VALUE> "Group name to delete").required(true))
 }
 
+pub fn subcommand_delete_partition() -> Command {
+  Command::new("partition")
+    .arg_required_else_help(true)
+    .about("Delete partition")
+    .arg(arg!(-y --"assume-yes" "Automatic yes to prompts; assume 'yes' as answer to all prompts and run non-interactively.").action(ArgAction::SetTrue))
+    .arg(arg!(<VALUE> "Partition name to delete").required(true))
+}
+
+pub fn subcommand_delete_nodemap() -> Command {
+  Command::new("nodemap")
+    .arg_required_else_help(true)
+    .about("Delete the node map (default NID and role) of a node")
+    .arg(arg!(-y --"assume-yes" "Automatic yes to prompts; assume 'yes' as answer to all prompts and run non-interactively.").action(ArgAction::SetTrue))
+    .arg(arg!(<XNAME> "Xname of the node map to delete").required(true))
+}
+
+pub fn subcommand_delete_interface() -> Command {
+  Command::new("interface")
+    .arg_required_else_help(true)
+    .about("Delete ethernet interface")
+    .arg(arg!(-y --"assume-yes" "Automatic yes to prompts; assume 'yes' as answer to all prompts and run non-interactively.").action(ArgAction::SetTrue))
+    .arg(arg!(<ID> "Ethernet interface ID to delete. Usually the MAC address in lowercase without ':'").required(true))
+}
+
 pub fn subcommand_delete_node() -> Command {
   Command::new("node")
     // .visible_alias("g")
@@ -181,6 +219,27 @@ pub fn subcommand_delete_configuration() -> Command {
     .group(ArgGroup::new("since_and_until").args(["since", "until"]).multiple(true).requires("until").conflicts_with("configuration-name"))
 }
 
+pub fn subcommand_delete_garbage() -> Command {
+  Command::new("garbage")
+    .visible_alias("gc")
+    .arg_required_else_help(true)
+    .about("Deletes CFS configurations and images of the groups not kept by a garbage collection policy and old failed CFS sessions. CFS configurations and images used by nodes or BOS sessiontemplates are never deleted.")
+    .arg(arg!(-H --"hsm-group" <HSM_GROUP> "Group to collect garbage from. Defaults to all groups available"))
+    .arg(arg!(-f --"policy-file" <PATH> "YAML file with the garbage collection policy. Arguments below take precedence over it.\neg:\nkeep_configurations: 5\nkeep_images: 3\nfailed_session_min_age: 7d").value_parser(value_parser!(PathBuf)).value_hint(ValueHint::FilePath))
+    .arg(arg!(--"keep-configurations" <NUMBER> "Number of most recent CFS configurations to keep per group").value_parser(value_parser!(usize)))
+    .arg(arg!(--"keep-images" <NUMBER> "Number of most recent images to keep per group").value_parser(value_parser!(usize)))
+    .arg(arg!(--"failed-sessions-min-age" <AGE> "Delete failed CFS sessions older than this. eg: 1d, 6h"))
+    .arg(arg!(-d --"dry-run" "Prints the data to delete without deleting it").action(ArgAction::SetTrue))
+    .arg(arg!(-y --"assume-yes" "Automatic yes to prompts; assume 'yes' as answer to all prompts and run non-interactively.").action(ArgAction::SetTrue))
+    .arg(arg!(-o --output <FORMAT> "Output format").value_parser(["table", "json"]).default_value("table"))
+    .group(
+      ArgGroup::new("policy")
+        .args(["policy-file", "keep-configurations", "keep-images", "failed-sessions-min-age"])
+        .multiple(true)
+        .required(true),
+    )
+}
+
 pub fn subcommand_delete_session() -> Command {
   Command::new("session")
     // .visible_alias("s")
@@ -242,6 +301,72 @@ pub fn subcommand_get_group() -> Command {
     )
 }
 
+pub fn subcommand_get_partitions() -> Command {
+  Command::new("partitions")
+    .about("Get partition details")
+    .arg(
+      arg!(<VALUE> "Partition name. Returns all partitions if missing")
+        .required(false),
+    )
+    .arg(arg!(-t --tag <VALUE> "Filter partitions by tag"))
+    .arg(
+      arg!(-o --output <VALUE> "Output format")
+        .value_parser(["json", "table"])
+        .default_value("table"),
+    )
+}
+
+pub fn subcommand_get_locks() -> Command {
+  Command::new("locks")
+    .visible_alias("lock")
+    .about("Get lock and reservation status of nodes")
+    .arg(arg!(<VALUE> "Comma separated list of nids or xnames, a hostlist expression or a regex. Returns all nodes the user has access to if missing").required(false))
+    .arg(
+      arg!(-o --output <VALUE> "Output format")
+        .value_parser(["json", "table"])
+        .default_value("table"),
+    )
+}
+
+pub fn subcommand_get_orphans() -> Command {
+  Command::new("orphans")
+    .visible_alias("orphan")
+    .about("Get images, S3 boot images, BOS session templates and CFS configurations nothing refers to or which refer to deleted data")
+    .long_about("Get orphan data across IMS, S3, BOS, CFS and BSS:\n - IMS images not used by any BSS boot parameters, BOS session template or CFS session\n - images in S3 'boot-images' bucket without an IMS record\n - BOS session templates pointing to images which do not exist in IMS\n - CFS configurations not used by any node, CFS session or BOS session template")
+    .arg(
+      arg!(-o --output <VALUE> "Output format")
+        .value_parser(["json", "table"])
+        .default_value("table"),
+    )
+}
+
+pub fn subcommand_get_nodemaps() -> Command {
+  Command::new("nodemaps")
+    .visible_alias("nodemap")
+    .about("Get node maps (default NID and role of each node)")
+    .arg(
+      arg!(<XNAME> "Xname of the node map. Returns all node maps if missing")
+        .required(false),
+    )
+    .arg(
+      arg!(-o --output <VALUE> "Output format")
+        .value_parser(["json", "table"])
+        .default_value("table"),
+    )
+}
+
+pub fn subcommand_get_interfaces() -> Command {
+  Command::new("interfaces")
+    .about("Get ethernet interfaces (MAC and IP addresses) related to nodes")
+    .arg(arg!(-n --nodes <VALUE> "Comma separated list of nids or xnames. Can use comma separated list of nodes or expressions. A node can be represented as an xname or nid and expressions accepted are hostlist or regex.\neg 'x1003c1s7b0n0,1003c1s7b0n1,x1003c1s7b1n0', 'nid001313,nid001314', 'x1003c1s7b0n[0-1],x1003c1s7b1n0', 'nid00131[0-9]' or 'nid00131.*'"))
+    .arg(arg!(-H --"hsm-group" <VALUE> "hsm group name"))
+    .arg(arg!(-m --mac <VALUE> "Filter ethernet interfaces by MAC address"))
+    .arg(arg!(-i --ip <VALUE> "Filter ethernet interfaces by IP address"))
+    .arg(arg!(-N --network <VALUE> "Filter ethernet interfaces by network. eg 'HMN' or 'NMN'"))
+    .arg(arg!(-o --output <VALUE> "Output format").value_parser(["json", "table"]).default_value("table"))
+    .group(ArgGroup::new("hsm-group_or_nodes").args(["hsm-group", "nodes"]))
+}
+
 pub fn subcommand_get_hardware() -> Command {
   let command_get_hw_configuration_cluster = Command::new("cluster")
     // .visible_aliases(["c", "clstr"])
@@ -262,12 +387,28 @@ pub fn subcommand_get_hardware() -> Command {
     .arg(arg!(-t --type <TYPE> "Filters output to specific type").value_parser(ArtifactType::iter().map(|e| e.into()).collect::<Vec<&str>>()))
     .arg(arg!(-o --output <FORMAT> "Output format. If missing it will print output data in human redeable (table) format").value_parser(["json"]));
 
+  let command_get_hw_fru = Command::new("fru")
+    .about("Get FRUs (field replaceable units) by FRU id, serial number or part number")
+    .arg(arg!(<FRU_ID> "FRU id").required(false))
+    .arg(arg!(-s --"serial-number" <VALUE> "Filters FRUs by serial number"))
+    .arg(arg!(-p --"part-number" <VALUE> "Filters FRUs by part number"))
+    .arg(arg!(-m --manufacturer <VALUE> "Filters FRUs by manufacturer"))
+    .arg(arg!(-t --type <TYPE> "Filters FRUs by type, eg 'Memory', 'NodeAccel' or 'NodeHsnNic'"))
+    .arg(arg!(-H --history "Shows the xnames each FRU has been added to or removed from over time").action(ArgAction::SetTrue))
+    .arg(
+      arg!(-o --output <FORMAT> "Output format")
+        .value_parser(["json", "table"])
+        .default_value("table"),
+    )
+    .group(ArgGroup::new("fru_filter").args(["FRU_ID", "serial-number", "part-number", "manufacturer", "type"]).multiple(true).required(true));
+
   Command::new("hardware")
     // .visible_alias("hw")
     .arg_required_else_help(true)
-    .about("Get hardware components for a cluster or a node")
+    .about("Get hardware components for a cluster, a node or a FRU")
     .subcommand(command_get_hw_configuration_cluster)
     .subcommand(command_get_hw_configuration_node)
+    .subcommand(command_get_hw_fru)
 }
 
 pub fn subcommand_get_cfs_configuration() -> Command {
@@ -416,6 +557,9 @@ pub fn subcommand_get() -> Command {
     .arg_required_else_help(true)
     .about("Get information from CSM system")
     .subcommand(subcommand_get_group())
+    .subcommand(subcommand_get_partitions())
+    .subcommand(subcommand_get_nodemaps())
+    .subcommand(subcommand_get_locks())
     .subcommand(subcommand_get_hardware())
     .subcommand(subcommand_get_cfs_session())
     .subcommand(subcommand_get_cfs_configuration())
@@ -427,6 +571,8 @@ pub fn subcommand_get() -> Command {
     .subcommand(subcommand_get_boot_parameters())
     .subcommand(subcommand_get_kernel_parameters())
     .subcommand(subcommand_get_redfish_endpoints())
+    .subcommand(subcommand_get_interfaces())
+    .subcommand(subcommand_get_orphans())
 }
 
 pub fn subcommand_apply_hw_configuration() -> Command {
@@ -575,9 +721,12 @@ pub fn subcommand_migrate_backup() -> Command {
   Command::new("backup")
     // .visible_aliases(["mb"])
     .arg_required_else_help(true)
-    .about("Backup the configuration (BOS, CFS, image and HSM group) of a given vCluster/BOS session template.")
-    .arg(arg!(-b --"bos" <SESSIONTEMPLATE> "BOS Sessiontemplate to use to derive CFS, boot parameters and HSM group"))
-    .arg(arg!(-d --"destination" <FOLDER> "Destination folder to store the backup on").value_hint(ValueHint::DirPath))
+    .about("Backup the configuration (BOS, CFS, image and HSM group) of BOS session templates. Image artifacts are verified against the IMS manifest checksums. Running the same command again resumes an interrupted backup.")
+    .arg(arg!(-b --"bos" <SESSIONTEMPLATE> ... "BOS Sessiontemplates to use to derive CFS, boot parameters and HSM group"))
+    .arg(arg!(-g --"hsm-group" <HSM_GROUP_NAME> ... "Backup all BOS session templates targeting these HSM groups"))
+    .group(ArgGroup::new("bos_or_hsm-group").args(["bos", "hsm-group"]).multiple(true).required(true))
+    .arg(arg!(-d --"destination" <FOLDER> "Destination folder to store the backup on").value_hint(ValueHint::DirPath).required(true))
+    .arg(arg!(-c --"concurrency" <NUMBER> "Number of image artifacts to download in parallel").value_parser(value_parser!(usize)).default_value("4"))
     .arg(arg!(-p --"pre-hook" <SCRIPT> "Command to run before doing the backup. If need to pass a command with params. Use \" or \'.\neg: --pre-hook \"echo hello\""))
     .arg(arg!(-a --"post-hook" <SCRIPT> "Command to run immediately after the backup is completed successfully. Use \" or \'.\neg: --post-hook \"echo hello\"."))
 }
@@ -586,14 +735,14 @@ pub fn subcommand_migrate_restore() -> Command {
   Command::new("restore")
     // .visible_aliases(["mr"])
     .arg_required_else_help(true)
-    .about("MIGRATE RESTORE of all the nodes in a HSM group. Boot configuration means updating the image used to boot the machine. Configuration of a node means the CFS configuration with the ansible scripts running once a node has been rebooted.\neg:\nmanta update hsm-group --boot-image <boot cfs configuration name> --desired-configuration <desired cfs configuration name>")
-    .arg(arg!(-b --"bos-file" <BOS_session_template_file> "BOS session template of the cluster backed previously with migrate backup").value_hint(ValueHint::FilePath))
-    .arg(arg!(-c --"cfs-file" <CFS_configuration_file> "CFS session template of the cluster backed previously with migrate backup").value_hint(ValueHint::FilePath))
-    .arg(arg!(-j --"hsm-file" <HSM_group_description_file> "HSM group description file of the cluster backed previously with migrate backup").value_hint(ValueHint::FilePath))
-    .arg(arg!(-m --"ims-file" <IMS_file> "IMS file backed previously with migrate backup").value_hint(ValueHint::FilePath))
-    .arg(arg!(-i --"image-dir" <IMAGE_path> "Path where the image files are stored.").value_hint(ValueHint::DirPath))
-    .arg(arg!(-p --"pre-hook" <SCRIPT> "Command to run before doing the backup. If need to pass a command with params. Use \" or \'.\neg: --pre-hook \"echo hello\""))
-    .arg(arg!(-a --"post-hook" <SCRIPT> "Command to run immediately after the backup is completed successfully. Use \" or \'.\neg: --pre-hook \"echo hello\"."))
+    .about("Restore a backup created with 'migrate vCluster backup'. Image artifacts are verified against the backup manifest before any change. Running the same command again resumes an interrupted restore.")
+    .arg(arg!(-d --"backup-dir" <FOLDER> "Folder with the backup created with 'migrate vCluster backup'").value_hint(ValueHint::DirPath).required(true))
+    .arg(arg!(-b --"bos" <SESSIONTEMPLATE> ... "BOS session templates in the backup to restore. All of them if missing"))
+    .arg(arg!(-c --"concurrency" <NUMBER> "Number of image artifacts to upload in parallel").value_parser(value_parser!(usize)).default_value("4"))
+    .arg(arg!(-y --"assume-yes" "Overwrite existing CFS configurations and BOS session templates without asking").action(ArgAction::SetTrue))
+    .arg(arg!(--"overwrite-groups" "Replace existing HSM groups whose members differ from the backup. Needed to replace them with --assume-yes").action(ArgAction::SetTrue))
+    .arg(arg!(-p --"pre-hook" <SCRIPT> "Command to run before doing the restore. If need to pass a command with params. Use \" or \'.\neg: --pre-hook \"echo hello\""))
+    .arg(arg!(-a --"post-hook" <SCRIPT> "Command to run immediately after the restore is completed successfully. Use \" or \'.\neg: --post-hook \"echo hello\"."))
 }
 
 pub fn subcommand_power() -> Command {
@@ -677,6 +826,43 @@ pub fn subcommand_power() -> Command {
             .arg(arg!(<VALUE> "List of xnames or nids. Can use comma separated list of nodes or expressions. A node can be represented as an xname or nid and expressions accepted are hostlist or regex.\neg 'x1003c1s7b0n0,1003c1s7b0n1,x1003c1s7b1n0', 'nid001313,nid001314', 'x1003c1s7b0n[0-1],x1003c1s7b1n0', 'nid00131[0-9]' or 'nid00131.*'")),
         ),
     )
+    .subcommand(subcommand_power_cap())
+}
+
+pub fn subcommand_power_cap() -> Command {
+  Command::new("cap")
+    .arg_required_else_help(true)
+    .about("Command to manage power capping of cluster/node")
+    .subcommand(
+      Command::new("get")
+        .about("Get current power cap values of a cluster or list of nodes. If no cluster or nodes are provided, then the list of power cap tasks is returned")
+        .arg(arg!(-n --nodes <VALUE> "List of xnames or nids. Can use comma separated list of nodes or expressions. A node can be represented as an xname or nid and expressions accepted are hostlist or regex.\neg 'x1003c1s7b0n0,1003c1s7b0n1,x1003c1s7b1n0', 'nid001313,nid001314', 'x1003c1s7b0n[0-1],x1003c1s7b1n0', 'nid00131[0-9]' or 'nid00131.*'"))
+        .arg(arg!(-H --"hsm-group" <HSM_GROUP> "Cluster name"))
+        .arg(arg!(-t --"task-id" <TASK_ID> "Power cap task ID"))
+        .arg(arg!(-o --output <FORMAT> "Output format.").value_parser(["table", "json"]).default_value("table"))
+        .group(ArgGroup::new("hsm-group_or_nodes_or_task-id").args(["hsm-group", "nodes", "task-id"])),
+    )
+    .subcommand(
+      Command::new("set")
+        .arg_required_else_help(true)
+        .about("Set power cap value to a cluster or list of nodes")
+        .arg(arg!(-n --nodes <VALUE> "List of xnames or nids. Can use comma separated list of nodes or expressions. A node can be represented as an xname or nid and expressions accepted are hostlist or regex.\neg 'x1003c1s7b0n0,1003c1s7b0n1,x1003c1s7b1n0', 'nid001313,nid001314', 'x1003c1s7b0n[0-1],x1003c1s7b1n0', 'nid00131[0-9]' or 'nid00131.*'"))
+        .arg(arg!(-H --"hsm-group" <HSM_GROUP> "Cluster name"))
+        .arg(arg!(-c --control <CONTROL_NAME> "Name of the power cap control to set").default_value("Node Power Limit"))
+        .arg(arg!(-y --"assume-yes" "Automatic yes to prompts; assume 'yes' as answer to all prompts and run non-interactively.").action(ArgAction::SetTrue))
+        .arg(arg!(-o --output <FORMAT> "Output format.").value_parser(["table", "json"]).default_value("table"))
+        .arg(arg!(<WATTS> "Power cap value in watts").value_parser(value_parser!(usize)))
+        .group(ArgGroup::new("hsm-group_or_nodes").args(["hsm-group", "nodes"]).required(true)),
+    )
+    .subcommand(
+      Command::new("snapshot")
+        .arg_required_else_help(true)
+        .about("Request a snapshot of the power cap values of a cluster or list of nodes. The command returns the power cap task ID without waiting for it to complete, use 'manta power cap get --task-id <TASK_ID>' to fetch the results")
+        .arg(arg!(-n --nodes <VALUE> "List of xnames or nids. Can use comma separated list of nodes or expressions. A node can be represented as an xname or nid and expressions accepted are hostlist or regex.\neg 'x1003c1s7b0n0,1003c1s7b0n1,x1003c1s7b1n0', 'nid001313,nid001314', 'x1003c1s7b0n[0-1],x1003c1s7b1n0', 'nid00131[0-9]' or 'nid00131.*'"))
+        .arg(arg!(-H --"hsm-group" <HSM_GROUP> "Cluster name"))
+        .arg(arg!(-o --output <FORMAT> "Output format.").value_parser(["table", "json"]).default_value("table"))
+        .group(ArgGroup::new("hsm-group_or_nodes").args(["hsm-group", "nodes"]).required(true)),
+    )
 }
 
 pub fn subcommand_log() -> Command {
@@ -705,6 +891,30 @@ pub fn subcommand_add_group() -> Command {
   // .arg(arg!(-D --"dry-run" "No changes applied to the system.").action(ArgAction::SetTrue))
 }
 
+pub fn subcommand_add_partition() -> Command {
+  Command::new("partition")
+    .about("Add/Create new partition")
+    .arg_required_else_help(true)
+    .arg(arg!(-N --name <VALUE> "Partition name").required(true))
+    .arg(arg!(-d --description <VALUE> "Partition description"))
+    .arg(arg!(-t --tags <VALUE> "Comma separated list of tags"))
+    .arg(arg!(-n --nodes <VALUE> "List of partition members. Can use comma separated list of nodes or expressions. A node can be represented as an xname or nid and expressions accepted are hostlist or regex.\neg 'x1003c1s7b0n0,1003c1s7b0n1,x1003c1s7b1n0', 'nid001313,nid001314', 'x1003c1s7b0n[0-1],x1003c1s7b1n0', 'nid00131[0-9]' or 'nid00131.*'"))
+    .arg(arg!(-y --"assume-yes" "Automatic yes to prompts; assume 'yes' as answer to all prompts and run non-interactively.").action(ArgAction::SetTrue))
+    .arg(arg!(-D --"dry-run" "Simulates the execution of the command without making any actual changes.").action(ArgAction::SetTrue))
+}
+
+pub fn subcommand_add_nodemap() -> Command {
+  Command::new("nodemap")
+    .about("Add/Update the node map (default NID and role) of a node")
+    .arg_required_else_help(true)
+    .arg(arg!(-x --xname <VALUE> "Xname of the node").required(true))
+    .arg(arg!(-n --nid <VALUE> "NID to assign to the node").value_parser(value_parser!(u32)).required(true))
+    .arg(arg!(-r --role <VALUE> "Node role, eg 'Compute' or 'Application'"))
+    .arg(arg!(-s --subrole <VALUE> "Node subrole, eg 'UAN'"))
+    .arg(arg!(-y --"assume-yes" "Automatic yes to prompts; assume 'yes' as answer to all prompts and run non-interactively.").action(ArgAction::SetTrue))
+    .arg(arg!(-D --"dry-run" "Simulates the execution of the command without making any actual changes.").action(ArgAction::SetTrue))
+}
+
 pub fn subcommand_add_node() -> Command {
   Command::new("node")
     // .visible_alias("n")
@@ -762,6 +972,18 @@ pub fn subcommand_add_redfish_endpoint() -> Command {
   // .arg(arg!(-D --"dry-run" "Simulates the execution of the command without making any actual changes.").action(ArgAction::SetTrue))
 }
 
+pub fn subcommand_add_interface() -> Command {
+  Command::new("interface")
+    .arg_required_else_help(true)
+    .about("Add/Create new ethernet interface")
+    .arg(arg!(-n --node <VALUE> "Xname or nid of the node the ethernet interface belongs to").required(true))
+    .arg(arg!(-m --mac <VALUE> "MAC address of the ethernet interface").required(true))
+    .arg(arg!(-i --ip <VALUE> "IP address assigned to the ethernet interface"))
+    .arg(arg!(-N --network <VALUE> "Network the IP address belongs to. eg 'HMN' or 'NMN'").requires("ip"))
+    .arg(arg!(-d --description <VALUE> "Ethernet interface description"))
+    .arg(arg!(-y --"assume-yes" "Automatic yes to prompts; assume 'yes' as answer to all prompts and run non-interactively.").action(ArgAction::SetTrue))
+}
+
 pub fn subcommand_add_boot_parameters() -> Command {
   Command::new("boot-parameters")
     // .visible_aliases(["n", "node"])
@@ -824,6 +1046,16 @@ pub fn subcommand_apply_kernel_parameters() -> Command {
     )
 }
 
+pub fn subcommand_apply_cluster_state() -> Command {
+  Command::new("cluster-state")
+    .arg_required_else_help(true)
+    .about("Reconcile a cluster with a desired state file. Compares group members, boot image, kernel parameters, runtime configuration and power state against the live system, prints the changes needed and applies them")
+    .arg(arg!(-f --file <PATH> "YAML file with the desired state of the cluster.\neg:\ngroup: zinal\nmembers: ['x1003c1s7b0n[0-1]', 'nid001313']\nboot_image_configuration: zinal-cos-config\nkernel_parameters: 'console=ttyS0,115200 root=...'\nruntime_configuration: zinal-cos-config\npower: on").value_parser(value_parser!(PathBuf)).required(true).value_hint(ValueHint::FilePath))
+    .arg(arg!(--"do-not-reboot" "Don't reboot nodes whose boot image or kernel parameters change").action(ArgAction::SetTrue))
+    .arg(arg!(-d --"dry-run" "Print the changes needed without applying them").action(ArgAction::SetTrue))
+    .arg(arg!(-y --"assume-yes" "Automatic yes to prompts; assume 'yes' as answer to all prompts and run non-interactively.").action(ArgAction::SetTrue))
+}
+
 pub fn subcommand_update_boot_parameters() -> Command {
   Command::new("boot-parameters")
     // .visible_aliases(["n", "node"])
@@ -871,12 +1103,47 @@ pub fn subcommand_update_redfish_endpoint() -> Command {
   // .arg(arg!(-d --"dry-run" "Simulates the execution of the command without making any actual changes.").action(ArgAction::SetTrue))
 }
 
+pub fn subcommand_update_interface() -> Command {
+  Command::new("interface")
+    .arg_required_else_help(true)
+    .about("Update ethernet interface. Useful to fix stale MAC/IP mappings after replacing node hardware")
+    .arg(arg!(<ID> "Ethernet interface ID to update. Usually the MAC address in lowercase without ':'").required(true))
+    .arg(arg!(-n --node <VALUE> "Xname or nid of the node the ethernet interface belongs to"))
+    .arg(arg!(-i --ip <VALUE> "IP address assigned to the ethernet interface. Replaces the IP addresses currently assigned"))
+    .arg(arg!(-N --network <VALUE> "Network the IP address belongs to. eg 'HMN' or 'NMN'").requires("ip"))
+    .arg(arg!(-d --description <VALUE> "Ethernet interface description"))
+    .arg(arg!(-y --"assume-yes" "Automatic yes to prompts; assume 'yes' as answer to all prompts and run non-interactively.").action(ArgAction::SetTrue))
+    .group(ArgGroup::new("update_values").args(["node", "ip", "description"]).multiple(true).required(true))
+}
+
+pub fn subcommand_update_node() -> Command {
+  Command::new("node")
+    .visible_alias("nodes")
+    .arg_required_else_help(true)
+    .about("Update the HSM state of nodes: enabled, flag, software status and role. eg drain or flag broken nodes")
+    .arg(arg!(<HOSTS_EXPRESSION> "Comma separated list of xnames or nids, a hostlist expression or a regex. eg 'x1003c1s7b0n0,x1003c1s7b0n1', 'nid00100[0-3]' or 'x1003c1s7b0n[0-1]'").required(false))
+    .arg(arg!(-g --group <GROUP_NAME> "Updates all nodes in the group"))
+    .arg(arg!(-e --enable "Enables the nodes").action(ArgAction::SetTrue))
+    .arg(arg!(-D --disable "Disables the nodes").action(ArgAction::SetTrue))
+    .arg(arg!(-f --flag <FLAG> "Sets the flag of the nodes without changing their state").value_parser(["OK", "Warning", "Alert", "Locked"]))
+    .arg(arg!(-s --"software-status" <VALUE> "Sets the software status of the nodes. eg 'AdminDown'"))
+    .arg(arg!(-r --role <ROLE> "Sets the role of the nodes. eg 'Compute' or 'Application'"))
+    .arg(arg!(-R --subrole <SUBROLE> "Sets the subrole of the nodes. eg 'UAN'").requires("role"))
+    .arg(arg!(-d --"dry-run" "Simulates the execution of the command without making any actual changes.").action(ArgAction::SetTrue))
+    .arg(arg!(-y --"assume-yes" "Automatic yes to prompts; assume 'yes' as answer to all prompts and run non-interactively.").action(ArgAction::SetTrue))
+    .group(ArgGroup::new("hosts_or_group").args(["HOSTS_EXPRESSION", "group"]).required(true))
+    .group(ArgGroup::new("enable_or_disable").args(["enable", "disable"]))
+    .group(ArgGroup::new("update_values").args(["enable", "disable", "flag", "software-status", "role"]).multiple(true).required(true))
+}
+
 pub fn subcommand_update() -> Command {
   Command::new("update")
     .arg_required_else_help(true)
     .about("Update elements to system.")
     .subcommand(subcommand_update_boot_parameters())
     .subcommand(subcommand_update_redfish_endpoint())
+    .subcommand(subcommand_update_interface())
+    .subcommand(subcommand_update_node())
 }
 
 pub fn subcommand_add() -> Command {
@@ -885,10 +1152,13 @@ pub fn subcommand_add() -> Command {
     .about("Add/Create new elements to system.")
     .subcommand(subcommand_add_node())
     .subcommand(subcommand_add_group())
+    .subcommand(subcommand_add_partition())
+    .subcommand(subcommand_add_nodemap())
     .subcommand(subcommand_add_hwcomponent())
     .subcommand(subcommand_add_boot_parameters())
     .subcommand(subcommand_add_kernel_parameters())
     .subcommand(subcommand_add_redfish_endpoint())
+    .subcommand(subcommand_add_interface())
 }
 
 pub fn subcommand_apply() -> Command {
@@ -908,6 +1178,7 @@ pub fn subcommand_apply() -> Command {
         .subcommand(subcommand_apply_boot_cluster()),
     )
     .subcommand(subcommand_apply_kernel_parameters())
+    .subcommand(subcommand_apply_cluster_state())
     .subcommand(subcommand_apply_session())
     .subcommand(subcommand_apply_ephemeral_environment())
     .subcommand(subcommand_apply_template())
@@ -943,7 +1214,24 @@ pub fn subcommand_console() -> Command {
       Command::new("node")
         // .visible_alias("n")
         .about("Connects to a node's console. Possible values are xname or nid.\neg: 'x1003c1s7b0n0' or 'nid001313'")
-        .arg(arg!(<XNAME> "node xname").required(true)),
+        .arg(arg!(<XNAME> "node xname").required(true))
+        .arg(arg!(-r --record <FILE> "Records the console output to an asciicast v2 file. Play it with 'manta console replay'").value_hint(ValueHint::FilePath)),
+    )
+    .subcommand(
+      Command::new("tail")
+        .arg_required_else_help(true)
+        .about("Shows the console output of many nodes at once. Consoles are attached read-only")
+        .arg(arg!(<VALUE> "Comma separated list of nids or xnames. Can use comma separated list of nodes or expressions. A node can be represented as an xname or nid and expressions accepted are hostlist or regex.\neg 'x1003c1s7b0n0,1003c1s7b0n1,x1003c1s7b1n0', 'nid001313,nid001314', 'x1003c1s7b0n[0-1],x1003c1s7b1n0', 'nid00131[0-9]' or 'nid00131.*'").required(true))
+        .arg(arg!(-H --highlight <REGEX> "Highlights the text matching this regex eg '(?i)error|panic'"))
+        .arg(arg!(-e --"exit-on" <REGEX> "Exits once every node printed a line matching this regex eg 'login:'")),
+    )
+    .subcommand(
+      Command::new("replay")
+        .arg_required_else_help(true)
+        .about("Plays a console recorded with 'manta console node --record'")
+        .arg(arg!(<FILE> "asciicast v2 file").required(true).value_hint(ValueHint::FilePath))
+        .arg(arg!(-s --speed <VALUE> "Playback speed, eg '2' plays twice as fast as recorded").value_parser(value_parser!(f64)).default_value("1"))
+        .arg(arg!(-i --"idle-time-limit" <SECONDS> "Shortens pauses longer than this number of seconds").value_parser(value_parser!(f64))),
     )
     .subcommand(
       Command::new("target-ansible")
@@ -956,6 +1244,44 @@ pub fn subcommand_console() -> Command {
     )
 }
 
+pub fn subcommand_lock() -> Command {
+  Command::new("lock")
+    .arg_required_else_help(true)
+    .about("Lock nodes so no operation (power, boot, hw configuration, etc) can change them until they are unlocked")
+    .arg(arg!(<VALUE> "Comma separated list of nids or xnames. Can use comma separated list of nodes or expressions. A node can be represented as an xname or nid and expressions accepted are hostlist or regex.\neg 'x1003c1s7b0n0,1003c1s7b0n1,x1003c1s7b1n0', 'nid001313,nid001314', 'x1003c1s7b0n[0-1],x1003c1s7b1n0', 'nid00131[0-9]' or 'nid00131.*'").required(true))
+    .arg(arg!(-y --"assume-yes" "Automatic yes to prompts; assume 'yes' as answer to all prompts and run non-interactively.").action(ArgAction::SetTrue))
+}
+
+pub fn subcommand_unlock() -> Command {
+  Command::new("unlock")
+    .arg_required_else_help(true)
+    .about("Unlock nodes")
+    .arg(arg!(<VALUE> "Comma separated list of nids or xnames. Can use comma separated list of nodes or expressions. A node can be represented as an xname or nid and expressions accepted are hostlist or regex.\neg 'x1003c1s7b0n0,1003c1s7b0n1,x1003c1s7b1n0', 'nid001313,nid001314', 'x1003c1s7b0n[0-1],x1003c1s7b1n0', 'nid00131[0-9]' or 'nid00131.*'").required(true))
+    .arg(arg!(-y --"assume-yes" "Automatic yes to prompts; assume 'yes' as answer to all prompts and run non-interactively.").action(ArgAction::SetTrue))
+}
+
+pub fn subcommand_undo() -> Command {
+  Command::new("undo")
+    .about("Restore the objects changed by 'delete group', 'delete kernel-parameters', 'delete configurations', 'migrate nodes', 'apply hw-configuration' and 'promote' to their previous state")
+    .arg(arg!(<JOURNAL_ID> "Journal entry id printed by the operation to undo. Lists the journal entries of the site if missing").required(false))
+    .arg(arg!(-d --"dry-run" "Simulates the execution of the command without making any actual changes.").action(ArgAction::SetTrue))
+    .arg(arg!(-y --"assume-yes" "Automatic yes to prompts; assume 'yes' as answer to all prompts and run non-interactively.").action(ArgAction::SetTrue))
+}
+
+pub fn subcommand_promote() -> Command {
+  Command::new("promote")
+    .arg_required_else_help(true)
+    .about("Copy a BOS session template together with its CFS configuration, images and HSM groups from one site to another")
+    .long_about("Copy a BOS session template together with its CFS configuration, images and HSM groups from one site to another.\nCFS configuration layers are pointed to the target site VCS, the commits must exist there unless '--use-branch-head' is set. Images get new ids and HSM groups keep the members they have in the target site (new groups are created empty).")
+    .arg(arg!(--"from-site" <SITE_NAME> "Site to copy the objects from").required(true))
+    .arg(arg!(--"to-site" <SITE_NAME> "Site to copy the objects to").required(true))
+    .arg(arg!(-t --template <SESSIONTEMPLATE> "BOS session template to promote").required(true))
+    .arg(arg!(-c --concurrency <VALUE> "Number of image artifacts transferred in parallel").value_parser(value_parser!(usize)).default_value("4"))
+    .arg(arg!(--"use-branch-head" "Layers whose commit is not in the target site VCS use the commit their branch points to there").action(ArgAction::SetTrue))
+    .arg(arg!(-d --"dry-run" "Show the changes in the target site without applying them").action(ArgAction::SetTrue))
+    .arg(arg!(-y --"assume-yes" "Automatic yes to prompts; assume 'yes' as answer to all prompts and run non-interactively.").action(ArgAction::SetTrue))
+}
+
 pub fn subcommand_add_nodes_to_groups() -> Command {
   Command::new("add-nodes-to-groups")
     // .visible_aliases(["ag"])
@@ -974,6 +1300,26 @@ pub fn subcommand_remove_nodes_from_groups() -> Command {
     .arg(arg!(-d --"dry-run" "Simulates the execution of the command without making any actual changes.").action(ArgAction::SetTrue))
 }
 
+pub fn subcommand_add_nodes_to_partition() -> Command {
+  Command::new("add-nodes-to-partition")
+    .arg_required_else_help(true)
+    .about("Add nodes to a partition")
+    .arg(arg!(-p --partition <VALUE> "Partition to assign the nodes to").required(true))
+    .arg(arg!(-n --nodes <VALUE> "Comma separated list of nids or xnames. Can use comma separated list of nodes or expressions. A node can be represented as an xname or nid and expressions accepted are hostlist or regex.\neg 'x1003c1s7b0n0,1003c1s7b0n1,x1003c1s7b1n0', 'nid001313,nid001314', 'x1003c1s7b0n[0-1],x1003c1s7b1n0', 'nid00131[0-9]' or 'nid00131.*'").required(true))
+    .arg(arg!(-y --"assume-yes" "Automatic yes to prompts; assume 'yes' as answer to all prompts and run non-interactively.").action(ArgAction::SetTrue))
+    .arg(arg!(-d --"dry-run" "Simulates the execution of the command without making any actual changes.").action(ArgAction::SetTrue))
+}
+
+pub fn subcommand_remove_nodes_from_partition() -> Command {
+  Command::new("remove-nodes-from-partition")
+    .arg_required_else_help(true)
+    .about("Remove nodes from a partition")
+    .arg(arg!(-p --partition <VALUE> "Partition to remove the nodes from").required(true))
+    .arg(arg!(-n --nodes <VALUE> "Comma separated list of nids or xnames. Can use comma separated list of nodes or expressions. A node can be represented as an xname or nid and expressions accepted are hostlist or regex.\neg 'x1003c1s7b0n0,1003c1s7b0n1,x1003c1s7b1n0', 'nid001313,nid001314', 'x1003c1s7b0n[0-1],x1003c1s7b1n0', 'nid00131[0-9]' or 'nid00131.*'").required(true))
+    .arg(arg!(-y --"assume-yes" "Automatic yes to prompts; assume 'yes' as answer to all prompts and run non-interactively.").action(ArgAction::SetTrue))
+    .arg(arg!(-d --"dry-run" "Simulates the execution of the command without making any actual changes.").action(ArgAction::SetTrue))
+}
+
 pub fn subcommand_download_boot_image() -> Command {
   Command::new("download-boot-image")
     .about("Downloads a boot image")
@@ -0,0 +1,163 @@
+use clap::{ArgMatches, Command};
+use manta_backend_dispatcher::types::capability::Capability;
+
+/// Backend capability required by each CLI subcommand. Subcommands not listed
+/// here do not depend on an optional backend operation. When several entries
+/// match, the one with the longest path wins.
+const SUBCOMMAND_CAPABILITY_VEC: &[(&[&str], Capability)] = &[
+  // Groups
+  (&["get", "groups"], Capability::Groups),
+  (&["add", "group"], Capability::Groups),
+  (&["delete", "group"], Capability::Groups),
+  (&["add-nodes-to-groups"], Capability::Groups),
+  (&["remove-nodes-from-groups"], Capability::Groups),
+  (&["migrate"], Capability::Groups),
+  (&["migrate", "nodes"], Capability::Groups),
+  // Components
+  (&["get", "nodes"], Capability::Components),
+  (&["add", "node"], Capability::Components),
+  (&["delete", "node"], Capability::Components),
+  (&["update", "node"], Capability::ComponentState),
+  // Hardware inventory
+  (&["get", "hardware"], Capability::HardwareInventory),
+  (&["get", "hardware", "fru"], Capability::FruInventory),
+  (&["add", "hardware"], Capability::HardwareInventory),
+  (&["delete", "hardware"], Capability::HardwareInventory),
+  (&["apply", "hardware"], Capability::HwClusterPin),
+  // Partitions
+  (&["get", "partitions"], Capability::Partitions),
+  (&["add", "partition"], Capability::Partitions),
+  (&["delete", "partition"], Capability::Partitions),
+  (&["add-nodes-to-partition"], Capability::Partitions),
+  (&["remove-nodes-from-partition"], Capability::Partitions),
+  // Node maps
+  (&["get", "nodemaps"], Capability::NodeMaps),
+  (&["add", "nodemap"], Capability::NodeMaps),
+  (&["delete", "nodemap"], Capability::NodeMaps),
+  // Locks
+  (&["get", "locks"], Capability::Locks),
+  (&["lock"], Capability::Locks),
+  (&["unlock"], Capability::Locks),
+  // Ethernet interfaces
+  (&["get", "interfaces"], Capability::EthernetInterfaces),
+  (&["add", "interface"], Capability::EthernetInterfaces),
+  (&["update", "interface"], Capability::EthernetInterfaces),
+  (&["delete", "interface"], Capability::EthernetInterfaces),
+  // Redfish endpoints
+  (&["get", "redfish-endpoints"], Capability::RedfishEndpoints),
+  (&["add", "redfish-endpoint"], Capability::RedfishEndpoints),
+  (
+    &["update", "redfish-endpoint"],
+    Capability::RedfishEndpoints,
+  ),
+  (
+    &["delete", "redfish-endpoint"],
+    Capability::RedfishEndpoints,
+  ),
+  // Boot parameters
+  (&["get", "boot-parameters"], Capability::BootParameters),
+  (&["add", "boot-parameters"], Capability::BootParameters),
+  (&["update", "boot-parameters"], Capability::BootParameters),
+  (
+    &["delete", "boot-parameters"],
+    Capability::BootParametersDelete,
+  ),
+  (&["get", "kernel-parameters"], Capability::BootParameters),
+  (&["add", "kernel-parameters"], Capability::BootParameters),
+  (&["apply", "kernel-parameters"], Capability::BootParameters),
+  (&["delete", "kernel-parameters"], Capability::BootParameters),
+  (&["apply", "boot"], Capability::BootParameters),
+  // Reconciles runtime configuration together with groups, boot parameters
+  // and power
+  (
+    &["apply", "cluster-state"],
+    Capability::RuntimeConfiguration,
+  ),
+  // Power
+  (&["power"], Capability::PowerManagement),
+  (&["power", "cap"], Capability::PowerCapping),
+  // Configurations and sessions
+  (&["get", "configurations"], Capability::Configurations),
+  (
+    &["delete", "configurations"],
+    Capability::ConfigurationsDelete,
+  ),
+  (&["get", "sessions"], Capability::Sessions),
+  (&["delete", "session"], Capability::Sessions),
+  (&["log"], Capability::Sessions),
+  (&["apply", "session"], Capability::ApplySession),
+  // Images
+  (&["get", "images"], Capability::Images),
+  (&["delete", "images"], Capability::ImagesDelete),
+  (&["download-boot-image"], Capability::Images),
+  (&["upload-boot-image"], Capability::Images),
+  // BOS
+  (&["get", "templates"], Capability::ClusterTemplates),
+  (&["apply", "template"], Capability::ClusterSessions),
+  (&["promote"], Capability::ClusterTemplates),
+  // SAT file
+  (&["apply", "sat-file"], Capability::SatFile),
+  // Migrate
+  (&["migrate", "vCluster"], Capability::MigrateBackup),
+  (
+    &["migrate", "vCluster", "backup"],
+    Capability::MigrateBackup,
+  ),
+  (
+    &["migrate", "vCluster", "restore"],
+    Capability::MigrateRestore,
+  ),
+  (&["delete", "garbage"], Capability::GarbageCollection),
+  (&["get", "orphans"], Capability::Orphans),
+  // Console
+  (&["console", "node"], Capability::Console),
+  (&["console", "tail"], Capability::ConsoleReadOnly),
+  (&["console", "target-ansible"], Capability::Console),
+];
+
+/// Returns the backend capability needed to run the subcommand selected by the
+/// user, if any
+pub fn get_required_capability(cli_root: &ArgMatches) -> Option<Capability> {
+  let mut subcommand_path: Vec<&str> = Vec::new();
+  let mut matches = cli_root;
+
+  while let Some((name, sub_matches)) = matches.subcommand() {
+    subcommand_path.push(name);
+    matches = sub_matches;
+  }
+
+  SUBCOMMAND_CAPABILITY_VEC
+    .iter()
+    .filter(|(path, _)| subcommand_path.starts_with(path))
+    .max_by_key(|(path, _)| path.len())
+    .map(|(_, capability)| *capability)
+}
+
+/// Hides from help and autocomplete the subcommands which need a capability the
+/// backend does not provide
+pub fn hide_unsupported_subcommands(
+  mut cli: Command,
+  capability_vec: &[Capability],
+) -> Command {
+  for (path, capability) in SUBCOMMAND_CAPABILITY_VEC {
+    if !capability_vec.contains(capability) {
+      cli = hide_subcommand(cli, path);
+    }
+  }
+
+  cli
+}
+
+fn hide_subcommand(cli: Command, path: &[&str]) -> Command {
+  match path {
+    [] => cli.hide(true),
+    [name, rest @ ..] => {
+      if cli.find_subcommand(name).is_some() {
+        cli
+          .mut_subcommand(*name, |subcommand| hide_subcommand(subcommand, rest))
+      } else {
+        cli
+      }
+    }
+  }
+}
@@ -1,14 +1,14 @@
-use crate::common::{self, jwt_ops};
+use std::time::Instant;
+
+use crate::common;
 use crate::{
-  common::{
-    audit::Audit, authorization::validate_target_hsm_members, kafka::Kafka,
-  },
+  common::{audit::Auditor, authorization::validate_target_hsm_members},
   manta_backend_dispatcher::StaticBackendDispatcher,
 };
 use dialoguer::theme::ColorfulTheme;
 use manta_backend_dispatcher::interfaces::hsm::component::ComponentTrait;
 use manta_backend_dispatcher::{
-  interfaces::hsm::group::GroupTrait, types::Group,
+  error::Error, interfaces::hsm::group::GroupTrait, types::Group,
 };
 
 /// Creates a group of nodes. It is allowed to create a group with no nodes.
@@ -20,32 +20,20 @@ pub async fn exec(
   hosts_expression_opt: Option<&String>,
   assume_yes: bool,
   dryrun: bool,
-  kafka_audit_opt: Option<&Kafka>,
-) {
+  audit_opt: Option<&Auditor>,
+) -> Result<(), Error> {
   let xname_vec_opt: Option<Vec<String>> = match hosts_expression_opt {
     Some(hosts_expression) => {
       // Convert user input to xname
-      let node_metadata_available_vec = backend
-        .get_node_metadata_available(auth_token)
-        .await
-        .unwrap_or_else(|e| {
-          eprintln!("ERROR - Could not get node metadata. Reason:\n{e}\nExit");
-          std::process::exit(1);
-        });
+      let node_metadata_available_vec =
+        backend.get_node_metadata_available(auth_token).await?;
 
       let xname_vec = common::node_ops::from_hosts_expression_to_xname_vec(
         hosts_expression,
         false,
         node_metadata_available_vec,
       )
-      .await
-      .unwrap_or_else(|e| {
-        eprintln!(
-          "ERROR - Could not convert user input to list of xnames. Reason:\n{}",
-          e
-        );
-        std::process::exit(1);
-      });
+      .await?;
 
       Some(xname_vec)
     }
@@ -74,7 +62,7 @@ pub async fn exec(
 
   // Validate user has access to the list of xnames requested
   if let Some(xname_vec) = &xname_vec_opt {
-    validate_target_hsm_members(&backend, &auth_token, xname_vec).await;
+    validate_target_hsm_members(&backend, &auth_token, xname_vec).await?;
   }
 
   // Create Group instance for http payload
@@ -96,8 +84,7 @@ pub async fn exec(
             .unwrap();
 
     if !proceed {
-      println!("Operation canceled by the user. Exit");
-      std::process::exit(1);
+      return Err(Error::Message("Operation canceled by the user".to_string()));
     }
   }
 
@@ -106,36 +93,30 @@ pub async fn exec(
       "Dryrun mode: The group below would be created:\n{}",
       serde_json::to_string_pretty(&group).unwrap()
     );
-    return;
+    return Ok(());
   }
 
   // Call backend to create group
-  let result = backend.add_group(&auth_token, group).await;
+  let start = Instant::now();
 
-  match result {
-    Ok(_) => {
-      eprintln!("Group '{}' created", label);
-    }
-    Err(error) => {
-      eprintln!("{}", error);
-      std::process::exit(1);
-    }
-  }
+  let result = backend.add_group(&auth_token, group).await;
 
   // Audit
-  if let Some(kafka_audit) = kafka_audit_opt {
-    let username = jwt_ops::get_name(auth_token).unwrap_or_default();
-    let user_id =
-      jwt_ops::get_preferred_username(auth_token).unwrap_or_default();
+  if let Some(auditor) = audit_opt {
+    let audit_event = auditor
+      .new_event(auth_token, "group.add")
+      .with_groups(&[label])
+      .with_xnames(xname_vec_opt.as_deref().unwrap_or_default())
+      .with_parameters(serde_json::json!({ "description": description }))
+      .with_result(&result)
+      .with_duration(start.elapsed());
 
-    let msg_json = serde_json::json!(
-        { "user": {"id": user_id, "name": username}, "host": {"hostname": xname_vec_opt.unwrap_or_default()}, "group": label, "message": format!("Create Group '{}'", label)});
+    auditor.send_event(&audit_event).await;
+  }
 
-    let msg_data = serde_json::to_string(&msg_json)
-      .expect("Could not serialize audit message data");
+  result?;
 
-    if let Err(e) = kafka_audit.produce_message(msg_data.as_bytes()).await {
-      log::warn!("Failed producing messages: {}", e);
-    }
-  }
+  eprintln!("Group '{}' created", label);
+
+  Ok(())
 }
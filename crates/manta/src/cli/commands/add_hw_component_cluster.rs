@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use dialoguer::{theme::ColorfulTheme, Confirm};
 use manta_backend_dispatcher::{
-  interfaces::hsm::group::GroupTrait, types::Group,
+  error::Error, interfaces::hsm::group::GroupTrait, types::Group,
 };
 
 use crate::{
@@ -21,7 +21,7 @@ pub async fn exec(
   pattern: &str,
   dryrun: bool,
   create_hsm_group: bool,
-) {
+) -> Result<(), Error> {
   let pattern = format!("{}:{}", target_hsm_group_name, pattern);
 
   match backend.get_group(shasta_token, target_hsm_group_name).await {
@@ -32,10 +32,10 @@ pub async fn exec(
       if create_hsm_group {
         log::info!("HSM group {} does not exist, but the option to create the group has been selected, creating it now.", target_hsm_group_name.to_string());
         if dryrun {
-          log::error!(
+          return Err(Error::Message(
             "Dryrun selected, cannot create the new group and continue."
-          );
-          std::process::exit(1);
+              .to_string(),
+          ));
         } else {
           let group = Group {
             label: target_hsm_group_name.to_string(),
@@ -50,8 +50,10 @@ pub async fn exec(
             .expect("Unable to create new HSM group");
         }
       } else {
-        log::error!("HSM group {} does not exist, but the option to create the group was NOT specificied, cannot continue.", target_hsm_group_name.to_string());
-        std::process::exit(1);
+        return Err(Error::Message(format!(
+          "HSM group {} does not exist, but the option to create the group was NOT specificied, cannot continue.",
+          target_hsm_group_name.to_string(
+        ))));
       }
     }
   };
@@ -84,8 +86,9 @@ pub async fn exec(
         hw_component_counter[1].parse::<isize>().unwrap(),
       );
     } else {
-      log::error!("Error in pattern. Please make sure to follow <hsm name>:<hw component>:<counter>:... eg <tasna>:a100:4:epyc:10:instinct:8");
-      std::process::exit(1);
+      return Err(Error::Message(
+        "Error in pattern. Please make sure to follow <hsm name>:<hw component>:<counter>:... eg <tasna>:a100:4:epyc:10:instinct:8".to_string(),
+      ));
     }
   }
 
@@ -195,7 +198,7 @@ pub async fn exec(
                 .collect::<Vec<String>>(),
             &mut parent_hsm_node_hw_component_count_vec,
             &parent_hsm_hw_component_type_scores_based_on_scarcity_hashmap,
-        );
+        )?;
 
   // *********************************************************************************************************
   // PREPARE INFORMATION TO SHOW
@@ -298,7 +301,7 @@ pub async fn exec(
     println!("Continue.");
   } else {
     println!("Cancelled by user. Aborting.");
-    std::process::exit(0);
+    return Ok(());
   }
 
   // *********************************************************************************************************
@@ -341,4 +344,6 @@ pub async fn exec(
     "{}",
     serde_json::to_string_pretty(&parent_hsm_group_value).unwrap()
   );
+
+  Ok(())
 }
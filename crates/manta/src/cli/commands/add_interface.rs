@@ -0,0 +1,108 @@
+use std::time::Instant;
+
+use dialoguer::{theme::ColorfulTheme, Confirm};
+use manta_backend_dispatcher::{
+  error::Error,
+  interfaces::hsm::{
+    component::ComponentTrait, ethernet_interfaces::EthernetInterfaceTrait,
+  },
+  types::hsm::inventory::{EthernetInterface, IpAddressMapping},
+};
+
+use crate::{
+  common::{self, audit::Auditor},
+  manta_backend_dispatcher::StaticBackendDispatcher,
+};
+
+pub async fn exec(
+  backend: &StaticBackendDispatcher,
+  shasta_token: &str,
+  node: &str,
+  mac_address: &str,
+  ip_address_opt: Option<&str>,
+  network_opt: Option<&str>,
+  description_opt: Option<&str>,
+  assume_yes: bool,
+  audit_opt: Option<&Auditor>,
+) -> Result<(), Error> {
+  // Check user has access to the node
+  let node_metadata_available_vec =
+    backend.get_node_metadata_available(shasta_token).await?;
+
+  let xname_vec = common::node_ops::from_hosts_expression_to_xname_vec(
+    node,
+    false,
+    node_metadata_available_vec,
+  )
+  .await?;
+
+  let xname = match xname_vec.as_slice() {
+    [xname] => xname.clone(),
+    _ => {
+      return Err(Error::Message(format!(
+        "Node '{}' must resolve to exactly one node, got {:?}",
+        node, xname_vec
+      )));
+    }
+  };
+
+  let ethernet_interface = EthernetInterface {
+    id: None,
+    description: description_opt.map(str::to_string),
+    mac_address: mac_address.to_string(),
+    last_update: None,
+    component_id: Some(xname.clone()),
+    r#type: None,
+    ip_addresses: ip_address_opt
+      .map(|ip_address| {
+        vec![IpAddressMapping {
+          ip_address: ip_address.to_string(),
+          network: network_opt.map(str::to_string),
+        }]
+      })
+      .unwrap_or_default(),
+  };
+
+  if !assume_yes {
+    if Confirm::with_theme(&ColorfulTheme::default())
+      .with_prompt(format!(
+        "Ethernet interface with MAC '{}' will be added to node '{}'. Do you want to proceed?",
+        mac_address, xname
+      ))
+      .interact()
+      .unwrap()
+    {
+      log::info!("Continue",);
+    } else {
+      println!("Cancelled by user. Aborting.");
+      return Ok(());
+    }
+  }
+
+  let start = Instant::now();
+
+  let result = backend
+    .add_ethernet_interface(shasta_token, &ethernet_interface)
+    .await;
+
+  // Audit
+  if let Some(auditor) = audit_opt {
+    let audit_event = auditor
+      .new_event(shasta_token, "interface.add")
+      .with_xnames(std::slice::from_ref(&xname))
+      .with_parameters(serde_json::json!({ "mac_address": mac_address, "ip_address": ip_address_opt, "network": network_opt, "description": description_opt }))
+      .with_result(&result)
+      .with_duration(start.elapsed());
+
+    auditor.send_event(&audit_event).await;
+  }
+
+  result?;
+
+  println!(
+    "Ethernet interface with MAC '{}' added to node '{}'",
+    mac_address, xname
+  );
+
+  Ok(())
+}
@@ -1,5 +1,7 @@
+use std::time::Instant;
+
 use crate::{
-  common::{self, audit::Audit, jwt_ops, kafka::Kafka},
+  common::{self, audit::Auditor},
   manta_backend_dispatcher::StaticBackendDispatcher,
 };
 use dialoguer::theme::ColorfulTheme;
@@ -23,34 +25,22 @@ pub async fn exec(
   overwrite: bool,
   assume_yes: bool,
   do_not_reboot: bool,
-  kafka_audit_opt: Option<&Kafka>,
+  audit_opt: Option<&Auditor>,
   dry_run: bool,
 ) -> Result<(), Error> {
   let mut need_restart = false;
   log::info!("Add kernel parameters");
 
   // Convert user input to xname
-  let node_metadata_available_vec = backend
-    .get_node_metadata_available(shasta_token)
-    .await
-    .unwrap_or_else(|e| {
-      eprintln!("ERROR - Could not get node metadata. Reason:\n{e}\nExit");
-      std::process::exit(1);
-    });
+  let node_metadata_available_vec =
+    backend.get_node_metadata_available(shasta_token).await?;
 
   let xname_vec = common::node_ops::from_hosts_expression_to_xname_vec(
     hosts_expression,
     false,
     node_metadata_available_vec,
   )
-  .await
-  .unwrap_or_else(|e| {
-    eprintln!(
-      "ERROR - Could not convert user input to list of xnames. Reason:\n{}",
-      e
-    );
-    std::process::exit(1);
-  });
+  .await?;
 
   let mut xname_to_reboot_vec: Vec<String> = Vec::new();
 
@@ -80,12 +70,15 @@ pub async fn exec(
         .unwrap();
 
   if !proceed {
-    println!("Operation canceled by the user. Exit");
-    std::process::exit(1);
+    return Err(Error::Message("Operation canceled by the user".to_string()));
   }
 
   log::debug!("new kernel params: {:#?}", current_node_boot_params_vec);
 
+  let start = Instant::now();
+
+  let mut result = Ok(());
+
   for mut boot_parameter in current_node_boot_params_vec {
     let kernel_params_changed =
       boot_parameter.add_kernel_params(&kernel_params, overwrite);
@@ -108,9 +101,13 @@ pub async fn exec(
           boot_parameter.params
         );
       } else {
-        backend
+        result = backend
           .update_bootparameters(shasta_token, &boot_parameter)
-          .await?;
+          .await;
+
+        if result.is_err() {
+          break;
+        }
       }
 
       if need_restart {
@@ -123,30 +120,28 @@ pub async fn exec(
   }
 
   // Audit
-  if let Some(kafka_audit) = kafka_audit_opt {
-    let username = jwt_ops::get_name(shasta_token).unwrap();
-    let user_id = jwt_ops::get_preferred_username(shasta_token).unwrap();
-
-    // FIXME: We should not need to make this call here but at the beginning of the method as a
-    // prerequisite
+  if let Some(auditor) = audit_opt {
     let xnames: Vec<&str> =
       xname_vec.iter().map(|xname| xname.as_str()).collect();
 
     let group_map_vec = backend
       .get_group_map_and_filter_by_member_vec(shasta_token, &xnames)
-      .await?;
-
-    let msg_json = serde_json::json!(
-        { "user": {"id": user_id, "name": username}, "host": {"hostname": xname_vec}, "group": group_map_vec.keys().collect::<Vec<_>>(), "message": format!("Add kernel parameters: {}", kernel_params)});
-
-    let msg_data = serde_json::to_string(&msg_json)
-      .expect("Could not serialize audit message data");
-
-    if let Err(e) = kafka_audit.produce_message(msg_data.as_bytes()).await {
-      log::warn!("Failed producing messages: {}", e);
-    }
+      .await
+      .unwrap_or_default();
+
+    let audit_event = auditor
+      .new_event(shasta_token, "kernel-parameters.add")
+      .with_xnames(&xname_vec)
+      .with_groups(&group_map_vec.keys().collect::<Vec<_>>())
+      .with_parameters(serde_json::json!({ "kernel_params": kernel_params, "overwrite": overwrite, "do_not_reboot": do_not_reboot, "dry_run": dry_run }))
+      .with_result(&result)
+      .with_duration(start.elapsed());
+
+    auditor.send_event(&audit_event).await;
   }
 
+  result?;
+
   // Reboot if needed
   if do_not_reboot {
     println!("Kernel parameters added. Reboot canceled by user");
@@ -166,9 +161,9 @@ pub async fn exec(
           true,
           assume_yes,
           "table",
-          kafka_audit_opt,
+          audit_opt,
         )
-        .await;
+        .await?;
       }
     }
   }
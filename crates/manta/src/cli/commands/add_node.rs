@@ -1,3 +1,5 @@
+use std::time::Instant;
+
 use anyhow::Result;
 use manta_backend_dispatcher::{
   interfaces::hsm::{
@@ -10,8 +12,7 @@ use manta_backend_dispatcher::{
 };
 
 use crate::{
-  common::{audit::Audit, jwt_ops, kafka::Kafka},
-  manta_backend_dispatcher::StaticBackendDispatcher,
+  common::audit::Auditor, manta_backend_dispatcher::StaticBackendDispatcher,
 };
 
 pub async fn exec(
@@ -22,7 +23,7 @@ pub async fn exec(
   enabled: bool,
   arch_opt: Option<String>,
   hw_inventory_opt: Option<HWInventoryByLocationList>,
-  kafka_audit_opt: Option<&Kafka>,
+  audit_opt: Option<&Auditor>,
 ) -> Result<()> {
   // Create node api payload
   let component: ComponentCreate = ComponentCreate {
@@ -45,38 +46,41 @@ pub async fn exec(
     force: Some(true),
   };
 
-  // Add node to backend
-  backend.post_nodes(shasta_token, components).await?;
-
-  log::info!("Node saved '{}'", id);
+  let start = Instant::now();
 
-  // Add hardware inventory
-  if let Some(hw_inventory) = hw_inventory_opt {
-    log::info!("Adding hardware inventory for '{}'", id);
-    backend
-      .post_inventory_hardware(&shasta_token, hw_inventory)
-      .await?;
-  }
+  let result = async {
+    // Add node to backend
+    backend.post_nodes(shasta_token, components).await?;
 
-  // Add node to group
-  backend.post_member(shasta_token, group, id).await?;
+    log::info!("Node saved '{}'", id);
 
-  // Audit
-  if let Some(kafka_audit) = kafka_audit_opt {
-    let username = jwt_ops::get_name(shasta_token).unwrap_or_default();
-    let user_id =
-      jwt_ops::get_preferred_username(shasta_token).unwrap_or_default();
+    // Add hardware inventory
+    if let Some(hw_inventory) = hw_inventory_opt {
+      log::info!("Adding hardware inventory for '{}'", id);
+      backend
+        .post_inventory_hardware(&shasta_token, hw_inventory)
+        .await?;
+    }
 
-    let msg_json = serde_json::json!(
-        { "user": {"id": user_id, "name": username}, "host": {"hostname": id}, "group": [], "message": "add node"});
+    // Add node to group
+    backend.post_member(shasta_token, group, id).await
+  }
+  .await;
 
-    let msg_data = serde_json::to_string(&msg_json)
-      .expect("Could not serialize audit message data");
+  // Audit
+  if let Some(auditor) = audit_opt {
+    let audit_event = auditor
+      .new_event(shasta_token, "node.add")
+      .with_xnames(&[id])
+      .with_groups(&[group])
+      .with_parameters(serde_json::json!({ "enabled": enabled }))
+      .with_result(&result)
+      .with_duration(start.elapsed());
 
-    if let Err(e) = kafka_audit.produce_message(msg_data.as_bytes()).await {
-      log::warn!("Failed producing messages: {}", e);
-    }
+    auditor.send_event(&audit_event).await;
   }
 
+  result?;
+
   Ok(())
 }
@@ -0,0 +1,77 @@
+use std::time::Instant;
+
+use crate::{
+  common::{audit::Auditor, authorization::validate_target_hsm_members},
+  manta_backend_dispatcher::StaticBackendDispatcher,
+};
+use dialoguer::theme::ColorfulTheme;
+use manta_backend_dispatcher::{
+  error::Error, interfaces::hsm::node_map::NodeMapTrait,
+  types::hsm::node_map::NodeMap,
+};
+
+/// Creates or updates the node map (default NID and role) of a node
+pub async fn exec(
+  backend: &StaticBackendDispatcher,
+  auth_token: &str,
+  xname: &str,
+  nid: u32,
+  role: Option<&String>,
+  sub_role: Option<&String>,
+  assume_yes: bool,
+  dryrun: bool,
+  audit_opt: Option<&Auditor>,
+) -> Result<(), Error> {
+  // Validate user has access to the node
+  validate_target_hsm_members(backend, auth_token, &vec![xname.to_string()])
+    .await?;
+
+  let node_map = NodeMap::new(xname, nid, role.cloned(), sub_role.cloned());
+
+  if !assume_yes {
+    let proceed = dialoguer::Confirm::with_theme(&ColorfulTheme::default())
+      .with_prompt(format!(
+        "This operation will create the node map below:\n{}\nPlease confirm to proceed",
+        serde_json::to_string_pretty(&node_map).unwrap()
+      ))
+      .interact()
+      .unwrap();
+
+    if !proceed {
+      return Err(Error::Message("Operation canceled by the user".to_string()));
+    }
+  }
+
+  if dryrun {
+    println!(
+      "Dryrun mode: The node map below would be created:\n{}",
+      serde_json::to_string_pretty(&node_map).unwrap()
+    );
+    return Ok(());
+  }
+
+  // Call backend to create node map
+  let start = Instant::now();
+
+  let result = backend.add_node_maps(auth_token, &[node_map.clone()]).await;
+
+  // Audit
+  if let Some(auditor) = audit_opt {
+    let audit_event = auditor
+      .new_event(auth_token, "nodemap.add")
+      .with_xnames(&[xname])
+      .with_parameters(
+        serde_json::json!({ "nid": nid, "role": role, "sub_role": sub_role }),
+      )
+      .with_result(&result)
+      .with_duration(start.elapsed());
+
+    auditor.send_event(&audit_event).await;
+  }
+
+  result?;
+
+  eprintln!("Node map for '{}' created (nid{:06})", xname, nid);
+
+  Ok(())
+}
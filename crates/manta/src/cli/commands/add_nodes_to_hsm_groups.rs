@@ -1,12 +1,15 @@
+use std::time::Instant;
+
 use dialoguer::{theme::ColorfulTheme, Confirm};
 use manta_backend_dispatcher::interfaces::hsm::{
   component::ComponentTrait, group::GroupTrait,
 };
 
 use crate::{
-  common::{self, audit::Audit, jwt_ops, kafka::Kafka},
+  common::{self, audit::Auditor},
   manta_backend_dispatcher::StaticBackendDispatcher,
 };
+use manta_backend_dispatcher::error::Error;
 
 /// Add/assign a list of xnames to a list of HSM groups
 pub async fn exec(
@@ -15,16 +18,11 @@ pub async fn exec(
   target_hsm_name: &String,
   hosts_expression: &str,
   dryrun: bool,
-  kafka_audit_opt: Option<&Kafka>,
-) {
+  audit_opt: Option<&Auditor>,
+) -> Result<(), Error> {
   // Convert user input to xname
-  let node_metadata_available_vec = backend
-    .get_node_metadata_available(shasta_token)
-    .await
-    .unwrap_or_else(|e| {
-      eprintln!("ERROR - Could not get node metadata. Reason:\n{e}\nExit");
-      std::process::exit(1);
-    });
+  let node_metadata_available_vec =
+    backend.get_node_metadata_available(shasta_token).await?;
 
   let mut xname_to_move_vec =
     common::node_ops::from_hosts_expression_to_xname_vec(
@@ -32,14 +30,7 @@ pub async fn exec(
       false,
       node_metadata_available_vec,
     )
-    .await
-    .unwrap_or_else(|e| {
-      eprintln!(
-        "ERROR - Could not convert user input to list of xnames. Reason:\n{}",
-        e
-      );
-      std::process::exit(1);
-    });
+    .await?;
 
   xname_to_move_vec.sort();
   xname_to_move_vec.dedup();
@@ -47,7 +38,7 @@ pub async fn exec(
   // Check if there are any xname to migrate/move and exit otherwise
   if xname_to_move_vec.is_empty() {
     println!("No hosts to move. Exit");
-    std::process::exit(0);
+    return Ok(());
   }
 
   if Confirm::with_theme(&ColorfulTheme::default())
@@ -61,7 +52,7 @@ pub async fn exec(
         log::info!("Continue",);
     } else {
         println!("Cancelled by user. Aborting.");
-        std::process::exit(0);
+        return Ok(());
     }
 
   let target_hsm_group =
@@ -84,13 +75,27 @@ pub async fn exec(
       "dryrun - Add nodes {:?} to {}",
       xnames_to_move, target_hsm_name
     );
-    std::process::exit(0);
+    return Ok(());
   }
 
+  let start = Instant::now();
+
   let node_migration_rslt = backend
     .add_members_to_group(shasta_token, &target_hsm_name, xnames_to_move)
     .await;
 
+  // Audit
+  if let Some(auditor) = audit_opt {
+    let audit_event = auditor
+      .new_event(shasta_token, "group.add-members")
+      .with_groups(&[target_hsm_name])
+      .with_xnames(&xname_to_move_vec)
+      .with_result(&node_migration_rslt)
+      .with_duration(start.elapsed());
+
+    auditor.send_event(&audit_event).await;
+  }
+
   match node_migration_rslt {
     Ok(mut target_hsm_group_member_vec) => {
       target_hsm_group_member_vec.sort();
@@ -102,19 +107,5 @@ pub async fn exec(
     Err(e) => eprintln!("{}", e),
   }
 
-  // Audit
-  if let Some(kafka_audit) = kafka_audit_opt {
-    let username = jwt_ops::get_name(shasta_token).unwrap();
-    let user_id = jwt_ops::get_preferred_username(shasta_token).unwrap();
-
-    let msg_json = serde_json::json!(
-        { "user": {"id": user_id, "name": username}, "host": {"hostname": xname_to_move_vec}, "group": vec![target_hsm_name], "message": format!("add nodes to group: {}", target_hsm_name)});
-
-    let msg_data = serde_json::to_string(&msg_json)
-      .expect("Could not serialize audit message data");
-
-    if let Err(e) = kafka_audit.produce_message(msg_data.as_bytes()).await {
-      log::warn!("Failed producing messages: {}", e);
-    }
-  }
+  Ok(())
 }
@@ -0,0 +1,93 @@
+use std::time::Instant;
+
+use dialoguer::{theme::ColorfulTheme, Confirm};
+use manta_backend_dispatcher::interfaces::hsm::{
+  component::ComponentTrait, partition::PartitionTrait,
+};
+
+use crate::{
+  common::{self, audit::Auditor},
+  manta_backend_dispatcher::StaticBackendDispatcher,
+};
+use manta_backend_dispatcher::error::Error;
+
+/// Add/assign a list of xnames to a partition
+pub async fn exec(
+  backend: &StaticBackendDispatcher,
+  shasta_token: &str,
+  partition_name: &str,
+  hosts_expression: &str,
+  assume_yes: bool,
+  dryrun: bool,
+  audit_opt: Option<&Auditor>,
+) -> Result<(), Error> {
+  // Convert user input to xname
+  let node_metadata_available_vec =
+    backend.get_node_metadata_available(shasta_token).await?;
+
+  let mut xname_vec = common::node_ops::from_hosts_expression_to_xname_vec(
+    hosts_expression,
+    false,
+    node_metadata_available_vec,
+  )
+  .await?;
+
+  xname_vec.sort();
+  xname_vec.dedup();
+
+  if xname_vec.is_empty() {
+    println!("No hosts to move. Exit");
+    return Ok(());
+  }
+
+  // Check partition exists
+  backend.get_partition(shasta_token, partition_name).await?;
+
+  if !assume_yes {
+    if Confirm::with_theme(&ColorfulTheme::default())
+      .with_prompt(format!(
+        "{:?}\nThe nodes above will be added to partition '{}'. Do you want to proceed?",
+        xname_vec, partition_name
+      ))
+      .interact()
+      .unwrap()
+    {
+      log::info!("Continue",);
+    } else {
+      println!("Cancelled by user. Aborting.");
+      return Ok(());
+    }
+  }
+
+  if dryrun {
+    println!("dryrun - Add nodes {:?} to {}", xname_vec, partition_name);
+    return Ok(());
+  }
+
+  let start = Instant::now();
+
+  let result = backend
+    .add_members_to_partition(shasta_token, partition_name, &xname_vec)
+    .await;
+
+  // Audit
+  if let Some(auditor) = audit_opt {
+    let audit_event = auditor
+      .new_event(shasta_token, "partition.add-members")
+      .with_xnames(&xname_vec)
+      .with_parameters(serde_json::json!({ "name": partition_name }))
+      .with_result(&result)
+      .with_duration(start.elapsed());
+
+    auditor.send_event(&audit_event).await;
+  }
+
+  result?;
+
+  println!(
+    "Nodes {:?} added to partition '{}'",
+    xname_vec, partition_name
+  );
+
+  Ok(())
+}
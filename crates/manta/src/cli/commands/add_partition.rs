@@ -0,0 +1,110 @@
+use std::time::Instant;
+
+use crate::common;
+use crate::{
+  common::{audit::Auditor, authorization::validate_target_hsm_members},
+  manta_backend_dispatcher::StaticBackendDispatcher,
+};
+use dialoguer::theme::ColorfulTheme;
+use manta_backend_dispatcher::{
+  error::Error,
+  interfaces::hsm::{component::ComponentTrait, partition::PartitionTrait},
+  types::hsm::partition::Partition,
+};
+
+/// Creates a partition of nodes. It is allowed to create a partition with no nodes.
+pub async fn exec(
+  backend: &StaticBackendDispatcher,
+  auth_token: &str,
+  name: &str,
+  description: Option<&String>,
+  tags_opt: Option<&String>,
+  hosts_expression_opt: Option<&String>,
+  assume_yes: bool,
+  dryrun: bool,
+  audit_opt: Option<&Auditor>,
+) -> Result<(), Error> {
+  let xname_vec_opt: Option<Vec<String>> = match hosts_expression_opt {
+    Some(hosts_expression) => {
+      // Convert user input to xname
+      let node_metadata_available_vec =
+        backend.get_node_metadata_available(auth_token).await?;
+
+      let xname_vec = common::node_ops::from_hosts_expression_to_xname_vec(
+        hosts_expression,
+        false,
+        node_metadata_available_vec,
+      )
+      .await?;
+
+      Some(xname_vec)
+    }
+    None => None,
+  };
+
+  // Validate user has access to the list of xnames requested
+  if let Some(xname_vec) = &xname_vec_opt {
+    validate_target_hsm_members(backend, auth_token, xname_vec).await?;
+  }
+
+  let tag_vec_opt: Option<Vec<String>> = tags_opt.map(|tags| {
+    tags
+      .split(",")
+      .map(|tag| tag.trim().to_string())
+      .filter(|tag| !tag.is_empty())
+      .collect()
+  });
+
+  // Create Partition instance for http payload
+  let partition = Partition::new(
+    name,
+    description.cloned(),
+    xname_vec_opt.clone(),
+    tag_vec_opt,
+  );
+
+  if !assume_yes {
+    let proceed = dialoguer::Confirm::with_theme(&ColorfulTheme::default())
+      .with_prompt(format!(
+        "This operation will create the partition below:\n{}\nPlease confirm to proceed",
+        serde_json::to_string_pretty(&partition).unwrap()
+      ))
+      .interact()
+      .unwrap();
+
+    if !proceed {
+      return Err(Error::Message("Operation canceled by the user".to_string()));
+    }
+  }
+
+  if dryrun {
+    println!(
+      "Dryrun mode: The partition below would be created:\n{}",
+      serde_json::to_string_pretty(&partition).unwrap()
+    );
+    return Ok(());
+  }
+
+  // Call backend to create partition
+  let start = Instant::now();
+
+  let result = backend.add_partition(auth_token, &partition).await;
+
+  // Audit
+  if let Some(auditor) = audit_opt {
+    let audit_event = auditor
+      .new_event(auth_token, "partition.add")
+      .with_xnames(xname_vec_opt.as_deref().unwrap_or_default())
+      .with_parameters(serde_json::json!({ "name": name, "description": description, "tags": tags_opt }))
+      .with_result(&result)
+      .with_duration(start.elapsed());
+
+    auditor.send_event(&audit_event).await;
+  }
+
+  result?;
+
+  eprintln!("Partition '{}' created", name);
+
+  Ok(())
+}
@@ -1,7 +1,7 @@
 use manta_backend_dispatcher::interfaces::hsm::group::GroupTrait;
 
 use crate::{
-  cli::commands::apply_boot_node, common::kafka::Kafka,
+  cli::commands::apply_boot_node, common::audit::Auditor,
   manta_backend_dispatcher::StaticBackendDispatcher,
 };
 
@@ -20,7 +20,7 @@ pub async fn exec(
   assume_yes: bool,
   do_not_reboot: bool,
   dry_run: bool,
-  kafka_audit_opt: Option<&Kafka>,
+  audit_opt: Option<&Auditor>,
 ) {
   let xname_vec_rslt = backend
     .get_member_vec_from_group_name_vec(
@@ -50,7 +50,7 @@ pub async fn exec(
     assume_yes,
     do_not_reboot,
     dry_run,
-    kafka_audit_opt,
+    audit_opt,
   )
   .await;
 
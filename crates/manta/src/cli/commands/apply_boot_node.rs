@@ -1,12 +1,14 @@
+use std::time::Instant;
+
 use crate::{
   cli::commands::power_reset_nodes,
   common::{
-    self, ims_ops::get_image_vec_related_cfs_configuration_name, kafka::Kafka,
+    self, audit::Auditor, ims_ops::get_image_vec_related_cfs_configuration_name,
   },
   manta_backend_dispatcher::StaticBackendDispatcher,
 };
 
-use anyhow::Error;
+use anyhow::{bail, Error};
 use dialoguer::{theme::ColorfulTheme, Confirm};
 use manta_backend_dispatcher::{
   interfaces::{
@@ -29,7 +31,7 @@ pub async fn exec(
   assume_yes: bool,
   do_not_reboot: bool,
   dry_run: bool,
-  kafka_audit_opt: Option<&Kafka>,
+  audit_opt: Option<&Auditor>,
 ) -> Result<(), Error> {
   let mut need_restart = false;
 
@@ -84,11 +86,10 @@ pub async fn exec(
       .await?;
 
       if image_vec.is_empty() {
-        eprintln!(
-          "ERROR - Could not find boot image related to configuration '{}'",
+        bail!(
+          "Could not find boot image related to configuration '{}'",
           new_boot_image_configuration
         );
-        std::process::exit(1);
       }
 
       backend.filter_images(&mut image_vec)?;
@@ -127,8 +128,7 @@ pub async fn exec(
         .await?;
 
       if image_in_csm_vec.is_empty() {
-        eprintln!("ERROR - boot image id '{}' not found", boot_image_id);
-        std::process::exit(1);
+        bail!("boot image id '{}' not found", boot_image_id);
       }
 
       let etag_opt = image_in_csm_vec
@@ -188,13 +188,13 @@ pub async fn exec(
   //
   // Check if boot image changes and notify the user and update the node boot params struct
   if let (Some(new_boot_image_id), Some(etag)) =
-    (new_boot_image_id_opt, etag_opt)
+    (&new_boot_image_id_opt, &etag_opt)
   {
     let boot_params_to_update_vec: Vec<&BootParameters> =
       current_node_boot_param_vec
         .iter()
         .filter(|boot_param| {
-          boot_param.get_boot_image_id() != new_boot_image_id
+          boot_param.get_boot_image_id() != *new_boot_image_id
         })
         .collect();
 
@@ -209,7 +209,7 @@ pub async fn exec(
             new_boot_image_id
           );
 
-          let _ = boot_parameter.update_boot_image(&new_boot_image_id, &etag);
+          let _ = boot_parameter.update_boot_image(new_boot_image_id, etag);
         });
 
       need_restart = true;
@@ -237,7 +237,7 @@ pub async fn exec(
                 log::info!("Continue",);
             } else {
                 println!("Cancelled by user. Aborting.");
-                std::process::exit(0);
+                return Ok(());
             }
     }
   }
@@ -249,6 +249,14 @@ pub async fn exec(
   } else {
     log::info!("Persist changes");
 
+    // Lock nodes so other operations can't change them meanwhile
+    let nodes_locked =
+      common::lock_ops::lock_nodes(backend, shasta_token, &xname_vec).await?;
+
+    let start = Instant::now();
+
+    let mut result = Ok(());
+
     // Update boot params
     for boot_parameter in current_node_boot_param_vec {
       log::debug!("Updating boot parameter:\n{:#?}", boot_parameter);
@@ -265,6 +273,10 @@ pub async fn exec(
         "Component boot parameters resp:\n{:#?}",
         component_patch_rep
       );
+
+      if result.is_ok() {
+        result = component_patch_rep;
+      }
     }
 
     // Update desired configuration
@@ -276,7 +288,7 @@ pub async fn exec(
         new_runtime_configuration_name
       );
 
-      let _ = backend
+      let runtime_configuration_rslt = backend
         .update_runtime_configuration(
           shasta_token,
           shasta_base_url,
@@ -285,15 +297,40 @@ pub async fn exec(
           new_runtime_configuration_name,
           true,
         )
-        .await
-        .inspect_err(|e| {
-          eprintln!("Error updating runtime configuration: {}", e);
-          std::process::exit(1);
-        });
+        .await;
+
+      if result.is_ok() {
+        result = runtime_configuration_rslt;
+      }
     } else {
       log::info!("Runtime configuration does not change.");
     }
 
+    // Unlock nodes before rebooting them since power management can't operate
+    // on locked nodes
+    if nodes_locked {
+      common::lock_ops::unlock_nodes(backend, shasta_token, &xname_vec).await;
+    }
+
+    // Audit
+    if let Some(auditor) = audit_opt {
+      let audit_event = auditor
+        .new_event(shasta_token, "boot.apply")
+        .with_xnames(&xname_vec)
+        .with_parameters(serde_json::json!({
+          "boot_image_id": new_boot_image_id_opt,
+          "boot_image_configuration": new_boot_image_configuration_opt,
+          "runtime_configuration": new_runtime_configuration_opt,
+          "kernel_parameters": new_kernel_parameters_opt,
+        }))
+        .with_result(&result)
+        .with_duration(start.elapsed());
+
+      auditor.send_event(&audit_event).await;
+    }
+
+    result?;
+
     if !do_not_reboot && need_restart {
       log::info!("Restarting nodes");
 
@@ -309,9 +346,9 @@ pub async fn exec(
         true,
         assume_yes,
         "table",
-        kafka_audit_opt,
+        audit_opt,
       )
-      .await;
+      .await?;
     }
 
     Ok(())
@@ -0,0 +1,489 @@
+use std::{path::Path, time::Instant};
+
+use dialoguer::{theme::ColorfulTheme, Confirm};
+use manta_backend_dispatcher::{
+  error::Error,
+  interfaces::{
+    bss::BootParametersTrait,
+    cfs::CfsTrait,
+    hsm::{component::ComponentTrait, group::GroupTrait},
+    ims::ImsTrait,
+    pcs::PCSTrait,
+  },
+  types::bss::BootParameters,
+};
+
+use crate::{
+  common::{
+    self, audit::Auditor,
+    ims_ops::get_image_vec_related_cfs_configuration_name, lock_ops,
+  },
+  manta_backend_dispatcher::StaticBackendDispatcher,
+};
+
+use super::utils::{self, ClusterState, ClusterStatePlan, NodeState};
+
+/// Reconciles a cluster with the desired state described in a file. Prints
+/// the changes needed and applies them, nothing else is changed
+pub async fn exec(
+  backend: &StaticBackendDispatcher,
+  shasta_token: &str,
+  shasta_base_url: &str,
+  shasta_root_cert: &[u8],
+  cluster_state_file: &Path,
+  settings_hsm_group_name_opt: Option<&String>,
+  do_not_reboot: bool,
+  dry_run: bool,
+  assume_yes: bool,
+  audit_opt: Option<&Auditor>,
+) -> Result<(), Error> {
+  let cluster_state_file_content = std::fs::read_to_string(cluster_state_file)
+    .map_err(|e| {
+      Error::Message(format!(
+        "Could not read cluster state file '{}'. Reason:\n{}",
+        cluster_state_file.display(),
+        e
+      ))
+    })?;
+
+  let cluster_state: ClusterState =
+    serde_yaml::from_str(&cluster_state_file_content).map_err(|e| {
+      Error::Message(format!(
+        "Could not parse cluster state file '{}'. Reason:\n{}",
+        cluster_state_file.display(),
+        e
+      ))
+    })?;
+
+  if cluster_state.boot_image_id.is_some()
+    && cluster_state.boot_image_configuration.is_some()
+  {
+    return Err(Error::Message(
+      "'boot_image_id' and 'boot_image_configuration' are mutually exclusive"
+        .to_string(),
+    ));
+  }
+
+  // Exits if the user has no access to the group
+  common::authorization::get_groups_available(
+    backend,
+    shasta_token,
+    Some(&cluster_state.group),
+    settings_hsm_group_name_opt,
+  )
+  .await?;
+
+  let current_member_vec = backend
+    .get_member_vec_from_group_name_vec(
+      shasta_token,
+      vec![cluster_state.group.clone()],
+    )
+    .await?;
+
+  let desired_member_vec_opt = match &cluster_state.members {
+    Some(member_vec) => {
+      let node_metadata_available_vec =
+        backend.get_node_metadata_available(shasta_token).await?;
+
+      let mut xname_vec = common::node_ops::from_hosts_expression_to_xname_vec(
+        &member_vec.join(","),
+        false,
+        node_metadata_available_vec,
+      )
+      .await?;
+
+      xname_vec.sort();
+      xname_vec.dedup();
+
+      Some(xname_vec)
+    }
+    None => None,
+  };
+
+  // Nodes in the cluster once membership is reconciled
+  let xname_vec = desired_member_vec_opt
+    .clone()
+    .unwrap_or_else(|| current_member_vec.clone());
+
+  if xname_vec.is_empty() {
+    println!(
+      "Cluster '{}' has no nodes. Nothing to do",
+      cluster_state.group
+    );
+    return Ok(());
+  }
+
+  let boot_image_opt = get_boot_image(
+    backend,
+    shasta_token,
+    shasta_base_url,
+    shasta_root_cert,
+    &cluster_state,
+  )
+  .await?;
+
+  let node_state_vec = get_node_state_vec(
+    backend,
+    shasta_token,
+    shasta_base_url,
+    shasta_root_cert,
+    &cluster_state,
+    &xname_vec,
+  )
+  .await?;
+
+  let xname_without_boot_parameters_vec =
+    utils::get_xname_without_boot_parameters_vec(
+      &cluster_state,
+      &node_state_vec,
+    );
+
+  if !xname_without_boot_parameters_vec.is_empty() {
+    return Err(Error::Message(format!(
+      "Can't change boot image or kernel parameters of nodes without boot parameters: {}",
+      xname_without_boot_parameters_vec.join(", ")
+    )));
+  }
+
+  let boot_image_id_opt = boot_image_opt.as_ref().map(|(id, _)| id.as_str());
+
+  let plan = utils::calculate_plan(
+    &cluster_state,
+    boot_image_id_opt,
+    desired_member_vec_opt.as_deref(),
+    &current_member_vec,
+    &node_state_vec,
+    !do_not_reboot,
+  );
+
+  utils::print_plan(&cluster_state, boot_image_id_opt, &plan);
+
+  if plan.is_empty() {
+    return Ok(());
+  }
+
+  if dry_run {
+    println!("Dry-run enabled. No changes persisted into the system");
+    return Ok(());
+  }
+
+  if !assume_yes {
+    if Confirm::with_theme(&ColorfulTheme::default())
+      .with_prompt("Apply the plan above?")
+      .interact()
+      .unwrap()
+    {
+      log::info!("Continue",);
+    } else {
+      println!("Cancelled by user. Aborting.");
+      return Ok(());
+    }
+  }
+
+  let start = Instant::now();
+
+  let result = apply_plan(
+    backend,
+    shasta_token,
+    shasta_base_url,
+    shasta_root_cert,
+    &cluster_state,
+    boot_image_opt.as_ref(),
+    &node_state_vec,
+    &plan,
+  )
+  .await;
+
+  // Audit
+  if let Some(auditor) = audit_opt {
+    let audit_event = auditor
+      .new_event(shasta_token, "cluster_state.apply")
+      .with_xnames(&xname_vec)
+      .with_groups(&[&cluster_state.group])
+      .with_parameters(serde_json::json!({
+        "cluster_state": cluster_state,
+        "plan": plan,
+        "do_not_reboot": do_not_reboot,
+      }))
+      .with_result(&result)
+      .with_duration(start.elapsed());
+
+    auditor.send_event(&audit_event).await;
+  }
+
+  result?;
+
+  println!("Cluster '{}' reconciled", cluster_state.group);
+
+  Ok(())
+}
+
+/// Boot image id and etag the cluster state resolves to
+async fn get_boot_image(
+  backend: &StaticBackendDispatcher,
+  shasta_token: &str,
+  shasta_base_url: &str,
+  shasta_root_cert: &[u8],
+  cluster_state: &ClusterState,
+) -> Result<Option<(String, String)>, Error> {
+  let image = if let Some(boot_image_configuration) =
+    &cluster_state.boot_image_configuration
+  {
+    let mut image_vec = get_image_vec_related_cfs_configuration_name(
+      backend,
+      shasta_token,
+      shasta_base_url,
+      shasta_root_cert,
+      boot_image_configuration.to_string(),
+    )
+    .await?;
+
+    backend.filter_images(&mut image_vec)?;
+
+    image_vec.pop().ok_or_else(|| {
+      Error::Message(format!(
+        "Could not find boot image related to configuration '{}'",
+        boot_image_configuration
+      ))
+    })?
+  } else if let Some(boot_image_id) = &cluster_state.boot_image_id {
+    backend
+      .get_images(
+        shasta_token,
+        shasta_base_url,
+        shasta_root_cert,
+        Some(boot_image_id.as_str()),
+      )
+      .await?
+      .into_iter()
+      .next()
+      .ok_or_else(|| {
+        Error::Message(format!("Boot image id '{}' not found", boot_image_id))
+      })?
+  } else {
+    return Ok(None);
+  };
+
+  let image_id = image
+    .id
+    .ok_or_else(|| Error::Message("Boot image without id".to_string()))?;
+
+  let etag = image
+    .link
+    .and_then(|link| link.etag)
+    .unwrap_or_else(|| "Not found".to_string());
+
+  Ok(Some((image_id, etag)))
+}
+
+/// Live state of the nodes. Only fetches the data the cluster state needs
+async fn get_node_state_vec(
+  backend: &StaticBackendDispatcher,
+  shasta_token: &str,
+  shasta_base_url: &str,
+  shasta_root_cert: &[u8],
+  cluster_state: &ClusterState,
+  xname_vec: &[String],
+) -> Result<Vec<NodeState>, Error> {
+  let boot_parameters_vec = if cluster_state.boot_image_id.is_some()
+    || cluster_state.boot_image_configuration.is_some()
+    || cluster_state.kernel_parameters.is_some()
+  {
+    backend.get_bootparameters(shasta_token, xname_vec).await?
+  } else {
+    Vec::new()
+  };
+
+  let cfs_component_vec = if cluster_state.runtime_configuration.is_some() {
+    backend
+      .get_cfs_components(
+        shasta_token,
+        shasta_base_url,
+        shasta_root_cert,
+        None,
+        Some(&xname_vec.join(",")),
+        None,
+      )
+      .await?
+  } else {
+    Vec::new()
+  };
+
+  // Power state is needed to power nodes and to know which ones to reboot
+  let mut power_status_vec = backend
+    .power_status(shasta_token, xname_vec, None, None)
+    .await?
+    .status;
+
+  let node_state_vec = xname_vec
+    .iter()
+    .map(|xname| NodeState {
+      xname: xname.clone(),
+      boot_parameters: boot_parameters_vec
+        .iter()
+        .find(|boot_parameters| boot_parameters.hosts.contains(xname))
+        .cloned(),
+      desired_configuration: cfs_component_vec
+        .iter()
+        .find(|component| component.id.as_ref() == Some(xname))
+        .and_then(|component| component.desired_config.clone()),
+      power_state: power_status_vec
+        .iter()
+        .position(|power_status| &power_status.xname == xname)
+        .and_then(|index| power_status_vec.swap_remove(index).power_state),
+    })
+    .collect();
+
+  Ok(node_state_vec)
+}
+
+/// Applies the plan: membership, then boot parameters and runtime
+/// configuration with the nodes locked, then power
+async fn apply_plan(
+  backend: &StaticBackendDispatcher,
+  shasta_token: &str,
+  shasta_base_url: &str,
+  shasta_root_cert: &[u8],
+  cluster_state: &ClusterState,
+  boot_image_opt: Option<&(String, String)>,
+  node_state_vec: &[NodeState],
+  plan: &ClusterStatePlan,
+) -> Result<(), Error> {
+  if !plan.members_to_add.is_empty() || !plan.members_to_remove.is_empty() {
+    log::info!("Update members of group '{}'", cluster_state.group);
+    backend
+      .update_group_members(
+        shasta_token,
+        &cluster_state.group,
+        &plan.members_to_remove,
+        &plan.members_to_add,
+      )
+      .await?;
+  }
+
+  let xname_to_lock_vec: Vec<String> = plan
+    .boot_parameters()
+    .into_iter()
+    .chain(plan.runtime_configuration.iter().cloned())
+    .collect::<std::collections::HashSet<_>>()
+    .into_iter()
+    .collect();
+
+  if !xname_to_lock_vec.is_empty() {
+    let locked =
+      lock_ops::lock_nodes(backend, shasta_token, &xname_to_lock_vec).await?;
+
+    let result = update_node_configuration(
+      backend,
+      shasta_token,
+      shasta_base_url,
+      shasta_root_cert,
+      cluster_state,
+      boot_image_opt,
+      node_state_vec,
+      plan,
+    )
+    .await;
+
+    if locked {
+      lock_ops::unlock_nodes(backend, shasta_token, &xname_to_lock_vec).await;
+    }
+
+    result?;
+  }
+
+  if !plan.power_off.is_empty() {
+    log::info!("Power off nodes: {}", plan.power_off.join(", "));
+    backend
+      .power_off_sync(shasta_token, &plan.power_off, false)
+      .await?;
+  }
+
+  if !plan.power_on.is_empty() {
+    log::info!("Power on nodes: {}", plan.power_on.join(", "));
+    backend.power_on_sync(shasta_token, &plan.power_on).await?;
+  }
+
+  if !plan.power_reset.is_empty() {
+    log::info!("Power reset nodes: {}", plan.power_reset.join(", "));
+    backend
+      .power_reset_sync(shasta_token, &plan.power_reset, false)
+      .await?;
+  }
+
+  Ok(())
+}
+
+async fn update_node_configuration(
+  backend: &StaticBackendDispatcher,
+  shasta_token: &str,
+  shasta_base_url: &str,
+  shasta_root_cert: &[u8],
+  cluster_state: &ClusterState,
+  boot_image_opt: Option<&(String, String)>,
+  node_state_vec: &[NodeState],
+  plan: &ClusterStatePlan,
+) -> Result<(), Error> {
+  let xname_boot_parameters_vec = plan.boot_parameters();
+
+  for node_state in node_state_vec
+    .iter()
+    .filter(|node_state| xname_boot_parameters_vec.contains(&node_state.xname))
+  {
+    let current_boot_parameters =
+      node_state.boot_parameters.as_ref().ok_or_else(|| {
+        Error::Message(format!(
+          "Node '{}' has no boot parameters",
+          node_state.xname
+        ))
+      })?;
+
+    // Only update the node the boot parameters are for, other hosts sharing
+    // them may not be part of the cluster
+    let mut boot_parameters = BootParameters {
+      hosts: vec![node_state.xname.clone()],
+      ..current_boot_parameters.clone()
+    };
+
+    // IMPORTANT: ALWAYS SET KERNEL PARAMS BEFORE BOOT IMAGE BECAUSE KERNEL
+    // ALSO UPDATES THE BOOT IMAGE
+    if let Some(kernel_parameters) = &cluster_state.kernel_parameters {
+      boot_parameters.apply_kernel_params(kernel_parameters);
+      utils::keep_boot_image_kernel_params(
+        &mut boot_parameters,
+        current_boot_parameters,
+      );
+    }
+
+    if let Some((boot_image_id, etag)) = boot_image_opt {
+      boot_parameters.update_boot_image(boot_image_id, etag)?;
+    }
+
+    log::info!("Update boot parameters of '{}'", node_state.xname);
+    backend
+      .update_bootparameters(shasta_token, &boot_parameters)
+      .await?;
+  }
+
+  if let Some(runtime_configuration) = &cluster_state.runtime_configuration {
+    if !plan.runtime_configuration.is_empty() {
+      log::info!(
+        "Set runtime configuration '{}' to nodes: {}",
+        runtime_configuration,
+        plan.runtime_configuration.join(", ")
+      );
+      backend
+        .update_runtime_configuration(
+          shasta_token,
+          shasta_base_url,
+          shasta_root_cert,
+          plan.runtime_configuration.clone(),
+          runtime_configuration,
+          true,
+        )
+        .await?;
+    }
+  }
+
+  Ok(())
+}
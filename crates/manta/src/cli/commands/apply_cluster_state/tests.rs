@@ -0,0 +1,209 @@
+use manta_backend_dispatcher::types::{
+  bss::BootParameters, pcs::power_status::types::PowerState,
+};
+
+use crate::cli::commands::apply_cluster_state::utils::{
+  calculate_plan, get_xname_without_boot_parameters_vec, ClusterState,
+  DesiredPowerState, NodeState,
+};
+
+const CURRENT_IMAGE_ID: &str = "59e0180a-3fdd-4936-bba7-14ba914ffd34";
+const NEW_IMAGE_ID: &str = "a5c3d9b4-6f0e-4b8a-9c1d-2e7f8a9b0c1d";
+
+fn cluster_state() -> ClusterState {
+  serde_yaml::from_str("group: zinal").unwrap()
+}
+
+fn kernel_parameters(image_id: &str, extra: &str) -> String {
+  format!(
+    "console=ttyS0,115200 root=craycps-s3:s3://boot-images/{}/rootfs:3dfae8d1fa3bb2bfb18152b4f9940ad0-667:dvs:api-gw-service-nmn.local:300:nmn0 {}",
+    image_id, extra
+  )
+}
+
+fn node_state(xname: &str, power_state: PowerState) -> NodeState {
+  NodeState {
+    xname: xname.to_string(),
+    boot_parameters: Some(BootParameters {
+      hosts: vec![xname.to_string()],
+      params: kernel_parameters(CURRENT_IMAGE_ID, "quiet"),
+      ..Default::default()
+    }),
+    desired_configuration: Some("zinal-cos-config".to_string()),
+    power_state: Some(power_state),
+  }
+}
+
+#[test]
+fn test_cluster_state_file_rejects_unknown_fields() {
+  let cluster_state_rslt =
+    serde_yaml::from_str::<ClusterState>("group: zinal\nimage: foo");
+
+  assert!(cluster_state_rslt.is_err());
+}
+
+#[test]
+fn test_plan_empty_if_cluster_state_only_has_group() {
+  let node_state_vec = vec![node_state("x1001c1s5b0n0", PowerState::On)];
+
+  let plan = calculate_plan(
+    &cluster_state(),
+    None,
+    None,
+    &["x1001c1s5b0n0".to_string()],
+    &node_state_vec,
+    true,
+  );
+
+  assert!(plan.is_empty());
+}
+
+#[test]
+fn test_plan_members() {
+  let desired_member_vec =
+    vec!["x1001c1s5b0n0".to_string(), "x1001c1s5b0n1".to_string()];
+  let current_member_vec =
+    vec!["x1001c1s5b0n1".to_string(), "x1001c1s5b1n0".to_string()];
+
+  let plan = calculate_plan(
+    &cluster_state(),
+    None,
+    Some(desired_member_vec.as_slice()),
+    &current_member_vec,
+    &[],
+    true,
+  );
+
+  assert_eq!(plan.members_to_add, vec!["x1001c1s5b0n0".to_string()]);
+  assert_eq!(plan.members_to_remove, vec!["x1001c1s5b1n0".to_string()]);
+}
+
+#[test]
+fn test_plan_boot_image_reboots_nodes_on() {
+  let node_state_vec = vec![
+    node_state("x1001c1s5b0n0", PowerState::On),
+    node_state("x1001c1s5b0n1", PowerState::Off),
+  ];
+
+  let plan = calculate_plan(
+    &cluster_state(),
+    Some(NEW_IMAGE_ID),
+    None,
+    &[],
+    &node_state_vec,
+    true,
+  );
+
+  assert_eq!(
+    plan.boot_image,
+    vec!["x1001c1s5b0n0".to_string(), "x1001c1s5b0n1".to_string()]
+  );
+  assert_eq!(plan.power_reset, vec!["x1001c1s5b0n0".to_string()]);
+
+  let plan = calculate_plan(
+    &cluster_state(),
+    Some(NEW_IMAGE_ID),
+    None,
+    &[],
+    &node_state_vec,
+    false,
+  );
+
+  assert!(plan.power_reset.is_empty());
+}
+
+#[test]
+fn test_plan_kernel_parameters_ignore_boot_image() {
+  let node_state_vec = vec![node_state("x1001c1s5b0n0", PowerState::On)];
+
+  // Same kernel parameters in a different order and pointing to another boot
+  // image, the boot image is not changed by kernel parameters
+  let mut cluster_state = cluster_state();
+  cluster_state.kernel_parameters =
+    Some(format!("quiet {}", kernel_parameters(NEW_IMAGE_ID, "")));
+
+  let plan =
+    calculate_plan(&cluster_state, None, None, &[], &node_state_vec, true);
+
+  assert!(plan.is_empty());
+
+  cluster_state.kernel_parameters =
+    Some(kernel_parameters(CURRENT_IMAGE_ID, "quiet nosmt"));
+
+  let plan =
+    calculate_plan(&cluster_state, None, None, &[], &node_state_vec, true);
+
+  assert_eq!(plan.kernel_parameters, vec!["x1001c1s5b0n0".to_string()]);
+  assert_eq!(plan.power_reset, vec!["x1001c1s5b0n0".to_string()]);
+}
+
+#[test]
+fn test_plan_runtime_configuration() {
+  let mut node_state_vec = vec![
+    node_state("x1001c1s5b0n0", PowerState::On),
+    node_state("x1001c1s5b0n1", PowerState::On),
+  ];
+  node_state_vec[1].desired_configuration = None;
+
+  let mut cluster_state = cluster_state();
+  cluster_state.runtime_configuration = Some("zinal-cos-config".to_string());
+
+  let plan =
+    calculate_plan(&cluster_state, None, None, &[], &node_state_vec, true);
+
+  assert_eq!(
+    plan.runtime_configuration,
+    vec!["x1001c1s5b0n1".to_string()]
+  );
+  assert!(plan.power_reset.is_empty());
+}
+
+#[test]
+fn test_plan_power_off_does_not_reboot() {
+  let node_state_vec = vec![
+    node_state("x1001c1s5b0n0", PowerState::On),
+    node_state("x1001c1s5b0n1", PowerState::Off),
+  ];
+
+  let mut cluster_state = cluster_state();
+  cluster_state.power = Some(DesiredPowerState::Off);
+
+  let plan = calculate_plan(
+    &cluster_state,
+    Some(NEW_IMAGE_ID),
+    None,
+    &[],
+    &node_state_vec,
+    true,
+  );
+
+  assert_eq!(plan.power_off, vec!["x1001c1s5b0n0".to_string()]);
+  assert!(plan.power_on.is_empty());
+  assert!(plan.power_reset.is_empty());
+}
+
+#[test]
+fn test_xname_without_boot_parameters_only_if_boot_parameters_change() {
+  let mut node_state_without_boot_parameters =
+    node_state("x1003c1s7b0n1", PowerState::On);
+  node_state_without_boot_parameters.boot_parameters = None;
+
+  let node_state_vec = vec![
+    node_state("x1003c1s7b0n0", PowerState::On),
+    node_state_without_boot_parameters,
+  ];
+
+  assert!(get_xname_without_boot_parameters_vec(
+    &cluster_state(),
+    &node_state_vec
+  )
+  .is_empty());
+
+  let mut cluster_state = cluster_state();
+  cluster_state.boot_image_id = Some(NEW_IMAGE_ID.to_string());
+
+  assert_eq!(
+    get_xname_without_boot_parameters_vec(&cluster_state, &node_state_vec),
+    vec!["x1003c1s7b0n1"]
+  );
+}
@@ -0,0 +1,286 @@
+use std::collections::HashSet;
+
+use manta_backend_dispatcher::types::{
+  bss::BootParameters, pcs::power_status::types::PowerState,
+};
+use nodeset::NodeSet;
+use serde::{Deserialize, Serialize};
+
+/// Desired state of an HSM group. Fields not set are left as they are
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct ClusterState {
+  /// HSM group name
+  pub group: String,
+  /// Group members. Xnames, nids or hostlist expressions
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub members: Option<Vec<String>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub boot_image_id: Option<String>,
+  /// CFS configuration used to build the boot image. The most recent image
+  /// built with it is used
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub boot_image_configuration: Option<String>,
+  /// All kernel parameters, the ones not listed here are removed
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub kernel_parameters: Option<String>,
+  /// Desired CFS configuration
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub runtime_configuration: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub power: Option<DesiredPowerState>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum DesiredPowerState {
+  On,
+  Off,
+}
+
+/// Live state of a node
+pub struct NodeState {
+  pub xname: String,
+  pub boot_parameters: Option<BootParameters>,
+  pub desired_configuration: Option<String>,
+  pub power_state: Option<PowerState>,
+}
+
+/// Changes needed to take a cluster from its live state to its desired state.
+/// Each list contains the xnames the change applies to
+#[derive(Debug, Serialize, Default, PartialEq)]
+pub struct ClusterStatePlan {
+  pub members_to_add: Vec<String>,
+  pub members_to_remove: Vec<String>,
+  pub boot_image: Vec<String>,
+  pub kernel_parameters: Vec<String>,
+  pub runtime_configuration: Vec<String>,
+  pub power_on: Vec<String>,
+  pub power_off: Vec<String>,
+  /// Nodes already running which need a restart to boot with their new boot
+  /// image or kernel parameters
+  pub power_reset: Vec<String>,
+}
+
+impl ClusterStatePlan {
+  pub fn is_empty(&self) -> bool {
+    self.members_to_add.is_empty()
+      && self.members_to_remove.is_empty()
+      && self.boot_image.is_empty()
+      && self.kernel_parameters.is_empty()
+      && self.runtime_configuration.is_empty()
+      && self.power_on.is_empty()
+      && self.power_off.is_empty()
+      && self.power_reset.is_empty()
+  }
+
+  /// Nodes whose boot parameters change
+  pub fn boot_parameters(&self) -> Vec<String> {
+    let mut xname_vec: Vec<String> = self
+      .boot_image
+      .iter()
+      .chain(self.kernel_parameters.iter())
+      .cloned()
+      .collect::<HashSet<_>>()
+      .into_iter()
+      .collect();
+
+    xname_vec.sort();
+
+    xname_vec
+  }
+}
+
+/// Nodes without boot parameters. Their boot image and kernel parameters
+/// can't be changed, therefore the cluster state can't be applied if it sets
+/// any of them
+pub fn get_xname_without_boot_parameters_vec(
+  cluster_state: &ClusterState,
+  node_state_vec: &[NodeState],
+) -> Vec<String> {
+  if cluster_state.boot_image_id.is_none()
+    && cluster_state.boot_image_configuration.is_none()
+    && cluster_state.kernel_parameters.is_none()
+  {
+    return Vec::new();
+  }
+
+  node_state_vec
+    .iter()
+    .filter(|node_state| node_state.boot_parameters.is_none())
+    .map(|node_state| node_state.xname.clone())
+    .collect()
+}
+
+/// Compares the desired state of a cluster against its live state.
+/// `boot_image_id_opt` is the boot image id the cluster state resolves to
+pub fn calculate_plan(
+  cluster_state: &ClusterState,
+  boot_image_id_opt: Option<&str>,
+  desired_member_vec_opt: Option<&[String]>,
+  current_member_vec: &[String],
+  node_state_vec: &[NodeState],
+  reboot: bool,
+) -> ClusterStatePlan {
+  let mut plan = ClusterStatePlan::default();
+
+  if let Some(desired_member_vec) = desired_member_vec_opt {
+    plan.members_to_add = desired_member_vec
+      .iter()
+      .filter(|xname| !current_member_vec.contains(xname))
+      .cloned()
+      .collect();
+
+    plan.members_to_remove = current_member_vec
+      .iter()
+      .filter(|xname| !desired_member_vec.contains(xname))
+      .cloned()
+      .collect();
+  }
+
+  for node_state in node_state_vec {
+    let xname = &node_state.xname;
+
+    if let Some(boot_parameters) = &node_state.boot_parameters {
+      if let Some(boot_image_id) = boot_image_id_opt {
+        if boot_parameters.get_boot_image_id() != boot_image_id {
+          plan.boot_image.push(xname.clone());
+        }
+      }
+
+      if let Some(kernel_parameters) = &cluster_state.kernel_parameters {
+        if kernel_parameters_change(boot_parameters, kernel_parameters) {
+          plan.kernel_parameters.push(xname.clone());
+        }
+      }
+    }
+
+    if let Some(runtime_configuration) = &cluster_state.runtime_configuration {
+      if node_state.desired_configuration.as_ref()
+        != Some(runtime_configuration)
+      {
+        plan.runtime_configuration.push(xname.clone());
+      }
+    }
+
+    let is_on = matches!(node_state.power_state, Some(PowerState::On));
+    let is_off = matches!(node_state.power_state, Some(PowerState::Off));
+
+    match cluster_state.power {
+      Some(DesiredPowerState::On) if !is_on => {
+        plan.power_on.push(xname.clone())
+      }
+      Some(DesiredPowerState::Off) if !is_off => {
+        plan.power_off.push(xname.clone())
+      }
+      Some(DesiredPowerState::Off) => {}
+      _ => {
+        let boot_parameters_change = plan.boot_image.contains(xname)
+          || plan.kernel_parameters.contains(xname);
+
+        if reboot && is_on && boot_parameters_change {
+          plan.power_reset.push(xname.clone());
+        }
+      }
+    }
+  }
+
+  plan.members_to_add.sort();
+  plan.members_to_remove.sort();
+  plan.boot_image.sort();
+  plan.kernel_parameters.sort();
+  plan.runtime_configuration.sort();
+  plan.power_on.sort();
+  plan.power_off.sort();
+  plan.power_reset.sort();
+
+  plan
+}
+
+/// Whether replacing the kernel parameters of a node changes them. The boot
+/// image related kernel parameters are kept as they are, like
+/// 'manta apply kernel-parameters' does
+pub fn kernel_parameters_change(
+  boot_parameters: &BootParameters,
+  kernel_parameters: &str,
+) -> bool {
+  let mut new_boot_parameters = boot_parameters.clone();
+
+  new_boot_parameters.apply_kernel_params(kernel_parameters);
+  keep_boot_image_kernel_params(&mut new_boot_parameters, boot_parameters);
+
+  let current_kernel_param_set: HashSet<&str> =
+    boot_parameters.params.split_whitespace().collect();
+  let new_kernel_param_set: HashSet<&str> =
+    new_boot_parameters.params.split_whitespace().collect();
+
+  current_kernel_param_set != new_kernel_param_set
+}
+
+pub fn print_plan(
+  cluster_state: &ClusterState,
+  boot_image_id_opt: Option<&str>,
+  plan: &ClusterStatePlan,
+) {
+  println!("Plan for cluster '{}':", cluster_state.group);
+
+  if plan.is_empty() {
+    println!("  No changes. Cluster is up to date");
+    return;
+  }
+
+  print_plan_line("+", "add to group", &plan.members_to_add);
+  print_plan_line("-", "remove from group", &plan.members_to_remove);
+  print_plan_line(
+    "~",
+    &format!("boot image -> {}", boot_image_id_opt.unwrap_or_default()),
+    &plan.boot_image,
+  );
+  print_plan_line("~", "kernel parameters", &plan.kernel_parameters);
+  print_plan_line(
+    "~",
+    &format!(
+      "runtime configuration -> {}",
+      cluster_state
+        .runtime_configuration
+        .as_deref()
+        .unwrap_or_default()
+    ),
+    &plan.runtime_configuration,
+  );
+  print_plan_line("~", "power on", &plan.power_on);
+  print_plan_line("~", "power off", &plan.power_off);
+  print_plan_line("~", "power reset", &plan.power_reset);
+}
+
+fn print_plan_line(symbol: &str, action: &str, xname_vec: &[String]) {
+  if xname_vec.is_empty() {
+    return;
+  }
+
+  let node_group: NodeSet = xname_vec.join(", ").parse().unwrap();
+
+  println!(
+    "  {} {} ({} nodes): {}",
+    symbol,
+    action,
+    node_group.len(),
+    node_group
+  );
+}
+
+/// Sets the boot image related kernel parameters ('root', 'metal.server' and
+/// 'nmd_data') back to the values in the current boot parameters
+pub fn keep_boot_image_kernel_params(
+  boot_parameters: &mut BootParameters,
+  current_boot_parameters: &BootParameters,
+) {
+  for (key, value) in current_boot_parameters
+    .params
+    .split_whitespace()
+    .filter_map(|kernel_param| kernel_param.split_once('='))
+    .filter(|(key, _)| ["root", "metal.server", "nmd_data"].contains(key))
+  {
+    boot_parameters.update_kernel_param(key, value);
+  }
+}
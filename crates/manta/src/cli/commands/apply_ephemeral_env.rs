@@ -1,11 +1,12 @@
 use csm_rs::ims;
+use manta_backend_dispatcher::error::Error;
 
 pub async fn exec(
   shasta_token: &str,
   shasta_base_url: &str,
   shasta_root_cert: &[u8],
   /* block: Option<bool>, */ image_id: &str,
-) {
+) -> Result<(), Error> {
   // Take user name and check if there is an SSH public key with that name already in Alps
   let user_public_key_name =
     csm_rs::common::jwt_ops::get_preferred_username(shasta_token)
@@ -24,8 +25,10 @@ pub async fn exec(
   {
     user_public_ssh_value["id"].clone()
   } else {
-    eprintln!("User '{}' does not have an SSH public key in Alps, Please contact platform sys admins. Exit", user_public_key_name);
-    std::process::exit(1);
+    return Err(Error::Message(format!(
+      "User '{}' does not have an SSH public key in Alps, Please contact platform sys admins",
+      user_public_key_name
+    )));
   };
 
   log::info!("SSH key found with ID {}", user_public_ssh_id_value);
@@ -48,19 +51,11 @@ pub async fn exec(
   )
   .await;
 
-  let hostname_value = match resp_json_rslt {
-    Ok(resp_json) => resp_json
-      .pointer("/ssh_containers/0/connection_info/customer_access/host")
-      .cloned()
-      .unwrap(),
-    Err(error) => {
-      eprintln!(
-        "ERROR: could not create ephemeral environment. Reason:\n{:#?}\nExit",
-        error
-      );
-      std::process::exit(1);
-    }
-  };
+  let hostname_value = resp_json_rslt
+    .map_err(Error::from)?
+    .pointer("/ssh_containers/0/connection_info/customer_access/host")
+    .cloned()
+    .unwrap();
 
   // if block.unwrap() {
   //     println!("Now block the call");
@@ -74,4 +69,6 @@ pub async fn exec(
     hostname_value.as_str().unwrap()
   );
   println!("{}", hostname_value.as_str().unwrap());
+
+  Ok(())
 }
@@ -1,7 +1,9 @@
 use std::{collections::HashMap, sync::Arc, time::Instant};
 
 use comfy_table::Color;
-use manta_backend_dispatcher::interfaces::hsm::hardware_inventory::HardwareInventory;
+use manta_backend_dispatcher::{
+  error::Error, interfaces::hsm::hardware_inventory::HardwareInventory,
+};
 use serde_json::Value;
 use tokio::sync::Semaphore;
 
@@ -19,10 +21,13 @@ pub async fn resolve_hw_description_to_xnames(
     HashMap<String, usize>,
   )>,
   user_defined_target_hsm_hw_component_count_hashmap: HashMap<String, usize>,
-) -> (
-  Vec<(String, HashMap<String, usize>)>,
-  Vec<(String, HashMap<String, usize>)>,
-) {
+) -> Result<
+  (
+    Vec<(String, HashMap<String, usize>)>,
+    Vec<(String, HashMap<String, usize>)>,
+  ),
+  Error,
+> {
   // *********************************************************************************************************
   // CALCULATE 'COMBINED HSM' WITH TARGET HSM AND PARENT HSM ELEMENTS COMBINED
   // NOTE: PARENT HSM may contain elements in TARGET HSM, we need to only add those xnames
@@ -82,14 +87,14 @@ pub async fn resolve_hw_description_to_xnames(
       &mut target_hsm_node_hw_component_count_vec,
       &mut parent_hsm_node_hw_component_count_vec,
       &hw_component_scarcity_scores_hashmap,
-    );
+    )?;
 
   let new_target_hsm_node_hw_component_count_vec =
     hw_component_counters_to_move_out_from_combined_hsm;
-  (
+  Ok((
     new_target_hsm_node_hw_component_count_vec,
     combined_target_parent_hsm_node_hw_component_count_vec,
-  )
+  ))
 }
 
 /// Pin means this function should be used when the user wants to keep as much nodes in
@@ -153,15 +158,6 @@ pub fn get_best_candidate_in_target_and_parent_hsm_pin(
   } else {
     None
   }
-
-  /* if let Some(target_best_candidate) = target_best_candidate_tuple {
-      target_best_candidate
-  } else if let Some(parent_best_candidate) = parent_best_candidate_tuple {
-      parent_best_candidate
-  } else {
-      eprintln!("ERROR - No best candidate found.");
-      std::process::exit(1);
-  } */
 }
 
 /// Generates a list of tuples with xnames and the hardware summary for each node. This method
@@ -193,7 +189,7 @@ pub fn calculate_target_hsm_pin(
   hw_component_scarcity_scores_hashmap: &HashMap<String, f32>, // hw
                                                                // component type score for as much hsm groups related to the stakeholders using these
                                                                // nodes
-) -> Vec<(String, HashMap<String, usize>)> {
+) -> Result<Vec<(String, HashMap<String, usize>)>, Error> {
   ////////////////////////////////
   // Initialize
 
@@ -254,31 +250,6 @@ pub fn calculate_target_hsm_pin(
     HashMap<String, usize>,
   )> = Vec::new();
 
-  /* // Get best candidate in 'target' HSM group
-  let target_best_candidate_tuple =
-      get_best_candidate_in_hsm_based_on_scarcity_node_score_pin(
-          &mut target_hsm_node_score_tuple_vec,
-          target_hsm_node_hw_component_count_vec,
-      );
-
-  // Get best candidate in 'parent' HSM group
-  let parent_best_candidate_tuple =
-      get_best_candidate_in_hsm_based_on_scarcity_node_score_pin(
-          &mut parent_hsm_node_score_tuple_vec,
-          parent_hsm_node_hw_component_count_vec,
-      );
-
-  // If best candidate exists (in 'target' HSM group), then use it. Otherwise, use the one in 'parent' HSM group
-  let (mut best_candidate, mut best_candidate_counters) =
-      if let Some(target_best_candidate) = target_best_candidate_tuple {
-          target_best_candidate
-      } else if let Some(parent_best_candidate) = parent_best_candidate_tuple {
-          parent_best_candidate
-      } else {
-          eprintln!("ERROR - No best candidate found.");
-          std::process::exit(1);
-      }; */
-
   let (mut best_candidate, mut best_candidate_counters) =
     get_best_candidate_in_target_and_parent_hsm_pin(
       &mut target_hsm_node_score_tuple_vec,
@@ -286,10 +257,7 @@ pub fn calculate_target_hsm_pin(
       target_hsm_node_hw_component_count_vec,
       parent_hsm_node_hw_component_count_vec,
     )
-    .unwrap_or_else(|| {
-      eprintln!("ERROR - No best candidate found.");
-      std::process::exit(1);
-    });
+    .ok_or_else(|| Error::Message("No best candidate found".to_string()))?;
 
   // Check if we need to keep iterating
   let mut work_to_do = keep_iterating_final_hsm(
@@ -413,31 +381,6 @@ pub fn calculate_target_hsm_pin(
         .or_insert(vec![node.clone()]);
     }
 
-    /* // Get best candidate in 'target' HSM group
-    let target_best_candidate_tuple =
-        get_best_candidate_in_hsm_based_on_scarcity_node_score_pin(
-            &mut target_hsm_node_score_tuple_vec,
-            target_hsm_node_hw_component_count_vec,
-        );
-
-    // Get best candidate in 'parent' HSM group
-    let parent_best_candidate_tuple =
-        get_best_candidate_in_hsm_based_on_scarcity_node_score_pin(
-            &mut parent_hsm_node_score_tuple_vec,
-            parent_hsm_node_hw_component_count_vec,
-        );
-
-    // If best candidate exists (in 'target' HSM group), then use it. Otherwise, use the one in 'parent' HSM group
-    (best_candidate, best_candidate_counters) =
-        if let Some(target_best_candidate) = target_best_candidate_tuple {
-            target_best_candidate
-        } else if let Some(parent_best_candidate) = parent_best_candidate_tuple {
-            parent_best_candidate
-        } else {
-            eprintln!("ERROR - No best candidate found.");
-            std::process::exit(1);
-        }; */
-
     (best_candidate, best_candidate_counters) =
       get_best_candidate_in_target_and_parent_hsm_pin(
         &mut target_hsm_node_score_tuple_vec,
@@ -445,7 +388,7 @@ pub fn calculate_target_hsm_pin(
         target_hsm_node_hw_component_count_vec,
         parent_hsm_node_hw_component_count_vec,
       )
-      .expect("ERROR - No best candidate found.");
+      .ok_or_else(|| Error::Message("No best candidate found".to_string()))?;
 
     // Check if we need to keep iterating
     work_to_do = keep_iterating_final_hsm(
@@ -476,7 +419,7 @@ pub fn calculate_target_hsm_pin(
     &parent_hsm_node_score_tuple_vec,
   );
 
-  nodes_migrated_from_combination_target_parent_hsm
+  Ok(nodes_migrated_from_combination_target_parent_hsm)
 }
 
 pub async fn calculate_hw_component_scarcity_scores(
@@ -1,5 +1,7 @@
 use manta_backend_dispatcher::{
-  interfaces::hsm::group::GroupTrait, types::Group,
+  error::{Error, ErrorKind},
+  interfaces::hsm::group::GroupTrait,
+  types::Group,
 };
 use std::collections::HashMap;
 
@@ -20,7 +22,7 @@ pub async fn exec(
   dryrun: bool,
   create_target_hsm_group: bool,
   delete_empty_parent_hsm_group: bool,
-) {
+) -> Result<(), Error> {
   // *********************************************************************************************************
   // PREPREQUISITES - FORMAT USER INPUT
 
@@ -48,16 +50,16 @@ pub async fn exec(
   // Check user pattern is of format <hw component>:<quantity> where
   // `hw component` is a string and `quantity` is a number.
   for hw_component_counter in pattern_element_vec.chunks(2) {
-    if hw_component_counter[0].parse::<String>().is_ok()
-      && hw_component_counter[1].parse::<usize>().is_ok()
-    {
-      user_defined_target_hsm_hw_component_count_hashmap.insert(
-        hw_component_counter[0].parse::<String>().unwrap(),
-        hw_component_counter[1].parse::<usize>().unwrap(),
-      );
-    } else {
-      log::error!("Error in pattern. Please make sure to follow <hsm name>:<hw component>:<counter>:... eg <tasna>:a100:4:epyc:10:instinct:8");
-      std::process::exit(1);
+    match hw_component_counter {
+      [hw_component, counter] if counter.parse::<usize>().is_ok() => {
+        user_defined_target_hsm_hw_component_count_hashmap
+          .insert(hw_component.to_string(), counter.parse::<usize>().unwrap());
+      }
+      _ => {
+        return Err(Error::Message(
+          "Error in pattern. Please make sure to follow <hsm name>:<hw component>:<counter>:... eg <tasna>:a100:4:epyc:10:instinct:8".to_string(),
+        ));
+      }
     }
   }
 
@@ -81,32 +83,33 @@ pub async fn exec(
     Ok(_) => {
       log::debug!("Target HSM group '{}' exists, good.", target_hsm_group_name)
     }
-    Err(_) => {
-      if create_target_hsm_group {
-        log::info!("Target HSM group '{}' does not exist, but the option to create the group has been selected, creating it now.", target_hsm_group_name.to_string());
-        if dryrun {
-          log::error!(
-            "Dryrun selected, cannot create the new group and continue."
-          );
-        } else {
-          let group = Group {
-            label: target_hsm_group_name.to_string(),
-            description: None,
-            tags: None,
-            members: None,
-            exclusive_group: Some("false".to_string()),
-          };
-
-          backend
-            .add_group(shasta_token, group)
-            .await
-            .expect("Unable to create new target HSM group");
-        }
+    Err(e) if e.kind() == ErrorKind::NotFound => {
+      if !create_target_hsm_group {
+        return Err(Error::Message(format!(
+          "Target HSM group '{}' does not exist, but the option to create the group was NOT specificied, cannot continue.",
+          target_hsm_group_name
+        )));
+      }
+
+      log::info!("Target HSM group '{}' does not exist, but the option to create the group has been selected, creating it now.", target_hsm_group_name);
+
+      if dryrun {
+        log::error!(
+          "Dryrun selected, cannot create the new group and continue."
+        );
       } else {
-        log::error!("Target HSM group '{}' does not exist, but the option to create the group was NOT specificied, cannot continue.", target_hsm_group_name.to_string());
-        std::process::exit(1);
+        let group = Group {
+          label: target_hsm_group_name.to_string(),
+          description: None,
+          tags: None,
+          members: None,
+          exclusive_group: Some("false".to_string()),
+        };
+
+        backend.add_group(shasta_token, group).await?;
       }
     }
+    Err(e) => return Err(e),
   };
 
   // Get target HSM group members
@@ -115,8 +118,7 @@ pub async fn exec(
       shasta_token,
       vec![target_hsm_group_name.to_string()],
     )
-    .await
-    .unwrap();
+    .await?;
 
   // Get HSM hw component counters for target HSM
   let mut target_hsm_node_hw_component_count_vec: Vec<(
@@ -155,8 +157,7 @@ pub async fn exec(
       shasta_token,
       vec![parent_hsm_group_name.to_string()],
     )
-    .await
-    .unwrap();
+    .await?;
 
   // Get HSM hw component counters for parent HSM
   let mut parent_hsm_node_hw_component_count_vec: Vec<(
@@ -207,10 +208,9 @@ pub async fn exec(
       // We are ok, user has access to enough resources to fullfill its request
     } else {
       // There are not enough resources to fulfill the user request
-      eprintln!(
-        "ERROR - there are not enough resources to fulfill user request."
-      );
-      std::process::exit(1);
+      return Err(Error::Message(
+        "There are not enough resources to fulfill user request".to_string(),
+      ));
     }
   }
 
@@ -225,7 +225,7 @@ pub async fn exec(
     parent_hsm_node_hw_component_count_vec,
     user_defined_target_hsm_hw_component_count_hashmap,
   )
-  .await;
+  .await?;
 
   // Calculate hw component counters (summary) across all node within the HSM group
   let target_hsm_hw_component_summary_hashmap =
@@ -256,14 +256,14 @@ pub async fn exec(
   } else {
     // The target HSM group will never be empty, the way the pattern works it'll always
     // contain at least one node, so there is no need to add code to delete it if it's empty.
-    let _ = backend
+    backend
       .update_group_members(
         shasta_token,
         target_hsm_group_name,
         &target_hsm_group_member_vec,
         &target_hsm_node_vec,
       )
-      .await;
+      .await?;
   }
 
   // *********************************************************************************************************
@@ -279,14 +279,14 @@ pub async fn exec(
     // if there are still nodes there and, delete it after moving out the resources.
     let parent_group_will_be_empty =
       &target_hsm_group_member_vec.len() == &parent_hsm_group_member_vec.len();
-    let _ = backend
+    backend
       .update_group_members(
         shasta_token,
         parent_hsm_group_name,
         &parent_hsm_group_member_vec,
         &parent_hsm_node_vec,
       )
-      .await;
+      .await?;
     if parent_group_will_be_empty {
       if delete_empty_parent_hsm_group {
         log::info!("Parent HSM group '{}' is now empty and the option to delete empty groups has been selected, removing it.",parent_hsm_group_name);
@@ -344,4 +344,6 @@ pub async fn exec(
     "{}",
     serde_json::to_string_pretty(&parent_hsm_group_value).unwrap()
   );
+
+  Ok(())
 }
@@ -1,7 +1,9 @@
 use std::{collections::HashMap, sync::Arc, time::Instant};
 
 use comfy_table::Color;
-use manta_backend_dispatcher::interfaces::hsm::hardware_inventory::HardwareInventory;
+use manta_backend_dispatcher::{
+  error::Error, interfaces::hsm::hardware_inventory::HardwareInventory,
+};
 use serde_json::Value;
 use tokio::sync::Semaphore;
 
@@ -13,10 +15,13 @@ pub async fn resolve_hw_description_to_xnames(
   target_hsm_node_hw_component_count_vec: Vec<(String, HashMap<String, usize>)>,
   parent_hsm_node_hw_component_count_vec: Vec<(String, HashMap<String, usize>)>,
   user_defined_target_hsm_hw_component_count_hashmap: HashMap<String, usize>,
-) -> (
-  Vec<(String, HashMap<String, usize>)>,
-  Vec<(String, HashMap<String, usize>)>,
-) {
+) -> Result<
+  (
+    Vec<(String, HashMap<String, usize>)>,
+    Vec<(String, HashMap<String, usize>)>,
+  ),
+  Error,
+> {
   // *********************************************************************************************************
   // CALCULATE 'COMBINED HSM' WITH TARGET HSM AND PARENT HSM ELEMENTS COMBINED
   // NOTE: PARENT HSM may contain elements in TARGET HSM, we need to only add those xnames
@@ -74,14 +79,14 @@ pub async fn resolve_hw_description_to_xnames(
         .collect::<Vec<String>>(),
       &mut combined_target_parent_hsm_node_hw_component_count_vec,
       &hw_component_scarcity_scores_hashmap,
-    );
+    )?;
 
   let new_target_hsm_node_hw_component_count_vec =
     hw_component_counters_to_move_out_from_combined_hsm;
-  (
+  Ok((
     new_target_hsm_node_hw_component_count_vec,
     combined_target_parent_hsm_node_hw_component_count_vec,
-  )
+  ))
 }
 
 /// Unpin means this function was defined to be used when user does not want to pin nodes in
@@ -135,7 +140,7 @@ pub fn calculate_target_hsm_unpin(
   hw_component_scarcity_scores_hashmap: &HashMap<String, f32>, // hw
                                                                // component type score for as much hsm groups related to the stakeholders using these
                                                                // nodes
-) -> Vec<(String, HashMap<String, usize>)> {
+) -> Result<Vec<(String, HashMap<String, usize>)>, Error> {
   ////////////////////////////////
   // Initialize
 
@@ -169,10 +174,7 @@ pub fn calculate_target_hsm_unpin(
       &mut combination_target_parent_hsm_node_score_tuple_vec,
       combination_target_parent_hsm_node_hw_component_count_vec,
     )
-    .unwrap_or_else(|| {
-      eprintln!("ERROR - No best candidate found.");
-      std::process::exit(1);
-    });
+    .ok_or_else(|| Error::Message("No best candidate found".to_string()))?;
 
   // Check if we need to keep iterating
   let mut work_to_do = keep_iterating_final_hsm(
@@ -255,10 +257,7 @@ pub fn calculate_target_hsm_unpin(
         &mut target_hsm_node_score_tuple_vec,
         combination_target_parent_hsm_node_hw_component_count_vec,
       )
-      .unwrap_or_else(|| {
-        eprintln!("ERROR - No best candidate found.");
-        std::process::exit(1);
-      });
+      .ok_or_else(|| Error::Message("No best candidate found".to_string()))?;
 
     // Check if we need to keep iterating
     work_to_do = keep_iterating_final_hsm(
@@ -280,7 +279,7 @@ pub fn calculate_target_hsm_unpin(
     &combination_target_parent_hsm_node_score_tuple_vec,
   );
 
-  nodes_migrated_from_combination_target_parent_hsm
+  Ok(nodes_migrated_from_combination_target_parent_hsm)
 }
 
 pub async fn calculate_hw_component_scarcity_scores(
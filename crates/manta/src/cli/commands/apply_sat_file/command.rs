@@ -1,14 +1,12 @@
 use dialoguer::theme::ColorfulTheme;
 use manta_backend_dispatcher::{
-  interfaces::apply_sat_file::SatTrait,
-  types::{K8sAuth, K8sDetails},
+  error::Error, interfaces::apply_sat_file::SatTrait, types::K8sDetails,
 };
 use serde_yaml::Value;
 use termion::color;
 
 use crate::{
   cli::commands::apply_sat_file::utils,
-  common::vault::http_client::fetch_shasta_k8s_secrets_from_vault,
   manta_backend_dispatcher::StaticBackendDispatcher,
 };
 
@@ -18,8 +16,6 @@ pub async fn exec(
   shasta_token: &str,
   shasta_base_url: &str,
   shasta_root_cert: &[u8],
-  vault_base_url: &str,
-  k8s_api_url: &str,
   sat_file_content: String,
   values_file_content_opt: Option<String>,
   values_cli_opt: Option<Vec<String>>,
@@ -39,7 +35,7 @@ pub async fn exec(
   dry_run: bool,
   assume_yes: bool,
   k8s: &K8sDetails,
-) {
+) -> Result<(), Error> {
   // Validate Pre-hook
   log::info!("Validating pre-hook script");
   if prehook.is_some() {
@@ -49,8 +45,11 @@ pub async fn exec(
         prehook.unwrap()
       ),
       Err(e) => {
-        log::error!("{}. File: {}", e, &prehook.unwrap());
-        std::process::exit(2);
+        return Err(Error::Message(format!(
+          "{}. File: {}",
+          e,
+          &prehook.unwrap()
+        )));
       }
     };
   }
@@ -64,8 +63,11 @@ pub async fn exec(
         posthook.unwrap()
       ),
       Err(e) => {
-        log::error!("{}. File: {}", e, &posthook.unwrap());
-        std::process::exit(2);
+        return Err(Error::Message(format!(
+          "{}. File: {}",
+          e,
+          &posthook.unwrap()
+        )));
       }
     };
   }
@@ -75,8 +77,7 @@ pub async fn exec(
     &sat_file_content,
     values_file_content_opt.as_ref(),
     values_cli_opt,
-  )
-  .clone();
+  )?;
 
   let sat_template_file_string =
     serde_yaml::to_string(&sat_template_file_yaml).unwrap();
@@ -87,7 +88,7 @@ pub async fn exec(
 
   // Filter either images or session_templates section according to user request
   //
-  sat_template.filter(image_only, session_template_only);
+  sat_template.filter(image_only, session_template_only)?;
 
   let sat_template_file_yaml: Value =
     serde_yaml::to_value(sat_template).unwrap();
@@ -114,8 +115,7 @@ pub async fn exec(
     match crate::common::hooks::run_hook(prehook).await {
       Ok(_code) => log::debug!("Pre-hook script completed ok. RT={}", _code),
       Err(_error) => {
-        log::error!("{}", _error);
-        std::process::exit(2);
+        return Err(Error::Message(format!("{}", _error)));
       }
     };
   }
@@ -124,34 +124,16 @@ pub async fn exec(
     println!("Proceed and process SAT file");
   } else {
     println!("Operation canceled by user. Exit");
-    std::process::exit(0);
+    return Ok(());
   }
 
-  // Get K8s secrets
-  let shasta_k8s_secrets = match &k8s.authentication {
-    K8sAuth::Native {
-      certificate_authority_data,
-      client_certificate_data,
-      client_key_data,
-    } => {
-      serde_json::json!({ "certificate-authority-data": certificate_authority_data, "client-certificate-data": client_certificate_data, "client-key-data": client_key_data })
-    }
-    K8sAuth::Vault { base_url } => {
-      fetch_shasta_k8s_secrets_from_vault(&base_url, site_name, shasta_token)
-        .await
-        .unwrap()
-    }
-  };
-
   backend
     .apply_sat_file(
       shasta_token,
       shasta_base_url,
       shasta_root_cert,
-      vault_base_url,
       site_name,
-      k8s_api_url,
-      shasta_k8s_secrets,
+      k8s,
       sat_template_file_yaml,
       hsm_group_available_vec,
       ansible_verbosity_opt,
@@ -164,11 +146,7 @@ pub async fn exec(
       overwrite,
       dry_run,
     )
-    .await
-    .unwrap_or_else(|e| {
-      eprintln!("{}", e);
-      std::process::exit(1);
-    });
+    .await?;
 
   // Run/process Post-hook
   if posthook.is_some() {
@@ -176,9 +154,10 @@ pub async fn exec(
     match crate::common::hooks::run_hook(posthook).await {
       Ok(_code) => log::debug!("Post-hook script completed ok. RT={}", _code),
       Err(_error) => {
-        log::error!("{}", _error);
-        std::process::exit(2);
+        return Err(Error::Message(format!("{}", _error)));
       }
     };
   }
+
+  Ok(())
 }
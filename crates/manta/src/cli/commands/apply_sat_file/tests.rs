@@ -259,7 +259,8 @@ fn test_render_sat_file_yaml_template_with_yaml_values_file() {
     &sat_file_content.to_string(),
     Some(&values_file_content.to_string()),
     Some(var_content),
-  );
+  )
+  .unwrap();
 }
 
 /* /// Test SAT file
@@ -17,7 +17,11 @@ pub struct SatFile {
 
 impl SatFile {
   /// Filter either images or session_templates section according to user request
-  pub fn filter(&mut self, image_only: bool, session_template_only: bool) {
+  pub fn filter(
+    &mut self,
+    image_only: bool,
+    session_template_only: bool,
+  ) -> Result<(), Error> {
     // Clean SAT template file if user only wan'ts to process the 'images' section. In this case,
     // we will remove 'session_templates' section from SAT fiel and also the entries in
     // 'configurations' section not used
@@ -32,8 +36,9 @@ impl SatFile {
           })
           .collect(),
         None => {
-          eprintln!("ERROR - 'images' section missing in SAT file");
-          std::process::exit(1);
+          return Err(Error::Message(
+            "'images' section missing in SAT file".to_string(),
+          ));
         }
       };
 
@@ -93,10 +98,9 @@ impl SatFile {
             })
             .collect(),
           None => {
-            eprintln!(
-              "ERROR - 'session_templates' section not defined in SAT file"
-            );
-            std::process::exit(1);
+            return Err(Error::Message(
+              "'session_templates' section not defined in SAT file".to_string(),
+            ));
           }
         };
 
@@ -131,6 +135,8 @@ impl SatFile {
         self.configurations = None;
       }
     }
+
+    Ok(())
   }
 }
 
@@ -483,7 +489,7 @@ pub fn render_jinja2_sat_file_yaml(
   sat_file_content: &String,
   values_file_content_opt: Option<&String>,
   value_cli_vec_opt: Option<Vec<String>>,
-) -> Value {
+) -> Result<Value, Error> {
   let mut env = minijinja::Environment::new();
   // Set/enable debug in order to force minijinja to print debug error messages which are more
   // descriptive. Eg https://github.com/mitsuhiko/minijinja/blob/main/examples/error/src/main.rs#L4-L5
@@ -543,16 +549,15 @@ pub fn render_jinja2_sat_file_yaml(
   let sat_file_rendered = match sat_file_rendered_rslt {
     Ok(sat_file_rendered) => sat_file_rendered,
     Err(err) => {
-      eprintln!("ERROR - Could not render template: {:#}", err);
+      let mut message = format!("Could not render template: {:#}", err);
       // render causes as well
       let mut err = &err as &dyn std::error::Error;
       while let Some(next_err) = err.source() {
-        eprintln!();
-        eprintln!("caused by: {:#}", next_err);
+        message.push_str(&format!("\n\ncaused by: {:#}", next_err));
         err = next_err;
       }
 
-      std::process::exit(1);
+      return Err(Error::Message(message));
     }
   };
 
@@ -561,5 +566,5 @@ pub fn render_jinja2_sat_file_yaml(
 
   let sat_file_yaml: Value = serde_yaml::from_str(&sat_file_rendered).unwrap();
 
-  sat_file_yaml
+  Ok(sat_file_yaml)
 }
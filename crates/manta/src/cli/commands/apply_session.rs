@@ -1,4 +1,4 @@
-use std::path::PathBuf;
+use std::{path::PathBuf, time::Instant};
 
 use futures::{AsyncBufReadExt, TryStreamExt};
 use manta_backend_dispatcher::{
@@ -11,14 +11,15 @@ use manta_backend_dispatcher::{
 };
 
 use crate::{
-  common::{self, audit::Audit, jwt_ops, kafka::Kafka, local_git_repo},
+  common::{self, audit::Auditor, local_git_repo},
   manta_backend_dispatcher::StaticBackendDispatcher,
 };
 use dialoguer::{theme::ColorfulTheme, Confirm};
 use substring::Substring;
 
 /// Creates a CFS session target dynamic
-/// Returns a tuple like (<cfs configuration name>, <cfs session name>)
+/// Returns a tuple like (<cfs configuration name>, <cfs session name>) or
+/// None if the user cancels the operation
 pub async fn exec(
   backend: StaticBackendDispatcher,
   site: &str,
@@ -35,32 +36,20 @@ pub async fn exec(
   ansible_verbosity: Option<String>,
   ansible_passthrough: Option<String>,
   watch_logs: bool,
-  kafka_audit_opt: Option<&Kafka>,
+  audit_opt: Option<&Auditor>,
   k8s: &K8sDetails,
-) -> Result<(String, String), Error> {
+) -> Result<Option<(String, String)>, Error> {
   let ansible_limit = if let Some(ansible_limit) = ansible_limit_opt {
     // Convert user input to xname
-    let node_metadata_available_vec = backend
-      .get_node_metadata_available(shasta_token)
-      .await
-      .unwrap_or_else(|e| {
-        eprintln!("ERROR - Could not get node metadata. Reason:\n{e}\nExit");
-        std::process::exit(1);
-      });
+    let node_metadata_available_vec =
+      backend.get_node_metadata_available(shasta_token).await?;
 
     let xname_vec = common::node_ops::from_hosts_expression_to_xname_vec(
       &ansible_limit,
       false,
       node_metadata_available_vec,
     )
-    .await
-    .unwrap_or_else(|e| {
-      eprintln!(
-        "ERROR - Could not convert user input to list of xnames. Reason:\n{}",
-        e
-      );
-      std::process::exit(1);
-    });
+    .await?;
 
     Some(xname_vec.join(","))
   } else {
@@ -68,10 +57,15 @@ pub async fn exec(
   };
 
   // Check local repos
-  let (repo_name_vec, repo_last_commit_id_vec) =
-    check_local_repos(repos_paths.clone())?;
+  let Some((repo_name_vec, repo_last_commit_id_vec)) =
+    check_local_repos(repos_paths.clone())?
+  else {
+    return Ok(None);
+  };
+
+  let start = Instant::now();
 
-  let (cfs_configuration_name, cfs_session_name) = backend
+  let apply_session_rslt = backend
     .apply_session(
       gitea_token,
       gitea_base_url,
@@ -87,7 +81,22 @@ pub async fn exec(
       ansible_verbosity,
       ansible_passthrough,
     )
-    .await?;
+    .await;
+
+  // Audit
+  if let Some(auditor) = audit_opt {
+    let audit_event = auditor
+      .new_event(shasta_token, "session.apply")
+      .with_xnames(&ansible_limit.as_deref().map(|ansible_limit| ansible_limit.split(',').collect::<Vec<_>>()).unwrap_or_default())
+      .with_groups(hsm_group_opt.as_slice())
+      .with_parameters(serde_json::json!({ "name": cfs_conf_sess_name, "playbook": playbook_yaml_file_name_opt, "repos": repos_paths }))
+      .with_result(&apply_session_rslt)
+      .with_duration(start.elapsed());
+
+    auditor.send_event(&audit_event).await;
+  }
+
+  let (cfs_configuration_name, cfs_session_name) = apply_session_rslt?;
 
   // FIXME: refactor becase this code is duplicated in command `manta apply sat-file` and also in
   // `manta logs`
@@ -104,28 +113,12 @@ pub async fn exec(
     }
   }
 
-  // Audit
-  if let Some(kafka_audit) = kafka_audit_opt {
-    let username = jwt_ops::get_name(shasta_token).unwrap();
-    let user_id = jwt_ops::get_preferred_username(shasta_token).unwrap();
-
-    let msg_json = serde_json::json!(
-        { "user": {"id": user_id, "name": username}, "host": {"hostname": ansible_limit}, "group": vec![hsm_group_opt], "message": "Apply session"});
-
-    let msg_data = serde_json::to_string(&msg_json)
-      .expect("Could not serialize audit message data");
-
-    if let Err(e) = kafka_audit.produce_message(msg_data.as_bytes()).await {
-      log::warn!("Failed producing messages: {}", e);
-    }
-  }
-
-  Ok((cfs_configuration_name, cfs_session_name))
+  Ok(Some((cfs_configuration_name, cfs_session_name)))
 }
 
 fn check_local_repos(
   repos: Vec<PathBuf>,
-) -> Result<(Vec<String>, Vec<String>), Error> {
+) -> Result<Option<(Vec<String>, Vec<String>)>, Error> {
   let mut layers_summary = vec![];
 
   for (i, repo_path) in repos.iter().enumerate() {
@@ -139,11 +132,10 @@ fn check_local_repos(
     let repo = match local_git_repo::get_repo(&repo_path.to_string_lossy()) {
       Ok(repo) => repo,
       Err(_) => {
-        eprintln!(
+        return Err(Error::Message(format!(
           "Could not find a git repo in {}",
           repos[i].to_string_lossy()
-        );
-        std::process::exit(1);
+        )));
       }
     };
 
@@ -167,7 +159,7 @@ fn check_local_repos(
         );
       } else {
         println!("Cancelled by user. Aborting.");
-        std::process::exit(0);
+        return Ok(None);
       }
     }
 
@@ -216,7 +208,7 @@ fn check_local_repos(
         println!("Continue. Creating new CFS configuration and layer(s)");
     } else {
         println!("Cancelled by user. Aborting.");
-        std::process::exit(0);
+        return Ok(None);
     }
 
   let mut repo_name_vec = Vec::new();
@@ -228,11 +220,10 @@ fn check_local_repos(
     let repo = match local_git_repo::get_repo(&repo_path.to_string_lossy()) {
       Ok(repo) => repo,
       Err(_) => {
-        eprintln!(
+        return Err(Error::Message(format!(
           "Could not find a git repo in {}",
           repo_path.to_string_lossy()
-        );
-        std::process::exit(1);
+        )));
       }
     };
 
@@ -260,5 +251,5 @@ fn check_local_repos(
     repo_name_vec.push(repo_name);
   }
 
-  Ok((repo_name_vec, repo_last_commit_id_vec))
+  Ok(Some((repo_name_vec, repo_last_commit_id_vec)))
 }
@@ -1,4 +1,5 @@
 use manta_backend_dispatcher::{
+  error::Error,
   interfaces::{
     bos::{ClusterSessionTrait, ClusterTemplateTrait},
     hsm::group::GroupTrait,
@@ -26,7 +27,7 @@ pub async fn exec(
   include_disabled: bool,
   assume_yes: bool,
   dry_run: bool,
-) {
+) -> Result<(), Error> {
   //***********************************************************
   // GET DATA
   //
@@ -44,23 +45,13 @@ pub async fn exec(
     )
     .await;
 
-  let bos_sessiontemplate_vec = match bos_sessiontemplate_vec_rslt {
-    Ok(bos_sessiontemplate_vec) => bos_sessiontemplate_vec,
-    Err(e) => {
-      eprintln!(
-                "ERROR - Could not fetch BOS sessiontemplate list. Reason:\n{:#?}\nExit",
-                e
-            );
-      std::process::exit(1);
-    }
-  };
+  let bos_sessiontemplate_vec = bos_sessiontemplate_vec_rslt?;
 
   let bos_sessiontemplate = if bos_sessiontemplate_vec.is_empty() {
-    eprintln!(
-      "ERROR - No BOS sessiontemplate '{}' found\nExit",
+    return Err(Error::Message(format!(
+      "No BOS sessiontemplate '{}' found",
       bos_sessiontemplate_name
-    );
-    std::process::exit(1);
+    )));
   } else {
     bos_sessiontemplate_vec.first().unwrap()
   };
@@ -170,7 +161,7 @@ pub async fn exec(
       log::info!("Continue",);
     } else {
       println!("Cancelled by user. Aborting.");
-      std::process::exit(0);
+      return Ok(());
     }
   }
 
@@ -215,4 +206,6 @@ pub async fn exec(
   }
   // END CREATE BOS SESSION
   //***********************************************************
+
+  Ok(())
 }
@@ -1,7 +1,9 @@
 use std::{fs, io::Write, path::PathBuf};
 
 use directories::ProjectDirs;
-use manta_backend_dispatcher::interfaces::hsm::group::GroupTrait;
+use manta_backend_dispatcher::{
+  error::Error, interfaces::hsm::group::GroupTrait,
+};
 use toml_edit::{value, DocumentMut};
 
 use crate::manta_backend_dispatcher::StaticBackendDispatcher;
@@ -11,7 +13,7 @@ pub async fn exec(
   shasta_token: &str,
   new_hsm_opt: Option<&String>,
   // all_hsm_available_vec: &[String],
-) {
+) -> Result<(), Error> {
   // Read configuration file
 
   // XDG Base Directory Specification
@@ -71,7 +73,7 @@ pub async fn exec(
   validate_hsm_group_and_hsm_available_config_params(
     new_hsm_opt.unwrap(),
     &hsm_available_vec,
-  );
+  )?;
 
   // All goot, we are safe to update 'hsm_group' config param
   log::info!(
@@ -102,7 +104,7 @@ pub async fn exec(
     validate_hsm_group_and_hsm_available_config_params(
       new_hsm_opt.unwrap(),
       &all_hsm_available_vec,
-    );
+    )?;
 
     // All goot, we are safe to update 'hsm_group' config param
     log::info!(
@@ -138,19 +140,22 @@ pub async fn exec(
     Some(hsm_value) => println!("Target HSM group set to {hsm_value}"),
     None => println!("Target HSM group unset"),
   }
+
+  Ok(())
 }
 
 pub fn validate_hsm_group_and_hsm_available_config_params(
   hsm_group: &String,
   hsm_available_vec: &[String],
-) {
+) -> Result<(), Error> {
   if !hsm_available_vec.contains(hsm_group) {
-    eprintln!(
-            "HSM group provided ({}) not valid, please choose one of the following options: {:?}",
-            hsm_group, hsm_available_vec
-        );
-    std::process::exit(1);
+    return Err(Error::Message(format!(
+      "HSM group provided ({}) not valid, please choose one of the following options: {:?}",
+      hsm_group, hsm_available_vec
+    )));
   }
+
+  Ok(())
 }
 
 /* pub fn unset_hsm(new_hsm_opt: Option<&String>, hsm_available: Option<&toml_edit::Item>) {
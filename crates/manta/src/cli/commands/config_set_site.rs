@@ -1,9 +1,10 @@
 use std::{fs, io::Write, path::PathBuf};
 
 use directories::ProjectDirs;
+use manta_backend_dispatcher::error::Error;
 use toml_edit::{value, DocumentMut, Table};
 
-pub async fn exec(new_site_opt: Option<&String>) {
+pub async fn exec(new_site_opt: Option<&String>) -> Result<(), Error> {
   // XDG Base Directory Specification
   let project_dirs = ProjectDirs::from(
     "local", /*qualifier*/
@@ -48,14 +49,13 @@ pub async fn exec(new_site_opt: Option<&String>) {
 
   // VALIDATION
   if site_available_table.is_empty() {
-    eprintln!("No 'sites' in config file");
-    std::process::exit(1);
+    return Err(Error::Message("No 'sites' in config file".to_string()));
   }
 
   validate_site_and_site_available_config_params(
     new_site_opt.unwrap(),
     site_available_table,
-  );
+  )?;
 
   // All goot, we are safe to update 'site' config param
   log::info!(
@@ -84,14 +84,20 @@ pub async fn exec(new_site_opt: Option<&String>) {
     Some(hsm_value) => println!("site set to {hsm_value}"),
     None => eprintln!("ERROR: this should not happen"),
   }
+
+  Ok(())
 }
 
 pub fn validate_site_and_site_available_config_params(
   site: &String,
   site_available_table: &Table,
-) {
+) -> Result<(), Error> {
   if !site_available_table.contains_key(site) {
-    eprintln!("Site provided ({}) not valid.", site);
-    std::process::exit(1);
+    return Err(Error::Message(format!(
+      "Site provided ({}) not valid.",
+      site
+    )));
   }
+
+  Ok(())
 }
@@ -1,9 +1,17 @@
 use std::collections::HashMap;
 
+use comfy_table::{Cell, Table};
 use config::{Config, Value};
-use manta_backend_dispatcher::interfaces::hsm::group::GroupTrait;
+use manta_backend_dispatcher::{
+  contracts::BackendTrait, interfaces::hsm::group::GroupTrait,
+  types::capability::Capability,
+};
+use strum::IntoEnumIterator;
 
-use crate::manta_backend_dispatcher::StaticBackendDispatcher;
+use crate::{
+  common::config::types::MantaConfiguration,
+  manta_backend_dispatcher::StaticBackendDispatcher,
+};
 
 /// Prints Manta's configuration on screen
 pub async fn exec(
@@ -12,6 +20,8 @@ pub async fn exec(
   /* _shasta_base_url: &str,
   _shasta_root_cert: &[u8], */
   settings: &Config,
+  configuration: &MantaConfiguration,
+  show_capabilities: bool,
 ) {
   // Read configuration file
   let log_level = settings.get_string("log").unwrap_or("error".to_string());
@@ -48,4 +58,50 @@ pub async fn exec(
   );
   println!("Current HSM: {}", settings_hsm_group);
   println!("Parent HSM: {}", settings_parent_hsm_group);
+
+  if show_capabilities {
+    print_capability_matrix(configuration);
+  }
+}
+
+/// Prints a table with the operations supported by the backend of each site
+fn print_capability_matrix(configuration: &MantaConfiguration) {
+  let mut site_name_vec: Vec<&String> = configuration.sites.keys().collect();
+  site_name_vec.sort();
+
+  // Capabilities do not depend on the site being reachable, so there is no need
+  // to load the site root certificate here
+  let site_capability_vec: Vec<Vec<Capability>> = site_name_vec
+    .iter()
+    .map(|site_name| {
+      let site = &configuration.sites[*site_name];
+      StaticBackendDispatcher::new(&site.backend, &site.shasta_base_url, &[])
+        .get_capabilities()
+    })
+    .collect();
+
+  let mut table = Table::new();
+
+  let mut header = vec![Cell::new("Capability")];
+  header.extend(site_name_vec.iter().map(|site_name| {
+    Cell::new(format!(
+      "{} ({})",
+      site_name, configuration.sites[*site_name].backend
+    ))
+  }));
+  table.set_header(header);
+
+  for capability in Capability::iter() {
+    let mut row = vec![Cell::new(capability.to_string())];
+    row.extend(site_capability_vec.iter().map(|capability_vec| {
+      if capability_vec.contains(&capability) {
+        Cell::new("✅").set_alignment(comfy_table::CellAlignment::Center)
+      } else {
+        Cell::new("❌").set_alignment(comfy_table::CellAlignment::Center)
+      }
+    }));
+    table.add_row(row);
+  }
+
+  println!("Capabilities:\n{table}");
 }
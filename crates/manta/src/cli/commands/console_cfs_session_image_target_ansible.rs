@@ -1,18 +1,14 @@
 use futures::StreamExt;
 use manta_backend_dispatcher::{
-  interfaces::cfs::CfsTrait,
-  types::{K8sAuth, K8sDetails},
+  error::Error, interfaces::cfs::CfsTrait, types::K8sDetails,
 };
 
-use csm_rs::node::console;
+use csm_rs::{common::kubernetes, node::console};
 use termion::color;
 use tokio::{io::AsyncWriteExt, select};
 
 use crate::{
-  common::{
-    terminal_ops, vault::http_client::fetch_shasta_k8s_secrets_from_vault,
-  },
-  manta_backend_dispatcher::StaticBackendDispatcher,
+  common::terminal_ops, manta_backend_dispatcher::StaticBackendDispatcher,
 };
 
 pub async fn exec(
@@ -22,10 +18,9 @@ pub async fn exec(
   shasta_token: &str,
   shasta_base_url: &str,
   shasta_root_cert: &[u8],
-  k8s_api_url: &str,
   cfs_session_name: &str,
   k8s: &K8sDetails,
-) {
+) -> Result<(), Error> {
   let cfs_session_vec = backend
     .get_and_filter_sessions(
       shasta_token,
@@ -40,14 +35,9 @@ pub async fn exec(
       None,
       None,
     )
-    .await
-    .unwrap_or_else(|e| {
-      log::error!("Failed to get CFS sessions. Reason:\n{e}");
-      std::process::exit(1);
-    });
+    .await?;
   if cfs_session_vec.is_empty() {
-    eprintln!("No CFS session found. Exit",);
-    std::process::exit(1);
+    return Err(Error::Message("No CFS session found".to_string()));
   }
   let cfs_session_details = cfs_session_vec.first().unwrap();
   if cfs_session_details
@@ -59,11 +49,10 @@ pub async fn exec(
     .unwrap()
     .ne("image")
   {
-    eprintln!(
-      "CFS session found {} is type dynamic. Exit",
+    return Err(Error::Message(format!(
+      "CFS session found {} is type dynamic",
       cfs_session_details.name.as_ref().unwrap()
-    );
-    std::process::exit(1);
+    )));
   }
   if cfs_session_details
     .status
@@ -75,11 +64,10 @@ pub async fn exec(
     .status
     .ne(&Some("running".to_string()))
   {
-    eprintln!(
-      "CFS session found {} state is not 'running'. Exit",
+    return Err(Error::Message(format!(
+      "CFS session found {} state is not 'running'",
       cfs_session_details.name.as_ref().unwrap()
-    );
-    std::process::exit(1);
+    )));
   }
   if !cfs_session_details
     .target
@@ -91,12 +79,11 @@ pub async fn exec(
     .iter()
     .any(|group| hsm_group_name_vec.contains(&group.name.to_string()))
   {
-    eprintln!(
+    return Err(Error::Message(format!(
       "CFS session found {} is not related to any availble HSM groups {:?}",
       cfs_session_details.name.as_ref().unwrap(),
       hsm_group_name_vec
-    );
-    std::process::exit(1);
+    )));
   }
 
   connect_to_console(
@@ -106,11 +93,12 @@ pub async fn exec(
     /* vault_base_url,
     vault_secret_path,
     vault_role_id, */
-    k8s_api_url,
     k8s,
   )
   .await
   .unwrap();
+
+  Ok(())
 }
 
 pub async fn connect_to_console(
@@ -120,37 +108,16 @@ pub async fn connect_to_console(
   /* vault_base_url: &str,
   vault_secret_path: &str,
   vault_role_id: &str, */
-  k8s_api_url: &str,
   k8s: &K8sDetails,
 ) -> Result<(), anyhow::Error> {
   log::info!("CFS session name: {}", cfs_session_name);
 
-  let shasta_k8s_secrets = match &k8s.authentication {
-    K8sAuth::Native {
-      certificate_authority_data,
-      client_certificate_data,
-      client_key_data,
-    } => {
-      serde_json::json!({ "certificate-authority-data": certificate_authority_data, "client-certificate-data": client_certificate_data, "client-key-data": client_key_data })
-    }
-    K8sAuth::Vault {
-      base_url,
-      // secret_path: _secret_path,
-    } => {
-      fetch_shasta_k8s_secrets_from_vault(&base_url, site_name, shasta_token)
-        .await
-        .unwrap()
-    }
-  };
+  let client = kubernetes::get_k8s_client(shasta_token, site_name, k8s).await?;
 
   let mut attached =
     console::get_container_attachment_to_cfs_session_image_target(
+      client,
       cfs_session_name,
-      /* vault_base_url,
-      vault_secret_path,
-      vault_role_id, */
-      k8s_api_url,
-      shasta_k8s_secrets,
     )
     .await?;
 
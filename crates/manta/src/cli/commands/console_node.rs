@@ -1,5 +1,8 @@
+use std::path::Path;
+
 use futures::StreamExt;
 use manta_backend_dispatcher::{
+  error::Error,
   interfaces::{console::ConsoleTrait, hsm::component::ComponentTrait},
   types::K8sDetails,
 };
@@ -7,7 +10,7 @@ use manta_backend_dispatcher::{
 use tokio::{io::AsyncWriteExt, select};
 
 use crate::{
-  common::{self},
+  common::{self, asciicast::AsciicastRecorder},
   manta_backend_dispatcher::StaticBackendDispatcher,
 };
 
@@ -16,34 +19,23 @@ pub async fn exec(
   site_name: &str,
   shasta_token: &str,
   xname: &str,
-  k8s: &K8sDetails,
-) {
+  k8s_opt: Option<&K8sDetails>,
+  record_path_opt: Option<&String>,
+) -> Result<(), Error> {
   // Convert user input to xname
-  let node_metadata_available_vec = backend
-    .get_node_metadata_available(shasta_token)
-    .await
-    .unwrap_or_else(|e| {
-      eprintln!("ERROR - Could not get node metadata. Reason:\n{e}\nExit");
-      std::process::exit(1);
-    });
+  let node_metadata_available_vec =
+    backend.get_node_metadata_available(shasta_token).await?;
 
   let xname_vec = common::node_ops::from_hosts_expression_to_xname_vec(
     xname,
     false,
     node_metadata_available_vec,
   )
-  .await
-  .unwrap_or_else(|e| {
-    eprintln!(
-      "ERROR - Could not convert user input to list of xnames. Reason:\n{}",
-      e
-    );
-    std::process::exit(1);
-  });
+  .await?;
 
   if xname_vec.len() != 1 {
     eprintln!("ERROR - The node to operate is not valid. Nothing to do. Exit");
-    std::process::exit(0);
+    return Ok(());
   }
 
   let xname = xname_vec.first().unwrap();
@@ -55,38 +47,66 @@ pub async fn exec(
     // included.iter().next().unwrap(),
     &xname.to_string(),
     // k8s_api_url,
-    k8s,
+    k8s_opt,
+    record_path_opt,
   )
   .await;
 
   match console_rslt {
-    Ok(_) => {
+    Ok(recorded) => {
       crossterm::terminal::disable_raw_mode().unwrap();
       log::info!("Console closed");
+
+      if let Some(record_path) = record_path_opt {
+        if recorded {
+          println!("Console recorded in '{}'", record_path);
+        } else {
+          eprintln!(
+            "WARNING - Recording stopped because of an error, '{}' is incomplete",
+            record_path
+          );
+        }
+      }
     }
     Err(error) => {
       crossterm::terminal::disable_raw_mode().unwrap();
       log::error!("{:?}", error);
     }
   }
+
+  Ok(())
 }
 
+/// Returns whether the console output was recorded until the console closed
 pub async fn connect_to_console(
   backend: &StaticBackendDispatcher,
   shasta_token: &str,
   site_name: &str,
   xname: &String,
   // k8s_api_url: &str,
-  k8s: &K8sDetails,
-) -> Result<(), anyhow::Error> {
+  k8s_opt: Option<&K8sDetails>,
+  record_path_opt: Option<&String>,
+) -> Result<bool, anyhow::Error> {
   log::info!("xname: {}", xname);
 
   let (width, height) = crossterm::terminal::size()?;
 
   let (a_input, a_output) = backend
-    .attach_to_console(shasta_token, site_name, xname, width, height, &k8s)
+    .attach_to_console(shasta_token, site_name, xname, width, height, k8s_opt)
     .await?;
 
+  // Only the console output is recorded, input may contain passwords
+  let mut recorder_opt = record_path_opt
+    .map(|record_path| {
+      AsciicastRecorder::new(
+        Path::new(record_path),
+        width,
+        height,
+        &format!("{} ({})", xname, site_name),
+      )
+    })
+    .transpose()?;
+
   let mut stdin = tokio_util::io::ReaderStream::new(tokio::io::stdin());
   let mut stdout = tokio::io::stdout();
 
@@ -120,6 +140,14 @@ pub async fn connect_to_console(
                 Some(Ok(message)) => {
                     stdout.write_all(&message).await?;
                     stdout.flush().await?;
+
+                    if let Some(recorder) = recorder_opt.as_mut() {
+                        if let Err(e) = recorder.record_output(&message) {
+                            // Keep the console open, stop recording
+                            log::error!("Could not record console output, recording stopped. Reason:\n{:?}", e);
+                            recorder_opt = None;
+                        }
+                    }
                 },
                 Some(Err(message)) => {
                    crossterm::terminal::disable_raw_mode()?;
@@ -148,5 +176,5 @@ pub async fn connect_to_console(
 
   crossterm::terminal::disable_raw_mode()?;
 
-  Ok(())
+  Ok(recorder_opt.is_some())
 }
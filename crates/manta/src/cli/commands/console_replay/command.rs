@@ -0,0 +1,69 @@
+use std::path::Path;
+
+use anyhow::{Context, Error};
+use tokio::io::AsyncWriteExt;
+
+use crate::common::asciicast::{self, AsciicastEvent};
+
+use super::utils;
+
+/// Plays a console recorded with 'manta console node --record' in the
+/// terminal
+pub async fn exec(
+  path: &str,
+  speed: f64,
+  idle_time_limit_opt: Option<f64>,
+) -> Result<(), Error> {
+  utils::validate_replay_settings(speed, idle_time_limit_opt)?;
+
+  let (header, event_vec) = asciicast::read(Path::new(path))?;
+
+  log::info!(
+    "Replaying '{}' recorded in a {}x{} terminal",
+    header.title.as_deref().unwrap_or(path),
+    header.width,
+    header.height
+  );
+
+  if let Ok((width, height)) = crossterm::terminal::size() {
+    if width < header.width || height < header.height {
+      eprintln!(
+        "WARNING - Terminal size is {}x{} and the recording is {}x{}, the output may not look as recorded",
+        width, height, header.width, header.height
+      );
+    }
+  }
+
+  // Idle time limit in the command line takes precedence over the one in the
+  // recording
+  let idle_time_limit_opt = idle_time_limit_opt.or(header.idle_time_limit);
+
+  utils::validate_replay_settings(speed, idle_time_limit_opt)
+    .with_context(|| format!("Invalid recording '{}'", path))?;
+
+  let mut stdout = tokio::io::stdout();
+  let mut previous_time = 0.0;
+
+  for AsciicastEvent(time, kind, data) in event_vec {
+    // Only output is played, input is already echoed by the console and the
+    // terminal can't be resized
+    if kind != "o" {
+      continue;
+    }
+
+    tokio::time::sleep(utils::get_delay(
+      previous_time,
+      time,
+      speed,
+      idle_time_limit_opt,
+    )?)
+    .await;
+
+    stdout.write_all(data.as_bytes()).await?;
+    stdout.flush().await?;
+
+    previous_time = time;
+  }
+
+  Ok(())
+}
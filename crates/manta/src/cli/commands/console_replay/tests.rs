@@ -0,0 +1,74 @@
+use std::time::Duration;
+
+use crate::{
+  cli::commands::console_replay::utils::{get_delay, validate_replay_settings},
+  common::asciicast::{self, decode_utf8, AsciicastEvent},
+};
+
+#[test]
+fn test_get_delay_applies_speed_and_idle_time_limit() {
+  assert_eq!(
+    get_delay(1.0, 3.0, 1.0, None).unwrap(),
+    Duration::from_secs(2)
+  );
+  assert_eq!(
+    get_delay(1.0, 3.0, 4.0, None).unwrap(),
+    Duration::from_millis(500)
+  );
+  assert_eq!(
+    get_delay(1.0, 61.0, 2.0, Some(2.0)).unwrap(),
+    Duration::from_secs(1)
+  );
+  assert_eq!(get_delay(3.0, 1.0, 1.0, None).unwrap(), Duration::ZERO);
+}
+
+#[test]
+fn test_get_delay_rejects_invalid_speed_and_idle_time_limit() {
+  assert!(get_delay(1.0, 3.0, f64::NAN, None).is_err());
+  assert!(get_delay(1.0, 3.0, f64::INFINITY, None).is_err());
+  assert!(get_delay(1.0, 3.0, 0.0, None).is_err());
+  assert!(get_delay(1.0, 3.0, 1.0, Some(-1.0)).is_err());
+  assert!(get_delay(1.0, 3.0, 1.0, Some(f64::NAN)).is_err());
+  assert!(validate_replay_settings(2.0, Some(0.5)).is_ok());
+}
+
+#[test]
+fn test_decode_utf8_keeps_split_characters_pending() {
+  // 'é' is 0xC3 0xA9
+  let mut pending = Vec::new();
+
+  assert_eq!(decode_utf8(&mut pending, b"caf\xC3"), "caf");
+  assert_eq!(pending, vec![0xC3]);
+
+  assert_eq!(decode_utf8(&mut pending, b"\xA9 ok"), "é ok");
+  assert!(pending.is_empty());
+
+  assert_eq!(decode_utf8(&mut pending, b"a\xFFb"), "a\u{FFFD}b");
+  assert!(pending.is_empty());
+}
+
+#[test]
+fn test_parse_asciicast() {
+  let recording = r#"{"version": 2, "width": 80, "height": 24, "title": "x1003c1s7b0n0"}
+[0.5, "o", "login: "]
+
+[1.25, "i", "root\r"]
+"#;
+
+  let (header, event_vec) = asciicast::parse(recording.as_bytes()).unwrap();
+
+  assert_eq!(header.width, 80);
+  assert_eq!(header.title.as_deref(), Some("x1003c1s7b0n0"));
+  assert_eq!(
+    event_vec,
+    vec![
+      AsciicastEvent(0.5, "o".to_string(), "login: ".to_string()),
+      AsciicastEvent(1.25, "i".to_string(), "root\r".to_string()),
+    ]
+  );
+
+  assert!(asciicast::parse(
+    r#"{"version": 1, "width": 80, "height": 24}"#.as_bytes()
+  )
+  .is_err());
+}
@@ -0,0 +1,51 @@
+use std::time::Duration;
+
+use anyhow::{bail, Context, Error};
+
+/// Speed and idle time limit must be finite numbers greater than 0
+pub fn validate_replay_settings(
+  speed: f64,
+  idle_time_limit_opt: Option<f64>,
+) -> Result<(), Error> {
+  if !speed.is_finite() || speed <= 0.0 {
+    bail!("Speed must be a number greater than 0, got '{}'", speed);
+  }
+
+  if let Some(idle_time_limit) = idle_time_limit_opt {
+    if !idle_time_limit.is_finite() || idle_time_limit <= 0.0 {
+      bail!(
+        "Idle time limit must be a number greater than 0, got '{}'",
+        idle_time_limit
+      );
+    }
+  }
+
+  Ok(())
+}
+
+/// Time to wait before playing an event recorded at 'time' seconds when the
+/// previous one was recorded at 'previous_time'. Pauses longer than the idle
+/// time limit are shortened to it before applying the speed
+pub fn get_delay(
+  previous_time: f64,
+  time: f64,
+  speed: f64,
+  idle_time_limit_opt: Option<f64>,
+) -> Result<Duration, Error> {
+  validate_replay_settings(speed, idle_time_limit_opt)?;
+
+  let mut delay = (time - previous_time).max(0.0);
+
+  if let Some(idle_time_limit) = idle_time_limit_opt {
+    delay = delay.min(idle_time_limit);
+  }
+
+  Duration::try_from_secs_f64(delay / speed).with_context(|| {
+    format!(
+      "Could not wait {} seconds between events recorded at {} and {}",
+      delay / speed,
+      previous_time,
+      time
+    )
+  })
+}
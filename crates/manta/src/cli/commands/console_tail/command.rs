@@ -0,0 +1,232 @@
+use std::{io::IsTerminal, sync::Arc};
+
+use anyhow::{bail, Context, Error};
+use manta_backend_dispatcher::{
+  interfaces::{console::ConsoleTrait, hsm::component::ComponentTrait},
+  types::K8sDetails,
+};
+use regex::Regex;
+use tokio::{io::AsyncReadExt, sync::Semaphore};
+
+use crate::{
+  common::node_ops, manta_backend_dispatcher::StaticBackendDispatcher,
+};
+
+use super::utils::{self, ConsoleEvent, LineBuffer};
+
+/// Consoles attached at the same time while connecting
+const ATTACH_CONCURRENCY: usize = 10;
+
+/// Console of a node being tailed
+struct NodeConsole {
+  xname: String,
+  prefix: String,
+  line_buffer: LineBuffer,
+  /// Printed a line matching the exit regex
+  matched: bool,
+  /// Console closed or could not be read, it won't print anything else
+  finished: bool,
+}
+
+/// Prints the console output of many nodes at once. Consoles are attached
+/// read-only so nothing typed reaches the nodes
+pub async fn exec(
+  backend: &StaticBackendDispatcher,
+  site_name: &str,
+  shasta_token: &str,
+  hosts_expression: &str,
+  k8s_opt: Option<&K8sDetails>,
+  highlight_opt: Option<&String>,
+  exit_on_opt: Option<&String>,
+) -> Result<(), Error> {
+  let highlight_regex_opt = highlight_opt
+    .map(|highlight| Regex::new(highlight))
+    .transpose()
+    .context("Invalid highlight regex")?;
+  let exit_on_regex_opt = exit_on_opt
+    .map(|exit_on| Regex::new(exit_on))
+    .transpose()
+    .context("Invalid exit-on regex")?;
+
+  // Convert user input to xname
+  let node_metadata_available_vec = backend
+    .get_node_metadata_available(shasta_token)
+    .await
+    .context("Could not get node metadata")?;
+
+  let xname_vec = node_ops::from_hosts_expression_to_xname_vec(
+    hosts_expression,
+    false,
+    node_metadata_available_vec,
+  )
+  .await
+  .context("Could not convert user input to list of xnames")?;
+
+  if xname_vec.is_empty() {
+    bail!("No nodes found for '{}'", hosts_expression);
+  }
+
+  // Escape sequences are only printed to terminals
+  let colored = std::io::stdout().is_terminal();
+  let highlight_regex_opt = highlight_regex_opt.filter(|_| colored);
+
+  let width = xname_vec.iter().map(String::len).max().unwrap_or_default();
+
+  let mut node_console_vec: Vec<NodeConsole> = xname_vec
+    .iter()
+    .enumerate()
+    .map(|(index, xname)| NodeConsole {
+      xname: xname.clone(),
+      prefix: utils::get_prefix(xname, index, width, colored),
+      line_buffer: LineBuffer::default(),
+      matched: false,
+      finished: false,
+    })
+    .collect();
+
+  eprintln!(
+    "Tailing the consoles of {} nodes. Press Ctrl-C to exit",
+    xname_vec.len()
+  );
+
+  let (tx, mut rx) = tokio::sync::mpsc::channel::<(usize, ConsoleEvent)>(1024);
+
+  let mut tasks = tokio::task::JoinSet::new();
+
+  let sem = Arc::new(Semaphore::new(ATTACH_CONCURRENCY));
+
+  for (index, xname) in xname_vec.into_iter().enumerate() {
+    let backend_cp = backend.clone();
+    let shasta_token_string = shasta_token.to_string();
+    let site_name_string = site_name.to_string();
+    let k8s_cp_opt = k8s_opt.cloned();
+    let sem_cp = Arc::clone(&sem);
+    let tx = tx.clone();
+
+    tasks.spawn(async move {
+      let permit = sem_cp.acquire_owned().await;
+
+      let console_output_rslt = backend_cp
+        .attach_to_console_read_only(
+          &shasta_token_string,
+          &site_name_string,
+          &xname,
+          k8s_cp_opt.as_ref(),
+        )
+        .await;
+
+      drop(permit);
+
+      let mut console_output = match console_output_rslt {
+        Ok(console_output) => console_output,
+        Err(e) => {
+          let _ = tx.send((index, ConsoleEvent::Failed(e.to_string()))).await;
+          return;
+        }
+      };
+
+      let mut buffer = vec![0u8; 4096];
+
+      loop {
+        let event = match console_output.read(&mut buffer).await {
+          Ok(0) => ConsoleEvent::Closed,
+          Ok(len) => ConsoleEvent::Output(buffer[..len].to_vec()),
+          Err(e) => ConsoleEvent::Failed(e.to_string()),
+        };
+
+        let is_output = matches!(event, ConsoleEvent::Output(_));
+
+        // Command finished, no one is reading
+        if tx.send((index, event)).await.is_err() || !is_output {
+          return;
+        }
+      }
+    });
+  }
+
+  // The channel closes once every console task finished
+  drop(tx);
+
+  while let Some((index, event)) = rx.recv().await {
+    let node_console = &mut node_console_vec[index];
+
+    match event {
+      ConsoleEvent::Output(data) => {
+        for line in node_console.line_buffer.push(&data) {
+          print_line(node_console, &line, highlight_regex_opt.as_ref());
+          node_console.matched |= exit_on_regex_opt
+            .as_ref()
+            .is_some_and(|exit_on_regex| exit_on_regex.is_match(&line));
+        }
+
+        // Prompts like 'login: ' are not followed by a new line
+        if let Some(exit_on_regex) = &exit_on_regex_opt {
+          if exit_on_regex.is_match(&node_console.line_buffer.pending()) {
+            let line = node_console.line_buffer.take_pending().unwrap();
+            print_line(node_console, &line, highlight_regex_opt.as_ref());
+            node_console.matched = true;
+          }
+        }
+      }
+      ConsoleEvent::Closed => {
+        if let Some(line) = node_console.line_buffer.take_pending() {
+          print_line(node_console, &line, highlight_regex_opt.as_ref());
+        }
+        eprintln!("{}console closed", node_console.prefix);
+        node_console.finished = true;
+      }
+      ConsoleEvent::Failed(e) => {
+        eprintln!(
+          "{}could not read console. Reason: {}",
+          node_console.prefix, e
+        );
+        node_console.finished = true;
+      }
+    }
+
+    // Consoles closed or failed won't print a matching line, stop waiting
+    // for them
+    if exit_on_regex_opt.is_some()
+      && node_console_vec
+        .iter()
+        .all(|node_console| node_console.matched || node_console.finished)
+    {
+      break;
+    }
+  }
+
+  if exit_on_regex_opt.is_some() {
+    let xname_not_matched_vec: Vec<&str> = node_console_vec
+      .iter()
+      .filter(|node_console| !node_console.matched)
+      .map(|node_console| node_console.xname.as_str())
+      .collect();
+
+    if !xname_not_matched_vec.is_empty() {
+      bail!(
+        "Consoles closed or failed before printing a line matching '{}': {}",
+        exit_on_opt.unwrap(),
+        xname_not_matched_vec.join(", ")
+      );
+    }
+
+    eprintln!(
+      "All nodes printed a line matching '{}'",
+      exit_on_opt.unwrap()
+    );
+  }
+
+  Ok(())
+}
+
+fn print_line(
+  node_console: &NodeConsole,
+  line: &str,
+  highlight_regex_opt: Option<&Regex>,
+) {
+  println!(
+    "{}{}",
+    node_console.prefix,
+    utils::highlight(line, highlight_regex_opt)
+  );
+}
@@ -0,0 +1,45 @@
+use regex::Regex;
+
+use crate::cli::commands::console_tail::utils::{
+  get_prefix, highlight, LineBuffer,
+};
+
+#[test]
+fn test_line_buffer_keeps_partial_lines_pending() {
+  let mut line_buffer = LineBuffer::default();
+
+  assert!(line_buffer.push(b"Booting Linux").is_empty());
+  assert_eq!(
+    line_buffer.push(b" 5.14\r\nStarting udev\r\nnid001313 login: "),
+    vec!["Booting Linux 5.14", "Starting udev"]
+  );
+  assert_eq!(line_buffer.pending(), "nid001313 login: ");
+
+  assert_eq!(
+    line_buffer.take_pending(),
+    Some("nid001313 login: ".to_string())
+  );
+  assert_eq!(line_buffer.take_pending(), None);
+}
+
+#[test]
+fn test_get_prefix_aligns_node_names() {
+  assert_eq!(
+    get_prefix("x1003c1s7b0n0", 0, 14, false),
+    "x1003c1s7b0n0  | "
+  );
+}
+
+#[test]
+fn test_highlight() {
+  let highlight_regex = Regex::new("(?i)error").unwrap();
+
+  assert_eq!(
+    highlight("PCIe Error on slot 3", Some(&highlight_regex)),
+    "PCIe \u{1b}[7mError\u{1b}[27m on slot 3"
+  );
+  assert_eq!(
+    highlight("PCIe Error on slot 3", None),
+    "PCIe Error on slot 3"
+  );
+}
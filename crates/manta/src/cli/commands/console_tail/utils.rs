@@ -0,0 +1,97 @@
+use regex::Regex;
+use termion::{color, style};
+
+/// Colors the node prefixes cycle through
+const PREFIX_COLOR_VEC: [u8; 12] = [2, 3, 4, 5, 6, 1, 10, 11, 12, 13, 14, 9];
+
+/// What a node console task reports to the command
+pub enum ConsoleEvent {
+  Output(Vec<u8>),
+  Closed,
+  Failed(String),
+}
+
+/// Splits the output of a console in lines. Text after the last new line is
+/// kept pending until the rest of the line arrives
+#[derive(Default)]
+pub struct LineBuffer {
+  pending: Vec<u8>,
+}
+
+impl LineBuffer {
+  /// Complete lines in the output read so far, without line endings
+  pub fn push(&mut self, data: &[u8]) -> Vec<String> {
+    self.pending.extend_from_slice(data);
+
+    let Some(last_new_line) =
+      self.pending.iter().rposition(|byte| *byte == b'\n')
+    else {
+      return Vec::new();
+    };
+
+    let line_vec = self.pending[..last_new_line]
+      .split(|byte| *byte == b'\n')
+      .map(to_line)
+      .collect();
+
+    self.pending.drain(..=last_new_line);
+
+    line_vec
+  }
+
+  /// Text after the last new line eg a 'login: ' prompt
+  pub fn pending(&self) -> String {
+    to_line(&self.pending)
+  }
+
+  pub fn take_pending(&mut self) -> Option<String> {
+    if self.pending.is_empty() {
+      return None;
+    }
+
+    let line = self.pending();
+    self.pending.clear();
+
+    Some(line)
+  }
+}
+
+fn to_line(data: &[u8]) -> String {
+  String::from_utf8_lossy(data)
+    .trim_end_matches('\r')
+    .to_string()
+}
+
+/// Node name padded to the longest node name so the output of all nodes is
+/// aligned, each node has a different color
+pub fn get_prefix(
+  xname: &str,
+  index: usize,
+  width: usize,
+  colored: bool,
+) -> String {
+  if colored {
+    format!(
+      "{}{:<width$}{} | ",
+      color::Fg(color::AnsiValue(
+        PREFIX_COLOR_VEC[index % PREFIX_COLOR_VEC.len()]
+      )),
+      xname,
+      color::Fg(color::Reset),
+    )
+  } else {
+    format!("{:<width$} | ", xname)
+  }
+}
+
+/// Shows the parts of the line matching the regex in inverted colors
+pub fn highlight(line: &str, highlight_regex_opt: Option<&Regex>) -> String {
+  match highlight_regex_opt {
+    Some(highlight_regex) => highlight_regex
+      .replace_all(line, |captures: &regex::Captures| {
+        format!("{}{}{}", style::Invert, &captures[0], style::NoInvert)
+      })
+      .to_string(),
+    None => line.to_string(),
+  }
+}
@@ -1,7 +1,16 @@
-use crate::manta_backend_dispatcher::StaticBackendDispatcher;
+use crate::{
+  common::journal::{Journal, JournalObject},
+  manta_backend_dispatcher::StaticBackendDispatcher,
+};
 use chrono::NaiveDateTime;
+use comfy_table::Table;
+use dialoguer::{theme::ColorfulTheme, Confirm};
 use manta_backend_dispatcher::{
-  error::Error, interfaces::commands::CommandsTrait,
+  error::Error,
+  interfaces::{
+    bos::ClusterTemplateTrait, cfs::CfsTrait, commands::CommandsTrait,
+  },
+  types::cfs::cfs_configuration_derivatives::CfsConfigurationDerivatives,
 };
 
 pub async fn exec(
@@ -15,18 +24,156 @@ pub async fn exec(
   since_opt: Option<NaiveDateTime>,
   until_opt: Option<NaiveDateTime>,
   assume_yes: bool,
+  journal: &Journal,
 ) -> Result<(), Error> {
-  backend
-    .i_delete_data_related_to_cfs_configuration(
-      &shasta_token,
+  let cfs_configuration_derivatives = backend
+    .get_data_related_to_cfs_configuration(
+      shasta_token,
       shasta_base_url,
       shasta_root_cert,
-      target_hsm_group_vec,
+      target_hsm_group_vec.clone(),
       configuration_name_opt,
       configuration_name_pattern,
       since_opt,
       until_opt,
-      assume_yes,
+    )
+    .await?;
+
+  if cfs_configuration_derivatives.is_empty() {
+    println!("Nothing to delete");
+    return Ok(());
+  }
+
+  print_summary(&cfs_configuration_derivatives);
+
+  if !assume_yes {
+    if Confirm::with_theme(&ColorfulTheme::default())
+      .with_prompt("Please revew the data above and confirm to delete:")
+      .interact()
+      .unwrap()
+    {
+      println!("Continue");
+    } else {
+      println!("Cancelled by user. Aborting.");
+      return Ok(());
+    }
+  }
+
+  // Keep the CFS configurations and the BOS session templates about to be
+  // deleted so the deletion can be undone. Images and CFS sessions can't be
+  // restored
+  let cfs_configuration_vec: Vec<_> = backend
+    .get_and_filter_configuration(
+      shasta_token,
+      shasta_base_url,
+      shasta_root_cert,
+      configuration_name_opt.map(String::as_str),
+      configuration_name_pattern.map(String::as_str),
+      &target_hsm_group_vec,
+      since_opt,
+      until_opt,
+      None,
+    )
+    .await?
+    .into_iter()
+    .filter(|cfs_configuration| {
+      cfs_configuration_derivatives
+        .cfs_configuration_vec
+        .iter()
+        .any(|(name, _)| *name == cfs_configuration.name)
+    })
+    .collect();
+
+  let bos_sessiontemplate_vec: Vec<_> = backend
+    .get_all_templates(shasta_token, shasta_base_url, shasta_root_cert)
+    .await?
+    .into_iter()
+    .filter(|bos_sessiontemplate| {
+      cfs_configuration_derivatives
+        .bos_sessiontemplate_vec
+        .iter()
+        .any(|(name, _, _)| Some(name) == bos_sessiontemplate.name.as_ref())
+    })
+    .collect();
+
+  let journal_object_vec = cfs_configuration_vec
+    .into_iter()
+    .map(JournalObject::CfsConfiguration)
+    .chain(
+      bos_sessiontemplate_vec
+        .into_iter()
+        .map(JournalObject::BosSessionTemplate),
+    )
+    .collect();
+
+  journal.record(shasta_token, "configurations.delete", journal_object_vec)?;
+
+  backend
+    .delete_data_related_to_cfs_configuration(
+      shasta_token,
+      shasta_base_url,
+      shasta_root_cert,
+      &cfs_configuration_derivatives,
     )
     .await
 }
+
+fn print_summary(cfs_configuration_derivatives: &CfsConfigurationDerivatives) {
+  println!("CFS sessions to delete:");
+
+  let mut cfs_session_table = Table::new();
+
+  cfs_session_table.set_header(vec!["Name", "Configuration", "Image ID"]);
+
+  for (name, configuration, image_id) in
+    &cfs_configuration_derivatives.cfs_session_vec
+  {
+    cfs_session_table.add_row(vec![name, configuration, image_id]);
+  }
+
+  println!("{cfs_session_table}");
+
+  println!("BOS sessiontemplates to delete:");
+
+  let mut bos_sessiontemplate_table = Table::new();
+
+  bos_sessiontemplate_table.set_header(vec![
+    "Name",
+    "Configuration",
+    "Image ID",
+  ]);
+
+  for (name, configuration, image_id) in
+    &cfs_configuration_derivatives.bos_sessiontemplate_vec
+  {
+    bos_sessiontemplate_table.add_row(vec![name, configuration, image_id]);
+  }
+
+  println!("{bos_sessiontemplate_table}");
+
+  println!("CFS configurations to delete:");
+
+  let mut cfs_configuration_table = Table::new();
+
+  cfs_configuration_table.set_header(vec!["Name", "Last Update"]);
+
+  for (name, last_updated) in
+    &cfs_configuration_derivatives.cfs_configuration_vec
+  {
+    cfs_configuration_table.add_row(vec![name, last_updated]);
+  }
+
+  println!("{cfs_configuration_table}");
+
+  println!("Images to delete:");
+
+  let mut image_id_table = Table::new();
+
+  image_id_table.set_header(vec!["Image ID"]);
+
+  for image_id in &cfs_configuration_derivatives.image_id_vec {
+    image_id_table.add_row(vec![image_id]);
+  }
+
+  println!("{image_id_table}");
+}
@@ -0,0 +1,197 @@
+use std::{path::Path, time::Instant};
+
+use comfy_table::Table;
+use dialoguer::{theme::ColorfulTheme, Confirm};
+use manta_backend_dispatcher::{
+  error::Error,
+  interfaces::commands::CommandsTrait,
+  types::gc::{GcItem, GcPolicy, GcReport},
+};
+
+use crate::{
+  common::audit::Auditor, manta_backend_dispatcher::StaticBackendDispatcher,
+};
+
+/// Reads a garbage collection policy from a YAML file. Rules passed as
+/// arguments take precedence over the ones in the file
+pub fn get_gc_policy(
+  policy_file_opt: Option<&Path>,
+  keep_configurations_opt: Option<usize>,
+  keep_images_opt: Option<usize>,
+  failed_session_min_age_opt: Option<&String>,
+) -> Result<GcPolicy, Error> {
+  let mut gc_policy = match policy_file_opt {
+    Some(policy_file) => {
+      let policy_file_content =
+        std::fs::read_to_string(policy_file).map_err(|e| {
+          Error::Message(format!(
+            "Could not read policy file '{}'. Reason:\n{}",
+            policy_file.display(),
+            e
+          ))
+        })?;
+
+      serde_yaml::from_str(&policy_file_content).map_err(|e| {
+        Error::Message(format!(
+          "Could not parse policy file '{}'. Reason:\n{}",
+          policy_file.display(),
+          e
+        ))
+      })?
+    }
+    None => GcPolicy::default(),
+  };
+
+  if keep_configurations_opt.is_some() {
+    gc_policy.keep_configurations = keep_configurations_opt;
+  }
+
+  if keep_images_opt.is_some() {
+    gc_policy.keep_images = keep_images_opt;
+  }
+
+  if let Some(failed_session_min_age) = failed_session_min_age_opt {
+    gc_policy.failed_session_min_age = Some(failed_session_min_age.clone());
+  }
+
+  Ok(gc_policy)
+}
+
+/// Deletes the CFS configurations, images and failed CFS sessions of the
+/// groups the policy does not keep. Prints a report with the data to delete
+/// and the data kept because it is in use, then deletes after confirmation.
+/// Exits with an error if any item could not be deleted
+pub async fn exec(
+  backend: &StaticBackendDispatcher,
+  shasta_token: &str,
+  shasta_base_url: &str,
+  shasta_root_cert: &[u8],
+  target_hsm_group_vec: &[String],
+  gc_policy: &GcPolicy,
+  dry_run: bool,
+  assume_yes: bool,
+  output: &str,
+  audit_opt: Option<&Auditor>,
+) -> Result<(), Error> {
+  let gc_report = backend
+    .garbage_collect(
+      shasta_token,
+      shasta_base_url,
+      shasta_root_cert,
+      target_hsm_group_vec,
+      gc_policy,
+      true,
+    )
+    .await?;
+
+  if gc_report.delete.is_empty() {
+    print_report(&gc_report, output, "To delete");
+    eprintln!("Nothing to delete");
+    return Ok(());
+  }
+
+  // Running non-interactively only the final report is printed
+  if dry_run || !assume_yes {
+    print_report(&gc_report, output, "To delete");
+  }
+
+  if dry_run {
+    eprintln!("Dry-run enabled. No changes persisted into the system");
+    return Ok(());
+  }
+
+  if !assume_yes {
+    if Confirm::with_theme(&ColorfulTheme::default())
+      .with_prompt("Please review the data above and confirm to delete")
+      .interact()
+      .unwrap()
+    {
+      log::info!("Continue",);
+    } else {
+      println!("Cancelled by user. Aborting.");
+      return Ok(());
+    }
+  }
+
+  let start = Instant::now();
+
+  // Delete what the user reviewed, not a new plan which may differ if the
+  // system changed in the meantime
+  let result = backend
+    .delete_garbage(
+      shasta_token,
+      shasta_base_url,
+      shasta_root_cert,
+      &gc_report.delete,
+    )
+    .await;
+
+  // Audit
+  if let Some(auditor) = audit_opt {
+    let audit_event = auditor
+      .new_event(shasta_token, "garbage.delete")
+      .with_groups(target_hsm_group_vec)
+      .with_parameters(serde_json::json!({
+        "policy": gc_policy,
+        "deleted": result.as_ref().ok().map(|gc_report| &gc_report.delete),
+        "failed": result.as_ref().ok().map(|gc_report| &gc_report.failed),
+      }))
+      .with_result(&result)
+      .with_duration(start.elapsed());
+
+    auditor.send_event(&audit_event).await;
+  }
+
+  let gc_report = result?;
+
+  print_report(&gc_report, output, "Deleted");
+
+  if !gc_report.failed.is_empty() {
+    return Err(Error::Message(format!(
+      "Could not delete {} items",
+      gc_report.failed.len()
+    )));
+  }
+
+  Ok(())
+}
+
+fn print_report(gc_report: &GcReport, output: &str, delete_title: &str) {
+  match output {
+    "json" => {
+      println!("{}", serde_json::to_string_pretty(gc_report).unwrap())
+    }
+    _ => {
+      println!("{}:", delete_title);
+      print_table(&gc_report.delete);
+
+      if !gc_report.keep.is_empty() {
+        println!("Kept because they are in use:");
+        print_table(&gc_report.keep);
+      }
+
+      if !gc_report.failed.is_empty() {
+        println!("Could not delete:");
+        print_table(&gc_report.failed);
+      }
+    }
+  }
+}
+
+fn print_table(gc_item_vec: &[GcItem]) {
+  let mut table = Table::new();
+
+  table.set_header(vec!["Kind", "ID", "Name", "Date", "Reason"]);
+
+  for gc_item in gc_item_vec {
+    table.add_row(vec![
+      gc_item.kind.to_string(),
+      gc_item.id.clone(),
+      gc_item.name.clone().unwrap_or_default(),
+      gc_item.date.clone().unwrap_or_default(),
+      gc_item.reason.clone(),
+    ]);
+  }
+
+  println!("{table}");
+}
@@ -1,5 +1,10 @@
+use std::time::Instant;
+
 use crate::{
-  common::{audit::Audit, jwt_ops, kafka::Kafka},
+  common::{
+    audit::Auditor,
+    journal::{Journal, JournalObject},
+  },
   manta_backend_dispatcher::StaticBackendDispatcher,
 };
 use manta_backend_dispatcher::{
@@ -11,42 +16,45 @@ pub async fn exec(
   auth_token: &str,
   label: &str,
   force: bool,
-  kafka_audit_opt: Option<&Kafka>,
-) {
+  audit_opt: Option<&Auditor>,
+  journal: &Journal,
+) -> Result<(), Error> {
   if !force {
     // Validate if group can be deleted
-    validation(backend, auth_token, label).await.unwrap();
+    validation(backend, auth_token, label).await?;
   }
 
+  // Keep group and members so the deletion can be undone
+  let group = backend.get_group(auth_token, label).await?;
+
+  journal.record(
+    auth_token,
+    "group.delete",
+    vec![JournalObject::Group(group)],
+  )?;
+
   // Delete group
-  let result = backend.delete_group(auth_token, label).await;
+  let start = Instant::now();
 
-  match result {
-    Ok(_) => {
-      eprintln!("Group '{}' deleted", label);
-    }
-    Err(error) => {
-      eprintln!("{}", error);
-      std::process::exit(1);
-    }
-  }
+  let result = backend.delete_group(auth_token, label).await;
 
   // Audit
-  if let Some(kafka_audit) = kafka_audit_opt {
-    let username = jwt_ops::get_name(auth_token).unwrap_or_default();
-    let user_id =
-      jwt_ops::get_preferred_username(auth_token).unwrap_or_default();
+  if let Some(auditor) = audit_opt {
+    let audit_event = auditor
+      .new_event(auth_token, "group.delete")
+      .with_groups(&[label])
+      .with_parameters(serde_json::json!({ "force": force }))
+      .with_result(&result)
+      .with_duration(start.elapsed());
 
-    let msg_json = serde_json::json!(
-        { "user": {"id": user_id, "name": username}, "group": label, "message": format!("Delete Group '{}'", label)});
+    auditor.send_event(&audit_event).await;
+  }
 
-    let msg_data = serde_json::to_string(&msg_json)
-      .expect("Could not serialize audit message data");
+  result?;
 
-    if let Err(e) = kafka_audit.produce_message(msg_data.as_bytes()).await {
-      log::warn!("Failed producing messages: {}", e);
-    }
-  }
+  eprintln!("Group '{}' deleted", label);
+
+  Ok(())
 }
 
 // Checks if a group can be deleted.
@@ -15,6 +15,7 @@ use crate::{
   common,
   manta_backend_dispatcher::StaticBackendDispatcher,
 };
+use manta_backend_dispatcher::error::Error;
 
 pub async fn exec(
   backend: &StaticBackendDispatcher,
@@ -24,7 +25,7 @@ pub async fn exec(
   pattern: &str,
   dryrun: bool,
   delete_hsm_group: bool,
-) {
+) -> Result<(), Error> {
   match backend.get_group(shasta_token, target_hsm_group_name).await {
     /* match hsm::group::http_client::get(
         shasta_token,
@@ -38,11 +39,10 @@ pub async fn exec(
       log::debug!("The HSM group {} exists, good.", target_hsm_group_name)
     }
     Err(_) => {
-      log::error!(
-                "HSM group {} does not exist, cannot remove hw from it and cannot continue.",
-                target_hsm_group_name.to_string()
-            );
-      std::process::exit(1);
+      return Err(Error::Message(format!(
+        "HSM group {} does not exist, cannot remove hw from it and cannot continue.",
+        target_hsm_group_name.to_string(
+      ))));
     }
   }
   let pattern = format!("{}:{}", target_hsm_group_name, pattern);
@@ -75,8 +75,9 @@ pub async fn exec(
         hw_component_counter[1].parse::<isize>().unwrap(),
       );
     } else {
-      log::error!("Error in pattern. Please make sure to follow <hsm name>:<hw component>:<counter>:... eg <tasna>:a100:4:epyc:10:instinct:8");
-      std::process::exit(1);
+      return Err(Error::Message(
+        "Error in pattern. Please make sure to follow <hsm name>:<hw component>:<counter>:... eg <tasna>:a100:4:epyc:10:instinct:8".to_string(),
+      ));
     }
   }
 
@@ -130,7 +131,7 @@ pub async fn exec(
 
     if dryrun && delete_hsm_group {
       log::info!("The option to delete empty groups has NOT been selected, or the dryrun has been enabled. We are done with this action.");
-      return;
+      return Ok(());
     } else {
       log::info!(
         "The option to delete empty groups has been selected, removing it."
@@ -141,7 +142,7 @@ pub async fn exec(
             {
                 Ok(_) => {
                     log::info!("HSM group removed successfully, we are done with this action.");
-                    return;
+                    return Ok(());
                 }
                 Err(e2) => log::debug!(
                     "Error removing the HSM group. This always fails, ignore please. Reported: {}",
@@ -261,7 +262,7 @@ pub async fn exec(
                 .collect::<Vec<String>>(),
             &mut target_hsm_node_hw_component_count_vec,
             &combined_target_parent_hsm_hw_component_type_scores_based_on_scarcity_hashmap,
-        );
+        )?;
 
   // *********************************************************************************************************
   // PREPARE INFORMATION TO SHOW
@@ -327,7 +328,7 @@ pub async fn exec(
     println!("Continue.");
   } else {
     println!("Cancelled by user. Aborting.");
-    std::process::exit(0);
+    return Ok(());
   }
 
   // *********************************************************************************************************
@@ -407,6 +408,8 @@ pub async fn exec(
     "{}",
     serde_json::to_string_pretty(&parent_hsm_group_value).unwrap()
   );
+
+  Ok(())
 }
 
 pub async fn get_hsm_hw_node_component_counter(
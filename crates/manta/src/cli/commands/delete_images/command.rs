@@ -1,4 +1,5 @@
 use manta_backend_dispatcher::{
+  error::Error,
   interfaces::{
     bss::BootParametersTrait, hsm::group::GroupTrait, ims::ImsTrait,
   },
@@ -18,7 +19,7 @@ pub async fn exec(
   hsm_name_available_vec: Vec<String>,
   image_id_vec: &[&str],
   dry_run: bool,
-) {
+) -> Result<(), Error> {
   log::info!(
     "Executing command to delete images: {}",
     image_id_vec.join(", "),
@@ -27,18 +28,12 @@ pub async fn exec(
   let group_available_vec_rslt =
     backend.get_group_available(shasta_token).await;
 
-  let group_available_vec = group_available_vec_rslt.unwrap_or_else(|e| {
-    eprintln!("ERROR - {}", e);
-    std::process::exit(1);
-  });
+  let group_available_vec = group_available_vec_rslt?;
 
   let boot_parameter_vec_rslt =
     backend.get_all_bootparameters(shasta_token).await;
 
-  let boot_parameter_vec = boot_parameter_vec_rslt.unwrap_or_else(|e| {
-    eprintln!("ERROR - {}", e);
-    std::process::exit(1);
-  });
+  let boot_parameter_vec = boot_parameter_vec_rslt?;
 
   // Get list of image ids that are used to boot nodes (Node of these images can be deleted)
   let image_used_to_boot_nodes: Vec<String> = boot_parameter_vec
@@ -57,11 +52,10 @@ pub async fn exec(
 
   // Exit if any image id user wants to delete is used to boot nodes
   if !image_xnames_boot_map.is_empty() {
-    eprintln!(
-        "ERROR - The following images could not be deleted since they boot nodes.\n{:#?}",
-        image_xnames_boot_map
-      );
-    std::process::exit(1);
+    return Err(Error::Message(format!(
+      "The following images could not be deleted since they boot nodes.\n{:#?}",
+      image_xnames_boot_map
+    )));
   }
 
   // Get list of boot parameters that user can't delete because it's host is not a member of
@@ -70,11 +64,10 @@ pub async fn exec(
     get_restricted_image_ids(&group_available_vec, &boot_parameter_vec);
 
   if !image_restricted_vec.is_empty() {
-    eprintln!(
-        "ERROR - The following image ids are not deletable because they are used by hosts that are not part of the groups available to the user:\n{:#?}",
-        image_restricted_vec
-      );
-    std::process::exit(1);
+    return Err(Error::Message(format!(
+      "The following image ids are not deletable because they are used by hosts that are not part of the groups available to the user:\n{:#?}",
+      image_restricted_vec
+    )));
   }
 
   if dry_run {
@@ -100,6 +93,8 @@ pub async fn exec(
   }
 
   println!("Images deleted:\n{:?}", image_id_vec);
+
+  Ok(())
 }
 
 pub fn get_restricted_image_ids(
@@ -0,0 +1,76 @@
+use std::time::Instant;
+
+use dialoguer::{theme::ColorfulTheme, Confirm};
+use manta_backend_dispatcher::{
+  error::Error, interfaces::hsm::ethernet_interfaces::EthernetInterfaceTrait,
+};
+
+use crate::{
+  common::{audit::Auditor, authorization::validate_target_hsm_members},
+  manta_backend_dispatcher::StaticBackendDispatcher,
+};
+
+pub async fn exec(
+  backend: &StaticBackendDispatcher,
+  shasta_token: &str,
+  eth_interface_id: &str,
+  assume_yes: bool,
+  audit_opt: Option<&Auditor>,
+) -> Result<(), Error> {
+  // Check ethernet interface exists
+  let ethernet_interface = backend
+    .get_ethernet_interface(shasta_token, eth_interface_id)
+    .await?;
+
+  // Check user has access to the node the ethernet interface belongs to
+  if let Some(component_id) = &ethernet_interface.component_id {
+    validate_target_hsm_members(
+      backend,
+      shasta_token,
+      &vec![component_id.clone()],
+    )
+    .await?;
+  }
+
+  if !assume_yes {
+    if Confirm::with_theme(&ColorfulTheme::default())
+      .with_prompt(format!(
+        "Ethernet interface '{}' (MAC '{}') for node '{}' will be deleted. Do you want to proceed?",
+        eth_interface_id,
+        ethernet_interface.mac_address,
+        ethernet_interface.component_id.as_deref().unwrap_or_default()
+      ))
+      .interact()
+      .unwrap()
+    {
+      log::info!("Continue",);
+    } else {
+      println!("Cancelled by user. Aborting.");
+      return Ok(());
+    }
+  }
+
+  let start = Instant::now();
+
+  let result = backend
+    .delete_ethernet_interface(shasta_token, eth_interface_id)
+    .await;
+
+  // Audit
+  if let Some(auditor) = audit_opt {
+    let audit_event = auditor
+      .new_event(shasta_token, "interface.delete")
+      .with_xnames(ethernet_interface.component_id.as_slice())
+      .with_parameters(serde_json::json!({ "id": eth_interface_id }))
+      .with_result(&result)
+      .with_duration(start.elapsed());
+
+    auditor.send_event(&audit_event).await;
+  }
+
+  result?;
+
+  println!("Ethernet interface '{}' deleted", eth_interface_id);
+
+  Ok(())
+}
@@ -0,0 +1,65 @@
+use std::time::Instant;
+
+use dialoguer::{theme::ColorfulTheme, Confirm};
+use manta_backend_dispatcher::{
+  error::Error, interfaces::hsm::node_map::NodeMapTrait,
+};
+
+use crate::{
+  common::{audit::Auditor, authorization::validate_target_hsm_members},
+  manta_backend_dispatcher::StaticBackendDispatcher,
+};
+
+pub async fn exec(
+  backend: &StaticBackendDispatcher,
+  auth_token: &str,
+  xname: &str,
+  assume_yes: bool,
+  audit_opt: Option<&Auditor>,
+) -> Result<(), Error> {
+  // Validate user has access to the node
+  validate_target_hsm_members(backend, auth_token, &vec![xname.to_string()])
+    .await?;
+
+  // Check node map exists
+  let node_map = backend.get_node_map(auth_token, xname).await?;
+
+  if !assume_yes {
+    if Confirm::with_theme(&ColorfulTheme::default())
+      .with_prompt(format!(
+        "Node map '{}' (nid{:06}) will be deleted. Do you want to proceed?",
+        xname, node_map.nid
+      ))
+      .interact()
+      .unwrap()
+    {
+      log::info!("Continue",);
+    } else {
+      println!("Cancelled by user. Aborting.");
+      return Ok(());
+    }
+  }
+
+  // Delete node map
+  let start = Instant::now();
+
+  let result = backend.delete_node_map(auth_token, xname).await;
+
+  // Audit
+  if let Some(auditor) = audit_opt {
+    let audit_event = auditor
+      .new_event(auth_token, "nodemap.delete")
+      .with_xnames(&[xname])
+      .with_parameters(serde_json::json!({ "nid": node_map.nid }))
+      .with_result(&result)
+      .with_duration(start.elapsed());
+
+    auditor.send_event(&audit_event).await;
+  }
+
+  result?;
+
+  eprintln!("Node map '{}' deleted", xname);
+
+  Ok(())
+}
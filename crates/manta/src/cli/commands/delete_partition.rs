@@ -0,0 +1,61 @@
+use std::time::Instant;
+
+use dialoguer::{theme::ColorfulTheme, Confirm};
+use manta_backend_dispatcher::{
+  error::Error, interfaces::hsm::partition::PartitionTrait,
+};
+
+use crate::{
+  common::audit::Auditor, manta_backend_dispatcher::StaticBackendDispatcher,
+};
+
+pub async fn exec(
+  backend: &StaticBackendDispatcher,
+  auth_token: &str,
+  name: &str,
+  assume_yes: bool,
+  audit_opt: Option<&Auditor>,
+) -> Result<(), Error> {
+  // Check partition exists
+  let partition = backend.get_partition(auth_token, name).await?;
+
+  if !assume_yes {
+    if Confirm::with_theme(&ColorfulTheme::default())
+      .with_prompt(format!(
+        "Partition '{}' with {} member(s) will be deleted. Do you want to proceed?",
+        name,
+        partition.get_members().len()
+      ))
+      .interact()
+      .unwrap()
+    {
+      log::info!("Continue",);
+    } else {
+      println!("Cancelled by user. Aborting.");
+      return Ok(());
+    }
+  }
+
+  // Delete partition
+  let start = Instant::now();
+
+  let result = backend.delete_partition(auth_token, name).await;
+
+  // Audit
+  if let Some(auditor) = audit_opt {
+    let audit_event = auditor
+      .new_event(auth_token, "partition.delete")
+      .with_xnames(&partition.get_members())
+      .with_parameters(serde_json::json!({ "name": name }))
+      .with_result(&result)
+      .with_duration(start.elapsed());
+
+    auditor.send_event(&audit_event).await;
+  }
+
+  result?;
+
+  eprintln!("Partition '{}' deleted", name);
+
+  Ok(())
+}
@@ -1,4 +1,6 @@
-use manta_backend_dispatcher::interfaces::hsm::group::GroupTrait;
+use manta_backend_dispatcher::{
+  error::Error, interfaces::hsm::group::GroupTrait,
+};
 
 use crate::{
   common::node_ops, manta_backend_dispatcher::StaticBackendDispatcher,
@@ -16,7 +18,7 @@ pub async fn exec(
   xnames_only: bool,
   output_opt: Option<&String>,
   summary_status: bool,
-) {
+) -> Result<(), Error> {
   // Take all nodes for all hsm_groups found and put them in a Vec
   let mut hsm_groups_node_list: Vec<String> = backend
     .get_member_vec_from_group_name_vec(shasta_token, hsm_name_vec.to_vec())
@@ -33,13 +35,7 @@ pub async fn exec(
   )
   .await;
 
-  let mut node_details_list = match node_details_list_rslt {
-    Ok(value) => value,
-    Err(e) => {
-      eprintln!("{}", e);
-      std::process::exit(1);
-    }
-  };
+  let mut node_details_list = node_details_list_rslt?;
 
   node_details_list.retain(|node_details| {
     if let Some(status) = status {
@@ -120,7 +116,10 @@ pub async fn exec(
   } else if output_opt.is_some() && output_opt.unwrap().eq("table") {
     node_ops::print_table(node_details_list);
   } else {
-    eprintln!("ERROR - output value not recognized or missing. Exit");
-    std::process::exit(1);
+    return Err(Error::Message(
+      "output value not recognized or missing".to_string(),
+    ));
   }
+
+  Ok(())
 }
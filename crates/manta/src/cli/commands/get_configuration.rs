@@ -1,4 +1,5 @@
 use manta_backend_dispatcher::{
+  error::Error,
   interfaces::cfs::CfsTrait,
   types::cfs::cfs_configuration_details::{ConfigurationDetails, LayerDetails},
   types::cfs::cfs_configuration_response::CfsConfigurationResponse,
@@ -25,7 +26,7 @@ pub async fn exec(
   limit: Option<&u8>,
   output_opt: Option<&String>,
   site_name: &str,
-) {
+) -> Result<(), Error> {
   let cfs_configuration_vec: Vec<CfsConfigurationResponse> = backend
     .get_and_filter_configuration(
       shasta_token,
@@ -38,15 +39,11 @@ pub async fn exec(
       until_opt,
       limit,
     )
-    .await
-    .unwrap_or_else(|e| {
-      eprintln!("ERROR - Could not fetch configurations. Reason:\n{:#?}", e);
-      std::process::exit(1);
-    });
+    .await?;
 
   if cfs_configuration_vec.is_empty() {
     println!("No CFS configuration found!");
-    std::process::exit(0);
+    return Ok(());
   }
 
   if output_opt.is_some() && output_opt.unwrap().eq("json") {
@@ -64,40 +61,27 @@ pub async fn exec(
 
       for layer in &most_recent_cfs_configuration.layers {
         let layer_details: LayerDetails = backend
-                    .get_configuration_layer_details(
-                        shasta_root_cert,
-                        gitea_base_url,
-                        gitea_token,
-                        layer.clone(),
-                        site_name,
-                    )
-                    .await
-                    .unwrap_or_else(|e| {
-                        eprintln!(
-                            "ERROR - Could not fetch configuration layer details. Reason:\n{:#?}",
-                            e
-                        );
-                        std::process::exit(1);
-                    });
+          .get_configuration_layer_details(
+            shasta_root_cert,
+            gitea_base_url,
+            gitea_token,
+            layer.clone(),
+            site_name,
+          )
+          .await?;
 
         layer_details_vec.push(layer_details);
       }
 
-      let (cfs_session_vec_opt, bos_sessiontemplate_vec_opt, image_vec_opt) = backend
-                .get_derivatives(
-                    shasta_token,
-                    shasta_base_url,
-                    shasta_root_cert,
-                    &most_recent_cfs_configuration.name,
-                )
-                .await
-                .unwrap_or_else(|e| {
-                    eprintln!(
-                        "ERROR - Could not fetch configuration derivatives. Reason:\n{:#?}",
-                        e
-                    );
-                    std::process::exit(1);
-                });
+      let (cfs_session_vec_opt, bos_sessiontemplate_vec_opt, image_vec_opt) =
+        backend
+          .get_derivatives(
+            shasta_token,
+            shasta_base_url,
+            shasta_root_cert,
+            &most_recent_cfs_configuration.name,
+          )
+          .await?;
 
       crate::common::cfs_configuration_utils::print_table_details_struct(
         ConfigurationDetails::new(
@@ -113,4 +97,6 @@ pub async fn exec(
       print_table_struct(&cfs_configuration_vec);
     }
   }
+
+  Ok(())
 }
@@ -42,8 +42,7 @@ pub async fn exec(
         .unwrap()
     ),
     _ => {
-      eprintln!("ERROR - output not valid");
-      std::process::exit(1);
+      return Err(Error::Message("output not valid".to_string()));
     }
   }
 
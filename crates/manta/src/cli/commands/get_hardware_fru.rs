@@ -0,0 +1,180 @@
+use std::sync::Arc;
+
+use comfy_table::Table;
+use manta_backend_dispatcher::{
+  error::Error,
+  interfaces::hsm::hardware_inventory::HardwareInventory,
+  types::{hsm::inventory::HWInvHistArray, HWInventoryByFRU},
+};
+
+use tokio::sync::Semaphore;
+
+use crate::manta_backend_dispatcher::StaticBackendDispatcher;
+
+/// FRU history requests sent at the same time
+const HISTORY_CONCURRENCY: usize = 10;
+
+/// Prints FRUs filtered by FRU id, type, manufacturer, part number or serial
+/// number. If `history` is set, prints the xnames each FRU has been in
+/// instead
+pub async fn exec(
+  backend: &StaticBackendDispatcher,
+  auth_token: &str,
+  fru_id_opt: Option<&str>,
+  type_opt: Option<&str>,
+  manufacturer_opt: Option<&str>,
+  part_number_opt: Option<&str>,
+  serial_number_opt: Option<&str>,
+  history: bool,
+  output: &str,
+) -> Result<(), Error> {
+  let hw_inventory_by_fru_vec: Vec<HWInventoryByFRU> = backend
+    .get_inventory_hardware_by_fru(
+      auth_token,
+      fru_id_opt,
+      type_opt,
+      manufacturer_opt,
+      part_number_opt,
+      serial_number_opt,
+    )
+    .await?;
+
+  if hw_inventory_by_fru_vec.is_empty() {
+    eprintln!("No FRU found");
+    return Ok(());
+  }
+
+  if !history {
+    match output {
+      "table" => print_table(&hw_inventory_by_fru_vec),
+      "json" => println!(
+        "{}",
+        serde_json::to_string_pretty(&hw_inventory_by_fru_vec).unwrap()
+      ),
+      _ => {
+        return Err(Error::Message("output not valid".to_string()));
+      }
+    }
+
+    return Ok(());
+  }
+
+  // One request per FRU, run them concurrently
+  let mut tasks = tokio::task::JoinSet::new();
+
+  let sem = Arc::new(Semaphore::new(HISTORY_CONCURRENCY));
+
+  for (index, fru_id) in hw_inventory_by_fru_vec
+    .iter()
+    .filter_map(|hw_inventory_by_fru| hw_inventory_by_fru.fru_id.clone())
+    .enumerate()
+  {
+    let backend_cp = backend.clone();
+    let auth_token_string = auth_token.to_string();
+
+    let permit = Arc::clone(&sem).acquire_owned().await;
+
+    tasks.spawn(async move {
+      let _permit = permit;
+
+      backend_cp
+        .get_inventory_hardware_history_by_fru(
+          &auth_token_string,
+          &fru_id,
+          None,
+          None,
+        )
+        .await
+        .map(|hw_inv_hist_array| (index, hw_inv_hist_array))
+    });
+  }
+
+  let mut hw_inv_hist_array_with_index_vec: Vec<(usize, HWInvHistArray)> =
+    Vec::new();
+
+  while let Some(message) = tasks.join_next().await {
+    let (index, mut hw_inv_hist_array) = message.map_err(|e| {
+      Error::Message(format!("Could not get FRU history. Reason:\n{}", e))
+    })??;
+
+    hw_inv_hist_array
+      .history
+      .sort_by(|a, b| a.timestamp.cmp(&b.timestamp));
+
+    hw_inv_hist_array_with_index_vec.push((index, hw_inv_hist_array));
+  }
+
+  // Keep the order FRUs were listed in
+  hw_inv_hist_array_with_index_vec.sort_by_key(|(index, _)| *index);
+
+  let hw_inv_hist_array_vec: Vec<HWInvHistArray> =
+    hw_inv_hist_array_with_index_vec
+      .into_iter()
+      .map(|(_, hw_inv_hist_array)| hw_inv_hist_array)
+      .collect();
+
+  match output {
+    "table" => print_history_table(&hw_inv_hist_array_vec),
+    "json" => println!(
+      "{}",
+      serde_json::to_string_pretty(&hw_inv_hist_array_vec).unwrap()
+    ),
+    _ => {
+      return Err(Error::Message("output not valid".to_string()));
+    }
+  }
+
+  Ok(())
+}
+
+pub fn print_table(hw_inventory_by_fru_vec: &[HWInventoryByFRU]) {
+  let mut table = Table::new();
+
+  table.set_header(vec![
+    "FRU ID",
+    "Type",
+    "Manufacturer",
+    "Part Number",
+    "Serial Number",
+  ]);
+
+  for hw_inventory_by_fru in hw_inventory_by_fru_vec {
+    table.add_row(vec![
+      hw_inventory_by_fru.fru_id.clone().unwrap_or_default(),
+      hw_inventory_by_fru.r#type.clone().unwrap_or_default(),
+      hw_inventory_by_fru
+        .get_manufacturer()
+        .unwrap_or_default()
+        .to_string(),
+      hw_inventory_by_fru
+        .get_part_number()
+        .unwrap_or_default()
+        .to_string(),
+      hw_inventory_by_fru
+        .get_serial_number()
+        .unwrap_or_default()
+        .to_string(),
+    ]);
+  }
+
+  println!("{table}");
+}
+
+pub fn print_history_table(hw_inv_hist_array_vec: &[HWInvHistArray]) {
+  let mut table = Table::new();
+
+  table.set_header(vec!["FRU ID", "Timestamp", "Event", "Xname"]);
+
+  for hw_inv_hist_array in hw_inv_hist_array_vec {
+    for hw_inv_hist in &hw_inv_hist_array.history {
+      table.add_row(vec![
+        hw_inv_hist.fru_id.clone(),
+        hw_inv_hist.timestamp.clone(),
+        hw_inv_hist.event_type.clone(),
+        hw_inv_hist.id.clone(),
+      ]);
+    }
+  }
+
+  println!("{table}");
+}
@@ -1,6 +1,7 @@
 use comfy_table::{Cell, Table};
 use manta_backend_dispatcher::{
-  interfaces::hsm::hardware_inventory::HardwareInventory, types::NodeSummary,
+  error::Error, interfaces::hsm::hardware_inventory::HardwareInventory,
+  types::NodeSummary,
 };
 use std::string::ToString;
 
@@ -13,7 +14,7 @@ pub async fn exec(
   xname: &str,
   type_artifact_opt: Option<&String>,
   output_opt: Option<&String>,
-) {
+) -> Result<(), Error> {
   let mut node_hw_inventory = &backend
     .get_inventory_hardware_query(
       shasta_token,
@@ -30,11 +31,10 @@ pub async fn exec(
   node_hw_inventory = match node_hw_inventory.pointer("/Nodes/0") {
     Some(node_value) => node_value,
     None => {
-      eprintln!(
-                "ERROR - json section '/Node' missing in json response API for node '{}'",
-                xname
-            );
-      std::process::exit(1);
+      return Err(Error::Message(format!(
+        "json section '/Node' missing in json response API for node '{}'",
+        xname
+      )));
     }
   };
 
@@ -54,6 +54,8 @@ pub async fn exec(
   } else {
     print_table(&[node_summary].to_vec());
   }
+
+  Ok(())
 }
 
 pub fn print_table(node_summary_vec: &Vec<NodeSummary>) {
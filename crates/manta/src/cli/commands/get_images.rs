@@ -18,7 +18,7 @@ pub async fn exec(
   hsm_group_name_vec: &[String],
   id_opt: Option<&String>,
   limit_number: Option<&u8>,
-) {
+) -> Result<(), Error> {
   let image_detail_vec_rslt: Result<Vec<(Image, String, String, bool)>, Error> =
     backend
       .get_images_and_details(
@@ -31,13 +31,7 @@ pub async fn exec(
       )
       .await;
 
-  let image_detail_vec = match image_detail_vec_rslt {
-    Ok(image_detail_vec) => image_detail_vec,
-    Err(e) => {
-      eprintln!("Error: {e}");
-      std::process::exit(1);
-    }
-  };
+  let image_detail_vec = image_detail_vec_rslt?;
 
   // Print data
   let mut table = Table::new();
@@ -54,7 +48,8 @@ pub async fn exec(
   ]);
 
   for image_details in image_detail_vec {
-    let creation_date = image_details.0.created.as_ref().unwrap();
+    // NOTE: some backends (eg OpenCHAMI) do not keep track of image creation time
+    let creation_date = image_details.0.created.clone().unwrap_or_default();
 
     // NOTE: CSM can have different date formats, so we need to try to parse it in different
     // ways
@@ -81,4 +76,6 @@ pub async fn exec(
   }
 
   println!("{table}");
+
+  Ok(())
 }
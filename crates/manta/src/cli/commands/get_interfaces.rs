@@ -0,0 +1,130 @@
+use comfy_table::Table;
+use manta_backend_dispatcher::{
+  error::Error,
+  interfaces::hsm::{
+    component::ComponentTrait, ethernet_interfaces::EthernetInterfaceTrait,
+    group::GroupTrait,
+  },
+  types::hsm::inventory::EthernetInterface,
+};
+
+use crate::{
+  common::{self},
+  manta_backend_dispatcher::StaticBackendDispatcher,
+};
+
+/// Get ethernet interfaces for a list of nodes. If no nodes are provided, then
+/// the ethernet interfaces are filtered by MAC and/or IP address and only
+/// those related to nodes the user has access to are returned
+pub async fn exec(
+  backend: &StaticBackendDispatcher,
+  shasta_token: &str,
+  hosts_expression_opt: Option<&str>,
+  mac_address_opt: Option<&str>,
+  ip_address_opt: Option<&str>,
+  network_opt: Option<&str>,
+  output: &str,
+) -> Result<(), Error> {
+  // Get list of xnames the user is allowed to see
+  let xname_vec: Vec<String> =
+    if let Some(hosts_expression) = hosts_expression_opt {
+      let node_metadata_available_vec =
+        backend.get_node_metadata_available(shasta_token).await?;
+
+      common::node_ops::from_hosts_expression_to_xname_vec(
+        hosts_expression,
+        false,
+        node_metadata_available_vec,
+      )
+      .await?
+    } else {
+      let group_available_vec =
+        backend.get_group_name_available(shasta_token).await?;
+
+      backend
+        .get_member_vec_from_group_name_vec(shasta_token, group_available_vec)
+        .await?
+    };
+
+  if xname_vec.is_empty() {
+    eprintln!("The list of nodes to operate is empty. Nothing to do. Exit");
+    return Ok(());
+  }
+
+  // Querying by xname one by one is slow, we fetch all interfaces matching
+  // the filters and then keep the ones related to the nodes requested
+  let mut ethernet_interface_vec: Vec<EthernetInterface> = backend
+    .get_ethernet_interfaces(
+      shasta_token,
+      mac_address_opt,
+      ip_address_opt,
+      network_opt,
+      None,
+      None,
+    )
+    .await?
+    .into_iter()
+    .filter(|ethernet_interface| {
+      ethernet_interface
+        .component_id
+        .as_ref()
+        .is_some_and(|component_id| xname_vec.contains(component_id))
+    })
+    .collect();
+
+  ethernet_interface_vec.sort_by(|a, b| {
+    a.component_id
+      .cmp(&b.component_id)
+      .then(a.mac_address.cmp(&b.mac_address))
+  });
+
+  match output {
+    "table" => print_table(&ethernet_interface_vec),
+    "json" => println!(
+      "{}",
+      serde_json::to_string_pretty(&ethernet_interface_vec).unwrap()
+    ),
+    _ => {
+      return Err(Error::Message("output not valid".to_string()));
+    }
+  }
+
+  Ok(())
+}
+
+pub fn print_table(ethernet_interface_vec: &[EthernetInterface]) {
+  let mut table = Table::new();
+
+  table.set_header(vec![
+    "ID",
+    "Component ID",
+    "MAC Address",
+    "IP Addresses",
+    "Description",
+    "Last Update",
+  ]);
+
+  for ethernet_interface in ethernet_interface_vec {
+    let ip_address_vec: Vec<String> = ethernet_interface
+      .ip_addresses
+      .iter()
+      .map(|ip_address_mapping| match &ip_address_mapping.network {
+        Some(network) => {
+          format!("{} ({})", ip_address_mapping.ip_address, network)
+        }
+        None => ip_address_mapping.ip_address.clone(),
+      })
+      .collect();
+
+    table.add_row(vec![
+      ethernet_interface.id.clone().unwrap_or_default(),
+      ethernet_interface.component_id.clone().unwrap_or_default(),
+      ethernet_interface.mac_address.clone(),
+      ip_address_vec.join("\n"),
+      ethernet_interface.description.clone().unwrap_or_default(),
+      ethernet_interface.last_update.clone().unwrap_or_default(),
+    ]);
+  }
+
+  println!("{table}");
+}
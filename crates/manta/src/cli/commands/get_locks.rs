@@ -0,0 +1,91 @@
+use comfy_table::Table;
+use manta_backend_dispatcher::{
+  error::Error,
+  interfaces::hsm::{component::ComponentTrait, lock::LockTrait},
+  types::hsm::lock::ComponentLockStatus,
+};
+
+use crate::{common, manta_backend_dispatcher::StaticBackendDispatcher};
+
+/// Prints the lock and reservation status of the nodes in a hosts expression,
+/// or of all nodes the user has access to
+pub async fn exec(
+  backend: &StaticBackendDispatcher,
+  shasta_token: &str,
+  hosts_expression_opt: Option<&str>,
+  output: &str,
+) -> Result<(), Error> {
+  // Filter xnames to the ones members to HSM groups the user has access to
+  let node_metadata_available_vec =
+    backend.get_node_metadata_available(shasta_token).await?;
+
+  let xname_vec: Vec<String> = match hosts_expression_opt {
+    Some(hosts_expression) => {
+      common::node_ops::from_hosts_expression_to_xname_vec(
+        hosts_expression,
+        false,
+        node_metadata_available_vec,
+      )
+      .await?
+    }
+    None => node_metadata_available_vec
+      .into_iter()
+      .filter_map(|component| component.id)
+      .collect(),
+  };
+
+  if xname_vec.is_empty() {
+    eprintln!("The list of nodes is empty. Nothing to do. Exit");
+    return Ok(());
+  }
+
+  let mut component_lock_status_vec =
+    backend.get_locks_status(shasta_token, &xname_vec).await?;
+
+  component_lock_status_vec.sort_by(|a, b| a.id.cmp(&b.id));
+
+  match output {
+    "table" => print_table(&component_lock_status_vec),
+    "json" => println!(
+      "{}",
+      serde_json::to_string_pretty(&component_lock_status_vec).unwrap()
+    ),
+    _ => {
+      return Err(Error::Message("output not valid".to_string()));
+    }
+  }
+
+  Ok(())
+}
+
+pub fn print_table(component_lock_status_vec: &[ComponentLockStatus]) {
+  let mut table = Table::new();
+
+  table.set_header(vec![
+    "Xname",
+    "Locked",
+    "Reserved",
+    "Reservation Disabled",
+    "Creation Time",
+    "Expiration Time",
+  ]);
+
+  for component_lock_status in component_lock_status_vec {
+    table.add_row(vec![
+      component_lock_status.id.clone(),
+      component_lock_status.locked.to_string(),
+      component_lock_status.reserved.to_string(),
+      component_lock_status.reservation_disabled.to_string(),
+      component_lock_status
+        .creation_time
+        .clone()
+        .unwrap_or_default(),
+      component_lock_status
+        .expiration_time
+        .clone()
+        .unwrap_or_default(),
+    ]);
+  }
+
+  println!("{table}");
+}
@@ -0,0 +1,50 @@
+use comfy_table::Table;
+use manta_backend_dispatcher::{
+  error::Error, interfaces::hsm::node_map::NodeMapTrait,
+  types::hsm::node_map::NodeMap,
+};
+
+use crate::manta_backend_dispatcher::StaticBackendDispatcher;
+
+pub async fn exec(
+  backend: &StaticBackendDispatcher,
+  auth_token: &str,
+  xname_opt: Option<&str>,
+  output: &str,
+) -> Result<(), Error> {
+  let mut node_map_vec: Vec<NodeMap> = match xname_opt {
+    Some(xname) => vec![backend.get_node_map(auth_token, xname).await?],
+    None => backend.get_node_maps(auth_token).await?,
+  };
+
+  node_map_vec.sort_by_key(|node_map| node_map.nid);
+
+  match output {
+    "table" => print_table(&node_map_vec),
+    "json" => {
+      println!("{}", serde_json::to_string_pretty(&node_map_vec).unwrap())
+    }
+    _ => {
+      return Err(Error::Message("output not valid".to_string()));
+    }
+  }
+
+  Ok(())
+}
+
+pub fn print_table(node_map_vec: &[NodeMap]) {
+  let mut table = Table::new();
+
+  table.set_header(vec!["Xname", "NID", "Role", "SubRole"]);
+
+  for node_map in node_map_vec {
+    table.add_row(vec![
+      node_map.id.clone(),
+      format!("nid{:06}", node_map.nid),
+      node_map.role.clone().unwrap_or_default(),
+      node_map.sub_role.clone().unwrap_or_default(),
+    ]);
+  }
+
+  println!("{table}");
+}
@@ -1,4 +1,6 @@
-use manta_backend_dispatcher::interfaces::hsm::component::ComponentTrait;
+use manta_backend_dispatcher::{
+  error::Error, interfaces::hsm::component::ComponentTrait,
+};
 
 use crate::{common, manta_backend_dispatcher::StaticBackendDispatcher};
 
@@ -15,53 +17,34 @@ pub async fn exec(
   silent_xname: bool,
   output_opt: Option<&String>,
   status_summary: bool,
-) {
+) -> Result<(), Error> {
   // Convert user input to xname
-  let node_metadata_available_vec = backend
-    .get_node_metadata_available(shasta_token)
-    .await
-    .unwrap_or_else(|e| {
-      eprintln!("ERROR - Could not get node metadata. Reason:\n{e}\nExit");
-      std::process::exit(1);
-    });
+  let node_metadata_available_vec =
+    backend.get_node_metadata_available(shasta_token).await?;
 
   let mut node_list = common::node_ops::from_hosts_expression_to_xname_vec(
     hosts_expression,
     is_include_siblings,
     node_metadata_available_vec,
   )
-  .await
-  .unwrap_or_else(|e| {
-    eprintln!(
-      "ERROR - Could not convert user input to list of xnames. Reason:\n{}",
-      e
-    );
-    std::process::exit(1);
-  });
+  .await?;
 
   if node_list.is_empty() {
     eprintln!("The list of nodes to operate is empty. Nothing to do. Exit");
-    std::process::exit(0);
+    return Ok(());
   }
 
   node_list.sort();
   node_list.dedup();
 
-  let node_details_list_rslt = csm_rs::node::utils::get_node_details(
+  let mut node_details_list = csm_rs::node::utils::get_node_details(
     shasta_token,
     shasta_base_url,
     shasta_root_cert,
     node_list.to_vec(),
   )
-  .await;
-
-  let mut node_details_list = match node_details_list_rslt {
-    Err(e) => {
-      eprintln!("{}", e);
-      std::process::exit(1);
-    }
-    Ok(node_details_list) => node_details_list,
-  };
+  .await
+  .map_err(Error::from)?;
 
   node_details_list.retain(|node_details| {
     if let Some(status) = status {
@@ -142,7 +125,10 @@ pub async fn exec(
   } else if output_opt.is_some() && output_opt.unwrap().eq("table") {
     common::node_ops::print_table(node_details_list);
   } else {
-    eprintln!("ERROR - output value not recognized or missing. Exit");
-    std::process::exit(1);
+    return Err(Error::Message(
+      "output value not recognized or missing".to_string(),
+    ));
   }
+
+  Ok(())
 }
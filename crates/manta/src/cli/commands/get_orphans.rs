@@ -0,0 +1,65 @@
+use comfy_table::Table;
+use manta_backend_dispatcher::{
+  error::Error,
+  interfaces::commands::CommandsTrait,
+  types::orphan::{Orphan, OrphanReport},
+};
+
+use crate::manta_backend_dispatcher::StaticBackendDispatcher;
+
+/// Prints the images, S3 boot images, BOS session templates and CFS
+/// configurations nothing refers to or which refer to deleted data
+pub async fn exec(
+  backend: &StaticBackendDispatcher,
+  shasta_token: &str,
+  shasta_base_url: &str,
+  shasta_root_cert: &[u8],
+  output: &str,
+) -> Result<(), Error> {
+  let orphan_report = backend
+    .get_orphans(shasta_token, shasta_base_url, shasta_root_cert)
+    .await?;
+
+  match output {
+    "json" => {
+      println!("{}", serde_json::to_string_pretty(&orphan_report).unwrap())
+    }
+    _ => print_report(&orphan_report),
+  }
+
+  Ok(())
+}
+
+fn print_report(orphan_report: &OrphanReport) {
+  if orphan_report.is_empty() {
+    eprintln!("No orphans found");
+    return;
+  }
+
+  print_table("Images", &orphan_report.images);
+  print_table("S3 boot images", &orphan_report.s3_boot_images);
+  print_table("BOS session templates", &orphan_report.session_templates);
+  print_table("CFS configurations", &orphan_report.configurations);
+}
+
+fn print_table(title: &str, orphan_vec: &[Orphan]) {
+  if orphan_vec.is_empty() {
+    return;
+  }
+
+  let mut table = Table::new();
+
+  table.set_header(vec!["ID", "Name", "Date", "Reason"]);
+
+  for orphan in orphan_vec {
+    table.add_row(vec![
+      orphan.id.clone(),
+      orphan.name.clone().unwrap_or_default(),
+      orphan.date.clone().unwrap_or_default(),
+      orphan.reason.clone(),
+    ]);
+  }
+
+  println!("{} ({}):", title, orphan_vec.len());
+  println!("{table}");
+}
@@ -0,0 +1,58 @@
+use comfy_table::Table;
+use manta_backend_dispatcher::{
+  error::Error, interfaces::hsm::partition::PartitionTrait,
+  types::hsm::partition::Partition,
+};
+
+use crate::manta_backend_dispatcher::StaticBackendDispatcher;
+
+pub async fn exec(
+  backend: &StaticBackendDispatcher,
+  auth_token: &str,
+  partition_name_opt: Option<&str>,
+  tag_opt: Option<&str>,
+  output: &str,
+) -> Result<(), Error> {
+  let partition_vec: Vec<Partition> = backend
+    .get_partitions(auth_token, partition_name_opt, tag_opt)
+    .await?;
+
+  match output {
+    "table" => print_table(&partition_vec),
+    "json" => {
+      println!("{}", serde_json::to_string_pretty(&partition_vec).unwrap())
+    }
+    _ => {
+      return Err(Error::Message("output not valid".to_string()));
+    }
+  }
+
+  Ok(())
+}
+
+pub fn print_table(partition_vec: &[Partition]) {
+  let mut table = Table::new();
+
+  table.set_header(vec![
+    "Partition Name",
+    "Description",
+    "# members",
+    "Members",
+    "Tags",
+  ]);
+
+  for partition in partition_vec {
+    let mut partition_members = partition.get_members();
+    partition_members.sort();
+
+    table.add_row(vec![
+      partition.name.clone(),
+      partition.description.clone().unwrap_or_default(),
+      partition_members.len().to_string(),
+      partition_members.join("\n"),
+      partition.tags.clone().unwrap_or_default().join("\n"),
+    ]);
+  }
+
+  println!("{table}");
+}
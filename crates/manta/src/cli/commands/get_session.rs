@@ -1,4 +1,4 @@
-use manta_backend_dispatcher::interfaces::cfs::CfsTrait;
+use manta_backend_dispatcher::{error::Error, interfaces::cfs::CfsTrait};
 
 use crate::{common, manta_backend_dispatcher::StaticBackendDispatcher};
 
@@ -15,7 +15,7 @@ pub async fn exec(
   cfs_session_name_opt: Option<&String>,
   limit_number_opt: Option<&u8>,
   output_opt: Option<&String>,
-) {
+) -> Result<(), Error> {
   log::info!(
     "Get CFS sessions for HSM groups: {:?}",
     hsm_group_name_available_vec_opt
@@ -35,11 +35,7 @@ pub async fn exec(
       limit_number_opt,
       None,
     )
-    .await
-    .unwrap_or_else(|e| {
-      log::error!("Failed to get CFS sessions. Reason:\n{e}");
-      std::process::exit(1);
-    });
+    .await?;
 
   if output_opt.is_some() && output_opt.unwrap().eq("json") {
     println!(
@@ -49,4 +45,6 @@ pub async fn exec(
   } else {
     common::cfs_session_utils::print_table_struct(&cfs_session_vec);
   }
+
+  Ok(())
 }
@@ -1,4 +1,6 @@
-use manta_backend_dispatcher::interfaces::bos::ClusterTemplateTrait;
+use manta_backend_dispatcher::{
+  error::Error, interfaces::bos::ClusterTemplateTrait,
+};
 
 use crate::manta_backend_dispatcher::StaticBackendDispatcher;
 
@@ -12,7 +14,7 @@ pub async fn exec(
   bos_sessiontemplate_name_opt: Option<&String>,
   limit_number_opt: Option<&u8>,
   output: &str,
-) {
+) -> Result<(), Error> {
   log::info!(
     "Get BOS sessiontemplates for HSM groups: {:?}",
     hsm_group_name_vec
@@ -30,20 +32,11 @@ pub async fn exec(
     )
     .await;
 
-  let bos_sessiontemplate_vec = match bos_sessiontemplate_vec_rslt {
-    Ok(bos_sessiontemplate_vec) => bos_sessiontemplate_vec,
-    Err(e) => {
-      eprintln!(
-                "ERROR - Could not fetch BOS sessiontemplate list. Reason:\n{:#?}\nExit",
-                e
-            );
-      std::process::exit(1);
-    }
-  };
+  let bos_sessiontemplate_vec = bos_sessiontemplate_vec_rslt?;
 
   if bos_sessiontemplate_vec.is_empty() {
     println!("No BOS template found!");
-    std::process::exit(0);
+    return Ok(());
   } else {
     if output == "table" {
       crate::common::bos_sessiontemplate_utils::print_table_struct(
@@ -56,4 +49,6 @@ pub async fn exec(
       );
     }
   }
+
+  Ok(())
 }
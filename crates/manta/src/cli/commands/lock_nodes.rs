@@ -0,0 +1,100 @@
+use std::time::Instant;
+
+use dialoguer::{theme::ColorfulTheme, Confirm};
+use manta_backend_dispatcher::interfaces::hsm::{
+  component::ComponentTrait, lock::LockTrait,
+};
+use nodeset::NodeSet;
+
+use crate::{
+  common::{self, audit::Auditor},
+  manta_backend_dispatcher::StaticBackendDispatcher,
+};
+use manta_backend_dispatcher::error::Error;
+
+/// Locks nodes so no operation can change them until they are unlocked. Either all nodes get locked or none
+pub async fn exec(
+  backend: &StaticBackendDispatcher,
+  shasta_token: &str,
+  hosts_expression: &str,
+  assume_yes: bool,
+  audit_opt: Option<&Auditor>,
+) -> Result<(), Error> {
+  // Filter xnames to the ones members to HSM groups the user has access to
+  let node_metadata_available_vec =
+    backend.get_node_metadata_available(shasta_token).await?;
+
+  let mut xname_vec = common::node_ops::from_hosts_expression_to_xname_vec(
+    hosts_expression,
+    false,
+    node_metadata_available_vec,
+  )
+  .await?;
+
+  if xname_vec.is_empty() {
+    eprintln!("The list of nodes to operate is empty. Nothing to do. Exit");
+    return Ok(());
+  }
+
+  xname_vec.sort();
+  xname_vec.dedup();
+
+  let node_group: NodeSet = xname_vec.join(", ").parse().unwrap();
+
+  println!(
+    "Number of nodes: {}\nlist of nodes: {}",
+    node_group.len(),
+    node_group.to_string()
+  );
+
+  if !assume_yes {
+    if Confirm::with_theme(&ColorfulTheme::default())
+      .with_prompt("The nodes above will be locked. Please confirm to proceed?")
+      .interact()
+      .unwrap()
+    {
+      log::info!("Continue",);
+    } else {
+      println!("Cancelled by user. Aborting.");
+      return Ok(());
+    }
+  }
+
+  let start = Instant::now();
+
+  let lock_result_rslt =
+    backend.lock_components(shasta_token, &xname_vec).await;
+
+  // Audit
+  if let Some(auditor) = audit_opt {
+    let audit_event = auditor
+      .new_event(shasta_token, "node.lock")
+      .with_xnames(&xname_vec)
+      .with_result(&lock_result_rslt)
+      .with_duration(start.elapsed());
+
+    auditor.send_event(&audit_event).await;
+  }
+
+  let lock_result = lock_result_rslt?;
+
+  if !lock_result.success.is_empty() {
+    println!("Nodes locked: {}", lock_result.success.join(", "));
+  }
+
+  for failure in &lock_result.failure {
+    eprintln!(
+      "ERROR - Could not lock node '{}'. Reason: {}",
+      failure.id, failure.reason
+    );
+  }
+
+  if !lock_result.failure.is_empty() {
+    return Err(Error::Message(format!(
+      "Could not lock {} node/s",
+      lock_result.failure.len()
+    )));
+  }
+
+  Ok(())
+}
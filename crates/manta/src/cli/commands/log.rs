@@ -22,14 +22,9 @@ pub async fn exec(
   group_available_vec: &[Group],
   hosts_expression: &str,
   k8s: &K8sDetails,
-) {
-  let node_metadata_available_vec = backend
-    .get_node_metadata_available(shasta_token)
-    .await
-    .unwrap_or_else(|e| {
-      eprintln!("ERROR - Could not get node metadata. Reason:\n{e}\nExit");
-      std::process::exit(1);
-    });
+) -> Result<(), Error> {
+  let node_metadata_available_vec =
+    backend.get_node_metadata_available(shasta_token).await?;
 
   let xname_vec_rslt = common::node_ops::from_hosts_expression_to_xname_vec(
     hosts_expression,
@@ -88,19 +83,15 @@ pub async fn exec(
     Ok([_, ..]) => {
       // User input is an expression that expands to multiple nodes
       log::debug!("User input is a list of nodes");
-      eprintln!("ERROR - Can only operate a single node. Exit");
-      std::process::exit(1);
+      return Err(Error::Message("Can only operate a single node".to_string()));
     }
   };
 
-  let cfs_sessions_vec = cfs_sessions_vec_rslt.unwrap_or_else(|e| {
-    eprintln!("ERROR - Could not get CFS sessions. Reason:\n{e}\nExit");
-    std::process::exit(1);
-  });
+  let cfs_sessions_vec = cfs_sessions_vec_rslt?;
 
   if cfs_sessions_vec.is_empty() {
     println!("No CFS session found");
-    std::process::exit(0);
+    return Ok(());
   }
 
   log::info!(
@@ -122,19 +113,14 @@ pub async fn exec(
     group_available_vec,
   );
 
-  let log_rslt = print_cfs_session_logs(
+  print_cfs_session_logs(
     backend,
     shasta_token,
     site_name,
     cfs_session.name.as_ref().unwrap(),
     k8s,
   )
-  .await;
-
-  if let Err(e) = log_rslt {
-    eprintln!("ERROR - {e}. Exit");
-    std::process::exit(1);
-  }
+  .await
 }
 
 pub async fn print_cfs_session_logs(
@@ -1,11 +1,17 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, time::Instant};
 
-use manta_backend_dispatcher::interfaces::hsm::{
-  component::ComponentTrait, group::GroupTrait,
+use manta_backend_dispatcher::{
+  error::Error,
+  error::ErrorKind,
+  interfaces::hsm::{component::ComponentTrait, group::GroupTrait},
 };
 
 use crate::{
-  common::{self, audit::Audit, jwt_ops, kafka::Kafka},
+  common::{
+    self,
+    audit::Auditor,
+    journal::{Journal, JournalObject},
+  },
   manta_backend_dispatcher::StaticBackendDispatcher,
 };
 
@@ -17,18 +23,14 @@ pub async fn exec(
   hosts_expression: &str,
   nodryrun: bool,
   create_hsm_group: bool,
-  kafka_audit_opt: Option<&Kafka>,
-) {
+  audit_opt: Option<&Auditor>,
+  journal: &Journal,
+) -> Result<(), Error> {
   // Filter xnames to the ones members to HSM groups the user has access to
   //
   // Convert user input to xname
-  let node_metadata_available_vec = backend
-    .get_node_metadata_available(shasta_token)
-    .await
-    .unwrap_or_else(|e| {
-      eprintln!("ERROR - Could not get node metadata. Reason:\n{e}\nExit");
-      std::process::exit(1);
-    });
+  let node_metadata_available_vec =
+    backend.get_node_metadata_available(shasta_token).await?;
 
   let mut xname_to_move_vec =
     common::node_ops::from_hosts_expression_to_xname_vec(
@@ -36,18 +38,11 @@ pub async fn exec(
       false,
       node_metadata_available_vec,
     )
-    .await
-    .unwrap_or_else(|e| {
-      eprintln!(
-        "ERROR - Could not convert user input to list of xnames. Reason:\n{}",
-        e
-      );
-      std::process::exit(1);
-    });
+    .await?;
 
   if xname_to_move_vec.is_empty() {
     eprintln!("The list of nodes to operate is empty. Nothing to do. Exit");
-    std::process::exit(0);
+    return Ok(());
   }
 
   xname_to_move_vec.sort();
@@ -81,11 +76,34 @@ pub async fn exec(
   // Check if there are any xname to migrate/move and exit otherwise
   if xname_to_move_vec.is_empty() {
       println!("No hosts to move. Exit");
-      std::process::exit(0);
+      return Ok(());
   } */
 
   log::debug!("xnames to move: {:?}", xname_to_move_vec);
 
+  // Keep target and parent groups so the migration can be undone. Target
+  // groups which do not exist yet are created by the migration and deleted
+  // when it is undone
+  let mut hsm_group_name_vec: Vec<&String> = target_hsm_name_vec
+    .iter()
+    .chain(hsm_group_summary.keys())
+    .collect();
+  hsm_group_name_vec.sort();
+  hsm_group_name_vec.dedup();
+
+  let mut journal_object_vec = Vec::new();
+
+  for hsm_group_name in hsm_group_name_vec {
+    match backend.get_group(shasta_token, hsm_group_name).await {
+      Ok(group) => journal_object_vec.push(JournalObject::Group(group)),
+      Err(e) if e.kind() == ErrorKind::NotFound => journal_object_vec
+        .push(JournalObject::CreatedGroup(hsm_group_name.to_string())),
+      Err(e) => return Err(e),
+    }
+  }
+
+  journal.record(shasta_token, "group.migrate-members", journal_object_vec)?;
+
   for target_hsm_name in target_hsm_name_vec {
     if backend
       .get_group(shasta_token, &target_hsm_name)
@@ -101,19 +119,23 @@ pub async fn exec(
         );
         if nodryrun {
         } else {
-          log::error!(
+          return Err(Error::Message(
             "Dry-run selected, cannot create the new group continue."
-          );
-          std::process::exit(1);
+              .to_string(),
+          ));
         }
       } else {
-        log::error!("HSM group {} does not exist, but the option to create the group was NOT specificied, cannot continue.", target_hsm_name);
-        std::process::exit(1);
+        return Err(Error::Message(format!(
+          "HSM group {} does not exist, but the option to create the group was NOT specificied, cannot continue.",
+          target_hsm_name
+        )));
       }
     }
 
     // Migrate nodes
     for (parent_hsm_name, xname_to_move_vec) in &hsm_group_summary {
+      let start = Instant::now();
+
       let node_migration_rslt = backend
         .migrate_group_members(
           shasta_token,
@@ -126,6 +148,23 @@ pub async fn exec(
         )
         .await;
 
+      // Audit
+      if let Some(auditor) = audit_opt {
+        let audit_event = auditor
+          .new_event(shasta_token, "group.migrate-members")
+          .with_xnames(xname_to_move_vec)
+          .with_groups(&[parent_hsm_name, target_hsm_name])
+          .with_parameters(serde_json::json!({
+            "parent": parent_hsm_name,
+            "target": target_hsm_name,
+            "create_hsm_group": create_hsm_group,
+          }))
+          .with_result(&node_migration_rslt)
+          .with_duration(start.elapsed());
+
+        auditor.send_event(&audit_event).await;
+      }
+
       match node_migration_rslt {
         Ok((
           mut target_hsm_group_member_vec,
@@ -147,19 +186,5 @@ pub async fn exec(
     }
   }
 
-  // Audit
-  if let Some(kafka_audit) = kafka_audit_opt {
-    let username = jwt_ops::get_name(shasta_token).unwrap();
-    let user_id = jwt_ops::get_preferred_username(shasta_token).unwrap();
-
-    let msg_json = serde_json::json!(
-        { "user": {"id": user_id, "name": username}, "host": {"hostname": xname_to_move_vec}, "group": vec![parent_hsm_name_vec, target_hsm_name_vec], "message": format!("Migrate nodes from {:?} to {:?}", parent_hsm_name_vec, target_hsm_name_vec)});
-
-    let msg_data = serde_json::to_string(&msg_json)
-      .expect("Could not serialize audit message data");
-
-    if let Err(e) = kafka_audit.produce_message(msg_data.as_bytes()).await {
-      log::warn!("Failed producing messages: {}", e);
-    }
-  }
+  Ok(())
 }
@@ -1,5 +1,6 @@
-use manta_backend_dispatcher::interfaces::migrate_restore::MigrateRestoreTrait;
-use std::process::exit;
+use manta_backend_dispatcher::{
+  error::Error, interfaces::migrate_restore::MigrateRestoreTrait,
+};
 
 use crate::manta_backend_dispatcher::StaticBackendDispatcher;
 
@@ -8,29 +9,34 @@ pub async fn exec(
   shasta_token: &str,
   shasta_base_url: &str,
   shasta_root_cert: &[u8],
-  bos_file: Option<&String>,
-  cfs_file: Option<&String>,
-  hsm_file: Option<&String>,
-  ims_file: Option<&String>,
-  image_dir: Option<&String>,
+  backup_dir: &str,
+  bos_sessiontemplate_name_vec: &[String],
+  concurrency: usize,
+  assume_yes: bool,
+  overwrite_groups: bool,
   prehook: Option<&String>,
   posthook: Option<&String>,
-) {
+) -> Result<(), Error> {
   println!(
-        "Migrate_restore\n Prehook: {}\n Posthook: {}\n BOS_file: {}\n CFS_file: {}\n IMS_file: {}\n HSM_file: {}",
+        "Migrate_restore\n Prehook: {}\n Posthook: {}\n Backup folder: {}\n BOS Templates: {}",
         &prehook.unwrap_or(&"none".to_string()),
         &posthook.unwrap_or(&"none".to_string()),
-        bos_file.unwrap(),
-        cfs_file.unwrap(),
-        ims_file.unwrap(),
-        hsm_file.unwrap()
+        backup_dir,
+        if bos_sessiontemplate_name_vec.is_empty() {
+          "all".to_string()
+        } else {
+          bos_sessiontemplate_name_vec.join(", ")
+        },
     );
   if prehook.is_some() {
     match crate::common::hooks::check_hook_perms(prehook).await {
       Ok(_) => log::debug!("Pre-hook script exists and is executable."),
       Err(e) => {
-        log::error!("{}. File: {}", e, &prehook.unwrap());
-        exit(2);
+        return Err(Error::Message(format!(
+          "{}. File: {}",
+          e,
+          &prehook.unwrap()
+        )));
       }
     };
   }
@@ -38,8 +44,11 @@ pub async fn exec(
     match crate::common::hooks::check_hook_perms(posthook).await {
       Ok(_) => log::debug!("Post-hook script exists and is executable."),
       Err(e) => {
-        log::error!("{}. File: {}", e, &posthook.unwrap());
-        exit(2);
+        return Err(Error::Message(format!(
+          "{}. File: {}",
+          e,
+          &posthook.unwrap()
+        )));
       }
     };
   }
@@ -50,8 +59,7 @@ pub async fn exec(
     match crate::common::hooks::run_hook(prehook).await {
       Ok(_code) => log::debug!("Pre-hook script completed ok. RT={}", _code),
       Err(_error) => {
-        log::error!("{}", _error);
-        exit(2);
+        return Err(Error::Message(format!("{}", _error)));
       }
     };
   }
@@ -61,30 +69,25 @@ pub async fn exec(
       shasta_token,
       shasta_base_url,
       shasta_root_cert,
-      bos_file,
-      cfs_file,
-      hsm_file,
-      ims_file,
-      image_dir,
+      backup_dir,
+      bos_sessiontemplate_name_vec,
+      concurrency,
+      assume_yes,
+      overwrite_groups,
     )
     .await;
 
-  if migrate_restore_rslt.is_err() {
-    eprintln!("Error: {}", migrate_restore_rslt.err().unwrap());
-    exit(2);
-  }
+  migrate_restore_rslt?;
 
   if posthook.is_some() {
     println!("Running the post-hook {}", &posthook.unwrap());
     match crate::common::hooks::run_hook(posthook).await {
       Ok(_code) => log::debug!("Post-hook script completed ok. RT={}", _code),
       Err(_error) => {
-        log::error!("{}", _error);
-        exit(2);
+        return Err(Error::Message(format!("{}", _error)));
       }
     };
   }
-  println!("\nDone, the image bundle, HSM group, CFS configuration and BOS sessiontemplate have been restored.");
 
-  // ========================================================================================================
+  Ok(())
 }
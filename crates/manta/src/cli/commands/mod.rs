@@ -1,10 +1,15 @@
 pub mod add_group;
 pub mod add_hw_component_cluster;
+pub mod add_interface;
 pub mod add_kernel_parameters;
 pub mod add_node;
+pub mod add_nodemap;
 pub mod add_nodes_to_hsm_groups;
+pub mod add_nodes_to_partition;
+pub mod add_partition;
 pub mod apply_boot_cluster;
 pub mod apply_boot_node;
+pub mod apply_cluster_state;
 pub mod apply_ephemeral_env;
 pub mod apply_hw_cluster_pin;
 pub mod apply_hw_cluster_unpin;
@@ -22,34 +27,56 @@ pub mod config_unset_hsm;
 pub mod config_unset_parent_hsm;
 pub mod console_cfs_session_image_target_ansible;
 pub mod console_node;
+pub mod console_replay;
+pub mod console_tail;
 pub mod delete_and_cancel_session;
 pub mod delete_configurations_and_derivatives;
+pub mod delete_garbage;
 pub mod delete_group;
 pub mod delete_hw_component_cluster;
 pub mod delete_images;
+pub mod delete_interface;
 pub mod delete_kernel_parameters;
 pub mod delete_node;
+pub mod delete_nodemap;
+pub mod delete_partition;
 pub mod get_boot_parameters;
 pub mod get_cluster;
 pub mod get_configuration;
 pub mod get_group;
 pub mod get_hardware_cluster;
+pub mod get_hardware_fru;
 pub mod get_hardware_node;
 pub mod get_images;
+pub mod get_interfaces;
 pub mod get_kernel_parameters;
+pub mod get_locks;
+pub mod get_nodemap;
 pub mod get_nodes;
+pub mod get_orphans;
+pub mod get_partition;
 pub mod get_session;
 pub mod get_template;
+pub mod lock_nodes;
 pub mod log;
 pub mod migrate_backup;
 pub mod migrate_nodes_between_hsm_groups;
 pub mod migrate_restore;
+pub mod power_cap_get;
+pub mod power_cap_set;
+pub mod power_cap_snapshot;
 pub mod power_off_cluster;
 pub mod power_off_nodes;
 pub mod power_on_cluster;
 pub mod power_on_nodes;
 pub mod power_reset_cluster;
 pub mod power_reset_nodes;
+pub mod promote;
 pub mod remove_nodes_from_hsm_groups;
+pub mod remove_nodes_from_partition;
+pub mod undo;
+pub mod unlock_nodes;
 pub mod update_boot_parameters;
+pub mod update_interface;
+pub mod update_node;
 pub mod validate_local_repo;
@@ -0,0 +1,123 @@
+use std::time::Instant;
+
+use dialoguer::{theme::ColorfulTheme, Confirm};
+use manta_backend_dispatcher::{
+  error::Error,
+  interfaces::{
+    hsm::{component::ComponentTrait, group::GroupTrait},
+    pcs::PCSTrait,
+  },
+  types::pcs::power_cap::types::{
+    PowerCapControl, PowerCapPatch, PowerCapPatchComponent,
+  },
+};
+
+use crate::{
+  common::{self, audit::Auditor},
+  manta_backend_dispatcher::StaticBackendDispatcher,
+};
+use nodeset::NodeSet;
+
+pub async fn exec(
+  backend: &StaticBackendDispatcher,
+  shasta_token: &str,
+  hosts_expression: &str,
+  control_name: &str,
+  watts: usize,
+  assume_yes: bool,
+  output: &str,
+  audit_opt: Option<&Auditor>,
+) -> Result<(), Error> {
+  // Convert user input to xname
+  let node_metadata_available_vec =
+    backend.get_node_metadata_available(shasta_token).await?;
+
+  let mut xname_vec = common::node_ops::from_hosts_expression_to_xname_vec(
+    hosts_expression,
+    false,
+    node_metadata_available_vec,
+  )
+  .await?;
+
+  if xname_vec.is_empty() {
+    eprintln!("The list of nodes to operate is empty. Nothing to do. Exit");
+    return Ok(());
+  }
+
+  xname_vec.sort();
+  xname_vec.dedup();
+
+  let node_group: NodeSet = xname_vec.join(", ").parse().unwrap();
+
+  println!(
+    "Number of nodes: {}\nlist of nodes: {}",
+    node_group.len(),
+    node_group.to_string()
+  );
+
+  if !assume_yes {
+    if Confirm::with_theme(&ColorfulTheme::default())
+      .with_prompt(format!(
+        "Power cap '{}' of the nodes above will be set to {} watts. Please confirm to proceed?",
+        control_name, watts
+      ))
+      .interact()
+      .unwrap()
+    {
+      log::info!("Continue",);
+    } else {
+      println!("Cancelled by user. Aborting.");
+      return Ok(());
+    }
+  }
+
+  let power_cap = PowerCapPatch {
+    components: xname_vec
+      .iter()
+      .map(|xname| PowerCapPatchComponent {
+        xname: xname.clone(),
+        controls: vec![PowerCapControl {
+          name: control_name.to_string(),
+          value: watts,
+        }],
+      })
+      .collect(),
+  };
+
+  let start = Instant::now();
+
+  let power_cap_task_rslt =
+    backend.power_cap_patch_sync(shasta_token, &power_cap).await;
+
+  // Audit
+  if let Some(auditor) = audit_opt {
+    let group_map = backend
+      .get_group_map_and_filter_by_member_vec(
+        shasta_token,
+        &xname_vec
+          .iter()
+          .map(|member| member.as_str())
+          .collect::<Vec<_>>(),
+      )
+      .await
+      .unwrap_or_default();
+
+    let audit_event = auditor
+      .new_event(shasta_token, "power.cap.set")
+      .with_xnames(&xname_vec)
+      .with_groups(&group_map.keys().collect::<Vec<_>>())
+      .with_parameters(
+        serde_json::json!({ "control": control_name, "watts": watts }),
+      )
+      .with_result(&power_cap_task_rslt)
+      .with_duration(start.elapsed());
+
+    auditor.send_event(&audit_event).await;
+  }
+
+  let power_cap_task = power_cap_task_rslt?;
+
+  common::pcs_utils::print_power_cap_task(&power_cap_task, output);
+
+  Ok(())
+}
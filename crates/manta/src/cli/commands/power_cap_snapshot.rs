@@ -0,0 +1,42 @@
+use manta_backend_dispatcher::interfaces::{
+  hsm::component::ComponentTrait, pcs::PCSTrait,
+};
+
+use crate::{
+  common::{self},
+  manta_backend_dispatcher::StaticBackendDispatcher,
+};
+use manta_backend_dispatcher::error::Error;
+
+pub async fn exec(
+  backend: &StaticBackendDispatcher,
+  shasta_token: &str,
+  hosts_expression: &str,
+  output: &str,
+) -> Result<(), Error> {
+  // Convert user input to xname
+  let node_metadata_available_vec =
+    backend.get_node_metadata_available(shasta_token).await?;
+
+  let mut xname_vec = common::node_ops::from_hosts_expression_to_xname_vec(
+    hosts_expression,
+    false,
+    node_metadata_available_vec,
+  )
+  .await?;
+
+  if xname_vec.is_empty() {
+    eprintln!("The list of nodes to operate is empty. Nothing to do. Exit");
+    return Ok(());
+  }
+
+  xname_vec.sort();
+  xname_vec.dedup();
+
+  let power_cap_task =
+    backend.power_cap_snapshot(shasta_token, &xname_vec).await?;
+
+  common::pcs_utils::print_power_cap_task(&power_cap_task, output);
+
+  Ok(())
+}
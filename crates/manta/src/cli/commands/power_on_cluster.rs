@@ -1,12 +1,15 @@
+use std::time::Instant;
+
 use dialoguer::{theme::ColorfulTheme, Confirm};
 use manta_backend_dispatcher::interfaces::{
   hsm::group::GroupTrait, pcs::PCSTrait,
 };
 
 use crate::{
-  common::{self, audit::Audit, jwt_ops, kafka::Kafka},
+  common::{self, audit::Auditor},
   manta_backend_dispatcher::StaticBackendDispatcher,
 };
+use manta_backend_dispatcher::error::Error;
 use nodeset::NodeSet;
 
 pub async fn exec(
@@ -15,8 +18,8 @@ pub async fn exec(
   hsm_group_name_arg: &str,
   assume_yes: bool,
   output: &str,
-  kafka_audit_opt: Option<&Kafka>,
-) {
+  audit_opt: Option<&Auditor>,
+) -> Result<(), Error> {
   let xname_vec = backend
     .get_member_vec_from_group_name_vec(
       shasta_token,
@@ -25,6 +28,10 @@ pub async fn exec(
     .await
     .unwrap();
 
+  // Power management can't operate on nodes another operation is working on
+  common::lock_ops::check_nodes_not_locked(&backend, shasta_token, &xname_vec)
+    .await?;
+
   let node_group: NodeSet = xname_vec.join(", ").parse().unwrap();
 
   println!(
@@ -44,41 +51,30 @@ pub async fn exec(
       log::info!("Continue",);
     } else {
       println!("Cancelled by user. Aborting.");
-      std::process::exit(0);
+      return Ok(());
     }
   }
 
+  let start = Instant::now();
+
   let power_mgmt_summary_rslt =
     backend.power_on_sync(shasta_token, &xname_vec).await;
 
-  let power_mgmt_summary = match power_mgmt_summary_rslt {
-    Ok(value) => value,
-    Err(e) => {
-      eprintln!(
-        "ERROR - Could not power on node/s '{:?}'. Reason:\n{}",
-        xname_vec,
-        e.to_string()
-      );
-
-      std::process::exit(1);
-    }
-  };
-
-  common::pcs_utils::print_summary_table(power_mgmt_summary, output);
-
   // Audit
-  if let Some(kafka_audit) = kafka_audit_opt {
-    let username = jwt_ops::get_name(shasta_token).unwrap();
-    let user_id = jwt_ops::get_preferred_username(shasta_token).unwrap();
+  if let Some(auditor) = audit_opt {
+    let audit_event = auditor
+      .new_event(shasta_token, "power.on")
+      .with_groups(&[hsm_group_name_arg])
+      .with_xnames(&xname_vec)
+      .with_result(&power_mgmt_summary_rslt)
+      .with_duration(start.elapsed());
 
-    let msg_json = serde_json::json!(
-        { "user": {"id": user_id, "name": username}, "group": hsm_group_name_arg, "message": "power on"});
+    auditor.send_event(&audit_event).await;
+  }
 
-    let msg_data = serde_json::to_string(&msg_json)
-      .expect("Could not serialize audit message data");
+  let power_mgmt_summary = power_mgmt_summary_rslt?;
 
-    if let Err(e) = kafka_audit.produce_message(msg_data.as_bytes()).await {
-      log::warn!("Failed producing messages: {}", e);
-    }
-  }
+  common::pcs_utils::print_summary_table(power_mgmt_summary, output);
+
+  Ok(())
 }
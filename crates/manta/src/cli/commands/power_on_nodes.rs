@@ -1,3 +1,5 @@
+use std::time::Instant;
+
 use dialoguer::{theme::ColorfulTheme, Confirm};
 use manta_backend_dispatcher::interfaces::{
   hsm::{component::ComponentTrait, group::GroupTrait},
@@ -5,9 +7,10 @@ use manta_backend_dispatcher::interfaces::{
 };
 
 use crate::{
-  common::{self, audit::Audit, jwt_ops, kafka::Kafka},
+  common::{self, audit::Auditor},
   manta_backend_dispatcher::StaticBackendDispatcher,
 };
+use manta_backend_dispatcher::error::Error;
 use nodeset::NodeSet;
 
 pub async fn exec(
@@ -16,40 +19,32 @@ pub async fn exec(
   hosts_expression: &str,
   assume_yes: bool,
   output: &str,
-  kafka_audit_opt: Option<&Kafka>,
-) {
+  audit_opt: Option<&Auditor>,
+) -> Result<(), Error> {
   log::info!("Powering on nodes...");
   // Convert user input to xname
-  let node_metadata_available_vec = backend
-    .get_node_metadata_available(shasta_token)
-    .await
-    .unwrap_or_else(|e| {
-      eprintln!("ERROR - Could not get node metadata. Reason:\n{e}\nExit");
-      std::process::exit(1);
-    });
+  let node_metadata_available_vec =
+    backend.get_node_metadata_available(shasta_token).await?;
 
   let mut xname_vec = common::node_ops::from_hosts_expression_to_xname_vec(
     hosts_expression,
     false,
     node_metadata_available_vec,
   )
-  .await
-  .unwrap_or_else(|e| {
-    eprintln!(
-      "ERROR - Could not convert user input to list of xnames. Reason:\n{}",
-      e
-    );
-    std::process::exit(1);
-  });
+  .await?;
 
   if xname_vec.is_empty() {
     eprintln!("The list of nodes to operate is empty. Nothing to do. Exit");
-    std::process::exit(0);
+    return Ok(());
   }
 
   xname_vec.sort();
   xname_vec.dedup();
 
+  // Power management can't operate on nodes another operation is working on
+  common::lock_ops::check_nodes_not_locked(backend, shasta_token, &xname_vec)
+    .await?;
+
   let node_group: NodeSet = xname_vec.join(", ").parse().unwrap();
 
   println!(
@@ -69,33 +64,17 @@ pub async fn exec(
       log::info!("Continue",);
     } else {
       println!("Cancelled by user. Aborting.");
-      std::process::exit(0);
+      return Ok(());
     }
   }
 
+  let start = Instant::now();
+
   let power_mgmt_summary_rslt =
     backend.power_on_sync(shasta_token, &xname_vec).await;
 
-  let power_mgmt_summary = match power_mgmt_summary_rslt {
-    Ok(value) => value,
-    Err(e) => {
-      eprintln!(
-        "ERROR - Could not power on node/s '{:?}'. Reason:\n{}",
-        xname_vec,
-        e.to_string()
-      );
-
-      std::process::exit(1);
-    }
-  };
-
-  common::pcs_utils::print_summary_table(power_mgmt_summary, output);
-
   // Audit
-  if let Some(kafka_audit) = kafka_audit_opt {
-    let username = jwt_ops::get_name(shasta_token).unwrap();
-    let user_id = jwt_ops::get_preferred_username(shasta_token).unwrap();
-
+  if let Some(auditor) = audit_opt {
     let group_map = backend
       .get_group_map_and_filter_by_member_vec(
         shasta_token,
@@ -105,16 +84,21 @@ pub async fn exec(
           .collect::<Vec<_>>(),
       )
       .await
-      .unwrap();
-
-    let msg_json = serde_json::json!(
-        { "user": {"id": user_id, "name": username}, "host": {"hostname": xname_vec}, "group": group_map.keys().collect::<Vec<_>>(), "message": "power on"});
+      .unwrap_or_default();
 
-    let msg_data = serde_json::to_string(&msg_json)
-      .expect("Could not serialize audit message data");
+    let audit_event = auditor
+      .new_event(shasta_token, "power.on")
+      .with_xnames(&xname_vec)
+      .with_groups(&group_map.keys().collect::<Vec<_>>())
+      .with_result(&power_mgmt_summary_rslt)
+      .with_duration(start.elapsed());
 
-    if let Err(e) = kafka_audit.produce_message(msg_data.as_bytes()).await {
-      log::warn!("Failed producing messages: {}", e);
-    }
+    auditor.send_event(&audit_event).await;
   }
+
+  let power_mgmt_summary = power_mgmt_summary_rslt?;
+
+  common::pcs_utils::print_summary_table(power_mgmt_summary, output);
+
+  Ok(())
 }
@@ -0,0 +1,773 @@
+use std::{collections::HashMap, path::Path, time::Instant};
+
+use anyhow::{bail, Context, Error};
+use dialoguer::{theme::ColorfulTheme, Confirm};
+use manta_backend_dispatcher::{
+  contracts::BackendTrait,
+  error::ErrorKind,
+  interfaces::{
+    bos::ClusterTemplateTrait, cfs::CfsTrait, hsm::group::GroupTrait,
+    ims::ImsTrait, migrate_backup::MigrateBackupTrait,
+    migrate_restore::MigrateRestoreTrait,
+  },
+  types::{
+    capability::Capability,
+    cfs::cfs_configuration_response::CfsConfigurationResponse, Group,
+  },
+};
+
+use crate::{
+  common::{
+    self,
+    audit::Auditor,
+    config::types::MantaConfiguration,
+    journal::{Journal, JournalObject},
+  },
+  manta_backend_dispatcher::StaticBackendDispatcher,
+};
+
+use super::utils::{self, CommitSubstitution, PromotePlan, StagedBackup};
+
+/// Connection details of a site taking part in a promotion
+struct SiteContext {
+  name: String,
+  backend: StaticBackendDispatcher,
+  shasta_token: String,
+  shasta_base_url: String,
+  shasta_root_cert: Vec<u8>,
+  gitea_base_url: String,
+  vault_base_url: Option<String>,
+}
+
+/// Copies a BOS session template together with its CFS configuration, boot
+/// images and HSM groups from one site to another. Site specific values (VCS
+/// URLs, image ids and group members) are rewritten for the target site
+pub async fn exec(
+  configuration: &MantaConfiguration,
+  from_site_name: &str,
+  to_site_name: &str,
+  bos_sessiontemplate_name: &str,
+  concurrency: usize,
+  use_branch_head: bool,
+  dry_run: bool,
+  assume_yes: bool,
+  audit_opt: Option<&Auditor>,
+) -> Result<(), Error> {
+  if from_site_name == to_site_name {
+    bail!("Source and target sites must be different");
+  }
+
+  check_socks5_proxy(configuration, from_site_name, to_site_name)?;
+
+  let source = get_site_context(configuration, from_site_name).await?;
+  let target = get_site_context(configuration, to_site_name).await?;
+
+  if !source.backend.is_supported(&Capability::MigrateBackup) {
+    bail!(
+      "Operation '{}' not supported by site '{}' backend",
+      Capability::MigrateBackup,
+      source.name
+    );
+  }
+
+  if !target.backend.is_supported(&Capability::MigrateRestore) {
+    bail!(
+      "Operation '{}' not supported by site '{}' backend",
+      Capability::MigrateRestore,
+      target.name
+    );
+  }
+
+  // Objects in the source site
+  let bos_sessiontemplate = source
+    .backend
+    .get_template(
+      &source.shasta_token,
+      &source.shasta_base_url,
+      &source.shasta_root_cert,
+      Some(bos_sessiontemplate_name),
+    )
+    .await?
+    .into_iter()
+    .next()
+    .with_context(|| {
+      format!(
+        "BOS session template '{}' not found in site '{}'",
+        bos_sessiontemplate_name, source.name
+      )
+    })?;
+
+  if bos_sessiontemplate.boot_sets.is_none() {
+    bail!(
+      "BOS session template '{}' has no boot sets",
+      bos_sessiontemplate_name
+    );
+  }
+
+  let source_configuration_opt = match bos_sessiontemplate.get_confguration() {
+    Some(configuration_name) => Some(
+      source
+        .backend
+        .get_configuration(
+          &source.shasta_token,
+          &source.shasta_base_url,
+          &source.shasta_root_cert,
+          Some(&configuration_name),
+        )
+        .await?
+        .into_iter()
+        .next()
+        .with_context(|| {
+          format!(
+            "CFS configuration '{}' not found in site '{}'",
+            configuration_name, source.name
+          )
+        })?,
+    ),
+    None => None,
+  };
+
+  check_access(
+    &source,
+    &bos_sessiontemplate.get_target_hsm(),
+    &bos_sessiontemplate.get_target_xname(),
+  )
+  .await?;
+
+  let mut source_group_vec: Vec<Group> = Vec::new();
+  for group_name in bos_sessiontemplate.get_target_hsm() {
+    source_group_vec.push(
+      source
+        .backend
+        .get_group(&source.shasta_token, &group_name)
+        .await?,
+    );
+  }
+
+  let mut image_vec: Vec<(String, String)> = Vec::new();
+  for image_id in bos_sessiontemplate.get_image_vec() {
+    let image = source
+      .backend
+      .get_images(
+        &source.shasta_token,
+        &source.shasta_base_url,
+        &source.shasta_root_cert,
+        Some(image_id.as_str()),
+      )
+      .await?
+      .into_iter()
+      .next()
+      .with_context(|| {
+        format!("Image '{}' not found in site '{}'", image_id, source.name)
+      })?;
+
+    image_vec.push((image_id, image.name));
+  }
+
+  // Objects in the target site the promotion overwrites. Only objects not
+  // found are missing, any other error stops the promotion since the object
+  // could exist and be overwritten without being journaled
+  let target_template_opt = match target
+    .backend
+    .get_template(
+      &target.shasta_token,
+      &target.shasta_base_url,
+      &target.shasta_root_cert,
+      Some(bos_sessiontemplate_name),
+    )
+    .await
+  {
+    Ok(bos_sessiontemplate_vec) => bos_sessiontemplate_vec.into_iter().next(),
+    Err(e) if e.kind() == ErrorKind::NotFound => None,
+    Err(e) => return Err(e.into()),
+  };
+
+  let target_configuration_opt = match &source_configuration_opt {
+    Some(source_configuration) => match target
+      .backend
+      .get_configuration(
+        &target.shasta_token,
+        &target.shasta_base_url,
+        &target.shasta_root_cert,
+        Some(&source_configuration.name),
+      )
+      .await
+    {
+      Ok(cfs_configuration_vec) => cfs_configuration_vec.into_iter().next(),
+      Err(e) if e.kind() == ErrorKind::NotFound => None,
+      Err(e) => return Err(e.into()),
+    },
+    None => None,
+  };
+
+  let mut target_group_vec: Vec<Group> = Vec::new();
+  for source_group in &source_group_vec {
+    match target
+      .backend
+      .get_group(&target.shasta_token, &source_group.label)
+      .await
+    {
+      Ok(target_group) => target_group_vec.push(target_group),
+      Err(e) if e.kind() == ErrorKind::NotFound => {}
+      Err(e) => return Err(e.into()),
+    }
+  }
+
+  // Groups missing in the target site are created empty
+  check_access(
+    &target,
+    &target_group_vec
+      .iter()
+      .map(|target_group| target_group.label.clone())
+      .collect::<Vec<String>>(),
+    &bos_sessiontemplate.get_target_xname(),
+  )
+  .await?;
+
+  // Objects as they will be in the target site
+  let (configuration_opt, commit_substitution_vec) =
+    match &source_configuration_opt {
+      Some(source_configuration) => {
+        let (configuration, commit_substitution_vec) = promote_configuration(
+          source_configuration,
+          &source,
+          &target,
+          use_branch_head,
+        )
+        .await?;
+
+        (Some(configuration), commit_substitution_vec)
+      }
+      None => (None, Vec::new()),
+    };
+
+  let group_vec: Vec<Group> = source_group_vec
+    .iter()
+    .map(|source_group| {
+      utils::get_promoted_group(
+        source_group,
+        target_group_vec
+          .iter()
+          .find(|target_group| target_group.label == source_group.label),
+      )
+    })
+    .collect();
+
+  // Images get their ids once registered in the target site
+  let image_id_placeholder_map: HashMap<String, String> = image_vec
+    .iter()
+    .map(|(image_id, image_name)| {
+      (
+        image_id.clone(),
+        format!("<new id of image '{}'>", image_name),
+      )
+    })
+    .collect();
+
+  let plan = PromotePlan {
+    configuration: configuration_opt,
+    commit_substitution_vec,
+    group_vec,
+    image_vec,
+    template: utils::replace_image_ids(
+      &bos_sessiontemplate,
+      &image_id_placeholder_map,
+    ),
+  };
+
+  utils::print_plan(
+    &source.name,
+    &target.name,
+    &plan,
+    target_configuration_opt.as_ref(),
+    &target_group_vec,
+    target_template_opt.as_ref(),
+  );
+
+  if dry_run {
+    println!("Dry-run enabled. No changes persisted into the system");
+    return Ok(());
+  }
+
+  if !assume_yes {
+    if Confirm::with_theme(&ColorfulTheme::default())
+      .with_prompt(format!("Apply the plan above to site '{}'?", target.name))
+      .interact()
+      .unwrap()
+    {
+      log::info!("Continue",);
+    } else {
+      println!("Cancelled by user. Aborting.");
+      return Ok(());
+    }
+  }
+
+  let start = Instant::now();
+
+  // Keep the objects about to be overwritten so 'manta undo' can restore
+  // them in the target site
+  let mut journal_object_vec: Vec<JournalObject> = target_group_vec
+    .iter()
+    .cloned()
+    .map(JournalObject::Group)
+    .collect();
+  journal_object_vec.extend(
+    target_configuration_opt
+      .clone()
+      .map(JournalObject::CfsConfiguration),
+  );
+  journal_object_vec.extend(
+    target_template_opt
+      .clone()
+      .map(JournalObject::BosSessionTemplate),
+  );
+
+  if !journal_object_vec.is_empty() {
+    Journal::new(&target.name).record(
+      &target.shasta_token,
+      "site.promote",
+      journal_object_vec,
+    )?;
+  }
+
+  let result = promote(
+    &source,
+    &target,
+    bos_sessiontemplate_name,
+    &plan,
+    concurrency,
+  )
+  .await;
+
+  // Audit
+  if let Some(auditor) = audit_opt {
+    let audit_event = auditor
+      .new_event(&target.shasta_token, "site.promote")
+      .with_groups(
+        &plan
+          .group_vec
+          .iter()
+          .map(|group| group.label.as_str())
+          .collect::<Vec<&str>>(),
+      )
+      .with_parameters(serde_json::json!({
+        "from_site": source.name,
+        "to_site": target.name,
+        "template": bos_sessiontemplate_name,
+        "configuration": plan.configuration.as_ref().map(|configuration| &configuration.name),
+        "use_branch_head": use_branch_head,
+        "images": plan.image_vec,
+      }))
+      .with_result(&result)
+      .with_duration(start.elapsed());
+
+    auditor.send_event(&audit_event).await;
+  }
+
+  result?;
+
+  println!(
+    "BOS session template '{}' promoted from '{}' to '{}'",
+    bos_sessiontemplate_name, source.name, target.name
+  );
+
+  Ok(())
+}
+
+async fn get_site_context(
+  configuration: &MantaConfiguration,
+  site_name: &str,
+) -> Result<SiteContext, Error> {
+  let site = configuration.sites.get(site_name).with_context(|| {
+    format!("Site '{}' not found in configuration", site_name)
+  })?;
+
+  let shasta_base_url = common::config::get_shasta_api_url(site)?;
+
+  let shasta_root_cert =
+    common::config::get_csm_root_cert_content(&site.root_ca_cert_file)
+      .with_context(|| {
+        format!(
+          "CA public root file '{}' of site '{}' not found",
+          site.root_ca_cert_file, site_name
+        )
+      })?;
+
+  let backend = StaticBackendDispatcher::new(
+    &site.backend,
+    &shasta_base_url,
+    &shasta_root_cert,
+  );
+
+  let shasta_token =
+    backend.get_api_token(site_name).await.with_context(|| {
+      format!("Could not authenticate to site '{}'", site_name)
+    })?;
+
+  Ok(SiteContext {
+    name: site_name.to_string(),
+    backend,
+    shasta_token,
+    shasta_base_url,
+    shasta_root_cert,
+    gitea_base_url: common::config::get_gitea_base_url(site),
+    vault_base_url: common::config::get_vault_base_url(site).cloned(),
+  })
+}
+
+/// Fails if the user can't access any of the groups or nodes in the site
+async fn check_access(
+  site: &SiteContext,
+  group_name_vec: &[String],
+  xname_vec: &[String],
+) -> Result<(), Error> {
+  let group_name_available_vec = site
+    .backend
+    .get_group_name_available(&site.shasta_token)
+    .await?;
+
+  let group_name_not_available_vec: Vec<&str> = group_name_vec
+    .iter()
+    .filter(|group_name| !group_name_available_vec.contains(group_name))
+    .map(String::as_str)
+    .collect();
+
+  if !group_name_not_available_vec.is_empty() {
+    bail!(
+      "Can't access HSM groups '{}' in site '{}'",
+      group_name_not_available_vec.join(", "),
+      site.name
+    );
+  }
+
+  if xname_vec.is_empty() {
+    return Ok(());
+  }
+
+  let xname_available_vec = site
+    .backend
+    .get_member_vec_from_group_name_vec(
+      &site.shasta_token,
+      group_name_available_vec,
+    )
+    .await?;
+
+  let xname_not_available_vec: Vec<&str> = xname_vec
+    .iter()
+    .filter(|xname| !xname_available_vec.contains(xname))
+    .map(String::as_str)
+    .collect();
+
+  if !xname_not_available_vec.is_empty() {
+    bail!(
+      "Can't access nodes '{}' in site '{}'",
+      xname_not_available_vec.join(", "),
+      site.name
+    );
+  }
+
+  Ok(())
+}
+
+/// Backends read the SOCKS5 proxy from the environment manta sets up at
+/// start for the active site, therefore requests to both sites go through
+/// that proxy. The environment is not changed while requests are running
+fn check_socks5_proxy(
+  configuration: &MantaConfiguration,
+  from_site_name: &str,
+  to_site_name: &str,
+) -> Result<(), Error> {
+  let active_socks5_proxy_opt = std::env::var("SOCKS5")
+    .ok()
+    .filter(|socks5_proxy| !socks5_proxy.is_empty());
+
+  for site_name in [from_site_name, to_site_name] {
+    let socks5_proxy_opt = configuration
+      .sites
+      .get(site_name)
+      .and_then(|site| site.socks5_proxy.clone())
+      .filter(|socks5_proxy| !socks5_proxy.is_empty());
+
+    if socks5_proxy_opt != active_socks5_proxy_opt {
+      bail!(
+        "Site '{}' uses a different SOCKS5 proxy than the active site. Switch to a site using the same proxy to promote from '{}' to '{}'",
+        site_name,
+        from_site_name,
+        to_site_name
+      );
+    }
+  }
+
+  Ok(())
+}
+
+/// CFS configuration as it will be in the target site. Layers are pointed to
+/// the target VCS and their commits must exist there. If `use_branch_head` is
+/// set, layers whose commit is missing use the commit their branch points to
+/// in the target VCS instead, these substitutions are returned as well
+async fn promote_configuration(
+  source_configuration: &CfsConfigurationResponse,
+  source: &SiteContext,
+  target: &SiteContext,
+  use_branch_head: bool,
+) -> Result<(CfsConfigurationResponse, Vec<CommitSubstitution>), Error> {
+  let vault_base_url = target.vault_base_url.as_ref().with_context(|| {
+    format!(
+      "Site '{}' has no vault configured, can't check the commits in its VCS",
+      target.name
+    )
+  })?;
+
+  let gitea_token = crate::common::vault::http_client::fetch_shasta_vcs_token(
+    &target.shasta_token,
+    vault_base_url,
+    &target.name,
+  )
+  .await
+  .with_context(|| {
+    format!("Could not get VCS token for site '{}'", target.name)
+  })?;
+
+  let mut configuration = source_configuration.clone();
+  let mut commit_substitution_vec = Vec::new();
+
+  for layer in configuration.layers.iter_mut() {
+    layer.clone_url = utils::rewrite_clone_url(
+      &layer.clone_url,
+      &source.gitea_base_url,
+      &target.gitea_base_url,
+    );
+
+    let Some(commit) = layer.commit.clone() else {
+      // Branch only layers are resolved by the target site
+      continue;
+    };
+
+    let repo_name = utils::get_repo_name(&layer.clone_url);
+
+    let commit_exists = csm_rs::common::gitea::http_client::get_commit_details(
+      &format!("{}/", target.gitea_base_url),
+      &format!("cray/{}", repo_name),
+      &commit,
+      &gitea_token,
+      &target.shasta_root_cert,
+    )
+    .await
+    .is_ok();
+
+    if commit_exists {
+      continue;
+    }
+
+    if !use_branch_head {
+      bail!(
+        "Commit '{}' of layer '{}' not found in site '{}' VCS. Push repository '{}' to site '{}' first or use '--use-branch-head' to promote the commit its branch points to",
+        commit,
+        layer.name,
+        target.name,
+        repo_name,
+        target.name
+      );
+    }
+
+    let branch_commit_opt = match &layer.branch {
+      Some(branch) => get_branch_commit(
+        &target.gitea_base_url,
+        &gitea_token,
+        &target.shasta_root_cert,
+        repo_name,
+        branch,
+      )
+      .await
+      .map(|branch_commit| (branch, branch_commit)),
+      None => None,
+    };
+
+    match branch_commit_opt {
+      Some((branch, branch_commit)) => {
+        commit_substitution_vec.push(CommitSubstitution {
+          layer_name: layer.name.clone(),
+          branch: branch.clone(),
+          source_commit: commit,
+          target_commit: branch_commit.clone(),
+        });
+        layer.commit = Some(branch_commit);
+      }
+      None => bail!(
+        "Commit '{}' of layer '{}' not found in site '{}' VCS and the layer has no branch in it. Push repository '{}' to site '{}' first",
+        commit,
+        layer.name,
+        target.name,
+        repo_name,
+        target.name
+      ),
+    }
+  }
+
+  if let Some(additional_inventory) =
+    configuration.additional_inventory.as_mut()
+  {
+    additional_inventory.clone_url = utils::rewrite_clone_url(
+      &additional_inventory.clone_url,
+      &source.gitea_base_url,
+      &target.gitea_base_url,
+    );
+  }
+
+  Ok((configuration, commit_substitution_vec))
+}
+
+/// Commit a branch points to in a VCS repository
+async fn get_branch_commit(
+  gitea_base_url: &str,
+  gitea_token: &str,
+  shasta_root_cert: &[u8],
+  repo_name: &str,
+  branch: &str,
+) -> Option<String> {
+  let ref_vec = csm_rs::common::gitea::http_client::get_all_refs(
+    gitea_base_url,
+    gitea_token,
+    repo_name,
+    shasta_root_cert,
+  )
+  .await
+  .ok()?;
+
+  ref_vec
+    .iter()
+    .find(|ref_details| {
+      ref_details["ref"].as_str()
+        == Some(format!("refs/heads/{}", branch).as_str())
+    })
+    .and_then(|ref_details| ref_details["object"]["sha"].as_str())
+    .map(str::to_string)
+}
+
+/// Copies the objects through a backup of the source site restored in the
+/// target site. The backup is staged locally so an interrupted promotion
+/// resumes where it stopped when run again
+async fn promote(
+  source: &SiteContext,
+  target: &SiteContext,
+  bos_sessiontemplate_name: &str,
+  plan: &PromotePlan,
+  concurrency: usize,
+) -> Result<(), Error> {
+  let staging_dir =
+    common::config::get_default_manta_promote_dir_path().join(format!(
+      "{}-{}-{}",
+      source.name, target.name, bos_sessiontemplate_name
+    ));
+
+  std::fs::create_dir_all(&staging_dir).with_context(|| {
+    format!(
+      "Could not create staging directory '{}'",
+      staging_dir.display()
+    )
+  })?;
+
+  let staging_dir_str = staging_dir.to_string_lossy().to_string();
+
+  println!("\nCopying objects from site '{}'...", source.name);
+
+  source
+    .backend
+    .migrate_backup(
+      &source.shasta_token,
+      &source.shasta_base_url,
+      &source.shasta_root_cert,
+      &[bos_sessiontemplate_name.to_string()],
+      &[],
+      &staging_dir_str,
+      concurrency,
+    )
+    .await?;
+
+  rewrite_staged_files(&staging_dir, bos_sessiontemplate_name, plan)?;
+
+  println!("\nCreating objects in site '{}'...", target.name);
+
+  target
+    .backend
+    .migrate_restore(
+      &target.shasta_token,
+      &target.shasta_base_url,
+      &target.shasta_root_cert,
+      &staging_dir_str,
+      &[bos_sessiontemplate_name.to_string()],
+      concurrency,
+      true,
+      // Groups keep the members they have in the target site
+      false,
+    )
+    .await?;
+
+  // Nothing left to resume, a later promotion must start from scratch
+  if let Err(e) = std::fs::remove_dir_all(&staging_dir) {
+    log::warn!(
+      "Could not remove staging directory '{}'. Reason:\n{}",
+      staging_dir.display(),
+      e
+    );
+  }
+
+  Ok(())
+}
+
+/// Replaces the CFS configuration and HSM groups in the backup with their
+/// versions for the target site
+fn rewrite_staged_files(
+  staging_dir: &Path,
+  bos_sessiontemplate_name: &str,
+  plan: &PromotePlan,
+) -> Result<(), Error> {
+  let backup_manifest_path = staging_dir.join("backup-manifest.json");
+
+  let staged_backup: StagedBackup = serde_json::from_str(
+    &std::fs::read_to_string(&backup_manifest_path).with_context(|| {
+      format!(
+        "Could not read backup manifest '{}'",
+        backup_manifest_path.display()
+      )
+    })?,
+  )
+  .with_context(|| {
+    format!(
+      "Could not parse backup manifest '{}'",
+      backup_manifest_path.display()
+    )
+  })?;
+
+  let staged_session_template = staged_backup
+    .session_templates
+    .iter()
+    .find(|staged_session_template| {
+      staged_session_template.name == bos_sessiontemplate_name
+    })
+    .with_context(|| {
+      format!(
+        "BOS session template '{}' not found in backup manifest '{}'",
+        bos_sessiontemplate_name,
+        backup_manifest_path.display()
+      )
+    })?;
+
+  if let (Some(cfs_file), Some(configuration)) =
+    (&staged_session_template.cfs_file, &plan.configuration)
+  {
+    std::fs::write(
+      staging_dir.join(cfs_file),
+      serde_json::to_string_pretty(configuration)?,
+    )?;
+  }
+
+  if let Some(hsm_file) = &staged_session_template.hsm_file {
+    std::fs::write(
+      staging_dir.join(hsm_file),
+      serde_json::to_string_pretty(&plan.group_vec)?,
+    )?;
+  }
+
+  Ok(())
+}
@@ -0,0 +1,130 @@
+use std::collections::HashMap;
+
+use manta_backend_dispatcher::types::{
+  bos::session_template::BosSessionTemplate, Group, Member,
+};
+use serde_json::json;
+
+use crate::cli::commands::promote::utils::{
+  diff_json, get_promoted_group, get_repo_name, replace_image_ids,
+  rewrite_clone_url,
+};
+
+#[test]
+fn test_rewrite_clone_url_only_rewrites_source_vcs() {
+  let source_gitea_base_url = "https://api.cmn.alps.cscs.ch/vcs";
+  let target_gitea_base_url = "https://api.cmn.prealps.cscs.ch/vcs";
+
+  assert_eq!(
+    rewrite_clone_url(
+      "https://api.cmn.alps.cscs.ch/vcs/cray/cos-config-management.git",
+      source_gitea_base_url,
+      target_gitea_base_url
+    ),
+    "https://api.cmn.prealps.cscs.ch/vcs/cray/cos-config-management.git"
+  );
+
+  assert_eq!(
+    rewrite_clone_url(
+      "https://api-gw-service-nmn.local/vcs/cray/cos-config-management.git",
+      source_gitea_base_url,
+      target_gitea_base_url
+    ),
+    "https://api-gw-service-nmn.local/vcs/cray/cos-config-management.git"
+  );
+}
+
+#[test]
+fn test_get_repo_name() {
+  assert_eq!(
+    get_repo_name(
+      "https://api-gw-service-nmn.local/vcs/cray/cos-config-management.git"
+    ),
+    "cos-config-management"
+  );
+}
+
+#[test]
+fn test_promoted_group_keeps_target_members() {
+  let source_group = Group {
+    label: "zinal".to_string(),
+    description: Some("zinal cluster".to_string()),
+    tags: None,
+    members: Some(Member {
+      ids: Some(vec!["x1001c1s5b0n0".to_string()]),
+    }),
+    exclusive_group: None,
+  };
+
+  let target_group = Group {
+    label: "zinal".to_string(),
+    description: None,
+    tags: None,
+    members: Some(Member {
+      ids: Some(vec!["x3000c0s1b0n0".to_string()]),
+    }),
+    exclusive_group: None,
+  };
+
+  let promoted_group = get_promoted_group(&source_group, Some(&target_group));
+  assert_eq!(promoted_group.get_members(), vec!["x3000c0s1b0n0"]);
+  assert_eq!(promoted_group.description, source_group.description);
+
+  let promoted_group = get_promoted_group(&source_group, None);
+  assert!(promoted_group.get_members().is_empty());
+}
+
+#[test]
+fn test_replace_image_ids_in_boot_sets() {
+  let bos_sessiontemplate: BosSessionTemplate = serde_json::from_value(json!({
+    "name": "zinal-template",
+    "boot_sets": {
+      "compute": {
+        "path": "s3://boot-images/59e0180a-3fdd-4936-bba7-14ba914ffd34/manifest.json",
+        "type": "s3"
+      }
+    }
+  }))
+  .unwrap();
+
+  let image_id_map = HashMap::from([(
+    "59e0180a-3fdd-4936-bba7-14ba914ffd34".to_string(),
+    "a5c3d9b4-6f0e-4b8a-9c1d-2e7f8a9b0c1d".to_string(),
+  )]);
+
+  assert_eq!(
+    replace_image_ids(&bos_sessiontemplate, &image_id_map).get_path_vec(),
+    vec!["s3://boot-images/a5c3d9b4-6f0e-4b8a-9c1d-2e7f8a9b0c1d/manifest.json"]
+  );
+}
+
+#[test]
+fn test_diff_json() {
+  let current = json!({
+    "name": "zinal-cos-config",
+    "layers": [
+      { "name": "cos", "commit": "aaa" },
+      { "name": "slurm", "commit": "bbb" }
+    ]
+  });
+
+  let desired = json!({
+    "name": "zinal-cos-config",
+    "layers": [
+      { "name": "cos", "commit": "ccc", "branch": "main" }
+    ]
+  });
+
+  assert_eq!(
+    diff_json(Some(&current), &desired),
+    vec![
+      "+ layers[0].branch: \"main\"",
+      "~ layers[0].commit: \"aaa\" -> \"ccc\"",
+      "- layers[1].commit: \"bbb\"",
+      "- layers[1].name: \"slurm\"",
+    ]
+  );
+
+  assert!(diff_json(Some(&desired), &desired).is_empty());
+  assert_eq!(diff_json(None, &json!({ "name": "zinal" })).len(), 1);
+}
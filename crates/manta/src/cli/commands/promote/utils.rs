@@ -0,0 +1,263 @@
+use std::collections::{BTreeMap, HashMap};
+
+use manta_backend_dispatcher::types::{
+  bos::session_template::BosSessionTemplate,
+  cfs::cfs_configuration_response::CfsConfigurationResponse, Group, Member,
+};
+use serde::Deserialize;
+use serde_json::Value;
+
+/// Entry of the 'backup-manifest.json' file written by 'migrate backup'. Only
+/// the fields 'promote' needs to find the staged files
+#[derive(Deserialize, Debug)]
+pub struct StagedSessionTemplate {
+  pub name: String,
+  pub cfs_file: Option<String>,
+  pub hsm_file: Option<String>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct StagedBackup {
+  pub session_templates: Vec<StagedSessionTemplate>,
+}
+
+/// CFS configuration layer whose commit is missing in the target site VCS
+/// and is promoted with the commit its branch points to there instead
+#[derive(Debug)]
+pub struct CommitSubstitution {
+  pub layer_name: String,
+  pub branch: String,
+  pub source_commit: String,
+  pub target_commit: String,
+}
+
+/// Objects to create or update in the target site
+#[derive(Debug)]
+pub struct PromotePlan {
+  pub configuration: Option<CfsConfigurationResponse>,
+  pub commit_substitution_vec: Vec<CommitSubstitution>,
+  pub group_vec: Vec<Group>,
+  /// Source image id and name of the images to copy
+  pub image_vec: Vec<(String, String)>,
+  pub template: BosSessionTemplate,
+}
+
+/// Clone URL of a layer in the target site. Only URLs pointing to the source
+/// site VCS are rewritten, the internal VCS URL is the same in every site
+pub fn rewrite_clone_url(
+  clone_url: &str,
+  source_gitea_base_url: &str,
+  target_gitea_base_url: &str,
+) -> String {
+  match clone_url.strip_prefix(source_gitea_base_url) {
+    Some(repo_path) => format!("{}{}", target_gitea_base_url, repo_path),
+    None => clone_url.to_string(),
+  }
+}
+
+/// Repository name of a clone URL eg
+/// 'https://api-gw-service-nmn.local/vcs/cray/cos-config-management.git' ->
+/// 'cos-config-management'
+pub fn get_repo_name(clone_url: &str) -> &str {
+  clone_url
+    .trim_end_matches('/')
+    .trim_end_matches(".git")
+    .rsplit('/')
+    .next()
+    .unwrap_or_default()
+}
+
+/// Group as it will be in the target site. Members are xnames and are
+/// specific to each site, the ones in the target site are kept and new groups
+/// are created empty
+pub fn get_promoted_group(
+  source_group: &Group,
+  target_group_opt: Option<&Group>,
+) -> Group {
+  let member_vec = target_group_opt
+    .map(|target_group| target_group.get_members())
+    .unwrap_or_default();
+
+  Group {
+    members: Some(Member {
+      ids: Some(member_vec),
+    }),
+    ..source_group.clone()
+  }
+}
+
+/// Points the boot sets of a BOS session template to other images
+pub fn replace_image_ids(
+  bos_sessiontemplate: &BosSessionTemplate,
+  image_id_map: &HashMap<String, String>,
+) -> BosSessionTemplate {
+  let mut bos_sessiontemplate = bos_sessiontemplate.clone();
+
+  for boot_set in bos_sessiontemplate
+    .boot_sets
+    .iter_mut()
+    .flat_map(|boot_set_map| boot_set_map.values_mut())
+  {
+    if let Some(path) = boot_set.path.as_mut() {
+      for (image_id, new_image_id) in image_id_map {
+        *path = path.replace(image_id, new_image_id);
+      }
+    }
+  }
+
+  bos_sessiontemplate
+}
+
+/// Field level differences between the current and the desired version of an
+/// object. Lines start with '+' for fields added, '-' for fields removed and
+/// '~' for fields changed. Every field is added if there is no current object
+pub fn diff_json(current_opt: Option<&Value>, desired: &Value) -> Vec<String> {
+  let mut current_field_map = BTreeMap::new();
+  if let Some(current) = current_opt {
+    flatten_json("", current, &mut current_field_map);
+  }
+
+  let mut desired_field_map = BTreeMap::new();
+  flatten_json("", desired, &mut desired_field_map);
+
+  let mut diff_vec = Vec::new();
+
+  for (field, desired_value) in &desired_field_map {
+    match current_field_map.get(field) {
+      None => diff_vec.push(format!("+ {}: {}", field, desired_value)),
+      Some(current_value) if current_value != desired_value => diff_vec.push(
+        format!("~ {}: {} -> {}", field, current_value, desired_value),
+      ),
+      Some(_) => {}
+    }
+  }
+
+  for (field, current_value) in &current_field_map {
+    if !desired_field_map.contains_key(field) {
+      diff_vec.push(format!("- {}: {}", field, current_value));
+    }
+  }
+
+  diff_vec
+}
+
+/// Flattens a JSON value into 'path -> value' pairs eg
+/// '{"layers": [{"name": "cos"}]}' -> 'layers[0].name -> "cos"'
+fn flatten_json(
+  prefix: &str,
+  value: &Value,
+  field_map: &mut BTreeMap<String, String>,
+) {
+  match value {
+    Value::Object(object) => {
+      for (key, value) in object {
+        let field = if prefix.is_empty() {
+          key.clone()
+        } else {
+          format!("{}.{}", prefix, key)
+        };
+
+        flatten_json(&field, value, field_map);
+      }
+    }
+    Value::Array(array) => {
+      for (index, value) in array.iter().enumerate() {
+        flatten_json(&format!("{}[{}]", prefix, index), value, field_map);
+      }
+    }
+    Value::Null => {}
+    _ => {
+      field_map.insert(prefix.to_string(), value.to_string());
+    }
+  }
+}
+
+/// Prints the differences between the objects in the target site and the
+/// ones the promotion will leave
+pub fn print_plan(
+  from_site: &str,
+  to_site: &str,
+  plan: &PromotePlan,
+  target_configuration_opt: Option<&CfsConfigurationResponse>,
+  target_group_vec: &[Group],
+  target_template_opt: Option<&BosSessionTemplate>,
+) {
+  println!("Plan to promote from '{}' to '{}':", from_site, to_site);
+
+  if let Some(configuration) = &plan.configuration {
+    // Set by the backend, not part of the configuration
+    let to_value = |configuration: &CfsConfigurationResponse| {
+      let mut value = serde_json::to_value(configuration).unwrap_or_default();
+      if let Some(object) = value.as_object_mut() {
+        object.remove("last_updated");
+      }
+      value
+    };
+
+    print_object_diff(
+      "CFS configuration",
+      &configuration.name,
+      target_configuration_opt.map(to_value).as_ref(),
+      &to_value(configuration),
+    );
+
+    for commit_substitution in &plan.commit_substitution_vec {
+      println!(
+        "      ! layer '{}': commit '{}' not found in '{}', using commit '{}' branch '{}' points to",
+        commit_substitution.layer_name,
+        commit_substitution.source_commit,
+        to_site,
+        commit_substitution.target_commit,
+        commit_substitution.branch
+      );
+    }
+  }
+
+  for group in &plan.group_vec {
+    print_object_diff(
+      "group",
+      &group.label,
+      target_group_vec
+        .iter()
+        .find(|target_group| target_group.label == group.label)
+        .and_then(|target_group| serde_json::to_value(target_group).ok())
+        .as_ref(),
+      &serde_json::to_value(group).unwrap_or_default(),
+    );
+  }
+
+  for (image_id, image_name) in &plan.image_vec {
+    println!("  + image '{}' (copy of '{}')", image_name, image_id);
+  }
+
+  print_object_diff(
+    "BOS session template",
+    &plan.template.name.clone().unwrap_or_default(),
+    target_template_opt
+      .and_then(|target_template| serde_json::to_value(target_template).ok())
+      .as_ref(),
+    &serde_json::to_value(&plan.template).unwrap_or_default(),
+  );
+}
+
+fn print_object_diff(
+  kind: &str,
+  name: &str,
+  current_opt: Option<&Value>,
+  desired: &Value,
+) {
+  let diff_vec = diff_json(current_opt, desired);
+
+  if current_opt.is_none() {
+    println!("  + {} '{}'", kind, name);
+  } else if diff_vec.is_empty() {
+    println!("  = {} '{}' (no changes)", kind, name);
+    return;
+  } else {
+    println!("  ~ {} '{}'", kind, name);
+  }
+
+  for diff in diff_vec {
+    println!("      {}", diff);
+  }
+}
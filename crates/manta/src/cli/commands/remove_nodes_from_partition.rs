@@ -0,0 +1,104 @@
+use std::time::Instant;
+
+use dialoguer::{theme::ColorfulTheme, Confirm};
+use manta_backend_dispatcher::interfaces::hsm::{
+  component::ComponentTrait, partition::PartitionTrait,
+};
+
+use crate::{
+  common::{self, audit::Auditor},
+  manta_backend_dispatcher::StaticBackendDispatcher,
+};
+use manta_backend_dispatcher::error::Error;
+
+/// Remove a list of xnames from a partition
+pub async fn exec(
+  backend: &StaticBackendDispatcher,
+  shasta_token: &str,
+  partition_name: &str,
+  hosts_expression: &str,
+  assume_yes: bool,
+  dryrun: bool,
+  audit_opt: Option<&Auditor>,
+) -> Result<(), Error> {
+  // Convert user input to xname
+  let node_metadata_available_vec =
+    backend.get_node_metadata_available(shasta_token).await?;
+
+  let mut xname_vec = common::node_ops::from_hosts_expression_to_xname_vec(
+    hosts_expression,
+    false,
+    node_metadata_available_vec,
+  )
+  .await?;
+
+  xname_vec.sort();
+  xname_vec.dedup();
+
+  if xname_vec.is_empty() {
+    println!("No hosts to move. Exit");
+    return Ok(());
+  }
+
+  // Check partition exists
+  backend.get_partition(shasta_token, partition_name).await?;
+
+  if !assume_yes {
+    if Confirm::with_theme(&ColorfulTheme::default())
+      .with_prompt(format!(
+        "{:?}\nThe nodes above will be removed from partition '{}'. Do you want to proceed?",
+        xname_vec, partition_name
+      ))
+      .interact()
+      .unwrap()
+    {
+      log::info!("Continue",);
+    } else {
+      println!("Cancelled by user. Aborting.");
+      return Ok(());
+    }
+  }
+
+  if dryrun {
+    println!(
+      "dryrun - Delete nodes {:?} in {}",
+      xname_vec, partition_name
+    );
+    return Ok(());
+  }
+
+  let start = Instant::now();
+
+  let mut result = Ok(());
+
+  for xname in &xname_vec {
+    result = backend
+      .delete_member_from_partition(shasta_token, partition_name, xname)
+      .await;
+
+    if result.is_err() {
+      break;
+    }
+  }
+
+  // Audit
+  if let Some(auditor) = audit_opt {
+    let audit_event = auditor
+      .new_event(shasta_token, "partition.remove-members")
+      .with_xnames(&xname_vec)
+      .with_parameters(serde_json::json!({ "name": partition_name }))
+      .with_result(&result)
+      .with_duration(start.elapsed());
+
+    auditor.send_event(&audit_event).await;
+  }
+
+  result?;
+
+  println!(
+    "Nodes {:?} removed from partition '{}'",
+    xname_vec, partition_name
+  );
+
+  Ok(())
+}
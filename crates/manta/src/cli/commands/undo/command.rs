@@ -0,0 +1,337 @@
+use std::time::Instant;
+
+use comfy_table::Table;
+use dialoguer::{theme::ColorfulTheme, Confirm};
+use manta_backend_dispatcher::{
+  error::{Error, ErrorKind, Service, ServiceError},
+  interfaces::{
+    bos::ClusterTemplateTrait, bss::BootParametersTrait, cfs::CfsTrait,
+    hsm::group::GroupTrait,
+  },
+  types::cfs::cfs_configuration_request::CfsConfigurationRequest,
+};
+
+use crate::{
+  common::{
+    audit::Auditor,
+    authorization::{get_groups_available, validate_target_hsm_members},
+    journal::{Journal, JournalEntry, JournalObject},
+  },
+  manta_backend_dispatcher::StaticBackendDispatcher,
+};
+
+use super::utils::{get_restore_order, get_target_group_and_xname_vec};
+
+/// Restores the objects in a journal entry to the state they had before the
+/// operation which recorded them. Lists the journal entries of the site if no
+/// journal id is provided
+pub async fn exec(
+  backend: &StaticBackendDispatcher,
+  shasta_token: &str,
+  shasta_base_url: &str,
+  shasta_root_cert: &[u8],
+  journal: &Journal,
+  journal_id_opt: Option<&str>,
+  dry_run: bool,
+  assume_yes: bool,
+  audit_opt: Option<&Auditor>,
+) -> Result<(), Error> {
+  let journal_id = match journal_id_opt {
+    Some(journal_id) => journal_id,
+    None => {
+      print_journal_entry_table(&journal.list()?);
+      return Ok(());
+    }
+  };
+
+  let journal_entry = journal.get(journal_id)?;
+
+  if journal_entry.site != journal.site {
+    return Err(Error::Message(format!(
+      "Journal entry '{}' belongs to site '{}', current site is '{}'",
+      journal_entry.id, journal_entry.site, journal.site
+    )));
+  }
+
+  validate_access(
+    backend,
+    shasta_token,
+    shasta_base_url,
+    shasta_root_cert,
+    &journal_entry.objects,
+  )
+  .await?;
+
+  println!(
+    "Operation '{}' run by '{}' at {}. Objects to restore:",
+    journal_entry.operation, journal_entry.user, journal_entry.timestamp
+  );
+  print_journal_object_table(&journal_entry.objects);
+
+  if dry_run {
+    eprintln!("Dry-run enabled. No changes persisted into the system");
+    return Ok(());
+  }
+
+  if !assume_yes {
+    if Confirm::with_theme(&ColorfulTheme::default())
+      .with_prompt(
+        "Objects above will be overwritten. Please confirm to proceed",
+      )
+      .interact()
+      .unwrap()
+    {
+      log::info!("Continue",);
+    } else {
+      println!("Cancelled by user. Aborting.");
+      return Ok(());
+    }
+  }
+
+  let start = Instant::now();
+
+  let journal_object_vec = get_restore_order(&journal_entry.objects);
+
+  let mut failed_vec: Vec<String> = Vec::new();
+
+  for journal_object in journal_object_vec {
+    log::info!(
+      "Restoring {} '{}'",
+      journal_object.kind(),
+      journal_object.id()
+    );
+
+    if let Err(e) = restore(
+      backend,
+      shasta_token,
+      shasta_base_url,
+      shasta_root_cert,
+      journal_object,
+    )
+    .await
+    {
+      eprintln!(
+        "ERROR - Could not restore {} '{}'. Reason:\n{}",
+        journal_object.kind(),
+        journal_object.id(),
+        e
+      );
+      failed_vec.push(format!(
+        "{} '{}'",
+        journal_object.kind(),
+        journal_object.id()
+      ));
+    } else {
+      println!(
+        "{} '{}' restored",
+        journal_object.kind(),
+        journal_object.id()
+      );
+    }
+  }
+
+  let result = if failed_vec.is_empty() {
+    Ok(())
+  } else {
+    Err(Error::Message(format!(
+      "Could not restore {}",
+      failed_vec.join(", ")
+    )))
+  };
+
+  // Audit
+  if let Some(auditor) = audit_opt {
+    let audit_event = auditor
+      .new_event(shasta_token, "journal.undo")
+      .with_parameters(serde_json::json!({
+        "journal_id": journal_entry.id,
+        "operation": journal_entry.operation,
+      }))
+      .with_result(&result)
+      .with_duration(start.elapsed());
+
+    auditor.send_event(&audit_event).await;
+  }
+
+  result
+}
+
+/// Returns an error if the user can't access any of the groups, nodes or CFS
+/// configurations the journal objects change. Journal entries are local files
+/// which can be edited, hence objects are checked before being restored
+async fn validate_access(
+  backend: &StaticBackendDispatcher,
+  shasta_token: &str,
+  shasta_base_url: &str,
+  shasta_root_cert: &[u8],
+  journal_object_vec: &[JournalObject],
+) -> Result<(), Error> {
+  let mut target_hsm_group_vec: Vec<String> = Vec::new();
+  let mut target_xname_vec: Vec<String> = Vec::new();
+
+  for journal_object in journal_object_vec {
+    let (hsm_group_vec, xname_vec) =
+      get_target_group_and_xname_vec(journal_object);
+
+    target_hsm_group_vec.extend(hsm_group_vec);
+    target_xname_vec.extend(xname_vec);
+  }
+
+  target_hsm_group_vec.sort();
+  target_hsm_group_vec.dedup();
+  target_xname_vec.sort();
+  target_xname_vec.dedup();
+
+  for target_hsm_group in &target_hsm_group_vec {
+    get_groups_available(backend, shasta_token, Some(target_hsm_group), None)
+      .await?;
+  }
+
+  if !target_xname_vec.is_empty() {
+    validate_target_hsm_members(backend, shasta_token, &target_xname_vec)
+      .await?;
+  }
+
+  let hsm_group_available_vec =
+    backend.get_group_name_available(shasta_token).await?;
+
+  for journal_object in journal_object_vec {
+    if let JournalObject::CfsConfiguration(cfs_configuration) = journal_object {
+      let has_access = match backend
+        .get_and_filter_configuration(
+          shasta_token,
+          shasta_base_url,
+          shasta_root_cert,
+          Some(&cfs_configuration.name),
+          None,
+          &hsm_group_available_vec,
+          None,
+          None,
+          None,
+        )
+        .await
+      {
+        Ok(cfs_configuration_vec) => !cfs_configuration_vec.is_empty(),
+        // Deleted by the operation. Configuration names include the name of
+        // the group they are built for
+        Err(e) if e.kind() == ErrorKind::NotFound => hsm_group_available_vec
+          .iter()
+          .any(|hsm_group| cfs_configuration.name.contains(hsm_group)),
+        Err(e) => return Err(e),
+      };
+
+      if !has_access {
+        return Err(Error::Forbidden(ServiceError::new(
+          None,
+          Service::Cfs,
+          Some(&cfs_configuration.name),
+          "Can't access CFS configuration",
+        )));
+      }
+    }
+  }
+
+  Ok(())
+}
+
+async fn restore(
+  backend: &StaticBackendDispatcher,
+  shasta_token: &str,
+  shasta_base_url: &str,
+  shasta_root_cert: &[u8],
+  journal_object: &JournalObject,
+) -> Result<(), Error> {
+  match journal_object {
+    JournalObject::BootParameters(boot_parameters) => {
+      backend
+        .update_bootparameters(shasta_token, boot_parameters)
+        .await
+    }
+    JournalObject::Group(group) => {
+      // Recreate the group if it was deleted, otherwise restore its members
+      match backend.get_group(shasta_token, &group.label).await {
+        Ok(current_group) => {
+          backend
+            .update_group_members(
+              shasta_token,
+              &group.label,
+              &current_group.get_members(),
+              &group.get_members(),
+            )
+            .await
+        }
+        Err(e) if e.kind() == ErrorKind::NotFound => backend
+          .add_group(shasta_token, group.clone())
+          .await
+          .map(|_| ()),
+        Err(e) => Err(e),
+      }
+    }
+    JournalObject::CreatedGroup(group_name) => {
+      match backend.delete_group(shasta_token, group_name).await {
+        Ok(_) => Ok(()),
+        // Already deleted
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(e),
+      }
+    }
+    JournalObject::CfsConfiguration(cfs_configuration) => backend
+      .put_configuration(
+        shasta_token,
+        shasta_base_url,
+        shasta_root_cert,
+        &CfsConfigurationRequest::from(cfs_configuration.clone()),
+        &cfs_configuration.name,
+        true,
+      )
+      .await
+      .map(|_| ()),
+    JournalObject::BosSessionTemplate(bos_sessiontemplate) => backend
+      .put_template(
+        shasta_token,
+        shasta_base_url,
+        shasta_root_cert,
+        bos_sessiontemplate,
+        &bos_sessiontemplate.name.clone().unwrap_or_default(),
+      )
+      .await
+      .map(|_| ()),
+  }
+}
+
+fn print_journal_entry_table(journal_entry_vec: &[JournalEntry]) {
+  let mut table = Table::new();
+
+  table.set_header(vec!["ID", "Date", "User", "Operation", "Objects"]);
+
+  for journal_entry in journal_entry_vec {
+    table.add_row(vec![
+      journal_entry.id.clone(),
+      journal_entry.timestamp.clone(),
+      journal_entry.user.clone(),
+      journal_entry.operation.clone(),
+      journal_entry
+        .objects
+        .iter()
+        .map(|journal_object| {
+          format!("{} '{}'", journal_object.kind(), journal_object.id())
+        })
+        .collect::<Vec<_>>()
+        .join("\n"),
+    ]);
+  }
+
+  println!("{table}");
+}
+
+fn print_journal_object_table(journal_object_vec: &[JournalObject]) {
+  let mut table = Table::new();
+
+  table.set_header(vec!["Kind", "ID"]);
+
+  for journal_object in journal_object_vec {
+    table.add_row(vec![journal_object.kind().to_string(), journal_object.id()]);
+  }
+
+  println!("{table}");
+}
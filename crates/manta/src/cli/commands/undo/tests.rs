@@ -0,0 +1,106 @@
+use serde_json::json;
+
+use crate::{
+  cli::commands::undo::utils::{
+    get_restore_order, get_target_group_and_xname_vec,
+  },
+  common::journal::JournalObject,
+};
+
+#[test]
+fn test_created_group_journal_object_round_trip() {
+  let journal_object_vec: Vec<JournalObject> = serde_json::from_value(json!([
+    {
+      "kind": "Group",
+      "data": {
+        "label": "zinal",
+        "members": { "ids": ["x1003c1s7b0n0"] }
+      }
+    },
+    { "kind": "CreatedGroup", "data": "nodes_free" }
+  ]))
+  .unwrap();
+
+  assert_eq!(journal_object_vec[1].kind(), "CreatedGroup");
+  assert_eq!(journal_object_vec[1].id(), "nodes_free");
+
+  assert_eq!(
+    serde_json::to_value(&journal_object_vec[1]).unwrap(),
+    json!({ "kind": "CreatedGroup", "data": "nodes_free" })
+  );
+}
+
+#[test]
+fn test_get_restore_order_deletes_created_groups_after_restoring_groups() {
+  let journal_object_vec: Vec<JournalObject> = serde_json::from_value(json!([
+    {
+      "kind": "BootParameters",
+      "data": { "hosts": ["x1003c1s7b0n0"] }
+    },
+    { "kind": "CreatedGroup", "data": "nodes_free" },
+    {
+      "kind": "CfsConfiguration",
+      "data": { "name": "zinal-cos", "last_updated": "", "layers": [] }
+    },
+    { "kind": "Group", "data": { "label": "zinal" } }
+  ]))
+  .unwrap();
+
+  let kind_vec: Vec<&str> = get_restore_order(&journal_object_vec)
+    .into_iter()
+    .map(JournalObject::kind)
+    .collect();
+
+  assert_eq!(
+    kind_vec,
+    vec![
+      "Group",
+      "CreatedGroup",
+      "CfsConfiguration",
+      "BootParameters"
+    ]
+  );
+}
+
+#[test]
+fn test_get_target_group_and_xname_vec() {
+  let journal_object_vec: Vec<JournalObject> = serde_json::from_value(json!([
+    {
+      "kind": "Group",
+      "data": {
+        "label": "zinal",
+        "members": { "ids": ["x1003c1s7b0n0"] }
+      }
+    },
+    {
+      "kind": "BosSessionTemplate",
+      "data": {
+        "name": "zinal-cos",
+        "boot_sets": {
+          "compute": {
+            "node_groups": ["zinal"],
+            "node_list": ["x1003c1s7b0n1"]
+          }
+        }
+      }
+    },
+    {
+      "kind": "BosSessionTemplate",
+      "data": { "name": "no-boot-sets" }
+    }
+  ]))
+  .unwrap();
+
+  assert_eq!(
+    get_target_group_and_xname_vec(&journal_object_vec[0]),
+    (vec!["zinal".to_string()], vec!["x1003c1s7b0n0".to_string()])
+  );
+  assert_eq!(
+    get_target_group_and_xname_vec(&journal_object_vec[1]),
+    (vec!["zinal".to_string()], vec!["x1003c1s7b0n1".to_string()])
+  );
+  assert_eq!(
+    get_target_group_and_xname_vec(&journal_object_vec[2]),
+    (Vec::new(), Vec::new())
+  );
+}
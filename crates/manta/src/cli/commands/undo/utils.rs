@@ -0,0 +1,59 @@
+use crate::common::journal::JournalObject;
+
+/// Groups first so nodes are back in their groups before anything else is
+/// restored, groups created by the operation are deleted once their nodes
+/// were moved back. CFS configurations go before the BOS session templates
+/// referring to them
+pub fn get_restore_order(
+  journal_object_vec: &[JournalObject],
+) -> Vec<&JournalObject> {
+  let mut journal_object_vec: Vec<&JournalObject> =
+    journal_object_vec.iter().collect();
+
+  journal_object_vec.sort_by_key(|journal_object| match journal_object {
+    JournalObject::Group(_) => 0,
+    JournalObject::CreatedGroup(_) => 1,
+    JournalObject::CfsConfiguration(_) => 2,
+    JournalObject::BosSessionTemplate(_) => 3,
+    JournalObject::BootParameters(_) => 4,
+  });
+
+  journal_object_vec
+}
+
+/// Groups and nodes restoring the journal object changes. CFS configurations
+/// are not linked to any group or node
+pub fn get_target_group_and_xname_vec(
+  journal_object: &JournalObject,
+) -> (Vec<String>, Vec<String>) {
+  match journal_object {
+    JournalObject::BootParameters(boot_parameters) => {
+      (Vec::new(), boot_parameters.hosts.clone())
+    }
+    JournalObject::Group(group) => {
+      (vec![group.label.clone()], group.get_members())
+    }
+    JournalObject::CreatedGroup(group_name) => {
+      (vec![group_name.clone()], Vec::new())
+    }
+    JournalObject::CfsConfiguration(_) => (Vec::new(), Vec::new()),
+    JournalObject::BosSessionTemplate(bos_sessiontemplate) => {
+      let boot_set_vec: Vec<_> = bos_sessiontemplate
+        .boot_sets
+        .iter()
+        .flat_map(|boot_set_map| boot_set_map.values())
+        .collect();
+
+      (
+        boot_set_vec
+          .iter()
+          .flat_map(|boot_set| boot_set.node_groups.clone().unwrap_or_default())
+          .collect(),
+        boot_set_vec
+          .iter()
+          .flat_map(|boot_set| boot_set.node_list.clone().unwrap_or_default())
+          .collect(),
+      )
+    }
+  }
+}
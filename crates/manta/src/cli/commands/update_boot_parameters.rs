@@ -1,11 +1,12 @@
+use std::time::Instant;
+
 use manta_backend_dispatcher::{
   error::Error, interfaces::bss::BootParametersTrait,
   types::bss::BootParameters,
 };
 
 use crate::{
-  common::{audit::Audit, jwt_ops, kafka::Kafka},
-  manta_backend_dispatcher::StaticBackendDispatcher,
+  common::audit::Auditor, manta_backend_dispatcher::StaticBackendDispatcher,
 };
 
 pub async fn exec(
@@ -17,7 +18,7 @@ pub async fn exec(
   params: Option<&String>,
   kernel: Option<&String>,
   initrd: Option<&String>,
-  kafka_audit_opt: Option<&Kafka>,
+  audit_opt: Option<&Auditor>,
 ) -> Result<(), Error> {
   println!("Update boot parameters");
 
@@ -45,26 +46,25 @@ pub async fn exec(
 
   log::debug!("new boot params: {:#?}", boot_parameters);
 
-  backend
+  let start = Instant::now();
+
+  let result = backend
     .update_bootparameters(shasta_token, &boot_parameters)
-    .await?;
+    .await;
 
   // Audit
-  if let Some(kafka_audit) = kafka_audit_opt {
-    let username = jwt_ops::get_name(shasta_token).unwrap_or_default();
-    let user_id =
-      jwt_ops::get_preferred_username(shasta_token).unwrap_or_default();
-
-    let msg_json = serde_json::json!(
-        { "user": {"id": user_id, "name": username}, "host": {"hostname": hosts}, "message": format!("Update boot parameters")});
-
-    let msg_data = serde_json::to_string(&msg_json)
-      .expect("Could not serialize audit message data");
+  if let Some(auditor) = audit_opt {
+    let audit_event = auditor
+      .new_event(shasta_token, "boot-parameters.update")
+      .with_xnames(&hosts)
+      .with_parameters(
+        serde_json::to_value(&boot_parameters).unwrap_or_default(),
+      )
+      .with_result(&result)
+      .with_duration(start.elapsed());
 
-    if let Err(e) = kafka_audit.produce_message(msg_data.as_bytes()).await {
-      log::warn!("Failed producing messages: {}", e);
-    }
+    auditor.send_event(&audit_event).await;
   }
 
-  Ok(())
+  result
 }
@@ -0,0 +1,123 @@
+use std::time::Instant;
+
+use dialoguer::{theme::ColorfulTheme, Confirm};
+use manta_backend_dispatcher::{
+  error::Error,
+  interfaces::hsm::{
+    component::ComponentTrait, ethernet_interfaces::EthernetInterfaceTrait,
+  },
+  types::hsm::inventory::IpAddressMapping,
+};
+
+use crate::{
+  common::{self, audit::Auditor, authorization::validate_target_hsm_members},
+  manta_backend_dispatcher::StaticBackendDispatcher,
+};
+
+pub async fn exec(
+  backend: &StaticBackendDispatcher,
+  shasta_token: &str,
+  eth_interface_id: &str,
+  node_opt: Option<&str>,
+  ip_address_opt: Option<&str>,
+  network_opt: Option<&str>,
+  description_opt: Option<&str>,
+  assume_yes: bool,
+  audit_opt: Option<&Auditor>,
+) -> Result<(), Error> {
+  // Check ethernet interface exists
+  let ethernet_interface = backend
+    .get_ethernet_interface(shasta_token, eth_interface_id)
+    .await?;
+
+  // Check user has access to the node the ethernet interface belongs to
+  if let Some(component_id) = &ethernet_interface.component_id {
+    validate_target_hsm_members(
+      backend,
+      shasta_token,
+      &vec![component_id.clone()],
+    )
+    .await?;
+  }
+
+  // Check user has access to the new node the ethernet interface will belong to
+  let xname_opt: Option<String> = if let Some(node) = node_opt {
+    let node_metadata_available_vec =
+      backend.get_node_metadata_available(shasta_token).await?;
+
+    let xname_vec = common::node_ops::from_hosts_expression_to_xname_vec(
+      node,
+      false,
+      node_metadata_available_vec,
+    )
+    .await?;
+
+    match xname_vec.as_slice() {
+      [xname] => Some(xname.clone()),
+      _ => {
+        return Err(Error::Message(format!(
+          "Node '{}' must resolve to exactly one node, got {:?}",
+          node, xname_vec
+        )));
+      }
+    }
+  } else {
+    None
+  };
+
+  // NOTE: HSM replaces the list of IP addresses, therefore the new IP address
+  // overrides any IP address currently assigned to the ethernet interface
+  let ip_address_vec_opt: Option<Vec<IpAddressMapping>> =
+    ip_address_opt.map(|ip_address| {
+      vec![IpAddressMapping {
+        ip_address: ip_address.to_string(),
+        network: network_opt.map(str::to_string),
+      }]
+    });
+
+  if !assume_yes {
+    if Confirm::with_theme(&ColorfulTheme::default())
+      .with_prompt(format!(
+        "Ethernet interface '{}' (MAC '{}') will be updated. Do you want to proceed?",
+        eth_interface_id, ethernet_interface.mac_address
+      ))
+      .interact()
+      .unwrap()
+    {
+      log::info!("Continue",);
+    } else {
+      println!("Cancelled by user. Aborting.");
+      return Ok(());
+    }
+  }
+
+  let start = Instant::now();
+
+  let result = backend
+    .update_ethernet_interface(
+      shasta_token,
+      eth_interface_id,
+      description_opt,
+      xname_opt.as_deref(),
+      ip_address_vec_opt.as_deref(),
+    )
+    .await;
+
+  // Audit
+  if let Some(auditor) = audit_opt {
+    let audit_event = auditor
+      .new_event(shasta_token, "interface.update")
+      .with_xnames(xname_opt.as_ref().or(ethernet_interface.component_id.as_ref()).as_slice())
+      .with_parameters(serde_json::json!({ "id": eth_interface_id, "ip_address": ip_address_opt, "network": network_opt, "description": description_opt }))
+      .with_result(&result)
+      .with_duration(start.elapsed());
+
+    auditor.send_event(&audit_event).await;
+  }
+
+  result?;
+
+  println!("Ethernet interface '{}' updated", eth_interface_id);
+
+  Ok(())
+}
@@ -0,0 +1,187 @@
+use std::time::Instant;
+
+use dialoguer::{theme::ColorfulTheme, Confirm};
+use manta_backend_dispatcher::{
+  error::Error,
+  interfaces::hsm::{component::ComponentTrait, group::GroupTrait},
+  types::ComponentFlag,
+};
+use nodeset::NodeSet;
+
+use crate::{
+  common::{self, audit::Auditor},
+  manta_backend_dispatcher::StaticBackendDispatcher,
+};
+
+/// Updates the HSM state of nodes: enabled, flag, software status and
+/// role/subrole. Nodes are either a hosts expression or the members of a
+/// group
+pub async fn exec(
+  backend: &StaticBackendDispatcher,
+  shasta_token: &str,
+  hosts_expression_opt: Option<&str>,
+  group_name_opt: Option<&str>,
+  enabled_opt: Option<bool>,
+  flag_opt: Option<ComponentFlag>,
+  software_status_opt: Option<&str>,
+  role_opt: Option<&str>,
+  sub_role_opt: Option<&str>,
+  dry_run: bool,
+  assume_yes: bool,
+  audit_opt: Option<&Auditor>,
+) -> Result<(), Error> {
+  let mut xname_vec = if let Some(hosts_expression) = hosts_expression_opt {
+    // Filter xnames to the ones members to HSM groups the user has access to
+    let node_metadata_available_vec =
+      backend.get_node_metadata_available(shasta_token).await?;
+
+    common::node_ops::from_hosts_expression_to_xname_vec(
+      hosts_expression,
+      false,
+      node_metadata_available_vec,
+    )
+    .await?
+  } else if let Some(group_name) = group_name_opt {
+    backend
+      .get_member_vec_from_group_name_vec(
+        shasta_token,
+        vec![group_name.to_string()],
+      )
+      .await?
+  } else {
+    return Err(Error::Message(
+      "Either a hosts expression or a group is required".to_string(),
+    ));
+  };
+
+  if xname_vec.is_empty() {
+    eprintln!("The list of nodes to operate is empty. Nothing to do. Exit");
+    return Ok(());
+  }
+
+  xname_vec.sort();
+  xname_vec.dedup();
+
+  let node_group: NodeSet = xname_vec.join(", ").parse().unwrap();
+
+  println!(
+    "Number of nodes: {}\nlist of nodes: {}",
+    node_group.len(),
+    node_group.to_string()
+  );
+
+  if let Some(enabled) = enabled_opt {
+    println!("Enabled: {}", enabled);
+  }
+  if let Some(flag) = flag_opt {
+    println!("Flag: {}", flag);
+  }
+  if let Some(software_status) = software_status_opt {
+    println!("Software status: {}", software_status);
+  }
+  if let Some(role) = role_opt {
+    println!("Role: {}", role);
+    println!("Subrole: {}", sub_role_opt.unwrap_or_default());
+  }
+
+  if dry_run {
+    println!("Dry-run enabled. No changes persisted into the system");
+    return Ok(());
+  }
+
+  if !assume_yes {
+    if Confirm::with_theme(&ColorfulTheme::default())
+      .with_prompt(
+        "The nodes above will be updated. Please confirm to proceed?",
+      )
+      .interact()
+      .unwrap()
+    {
+      log::info!("Continue",);
+    } else {
+      println!("Cancelled by user. Aborting.");
+      return Ok(());
+    }
+  }
+
+  let start = Instant::now();
+
+  let result = update_node_state(
+    backend,
+    shasta_token,
+    &xname_vec,
+    enabled_opt,
+    flag_opt,
+    software_status_opt,
+    role_opt,
+    sub_role_opt,
+  )
+  .await;
+
+  // Audit
+  if let Some(auditor) = audit_opt {
+    let audit_event = auditor
+      .new_event(shasta_token, "node.update")
+      .with_xnames(&xname_vec)
+      .with_groups(&group_name_opt.into_iter().collect::<Vec<_>>())
+      .with_parameters(serde_json::json!({
+        "enabled": enabled_opt,
+        "flag": flag_opt,
+        "software_status": software_status_opt,
+        "role": role_opt,
+        "sub_role": sub_role_opt,
+      }))
+      .with_result(&result)
+      .with_duration(start.elapsed());
+
+    auditor.send_event(&audit_event).await;
+  }
+
+  result?;
+
+  println!("Nodes updated");
+
+  Ok(())
+}
+
+/// Applies each requested change in turn, stops on the first error
+async fn update_node_state(
+  backend: &StaticBackendDispatcher,
+  shasta_token: &str,
+  xname_vec: &[String],
+  enabled_opt: Option<bool>,
+  flag_opt: Option<ComponentFlag>,
+  software_status_opt: Option<&str>,
+  role_opt: Option<&str>,
+  sub_role_opt: Option<&str>,
+) -> Result<(), Error> {
+  if let Some(enabled) = enabled_opt {
+    backend
+      .update_components_enabled(shasta_token, xname_vec, enabled)
+      .await?;
+  }
+
+  if let Some(flag) = flag_opt {
+    backend
+      .update_components_flag(shasta_token, xname_vec, flag)
+      .await?;
+  }
+
+  if let Some(software_status) = software_status_opt {
+    backend
+      .update_components_software_status(
+        shasta_token,
+        xname_vec,
+        software_status,
+      )
+      .await?;
+  }
+
+  if let Some(role) = role_opt {
+    backend
+      .update_components_role(shasta_token, xname_vec, role, sub_role_opt)
+      .await?;
+  }
+
+  Ok(())
+}
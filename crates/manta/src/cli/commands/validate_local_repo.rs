@@ -2,13 +2,14 @@ use chrono::DateTime;
 use substring::Substring;
 
 use crate::common::local_git_repo;
+use manta_backend_dispatcher::error::Error;
 
 pub async fn exec(
   shasta_root_cert: &[u8],
   gitea_base_url: &str,
   gitea_token: &str,
   repo_path: &str,
-) {
+) -> Result<(), Error> {
   let mut exit_code = 0;
 
   println!("Validate local repo {}", repo_path);
@@ -16,8 +17,10 @@ pub async fn exec(
   let repo = match local_git_repo::get_repo(&repo_path) {
     Ok(repo) => repo,
     Err(_) => {
-      eprintln!("Could not find a git repo in {}", repo_path);
-      std::process::exit(1);
+      return Err(Error::Message(format!(
+        "Could not find a git repo in {}",
+        repo_path
+      )));
     }
   };
 
@@ -139,6 +142,11 @@ pub async fn exec(
   println!("Repo synced? {}", exit_code == 0);
 
   if exit_code != 0 {
-    std::process::exit(exit_code);
+    return Err(Error::Message(format!(
+      "Repo '{}' is not synced",
+      repo_path
+    )));
   }
+
+  Ok(())
 }
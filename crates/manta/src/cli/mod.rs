@@ -1,4 +1,4 @@
 pub mod build;
+pub mod capability;
 pub mod commands;
 pub mod process;
-
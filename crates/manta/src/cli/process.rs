@@ -1,6 +1,7 @@
 use clap_complete::{generate, generate_to};
 use manta_backend_dispatcher::{
   contracts::BackendTrait,
+  error::{Error, ErrorKind},
   interfaces::{
     bss::BootParametersTrait,
     hsm::{
@@ -11,7 +12,7 @@ use manta_backend_dispatcher::{
   types::{
     bss::BootParameters,
     hsm::inventory::{RedfishEndpoint, RedfishEndpointArray},
-    HWInventoryByLocationList,
+    ComponentFlag, HWInventoryByLocationList,
   },
 };
 use std::{
@@ -19,6 +20,8 @@ use std::{
   fs::File,
   io::{self, BufReader, IsTerminal},
   path::PathBuf,
+  str::FromStr,
+  time::Instant,
 };
 
 use clap::Command;
@@ -28,29 +31,39 @@ use k8s_openapi::chrono;
 use crate::{
   cli::commands::{add_node, validate_local_repo},
   common::{
+    audit::Auditor,
     authorization::{get_groups_available, validate_target_hsm_members},
     config::types::MantaConfiguration,
-    kafka::Kafka,
+    journal::{Journal, JournalObject},
+    lock_ops,
   },
   manta_backend_dispatcher::StaticBackendDispatcher,
 };
 
+use super::capability;
 use super::commands::{
-  self, add_group, add_hw_component_cluster, add_kernel_parameters,
-  add_nodes_to_hsm_groups, apply_boot_cluster, apply_boot_node,
-  apply_ephemeral_env, apply_hw_cluster_pin, apply_hw_cluster_unpin,
-  apply_kernel_parameters, apply_sat_file, apply_session, apply_template,
-  config_set_hsm, config_set_log, config_set_parent_hsm, config_set_site,
-  config_show, config_unset_auth, config_unset_hsm, config_unset_parent_hsm,
-  console_cfs_session_image_target_ansible, console_node,
-  delete_and_cancel_session, delete_configurations_and_derivatives,
-  delete_group, delete_hw_component_cluster, delete_images,
-  delete_kernel_parameters, get_boot_parameters, get_cluster,
-  get_configuration, get_hardware_node, get_images, get_kernel_parameters,
-  get_nodes, get_session, get_template, migrate_backup,
-  migrate_nodes_between_hsm_groups, power_off_cluster, power_off_nodes,
-  power_on_cluster, power_on_nodes, power_reset_cluster, power_reset_nodes,
-  remove_nodes_from_hsm_groups, update_boot_parameters,
+  self, add_group, add_hw_component_cluster, add_interface,
+  add_kernel_parameters, add_nodemap, add_nodes_to_hsm_groups,
+  add_nodes_to_partition, add_partition, apply_boot_cluster, apply_boot_node,
+  apply_cluster_state, apply_ephemeral_env, apply_hw_cluster_pin,
+  apply_hw_cluster_unpin, apply_kernel_parameters, apply_sat_file,
+  apply_session, apply_template, config_set_hsm, config_set_log,
+  config_set_parent_hsm, config_set_site, config_show, config_unset_auth,
+  config_unset_hsm, config_unset_parent_hsm,
+  console_cfs_session_image_target_ansible, console_node, console_replay,
+  console_tail, delete_and_cancel_session,
+  delete_configurations_and_derivatives, delete_garbage, delete_group,
+  delete_hw_component_cluster, delete_images, delete_interface,
+  delete_kernel_parameters, delete_nodemap, delete_partition,
+  get_boot_parameters, get_cluster, get_configuration, get_hardware_fru,
+  get_hardware_node, get_images, get_interfaces, get_kernel_parameters,
+  get_locks, get_nodemap, get_nodes, get_orphans, get_partition, get_session,
+  get_template, lock_nodes, migrate_backup, migrate_nodes_between_hsm_groups,
+  power_cap_get, power_cap_set, power_cap_snapshot, power_off_cluster,
+  power_off_nodes, power_on_cluster, power_on_nodes, power_reset_cluster,
+  power_reset_nodes, promote, remove_nodes_from_hsm_groups,
+  remove_nodes_from_partition, undo, unlock_nodes, update_boot_parameters,
+  update_interface, update_node,
 };
 use serde_json::Value;
 
@@ -62,28 +75,53 @@ pub async fn process_cli(
   vault_base_url: Option<&String>,
   gitea_base_url: &str,
   settings_hsm_group_name_opt: Option<&String>,
-  k8s_api_url: Option<&String>,
-  kafka_audit_opt: Option<&Kafka>,
+  audit_opt: Option<&Auditor>,
   settings: &Config,
   configuration: &MantaConfiguration,
 ) -> Result<(), Box<dyn std::error::Error>> {
   let site_name: String = match settings.get("site") {
     Ok(site_name) => site_name,
     Err(_) => {
-      eprintln!(
-                "'site' value in configuration file is missing or does not have a value. Exit"
-            );
-      std::process::exit(1);
+      return Err(
+        Error::Message(
+          "'site' value in configuration file is missing or does not have a value"
+            .to_string(),
+        )
+        .into(),
+      );
     }
   };
 
+  let journal = Journal::new(&site_name);
+
   let cli_root = cli.clone().get_matches();
 
+  if let Some(capability) = capability::get_required_capability(&cli_root) {
+    if !backend.is_supported(&capability) {
+      return Err(
+        Error::UnsupportedCapability(format!(
+          "Operation '{}' not supported by site '{}' backend",
+          capability, site_name
+        ))
+        .into(),
+      );
+    }
+  }
+
   if let Some(cli_config) = cli_root.subcommand_matches("config") {
-    if let Some(_cli_config_show) = cli_config.subcommand_matches("show") {
+    if let Some(cli_config_show) = cli_config.subcommand_matches("show") {
       let shasta_token_rslt = backend.get_api_token(&site_name).await;
 
-      config_show::exec(&backend, shasta_token_rslt.ok(), settings).await;
+      let show_capabilities = cli_config_show.get_flag("capabilities");
+
+      config_show::exec(
+        &backend,
+        shasta_token_rslt.ok(),
+        settings,
+        configuration,
+        show_capabilities,
+      )
+      .await;
     } else if let Some(cli_config_set) = cli_config.subcommand_matches("set") {
       if let Some(cli_config_set_hsm) = cli_config_set.subcommand_matches("hsm")
       {
@@ -94,7 +132,7 @@ pub async fn process_cli(
           &shasta_token,
           cli_config_set_hsm.get_one::<String>("HSM_GROUP_NAME"),
         )
-        .await;
+        .await?;
       }
       if let Some(cli_config_set_parent_hsm) =
         cli_config_set.subcommand_matches("parent-hsm")
@@ -106,7 +144,7 @@ pub async fn process_cli(
           &shasta_token,
           cli_config_set_parent_hsm.get_one::<String>("HSM_GROUP_NAME"),
         )
-        .await;
+        .await?;
       }
       if let Some(cli_config_set_site) =
         cli_config_set.subcommand_matches("site")
@@ -114,7 +152,7 @@ pub async fn process_cli(
         config_set_site::exec(
           cli_config_set_site.get_one::<String>("SITE_NAME"),
         )
-        .await;
+        .await?;
       }
       if let Some(cli_config_set_log) = cli_config_set.subcommand_matches("log")
       {
@@ -169,8 +207,9 @@ pub async fn process_cli(
         "ZSH" => clap_complete::Shell::Zsh,
         "FISH" => clap_complete::Shell::Fish,
         _ => {
-          eprintln!("ERROR - Shell '{shell}' not supported",);
-          std::process::exit(1);
+          return Err(
+            Error::Message(format!("Shell '{shell}' not supported")).into(),
+          );
         }
       };
 
@@ -228,9 +267,9 @@ pub async fn process_cli(
             target_hsm_group,
             assume_yes,
             output,
-            kafka_audit_opt,
+            audit_opt,
           )
-          .await;
+          .await?;
         } else if let Some(cli_power_on_node) =
           cli_power_on.subcommand_matches("nodes")
         {
@@ -251,9 +290,9 @@ pub async fn process_cli(
             xname_requested,
             assume_yes,
             output,
-            kafka_audit_opt,
+            audit_opt,
           )
-          .await;
+          .await?;
         }
       } else if let Some(cli_power_off) = cli_power.subcommand_matches("off") {
         if let Some(cli_power_off_cluster) =
@@ -293,9 +332,9 @@ pub async fn process_cli(
             *force,
             assume_yes,
             output,
-            kafka_audit_opt,
+            audit_opt,
           )
-          .await;
+          .await?;
         } else if let Some(cli_power_off_node) =
           cli_power_off.subcommand_matches("nodes")
         {
@@ -321,9 +360,9 @@ pub async fn process_cli(
             *force,
             assume_yes,
             output,
-            kafka_audit_opt,
+            audit_opt,
           )
-          .await;
+          .await?;
         }
       } else if let Some(cli_power_reset) =
         cli_power.subcommand_matches("reset")
@@ -365,9 +404,9 @@ pub async fn process_cli(
             *force,
             assume_yes,
             output,
-            kafka_audit_opt,
+            audit_opt,
           )
-          .await;
+          .await?;
         } else if let Some(cli_power_reset_node) =
           cli_power_reset.subcommand_matches("nodes")
         {
@@ -393,9 +432,101 @@ pub async fn process_cli(
             *force,
             assume_yes,
             output,
-            kafka_audit_opt,
+            audit_opt,
           )
-          .await;
+          .await?;
+        }
+      } else if let Some(cli_power_cap) = cli_power.subcommand_matches("cap") {
+        if let Some(cli_power_cap_get) = cli_power_cap.subcommand_matches("get")
+        {
+          let shasta_token = backend.get_api_token(&site_name).await?;
+
+          let hosts_expression_opt = get_hosts_expression_from_group_or_nodes(
+            &backend,
+            &shasta_token,
+            cli_power_cap_get.get_one::<String>("hsm-group"),
+            cli_power_cap_get.get_one::<String>("nodes"),
+            settings_hsm_group_name_opt,
+          )
+          .await?;
+
+          let task_id_opt = cli_power_cap_get.get_one::<String>("task-id");
+
+          let output: &str =
+            cli_power_cap_get.get_one::<String>("output").unwrap();
+
+          power_cap_get::exec(
+            &backend,
+            &shasta_token,
+            hosts_expression_opt.as_deref(),
+            task_id_opt,
+            output,
+          )
+          .await?;
+        } else if let Some(cli_power_cap_set) =
+          cli_power_cap.subcommand_matches("set")
+        {
+          let shasta_token = backend.get_api_token(&site_name).await?;
+
+          let hosts_expression = get_hosts_expression_from_group_or_nodes(
+            &backend,
+            &shasta_token,
+            cli_power_cap_set.get_one::<String>("hsm-group"),
+            cli_power_cap_set.get_one::<String>("nodes"),
+            settings_hsm_group_name_opt,
+          )
+          .await?
+          .expect("Neither HSM group nor nodes defined");
+
+          let control_name: &str = cli_power_cap_set
+            .get_one::<String>("control")
+            .expect("The 'control' argument must have a value");
+
+          let watts: usize = *cli_power_cap_set
+            .get_one::<usize>("WATTS")
+            .expect("The 'watts' argument must have a value");
+
+          let assume_yes: bool = cli_power_cap_set.get_flag("assume-yes");
+
+          let output: &str =
+            cli_power_cap_set.get_one::<String>("output").unwrap();
+
+          power_cap_set::exec(
+            &backend,
+            &shasta_token,
+            &hosts_expression,
+            control_name,
+            watts,
+            assume_yes,
+            output,
+            audit_opt,
+          )
+          .await?;
+        } else if let Some(cli_power_cap_snapshot) =
+          cli_power_cap.subcommand_matches("snapshot")
+        {
+          let shasta_token = backend.get_api_token(&site_name).await?;
+
+          let hosts_expression = get_hosts_expression_from_group_or_nodes(
+            &backend,
+            &shasta_token,
+            cli_power_cap_snapshot.get_one::<String>("hsm-group"),
+            cli_power_cap_snapshot.get_one::<String>("nodes"),
+            settings_hsm_group_name_opt,
+          )
+          .await?
+          .expect("Neither HSM group nor nodes defined");
+
+          let output: &str =
+            cli_power_cap_snapshot.get_one::<String>("output").unwrap();
+
+          power_cap_snapshot::exec(
+            &backend,
+            &shasta_token,
+            &hosts_expression,
+            output,
+          )
+          .await?;
         }
       }
     } else if let Some(cli_add) = cli_root.subcommand_matches("add") {
@@ -445,7 +576,7 @@ pub async fn process_cli(
           enabled,
           arch_opt,
           hw_inventory_opt,
-          kafka_audit_opt,
+          audit_opt,
         )
         .await;
 
@@ -462,7 +593,7 @@ pub async fn process_cli(
             eprintln!("Node '{}' deleted", id);
           }
 
-          std::process::exit(1);
+          return Err(error.into());
         }
 
         log::info!("Node '{}' created", id);
@@ -500,9 +631,113 @@ pub async fn process_cli(
           node_expression,
           true,
           false,
-          kafka_audit_opt,
+          audit_opt,
         )
-        .await;
+        .await?;
+      } else if let Some(cli_add_partition) =
+        cli_add.subcommand_matches("partition")
+      {
+        let shasta_token = backend.get_api_token(&site_name).await?;
+
+        let name = cli_add_partition
+          .get_one::<String>("name")
+          .expect("ERROR - 'name' argument is mandatory");
+
+        let description = cli_add_partition.get_one::<String>("description");
+
+        let tags = cli_add_partition.get_one::<String>("tags");
+
+        let node_expression: Option<&String> =
+          cli_add_partition.get_one::<String>("nodes");
+
+        let assume_yes: bool = cli_add_partition.get_flag("assume-yes");
+
+        let dryrun: bool = cli_add_partition.get_flag("dry-run");
+
+        add_partition::exec(
+          &backend,
+          &shasta_token,
+          name,
+          description,
+          tags,
+          node_expression,
+          assume_yes,
+          dryrun,
+          audit_opt,
+        )
+        .await?;
+      } else if let Some(cli_add_nodemap) =
+        cli_add.subcommand_matches("nodemap")
+      {
+        let shasta_token = backend.get_api_token(&site_name).await?;
+
+        let xname = cli_add_nodemap
+          .get_one::<String>("xname")
+          .expect("ERROR - 'xname' argument is mandatory");
+
+        let nid: u32 = *cli_add_nodemap
+          .get_one::<u32>("nid")
+          .expect("ERROR - 'nid' argument is mandatory");
+
+        let role = cli_add_nodemap.get_one::<String>("role");
+
+        let sub_role = cli_add_nodemap.get_one::<String>("subrole");
+
+        let assume_yes: bool = cli_add_nodemap.get_flag("assume-yes");
+
+        let dryrun: bool = cli_add_nodemap.get_flag("dry-run");
+
+        add_nodemap::exec(
+          &backend,
+          &shasta_token,
+          xname,
+          nid,
+          role,
+          sub_role,
+          assume_yes,
+          dryrun,
+          audit_opt,
+        )
+        .await?;
+      } else if let Some(cli_add_interface) =
+        cli_add.subcommand_matches("interface")
+      {
+        let shasta_token = backend.get_api_token(&site_name).await?;
+
+        let node: &String = cli_add_interface
+          .get_one("node")
+          .expect("ERROR - 'node' argument is mandatory");
+
+        let mac_address: &String = cli_add_interface
+          .get_one("mac")
+          .expect("ERROR - 'mac' argument is mandatory");
+
+        let ip_address_opt: Option<&str> = cli_add_interface
+          .get_one::<String>("ip")
+          .map(String::as_str);
+
+        let network_opt: Option<&str> = cli_add_interface
+          .get_one::<String>("network")
+          .map(String::as_str);
+
+        let description_opt: Option<&str> = cli_add_interface
+          .get_one::<String>("description")
+          .map(String::as_str);
+
+        let assume_yes: bool = cli_add_interface.get_flag("assume-yes");
+
+        add_interface::exec(
+          &backend,
+          &shasta_token,
+          node,
+          mac_address,
+          ip_address_opt,
+          network_opt,
+          description_opt,
+          assume_yes,
+          audit_opt,
+        )
+        .await?;
       } else if let Some(cli_add_hw_configuration) =
         cli_add.subcommand_matches("hardware")
       {
@@ -537,6 +772,8 @@ pub async fn process_cli(
           .get_one::<bool>("create-hsm-group")
           .unwrap_or(&false);
 
+        let start = Instant::now();
+
         add_hw_component_cluster::exec(
           &backend,
           &shasta_token,
@@ -548,7 +785,25 @@ pub async fn process_cli(
           dryrun,
           create_hsm_group,
         )
-        .await;
+        .await?;
+
+        // Audit
+        if let Some(auditor) = audit_opt {
+          let audit_event = auditor
+            .new_event(&shasta_token, "hardware.add")
+            .with_groups(&[
+              target_hsm_group_vec.first().unwrap(),
+              parent_hsm_group_vec.first().unwrap(),
+            ])
+            .with_parameters(serde_json::json!({
+              "pattern": cli_add_hw_configuration.get_one::<String>("pattern"),
+              "create_hsm_group": create_hsm_group,
+              "dry_run": dryrun,
+            }))
+            .with_duration(start.elapsed());
+
+          auditor.send_event(&audit_event).await;
+        }
       } else if let Some(cli_add_boot_parameters) =
         cli_add.subcommand_matches("boot-parameters")
       {
@@ -605,9 +860,27 @@ pub async fn process_cli(
           cloud_init,
         };
 
-        backend
+        let start = Instant::now();
+
+        let result = backend
           .add_bootparameters(&shasta_token, &boot_parameters)
-          .await?;
+          .await;
+
+        // Audit
+        if let Some(auditor) = audit_opt {
+          let audit_event = auditor
+            .new_event(&shasta_token, "boot-parameters.add")
+            .with_xnames(&boot_parameters.hosts)
+            .with_parameters(
+              serde_json::to_value(&boot_parameters).unwrap_or_default(),
+            )
+            .with_result(&result)
+            .with_duration(start.elapsed());
+
+          auditor.send_event(&audit_event).await;
+        }
+
+        result?;
 
         println!("Boot parameters created successfully");
       } else if let Some(cli_add_kernel_parameters) =
@@ -634,16 +907,7 @@ pub async fn process_cli(
             )
             .await;
 
-          match hsm_members_rslt {
-            Ok(hsm_members) => &hsm_members.join(","),
-            Err(e) => {
-              eprintln!(
-                "ERROR - could not fetch HSM groups members. Reason:\n{}",
-                e.to_string()
-              );
-              std::process::exit(1);
-            }
-          }
+          &hsm_members_rslt?.join(",")
         } else {
           cli_add_kernel_parameters
             .get_one::<String>("nodes")
@@ -669,15 +933,12 @@ pub async fn process_cli(
           overwrite,
           assume_yes,
           do_not_reboot,
-          kafka_audit_opt,
+          audit_opt,
           dryrun,
         )
         .await;
 
-        match result {
-          Ok(_) => {}
-          Err(error) => eprintln!("{}", error),
-        }
+        result?;
       } else if let Some(cli_add_redfish_endpoint) =
         cli_add.subcommand_matches("redfish-endpoint")
       {
@@ -754,12 +1015,36 @@ pub async fn process_cli(
         };
 
         let redfish_endpoint_array = RedfishEndpointArray {
-          redfish_endpoints: Some(vec![redfish_endpoint]),
+          redfish_endpoints: Some(vec![redfish_endpoint.clone()]),
         };
 
-        backend
+        let start = Instant::now();
+
+        let result = backend
           .add_redfish_endpoint(&shasta_token, &redfish_endpoint_array)
-          .await?;
+          .await;
+
+        // Audit
+        if let Some(auditor) = audit_opt {
+          // Credentials are left out of the audit event on purpose
+          let audit_event = auditor
+            .new_event(&shasta_token, "redfish-endpoint.add")
+            .with_xnames(&[&id])
+            .with_parameters(serde_json::json!({
+              "name": redfish_endpoint.name,
+              "hostname": redfish_endpoint.hostname,
+              "domain": redfish_endpoint.domain,
+              "fqdn": redfish_endpoint.fqdn,
+              "enabled": redfish_endpoint.enabled,
+              "ip_address": redfish_endpoint.ip_address,
+            }))
+            .with_result(&result)
+            .with_duration(start.elapsed());
+
+          auditor.send_event(&audit_event).await;
+        }
+
+        result?;
 
         println!("Redfish endpoint for node '{}' added", id);
       }
@@ -790,7 +1075,8 @@ pub async fn process_cli(
           .collect();
 
         // Validate user has access to the list of xnames requested
-        validate_target_hsm_members(&backend, &shasta_token, &xname_vec).await;
+        validate_target_hsm_members(&backend, &shasta_token, &xname_vec)
+          .await?;
 
         let result = update_boot_parameters::exec(
           &backend,
@@ -803,14 +1089,117 @@ pub async fn process_cli(
           params,
           kernel,
           initrd,
-          kafka_audit_opt,
+          audit_opt,
         )
         .await;
 
-        match result {
-          Ok(_) => {}
-          Err(error) => eprintln!("{}", error),
-        }
+        result?;
+      } else if let Some(cli_update_node) =
+        cli_update.subcommand_matches("node")
+      {
+        let shasta_token = backend.get_api_token(&site_name).await?;
+
+        let hosts_expression_opt: Option<&str> = cli_update_node
+          .get_one::<String>("HOSTS_EXPRESSION")
+          .map(String::as_str);
+
+        // Validate user has access to the group requested
+        let group_name_opt: Option<String> = if let Some(group_name) =
+          cli_update_node.get_one::<String>("group")
+        {
+          get_groups_available(
+            &backend,
+            &shasta_token,
+            Some(group_name),
+            settings_hsm_group_name_opt,
+          )
+          .await?
+          .first()
+          .cloned()
+        } else {
+          None
+        };
+
+        let enabled_opt: Option<bool> = if cli_update_node.get_flag("enable") {
+          Some(true)
+        } else if cli_update_node.get_flag("disable") {
+          Some(false)
+        } else {
+          None
+        };
+
+        let flag_opt: Option<ComponentFlag> = cli_update_node
+          .get_one::<String>("flag")
+          .map(|flag| {
+            ComponentFlag::from_str(flag).map_err(|e| {
+              Error::Message(format!(
+                "Invalid flag '{}'. Reason: {:?}",
+                flag, e
+              ))
+            })
+          })
+          .transpose()?;
+
+        update_node::exec(
+          &backend,
+          &shasta_token,
+          hosts_expression_opt,
+          group_name_opt.as_deref(),
+          enabled_opt,
+          flag_opt,
+          cli_update_node
+            .get_one::<String>("software-status")
+            .map(String::as_str),
+          cli_update_node
+            .get_one::<String>("role")
+            .map(String::as_str),
+          cli_update_node
+            .get_one::<String>("subrole")
+            .map(String::as_str),
+          cli_update_node.get_flag("dry-run"),
+          cli_update_node.get_flag("assume-yes"),
+          audit_opt,
+        )
+        .await?;
+      } else if let Some(cli_update_interface) =
+        cli_update.subcommand_matches("interface")
+      {
+        let shasta_token = backend.get_api_token(&site_name).await?;
+
+        let eth_interface_id: &String = cli_update_interface
+          .get_one("ID")
+          .expect("ERROR - ethernet interface id argument is mandatory");
+
+        let node_opt: Option<&str> = cli_update_interface
+          .get_one::<String>("node")
+          .map(String::as_str);
+
+        let ip_address_opt: Option<&str> = cli_update_interface
+          .get_one::<String>("ip")
+          .map(String::as_str);
+
+        let network_opt: Option<&str> = cli_update_interface
+          .get_one::<String>("network")
+          .map(String::as_str);
+
+        let description_opt: Option<&str> = cli_update_interface
+          .get_one::<String>("description")
+          .map(String::as_str);
+
+        let assume_yes: bool = cli_update_interface.get_flag("assume-yes");
+
+        update_interface::exec(
+          &backend,
+          &shasta_token,
+          eth_interface_id,
+          node_opt,
+          ip_address_opt,
+          network_opt,
+          description_opt,
+          assume_yes,
+          audit_opt,
+        )
+        .await?;
       } else if let Some(cli_update_redfish_endpoint) =
         cli_update.subcommand_matches("redfish-endpoint")
       {
@@ -886,9 +1275,33 @@ pub async fn process_cli(
           discovery_info: None,
         };
 
-        backend
+        let start = Instant::now();
+
+        let result = backend
           .update_redfish_endpoint(&shasta_token, &redfish_endpoint)
-          .await?
+          .await;
+
+        // Audit
+        if let Some(auditor) = audit_opt {
+          // Credentials are left out of the audit event on purpose
+          let audit_event = auditor
+            .new_event(&shasta_token, "redfish-endpoint.update")
+            .with_xnames(&[&redfish_endpoint.id])
+            .with_parameters(serde_json::json!({
+              "name": redfish_endpoint.name,
+              "hostname": redfish_endpoint.hostname,
+              "domain": redfish_endpoint.domain,
+              "fqdn": redfish_endpoint.fqdn,
+              "enabled": redfish_endpoint.enabled,
+              "ip_address": redfish_endpoint.ip_address,
+            }))
+            .with_result(&result)
+            .with_duration(start.elapsed());
+
+          auditor.send_event(&audit_event).await;
+        }
+
+        result?
       }
     } else if let Some(cli_get) = cli_root.subcommand_matches("get") {
       if let Some(cli_get_groups) = cli_get.subcommand_matches("groups") {
@@ -920,6 +1333,114 @@ pub async fn process_cli(
           output,
         )
         .await?;
+      } else if let Some(cli_get_partitions) =
+        cli_get.subcommand_matches("partitions")
+      {
+        let shasta_token = backend.get_api_token(&site_name).await?;
+
+        let partition_name_arg_opt: Option<&str> = cli_get_partitions
+          .get_one::<String>("VALUE")
+          .map(String::as_str);
+
+        let tag_opt: Option<&str> = cli_get_partitions
+          .get_one::<String>("tag")
+          .map(String::as_str);
+
+        let output = cli_get_partitions
+          .get_one::<String>("output")
+          .expect("ERROR - 'output' argument is mandatory");
+
+        get_partition::exec(
+          &backend,
+          &shasta_token,
+          partition_name_arg_opt,
+          tag_opt,
+          output,
+        )
+        .await?;
+      } else if let Some(cli_get_nodemaps) =
+        cli_get.subcommand_matches("nodemaps")
+      {
+        let shasta_token = backend.get_api_token(&site_name).await?;
+
+        let xname_opt: Option<&str> = cli_get_nodemaps
+          .get_one::<String>("XNAME")
+          .map(String::as_str);
+
+        let output = cli_get_nodemaps
+          .get_one::<String>("output")
+          .expect("ERROR - 'output' argument is mandatory");
+
+        get_nodemap::exec(&backend, &shasta_token, xname_opt, output).await?;
+      } else if let Some(cli_get_locks) = cli_get.subcommand_matches("locks") {
+        let shasta_token = backend.get_api_token(&site_name).await?;
+
+        let hosts_expression_opt: Option<&str> =
+          cli_get_locks.get_one::<String>("VALUE").map(String::as_str);
+
+        let output = cli_get_locks
+          .get_one::<String>("output")
+          .expect("ERROR - 'output' argument is mandatory");
+
+        get_locks::exec(&backend, &shasta_token, hosts_expression_opt, output)
+          .await?;
+      } else if let Some(cli_get_orphans) =
+        cli_get.subcommand_matches("orphans")
+      {
+        let shasta_token = backend.get_api_token(&site_name).await?;
+
+        let output = cli_get_orphans
+          .get_one::<String>("output")
+          .expect("ERROR - 'output' argument is mandatory");
+
+        get_orphans::exec(
+          &backend,
+          &shasta_token,
+          shasta_base_url,
+          shasta_root_cert,
+          output,
+        )
+        .await?;
+      } else if let Some(cli_get_interfaces) =
+        cli_get.subcommand_matches("interfaces")
+      {
+        let shasta_token = backend.get_api_token(&site_name).await?;
+
+        let hosts_expression_opt = get_hosts_expression_from_group_or_nodes(
+          &backend,
+          &shasta_token,
+          cli_get_interfaces.get_one::<String>("hsm-group"),
+          cli_get_interfaces.get_one::<String>("nodes"),
+          settings_hsm_group_name_opt,
+        )
+        .await?;
+
+        let mac_address_opt: Option<&str> = cli_get_interfaces
+          .get_one::<String>("mac")
+          .map(String::as_str);
+
+        let ip_address_opt: Option<&str> = cli_get_interfaces
+          .get_one::<String>("ip")
+          .map(String::as_str);
+
+        let network_opt: Option<&str> = cli_get_interfaces
+          .get_one::<String>("network")
+          .map(String::as_str);
+
+        let output: &str = cli_get_interfaces
+          .get_one::<String>("output")
+          .expect("ERROR - 'output' argument is mandatory");
+
+        get_interfaces::exec(
+          &backend,
+          &shasta_token,
+          hosts_expression_opt.as_deref(),
+          mac_address_opt,
+          ip_address_opt,
+          network_opt,
+          output,
+        )
+        .await?;
       } else if let Some(cli_get_hardware) =
         cli_get.subcommand_matches("hardware")
       {
@@ -959,7 +1480,7 @@ pub async fn process_cli(
             xnames.split(',').map(|xname| xname.to_string()).collect();
 
           validate_target_hsm_members(&backend, &shasta_token, &xname_vec)
-            .await;
+            .await?;
 
           get_hardware_node::exec(
             &backend,
@@ -968,7 +1489,38 @@ pub async fn process_cli(
             cli_get_hardware_node.get_one::<String>("type"),
             cli_get_hardware_node.get_one::<String>("output"),
           )
-          .await;
+          .await?;
+        } else if let Some(cli_get_hardware_fru) =
+          cli_get_hardware.subcommand_matches("fru")
+        {
+          let shasta_token = backend.get_api_token(&site_name).await?;
+
+          let output = cli_get_hardware_fru
+            .get_one::<String>("output")
+            .expect("ERROR - 'output' argument is mandatory");
+
+          get_hardware_fru::exec(
+            &backend,
+            &shasta_token,
+            cli_get_hardware_fru
+              .get_one::<String>("FRU_ID")
+              .map(String::as_str),
+            cli_get_hardware_fru
+              .get_one::<String>("type")
+              .map(String::as_str),
+            cli_get_hardware_fru
+              .get_one::<String>("manufacturer")
+              .map(String::as_str),
+            cli_get_hardware_fru
+              .get_one::<String>("part-number")
+              .map(String::as_str),
+            cli_get_hardware_fru
+              .get_one::<String>("serial-number")
+              .map(String::as_str),
+            cli_get_hardware_fru.get_flag("history"),
+            output,
+          )
+          .await?;
         }
       } else if let Some(cli_get_configuration) =
         cli_get.subcommand_matches("configurations")
@@ -1019,7 +1571,7 @@ pub async fn process_cli(
           cli_get_configuration.get_one("output"),
           &site_name,
         )
-        .await;
+        .await?;
       } else if let Some(cli_get_session) =
         cli_get.subcommand_matches("sessions")
       {
@@ -1062,7 +1614,7 @@ pub async fn process_cli(
           limit,
           cli_get_session.get_one("output"),
         )
-        .await;
+        .await?;
       } else if let Some(cli_get_template) =
         cli_get.subcommand_matches("templates")
       {
@@ -1109,7 +1661,7 @@ pub async fn process_cli(
           limit_number_opt,
           output,
         )
-        .await;
+        .await?;
       } else if let Some(cli_get_cluster) =
         cli_get.subcommand_matches("cluster")
       {
@@ -1145,7 +1697,7 @@ pub async fn process_cli(
           output,
           summary_status,
         )
-        .await;
+        .await?;
       } else if let Some(cli_get_nodes) = cli_get.subcommand_matches("nodes") {
         // Get list of nodes from cli argument
         let shasta_token = backend.get_api_token(&site_name).await?;
@@ -1173,7 +1725,7 @@ pub async fn process_cli(
           output,
           status_summary,
         )
-        .await;
+        .await?;
       } else if let Some(cli_get_images) = cli_get.subcommand_matches("images")
       {
         let shasta_token = backend.get_api_token(&site_name).await?;
@@ -1197,7 +1749,7 @@ pub async fn process_cli(
           cli_get_images.get_one::<String>("id"),
           cli_get_images.get_one::<u8>("limit"),
         )
-        .await;
+        .await?;
       } else if let Some(cli_get_boot_parameters) =
         cli_get.subcommand_matches("boot-parameters")
       {
@@ -1222,16 +1774,7 @@ pub async fn process_cli(
             )
             .await;
 
-          match hsm_members_rslt {
-            Ok(hsm_members) => &hsm_members.join(","),
-            Err(e) => {
-              eprintln!(
-                "ERROR - could not fetch HSM groups members. Reason:\n{}",
-                e.to_string()
-              );
-              std::process::exit(1);
-            }
-          }
+          &hsm_members_rslt?.join(",")
         } else {
           cli_get_boot_parameters
             .get_one::<String>("nodes")
@@ -1279,16 +1822,7 @@ pub async fn process_cli(
             )
             .await;
 
-          match hsm_members_rslt {
-            Ok(hsm_members) => &hsm_members.join(","),
-            Err(e) => {
-              eprintln!(
-                "ERROR - could not fetch HSM groups members. Reason:\n{}",
-                e.to_string()
-              );
-              std::process::exit(1);
-            }
-          }
+          &hsm_members_rslt?.join(",")
         } else {
           cli_get_kernel_parameters
             .get_one::<String>("nodes")
@@ -1299,14 +1833,14 @@ pub async fn process_cli(
           .get_one("output")
           .expect("ERROR - output value missing");
 
-        let _ = get_kernel_parameters::exec(
+        get_kernel_parameters::exec(
           &backend,
           &shasta_token,
           nodes,
           filter_opt,
           output,
         )
-        .await;
+        .await?;
       } else if let Some(cli_get_redfish_endopints) =
         cli_get.subcommand_matches("redfish-endpoints")
       {
@@ -1393,31 +1927,102 @@ pub async fn process_cli(
             .get_one::<bool>("unpin-nodes")
             .unwrap_or(&false);
 
-          if *is_unpin {
-            apply_hw_cluster_unpin::command::exec(
-              &backend,
+          let target_hsm_group_name = target_hsm_group_vec.first().unwrap();
+          let parent_hsm_group_name = parent_hsm_group_vec.first().unwrap();
+
+          let operation = if *is_unpin {
+            "hardware.unpin"
+          } else {
+            "hardware.pin"
+          };
+
+          // Lock the nodes in both clusters so other operations can't change
+          // them while they are moved between clusters. The target group may
+          // not exist yet
+          let xname_vec = backend
+            .get_member_vec_from_group_name_vec(
               &shasta_token,
-              target_hsm_group_vec.first().unwrap(),
-              parent_hsm_group_vec.first().unwrap(),
-              cli_apply_hw_cluster.get_one::<String>("pattern").unwrap(),
-              dryrun,
-              create_target_hsm_group,
-              delete_empty_parent_hsm_group,
+              vec![
+                target_hsm_group_name.to_string(),
+                parent_hsm_group_name.to_string(),
+              ],
             )
-            .await;
+            .await?;
+
+          let nodes_locked = if dryrun {
+            false
           } else {
-            apply_hw_cluster_pin::command::exec(
+            lock_ops::lock_nodes(&backend, &shasta_token, &xname_vec).await?
+          };
+
+          let start = Instant::now();
+
+          // Nodes are unlocked whatever the result, errors are returned once
+          // they are
+          let mut result = if dryrun {
+            Ok(())
+          } else {
+            record_hw_cluster_groups(
               &backend,
               &shasta_token,
-              target_hsm_group_vec.first().unwrap(),
-              parent_hsm_group_vec.first().unwrap(),
-              cli_apply_hw_cluster.get_one::<String>("pattern").unwrap(),
-              dryrun,
-              create_target_hsm_group,
-              delete_empty_parent_hsm_group,
+              &journal,
+              operation,
+              target_hsm_group_name,
+              parent_hsm_group_name,
             )
-            .await;
+            .await
+          };
+
+          if result.is_ok() {
+            result = if *is_unpin {
+              apply_hw_cluster_unpin::command::exec(
+                &backend,
+                &shasta_token,
+                target_hsm_group_name,
+                parent_hsm_group_name,
+                cli_apply_hw_cluster.get_one::<String>("pattern").unwrap(),
+                dryrun,
+                create_target_hsm_group,
+                delete_empty_parent_hsm_group,
+              )
+              .await
+            } else {
+              apply_hw_cluster_pin::command::exec(
+                &backend,
+                &shasta_token,
+                target_hsm_group_name,
+                parent_hsm_group_name,
+                cli_apply_hw_cluster.get_one::<String>("pattern").unwrap(),
+                dryrun,
+                create_target_hsm_group,
+                delete_empty_parent_hsm_group,
+              )
+              .await
+            };
+          }
+
+          // Audit
+          if let Some(auditor) = audit_opt {
+            let audit_event = auditor
+              .new_event(&shasta_token, operation)
+              .with_groups(&[target_hsm_group_name, parent_hsm_group_name])
+              .with_parameters(serde_json::json!({
+                "pattern": cli_apply_hw_cluster.get_one::<String>("pattern"),
+                "create_target_hsm_group": create_target_hsm_group,
+                "delete_empty_parent_hsm_group": delete_empty_parent_hsm_group,
+                "dry_run": dryrun,
+              }))
+              .with_result(&result)
+              .with_duration(start.elapsed());
+
+            auditor.send_event(&audit_event).await;
+          }
+
+          if nodes_locked {
+            lock_ops::unlock_nodes(&backend, &shasta_token, &xname_vec).await;
           }
+
+          result?;
         }
       } else if let Some(cli_apply_session) =
         cli_apply.subcommand_matches("session")
@@ -1464,7 +2069,7 @@ pub async fn process_cli(
               .map(|xname| xname.trim().to_string())
               .collect::<Vec<String>>(),
           )
-          .await;
+          .await?;
         }
 
         let site = configuration
@@ -1498,7 +2103,7 @@ pub async fn process_cli(
           *cli_apply_session
             .get_one::<bool>("watch-logs")
             .unwrap_or(&false),
-          kafka_audit_opt,
+          audit_opt,
           &site
             .k8s
             .as_ref()
@@ -1507,10 +2112,7 @@ pub async fn process_cli(
         )
         .await;
 
-        if let Err(e) = apply_session_rslt {
-          eprintln!("ERROR - Could not apply session. Reason:\n{:#?}", e);
-          std::process::exit(1);
-        }
+        apply_session_rslt?;
       } else if let Some(cli_apply_sat_file) =
         cli_apply.subcommand_matches("sat-file")
       {
@@ -1588,14 +2190,14 @@ pub async fn process_cli(
           .get(&configuration.site.clone())
           .unwrap();
 
+        let start = Instant::now();
+
         apply_sat_file::command::exec(
           &backend,
           &site_name,
           &shasta_token,
           shasta_base_url,
           shasta_root_cert,
-          vault_base_url.expect("ERROR - vault_base_url is mandatory"),
-          k8s_api_url.expect("ERROR - k8s_api_url is mandatory"),
           sat_file_content,
           cli_values_file_content_opt,
           cli_value_vec_opt,
@@ -1619,7 +2221,24 @@ pub async fn process_cli(
             .as_ref()
             .expect("ERROR - k8s section not found in configuration"), // FIXME:
         )
-        .await;
+        .await?;
+
+        // Audit
+        if let Some(auditor) = audit_opt {
+          let audit_event = auditor
+            .new_event(&shasta_token, "sat-file.apply")
+            .with_groups(&target_hsm_group_vec)
+            .with_parameters(serde_json::json!({
+              "image_only": cli_apply_sat_file.get_flag("image-only"),
+              "sessiontemplate_only": cli_apply_sat_file.get_flag("sessiontemplate-only"),
+              "overwrite": overwrite,
+              "do_not_reboot": do_not_reboot,
+              "dry_run": dry_run,
+            }))
+            .with_duration(start.elapsed());
+
+          auditor.send_event(&audit_event).await;
+        }
       } else if let Some(cli_apply_template) =
         cli_apply.subcommand_matches("template")
       {
@@ -1644,6 +2263,8 @@ pub async fn process_cli(
         let assume_yes: bool = cli_apply_template.get_flag("assume-yes");
         let dry_run: bool = cli_apply_template.get_flag("dry-run");
 
+        let start = Instant::now();
+
         apply_template::exec(
           &backend,
           &shasta_token,
@@ -1657,17 +2278,40 @@ pub async fn process_cli(
           assume_yes,
           dry_run,
         )
-        .await;
+        .await?;
+
+        // Audit
+        if let Some(auditor) = audit_opt {
+          let audit_event = auditor
+            .new_event(&shasta_token, "template.apply")
+            .with_xnames(&limit.split(',').map(str::trim).collect::<Vec<_>>())
+            .with_parameters(serde_json::json!({
+              "name": bos_session_name_opt,
+              "template": bos_sessiontemplate_name,
+              "operation": bos_session_operation,
+              "include_disabled": include_disabled,
+              "dry_run": dry_run,
+            }))
+            .with_duration(start.elapsed());
+
+          auditor.send_event(&audit_event).await;
+        }
       } else if let Some(cli_apply_ephemeral_environment) =
         cli_apply.subcommand_matches("ephemeral-environment")
       {
         let shasta_token = backend.get_api_token(&site_name).await?;
 
         if !std::io::stdout().is_terminal() {
-          eprintln!("This command needs to run in interactive mode. Exit");
-          std::process::exit(1);
+          return Err(
+            Error::Message(
+              "This command needs to run in interactive mode".to_string(),
+            )
+            .into(),
+          );
         }
 
+        let start = Instant::now();
+
         apply_ephemeral_env::exec(
           &shasta_token,
           shasta_base_url,
@@ -1679,7 +2323,19 @@ pub async fn process_cli(
             .get_one::<String>("image-id")
             .unwrap(),
         )
-        .await;
+        .await?;
+
+        // Audit
+        if let Some(auditor) = audit_opt {
+          let audit_event = auditor
+            .new_event(&shasta_token, "ephemeral-environment.apply")
+            .with_parameters(serde_json::json!({
+              "image_id": cli_apply_ephemeral_environment.get_one::<String>("image-id"),
+            }))
+            .with_duration(start.elapsed());
+
+          auditor.send_event(&audit_event).await;
+        }
       } else if let Some(cli_apply_kernel_parameters) =
         cli_apply.subcommand_matches("kernel-parameters")
       {
@@ -1704,16 +2360,7 @@ pub async fn process_cli(
             )
             .await;
 
-          match hsm_members_rslt {
-            Ok(hsm_members) => &hsm_members.join(","),
-            Err(e) => {
-              eprintln!(
-                "ERROR - could not fetch HSM groups members. Reason:\n{}",
-                e.to_string()
-              );
-              std::process::exit(1);
-            }
-          }
+          &hsm_members_rslt?.join(",")
         } else {
           cli_apply_kernel_parameters
             .get_one::<String>("nodes")
@@ -1738,15 +2385,39 @@ pub async fn process_cli(
           nodes,
           assume_yes,
           do_not_reboot,
-          kafka_audit_opt,
+          audit_opt,
           dryrun,
         )
         .await;
 
-        match result {
-          Ok(_) => {}
-          Err(error) => eprintln!("{}", error),
-        }
+        result?;
+      } else if let Some(cli_apply_cluster_state) =
+        cli_apply.subcommand_matches("cluster-state")
+      {
+        let shasta_token = backend.get_api_token(&site_name).await?;
+
+        let cluster_state_file = cli_apply_cluster_state
+          .get_one::<PathBuf>("file")
+          .expect("The 'file' argument must have a value");
+
+        let do_not_reboot: bool =
+          cli_apply_cluster_state.get_flag("do-not-reboot");
+        let dry_run: bool = cli_apply_cluster_state.get_flag("dry-run");
+        let assume_yes: bool = cli_apply_cluster_state.get_flag("assume-yes");
+
+        apply_cluster_state::command::exec(
+          &backend,
+          &shasta_token,
+          shasta_base_url,
+          shasta_root_cert,
+          cluster_state_file,
+          settings_hsm_group_name_opt,
+          do_not_reboot,
+          dry_run,
+          assume_yes,
+          audit_opt,
+        )
+        .await?;
       } else if let Some(cli_apply_boot) = cli_apply.subcommand_matches("boot")
       {
         if let Some(cli_apply_boot_nodes) =
@@ -1763,8 +2434,9 @@ pub async fn process_cli(
 
           if let Some(new_boot_image_id) = new_boot_image_id_opt {
             if uuid::Uuid::parse_str(new_boot_image_id).is_err() {
-              eprintln!("ERROR - image id is not an UUID");
-              std::process::exit(1);
+              return Err(
+                Error::Message("image id is not an UUID".to_string()).into(),
+              );
             }
           }
 
@@ -1797,14 +2469,11 @@ pub async fn process_cli(
             assume_yes,
             do_not_reboot,
             dry_run,
-            kafka_audit_opt,
+            audit_opt,
           )
           .await;
 
-          match result {
-            Ok(_) => {}
-            Err(error) => eprintln!("{}", error),
-          }
+          result?;
         } else if let Some(cli_apply_boot_cluster) =
           cli_apply_boot.subcommand_matches("cluster")
         {
@@ -1861,11 +2530,85 @@ pub async fn process_cli(
             assume_yes,
             do_not_reboot,
             dry_run,
-            kafka_audit_opt,
+            audit_opt,
           )
           .await;
         }
       }
+    } else if let Some(cli_lock) = cli_root.subcommand_matches("lock") {
+      let shasta_token = backend.get_api_token(&site_name).await?;
+
+      let hosts_expression: &str = cli_lock
+        .get_one::<String>("VALUE")
+        .expect("The 'xnames' argument must have values");
+
+      lock_nodes::exec(
+        &backend,
+        &shasta_token,
+        hosts_expression,
+        cli_lock.get_flag("assume-yes"),
+        audit_opt,
+      )
+      .await?;
+    } else if let Some(cli_unlock) = cli_root.subcommand_matches("unlock") {
+      let shasta_token = backend.get_api_token(&site_name).await?;
+
+      let hosts_expression: &str = cli_unlock
+        .get_one::<String>("VALUE")
+        .expect("The 'xnames' argument must have values");
+
+      unlock_nodes::exec(
+        &backend,
+        &shasta_token,
+        hosts_expression,
+        cli_unlock.get_flag("assume-yes"),
+        audit_opt,
+      )
+      .await?;
+    } else if let Some(cli_undo) = cli_root.subcommand_matches("undo") {
+      let shasta_token = backend.get_api_token(&site_name).await?;
+
+      let journal_id_opt: Option<&str> =
+        cli_undo.get_one::<String>("JOURNAL_ID").map(String::as_str);
+
+      undo::command::exec(
+        &backend,
+        &shasta_token,
+        shasta_base_url,
+        shasta_root_cert,
+        &journal,
+        journal_id_opt,
+        cli_undo.get_flag("dry-run"),
+        cli_undo.get_flag("assume-yes"),
+        audit_opt,
+      )
+      .await?;
+    } else if let Some(cli_promote) = cli_root.subcommand_matches("promote") {
+      let from_site_name = cli_promote
+        .get_one::<String>("from-site")
+        .expect("The 'from-site' argument must have a value");
+      let to_site_name = cli_promote
+        .get_one::<String>("to-site")
+        .expect("The 'to-site' argument must have a value");
+      let bos_sessiontemplate_name = cli_promote
+        .get_one::<String>("template")
+        .expect("The 'template' argument must have a value");
+      let concurrency = *cli_promote
+        .get_one::<usize>("concurrency")
+        .expect("The 'concurrency' argument must have a value");
+
+      promote::command::exec(
+        configuration,
+        from_site_name,
+        to_site_name,
+        bos_sessiontemplate_name,
+        concurrency,
+        cli_promote.get_flag("use-branch-head"),
+        cli_promote.get_flag("dry-run"),
+        cli_promote.get_flag("assume-yes"),
+        audit_opt,
+      )
+      .await?;
     } else if let Some(cli_log) = cli_root.subcommand_matches("log") {
       let shasta_token = backend.get_api_token(&site_name).await?;
 
@@ -1895,12 +2638,16 @@ pub async fn process_cli(
           .as_ref()
           .expect("ERROR - k8s section not found in configuration"), // FIXME:
       )
-      .await;
+      .await?;
     } else if let Some(cli_console) = cli_root.subcommand_matches("console") {
       if let Some(cli_console_node) = cli_console.subcommand_matches("node") {
         if !std::io::stdout().is_terminal() {
-          eprintln!("This command needs to run in interactive mode. Exit");
-          std::process::exit(1);
+          return Err(
+            Error::Message(
+              "This command needs to run in interactive mode".to_string(),
+            )
+            .into(),
+          );
         }
         let shasta_token = backend.get_api_token(&site_name).await?;
 
@@ -1914,18 +2661,55 @@ pub async fn process_cli(
           &site_name,
           &shasta_token,
           cli_console_node.get_one::<String>("XNAME").unwrap(),
-          &site
-            .k8s
-            .as_ref()
-            .expect("ERROR - k8s section not found in configuration"), // FIXME:
+          site.k8s.as_ref(),
+          cli_console_node.get_one::<String>("record"),
         )
-        .await;
+        .await?;
+      } else if let Some(cli_console_tail) =
+        cli_console.subcommand_matches("tail")
+      {
+        let shasta_token = backend.get_api_token(&site_name).await?;
+
+        let site = configuration
+          .sites
+          .get(&configuration.site.clone())
+          .unwrap();
+
+        console_tail::command::exec(
+          &backend,
+          &site_name,
+          &shasta_token,
+          cli_console_tail.get_one::<String>("VALUE").unwrap(),
+          site.k8s.as_ref(),
+          cli_console_tail.get_one::<String>("highlight"),
+          cli_console_tail.get_one::<String>("exit-on"),
+        )
+        .await?;
+      } else if let Some(cli_console_replay) =
+        cli_console.subcommand_matches("replay")
+      {
+        let speed = *cli_console_replay
+          .get_one::<f64>("speed")
+          .expect("The 'speed' argument must have a value");
+
+        console_replay::command::exec(
+          cli_console_replay.get_one::<String>("FILE").unwrap(),
+          speed,
+          cli_console_replay
+            .get_one::<f64>("idle-time-limit")
+            .copied(),
+        )
+        .await?;
       } else if let Some(cli_console_target_ansible) =
         cli_console.subcommand_matches("target-ansible")
       {
         if !std::io::stdout().is_terminal() {
-          eprintln!("This command needs to run in interactive mode. Exit");
-          std::process::exit(1);
+          return Err(
+            Error::Message(
+              "This command needs to run in interactive mode".to_string(),
+            )
+            .into(),
+          );
         }
         let shasta_token = backend.get_api_token(&site_name).await?;
 
@@ -1949,7 +2733,6 @@ pub async fn process_cli(
           &shasta_token,
           shasta_base_url,
           shasta_root_cert,
-          k8s_api_url.expect("ERROR - k8s api url is mandatory"),
           cli_console_target_ansible
             .get_one::<String>("SESSION_NAME")
             .unwrap(),
@@ -1958,7 +2741,7 @@ pub async fn process_cli(
             .as_ref()
             .expect("ERROR - k8s section not found in configuration"), // FIXME:
         )
-        .await;
+        .await?;
       }
     } else if let Some(cli_migrate) = cli_root.subcommand_matches("migrate") {
       if let Some(cli_migrate_nodes) = cli_migrate.subcommand_matches("nodes") {
@@ -1983,13 +2766,7 @@ pub async fn process_cli(
         )
         .await;
 
-        let from = match from_rslt {
-          Ok(from) => from,
-          Err(e) => {
-            eprintln!("{}", e);
-            std::process::exit(1);
-          }
-        };
+        let from = from_rslt?;
 
         // Validate 'to' hsm groups
         let to_rslt = get_groups_available(
@@ -2002,13 +2779,7 @@ pub async fn process_cli(
         )
         .await;
 
-        let to = match to_rslt {
-          Ok(to) => to,
-          Err(e) => {
-            eprintln!("{}", e);
-            std::process::exit(1);
-          }
-        };
+        let to = to_rslt?;
 
         // Migrate nodes
         migrate_nodes_between_hsm_groups::exec(
@@ -2019,69 +2790,124 @@ pub async fn process_cli(
           xnames_string,
           !dry_run,
           false,
-          kafka_audit_opt,
+          audit_opt,
+          &journal,
         )
-        .await;
-      } else if let Some(_cli_migrate_vcluster) =
+        .await?;
+      } else if let Some(cli_migrate_vcluster) =
         cli_migrate.subcommand_matches("vCluster")
       {
         if let Some(cli_migrate_vcluster_backup) =
-          cli_migrate.subcommand_matches("backup")
+          cli_migrate_vcluster.subcommand_matches("backup")
         {
           let shasta_token = backend.get_api_token(&site_name).await?;
 
-          let bos = cli_migrate_vcluster_backup.get_one::<String>("bos");
-          let destination =
-            cli_migrate_vcluster_backup.get_one::<String>("destination");
+          let bos_sessiontemplate_name_vec: Vec<String> =
+            cli_migrate_vcluster_backup
+              .get_many::<String>("bos")
+              .unwrap_or_default()
+              .cloned()
+              .collect();
+          let hsm_group_name_vec: Vec<String> = cli_migrate_vcluster_backup
+            .get_many::<String>("hsm-group")
+            .unwrap_or_default()
+            .cloned()
+            .collect();
+          let destination: &String = cli_migrate_vcluster_backup
+            .get_one("destination")
+            .expect("ERROR - destination argument is mandatory");
+          let concurrency: usize = *cli_migrate_vcluster_backup
+            .get_one("concurrency")
+            .expect("The 'concurrency' argument must have a value");
           let prehook =
             cli_migrate_vcluster_backup.get_one::<String>("pre-hook");
           let posthook =
             cli_migrate_vcluster_backup.get_one::<String>("post-hook");
+
+          let start = Instant::now();
+
           migrate_backup::exec(
             &backend,
             &shasta_token,
             shasta_base_url,
             shasta_root_cert,
-            bos,
+            &bos_sessiontemplate_name_vec,
+            &hsm_group_name_vec,
             destination,
+            concurrency,
             prehook,
             posthook,
           )
-          .await;
+          .await?;
+
+          // Audit
+          if let Some(auditor) = audit_opt {
+            let audit_event = auditor
+              .new_event(&shasta_token, "vcluster.backup")
+              .with_groups(&hsm_group_name_vec)
+              .with_parameters(serde_json::json!({
+                "bos": bos_sessiontemplate_name_vec,
+                "destination": destination,
+              }))
+              .with_duration(start.elapsed());
+
+            auditor.send_event(&audit_event).await;
+          }
         } else if let Some(cli_migrate_vcluster_restore) =
-          cli_migrate.subcommand_matches("restore")
+          cli_migrate_vcluster.subcommand_matches("restore")
         {
           let shasta_token = backend.get_api_token(&site_name).await?;
 
-          let bos_file =
-            cli_migrate_vcluster_restore.get_one::<String>("bos-file");
-          let cfs_file =
-            cli_migrate_vcluster_restore.get_one::<String>("cfs-file");
-          let hsm_file =
-            cli_migrate_vcluster_restore.get_one::<String>("hsm-file");
-          let ims_file =
-            cli_migrate_vcluster_restore.get_one::<String>("ims-file");
-          let image_dir =
-            cli_migrate_vcluster_restore.get_one::<String>("image-dir");
+          let backup_dir: &String = cli_migrate_vcluster_restore
+            .get_one("backup-dir")
+            .expect("ERROR - backup-dir argument is mandatory");
+          let bos_sessiontemplate_name_vec: Vec<String> =
+            cli_migrate_vcluster_restore
+              .get_many::<String>("bos")
+              .unwrap_or_default()
+              .cloned()
+              .collect();
+          let concurrency: usize = *cli_migrate_vcluster_restore
+            .get_one("concurrency")
+            .expect("The 'concurrency' argument must have a value");
+          let assume_yes = cli_migrate_vcluster_restore.get_flag("assume-yes");
+          let overwrite_groups =
+            cli_migrate_vcluster_restore.get_flag("overwrite-groups");
           let prehook =
             cli_migrate_vcluster_restore.get_one::<String>("pre-hook");
           let posthook =
             cli_migrate_vcluster_restore.get_one::<String>("post-hook");
 
+          let start = Instant::now();
+
           commands::migrate_restore::exec(
             &backend,
             &shasta_token,
             shasta_base_url,
             shasta_root_cert,
-            bos_file,
-            cfs_file,
-            hsm_file,
-            ims_file,
-            image_dir,
+            backup_dir,
+            &bos_sessiontemplate_name_vec,
+            concurrency,
+            assume_yes,
+            overwrite_groups,
             prehook,
             posthook,
           )
-          .await;
+          .await?;
+
+          // Audit
+          if let Some(auditor) = audit_opt {
+            let audit_event = auditor
+              .new_event(&shasta_token, "vcluster.restore")
+              .with_parameters(serde_json::json!({
+                "backup_dir": backup_dir,
+                "bos": bos_sessiontemplate_name_vec,
+                "overwrite_groups": overwrite_groups,
+              }))
+              .with_duration(start.elapsed());
+
+            auditor.send_event(&audit_event).await;
+          }
         }
       }
     } else if let Some(cli_delete) = cli_root.subcommand_matches("delete") {
@@ -2101,9 +2927,67 @@ pub async fn process_cli(
           &shasta_token,
           label,
           force,
-          kafka_audit_opt,
+          audit_opt,
+          &journal,
         )
-        .await;
+        .await?;
+      } else if let Some(cli_delete_partition) =
+        cli_delete.subcommand_matches("partition")
+      {
+        let shasta_token = backend.get_api_token(&site_name).await?;
+
+        let name: &String = cli_delete_partition
+          .get_one("VALUE")
+          .expect("ERROR - partition name argument is mandatory");
+
+        let assume_yes: bool = cli_delete_partition.get_flag("assume-yes");
+
+        delete_partition::exec(
+          &backend,
+          &shasta_token,
+          name,
+          assume_yes,
+          audit_opt,
+        )
+        .await?;
+      } else if let Some(cli_delete_nodemap) =
+        cli_delete.subcommand_matches("nodemap")
+      {
+        let shasta_token = backend.get_api_token(&site_name).await?;
+
+        let xname: &String = cli_delete_nodemap
+          .get_one("XNAME")
+          .expect("ERROR - xname argument is mandatory");
+
+        let assume_yes: bool = cli_delete_nodemap.get_flag("assume-yes");
+
+        delete_nodemap::exec(
+          &backend,
+          &shasta_token,
+          xname,
+          assume_yes,
+          audit_opt,
+        )
+        .await?;
+      } else if let Some(cli_delete_interface) =
+        cli_delete.subcommand_matches("interface")
+      {
+        let shasta_token = backend.get_api_token(&site_name).await?;
+
+        let eth_interface_id: &String = cli_delete_interface
+          .get_one("ID")
+          .expect("ERROR - ethernet interface id argument is mandatory");
+
+        let assume_yes: bool = cli_delete_interface.get_flag("assume-yes");
+
+        delete_interface::exec(
+          &backend,
+          &shasta_token,
+          eth_interface_id,
+          assume_yes,
+          audit_opt,
+        )
+        .await?;
       } else if let Some(cli_delete_node) =
         cli_delete.subcommand_matches("node")
       {
@@ -2113,7 +2997,22 @@ pub async fn process_cli(
           .get_one("VALUE")
           .expect("ERROR - group name argument is mandatory");
 
-        backend.delete_node(&shasta_token, id).await?;
+        let start = Instant::now();
+
+        let result = backend.delete_node(&shasta_token, id).await;
+
+        // Audit
+        if let Some(auditor) = audit_opt {
+          let audit_event = auditor
+            .new_event(&shasta_token, "node.delete")
+            .with_xnames(&[id])
+            .with_result(&result)
+            .with_duration(start.elapsed());
+
+          auditor.send_event(&audit_event).await;
+        }
+
+        result?;
 
         println!("Node '{}' deleted", id);
       } else if let Some(cli_delete_hw_configuration) =
@@ -2151,6 +3050,8 @@ pub async fn process_cli(
         )
         .await?;
 
+        let start = Instant::now();
+
         delete_hw_component_cluster::exec(
           &backend,
           &shasta_token,
@@ -2162,7 +3063,25 @@ pub async fn process_cli(
           dryrun,
           delete_hsm_group,
         )
-        .await;
+        .await?;
+
+        // Audit
+        if let Some(auditor) = audit_opt {
+          let audit_event = auditor
+            .new_event(&shasta_token, "hardware.delete")
+            .with_groups(&[
+              target_hsm_group_vec.first().unwrap(),
+              parent_hsm_group_vec.first().unwrap(),
+            ])
+            .with_parameters(serde_json::json!({
+              "pattern": cli_delete_hw_configuration.get_one::<String>("pattern"),
+              "delete_hsm_group": delete_hsm_group,
+              "dry_run": dryrun,
+            }))
+            .with_duration(start.elapsed());
+
+          auditor.send_event(&audit_event).await;
+        }
       } else if let Some(cli_delete_boot_parameters) =
         cli_delete.subcommand_matches("boot-parameters")
       {
@@ -2188,10 +3107,23 @@ pub async fn process_cli(
           cloud_init: None,
         };
 
+        let start = Instant::now();
+
         let result = backend
           .delete_bootparameters(&shasta_token, &boot_parameters)
           .await;
 
+        // Audit
+        if let Some(auditor) = audit_opt {
+          let audit_event = auditor
+            .new_event(&shasta_token, "boot-parameters.delete")
+            .with_xnames(&boot_parameters.hosts)
+            .with_result(&result)
+            .with_duration(start.elapsed());
+
+          auditor.send_event(&audit_event).await;
+        }
+
         match result {
           Ok(_) => println!("Boot parameters deleted successfully"),
           Err(error) => eprintln!("{}", error),
@@ -2205,8 +3137,21 @@ pub async fn process_cli(
                     "ERROR - host argument is mandatory. Please provide the host to delete",
                 );
 
+        let start = Instant::now();
+
         let result = backend.delete_redfish_endpoint(&shasta_token, &id).await;
 
+        // Audit
+        if let Some(auditor) = audit_opt {
+          let audit_event = auditor
+            .new_event(&shasta_token, "redfish-endpoint.delete")
+            .with_xnames(&[id])
+            .with_result(&result)
+            .with_duration(start.elapsed());
+
+          auditor.send_event(&audit_event).await;
+        }
+
         match result {
           Ok(_) => {
             println!("Redfish endpoint for id '{}' deleted successfully", id)
@@ -2237,16 +3182,7 @@ pub async fn process_cli(
             )
             .await;
 
-          match hsm_members_rslt {
-            Ok(hsm_members) => &hsm_members.join(","),
-            Err(e) => {
-              eprintln!(
-                "ERROR - could not fetch HSM groups members. Reason:\n{}",
-                e.to_string()
-              );
-              std::process::exit(1);
-            }
-          }
+          &hsm_members_rslt?.join(",")
         } else {
           cli_delete_kernel_parameters
             .get_one::<String>("nodes")
@@ -2271,15 +3207,13 @@ pub async fn process_cli(
           node_expression,
           assume_yes,
           do_not_reboot,
-          kafka_audit_opt,
+          audit_opt,
+          &journal,
           dryrun,
         )
         .await;
 
-        match result {
-          Ok(_) => {}
-          Err(error) => eprintln!("{}", error),
-        }
+        result?;
       } else if let Some(cli_delete_session) =
         cli_delete.subcommand_matches("session")
       {
@@ -2300,7 +3234,10 @@ pub async fn process_cli(
         let assume_yes: bool = cli_delete_session.get_flag("assume-yes");
 
         let dry_run: bool = cli_delete_session.get_flag("dry-run");
-        delete_and_cancel_session::exec(
+
+        let start = Instant::now();
+
+        let result = delete_and_cancel_session::exec(
           backend,
           &shasta_token,
           shasta_base_url,
@@ -2309,7 +3246,23 @@ pub async fn process_cli(
           session_name,
           dry_run,
           assume_yes,
-        );
+        )
+        .await;
+
+        // Audit
+        if let Some(auditor) = audit_opt {
+          let audit_event = auditor
+            .new_event(&shasta_token, "session.delete")
+            .with_parameters(
+              serde_json::json!({ "name": session_name, "dry_run": dry_run }),
+            )
+            .with_result(&result)
+            .with_duration(start.elapsed());
+
+          auditor.send_event(&audit_event).await;
+        }
+
+        result?;
       } else if let Some(cli_delete_configurations) =
         cli_delete.subcommand_matches("configurations")
       {
@@ -2354,8 +3307,12 @@ pub async fn process_cli(
           && until_opt.is_some()
           && since_opt.unwrap() > until_opt.unwrap()
         {
-          eprintln!("ERROR - 'since' date can't be after 'until' date. Exit");
-          std::process::exit(1);
+          return Err(
+            Error::Message(
+              "'since' date can't be after 'until' date".to_string(),
+            )
+            .into(),
+          );
         }
 
         let target_hsm_group_vec =
@@ -2371,24 +3328,83 @@ pub async fn process_cli(
             .await?
           };
 
+        let start = Instant::now();
+
         let result = delete_configurations_and_derivatives::exec(
           backend,
           &shasta_token,
           shasta_base_url,
           shasta_root_cert,
-          target_hsm_group_vec,
+          target_hsm_group_vec.clone(),
           cfs_configuration_name_opt,
           cfs_configuration_name_pattern,
           since_opt,
           until_opt,
           assume_yes,
+          &journal,
         )
         .await;
 
-        if let Err(e) = result {
-          eprintln!("{}", e.to_string());
-          std::process::exit(1);
+        // Audit
+        if let Some(auditor) = audit_opt {
+          let audit_event = auditor
+            .new_event(&shasta_token, "configurations.delete")
+            .with_groups(&target_hsm_group_vec)
+            .with_parameters(serde_json::json!({
+              "name": cfs_configuration_name_opt,
+              "pattern": cfs_configuration_name_pattern,
+              "since": since_opt.map(|since| since.to_string()),
+              "until": until_opt.map(|until| until.to_string()),
+            }))
+            .with_result(&result)
+            .with_duration(start.elapsed());
+
+          auditor.send_event(&audit_event).await;
         }
+
+        result?;
+      } else if let Some(cli_delete_garbage) =
+        cli_delete.subcommand_matches("garbage")
+      {
+        let shasta_token = backend.get_api_token(&site_name).await?;
+
+        let target_hsm_group_vec = get_groups_available(
+          &backend,
+          &shasta_token,
+          cli_delete_garbage.get_one::<String>("hsm-group"),
+          settings_hsm_group_name_opt,
+        )
+        .await?;
+
+        let gc_policy = delete_garbage::get_gc_policy(
+          cli_delete_garbage
+            .get_one::<PathBuf>("policy-file")
+            .map(PathBuf::as_path),
+          cli_delete_garbage
+            .get_one::<usize>("keep-configurations")
+            .copied(),
+          cli_delete_garbage.get_one::<usize>("keep-images").copied(),
+          cli_delete_garbage.get_one::<String>("failed-sessions-min-age"),
+        )?;
+
+        let dry_run = cli_delete_garbage.get_flag("dry-run");
+        let assume_yes = cli_delete_garbage.get_flag("assume-yes");
+        let output: &str =
+          cli_delete_garbage.get_one::<String>("output").unwrap();
+
+        delete_garbage::exec(
+          &backend,
+          &shasta_token,
+          shasta_base_url,
+          shasta_root_cert,
+          &target_hsm_group_vec,
+          &gc_policy,
+          dry_run,
+          assume_yes,
+          output,
+          audit_opt,
+        )
+        .await?;
       } else if let Some(cli_delete_images) =
         cli_delete.subcommand_matches("images")
       {
@@ -2411,6 +3427,8 @@ pub async fn process_cli(
 
         let dry_run: bool = cli_delete_images.get_flag("dry-run");
 
+        let start = Instant::now();
+
         delete_images::command::exec(
           &backend,
           &shasta_token,
@@ -2420,7 +3438,20 @@ pub async fn process_cli(
           image_id_vec.as_slice(),
           dry_run,
         )
-        .await;
+        .await?;
+
+        // Audit
+        if let Some(auditor) = audit_opt {
+          let audit_event = auditor
+            .new_event(&shasta_token, "images.delete")
+            .with_parameters(serde_json::json!({
+              "image_ids": image_id_vec,
+              "dry_run": dry_run,
+            }))
+            .with_duration(start.elapsed());
+
+          auditor.send_event(&audit_event).await;
+        }
       }
     } else if let Some(cli_validate_local_repo) =
       cli_root.subcommand_matches("validate-local-repo")
@@ -2447,7 +3478,7 @@ pub async fn process_cli(
         &gitea_token,
         repo_path,
       )
-      .await;
+      .await?;
     } else if let Some(cli_add_nodes) =
       cli_root.subcommand_matches("add-nodes-to-groups")
     {
@@ -2467,9 +3498,9 @@ pub async fn process_cli(
         target_hsm_name,
         hosts_expression,
         dryrun,
-        kafka_audit_opt,
+        audit_opt,
       )
-      .await;
+      .await?;
     } else if let Some(cli_remove_nodes) =
       cli_root.subcommand_matches("remove-nodes-from-groups")
     {
@@ -2489,9 +3520,63 @@ pub async fn process_cli(
         target_hsm_name,
         nodes,
         dryrun,
-        kafka_audit_opt,
+        audit_opt,
       )
-      .await;
+      .await?;
+    } else if let Some(cli_add_nodes_to_partition) =
+      cli_root.subcommand_matches("add-nodes-to-partition")
+    {
+      let shasta_token = backend.get_api_token(&site_name).await?;
+
+      let partition_name: &String = cli_add_nodes_to_partition
+        .get_one::<String>("partition")
+        .expect("Error - target partition is mandatory");
+
+      let hosts_expression: &String = cli_add_nodes_to_partition
+        .get_one::<String>("nodes")
+        .expect("Error - nodes are mandatory");
+
+      let assume_yes = cli_add_nodes_to_partition.get_flag("assume-yes");
+
+      let dryrun = cli_add_nodes_to_partition.get_flag("dry-run");
+
+      add_nodes_to_partition::exec(
+        &backend,
+        &shasta_token,
+        partition_name,
+        hosts_expression,
+        assume_yes,
+        dryrun,
+        audit_opt,
+      )
+      .await?;
+    } else if let Some(cli_remove_nodes_from_partition) =
+      cli_root.subcommand_matches("remove-nodes-from-partition")
+    {
+      let shasta_token = backend.get_api_token(&site_name).await?;
+
+      let partition_name: &String = cli_remove_nodes_from_partition
+        .get_one::<String>("partition")
+        .expect("Error - target partition is mandatory");
+
+      let hosts_expression: &String = cli_remove_nodes_from_partition
+        .get_one::<String>("nodes")
+        .expect("Error - nodes are mandatory");
+
+      let assume_yes = cli_remove_nodes_from_partition.get_flag("assume-yes");
+
+      let dryrun = cli_remove_nodes_from_partition.get_flag("dry-run");
+
+      remove_nodes_from_partition::exec(
+        &backend,
+        &shasta_token,
+        partition_name,
+        hosts_expression,
+        assume_yes,
+        dryrun,
+        audit_opt,
+      )
+      .await?;
     } else if let Some(_) = cli_root.subcommand_matches("download-boot-image") {
       println!("Download boot image");
     } else if let Some(_) = cli_root.subcommand_matches("upload-boot-image") {
@@ -2501,3 +3586,62 @@ pub async fn process_cli(
 
   Ok(())
 }
+
+/// Returns the hosts expression to operate against. If the user requested an HSM group, then the
+/// hosts expression is the list of members of the group, otherwise, the nodes requested by the
+/// user are returned
+async fn get_hosts_expression_from_group_or_nodes(
+  backend: &StaticBackendDispatcher,
+  shasta_token: &str,
+  hsm_group_name_arg_opt: Option<&String>,
+  nodes_arg_opt: Option<&String>,
+  settings_hsm_group_name_opt: Option<&String>,
+) -> Result<Option<String>, manta_backend_dispatcher::error::Error> {
+  if hsm_group_name_arg_opt.is_some() {
+    let hsm_group_name_vec = get_groups_available(
+      backend,
+      shasta_token,
+      hsm_group_name_arg_opt,
+      settings_hsm_group_name_opt,
+    )
+    .await?;
+
+    let hsm_members = backend
+      .get_member_vec_from_group_name_vec(shasta_token, hsm_group_name_vec)
+      .await?;
+
+    Ok(Some(hsm_members.join(",")))
+  } else {
+    Ok(nodes_arg_opt.cloned())
+  }
+}
+
+/// Keeps the groups 'apply hardware cluster' changes so the changes can be
+/// undone. The target group may not exist yet, in which case undoing the
+/// changes deletes it
+async fn record_hw_cluster_groups(
+  backend: &StaticBackendDispatcher,
+  shasta_token: &str,
+  journal: &Journal,
+  operation: &str,
+  target_hsm_group_name: &str,
+  parent_hsm_group_name: &str,
+) -> Result<(), manta_backend_dispatcher::error::Error> {
+  let mut journal_object_vec = vec![JournalObject::Group(
+    backend
+      .get_group(shasta_token, parent_hsm_group_name)
+      .await?,
+  )];
+
+  match backend.get_group(shasta_token, target_hsm_group_name).await {
+    Ok(group) => journal_object_vec.push(JournalObject::Group(group)),
+    Err(e) if e.kind() == ErrorKind::NotFound => journal_object_vec.push(
+      JournalObject::CreatedGroup(target_hsm_group_name.to_string()),
+    ),
+    Err(e) => return Err(e),
+  }
+
+  journal.record(shasta_token, operation, journal_object_vec)?;
+
+  Ok(())
+}
@@ -0,0 +1,182 @@
+use std::{
+  fs::File,
+  io::{BufRead, BufReader, BufWriter, Write},
+  path::Path,
+  time::Instant,
+};
+
+use anyhow::{bail, Context};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+/// First line of an asciicast v2 file
+/// https://docs.asciinema.org/manual/asciicast/v2/
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct AsciicastHeader {
+  pub version: u8,
+  pub width: u16,
+  pub height: u16,
+  /// Unix time the recording started
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub timestamp: Option<i64>,
+  /// Pauses longer than this number of seconds are shortened on replay
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub idle_time_limit: Option<f64>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub title: Option<String>,
+}
+
+/// Line of an asciicast v2 file after the header eg '[1.0031, "o", "login: "]'
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct AsciicastEvent(
+  /// Seconds since the recording started
+  pub f64,
+  /// 'o' for output, 'i' for input and 'r' for terminal resize
+  pub String,
+  pub String,
+);
+
+/// Writes the output of a console to an asciicast v2 file. Events are flushed
+/// as they arrive so the recording is kept if manta is killed
+pub struct AsciicastRecorder {
+  writer: BufWriter<File>,
+  start: Instant,
+  /// Bytes of a UTF-8 character split between two reads of the console
+  pending: Vec<u8>,
+}
+
+impl AsciicastRecorder {
+  pub fn new(
+    path: &Path,
+    width: u16,
+    height: u16,
+    title: &str,
+  ) -> Result<Self, anyhow::Error> {
+    let file = File::create(path).with_context(|| {
+      format!("Could not create recording file '{}'", path.display())
+    })?;
+
+    let mut recorder = Self {
+      writer: BufWriter::new(file),
+      start: Instant::now(),
+      pending: Vec::new(),
+    };
+
+    let header = AsciicastHeader {
+      version: 2,
+      width,
+      height,
+      timestamp: Some(Utc::now().timestamp()),
+      idle_time_limit: None,
+      title: Some(title.to_string()),
+    };
+
+    recorder.write_line(&serde_json::to_string(&header)?)?;
+
+    Ok(recorder)
+  }
+
+  pub fn record_output(&mut self, data: &[u8]) -> Result<(), anyhow::Error> {
+    let output = decode_utf8(&mut self.pending, data);
+
+    if output.is_empty() {
+      return Ok(());
+    }
+
+    let event = AsciicastEvent(
+      self.start.elapsed().as_secs_f64(),
+      "o".to_string(),
+      output,
+    );
+
+    self.write_line(&serde_json::to_string(&event)?)
+  }
+
+  fn write_line(&mut self, line: &str) -> Result<(), anyhow::Error> {
+    writeln!(self.writer, "{}", line)?;
+    self.writer.flush()?;
+
+    Ok(())
+  }
+}
+
+/// Appends the bytes read from a console to the ones pending and returns the
+/// text decoded. An incomplete UTF-8 character at the end is kept pending for
+/// the next read and invalid bytes are replaced with U+FFFD
+pub fn decode_utf8(pending: &mut Vec<u8>, data: &[u8]) -> String {
+  pending.extend_from_slice(data);
+
+  let mut output = String::new();
+
+  loop {
+    let (valid_up_to, error_len_opt) = match std::str::from_utf8(pending) {
+      Ok(_) => (pending.len(), None),
+      Err(e) => (e.valid_up_to(), Some(e.error_len())),
+    };
+
+    output.push_str(&String::from_utf8_lossy(&pending[..valid_up_to]));
+
+    match error_len_opt {
+      // Invalid bytes
+      Some(Some(error_len)) => {
+        output.push(char::REPLACEMENT_CHARACTER);
+        pending.drain(..valid_up_to + error_len);
+      }
+      // Incomplete character or everything decoded
+      _ => {
+        pending.drain(..valid_up_to);
+        break;
+      }
+    }
+  }
+
+  output
+}
+
+/// Reads an asciicast v2 file
+pub fn read(
+  path: &Path,
+) -> Result<(AsciicastHeader, Vec<AsciicastEvent>), anyhow::Error> {
+  let file = File::open(path).with_context(|| {
+    format!("Could not open recording file '{}'", path.display())
+  })?;
+
+  parse(BufReader::new(file))
+    .with_context(|| format!("Could not parse '{}'", path.display()))
+}
+
+pub fn parse(
+  reader: impl BufRead,
+) -> Result<(AsciicastHeader, Vec<AsciicastEvent>), anyhow::Error> {
+  let mut line_iter = reader.lines().enumerate();
+
+  let header: AsciicastHeader = match line_iter.next() {
+    Some((_, line)) => serde_json::from_str(&line?)
+      .context("First line is not an asciicast header")?,
+    None => bail!("File is empty"),
+  };
+
+  if header.version != 2 {
+    bail!(
+      "asciicast version {} not supported, only version 2 is",
+      header.version
+    );
+  }
+
+  let mut event_vec = Vec::new();
+
+  for (index, line) in line_iter {
+    let line = line?;
+
+    if line.trim().is_empty() {
+      continue;
+    }
+
+    let event: AsciicastEvent = serde_json::from_str(&line)
+      .with_context(|| format!("Line {} is not a valid event", index + 1))?;
+
+    event_vec.push(event);
+  }
+
+  Ok((header, event_vec))
+}
@@ -1,14 +1,98 @@
 use anyhow::Result;
+use manta_backend_dispatcher::audit::{AuditActor, AuditEvent};
 use serde::{Deserialize, Serialize};
 
-use super::kafka::Kafka;
+use super::{
+  audit_file::AuditFile, jwt_ops, kafka::Kafka, syslog::Syslog,
+  webhook::Webhook,
+};
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+/// Audit sinks. Any combination of them can be enabled in the configuration
+/// file, each message is sent to all of them
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
 pub struct Auditor {
-  pub kafka: Kafka,
-  // pub syslog: Option<Syslog>,
+  pub kafka: Option<Kafka>,
+  pub syslog: Option<Syslog>,
+  pub file: Option<AuditFile>,
+  pub webhook: Option<Webhook>,
+  /// Site and backend audit events are produced for. Not part of the
+  /// configuration file, set at runtime
+  #[serde(skip)]
+  pub site: String,
+  #[serde(skip)]
+  pub backend: String,
+}
+
+impl Auditor {
+  pub fn is_empty(&self) -> bool {
+    self.kafka.is_none()
+      && self.syslog.is_none()
+      && self.file.is_none()
+      && self.webhook.is_none()
+  }
+
+  /// Creates an audit event for an operation run by the owner of the token
+  pub fn new_event(&self, shasta_token: &str, operation: &str) -> AuditEvent {
+    let actor = AuditActor {
+      id: jwt_ops::get_preferred_username(shasta_token).unwrap_or_default(),
+      name: jwt_ops::get_name(shasta_token).unwrap_or_default(),
+    };
+
+    AuditEvent::new(actor, &self.site, &self.backend, operation)
+  }
+
+  /// Sends an audit event to all sinks. Failures are logged but never stop
+  /// the operation being audited
+  pub async fn send_event(&self, event: &AuditEvent) {
+    let msg_data = serde_json::to_vec(event)
+      .expect("Could not serialize audit message data");
+
+    if let Err(e) = self.produce_message(&msg_data).await {
+      log::warn!("Failed producing messages: {}", e);
+    }
+  }
 }
 
 pub trait Audit {
   async fn produce_message(&self, data: &[u8]) -> Result<()>;
 }
+
+impl Audit for Auditor {
+  async fn produce_message(&self, data: &[u8]) -> Result<()> {
+    // A failing sink must not prevent the message from reaching the others
+    let mut error_vec = Vec::new();
+
+    if let Some(kafka) = &self.kafka {
+      if let Err(e) = kafka.produce_message(data).await {
+        error_vec.push(format!("kafka: {}", e));
+      }
+    }
+
+    if let Some(syslog) = &self.syslog {
+      if let Err(e) = syslog.produce_message(data).await {
+        error_vec.push(format!("syslog: {}", e));
+      }
+    }
+
+    if let Some(file) = &self.file {
+      if let Err(e) = file.produce_message(data).await {
+        error_vec.push(format!("file: {}", e));
+      }
+    }
+
+    if let Some(webhook) = &self.webhook {
+      if let Err(e) = webhook.produce_message(data).await {
+        error_vec.push(format!("webhook: {}", e));
+      }
+    }
+
+    if error_vec.is_empty() {
+      Ok(())
+    } else {
+      Err(anyhow::anyhow!(
+        "Could not send audit message to: {}",
+        error_vec.join(", ")
+      ))
+    }
+  }
+}
@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize};
+use tokio::{fs::OpenOptions, io::AsyncWriteExt};
+
+use super::audit::Audit;
+
+use anyhow::Result;
+
+/// Local file where audit messages are appended, one JSON document per line
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AuditFile {
+  pub path: String,
+}
+
+impl Audit for AuditFile {
+  async fn produce_message(&self, data: &[u8]) -> Result<()> {
+    // Make sure each audit message takes exactly one line
+    let mut line =
+      serde_json::to_vec(&serde_json::from_slice::<serde_json::Value>(data)?)?;
+    line.push(b'\n');
+
+    let mut file = OpenOptions::new()
+      .create(true)
+      .append(true)
+      .open(&self.path)
+      .await?;
+
+    file.write_all(&line).await?;
+    file.flush().await?;
+
+    log::info!("Audit message appended to file {}", self.path);
+
+    Ok(())
+  }
+}
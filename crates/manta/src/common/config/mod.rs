@@ -84,6 +84,71 @@ pub fn get_default_manta_audit_file_path() -> PathBuf {
   log_file_path
 }
 
+pub fn get_default_manta_journal_dir_path() -> PathBuf {
+  // XDG Base Directory Specification
+  let project_dirs = ProjectDirs::from(
+    "local", /*qualifier*/
+    "cscs",  /*organization*/
+    "manta", /*application*/
+  );
+
+  let mut journal_dir_path = PathBuf::from(project_dirs.unwrap().data_dir());
+  journal_dir_path.push("journal");
+
+  journal_dir_path
+}
+
+/// Directory where 'manta promote' stages the objects copied between sites.
+/// Kept between runs so an interrupted promotion can be resumed
+pub fn get_default_manta_promote_dir_path() -> PathBuf {
+  // XDG Base Directory Specification
+  let project_dirs = ProjectDirs::from(
+    "local", /*qualifier*/
+    "cscs",  /*organization*/
+    "manta", /*application*/
+  );
+
+  let mut promote_dir_path = PathBuf::from(project_dirs.unwrap().data_dir());
+  promote_dir_path.push("promote");
+
+  promote_dir_path
+}
+
+/// Backend API URL of a site. CSM APIs are under '/apis', OpenCHAMI ones are
+/// not
+pub fn get_shasta_api_url(site: &Site) -> Result<String, Error> {
+  match site.backend.as_str() {
+    "csm" => Ok(get_shasta_barebone_url(site).to_owned() + "/apis"),
+    "ochami" => Ok(get_shasta_barebone_url(site).to_owned()),
+    backend_tech => Err(Error::Message(format!(
+      "Invalid backend technology: {}",
+      backend_tech
+    ))),
+  }
+}
+
+/// VCS (gitea) URL of a site
+pub fn get_gitea_base_url(site: &Site) -> String {
+  get_shasta_barebone_url(site).to_owned() + "/vcs"
+}
+
+/// Vault URL of a site, only sites authenticating against k8s through vault
+/// have one
+pub fn get_vault_base_url(site: &Site) -> Option<&String> {
+  site.k8s.as_ref().and_then(|k8s| match &k8s.authentication {
+    K8sAuth::Vault { base_url, .. } => Some(base_url),
+    _ => None,
+  })
+}
+
+fn get_shasta_barebone_url(site: &Site) -> &str {
+  // HACK to not break compatibility with configurations pointing to '/apis'
+  site
+    .shasta_base_url
+    .strip_suffix("/apis")
+    .unwrap_or(&site.shasta_base_url)
+}
+
 pub fn get_default_mgmt_plane_ca_cert_file_path() -> PathBuf {
   // XDG Base Directory Specification
   let project_dirs = ProjectDirs::from(
@@ -271,15 +336,20 @@ pub async fn create_new_config_file(config_file_path_opt: Option<&PathBuf>) {
     "".to_string()
   };
 
-  // If both kafka broker and topic are empty, then auditor is None
+  // If either kafka broker or topic are empty, then auditor is None. Other
+  // audit sinks (syslog, file, webhook) can be added manually to the config
+  // file
   let auditor =
-    if audit_kafka_brokers.is_empty() && audit_kafka_topic.is_empty() {
+    if !audit_kafka_brokers.is_empty() && !audit_kafka_topic.is_empty() {
       let kafka = Kafka {
         brokers: vec![audit_kafka_brokers],
         topic: audit_kafka_topic,
       };
 
-      Some(Auditor { kafka })
+      Some(Auditor {
+        kafka: Some(kafka),
+        ..Default::default()
+      })
     } else {
       None
     };
@@ -314,6 +384,7 @@ pub async fn create_new_config_file(config_file_path_opt: Option<&PathBuf>) {
 
   let k8s_details = K8sDetails {
     api_url: k8s_api_url.clone(),
+    tls_server_name: None,
     authentication: k8s_auth,
   };
 
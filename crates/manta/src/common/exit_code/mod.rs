@@ -0,0 +1,39 @@
+use manta_backend_dispatcher::error::{Error, ErrorKind};
+
+// Exit codes returned by manta when a command fails. 2 is left out because
+// clap uses it for command line usage errors.
+pub const GENERIC: i32 = 1;
+pub const UNSUPPORTED: i32 = 3;
+pub const NOT_FOUND: i32 = 4;
+pub const UNAUTHORIZED: i32 = 5;
+pub const FORBIDDEN: i32 = 6;
+pub const CONFLICT: i32 = 7;
+pub const VALIDATION: i32 = 8;
+pub const TIMEOUT: i32 = 9;
+pub const UNAVAILABLE: i32 = 10;
+
+/// Exit code for a backend error
+pub fn from_backend_error(error: &Error) -> i32 {
+  match error.kind() {
+    ErrorKind::NotFound => NOT_FOUND,
+    ErrorKind::Unauthorized => UNAUTHORIZED,
+    ErrorKind::Forbidden => FORBIDDEN,
+    ErrorKind::Conflict => CONFLICT,
+    ErrorKind::Validation => VALIDATION,
+    ErrorKind::Timeout => TIMEOUT,
+    ErrorKind::Unavailable => UNAVAILABLE,
+    ErrorKind::Unsupported => UNSUPPORTED,
+    ErrorKind::Other => GENERIC,
+  }
+}
+
+/// Exit code for any error returned while processing a command
+pub fn from_error(error: &(dyn std::error::Error + 'static)) -> i32 {
+  error
+    .downcast_ref::<Error>()
+    .map(from_backend_error)
+    .unwrap_or(GENERIC)
+}
+
+#[cfg(test)]
+mod tests;
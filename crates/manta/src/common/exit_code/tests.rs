@@ -0,0 +1,52 @@
+use manta_backend_dispatcher::error::{Error, Service};
+
+use super::{
+  from_backend_error, from_error, CONFLICT, FORBIDDEN, GENERIC, NOT_FOUND,
+  TIMEOUT, UNAUTHORIZED, UNAVAILABLE, UNSUPPORTED, VALIDATION,
+};
+
+#[test]
+fn test_from_backend_error() {
+  let exit_code_for_status = |status| {
+    from_backend_error(&Error::from_http_status(
+      status,
+      Service::Hsm,
+      None,
+      "message",
+    ))
+  };
+
+  assert_eq!(exit_code_for_status(400), VALIDATION);
+  assert_eq!(exit_code_for_status(401), UNAUTHORIZED);
+  assert_eq!(exit_code_for_status(403), FORBIDDEN);
+  assert_eq!(exit_code_for_status(404), NOT_FOUND);
+  assert_eq!(exit_code_for_status(409), CONFLICT);
+  assert_eq!(exit_code_for_status(504), TIMEOUT);
+  assert_eq!(exit_code_for_status(503), UNAVAILABLE);
+  assert_eq!(exit_code_for_status(418), GENERIC);
+
+  assert_eq!(
+    from_backend_error(&Error::UnsupportedCapability("power".to_string())),
+    UNSUPPORTED
+  );
+  assert_eq!(
+    from_backend_error(&Error::Message("message".to_string())),
+    GENERIC
+  );
+}
+
+#[test]
+fn test_from_error() {
+  // Backend errors keep their exit code once boxed by the command processing
+  let error: Box<dyn std::error::Error> =
+    Error::from_http_status(404, Service::Cfs, Some("config"), "not found")
+      .into();
+
+  assert_eq!(from_error(error.as_ref()), NOT_FOUND);
+
+  // Any other error is a generic failure
+  let error: Box<dyn std::error::Error> =
+    std::io::Error::other("message").into();
+
+  assert_eq!(from_error(error.as_ref()), GENERIC);
+}
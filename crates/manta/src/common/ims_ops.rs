@@ -1,5 +1,5 @@
-use anyhow::Error;
 use manta_backend_dispatcher::{
+  error::Error,
   interfaces::{cfs::CfsTrait, ims::ImsTrait},
   types::ims::Image,
 };
@@ -39,8 +39,7 @@ pub async fn get_image_vec_related_cfs_configuration_name(
       None,
       Some(true),
     )
-    .await
-    .unwrap();
+    .await?;
 
   // Filter CFS sessions to the ones related to CFS configuration and built an image (target
   // definition is 'image' and it actually has at least one artifact)
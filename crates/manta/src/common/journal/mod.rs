@@ -0,0 +1,217 @@
+use std::path::PathBuf;
+
+use chrono::{SecondsFormat, Utc};
+use manta_backend_dispatcher::{
+  error::Error,
+  types::{
+    bos::session_template::BosSessionTemplate, bss::BootParameters,
+    cfs::cfs_configuration_response::CfsConfigurationResponse, Group,
+  },
+};
+use serde::{Deserialize, Serialize};
+
+use super::{config, jwt_ops};
+
+#[cfg(test)]
+mod tests;
+
+/// Local record of the objects destructive operations are about to change so
+/// they can be restored with 'manta undo'. Each entry is a JSON file in the
+/// manta data directory
+#[derive(Debug, Clone)]
+pub struct Journal {
+  pub path: PathBuf,
+  /// Site the operations run against. Entries can only be restored against
+  /// the same site
+  pub site: String,
+}
+
+/// Object as it was before the operation
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "kind", content = "data")]
+pub enum JournalObject {
+  BootParameters(BootParameters),
+  /// Group including its members
+  Group(Group),
+  /// Name of a group the operation created, undoing the operation deletes it
+  CreatedGroup(String),
+  CfsConfiguration(CfsConfigurationResponse),
+  BosSessionTemplate(BosSessionTemplate),
+}
+
+impl JournalObject {
+  pub fn kind(&self) -> &str {
+    match self {
+      JournalObject::BootParameters(_) => "BootParameters",
+      JournalObject::Group(_) => "Group",
+      JournalObject::CreatedGroup(_) => "CreatedGroup",
+      JournalObject::CfsConfiguration(_) => "CfsConfiguration",
+      JournalObject::BosSessionTemplate(_) => "BosSessionTemplate",
+    }
+  }
+
+  /// Nodes for boot parameters, name for the rest
+  pub fn id(&self) -> String {
+    match self {
+      JournalObject::BootParameters(boot_parameters) => {
+        boot_parameters.hosts.join(",")
+      }
+      JournalObject::Group(group) => group.label.clone(),
+      JournalObject::CreatedGroup(group_name) => group_name.clone(),
+      JournalObject::CfsConfiguration(cfs_configuration) => {
+        cfs_configuration.name.clone()
+      }
+      JournalObject::BosSessionTemplate(bos_sessiontemplate) => {
+        bos_sessiontemplate.name.clone().unwrap_or_default()
+      }
+    }
+  }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct JournalEntry {
+  pub id: String,
+  pub timestamp: String,
+  pub site: String,
+  /// User who ran the operation
+  pub user: String,
+  /// Same operation names as audit events eg 'group.delete'
+  pub operation: String,
+  pub objects: Vec<JournalObject>,
+}
+
+impl Journal {
+  pub fn new(site: &str) -> Self {
+    Self {
+      path: config::get_default_manta_journal_dir_path(),
+      site: site.to_string(),
+    }
+  }
+
+  /// Stores the objects an operation is about to change. Operations must not
+  /// continue if this fails, otherwise the changes could not be undone
+  pub fn record(
+    &self,
+    shasta_token: &str,
+    operation: &str,
+    objects: Vec<JournalObject>,
+  ) -> Result<JournalEntry, Error> {
+    let now = Utc::now();
+
+    let journal_entry = JournalEntry {
+      id: format!(
+        "{}-{}",
+        now.format("%Y%m%d%H%M%S"),
+        &uuid::Uuid::new_v4().simple().to_string()[..8]
+      ),
+      timestamp: now.to_rfc3339_opts(SecondsFormat::Secs, true),
+      site: self.site.clone(),
+      user: jwt_ops::get_preferred_username(shasta_token).unwrap_or_default(),
+      operation: operation.to_string(),
+      objects,
+    };
+
+    std::fs::create_dir_all(&self.path).map_err(|e| {
+      Error::Message(format!(
+        "Could not create journal directory '{}'. Reason:\n{}",
+        self.path.display(),
+        e
+      ))
+    })?;
+
+    let journal_entry_path = self.entry_path(&journal_entry.id);
+
+    std::fs::write(
+      &journal_entry_path,
+      serde_json::to_string_pretty(&journal_entry)?,
+    )
+    .map_err(|e| {
+      Error::Message(format!(
+        "Could not write journal entry '{}'. Reason:\n{}",
+        journal_entry_path.display(),
+        e
+      ))
+    })?;
+
+    log::info!(
+      "Journal entry '{}' written to '{}'",
+      journal_entry.id,
+      journal_entry_path.display()
+    );
+
+    eprintln!(
+      "Previous state saved. Run 'manta undo {}' to restore it",
+      journal_entry.id
+    );
+
+    Ok(journal_entry)
+  }
+
+  pub fn get(&self, journal_id: &str) -> Result<JournalEntry, Error> {
+    if !is_valid_id(journal_id) {
+      return Err(Error::Message(format!(
+        "Journal entry id '{}' not valid. Run 'manta undo' to list journal entries",
+        journal_id
+      )));
+    }
+
+    let journal_entry_path = self.entry_path(journal_id);
+
+    let journal_entry_content = std::fs::read_to_string(&journal_entry_path)
+      .map_err(|_| {
+        Error::Message(format!("Journal entry '{}' not found", journal_id))
+      })?;
+
+    serde_json::from_str(&journal_entry_content).map_err(|e| {
+      Error::Message(format!(
+        "Could not parse journal entry '{}'. Reason:\n{}",
+        journal_entry_path.display(),
+        e
+      ))
+    })
+  }
+
+  /// Journal entries of the site sorted by date
+  pub fn list(&self) -> Result<Vec<JournalEntry>, Error> {
+    let dir_entry_iter = match std::fs::read_dir(&self.path) {
+      Ok(dir_entry_iter) => dir_entry_iter,
+      Err(_) => return Ok(Vec::new()),
+    };
+
+    let mut journal_entry_vec: Vec<JournalEntry> = dir_entry_iter
+      .filter_map(Result::ok)
+      .filter_map(|dir_entry| {
+        let content = std::fs::read_to_string(dir_entry.path()).ok()?;
+        serde_json::from_str::<JournalEntry>(&content).ok()
+      })
+      .filter(|journal_entry| journal_entry.site == self.site)
+      .collect();
+
+    journal_entry_vec.sort_by(|journal_entry_1, journal_entry_2| {
+      journal_entry_1.id.cmp(&journal_entry_2.id)
+    });
+
+    Ok(journal_entry_vec)
+  }
+
+  fn entry_path(&self, journal_id: &str) -> PathBuf {
+    self.path.join(format!("{}.json", journal_id))
+  }
+}
+
+/// Journal entry ids are the date the entry was recorded followed by a
+/// random suffix eg '20241015103000-1a2b3c4d'. Anything else could point to
+/// a file outside the journal directory
+pub fn is_valid_id(journal_id: &str) -> bool {
+  match journal_id.split_once('-') {
+    Some((date, suffix)) => {
+      date.len() == 14
+        && date.chars().all(|c| c.is_ascii_digit())
+        && suffix.len() == 8
+        && suffix
+          .chars()
+          .all(|c| c.is_ascii_digit() || ('a'..='f').contains(&c))
+    }
+    None => false,
+  }
+}
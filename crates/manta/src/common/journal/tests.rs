@@ -0,0 +1,26 @@
+use super::{is_valid_id, Journal};
+
+#[test]
+fn test_is_valid_id() {
+  assert!(is_valid_id("20241015103000-1a2b3c4d"));
+
+  assert!(!is_valid_id(""));
+  assert!(!is_valid_id("20241015103000"));
+  assert!(!is_valid_id("20241015103000-1A2B3C4D"));
+  assert!(!is_valid_id("20241015103000-1a2b3c4d5"));
+  assert!(!is_valid_id("../../etc/passwd"));
+  assert!(!is_valid_id("20241015103000-../../a"));
+  assert!(!is_valid_id("2024101510300/-1a2b3c4d"));
+}
+
+#[test]
+fn test_get_rejects_ids_outside_journal_directory() {
+  let journal = Journal {
+    path: std::env::temp_dir().join("manta-journal-test"),
+    site: "alps".to_string(),
+  };
+
+  let error = journal.get("../../etc/passwd").unwrap_err();
+
+  assert!(error.to_string().contains("not valid"));
+}
@@ -0,0 +1,109 @@
+use manta_backend_dispatcher::{
+  error::Error, interfaces::hsm::lock::LockTrait,
+};
+
+use crate::manta_backend_dispatcher::StaticBackendDispatcher;
+
+/// Returns an error if any node is locked or reserved, eg because another
+/// operator is working on it. Nodes are not checked if the backend does not
+/// support locks
+pub async fn check_nodes_not_locked(
+  backend: &StaticBackendDispatcher,
+  shasta_token: &str,
+  xname_vec: &[String],
+) -> Result<(), Error> {
+  let component_lock_status_vec =
+    match backend.get_locks_status(shasta_token, xname_vec).await {
+      Ok(component_lock_status_vec) => component_lock_status_vec,
+      Err(Error::UnsupportedCapability(e)) => {
+        log::warn!("Nodes not checked for locks. Reason: {}", e);
+        return Ok(());
+      }
+      Err(e) => return Err(e),
+    };
+
+  let mut xname_locked_vec: Vec<String> = component_lock_status_vec
+    .into_iter()
+    .filter(|component_lock_status| {
+      component_lock_status.locked || component_lock_status.reserved
+    })
+    .map(|component_lock_status| component_lock_status.id)
+    .collect();
+
+  if xname_locked_vec.is_empty() {
+    return Ok(());
+  }
+
+  xname_locked_vec.sort();
+
+  Err(Error::Message(format!(
+    "Nodes locked or reserved by another operation: {}. Run 'manta get locks' for details",
+    xname_locked_vec.join(", ")
+  )))
+}
+
+/// Locks nodes so other operations can't change them. Fails without locking
+/// any node if any of them is already locked or reserved. Returns false if
+/// the backend does not support locks, in which case there is nothing to
+/// unlock afterwards
+pub async fn lock_nodes(
+  backend: &StaticBackendDispatcher,
+  shasta_token: &str,
+  xname_vec: &[String],
+) -> Result<bool, Error> {
+  check_nodes_not_locked(backend, shasta_token, xname_vec).await?;
+
+  let lock_result = match backend.lock_components(shasta_token, xname_vec).await
+  {
+    Ok(lock_result) => lock_result,
+    Err(Error::UnsupportedCapability(e)) => {
+      log::warn!("Nodes not locked. Reason: {}", e);
+      return Ok(false);
+    }
+    Err(e) => return Err(e),
+  };
+
+  if !lock_result.failure.is_empty() {
+    return Err(Error::Message(format!(
+      "Could not lock nodes: {}",
+      lock_result
+        .failure
+        .iter()
+        .map(|failure| format!("{} ({})", failure.id, failure.reason))
+        .collect::<Vec<_>>()
+        .join(", ")
+    )));
+  }
+
+  log::info!("Nodes locked: {}", lock_result.success.join(", "));
+
+  Ok(true)
+}
+
+/// Unlocks nodes locked with `lock_nodes`. Failures are reported but do not
+/// stop the caller since the operation the nodes were locked for is already
+/// done
+pub async fn unlock_nodes(
+  backend: &StaticBackendDispatcher,
+  shasta_token: &str,
+  xname_vec: &[String],
+) {
+  match backend.unlock_components(shasta_token, xname_vec).await {
+    Ok(lock_result) if lock_result.failure.is_empty() => {
+      log::info!("Nodes unlocked: {}", lock_result.success.join(", "));
+    }
+    Ok(lock_result) => eprintln!(
+      "WARNING - Could not unlock nodes: {}. Run 'manta unlock' to unlock them",
+      lock_result
+        .failure
+        .iter()
+        .map(|failure| format!("{} ({})", failure.id, failure.reason))
+        .collect::<Vec<_>>()
+        .join(", ")
+    ),
+    Err(e) => eprintln!(
+      "WARNING - Could not unlock nodes. Reason:\n{}\nRun 'manta unlock' to unlock them",
+      e
+    ),
+  }
+}
@@ -1,4 +1,6 @@
+pub mod asciicast;
 pub mod audit;
+pub mod audit_file;
 pub mod authorization;
 pub mod boot_parameters;
 pub mod bos_sessiontemplate_utils;
@@ -6,15 +8,20 @@ pub mod cfs_configuration_utils;
 pub mod cfs_session_utils;
 pub mod check_network_connectivity;
 pub mod config;
+pub mod exit_code;
 pub mod hooks;
 pub mod hw_inventory_utils;
 pub mod ims_ops;
+pub mod journal;
 pub mod jwt_ops;
 pub mod kafka;
 pub mod kernel_parameters_ops;
 pub mod local_git_repo;
+pub mod lock_ops;
 pub mod log_ops;
 pub mod node_ops;
 pub mod pcs_utils;
+pub mod syslog;
 pub mod terminal_ops;
 pub mod vault;
+pub mod webhook;
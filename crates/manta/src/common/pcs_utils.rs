@@ -1,4 +1,5 @@
 use comfy_table::Table;
+use manta_backend_dispatcher::types::pcs::power_cap::types::PowerCapTaskInfo;
 use serde_json::Value;
 
 pub fn print_summary_table(transition: Value, output: &str) {
@@ -33,3 +34,111 @@ pub fn print_summary_table(transition: Value, output: &str) {
     println!("{}", serde_json::to_string_pretty(&transition).unwrap());
   }
 }
+
+pub fn print_power_cap_task(power_cap_task: &PowerCapTaskInfo, output: &str) {
+  if output == "table" {
+    println!(
+      "\nTask ID: {}",
+      power_cap_task.task_id.as_deref().unwrap_or("N/A")
+    );
+    println!(
+      "Task Type: {}",
+      power_cap_task.r#type.as_deref().unwrap_or("N/A")
+    );
+    println!(
+      "Task Status: {}",
+      power_cap_task.task_status.as_deref().unwrap_or("N/A")
+    );
+
+    let mut table = Table::new();
+
+    table
+      .load_preset(comfy_table::presets::ASCII_FULL_CONDENSED)
+      .set_header(vec![
+        "XNAME", "Control", "Current", "Minimum", "Maximum", "Error",
+      ]);
+
+    for component in power_cap_task.components.iter().flatten() {
+      let xname = component.xname.clone().unwrap_or_default();
+      let error = component.error.clone().unwrap_or_default();
+
+      let power_cap_limit_vec =
+        component.power_cap_limits.clone().unwrap_or_default();
+
+      if power_cap_limit_vec.is_empty() {
+        table.add_row(vec![
+          xname,
+          "".to_string(),
+          "".to_string(),
+          "".to_string(),
+          "".to_string(),
+          error,
+        ]);
+        continue;
+      }
+
+      for power_cap_limit in power_cap_limit_vec {
+        table.add_row(vec![
+          xname.clone(),
+          power_cap_limit.name.unwrap_or_default(),
+          power_cap_limit
+            .current_value
+            .map(|value| value.to_string())
+            .unwrap_or_default(),
+          power_cap_limit
+            .minimum_value
+            .map(|value| value.to_string())
+            .unwrap_or_default(),
+          power_cap_limit
+            .maximum_value
+            .map(|value| value.to_string())
+            .unwrap_or_default(),
+          error.clone(),
+        ]);
+      }
+    }
+
+    println!("{table}");
+  } else if output.to_lowercase() == "json" {
+    println!("{}", serde_json::to_string_pretty(&power_cap_task).unwrap());
+  }
+}
+
+pub fn print_power_cap_task_vec(
+  power_cap_task_vec: &[PowerCapTaskInfo],
+  output: &str,
+) {
+  if output == "table" {
+    let mut table = Table::new();
+
+    table
+      .load_preset(comfy_table::presets::ASCII_FULL_CONDENSED)
+      .set_header(vec![
+        "Task ID",
+        "Type",
+        "Status",
+        "Creation time",
+        "Expiration time",
+      ]);
+
+    for power_cap_task in power_cap_task_vec {
+      table.add_row(vec![
+        power_cap_task.task_id.clone().unwrap_or_default(),
+        power_cap_task.r#type.clone().unwrap_or_default(),
+        power_cap_task.task_status.clone().unwrap_or_default(),
+        power_cap_task.task_create_time.clone().unwrap_or_default(),
+        power_cap_task
+          .automatic_expiration_time
+          .clone()
+          .unwrap_or_default(),
+      ]);
+    }
+
+    println!("{table}");
+  } else if output.to_lowercase() == "json" {
+    println!(
+      "{}",
+      serde_json::to_string_pretty(&power_cap_task_vec).unwrap()
+    );
+  }
+}
@@ -123,25 +123,4 @@ pub mod http_client {
       vault_secret["data"]["token"].as_str().unwrap(),
     )) // this works for vault v1.12.0 for older versions may need vault_secret["data"]["token"]
   }
-
-  pub async fn fetch_shasta_k8s_secrets_from_vault(
-    vault_base_url: &str,
-    site_name: &str,
-    // vault_role_id: &str,
-    shasta_token: &str,
-    // secret_path: &str,
-  ) -> Result<Value, Error> {
-    let vault_token =
-      auth_oidc_jwt(vault_base_url, shasta_token, site_name).await?;
-
-    let vault_secret_path = format!("manta/data/{}", site_name);
-
-    fetch_secret(
-      &vault_token,
-      vault_base_url,
-      &format!("/v1/{}/k8s", vault_secret_path),
-    )
-    .await
-    .map(|secret| secret["data"].clone())
-  }
 }
@@ -2,8 +2,8 @@ mod cli;
 mod common;
 mod manta_backend_dispatcher;
 
-use ::manta_backend_dispatcher::types::K8sAuth;
-use common::{config::types::MantaConfiguration, kafka::Kafka};
+use ::manta_backend_dispatcher::contracts::BackendTrait;
+use common::{audit::Auditor, config::types::MantaConfiguration};
 use manta_backend_dispatcher::StaticBackendDispatcher;
 
 use crate::common::log_ops;
@@ -31,48 +31,32 @@ async fn main() -> core::result::Result<(), Box<dyn std::error::Error>> {
   let site_detail_value = configuration.sites.get(&site_name).unwrap();
 
   let backend_tech = &site_detail_value.backend;
-  let shasta_base_url = &site_detail_value.shasta_base_url;
-  let shasta_barebone_url = shasta_base_url // HACK to not break compatibility with
-    .strip_suffix("/apis")
-    .unwrap_or(&shasta_base_url);
-  let shasta_api_url = match backend_tech.as_str() {
-    "csm" => shasta_barebone_url.to_owned() + "/apis",
-    "ochami" => shasta_barebone_url.to_owned(),
-    _ => {
-      eprintln!("ERROR - Invalid backend technology: {}", backend_tech);
+  let shasta_api_url = common::config::get_shasta_api_url(site_detail_value)
+    .unwrap_or_else(|e| {
+      eprintln!("ERROR - {}", e);
       std::process::exit(1);
-    }
-  };
+    });
   log::debug!("config - shasta_api_url:  {shasta_api_url}");
-  let gitea_base_url = shasta_barebone_url.to_owned() + "/vcs";
+  let gitea_base_url = common::config::get_gitea_base_url(site_detail_value);
   log::debug!("config - gitea_base_url:  {gitea_base_url}");
-  let k8s_api_url: Option<&String> = site_detail_value
-    .k8s
-    .as_ref()
-    .map(|k8s_details| &k8s_details.api_url);
-  log::debug!("config - k8s_api_url:  {k8s_api_url:?}");
-  let vault_base_url =
-    site_detail_value
-      .k8s
-      .as_ref()
-      .and_then(|k8s| match &k8s.authentication {
-        K8sAuth::Vault { base_url, .. } => Some(base_url),
-        K8sAuth::Native { .. } => None,
-      });
+  let vault_base_url = common::config::get_vault_base_url(site_detail_value);
   log::debug!("config - vault_base_url:  {vault_base_url:?}");
 
   // let audit_detail = settings.get_table("audit").unwrap();
-  let audit_detail = configuration.auditor.clone();
-  let audit_kafka_opt: Option<Kafka> = if audit_detail.is_some() {
-    Some(audit_detail.unwrap().kafka)
-  } else {
-    None
-  };
+  let auditor_opt: Option<Auditor> = configuration
+    .auditor
+    .clone()
+    .filter(|auditor| !auditor.is_empty())
+    .map(|auditor| Auditor {
+      site: site_name.clone(),
+      backend: backend_tech.clone(),
+      ..auditor
+    });
 
   let log_level = settings.get_string("log").unwrap_or("error".to_string());
   log::debug!("config - log_level:  {log_level}");
 
-  if audit_kafka_opt.is_none() {
+  if auditor_opt.is_none() {
     log::warn!("config - Auditor not defined");
   }
 
@@ -84,7 +68,7 @@ async fn main() -> core::result::Result<(), Box<dyn std::error::Error>> {
     };
   log::debug!("config - audit_file_path:  {audit_file_path}");
 
-  log_ops::configure(log_level, audit_file_path.as_str()); 
+  log_ops::configure(log_level, audit_file_path.as_str());
 
   if let Some(socks_proxy) = &site_detail_value.socks5_proxy {
     let socks_proxy = socks_proxy.to_string();
@@ -123,7 +107,10 @@ async fn main() -> core::result::Result<(), Box<dyn std::error::Error>> {
   );
 
   // Process input params
-  let cli = crate::cli::build::build_cli();
+  let cli = crate::cli::capability::hide_unsupported_subcommands(
+    crate::cli::build::build_cli(),
+    &backend.get_capabilities(),
+  );
 
   let cli_result = crate::cli::process::process_cli(
     cli,
@@ -133,8 +120,7 @@ async fn main() -> core::result::Result<(), Box<dyn std::error::Error>> {
     vault_base_url,
     &gitea_base_url,
     settings_hsm_group_name_opt.as_ref(),
-    k8s_api_url,
-    audit_kafka_opt.as_ref(),
+    auditor_opt.as_ref(),
     &settings,
     &configuration,
   )
@@ -142,6 +128,9 @@ async fn main() -> core::result::Result<(), Box<dyn std::error::Error>> {
 
   match cli_result {
     Ok(_) => Ok(()),
-    Err(e) => panic!("{}", e),
+    Err(e) => {
+      eprintln!("{}", e);
+      std::process::exit(common::exit_code::from_error(e.as_ref()));
+    }
   }
 }
@@ -22,9 +22,11 @@ use manta_backend_dispatcher::{
     commands::CommandsTrait,
     console::ConsoleTrait,
     get_images_and_details::GetImagesAndDetailsTrait,
+    health::HealthTrait,
     hsm::{
-      component::ComponentTrait, group::GroupTrait,
-      hardware_inventory::HardwareInventory,
+      component::ComponentTrait, ethernet_interfaces::EthernetInterfaceTrait,
+      group::GroupTrait, hardware_inventory::HardwareInventory,
+      lock::LockTrait, node_map::NodeMapTrait, partition::PartitionTrait,
       redfish_endpoint::RedfishEndpointTrait,
     },
     ims::ImsTrait,
@@ -36,17 +38,33 @@ use manta_backend_dispatcher::{
     self,
     bos::{session::BosSession, session_template::BosSessionTemplate},
     bss::BootParameters,
+    capability::Capability,
     cfs::{
+      cfs_configuration_derivatives::CfsConfigurationDerivatives,
       cfs_configuration_details::LayerDetails,
       cfs_configuration_request::CfsConfigurationRequest,
       cfs_configuration_response::{CfsConfigurationResponse, Layer},
       component::Component as CfsComponent,
       session::{CfsSessionGetResponse, CfsSessionPostRequest},
     },
-    hsm::inventory::{RedfishEndpoint, RedfishEndpointArray},
+    gc::{GcItem, GcPolicy, GcReport},
+    hsm::{
+      inventory::{
+        EthernetInterface, HWInvHistArray, IpAddressMapping, RedfishEndpoint,
+        RedfishEndpointArray,
+      },
+      lock::{ComponentLockStatus, LockResult},
+      node_map::NodeMap,
+      partition::Partition,
+    },
     ims::Image,
-    Component, ComponentArrayPostArray, Group, HWInventoryByLocationList,
-    K8sDetails, NodeMetadataArray,
+    orphan::OrphanReport,
+    pcs::power_cap::types::{
+      PowerCapPatch, PowerCapTaskInfo, PowerCapTaskList,
+    },
+    pcs::power_status::types::PowerStatusAll,
+    Component, ComponentArrayPostArray, ComponentFlag, Group, HWInventoryByFRU,
+    HWInventoryByLocationList, K8sDetails, NodeMetadataArray,
   },
 };
 
@@ -384,6 +402,64 @@ impl HardwareInventory for StaticBackendDispatcher {
       OCHAMI(b) => b.post_inventory_hardware(auth_token, hardware).await,
     }
   }
+
+  async fn get_inventory_hardware_by_fru(
+    &self,
+    auth_token: &str,
+    fru_id: Option<&str>,
+    r#type: Option<&str>,
+    manufacturer: Option<&str>,
+    part_number: Option<&str>,
+    serial_number: Option<&str>,
+  ) -> Result<Vec<HWInventoryByFRU>, Error> {
+    match self {
+      CSM(b) => {
+        b.get_inventory_hardware_by_fru(
+          auth_token,
+          fru_id,
+          r#type,
+          manufacturer,
+          part_number,
+          serial_number,
+        )
+        .await
+      }
+      OCHAMI(b) => {
+        b.get_inventory_hardware_by_fru(
+          auth_token,
+          fru_id,
+          r#type,
+          manufacturer,
+          part_number,
+          serial_number,
+        )
+        .await
+      }
+    }
+  }
+
+  async fn get_inventory_hardware_history_by_fru(
+    &self,
+    auth_token: &str,
+    fru_id: &str,
+    start_time: Option<&str>,
+    end_time: Option<&str>,
+  ) -> Result<HWInvHistArray, Error> {
+    match self {
+      CSM(b) => {
+        b.get_inventory_hardware_history_by_fru(
+          auth_token, fru_id, start_time, end_time,
+        )
+        .await
+      }
+      OCHAMI(b) => {
+        b.get_inventory_hardware_history_by_fru(
+          auth_token, fru_id, start_time, end_time,
+        )
+        .await
+      }
+    }
+  }
 }
 
 impl ComponentTrait for StaticBackendDispatcher {
@@ -509,6 +585,249 @@ impl ComponentTrait for StaticBackendDispatcher {
       OCHAMI(b) => b.delete_node(auth_token, id).await,
     }
   }
+
+  async fn get_node_details(
+    &self,
+    auth_token: &str,
+    xname_vec: &[String],
+  ) -> Result<Value, Error> {
+    match self {
+      CSM(b) => b.get_node_details(auth_token, xname_vec).await,
+      OCHAMI(b) => b.get_node_details(auth_token, xname_vec).await,
+    }
+  }
+
+  async fn update_components_enabled(
+    &self,
+    auth_token: &str,
+    xname_vec: &[String],
+    enabled: bool,
+  ) -> Result<(), Error> {
+    match self {
+      CSM(b) => {
+        b.update_components_enabled(auth_token, xname_vec, enabled)
+          .await
+      }
+      OCHAMI(b) => {
+        b.update_components_enabled(auth_token, xname_vec, enabled)
+          .await
+      }
+    }
+  }
+
+  async fn update_components_flag(
+    &self,
+    auth_token: &str,
+    xname_vec: &[String],
+    flag: ComponentFlag,
+  ) -> Result<(), Error> {
+    match self {
+      CSM(b) => b.update_components_flag(auth_token, xname_vec, flag).await,
+      OCHAMI(b) => b.update_components_flag(auth_token, xname_vec, flag).await,
+    }
+  }
+
+  async fn update_components_software_status(
+    &self,
+    auth_token: &str,
+    xname_vec: &[String],
+    software_status: &str,
+  ) -> Result<(), Error> {
+    match self {
+      CSM(b) => {
+        b.update_components_software_status(
+          auth_token,
+          xname_vec,
+          software_status,
+        )
+        .await
+      }
+      OCHAMI(b) => {
+        b.update_components_software_status(
+          auth_token,
+          xname_vec,
+          software_status,
+        )
+        .await
+      }
+    }
+  }
+
+  async fn update_components_role(
+    &self,
+    auth_token: &str,
+    xname_vec: &[String],
+    role: &str,
+    sub_role: Option<&str>,
+  ) -> Result<(), Error> {
+    match self {
+      CSM(b) => {
+        b.update_components_role(auth_token, xname_vec, role, sub_role)
+          .await
+      }
+      OCHAMI(b) => {
+        b.update_components_role(auth_token, xname_vec, role, sub_role)
+          .await
+      }
+    }
+  }
+}
+
+impl PartitionTrait for StaticBackendDispatcher {
+  async fn get_partitions(
+    &self,
+    auth_token: &str,
+    name: Option<&str>,
+    tag: Option<&str>,
+  ) -> Result<Vec<Partition>, Error> {
+    match self {
+      CSM(b) => b.get_partitions(auth_token, name, tag).await,
+      OCHAMI(b) => b.get_partitions(auth_token, name, tag).await,
+    }
+  }
+
+  async fn get_partition(
+    &self,
+    auth_token: &str,
+    name: &str,
+  ) -> Result<Partition, Error> {
+    match self {
+      CSM(b) => b.get_partition(auth_token, name).await,
+      OCHAMI(b) => b.get_partition(auth_token, name).await,
+    }
+  }
+
+  async fn add_partition(
+    &self,
+    auth_token: &str,
+    partition: &Partition,
+  ) -> Result<(), Error> {
+    match self {
+      CSM(b) => b.add_partition(auth_token, partition).await,
+      OCHAMI(b) => b.add_partition(auth_token, partition).await,
+    }
+  }
+
+  async fn delete_partition(
+    &self,
+    auth_token: &str,
+    name: &str,
+  ) -> Result<(), Error> {
+    match self {
+      CSM(b) => b.delete_partition(auth_token, name).await,
+      OCHAMI(b) => b.delete_partition(auth_token, name).await,
+    }
+  }
+
+  async fn add_members_to_partition(
+    &self,
+    auth_token: &str,
+    name: &str,
+    members: &[String],
+  ) -> Result<Vec<String>, Error> {
+    match self {
+      CSM(b) => b.add_members_to_partition(auth_token, name, members).await,
+      OCHAMI(b) => b.add_members_to_partition(auth_token, name, members).await,
+    }
+  }
+
+  async fn delete_member_from_partition(
+    &self,
+    auth_token: &str,
+    name: &str,
+    xname: &str,
+  ) -> Result<(), Error> {
+    match self {
+      CSM(b) => {
+        b.delete_member_from_partition(auth_token, name, xname)
+          .await
+      }
+      OCHAMI(b) => {
+        b.delete_member_from_partition(auth_token, name, xname)
+          .await
+      }
+    }
+  }
+}
+
+impl LockTrait for StaticBackendDispatcher {
+  async fn get_locks_status(
+    &self,
+    auth_token: &str,
+    xname_vec: &[String],
+  ) -> Result<Vec<ComponentLockStatus>, Error> {
+    match self {
+      CSM(b) => b.get_locks_status(auth_token, xname_vec).await,
+      OCHAMI(b) => b.get_locks_status(auth_token, xname_vec).await,
+    }
+  }
+
+  async fn lock_components(
+    &self,
+    auth_token: &str,
+    xname_vec: &[String],
+  ) -> Result<LockResult, Error> {
+    match self {
+      CSM(b) => b.lock_components(auth_token, xname_vec).await,
+      OCHAMI(b) => b.lock_components(auth_token, xname_vec).await,
+    }
+  }
+
+  async fn unlock_components(
+    &self,
+    auth_token: &str,
+    xname_vec: &[String],
+  ) -> Result<LockResult, Error> {
+    match self {
+      CSM(b) => b.unlock_components(auth_token, xname_vec).await,
+      OCHAMI(b) => b.unlock_components(auth_token, xname_vec).await,
+    }
+  }
+}
+
+impl NodeMapTrait for StaticBackendDispatcher {
+  async fn get_node_maps(
+    &self,
+    auth_token: &str,
+  ) -> Result<Vec<NodeMap>, Error> {
+    match self {
+      CSM(b) => b.get_node_maps(auth_token).await,
+      OCHAMI(b) => b.get_node_maps(auth_token).await,
+    }
+  }
+
+  async fn get_node_map(
+    &self,
+    auth_token: &str,
+    xname: &str,
+  ) -> Result<NodeMap, Error> {
+    match self {
+      CSM(b) => b.get_node_map(auth_token, xname).await,
+      OCHAMI(b) => b.get_node_map(auth_token, xname).await,
+    }
+  }
+
+  async fn add_node_maps(
+    &self,
+    auth_token: &str,
+    node_map_vec: &[NodeMap],
+  ) -> Result<(), Error> {
+    match self {
+      CSM(b) => b.add_node_maps(auth_token, node_map_vec).await,
+      OCHAMI(b) => b.add_node_maps(auth_token, node_map_vec).await,
+    }
+  }
+
+  async fn delete_node_map(
+    &self,
+    auth_token: &str,
+    xname: &str,
+  ) -> Result<(), Error> {
+    match self {
+      CSM(b) => b.delete_node_map(auth_token, xname).await,
+      OCHAMI(b) => b.delete_node_map(auth_token, xname).await,
+    }
+  }
 }
 
 impl PCSTrait for StaticBackendDispatcher {
@@ -546,6 +865,89 @@ impl PCSTrait for StaticBackendDispatcher {
       OCHAMI(b) => b.power_reset_sync(auth_token, nodes, force).await,
     }
   }
+
+  async fn power_status(
+    &self,
+    auth_token: &str,
+    nodes: &[String],
+    power_state_filter: Option<&str>,
+    management_state_filter: Option<&str>,
+  ) -> Result<PowerStatusAll, Error> {
+    match self {
+      CSM(b) => {
+        b.power_status(
+          auth_token,
+          nodes,
+          power_state_filter,
+          management_state_filter,
+        )
+        .await
+      }
+      OCHAMI(b) => {
+        b.power_status(
+          auth_token,
+          nodes,
+          power_state_filter,
+          management_state_filter,
+        )
+        .await
+      }
+    }
+  }
+
+  async fn get_power_cap_task_vec(
+    &self,
+    auth_token: &str,
+  ) -> Result<PowerCapTaskList, Error> {
+    match self {
+      CSM(b) => b.get_power_cap_task_vec(auth_token).await,
+      OCHAMI(b) => b.get_power_cap_task_vec(auth_token).await,
+    }
+  }
+
+  async fn get_power_cap_task(
+    &self,
+    auth_token: &str,
+    task_id: &str,
+  ) -> Result<PowerCapTaskInfo, Error> {
+    match self {
+      CSM(b) => b.get_power_cap_task(auth_token, task_id).await,
+      OCHAMI(b) => b.get_power_cap_task(auth_token, task_id).await,
+    }
+  }
+
+  async fn power_cap_snapshot(
+    &self,
+    auth_token: &str,
+    nodes: &[String],
+  ) -> Result<PowerCapTaskInfo, Error> {
+    match self {
+      CSM(b) => b.power_cap_snapshot(auth_token, nodes).await,
+      OCHAMI(b) => b.power_cap_snapshot(auth_token, nodes).await,
+    }
+  }
+
+  async fn power_cap_snapshot_sync(
+    &self,
+    auth_token: &str,
+    nodes: &[String],
+  ) -> Result<PowerCapTaskInfo, Error> {
+    match self {
+      CSM(b) => b.power_cap_snapshot_sync(auth_token, nodes).await,
+      OCHAMI(b) => b.power_cap_snapshot_sync(auth_token, nodes).await,
+    }
+  }
+
+  async fn power_cap_patch_sync(
+    &self,
+    auth_token: &str,
+    power_cap: &PowerCapPatch,
+  ) -> Result<PowerCapTaskInfo, Error> {
+    match self {
+      CSM(b) => b.power_cap_patch_sync(auth_token, power_cap).await,
+      OCHAMI(b) => b.power_cap_patch_sync(auth_token, power_cap).await,
+    }
+  }
 }
 
 impl BootParametersTrait for StaticBackendDispatcher {
@@ -604,6 +1006,120 @@ impl BootParametersTrait for StaticBackendDispatcher {
   }
 }
 
+impl EthernetInterfaceTrait for StaticBackendDispatcher {
+  async fn get_ethernet_interfaces(
+    &self,
+    auth_token: &str,
+    mac_address: Option<&str>,
+    ip_address: Option<&str>,
+    network: Option<&str>,
+    component_id: Option<&str>,
+    r#type: Option<&str>,
+  ) -> Result<Vec<EthernetInterface>, Error> {
+    match self {
+      CSM(b) => {
+        b.get_ethernet_interfaces(
+          auth_token,
+          mac_address,
+          ip_address,
+          network,
+          component_id,
+          r#type,
+        )
+        .await
+      }
+      OCHAMI(b) => {
+        b.get_ethernet_interfaces(
+          auth_token,
+          mac_address,
+          ip_address,
+          network,
+          component_id,
+          r#type,
+        )
+        .await
+      }
+    }
+  }
+
+  async fn get_ethernet_interface(
+    &self,
+    auth_token: &str,
+    eth_interface_id: &str,
+  ) -> Result<EthernetInterface, Error> {
+    match self {
+      CSM(b) => b.get_ethernet_interface(auth_token, eth_interface_id).await,
+      OCHAMI(b) => b.get_ethernet_interface(auth_token, eth_interface_id).await,
+    }
+  }
+
+  async fn add_ethernet_interface(
+    &self,
+    auth_token: &str,
+    ethernet_interface: &EthernetInterface,
+  ) -> Result<(), Error> {
+    match self {
+      CSM(b) => {
+        b.add_ethernet_interface(auth_token, ethernet_interface)
+          .await
+      }
+      OCHAMI(b) => {
+        b.add_ethernet_interface(auth_token, ethernet_interface)
+          .await
+      }
+    }
+  }
+
+  async fn update_ethernet_interface(
+    &self,
+    auth_token: &str,
+    eth_interface_id: &str,
+    description: Option<&str>,
+    component_id: Option<&str>,
+    ip_address_vec_opt: Option<&[IpAddressMapping]>,
+  ) -> Result<(), Error> {
+    match self {
+      CSM(b) => {
+        b.update_ethernet_interface(
+          auth_token,
+          eth_interface_id,
+          description,
+          component_id,
+          ip_address_vec_opt,
+        )
+        .await
+      }
+      OCHAMI(b) => {
+        b.update_ethernet_interface(
+          auth_token,
+          eth_interface_id,
+          description,
+          component_id,
+          ip_address_vec_opt,
+        )
+        .await
+      }
+    }
+  }
+
+  async fn delete_ethernet_interface(
+    &self,
+    auth_token: &str,
+    eth_interface_id: &str,
+  ) -> Result<(), Error> {
+    match self {
+      CSM(b) => {
+        b.delete_ethernet_interface(auth_token, eth_interface_id)
+          .await
+      }
+      OCHAMI(b) => {
+        b.delete_ethernet_interface(auth_token, eth_interface_id)
+          .await
+      }
+    }
+  }
+}
+
 impl RedfishEndpointTrait for StaticBackendDispatcher {
   async fn get_all_redfish_endpoints(
     &self,
@@ -702,6 +1218,13 @@ impl BackendTrait for StaticBackendDispatcher {
     "in manta backend".to_string()
   }
 
+  fn get_capabilities(&self) -> Vec<Capability> {
+    match self {
+      CSM(b) => b.get_capabilities(),
+      OCHAMI(b) => b.get_capabilities(),
+    }
+  }
+
   // AUTHENTICATION
   async fn get_api_token(&self, site_name: &str) -> Result<String, Error> {
     match self {
@@ -710,6 +1233,17 @@ impl BackendTrait for StaticBackendDispatcher {
     }
   }
 
+  async fn authenticate(
+    &self,
+    username: &str,
+    password: &str,
+  ) -> Result<String, Error> {
+    match self {
+      CSM(b) => b.authenticate(username, password).await,
+      OCHAMI(b) => b.authenticate(username, password).await,
+    }
+  }
+
   async fn nid_to_xname(
     &self,
     auth_token: &str,
@@ -1275,10 +1809,8 @@ impl SatTrait for StaticBackendDispatcher {
     shasta_token: &str,
     shasta_base_url: &str,
     shasta_root_cert: &[u8],
-    vault_base_url: &str,
     vault_secret_path: &str,
-    k8s_api_url: &str,
-    shasta_k8s_secrets: serde_json::Value,
+    k8s: &K8sDetails,
     sat_template_file_yaml: serde_yaml::Value,
     hsm_group_available_vec: &Vec<String>,
     ansible_verbosity_opt: Option<u8>,
@@ -1297,10 +1829,8 @@ impl SatTrait for StaticBackendDispatcher {
           shasta_token,
           shasta_base_url,
           shasta_root_cert,
-          vault_base_url,
           vault_secret_path,
-          k8s_api_url,
-          shasta_k8s_secrets,
+          k8s,
           sat_template_file_yaml,
           hsm_group_available_vec,
           ansible_verbosity_opt,
@@ -1320,10 +1850,8 @@ impl SatTrait for StaticBackendDispatcher {
           shasta_token,
           shasta_base_url,
           shasta_root_cert,
-          vault_base_url,
           vault_secret_path,
-          k8s_api_url,
-          shasta_k8s_secrets,
+          k8s,
           sat_template_file_yaml,
           hsm_group_available_vec,
           ansible_verbosity_opt,
@@ -1537,11 +2065,11 @@ impl MigrateRestoreTrait for StaticBackendDispatcher {
     shasta_token: &str,
     shasta_base_url: &str,
     shasta_root_cert: &[u8],
-    bos_file: Option<&String>,
-    cfs_file: Option<&String>,
-    hsm_file: Option<&String>,
-    ims_file: Option<&String>,
-    image_dir: Option<&String>,
+    backup_dir: &str,
+    bos_sessiontemplate_name_vec: &[String],
+    concurrency: usize,
+    assume_yes: bool,
+    overwrite_groups: bool,
   ) -> Result<(), Error> {
     match self {
       CSM(b) => {
@@ -1549,11 +2077,11 @@ impl MigrateRestoreTrait for StaticBackendDispatcher {
           shasta_token,
           shasta_base_url,
           shasta_root_cert,
-          bos_file,
-          cfs_file,
-          hsm_file,
-          ims_file,
-          image_dir,
+          backup_dir,
+          bos_sessiontemplate_name_vec,
+          concurrency,
+          assume_yes,
+          overwrite_groups,
         )
         .await
       }
@@ -1562,11 +2090,11 @@ impl MigrateRestoreTrait for StaticBackendDispatcher {
           shasta_token,
           shasta_base_url,
           shasta_root_cert,
-          bos_file,
-          cfs_file,
-          hsm_file,
-          ims_file,
-          image_dir,
+          backup_dir,
+          bos_sessiontemplate_name_vec,
+          concurrency,
+          assume_yes,
+          overwrite_groups,
         )
         .await
       }
@@ -1580,8 +2108,10 @@ impl MigrateBackupTrait for StaticBackendDispatcher {
     shasta_token: &str,
     shasta_base_url: &str,
     shasta_root_cert: &[u8],
-    bos: Option<&String>,
-    destination: Option<&String>,
+    bos_sessiontemplate_name_vec: &[String],
+    hsm_group_name_vec: &[String],
+    destination: &str,
+    concurrency: usize,
   ) -> Result<(), Error> {
     match self {
       CSM(b) => {
@@ -1589,8 +2119,10 @@ impl MigrateBackupTrait for StaticBackendDispatcher {
           shasta_token,
           shasta_base_url,
           shasta_root_cert,
-          bos,
+          bos_sessiontemplate_name_vec,
+          hsm_group_name_vec,
           destination,
+          concurrency,
         )
         .await
       }
@@ -1599,8 +2131,10 @@ impl MigrateBackupTrait for StaticBackendDispatcher {
           shasta_token,
           shasta_base_url,
           shasta_root_cert,
-          bos,
+          bos_sessiontemplate_name_vec,
+          hsm_group_name_vec,
           destination,
+          concurrency,
         )
         .await
       }
@@ -1825,7 +2359,7 @@ impl ClusterTemplateTrait for StaticBackendDispatcher {
 }
 
 impl CommandsTrait for StaticBackendDispatcher {
-  async fn i_delete_data_related_to_cfs_configuration(
+  async fn get_data_related_to_cfs_configuration(
     &self,
     shasta_token: &str,
     shasta_base_url: &str,
@@ -1835,11 +2369,10 @@ impl CommandsTrait for StaticBackendDispatcher {
     configuration_name_pattern: Option<&String>,
     since_opt: Option<NaiveDateTime>,
     until_opt: Option<NaiveDateTime>,
-    assume_yes: bool,
-  ) -> Result<(), Error> {
+  ) -> Result<CfsConfigurationDerivatives, Error> {
     match self {
       CSM(b) => {
-        b.i_delete_data_related_to_cfs_configuration(
+        b.get_data_related_to_cfs_configuration(
           shasta_token,
           shasta_base_url,
           shasta_root_cert,
@@ -1848,12 +2381,11 @@ impl CommandsTrait for StaticBackendDispatcher {
           configuration_name_pattern,
           since_opt,
           until_opt,
-          assume_yes,
         )
         .await
       }
       OCHAMI(b) => {
-        b.i_delete_data_related_to_cfs_configuration(
+        b.get_data_related_to_cfs_configuration(
           shasta_token,
           shasta_base_url,
           shasta_root_cert,
@@ -1862,17 +2394,127 @@ impl CommandsTrait for StaticBackendDispatcher {
           configuration_name_pattern,
           since_opt,
           until_opt,
-          assume_yes,
         )
         .await
       }
     }
   }
+
+  async fn delete_data_related_to_cfs_configuration(
+    &self,
+    shasta_token: &str,
+    shasta_base_url: &str,
+    shasta_root_cert: &[u8],
+    cfs_configuration_derivatives: &CfsConfigurationDerivatives,
+  ) -> Result<(), Error> {
+    match self {
+      CSM(b) => {
+        b.delete_data_related_to_cfs_configuration(
+          shasta_token,
+          shasta_base_url,
+          shasta_root_cert,
+          cfs_configuration_derivatives,
+        )
+        .await
+      }
+      OCHAMI(b) => {
+        b.delete_data_related_to_cfs_configuration(
+          shasta_token,
+          shasta_base_url,
+          shasta_root_cert,
+          cfs_configuration_derivatives,
+        )
+        .await
+      }
+    }
+  }
+
+  async fn garbage_collect(
+    &self,
+    shasta_token: &str,
+    shasta_base_url: &str,
+    shasta_root_cert: &[u8],
+    hsm_group_name_vec: &[String],
+    gc_policy: &GcPolicy,
+    dry_run: bool,
+  ) -> Result<GcReport, Error> {
+    match self {
+      CSM(b) => {
+        b.garbage_collect(
+          shasta_token,
+          shasta_base_url,
+          shasta_root_cert,
+          hsm_group_name_vec,
+          gc_policy,
+          dry_run,
+        )
+        .await
+      }
+      OCHAMI(b) => {
+        b.garbage_collect(
+          shasta_token,
+          shasta_base_url,
+          shasta_root_cert,
+          hsm_group_name_vec,
+          gc_policy,
+          dry_run,
+        )
+        .await
+      }
+    }
+  }
+
+  async fn delete_garbage(
+    &self,
+    shasta_token: &str,
+    shasta_base_url: &str,
+    shasta_root_cert: &[u8],
+    gc_item_vec: &[GcItem],
+  ) -> Result<GcReport, Error> {
+    match self {
+      CSM(b) => {
+        b.delete_garbage(
+          shasta_token,
+          shasta_base_url,
+          shasta_root_cert,
+          gc_item_vec,
+        )
+        .await
+      }
+      OCHAMI(b) => {
+        b.delete_garbage(
+          shasta_token,
+          shasta_base_url,
+          shasta_root_cert,
+          gc_item_vec,
+        )
+        .await
+      }
+    }
+  }
+
+  async fn get_orphans(
+    &self,
+    shasta_token: &str,
+    shasta_base_url: &str,
+    shasta_root_cert: &[u8],
+  ) -> Result<OrphanReport, Error> {
+    match self {
+      CSM(b) => {
+        b.get_orphans(shasta_token, shasta_base_url, shasta_root_cert)
+          .await
+      }
+      OCHAMI(b) => {
+        b.get_orphans(shasta_token, shasta_base_url, shasta_root_cert)
+          .await
+      }
+    }
+  }
 }
 
 impl ConsoleTrait for StaticBackendDispatcher {
-  type T = Box<dyn AsyncWrite + Unpin>;
-  type U = Box<dyn AsyncRead + Unpin>;
+  type T = Box<dyn AsyncWrite + Unpin + Send>;
+  type U = Box<dyn AsyncRead + Unpin + Send>;
 
   async fn attach_to_console(
     &self,
@@ -1881,18 +2523,63 @@ impl ConsoleTrait for StaticBackendDispatcher {
     xname: &str,
     width: u16,
     height: u16,
-    k8s: &K8sDetails,
-  ) -> Result<(Box<dyn AsyncWrite + Unpin>, Box<dyn AsyncRead + Unpin>), Error>
-  {
+    k8s_opt: Option<&K8sDetails>,
+  ) -> Result<(Self::T, Self::U), Error> {
     match self {
       CSM(b) => {
-        b.attach_to_console(shasta_token, site_name, xname, width, height, k8s)
+        b.attach_to_console(
+          shasta_token,
+          site_name,
+          xname,
+          width,
+          height,
+          k8s_opt,
+        )
+        .await
+      }
+      OCHAMI(b) => {
+        b.attach_to_console(
+          shasta_token,
+          site_name,
+          xname,
+          width,
+          height,
+          k8s_opt,
+        )
+        .await
+      }
+    }
+  }
+
+  async fn attach_to_console_read_only(
+    &self,
+    shasta_token: &str,
+    site_name: &str,
+    xname: &str,
+    k8s_opt: Option<&K8sDetails>,
+  ) -> Result<Self::U, Error> {
+    match self {
+      CSM(b) => {
+        b.attach_to_console_read_only(shasta_token, site_name, xname, k8s_opt)
           .await
       }
       OCHAMI(b) => {
-        b.attach_to_console(shasta_token, site_name, xname, width, height, k8s)
+        b.attach_to_console_read_only(shasta_token, site_name, xname, k8s_opt)
           .await
       }
     }
   }
 }
+
+impl HealthTrait for StaticBackendDispatcher {
+  async fn get_service_health(
+    &self,
+    auth_token: &str,
+    service: &str,
+  ) -> Result<Value, Error> {
+    match self {
+      CSM(b) => b.get_service_health(auth_token, service).await,
+      OCHAMI(b) => b.get_service_health(auth_token, service).await,
+    }
+  }
+}
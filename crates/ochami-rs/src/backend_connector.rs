@@ -1,7 +1,14 @@
+use manta_backend_dispatcher::types::pcs::power_cap::types::{
+  PowerCapPatch as FrontEndPowerCapPatch,
+  PowerCapTaskInfo as FrontEndPowerCapTaskInfo,
+  PowerCapTaskList as FrontEndPowerCapTaskList,
+};
 use manta_backend_dispatcher::types::pcs::power_status::types::PowerStatusAll as FrontEndPowerStatusAll;
 use std::{collections::HashMap, pin::Pin};
 
+use chrono::NaiveDateTime;
 use futures_io::AsyncBufRead;
+use globset::Glob;
 use hostlist_parser::parse;
 use manta_backend_dispatcher::{
   contracts::BackendTrait,
@@ -16,9 +23,11 @@ use manta_backend_dispatcher::{
     commands::CommandsTrait,
     console::ConsoleTrait,
     get_images_and_details::GetImagesAndDetailsTrait,
+    health::HealthTrait,
     hsm::{
-      component::ComponentTrait, group::GroupTrait,
-      hardware_inventory::HardwareInventory,
+      component::ComponentTrait, ethernet_interfaces::EthernetInterfaceTrait,
+      group::GroupTrait, hardware_inventory::HardwareInventory,
+      lock::LockTrait, node_map::NodeMapTrait, partition::PartitionTrait,
       redfish_endpoint::RedfishEndpointTrait,
     },
     ims::ImsTrait,
@@ -27,11 +36,32 @@ use manta_backend_dispatcher::{
     pcs::PCSTrait,
   },
   types::{
+    bos::session_template::BosSessionTemplate,
     bss::BootParameters,
-    hsm::inventory::{RedfishEndpoint, RedfishEndpointArray},
+    capability::Capability,
+    cfs::{
+      cfs_configuration_response::CfsConfigurationResponse,
+      session::CfsSessionGetResponse,
+    },
+    hsm::{
+      inventory::{
+        EthernetInterface as FrontEndEthernetInterface,
+        HWInvHistArray as FrontEndHWInvHistArray,
+        IpAddressMapping as FrontEndIpAddressMapping, RedfishEndpoint,
+        RedfishEndpointArray,
+      },
+      lock::{
+        ComponentLockStatus as FrontEndComponentLockStatus,
+        LockResult as FrontEndLockResult,
+      },
+      node_map::NodeMap as FrontEndNodeMap,
+      partition::Partition as FrontEndPartition,
+    },
+    ims::Image as FrontEndImage,
     Component, ComponentArrayPostArray as FrontEndComponentArrayPostArray,
-    Group as FrontEndGroup,
-    HWInventoryByLocationList as FrontEndHWInventoryByLocationList,
+    ComponentFlag, Group as FrontEndGroup,
+    HWInventoryByFRU as FrontEndHWInventoryByFRU,
+    HWInventoryByLocationList as FrontEndHWInventoryByLocationList, K8sDetails,
     NodeMetadataArray,
   },
 };
@@ -39,9 +69,16 @@ use regex::Regex;
 use serde_json::Value;
 use tokio::io::{AsyncRead, AsyncWrite};
 
-use crate::{authentication, bss};
+use crate::{authentication, bss, cloud_init, node};
 use crate::{
-  hsm::{self, component::types::ComponentArrayPostArray, group::types::Group},
+  hsm::{
+    self,
+    component::types::{ComponentArrayPatch, ComponentArrayPostArray},
+    group::types::Group,
+    lock::types::{LockRequest, LockStatusRequest, ProcessingModel},
+    node_map::types::NodeMapArray,
+    partition::types::Member as PartitionMember,
+  },
   pcs,
 };
 
@@ -102,7 +139,7 @@ impl GroupTrait for Ochami {
       hsm_group.clone().into(),
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))?;
+    .map_err(Error::from)?;
 
     log::info!("Group created: {}", hsm_group_backend);
 
@@ -122,7 +159,7 @@ impl GroupTrait for Ochami {
       hsm_group_name_vec,
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
   }
 
   async fn get_group_map_and_filter_by_group_vec(
@@ -137,7 +174,7 @@ impl GroupTrait for Ochami {
       hsm_name_vec,
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
   }
 
   async fn get_group_map_and_filter_by_member_vec(
@@ -152,7 +189,7 @@ impl GroupTrait for Ochami {
       hsm_name_vec,
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
   }
 
   async fn get_all_groups(
@@ -168,7 +205,7 @@ impl GroupTrait for Ochami {
       None,
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))?;
+    .map_err(Error::from)?;
 
     // Convert from HsmGroup (silla) to HsmGroup (infra)
     let hsm_group_vec =
@@ -190,7 +227,7 @@ impl GroupTrait for Ochami {
       hsm_name,
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))?;
+    .map_err(Error::from)?;
 
     let hsm_group: FrontEndGroup = hsm_group_backend.into();
 
@@ -211,7 +248,7 @@ impl GroupTrait for Ochami {
       None,
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))?;
+    .map_err(Error::from)?;
 
     // Convert from HsmGroup (silla) to HsmGroup (infra)
     let mut hsm_group_vec = Vec::new();
@@ -235,7 +272,7 @@ impl GroupTrait for Ochami {
       hsm_group_name,
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
   }
 
   async fn get_hsm_map_and_filter_by_hsm_name_vec(
@@ -250,7 +287,7 @@ impl GroupTrait for Ochami {
       hsm_name_vec,
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
   }
 
   async fn post_member(
@@ -271,7 +308,7 @@ impl GroupTrait for Ochami {
       member,
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
   }
 
   async fn add_members_to_group(
@@ -291,7 +328,7 @@ impl GroupTrait for Ochami {
         new_member,
       )
       .await
-      .map_err(|e| Error::Message(e.to_string()))?;
+      .map_err(Error::from)?;
     }
 
     Ok(sol)
@@ -311,7 +348,7 @@ impl GroupTrait for Ochami {
       xname,
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
   }
 
   async fn update_group_members(
@@ -330,7 +367,7 @@ impl GroupTrait for Ochami {
       members_to_add,
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
   }
 
   async fn migrate_group_members(
@@ -350,7 +387,7 @@ impl GroupTrait for Ochami {
       true,
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
   }
 }
 
@@ -372,10 +409,10 @@ impl HardwareInventory for Ochami {
       None,
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
     .and_then(|hw_inventory| {
       serde_json::to_value(hw_inventory)
-        .map_err(|e| Error::Message(e.to_string()))
+        .map_err(Error::from)
     })
   }
 
@@ -401,7 +438,7 @@ impl HardwareInventory for Ochami {
       format,
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
   }
 
   async fn post_inventory_hardware(
@@ -416,7 +453,58 @@ impl HardwareInventory for Ochami {
       hardware.into(),
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
+  }
+
+  async fn get_inventory_hardware_by_fru(
+    &self,
+    auth_token: &str,
+    fru_id: Option<&str>,
+    r#type: Option<&str>,
+    manufacturer: Option<&str>,
+    part_number: Option<&str>,
+    serial_number: Option<&str>,
+  ) -> Result<Vec<FrontEndHWInventoryByFRU>, Error> {
+    let hw_inventory_by_fru_vec =
+      hsm::inventory::hardware_by_fru::http_client::get(
+        auth_token,
+        &self.base_url,
+        &self.root_cert,
+        fru_id,
+        r#type,
+        manufacturer,
+        part_number,
+        serial_number,
+      )
+      .await
+      .map_err(Error::from)?;
+
+    Ok(
+      hw_inventory_by_fru_vec
+        .into_iter()
+        .map(|hw_inventory_by_fru| hw_inventory_by_fru.into())
+        .collect(),
+    )
+  }
+
+  async fn get_inventory_hardware_history_by_fru(
+    &self,
+    auth_token: &str,
+    fru_id: &str,
+    start_time: Option<&str>,
+    end_time: Option<&str>,
+  ) -> Result<FrontEndHWInvHistArray, Error> {
+    hsm::inventory::hardware_by_fru::http_client::get_history(
+      auth_token,
+      &self.base_url,
+      &self.root_cert,
+      fru_id,
+      start_time,
+      end_time,
+    )
+    .await
+    .map(|hw_inv_hist_array| hw_inv_hist_array.into())
+    .map_err(Error::from)
   }
 }
 
@@ -453,7 +541,7 @@ impl ComponentTrait for Ochami {
     )
     .await
     .map(|c| c.into())
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
   }
 
   async fn get_node_metadata_available(
@@ -517,7 +605,7 @@ impl ComponentTrait for Ochami {
     )
     .await
     .map(|c| c.into())
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
   }
 
   async fn post_nodes(
@@ -534,7 +622,7 @@ impl ComponentTrait for Ochami {
       component_backend,
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
   }
 
   async fn delete_node(
@@ -549,7 +637,354 @@ impl ComponentTrait for Ochami {
       id,
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
+  }
+
+  async fn update_components_enabled(
+    &self,
+    auth_token: &str,
+    xname_vec: &[String],
+    enabled: bool,
+  ) -> Result<(), Error> {
+    hsm::component::http_client::patch_bulk(
+      &self.base_url,
+      auth_token,
+      &self.root_cert,
+      "BulkEnabled",
+      ComponentArrayPatch {
+        component_ids: xname_vec.to_vec(),
+        enabled: Some(enabled),
+        ..Default::default()
+      },
+    )
+    .await
+    .map_err(Error::from)
+  }
+
+  async fn update_components_flag(
+    &self,
+    auth_token: &str,
+    xname_vec: &[String],
+    flag: ComponentFlag,
+  ) -> Result<(), Error> {
+    hsm::component::http_client::patch_bulk(
+      &self.base_url,
+      auth_token,
+      &self.root_cert,
+      "BulkFlagOnly",
+      ComponentArrayPatch {
+        component_ids: xname_vec.to_vec(),
+        flag: Some(flag.to_string()),
+        ..Default::default()
+      },
+    )
+    .await
+    .map_err(Error::from)
+  }
+
+  async fn update_components_software_status(
+    &self,
+    auth_token: &str,
+    xname_vec: &[String],
+    software_status: &str,
+  ) -> Result<(), Error> {
+    hsm::component::http_client::patch_bulk(
+      &self.base_url,
+      auth_token,
+      &self.root_cert,
+      "BulkSoftwareStatus",
+      ComponentArrayPatch {
+        component_ids: xname_vec.to_vec(),
+        software_status: Some(software_status.to_string()),
+        ..Default::default()
+      },
+    )
+    .await
+    .map_err(Error::from)
+  }
+
+  async fn update_components_role(
+    &self,
+    auth_token: &str,
+    xname_vec: &[String],
+    role: &str,
+    sub_role: Option<&str>,
+  ) -> Result<(), Error> {
+    hsm::component::http_client::patch_bulk(
+      &self.base_url,
+      auth_token,
+      &self.root_cert,
+      "BulkRole",
+      ComponentArrayPatch {
+        component_ids: xname_vec.to_vec(),
+        role: Some(role.to_string()),
+        sub_role: sub_role.map(str::to_string),
+        ..Default::default()
+      },
+    )
+    .await
+    .map_err(Error::from)
+  }
+}
+
+impl PartitionTrait for Ochami {
+  async fn get_partitions(
+    &self,
+    auth_token: &str,
+    name: Option<&str>,
+    tag: Option<&str>,
+  ) -> Result<Vec<FrontEndPartition>, Error> {
+    let partition_vec = hsm::partition::http_client::get(
+      &self.base_url,
+      auth_token,
+      &self.root_cert,
+      name,
+      tag,
+    )
+    .await
+    .map_err(Error::from)?;
+
+    Ok(
+      partition_vec
+        .into_iter()
+        .map(|partition| partition.into())
+        .collect(),
+    )
+  }
+
+  async fn get_partition(
+    &self,
+    auth_token: &str,
+    name: &str,
+  ) -> Result<FrontEndPartition, Error> {
+    hsm::partition::http_client::get_one(
+      &self.base_url,
+      auth_token,
+      &self.root_cert,
+      name,
+    )
+    .await
+    .map(|partition| partition.into())
+    .map_err(Error::from)
+  }
+
+  async fn add_partition(
+    &self,
+    auth_token: &str,
+    partition: &FrontEndPartition,
+  ) -> Result<(), Error> {
+    hsm::partition::http_client::post(
+      &self.base_url,
+      auth_token,
+      &self.root_cert,
+      partition.clone().into(),
+    )
+    .await
+    .map(|_| ())
+    .map_err(Error::from)
+  }
+
+  async fn delete_partition(
+    &self,
+    auth_token: &str,
+    name: &str,
+  ) -> Result<(), Error> {
+    hsm::partition::http_client::delete_one(
+      &self.base_url,
+      auth_token,
+      &self.root_cert,
+      name,
+    )
+    .await
+    .map(|_| ())
+    .map_err(Error::from)
+  }
+
+  async fn add_members_to_partition(
+    &self,
+    auth_token: &str,
+    name: &str,
+    members: &[String],
+  ) -> Result<Vec<String>, Error> {
+    for member in members {
+      hsm::partition::http_client::post_members(
+        &self.base_url,
+        auth_token,
+        &self.root_cert,
+        name,
+        PartitionMember {
+          id: Some(member.clone()),
+        },
+      )
+      .await
+      .map_err(Error::from)?;
+    }
+
+    Ok(members.to_vec())
+  }
+
+  async fn delete_member_from_partition(
+    &self,
+    auth_token: &str,
+    name: &str,
+    xname: &str,
+  ) -> Result<(), Error> {
+    hsm::partition::http_client::delete_member(
+      &self.base_url,
+      auth_token,
+      &self.root_cert,
+      name,
+      xname,
+    )
+    .await
+    .map(|_| ())
+    .map_err(Error::from)
+  }
+}
+
+impl NodeMapTrait for Ochami {
+  async fn get_node_maps(
+    &self,
+    auth_token: &str,
+  ) -> Result<Vec<FrontEndNodeMap>, Error> {
+    let node_map_array = hsm::node_map::http_client::get(
+      &self.base_url,
+      auth_token,
+      &self.root_cert,
+    )
+    .await
+    .map_err(Error::from)?;
+
+    Ok(
+      node_map_array
+        .node_maps
+        .unwrap_or_default()
+        .into_iter()
+        .map(|node_map| node_map.into())
+        .collect(),
+    )
+  }
+
+  async fn get_node_map(
+    &self,
+    auth_token: &str,
+    xname: &str,
+  ) -> Result<FrontEndNodeMap, Error> {
+    hsm::node_map::http_client::get_one(
+      &self.base_url,
+      auth_token,
+      &self.root_cert,
+      xname,
+    )
+    .await
+    .map(|node_map| node_map.into())
+    .map_err(Error::from)
+  }
+
+  async fn add_node_maps(
+    &self,
+    auth_token: &str,
+    node_map_vec: &[FrontEndNodeMap],
+  ) -> Result<(), Error> {
+    let node_map_array = NodeMapArray {
+      node_maps: Some(
+        node_map_vec
+          .iter()
+          .cloned()
+          .map(|node_map| node_map.into())
+          .collect(),
+      ),
+    };
+
+    hsm::node_map::http_client::post(
+      &self.base_url,
+      auth_token,
+      &self.root_cert,
+      node_map_array,
+    )
+    .await
+    .map(|_| ())
+    .map_err(Error::from)
+  }
+
+  async fn delete_node_map(
+    &self,
+    auth_token: &str,
+    xname: &str,
+  ) -> Result<(), Error> {
+    hsm::node_map::http_client::delete_one(
+      &self.base_url,
+      auth_token,
+      &self.root_cert,
+      xname,
+    )
+    .await
+    .map(|_| ())
+    .map_err(Error::from)
+  }
+}
+
+impl LockTrait for Ochami {
+  async fn get_locks_status(
+    &self,
+    auth_token: &str,
+    xname_vec: &[String],
+  ) -> Result<Vec<FrontEndComponentLockStatus>, Error> {
+    hsm::lock::http_client::get_status(
+      &self.base_url,
+      auth_token,
+      &self.root_cert,
+      LockStatusRequest {
+        component_ids: xname_vec.to_vec(),
+      },
+    )
+    .await
+    .map(|component_lock_status_array| {
+      component_lock_status_array
+        .components
+        .into_iter()
+        .map(|component_lock_status| component_lock_status.into())
+        .collect()
+    })
+    .map_err(Error::from)
+  }
+
+  async fn lock_components(
+    &self,
+    auth_token: &str,
+    xname_vec: &[String],
+  ) -> Result<FrontEndLockResult, Error> {
+    hsm::lock::http_client::lock(
+      &self.base_url,
+      auth_token,
+      &self.root_cert,
+      LockRequest {
+        component_ids: xname_vec.to_vec(),
+        processing_model: ProcessingModel::Rigid,
+      },
+    )
+    .await
+    .map(|lock_response| lock_response.into())
+    .map_err(Error::from)
+  }
+
+  async fn unlock_components(
+    &self,
+    auth_token: &str,
+    xname_vec: &[String],
+  ) -> Result<FrontEndLockResult, Error> {
+    hsm::lock::http_client::unlock(
+      &self.base_url,
+      auth_token,
+      &self.root_cert,
+      LockRequest {
+        component_ids: xname_vec.to_vec(),
+        processing_model: ProcessingModel::Flexible,
+      },
+    )
+    .await
+    .map(|lock_response| lock_response.into())
+    .map_err(Error::from)
   }
 }
 
@@ -569,7 +1004,7 @@ impl PCSTrait for Ochami {
       &nodes.to_vec(),
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
   }
 
   async fn power_off_sync(
@@ -588,7 +1023,7 @@ impl PCSTrait for Ochami {
       &nodes.to_vec(),
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
   }
 
   async fn power_reset_sync(
@@ -611,7 +1046,7 @@ impl PCSTrait for Ochami {
       &nodes.to_vec(),
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
   }
 
   async fn power_status(
@@ -636,10 +1071,88 @@ impl PCSTrait for Ochami {
     )
     .await
     .map(|status| {
-      println!("return value from async fn power_status : {:?}", status);
+      log::debug!("Power status:\n{:#?}", status);
       status.into()
     })
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
+  }
+
+  async fn get_power_cap_task_vec(
+    &self,
+    auth_token: &str,
+  ) -> Result<FrontEndPowerCapTaskList, Error> {
+    pcs::power_cap::http_client::get(
+      &self.base_url,
+      auth_token,
+      &self.root_cert,
+    )
+    .await
+    .map(|power_cap_task_list| power_cap_task_list.into())
+    .map_err(Error::from)
+  }
+
+  async fn get_power_cap_task(
+    &self,
+    auth_token: &str,
+    task_id: &str,
+  ) -> Result<FrontEndPowerCapTaskInfo, Error> {
+    pcs::power_cap::http_client::get_task_id(
+      &self.base_url,
+      auth_token,
+      &self.root_cert,
+      task_id,
+    )
+    .await
+    .map(|power_cap_task| power_cap_task.into())
+    .map_err(Error::from)
+  }
+
+  async fn power_cap_snapshot(
+    &self,
+    auth_token: &str,
+    nodes: &[String],
+  ) -> Result<FrontEndPowerCapTaskInfo, Error> {
+    pcs::power_cap::http_client::post_snapshot(
+      &self.base_url,
+      auth_token,
+      &self.root_cert,
+      nodes.iter().map(|node| node.as_str()).collect(),
+    )
+    .await
+    .map(|power_cap_task| power_cap_task.into())
+    .map_err(Error::from)
+  }
+
+  async fn power_cap_snapshot_sync(
+    &self,
+    auth_token: &str,
+    nodes: &[String],
+  ) -> Result<FrontEndPowerCapTaskInfo, Error> {
+    pcs::power_cap::http_client::post_snapshot_block(
+      &self.base_url,
+      auth_token,
+      &self.root_cert,
+      nodes.iter().map(|node| node.as_str()).collect(),
+    )
+    .await
+    .map(|power_cap_task| power_cap_task.into())
+    .map_err(Error::from)
+  }
+
+  async fn power_cap_patch_sync(
+    &self,
+    auth_token: &str,
+    power_cap: &FrontEndPowerCapPatch,
+  ) -> Result<FrontEndPowerCapTaskInfo, Error> {
+    pcs::power_cap::http_client::patch_block(
+      &self.base_url,
+      auth_token,
+      &self.root_cert,
+      power_cap.clone().into(),
+    )
+    .await
+    .map(|power_cap_task| power_cap_task.into())
+    .map_err(Error::from)
   }
 }
 
@@ -651,7 +1164,7 @@ impl BootParametersTrait for Ochami {
     let boot_parameter_vec =
       bss::http_client::get(&self.base_url, auth_token, &self.root_cert, &None)
         .await
-        .map_err(|e| Error::Message(e.to_string()))?;
+        .map_err(Error::from)?;
 
     let boot_parameter_infra_vec = boot_parameter_vec
       .into_iter()
@@ -679,7 +1192,7 @@ impl BootParametersTrait for Ochami {
       &hosts,
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))?;
+    .map_err(Error::from)?;
 
     let boot_parameter_infra_vec = boot_parameter_vec
       .into_iter()
@@ -701,7 +1214,7 @@ impl BootParametersTrait for Ochami {
       boot_parameters.clone().into(),
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
     .map(|boot_parameter| boot_parameter.into())
   }
 
@@ -717,7 +1230,7 @@ impl BootParametersTrait for Ochami {
       &boot_parameter.clone().into(),
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
   }
 
   async fn delete_bootparameters(
@@ -732,7 +1245,115 @@ impl BootParametersTrait for Ochami {
       &boot_parameter.clone().into(),
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
+  }
+}
+
+impl EthernetInterfaceTrait for Ochami {
+  async fn get_ethernet_interfaces(
+    &self,
+    auth_token: &str,
+    mac_address: Option<&str>,
+    ip_address: Option<&str>,
+    network: Option<&str>,
+    component_id: Option<&str>,
+    r#type: Option<&str>,
+  ) -> Result<Vec<FrontEndEthernetInterface>, Error> {
+    hsm::inventory::ethernet_interfaces::http_client::get(
+      auth_token,
+      &self.base_url,
+      &self.root_cert,
+      mac_address,
+      ip_address,
+      network,
+      component_id,
+      r#type,
+      None,
+      None,
+    )
+    .await
+    .map(|eth_interface_vec| {
+      eth_interface_vec
+        .into_iter()
+        .map(|eth_interface| eth_interface.into())
+        .collect()
+    })
+    .map_err(Error::from)
+  }
+
+  async fn get_ethernet_interface(
+    &self,
+    auth_token: &str,
+    eth_interface_id: &str,
+  ) -> Result<FrontEndEthernetInterface, Error> {
+    hsm::inventory::ethernet_interfaces::http_client::get_one(
+      auth_token,
+      &self.base_url,
+      &self.root_cert,
+      eth_interface_id,
+    )
+    .await
+    .map(|eth_interface| eth_interface.into())
+    .map_err(Error::from)
+  }
+
+  async fn add_ethernet_interface(
+    &self,
+    auth_token: &str,
+    ethernet_interface: &FrontEndEthernetInterface,
+  ) -> Result<(), Error> {
+    hsm::inventory::ethernet_interfaces::http_client::post(
+      auth_token,
+      &self.base_url,
+      &self.root_cert,
+      &ethernet_interface.clone().into(),
+    )
+    .await
+    .map(|_| ())
+    .map_err(Error::from)
+  }
+
+  async fn update_ethernet_interface(
+    &self,
+    auth_token: &str,
+    eth_interface_id: &str,
+    description: Option<&str>,
+    component_id: Option<&str>,
+    ip_address_vec_opt: Option<&[FrontEndIpAddressMapping]>,
+  ) -> Result<(), Error> {
+    hsm::inventory::ethernet_interfaces::http_client::patch(
+      auth_token,
+      &self.base_url,
+      &self.root_cert,
+      eth_interface_id,
+      description,
+      component_id,
+      ip_address_vec_opt.map(|ip_address_vec| {
+        ip_address_vec
+          .iter()
+          .cloned()
+          .map(|ip_address_mapping| ip_address_mapping.into())
+          .collect()
+      }),
+    )
+    .await
+    .map_err(Error::from)
+  }
+
+  async fn delete_ethernet_interface(
+    &self,
+    auth_token: &str,
+    eth_interface_id: &str,
+  ) -> Result<(), Error> {
+    hsm::inventory::ethernet_interfaces::http_client::delete_one(
+      &self.base_url,
+      auth_token,
+      &self.root_cert,
+      eth_interface_id,
+    )
+    .await
+    .map(|_| ())
+    .map_err(Error::from)
   }
 }
 
@@ -748,7 +1369,7 @@ impl RedfishEndpointTrait for Ochami {
     )
     .await
     .map(|re| re.into())
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
   }
 
   async fn get_redfish_endpoints(
@@ -776,7 +1397,7 @@ impl RedfishEndpointTrait for Ochami {
     )
     .await
     .map(|re| re.into())
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
   }
 
   async fn add_redfish_endpoint(
@@ -791,7 +1412,7 @@ impl RedfishEndpointTrait for Ochami {
       redfish_endpoint.clone().into(),
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))?;
+    .map_err(Error::from)?;
 
     Ok(())
   }
@@ -809,7 +1430,7 @@ impl RedfishEndpointTrait for Ochami {
       redfish_endpoint.clone().into(),
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))?;
+    .map_err(Error::from)?;
 
     Ok(())
   }
@@ -826,7 +1447,7 @@ impl RedfishEndpointTrait for Ochami {
       id,
     )
     .await
-    .map_err(|e| Error::Message(e.to_string()))
+    .map_err(Error::from)
   }
 }
 
@@ -836,6 +1457,28 @@ impl BackendTrait for Ochami {
     "in silla backend".to_string()
   }
 
+  fn get_capabilities(&self) -> Vec<Capability> {
+    vec![
+      Capability::Groups,
+      Capability::Components,
+      Capability::ComponentState,
+      Capability::HardwareInventory,
+      Capability::FruInventory,
+      Capability::Partitions,
+      Capability::EthernetInterfaces,
+      Capability::RedfishEndpoints,
+      Capability::NodeMaps,
+      Capability::Locks,
+      Capability::BootParameters,
+      Capability::BootParametersDelete,
+      Capability::PowerManagement,
+      Capability::PowerCapping,
+      Capability::Configurations,
+      Capability::Images,
+      Capability::Console,
+    ]
+  }
+
   async fn get_api_token(&self, _site_name: &str) -> Result<String, Error> {
     authentication::get_api_token().await.map_err(|_e| {
       Error::Message(
@@ -855,6 +1498,16 @@ impl BackendTrait for Ochami {
     user_input_nid: &str,
     is_regex: bool,
   ) -> Result<Vec<String>, Error> {
+    // Node maps are much smaller than the list of HSM components, use them
+    // to resolve NIDs if the system has any
+    let nid_xname_map: HashMap<u32, String> = self
+      .get_node_maps(shasta_token)
+      .await
+      .unwrap_or_default()
+      .into_iter()
+      .map(|node_map| (node_map.nid, node_map.id))
+      .collect();
+
     if is_regex {
       log::debug!("Regex found, getting xnames from NIDs");
       // Get list of regex
@@ -864,6 +1517,19 @@ impl BackendTrait for Ochami {
         .collect::<Result<Vec<Regex>, regex::Error>>()
         .map_err(|e| Error::Message(e.to_string()))?;
 
+      // Node maps may only cover some nodes, NIDs not in them are resolved
+      // through HSM components
+      let mut xname_vec: Vec<String> = nid_xname_map
+        .iter()
+        .filter(|(nid, _)| {
+          let nid_long = format!("nid{:06}", nid);
+          regex_vec.iter().any(|regex| regex.is_match(&nid_long))
+        })
+        .map(|(_, xname)| xname.clone())
+        .collect();
+
+      log::debug!("xname list from node maps:\n{:#?}", xname_vec);
+
       // Get all HSM components (list of xnames + nids)
       let hsm_component_vec = hsm::component::http_client::get_all_nodes(
         &self.base_url,
@@ -872,25 +1538,31 @@ impl BackendTrait for Ochami {
         Some("true"),
       )
       .await
-      .map_err(|e| Error::Message(e.to_string()))?
+      .map_err(Error::from)?
       .components
       .unwrap_or_default();
 
-      let mut xname_vec: Vec<String> = vec![];
-
       // Get list of xnames the user is asking for
       for hsm_component in hsm_component_vec {
-        let nid_long =
-          format!("nid{:06}", &hsm_component.nid.expect("No NID found"));
-        for regex in &regex_vec {
-          if regex.is_match(&nid_long) {
-            log::debug!(
-              "Nid '{}' IS included in regex '{}'",
-              nid_long,
-              regex.as_str()
-            );
-            xname_vec.push(hsm_component.id.clone().expect("No XName found"));
-          }
+        let (Some(nid), Some(xname)) = (hsm_component.nid, hsm_component.id)
+        else {
+          continue;
+        };
+
+        if nid_xname_map.contains_key(&(nid as u32)) {
+          continue;
+        }
+
+        let nid_long = format!("nid{:06}", nid);
+        if let Some(regex) =
+          regex_vec.iter().find(|regex| regex.is_match(&nid_long))
+        {
+          log::debug!(
+            "Nid '{}' IS included in regex '{}'",
+            nid_long,
+            regex.as_str()
+          );
+          xname_vec.push(xname);
         }
       }
 
@@ -914,6 +1586,28 @@ impl BackendTrait for Ochami {
       log::debug!("hostlist: {}", user_input_nid);
       log::debug!("hostlist expanded: {:?}", nid_hostlist_expanded_vec);
 
+      if !nid_xname_map.is_empty() {
+        let xname_vec_opt: Option<Vec<String>> = nid_hostlist_expanded_vec
+          .iter()
+          .map(|nid_long| {
+            nid_long
+              .strip_prefix("nid")
+              .and_then(|nid| nid.parse::<u32>().ok())
+              .and_then(|nid| nid_xname_map.get(&nid).cloned())
+          })
+          .collect();
+
+        match xname_vec_opt {
+          Some(xname_vec) => {
+            log::debug!("xname list from node maps:\n{:#?}", xname_vec);
+            return Ok(xname_vec);
+          }
+          None => log::debug!(
+            "Some NIDs are not in node maps, getting xnames from HSM components"
+          ),
+        }
+      }
+
       let nid_short = nid_hostlist_expanded_vec
         .iter()
         .map(|nid_long| {
@@ -956,7 +1650,7 @@ impl BackendTrait for Ochami {
         Some("true"),
       )
       .await
-      .map_err(|e| Error::Message(e.to_string()))?;
+      .map_err(Error::from)?;
 
       // Get list of xnames from HSM components
       let xname_vec: Vec<String> = hsm_components
@@ -973,15 +1667,218 @@ impl BackendTrait for Ochami {
   }
 }
 
+/// OpenCHAMI does not have CFS, node configuration is done through cloud-init. cloud-init
+/// groups are mapped to CFS configurations
 impl CfsTrait for Ochami {
   type T = Pin<Box<dyn AsyncBufRead + Send>>;
+
+  async fn get_configuration(
+    &self,
+    auth_token: &str,
+    _base_url: &str,
+    _root_cert: &[u8],
+    configuration_name_opt: Option<&String>,
+  ) -> Result<Vec<CfsConfigurationResponse>, Error> {
+    let group_vec = if let Some(configuration_name) = configuration_name_opt {
+      cloud_init::http_client::get(
+        &self.base_url,
+        auth_token,
+        &self.root_cert,
+        configuration_name,
+      )
+      .await
+      .map(|group| vec![group])
+    } else {
+      cloud_init::http_client::get_all(
+        &self.base_url,
+        auth_token,
+        &self.root_cert,
+      )
+      .await
+    }
+    .map_err(Error::from)?;
+
+    Ok(group_vec.into_iter().map(|group| group.into()).collect())
+  }
+
+  /// NOTE: cloud-init groups are not versioned, therefore 'since' and 'until' filters are
+  /// ignored
+  async fn get_and_filter_configuration(
+    &self,
+    shasta_token: &str,
+    _shasta_base_url: &str,
+    _shasta_root_cert: &[u8],
+    configuration_name: Option<&str>,
+    configuration_name_pattern: Option<&str>,
+    hsm_group_name_vec: &[String],
+    _since_opt: Option<NaiveDateTime>,
+    _until_opt: Option<NaiveDateTime>,
+    limit_number_opt: Option<&u8>,
+  ) -> Result<Vec<CfsConfigurationResponse>, Error> {
+    let mut group_vec = cloud_init::http_client::get_all(
+      &self.base_url,
+      shasta_token,
+      &self.root_cert,
+    )
+    .await
+    .map_err(Error::from)?;
+
+    // cloud-init groups are applied to SMD groups with the same name, therefore users can only
+    // see cloud-init groups related to the groups they have access to
+    group_vec.retain(|group| hsm_group_name_vec.contains(&group.name));
+
+    if let Some(configuration_name) = configuration_name {
+      group_vec.retain(|group| group.name.eq(configuration_name));
+    }
+
+    if let Some(configuration_name_pattern) = configuration_name_pattern {
+      let glob = Glob::new(configuration_name_pattern)
+        .map_err(|e| Error::Message(e.to_string()))?
+        .compile_matcher();
+
+      group_vec.retain(|group| glob.is_match(&group.name));
+    }
+
+    group_vec.sort_by(|group_1, group_2| group_1.name.cmp(&group_2.name));
+
+    if let Some(limit_number) = limit_number_opt {
+      group_vec = group_vec
+        [group_vec.len().saturating_sub(*limit_number as usize)..]
+        .to_vec();
+    }
+
+    Ok(group_vec.into_iter().map(|group| group.into()).collect())
+  }
+
+  /// cloud-init configurations are applied to nodes members of the SMD group with the same
+  /// name, therefore, derivatives are the boot images used by the members of that group
+  async fn get_derivatives(
+    &self,
+    shasta_token: &str,
+    _shasta_base_url: &str,
+    _shasta_root_cert: &[u8],
+    configuration_name: &str,
+  ) -> Result<
+    (
+      Option<Vec<CfsSessionGetResponse>>,
+      Option<Vec<BosSessionTemplate>>,
+      Option<Vec<FrontEndImage>>,
+    ),
+    Error,
+  > {
+    let member_vec = self
+      .get_member_vec_from_group_name_vec(
+        shasta_token,
+        vec![configuration_name.to_string()],
+      )
+      .await?;
+
+    if member_vec.is_empty() {
+      return Ok((None, None, None));
+    }
+
+    let boot_param_vec = bss::http_client::get(
+      &self.base_url,
+      shasta_token,
+      &self.root_cert,
+      &Some(member_vec),
+    )
+    .await
+    .map_err(Error::from)?;
+
+    let image_vec: Vec<FrontEndImage> =
+      bss::utils::group_boot_params_by_image(boot_param_vec)
+        .iter()
+        .map(|(image_id, boot_param_vec)| {
+          bss::utils::get_image_from_boot_params(image_id, &boot_param_vec[0])
+        })
+        .collect();
+
+    Ok((None, None, Some(image_vec)))
+  }
+
+  async fn update_runtime_configuration(
+    &self,
+    _shasta_token: &str,
+    _shasta_base_url: &str,
+    _shasta_root_cert: &[u8],
+    _xnames: Vec<String>,
+    desired_configuration: &str,
+    _enabled: bool,
+  ) -> Result<(), Error> {
+    Err(Error::UnsupportedCapability(format!(
+      "OpenCHAMI applies cloud-init configurations based on group membership. Please add the nodes to group '{}' instead",
+      desired_configuration
+    )))
+  }
 }
 
 impl SatTrait for Ochami {}
 
 impl ApplyHwClusterPin for Ochami {}
 
-impl ImsTrait for Ochami {}
+/// OpenCHAMI does not have an image management service, boot images are the kernel and initrd
+/// artifacts referenced by BSS boot parameters
+impl ImsTrait for Ochami {
+  async fn get_images(
+    &self,
+    shasta_token: &str,
+    shasta_base_url: &str,
+    shasta_root_cert: &[u8],
+    image_id_opt: Option<&str>,
+  ) -> Result<Vec<FrontEndImage>, Error> {
+    let mut image_vec = self
+      .get_all_images(shasta_token, shasta_base_url, shasta_root_cert)
+      .await?;
+
+    if let Some(image_id) = image_id_opt {
+      image_vec.retain(|image| image.id.as_deref() == Some(image_id));
+    }
+
+    Ok(image_vec)
+  }
+
+  async fn get_all_images(
+    &self,
+    shasta_token: &str,
+    _shasta_base_url: &str,
+    _shasta_root_cert: &[u8],
+  ) -> Result<Vec<FrontEndImage>, Error> {
+    let boot_param_vec =
+      bss::http_client::get_all(&self.base_url, shasta_token, &self.root_cert)
+        .await
+        .map_err(Error::from)?;
+
+    Ok(
+      bss::utils::group_boot_params_by_image(boot_param_vec)
+        .iter()
+        .map(|(image_id, boot_param_vec)| {
+          bss::utils::get_image_from_boot_params(image_id, &boot_param_vec[0])
+        })
+        .collect(),
+    )
+  }
+
+  /// Images are already sorted by id and there is no naming convention to filter them
+  fn filter_images(
+    &self,
+    _image_vec: &mut Vec<FrontEndImage>,
+  ) -> Result<(), Error> {
+    Ok(())
+  }
+
+  async fn delete_image(
+    &self,
+    _shasta_token: &str,
+    _shasta_base_url: &str,
+    _shasta_root_cert: &[u8],
+    _image_id: &str,
+  ) -> Result<(), Error> {
+    Err(Error::UnsupportedCapability(
+      "OpenCHAMI boot images are not managed by any service. Please delete the image artifacts from the object storage".to_string(),
+    ))
+  }
+}
 
 impl ApplySessionTrait for Ochami {}
 
@@ -989,7 +1886,92 @@ impl MigrateRestoreTrait for Ochami {}
 
 impl MigrateBackupTrait for Ochami {}
 
-impl GetImagesAndDetailsTrait for Ochami {}
+impl GetImagesAndDetailsTrait for Ochami {
+  /// Returns the boot images used by the members of the groups requested. OpenCHAMI does not
+  /// have CFS, therefore the configuration related to each image is left empty
+  async fn get_images_and_details(
+    &self,
+    shasta_token: &str,
+    _shasta_base_url: &str,
+    _shasta_root_cert: &[u8],
+    hsm_group_name_vec: &[String],
+    id_opt: Option<&String>,
+    limit_number: Option<&u8>,
+  ) -> Result<Vec<(FrontEndImage, String, String, bool)>, Error> {
+    if hsm_group_name_vec.is_empty() {
+      return Ok(Vec::new());
+    }
+
+    let hsm_group_name_vec: Vec<&str> =
+      hsm_group_name_vec.iter().map(String::as_str).collect();
+
+    let group_vec = self
+      .get_groups(shasta_token, Some(&hsm_group_name_vec))
+      .await?;
+
+    let member_vec: Vec<String> = group_vec
+      .iter()
+      .flat_map(|group| group.get_members())
+      .collect();
+
+    if member_vec.is_empty() {
+      return Ok(Vec::new());
+    }
+
+    let boot_param_vec = bss::http_client::get(
+      &self.base_url,
+      shasta_token,
+      &self.root_cert,
+      &Some(member_vec),
+    )
+    .await
+    .map_err(Error::from)?;
+
+    let mut image_detail_vec: Vec<(FrontEndImage, String, String, bool)> =
+      bss::utils::group_boot_params_by_image(boot_param_vec)
+        .iter()
+        .filter(|(image_id, _)| match id_opt {
+          Some(id) => id.eq(image_id.as_str()),
+          None => true,
+        })
+        .map(|(image_id, boot_param_vec)| {
+          // Groups with members booting this image
+          let group_name_vec: Vec<String> = group_vec
+            .iter()
+            .filter(|group| {
+              let group_member_vec = group.get_members();
+              boot_param_vec.iter().any(|boot_param| {
+                boot_param
+                  .hosts
+                  .iter()
+                  .any(|host| group_member_vec.contains(host))
+              })
+            })
+            .map(|group| group.label.clone())
+            .collect();
+
+          (
+            bss::utils::get_image_from_boot_params(
+              image_id,
+              &boot_param_vec[0],
+            ),
+            String::new(),
+            group_name_vec.join(", "),
+            true,
+          )
+        })
+        .collect();
+
+    if let Some(limit_number) = limit_number {
+      image_detail_vec = image_detail_vec[image_detail_vec
+        .len()
+        .saturating_sub(*limit_number as usize)..]
+        .to_vec();
+    }
+
+    Ok(image_detail_vec)
+  }
+}
 
 impl ClusterSessionTrait for Ochami {}
 
@@ -998,6 +1980,43 @@ impl ClusterTemplateTrait for Ochami {}
 impl CommandsTrait for Ochami {}
 
 impl ConsoleTrait for Ochami {
-  type T = Box<dyn AsyncWrite + Unpin>;
-  type U = Box<dyn AsyncRead + Unpin>;
+  type T = Box<dyn AsyncWrite + Unpin + Send>;
+  type U = Box<dyn AsyncRead + Unpin + Send>;
+
+  /// Serial over LAN session through the node's BMC. The k8s details are not
+  /// used, OpenCHAMI has no conman
+  async fn attach_to_console(
+    &self,
+    shasta_token: &str,
+    _site_name: &str,
+    xname: &str,
+    _term_width: u16,
+    _term_height: u16,
+    _k8s_opt: Option<&K8sDetails>,
+  ) -> Result<(Self::T, Self::U), Error> {
+    let bmc_xname = node::console::get_bmc_xname(xname).ok_or_else(|| {
+      Error::Message(format!("'{}' is not a node xname", xname))
+    })?;
+
+    let redfish_endpoint =
+      hsm::inventory::redfish_endpoint::http_client::get_one(
+        shasta_token,
+        &self.base_url,
+        &self.root_cert,
+        bmc_xname,
+      )
+      .await
+      .map_err(Error::from)?;
+
+    let (stdin, stdout) = node::console::attach_to_sol(&redfish_endpoint)
+      .await
+      .map_err(Error::from)?;
+
+    println!("Connected to {}!", xname);
+    println!("Use &. key combination to exit the console.");
+
+    Ok((Box::new(stdin), Box::new(stdout)))
+  }
 }
+
+impl HealthTrait for Ochami {}
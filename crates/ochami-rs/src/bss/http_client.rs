@@ -2,6 +2,7 @@ use crate::error::Error;
 use serde_json::Value;
 
 use core::result::Result;
+use manta_backend_dispatcher::error::Service;
 
 use super::types::BootParameters;
 
@@ -56,25 +57,16 @@ pub async fn get(
     .send()
     .await?;
 
-  if let Err(e) = response.error_for_status_ref() {
-    match response.status() {
-      reqwest::StatusCode::UNAUTHORIZED => {
-        let error_payload = response.text().await?;
-        let error = Error::RequestError {
-          response: e,
-          payload: error_payload,
-        };
-        return Err(error);
-      }
-      _ => {
-        let error_payload = response.text().await?;
-        let error = Error::RequestError {
-          response: e,
-          payload: serde_json::to_string_pretty(&error_payload)?,
-        };
-        return Err(error);
-      }
-    }
+  if !response.status().is_success() {
+    let status = response.status().as_u16();
+    let payload = response.text().await?;
+
+    return Err(Error::HttpError {
+      status,
+      service: Service::Bss,
+      resource_id: None,
+      payload,
+    });
   }
 
   match response.json().await {
@@ -118,25 +110,16 @@ pub async fn post(
     .send()
     .await?;
 
-  if let Err(e) = response.error_for_status_ref() {
-    match response.status() {
-      reqwest::StatusCode::UNAUTHORIZED => {
-        let error_payload = response.text().await?;
-        let error = Error::RequestError {
-          response: e,
-          payload: error_payload,
-        };
-        return Err(error);
-      }
-      _ => {
-        let error_payload = response.json::<Value>().await?;
-        let error = Error::RequestError {
-          response: e,
-          payload: serde_json::to_string_pretty(&error_payload)?,
-        };
-        return Err(error);
-      }
-    }
+  if !response.status().is_success() {
+    let status = response.status().as_u16();
+    let payload = response.text().await?;
+
+    return Err(Error::HttpError {
+      status,
+      service: Service::Bss,
+      resource_id: None,
+      payload,
+    });
   }
 
   Ok(())
@@ -174,25 +157,16 @@ pub async fn put(
     .send()
     .await?;
 
-  if let Err(e) = response.error_for_status_ref() {
-    match response.status() {
-      reqwest::StatusCode::UNAUTHORIZED => {
-        let error_payload = response.text().await?;
-        let error = Error::RequestError {
-          response: e,
-          payload: error_payload,
-        };
-        return Err(error);
-      }
-      _ => {
-        let error_payload = response.json::<Value>().await?;
-        let error = Error::RequestError {
-          response: e,
-          payload: serde_json::to_string_pretty(&error_payload)?,
-        };
-        return Err(error);
-      }
-    }
+  if !response.status().is_success() {
+    let status = response.status().as_u16();
+    let payload = response.text().await?;
+
+    return Err(Error::HttpError {
+      status,
+      service: Service::Bss,
+      resource_id: None,
+      payload,
+    });
   }
 
   response.json().await.map_err(|e| Error::NetError(e))
@@ -229,25 +203,16 @@ pub async fn patch(
     .send()
     .await?;
 
-  if let Err(e) = response.error_for_status_ref() {
-    match response.status() {
-      reqwest::StatusCode::UNAUTHORIZED => {
-        let error_payload = response.text().await?;
-        let error = Error::RequestError {
-          response: e,
-          payload: error_payload,
-        };
-        return Err(error);
-      }
-      _ => {
-        let error_payload = response.json::<Value>().await?;
-        let error = Error::RequestError {
-          response: e,
-          payload: serde_json::to_string_pretty(&error_payload)?,
-        };
-        return Err(error);
-      }
-    }
+  if !response.status().is_success() {
+    let status = response.status().as_u16();
+    let payload = response.text().await?;
+
+    return Err(Error::HttpError {
+      status,
+      service: Service::Bss,
+      resource_id: None,
+      payload,
+    });
   }
 
   Ok(())
@@ -284,25 +249,16 @@ pub async fn delete(
     .send()
     .await?;
 
-  if let Err(e) = response.error_for_status_ref() {
-    match response.status() {
-      reqwest::StatusCode::UNAUTHORIZED => {
-        let error_payload = response.text().await?;
-        let error = Error::RequestError {
-          response: e,
-          payload: error_payload,
-        };
-        return Err(error);
-      }
-      _ => {
-        let error_payload = response.text().await?;
-        let error = Error::RequestError {
-          response: e,
-          payload: serde_json::to_string_pretty(&error_payload)?,
-        };
-        return Err(error);
-      }
-    }
+  if !response.status().is_success() {
+    let status = response.status().as_u16();
+    let payload = response.text().await?;
+
+    return Err(Error::HttpError {
+      status,
+      service: Service::Bss,
+      resource_id: None,
+      payload,
+    });
   }
 
   response
@@ -1,5 +1,8 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
+use manta_backend_dispatcher::types::ims::{
+  Image as FrontEndImage, Link as FrontEndLink,
+};
 use serde_json::Value;
 
 use super::types::BootParameters;
@@ -33,6 +36,64 @@ pub fn find_boot_params_related_to_node(
     .cloned()
 }
 
+/// OpenCHAMI does not have an image management service, boot images are the kernel and initrd
+/// artifacts referenced in boot parameters. Returns the list of boot parameters grouped by the
+/// boot image they use
+pub fn group_boot_params_by_image(
+  boot_param_vec: Vec<BootParameters>,
+) -> BTreeMap<String, Vec<BootParameters>> {
+  let mut boot_param_by_image_map: BTreeMap<String, Vec<BootParameters>> =
+    BTreeMap::new();
+
+  for boot_param in boot_param_vec {
+    let image_id = boot_param.get_boot_image();
+
+    if image_id.is_empty() {
+      continue;
+    }
+
+    boot_param_by_image_map
+      .entry(image_id)
+      .or_default()
+      .push(boot_param);
+  }
+
+  boot_param_by_image_map
+}
+
+/// Build boot image details from the boot parameters using it. The image link points to the
+/// location where kernel and initrd are stored
+pub fn get_image_from_boot_params(
+  image_id: &str,
+  boot_param: &BootParameters,
+) -> FrontEndImage {
+  let path = boot_param
+    .kernel
+    .rsplit_once('/')
+    .map(|(path, _)| path)
+    .unwrap_or(&boot_param.kernel)
+    .to_string();
+
+  let r#type = boot_param
+    .kernel
+    .split_once("://")
+    .map(|(scheme, _)| scheme)
+    .unwrap_or("s3")
+    .to_string();
+
+  FrontEndImage {
+    id: Some(image_id.to_string()),
+    created: None,
+    name: image_id.to_string(),
+    link: Some(FrontEndLink {
+      path,
+      etag: None,
+      r#type,
+    }),
+    arch: None,
+  }
+}
+
 /// Get Image ID from kernel field
 #[deprecated(
   since = "1.26.6",
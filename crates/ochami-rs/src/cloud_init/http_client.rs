@@ -0,0 +1,111 @@
+use manta_backend_dispatcher::error::Service;
+use serde_json::Value;
+
+use crate::error::Error;
+
+use super::types::Group;
+
+/// Get cloud-init groups
+/// NOTE: cloud-init API returns a map of groups indexed by group name
+pub async fn get_all(
+  base_url: &str,
+  auth_token: &str,
+  root_cert: &[u8],
+) -> Result<Vec<Group>, Error> {
+  let client_builder = reqwest::Client::builder()
+    .add_root_certificate(reqwest::Certificate::from_pem(root_cert)?)
+    .use_rustls_tls();
+
+  // Build client
+  let client = if let Ok(socks5_env) = std::env::var("SOCKS5") {
+    // socks5 proxy
+    log::debug!("SOCKS5 enabled");
+    let socks5proxy = reqwest::Proxy::all(socks5_env)?;
+
+    // rest client to authenticate
+    client_builder.proxy(socks5proxy).build()?
+  } else {
+    client_builder.build()?
+  };
+
+  let api_url: String = format!("{}/{}", base_url, "cloud-init/admin/groups");
+
+  let response = client.get(api_url).bearer_auth(auth_token).send().await?;
+
+  if !response.status().is_success() {
+    let status = response.status().as_u16();
+    let payload = response.text().await?;
+
+    return Err(Error::HttpError {
+      status,
+      service: Service::CloudInit,
+      resource_id: None,
+      payload,
+    });
+  }
+
+  match response.json().await {
+    Ok(Value::Null) => Ok(Vec::new()),
+    Ok(Value::Object(group_map)) => group_map
+      .into_iter()
+      .map(|(name, group_value)| {
+        let mut group: Group = serde_json::from_value(group_value)?;
+        if group.name.is_empty() {
+          group.name = name;
+        }
+        Ok(group)
+      })
+      .collect(),
+    Ok(v) => serde_json::from_value(v).map_err(Error::SerdeError),
+    Err(e) => Err(Error::NetError(e)),
+  }
+}
+
+/// Get cloud-init group by name
+pub async fn get(
+  base_url: &str,
+  auth_token: &str,
+  root_cert: &[u8],
+  group_name: &str,
+) -> Result<Group, Error> {
+  let client_builder = reqwest::Client::builder()
+    .add_root_certificate(reqwest::Certificate::from_pem(root_cert)?)
+    .use_rustls_tls();
+
+  // Build client
+  let client = if let Ok(socks5_env) = std::env::var("SOCKS5") {
+    // socks5 proxy
+    log::debug!("SOCKS5 enabled");
+    let socks5proxy = reqwest::Proxy::all(socks5_env)?;
+
+    // rest client to authenticate
+    client_builder.proxy(socks5proxy).build()?
+  } else {
+    client_builder.build()?
+  };
+
+  let api_url: String =
+    format!("{}/{}/{}", base_url, "cloud-init/admin/groups", group_name);
+
+  let response = client.get(api_url).bearer_auth(auth_token).send().await?;
+
+  if !response.status().is_success() {
+    let status = response.status().as_u16();
+    let payload = response.text().await?;
+
+    return Err(Error::HttpError {
+      status,
+      service: Service::CloudInit,
+      resource_id: Some(group_name.to_string()),
+      payload,
+    });
+  }
+
+  let mut group: Group = response.json().await?;
+
+  if group.name.is_empty() {
+    group.name = group_name.to_string();
+  }
+
+  Ok(group)
+}
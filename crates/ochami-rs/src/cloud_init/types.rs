@@ -0,0 +1,38 @@
+use manta_backend_dispatcher::types::cfs::cfs_configuration_response::CfsConfigurationResponse as FrontEndCfsConfigurationResponse;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct File {
+  #[serde(default)]
+  pub content: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub encoding: Option<String>,
+}
+
+/// cloud-init configuration applied to all nodes members of the SMD group with the same name
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct Group {
+  #[serde(default)]
+  pub name: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub description: Option<String>,
+  #[serde(rename = "meta-data")]
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub meta_data: Option<Value>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub file: Option<File>,
+}
+
+/// NOTE: cloud-init configurations are not git based nor versioned, therefore, the CFS
+/// configuration returned has no layers and no last updated date
+impl Into<FrontEndCfsConfigurationResponse> for Group {
+  fn into(self) -> FrontEndCfsConfigurationResponse {
+    FrontEndCfsConfigurationResponse {
+      name: self.name,
+      last_updated: String::new(),
+      layers: Vec::new(),
+      additional_inventory: None,
+    }
+  }
+}
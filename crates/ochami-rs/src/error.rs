@@ -1,5 +1,6 @@
 use std::io;
 
+use manta_backend_dispatcher::error::{Error as FrontEndError, Service};
 use serde_json::Value;
 
 #[derive(thiserror::Error, Debug)]
@@ -22,4 +23,37 @@ pub enum Error {
   },
   #[error("OCHAMI-RS > OCHAMI: {0}")]
   OchamiError(Value),
+  #[error("OCHAMI-RS > {service} (HTTP {status}): {payload}")]
+  HttpError {
+    status: u16,
+    service: Service,
+    resource_id: Option<String>,
+    payload: String,
+  },
+}
+
+impl From<Error> for FrontEndError {
+  fn from(error: Error) -> Self {
+    match error {
+      Error::HttpError {
+        status,
+        service,
+        resource_id,
+        payload,
+      } => FrontEndError::from_http_status(
+        status,
+        service,
+        resource_id.as_deref(),
+        payload,
+      ),
+      Error::Message(message) => FrontEndError::Message(message),
+      Error::IoError(e) => FrontEndError::IoError(e),
+      Error::SerdeError(e) => FrontEndError::SerdeError(e),
+      Error::NetError(e) => FrontEndError::NetError(e),
+      Error::RequestError { response, payload } => {
+        FrontEndError::RequestError { response, payload }
+      }
+      Error::OchamiError(payload) => FrontEndError::CsmError(payload),
+    }
+  }
 }
@@ -1,10 +1,11 @@
+use manta_backend_dispatcher::error::Service;
 use serde_json::Value;
 
 use crate::{error::Error, hsm::state::components::types::Component};
 
 use super::types::{
-  ComponentArray, ComponentArrayPostArray, ComponentArrayPostByNidQuery,
-  ComponentArrayPostQuery, ComponentPut,
+  ComponentArray, ComponentArrayPatch, ComponentArrayPostArray,
+  ComponentArrayPostByNidQuery, ComponentArrayPostQuery, ComponentPut,
 };
 
 pub async fn get_all(
@@ -144,21 +145,15 @@ pub async fn get(
     .await?;
 
   if !response.status().is_success() {
-    match response.status() {
-      reqwest::StatusCode::UNAUTHORIZED => {
-        let error_payload = response.text().await?;
-        let error = Error::Message(error_payload);
-        return Err(error);
-
-        // return Err(Error::Message(response.text().await?));
-      }
-      _ => {
-        let error_payload = response.text().await?;
-        let error = Error::Message(error_payload);
-        return Err(error);
-        // return Err(Error::CsmError(response.json::<Value>().await?));
-      }
-    }
+    let status = response.status().as_u16();
+    let payload = response.text().await?;
+
+    return Err(Error::HttpError {
+      status,
+      service: Service::Hsm,
+      resource_id: None,
+      payload,
+    });
   }
 
   response
@@ -194,22 +189,16 @@ pub async fn get_one(
 
   let response = client.get(api_url).bearer_auth(auth_token).send().await?;
 
-  if let Err(e) = response.error_for_status_ref() {
-    match response.status() {
-      reqwest::StatusCode::UNAUTHORIZED => {
-        let error_payload = response.text().await?;
-        let error = Error::RequestError {
-          response: e,
-          payload: error_payload,
-        };
-        return Err(error);
-      }
-      _ => {
-        let error_payload = response.json::<Value>().await?;
-        let error = Error::OchamiError(error_payload);
-        return Err(error);
-      }
-    }
+  if !response.status().is_success() {
+    let status = response.status().as_u16();
+    let payload = response.text().await?;
+
+    return Err(Error::HttpError {
+      status,
+      service: Service::Hsm,
+      resource_id: Some(xname.to_string()),
+      payload,
+    });
   }
 
   response
@@ -249,22 +238,16 @@ pub async fn post(
     .send()
     .await?;
 
-  if let Err(e) = response.error_for_status_ref() {
-    match response.status() {
-      reqwest::StatusCode::UNAUTHORIZED => {
-        let error_payload = response.text().await?;
-        let error = Error::RequestError {
-          response: e,
-          payload: error_payload,
-        };
-        return Err(error);
-      }
-      _ => {
-        let error_payload = response.json::<Value>().await?;
-        let error = Error::OchamiError(error_payload);
-        return Err(error);
-      }
-    }
+  if !response.status().is_success() {
+    let status = response.status().as_u16();
+    let payload = response.text().await?;
+
+    return Err(Error::HttpError {
+      status,
+      service: Service::Hsm,
+      resource_id: None,
+      payload,
+    });
   }
 
   Ok(())
@@ -301,22 +284,16 @@ pub async fn post_query(
     .send()
     .await?;
 
-  if let Err(e) = response.error_for_status_ref() {
-    match response.status() {
-      reqwest::StatusCode::UNAUTHORIZED => {
-        let error_payload = response.text().await?;
-        let error = Error::RequestError {
-          response: e,
-          payload: error_payload,
-        };
-        return Err(error);
-      }
-      _ => {
-        let error_payload = response.json::<Value>().await?;
-        let error = Error::OchamiError(error_payload);
-        return Err(error);
-      }
-    }
+  if !response.status().is_success() {
+    let status = response.status().as_u16();
+    let payload = response.text().await?;
+
+    return Err(Error::HttpError {
+      status,
+      service: Service::Hsm,
+      resource_id: None,
+      payload,
+    });
   }
 
   response
@@ -357,22 +334,16 @@ pub async fn post_bynid_query(
     .send()
     .await?;
 
-  if let Err(e) = response.error_for_status_ref() {
-    match response.status() {
-      reqwest::StatusCode::UNAUTHORIZED => {
-        let error_payload = response.text().await?;
-        let error = Error::RequestError {
-          response: e,
-          payload: error_payload,
-        };
-        return Err(error);
-      }
-      _ => {
-        let error_payload = response.json::<Value>().await?;
-        let error = Error::OchamiError(error_payload);
-        return Err(error);
-      }
-    }
+  if !response.status().is_success() {
+    let status = response.status().as_u16();
+    let payload = response.text().await?;
+
+    return Err(Error::HttpError {
+      status,
+      service: Service::Hsm,
+      resource_id: None,
+      payload,
+    });
   }
 
   response
@@ -414,22 +385,16 @@ pub async fn put(
     .send()
     .await?;
 
-  if let Err(e) = response.error_for_status_ref() {
-    match response.status() {
-      reqwest::StatusCode::UNAUTHORIZED => {
-        let error_payload = response.text().await?;
-        let error = Error::RequestError {
-          response: e,
-          payload: error_payload,
-        };
-        return Err(error);
-      }
-      _ => {
-        let error_payload = response.json::<Value>().await?;
-        let error = Error::OchamiError(error_payload);
-        return Err(error);
-      }
-    }
+  if !response.status().is_success() {
+    let status = response.status().as_u16();
+    let payload = response.text().await?;
+
+    return Err(Error::HttpError {
+      status,
+      service: Service::Hsm,
+      resource_id: Some(xname.to_string()),
+      payload,
+    });
   }
 
   response
@@ -438,6 +403,56 @@ pub async fn put(
     .map_err(|error| Error::NetError(error))
 }
 
+/// Updates a field of a list of components. `bulk_endpoint` is one of
+/// 'BulkEnabled', 'BulkFlagOnly', 'BulkSoftwareStatus' or 'BulkRole'
+pub async fn patch_bulk(
+  base_url: &str,
+  auth_token: &str,
+  root_cert: &[u8],
+  bulk_endpoint: &str,
+  component_patch: ComponentArrayPatch,
+) -> Result<(), Error> {
+  let client_builder = reqwest::Client::builder()
+    .add_root_certificate(reqwest::Certificate::from_pem(root_cert)?)
+    .use_rustls_tls();
+
+  // Build client
+  let client = if let Ok(socks5_env) = std::env::var("SOCKS5") {
+    // socks5 proxy
+    log::debug!("SOCKS5 enabled");
+    let socks5proxy = reqwest::Proxy::all(socks5_env)?;
+
+    // rest client to authenticate
+    client_builder.proxy(socks5proxy).build()?
+  } else {
+    client_builder.build()?
+  };
+
+  let api_url: String =
+    format!("{}/hsm/v2/State/Components/{}", base_url, bulk_endpoint);
+
+  let response = client
+    .patch(api_url)
+    .bearer_auth(auth_token)
+    .json(&component_patch)
+    .send()
+    .await?;
+
+  if !response.status().is_success() {
+    let status = response.status().as_u16();
+    let payload = response.text().await?;
+
+    return Err(Error::HttpError {
+      status,
+      service: Service::Hsm,
+      resource_id: None,
+      payload,
+    });
+  }
+
+  Ok(())
+}
+
 pub async fn delete_one(
   auth_token: &str,
   base_url: &str,
@@ -469,22 +484,16 @@ pub async fn delete_one(
     .send()
     .await?;
 
-  if let Err(e) = response.error_for_status_ref() {
-    match response.status() {
-      reqwest::StatusCode::UNAUTHORIZED => {
-        let error_payload = response.text().await?;
-        let error = Error::RequestError {
-          response: e,
-          payload: error_payload,
-        };
-        return Err(error);
-      }
-      _ => {
-        let error_payload = response.json::<Value>().await?;
-        let error = Error::OchamiError(error_payload);
-        return Err(error);
-      }
-    }
+  if !response.status().is_success() {
+    let status = response.status().as_u16();
+    let payload = response.text().await?;
+
+    return Err(Error::HttpError {
+      status,
+      service: Service::Hsm,
+      resource_id: Some(xname.to_string()),
+      payload,
+    });
   }
 
   response
@@ -522,22 +531,16 @@ pub async fn delete(
     .send()
     .await?;
 
-  if let Err(e) = response.error_for_status_ref() {
-    match response.status() {
-      reqwest::StatusCode::UNAUTHORIZED => {
-        let error_payload = response.text().await?;
-        let error = Error::RequestError {
-          response: e,
-          payload: error_payload,
-        };
-        return Err(error);
-      }
-      _ => {
-        let error_payload = response.json::<Value>().await?;
-        let error = Error::OchamiError(error_payload);
-        return Err(error);
-      }
-    }
+  if !response.status().is_success() {
+    let status = response.status().as_u16();
+    let payload = response.text().await?;
+
+    return Err(Error::HttpError {
+      status,
+      service: Service::Hsm,
+      resource_id: None,
+      payload,
+    });
   }
 
   response
@@ -348,3 +348,26 @@ pub struct ComponentPut {
   #[serde(rename(serialize = "Force"))]
   force: Option<bool>,
 }
+
+/// Body of the 'BulkEnabled', 'BulkFlagOnly', 'BulkSoftwareStatus' and
+/// 'BulkRole' endpoints. Each endpoint only reads its own fields
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct ComponentArrayPatch {
+  #[serde(rename = "ComponentIDs")]
+  pub component_ids: Vec<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  #[serde(rename = "Enabled")]
+  pub enabled: Option<bool>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  #[serde(rename = "Flag")]
+  pub flag: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  #[serde(rename = "SoftwareStatus")]
+  pub software_status: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  #[serde(rename = "Role")]
+  pub role: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  #[serde(rename = "SubRole")]
+  pub sub_role: Option<String>,
+}
@@ -1,14 +1,17 @@
+use manta_backend_dispatcher::error::Service;
 use serde_json::Value;
 
 use crate::error::Error;
 
-use super::types::{ComponentEthernetInterface, IpAddressMapping};
+use super::types::{
+  ComponentEthernetInterface, EthernetInterface, IpAddressMapping,
+};
 
 pub async fn post(
   auth_token: &str,
   base_url: &str,
   root_cert: &[u8],
-  eht_interface: ComponentEthernetInterface,
+  eht_interface: &EthernetInterface,
 ) -> Result<Value, Error> {
   let client_builder = reqwest::Client::builder()
     .add_root_certificate(reqwest::Certificate::from_pem(root_cert)?)
@@ -32,26 +35,20 @@ pub async fn post(
   let response = client
     .post(api_url)
     .bearer_auth(auth_token)
-    .json(&eht_interface)
+    .json(eht_interface)
     .send()
     .await?;
 
-  if let Err(e) = response.error_for_status_ref() {
-    match response.status() {
-      reqwest::StatusCode::UNAUTHORIZED => {
-        let error_payload = response.text().await?;
-        let error = Error::RequestError {
-          response: e,
-          payload: error_payload,
-        };
-        return Err(error);
-      }
-      _ => {
-        let error_payload = response.text().await?;
-        let error = Error::Message(error_payload);
-        return Err(error);
-      }
-    }
+  if !response.status().is_success() {
+    let status = response.status().as_u16();
+    let payload = response.text().await?;
+
+    return Err(Error::HttpError {
+      status,
+      service: Service::Hsm,
+      resource_id: None,
+      payload,
+    });
   }
 
   response
@@ -64,7 +61,8 @@ pub async fn post_ip_addresses(
   auth_token: &str,
   base_url: &str,
   root_cert: &[u8],
-  eht_interface: ComponentEthernetInterface,
+  eth_interface_id: &str,
+  ip_address_mapping: &IpAddressMapping,
 ) -> Result<Value, Error> {
   let client_builder = reqwest::Client::builder()
     .add_root_certificate(reqwest::Certificate::from_pem(root_cert)?)
@@ -84,34 +82,26 @@ pub async fn post_ip_addresses(
 
   let api_url: String = format!(
     "{}/{}/{}/IPAddresses",
-    base_url,
-    "hsm/v2/Inventory/EthernetInterfaces",
-    eht_interface.component_id.as_ref().unwrap()
+    base_url, "hsm/v2/Inventory/EthernetInterfaces", eth_interface_id
   );
 
   let response = client
     .post(api_url)
     .bearer_auth(auth_token)
-    .json(&eht_interface)
+    .json(ip_address_mapping)
     .send()
     .await?;
 
-  if let Err(e) = response.error_for_status_ref() {
-    match response.status() {
-      reqwest::StatusCode::UNAUTHORIZED => {
-        let error_payload = response.text().await?;
-        let error = Error::RequestError {
-          response: e,
-          payload: error_payload,
-        };
-        return Err(error);
-      }
-      _ => {
-        let error_payload = response.text().await?;
-        let error = Error::Message(error_payload);
-        return Err(error);
-      }
-    }
+  if !response.status().is_success() {
+    let status = response.status().as_u16();
+    let payload = response.text().await?;
+
+    return Err(Error::HttpError {
+      status,
+      service: Service::Hsm,
+      resource_id: Some(eth_interface_id.to_string()),
+      payload,
+    });
   }
 
   response
@@ -126,14 +116,14 @@ pub async fn get(
   auth_token: &str,
   base_url: &str,
   root_cert: &[u8],
-  mac_address: &str,
-  ip_address: &str,
-  network: &str,
-  component_id: &str, // Node's xname
-  r#type: &str,
-  olther_than: &str,
-  newer_than: &str,
-) -> Result<Vec<ComponentEthernetInterface>, Error> {
+  mac_address: Option<&str>,
+  ip_address: Option<&str>,
+  network: Option<&str>,
+  component_id: Option<&str>, // Node's xname
+  r#type: Option<&str>,
+  olther_than: Option<&str>,
+  newer_than: Option<&str>,
+) -> Result<Vec<EthernetInterface>, Error> {
   let client_builder = reqwest::Client::builder()
     .add_root_certificate(reqwest::Certificate::from_pem(root_cert)?)
     .use_rustls_tls();
@@ -151,39 +141,38 @@ pub async fn get(
   };
 
   let api_url: String =
-    base_url.to_owned() + "/smd/hsm/v2/Inventory/EthernetInterfaces";
+    format!("{}/{}", base_url, "hsm/v2/Inventory/EthernetInterfaces");
+
+  let query: Vec<(&str, &str)> = [
+    ("MACAddress", mac_address),
+    ("IPAddress", ip_address),
+    ("Network", network),
+    ("ComponentID", component_id),
+    ("Type", r#type),
+    ("OlderThan", olther_than),
+    ("NewerThan", newer_than),
+  ]
+  .into_iter()
+  .filter_map(|(key, value_opt)| value_opt.map(|value| (key, value)))
+  .collect();
 
   let response = client
     .get(api_url)
-    .query(&[
-      ("MACAddress", mac_address),
-      ("IPAddress", ip_address),
-      ("Network", network),
-      ("ComponentID", component_id),
-      ("Type", r#type),
-      ("OlderThan", olther_than),
-      ("NewerThan", newer_than),
-    ])
+    .query(&query)
     .bearer_auth(auth_token)
     .send()
     .await?;
 
-  if let Err(e) = response.error_for_status_ref() {
-    match response.status() {
-      reqwest::StatusCode::UNAUTHORIZED => {
-        let error_payload = response.text().await?;
-        let error = Error::RequestError {
-          response: e,
-          payload: error_payload,
-        };
-        return Err(error);
-      }
-      _ => {
-        let error_payload = response.json::<Value>().await?;
-        let error = Error::OchamiError(error_payload);
-        return Err(error);
-      }
-    }
+  if !response.status().is_success() {
+    let status = response.status().as_u16();
+    let payload = response.text().await?;
+
+    return Err(Error::HttpError {
+      status,
+      service: Service::Hsm,
+      resource_id: None,
+      payload,
+    });
   }
 
   response
@@ -197,7 +186,7 @@ pub async fn get_one(
   base_url: &str,
   root_cert: &[u8],
   eth_interface_id: &str,
-) -> Result<ComponentEthernetInterface, Error> {
+) -> Result<EthernetInterface, Error> {
   let client_builder = reqwest::Client::builder()
     .add_root_certificate(reqwest::Certificate::from_pem(root_cert)?)
     .use_rustls_tls();
@@ -215,28 +204,22 @@ pub async fn get_one(
   };
 
   let api_url: String = format!(
-    "{}/smd/hsm/v2/Inventory/EthernetInterfaces/{}",
+    "{}/hsm/v2/Inventory/EthernetInterfaces/{}",
     base_url, eth_interface_id
   );
 
   let response = client.get(api_url).bearer_auth(auth_token).send().await?;
 
-  if let Err(e) = response.error_for_status_ref() {
-    match response.status() {
-      reqwest::StatusCode::UNAUTHORIZED => {
-        let error_payload = response.text().await?;
-        let error = Error::RequestError {
-          response: e,
-          payload: error_payload,
-        };
-        return Err(error);
-      }
-      _ => {
-        let error_payload = response.json::<Value>().await?;
-        let error = Error::OchamiError(error_payload);
-        return Err(error);
-      }
-    }
+  if !response.status().is_success() {
+    let status = response.status().as_u16();
+    let payload = response.text().await?;
+
+    return Err(Error::HttpError {
+      status,
+      service: Service::Hsm,
+      resource_id: Some(eth_interface_id.to_string()),
+      payload,
+    });
   }
 
   response
@@ -268,28 +251,22 @@ pub async fn get_ip_addresses(
   };
 
   let api_url: String = format!(
-    "{}/smd/hsm/v2/Inventory/EthernetInterfaces/{}/IPAddresses",
+    "{}/hsm/v2/Inventory/EthernetInterfaces/{}/IPAddresses",
     base_url, eth_interface_id
   );
 
   let response = client.get(api_url).bearer_auth(auth_token).send().await?;
 
-  if let Err(e) = response.error_for_status_ref() {
-    match response.status() {
-      reqwest::StatusCode::UNAUTHORIZED => {
-        let error_payload = response.text().await?;
-        let error = Error::RequestError {
-          response: e,
-          payload: error_payload,
-        };
-        return Err(error);
-      }
-      _ => {
-        let error_payload = response.json::<Value>().await?;
-        let error = Error::OchamiError(error_payload);
-        return Err(error);
-      }
-    }
+  if !response.status().is_success() {
+    let status = response.status().as_u16();
+    let payload = response.text().await?;
+
+    return Err(Error::HttpError {
+      status,
+      service: Service::Hsm,
+      resource_id: Some(eth_interface_id.to_string()),
+      payload,
+    });
   }
 
   response
@@ -304,18 +281,13 @@ pub async fn patch(
   shasta_root_cert: &[u8],
   eth_interface_id: &str,
   description: Option<&str>,
-  ip_address_mapping: (&str, &str), // [(<ip address>, <network>), ...], examle
-                                    // [("192.168.1.10", "HMN"), ...]
+  component_id: Option<&str>,
+  ip_address_vec_opt: Option<Vec<IpAddressMapping>>,
 ) -> Result<(), Error> {
-  let ip_address = ip_address_mapping.0;
-  let network = ip_address_mapping.1;
   let cei = ComponentEthernetInterface {
     description: description.map(|value| value.to_string()),
-    ip_addresses: vec![IpAddressMapping {
-      ip_address: ip_address.to_string(),
-      network: Some(network.to_string()),
-    }],
-    component_id: Some(eth_interface_id.to_string()),
+    ip_addresses: ip_address_vec_opt,
+    component_id: component_id.map(|value| value.to_string()),
   };
 
   let client_builder = reqwest::Client::builder()
@@ -335,34 +307,27 @@ pub async fn patch(
   };
 
   let api_url: String = format!(
-    "{}/smd/hsm/v2/Inventory/EthernetInterfaces/{}",
+    "{}/hsm/v2/Inventory/EthernetInterfaces/{}",
     shasta_base_url, eth_interface_id
   );
 
   let response = client
     .patch(api_url)
-    .query(&[("ethInterfaceID", ip_address), ("ipAddress", ip_address)])
     .bearer_auth(shasta_token)
     .json(&cei)
     .send()
     .await?;
 
-  if let Err(e) = response.error_for_status_ref() {
-    match response.status() {
-      reqwest::StatusCode::UNAUTHORIZED => {
-        let error_payload = response.text().await?;
-        let error = Error::RequestError {
-          response: e,
-          payload: error_payload,
-        };
-        return Err(error);
-      }
-      _ => {
-        let error_payload = response.json::<Value>().await?;
-        let error = Error::OchamiError(error_payload);
-        return Err(error);
-      }
-    }
+  if !response.status().is_success() {
+    let status = response.status().as_u16();
+    let payload = response.text().await?;
+
+    return Err(Error::HttpError {
+      status,
+      service: Service::Hsm,
+      resource_id: Some(eth_interface_id.to_string()),
+      payload,
+    });
   }
 
   Ok(())
@@ -390,7 +355,7 @@ pub async fn delete_all(
   };
 
   let api_url: String =
-    format!("{}/smd/hsm/v2/Inventory/EthernetInterfaces", base_url);
+    format!("{}/hsm/v2/Inventory/EthernetInterfaces", base_url);
 
   let response = client
     .delete(api_url)
@@ -398,22 +363,16 @@ pub async fn delete_all(
     .send()
     .await?;
 
-  if let Err(e) = response.error_for_status_ref() {
-    match response.status() {
-      reqwest::StatusCode::UNAUTHORIZED => {
-        let error_payload = response.text().await?;
-        let error = Error::RequestError {
-          response: e,
-          payload: error_payload,
-        };
-        return Err(error);
-      }
-      _ => {
-        let error_payload = response.json::<Value>().await?;
-        let error = Error::OchamiError(error_payload);
-        return Err(error);
-      }
-    }
+  if !response.status().is_success() {
+    let status = response.status().as_u16();
+    let payload = response.text().await?;
+
+    return Err(Error::HttpError {
+      status,
+      service: Service::Hsm,
+      resource_id: None,
+      payload,
+    });
   }
 
   response
@@ -445,7 +404,7 @@ pub async fn delete_one(
   };
 
   let api_url: String = format!(
-    "{}/smd/hsm/v2/Inventory/EthernetInterfaces/{}",
+    "{}/hsm/v2/Inventory/EthernetInterfaces/{}",
     base_url, eth_interface_id
   );
 
@@ -455,22 +414,16 @@ pub async fn delete_one(
     .send()
     .await?;
 
-  if let Err(e) = response.error_for_status_ref() {
-    match response.status() {
-      reqwest::StatusCode::UNAUTHORIZED => {
-        let error_payload = response.text().await?;
-        let error = Error::RequestError {
-          response: e,
-          payload: error_payload,
-        };
-        return Err(error);
-      }
-      _ => {
-        let error_payload = response.json::<Value>().await?;
-        let error = Error::OchamiError(error_payload);
-        return Err(error);
-      }
-    }
+  if !response.status().is_success() {
+    let status = response.status().as_u16();
+    let payload = response.text().await?;
+
+    return Err(Error::HttpError {
+      status,
+      service: Service::Hsm,
+      resource_id: Some(eth_interface_id.to_string()),
+      payload,
+    });
   }
 
   response
@@ -504,7 +457,7 @@ pub async fn delete_ip_address(
   };
 
   let api_url: String = format!(
-    "{}/smd/hsm/v2/Inventory/EthernetInterfaces/{}/IpAddress/{}",
+    "{}/hsm/v2/Inventory/EthernetInterfaces/{}/IpAddress/{}",
     base_url, eth_interface_id, ip_address
   );
 
@@ -514,22 +467,16 @@ pub async fn delete_ip_address(
     .send()
     .await?;
 
-  if let Err(e) = response.error_for_status_ref() {
-    match response.status() {
-      reqwest::StatusCode::UNAUTHORIZED => {
-        let error_payload = response.text().await?;
-        let error = Error::RequestError {
-          response: e,
-          payload: error_payload,
-        };
-        return Err(error);
-      }
-      _ => {
-        let error_payload = response.json::<Value>().await?;
-        let error = Error::OchamiError(error_payload);
-        return Err(error);
-      }
-    }
+  if !response.status().is_success() {
+    let status = response.status().as_u16();
+    let payload = response.text().await?;
+
+    return Err(Error::HttpError {
+      status,
+      service: Service::Hsm,
+      resource_id: Some(eth_interface_id.to_string()),
+      payload,
+    });
   }
 
   response
@@ -1,6 +1,10 @@
+use manta_backend_dispatcher::error::Service;
 use serde_json::Value;
 
-use crate::{error::Error, hsm::inventory::types::HWInventoryByFRU};
+use crate::{
+  error::Error,
+  hsm::inventory::types::{HWInvHistArray, HWInventoryByFRU},
+};
 
 pub async fn get(
   auth_token: &str,
@@ -29,31 +33,36 @@ pub async fn get(
   };
 
   let api_url: String =
-    format!("{}/{}", base_url, "/smd/hsm/v2/Inventory/HardwareByFRU");
+    format!("{}/{}", base_url, "hsm/v2/Inventory/HardwareByFRU");
+
+  let query_vec: Vec<(&str, &str)> = [
+    ("fruid", fruid),
+    ("type", r#type),
+    ("manufacturer", manufacturer),
+    ("partnumber", partnumber),
+    ("serialnumber", serialnumber),
+  ]
+  .into_iter()
+  .filter_map(|(key, value_opt)| value_opt.map(|value| (key, value)))
+  .collect();
 
   let response = client
     .get(api_url)
-    .query(&[fruid, r#type, manufacturer, partnumber, serialnumber, fruid])
+    .query(&query_vec)
     .bearer_auth(auth_token)
     .send()
     .await?;
 
-  if let Err(e) = response.error_for_status_ref() {
-    match response.status() {
-      reqwest::StatusCode::UNAUTHORIZED => {
-        let error_payload = response.text().await?;
-        let error = Error::RequestError {
-          response: e,
-          payload: error_payload,
-        };
-        return Err(error);
-      }
-      _ => {
-        let error_payload = response.json::<Value>().await?;
-        let error = Error::OchamiError(error_payload);
-        return Err(error);
-      }
-    }
+  if !response.status().is_success() {
+    let status = response.status().as_u16();
+    let payload = response.text().await?;
+
+    return Err(Error::HttpError {
+      status,
+      service: Service::Hsm,
+      resource_id: None,
+      payload,
+    });
   }
 
   response
@@ -86,27 +95,82 @@ pub async fn get_one(
 
   let api_url: String = format!(
     "{}/{}/{}",
-    base_url, "/smd/hsm/v2/Inventory/Hardware", fruid
+    base_url, "hsm/v2/Inventory/HardwareByFRU", fruid
   );
 
   let response = client.get(api_url).bearer_auth(auth_token).send().await?;
 
-  if let Err(e) = response.error_for_status_ref() {
-    match response.status() {
-      reqwest::StatusCode::UNAUTHORIZED => {
-        let error_payload = response.text().await?;
-        let error = Error::RequestError {
-          response: e,
-          payload: error_payload,
-        };
-        return Err(error);
-      }
-      _ => {
-        let error_payload = response.json::<Value>().await?;
-        let error = Error::OchamiError(error_payload);
-        return Err(error);
-      }
-    }
+  if !response.status().is_success() {
+    let status = response.status().as_u16();
+    let payload = response.text().await?;
+
+    return Err(Error::HttpError {
+      status,
+      service: Service::Hsm,
+      resource_id: Some(fruid.to_string()),
+      payload,
+    });
+  }
+
+  response
+    .json()
+    .await
+    .map_err(|error| Error::NetError(error))
+}
+
+/// Events of a FRU, eg the xnames it was added to or removed from
+pub async fn get_history(
+  auth_token: &str,
+  base_url: &str,
+  root_cert: &[u8],
+  fruid: &str,
+  start_time: Option<&str>,
+  end_time: Option<&str>,
+) -> Result<HWInvHistArray, Error> {
+  let client_builder = reqwest::Client::builder()
+    .add_root_certificate(reqwest::Certificate::from_pem(root_cert)?)
+    .use_rustls_tls();
+
+  // Build client
+  let client = if let Ok(socks5_env) = std::env::var("SOCKS5") {
+    // socks5 proxy
+    log::debug!("SOCKS5 enabled");
+    let socks5proxy = reqwest::Proxy::all(socks5_env)?;
+
+    // rest client to authenticate
+    client_builder.proxy(socks5proxy).build()?
+  } else {
+    client_builder.build()?
+  };
+
+  let api_url: String = format!(
+    "{}/{}/{}",
+    base_url, "hsm/v2/Inventory/HardwareByFRU/History", fruid
+  );
+
+  let query_vec: Vec<(&str, &str)> =
+    [("starttime", start_time), ("endtime", end_time)]
+      .into_iter()
+      .filter_map(|(key, value_opt)| value_opt.map(|value| (key, value)))
+      .collect();
+
+  let response = client
+    .get(api_url)
+    .query(&query_vec)
+    .bearer_auth(auth_token)
+    .send()
+    .await?;
+
+  if !response.status().is_success() {
+    let status = response.status().as_u16();
+    let payload = response.text().await?;
+
+    return Err(Error::HttpError {
+      status,
+      service: Service::Hsm,
+      resource_id: Some(fruid.to_string()),
+      payload,
+    });
   }
 
   response
@@ -136,8 +200,7 @@ pub async fn delete_all(
     client_builder.build()?
   };
 
-  let api_url: String =
-    base_url.to_owned() + "/smd/hsm/v2/Inventory/HardwareByFRU";
+  let api_url: String = base_url.to_owned() + "/hsm/v2/Inventory/HardwareByFRU";
 
   let response = client
     .delete(api_url)
@@ -145,22 +208,16 @@ pub async fn delete_all(
     .send()
     .await?;
 
-  if let Err(e) = response.error_for_status_ref() {
-    match response.status() {
-      reqwest::StatusCode::UNAUTHORIZED => {
-        let error_payload = response.text().await?;
-        let error = Error::RequestError {
-          response: e,
-          payload: error_payload,
-        };
-        return Err(error);
-      }
-      _ => {
-        let error_payload = response.json::<Value>().await?;
-        let error = Error::OchamiError(error_payload);
-        return Err(error);
-      }
-    }
+  if !response.status().is_success() {
+    let status = response.status().as_u16();
+    let payload = response.text().await?;
+
+    return Err(Error::HttpError {
+      status,
+      service: Service::Hsm,
+      resource_id: None,
+      payload,
+    });
   }
 
   response
@@ -193,7 +250,7 @@ pub async fn delete_one(
 
   let api_url: String = format!(
     "{}/{}/{}",
-    base_url, "smd/hsm/v2/Inventory/HardwareByFRU", fruid
+    base_url, "hsm/v2/Inventory/HardwareByFRU", fruid
   );
 
   let response = client
@@ -202,22 +259,16 @@ pub async fn delete_one(
     .send()
     .await?;
 
-  if let Err(e) = response.error_for_status_ref() {
-    match response.status() {
-      reqwest::StatusCode::UNAUTHORIZED => {
-        let error_payload = response.text().await?;
-        let error = Error::RequestError {
-          response: e,
-          payload: error_payload,
-        };
-        return Err(error);
-      }
-      _ => {
-        let error_payload = response.json::<Value>().await?;
-        let error = Error::OchamiError(error_payload);
-        return Err(error);
-      }
-    }
+  if !response.status().is_success() {
+    let status = response.status().as_u16();
+    let payload = response.text().await?;
+
+    return Err(Error::HttpError {
+      status,
+      service: Service::Hsm,
+      resource_id: Some(fruid.to_string()),
+      payload,
+    });
   }
 
   response
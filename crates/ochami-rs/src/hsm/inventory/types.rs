@@ -1,4 +1,7 @@
 use manta_backend_dispatcher::types::{
+  hsm::inventory::{
+    HWInvHist as FrontEndHWInvHist, HWInvHistArray as FrontEndHWInvHistArray,
+  },
   ArtifactSummary as FrontEndArtifactSummary,
   ArtifactType as FrontEndArtifactType, HSNNICFRUInfo as FrontEndHSNNICFRUInfo,
   HSNNICLocationInfo as FrontEndHSNNICLocationInfo,
@@ -13,6 +16,7 @@ use manta_backend_dispatcher::types::{
   HWInvByLocNodeAccel as FrontEndHWInvByLocNodeAccel,
   HWInvByLocProcessor as FrontEndHWInvByLocProcessor,
   HWInventory as FrontEndHWInventory,
+  HWInventoryByFRU as FrontEndHWInventoryByFRU,
   HWInventoryByLocation as FrontEndHWInventoryByLocation,
   HWInventoryByLocationList as FrontEndHWInventoryByLocationList,
   MemoryLocation as FrontEndMemoryLocation,
@@ -31,6 +35,7 @@ use manta_backend_dispatcher::types::{
 use serde::{Deserialize, Serialize};
 
 use serde_json::Value;
+use std::collections::HashMap;
 use std::str::FromStr;
 use std::string::ToString;
 use strum_macros::{AsRefStr, Display, EnumIter, EnumString, IntoStaticStr};
@@ -1191,6 +1196,21 @@ pub struct HWInventoryByFRU {
   pub fru_sub_type: Option<String>,
   #[serde(rename = "HWInventoryByFRUType")]
   pub hw_inventory_by_fru_type: String,
+  /// FRU details, eg 'MemoryFRUInfo' or 'ProcessorFRUInfo'
+  #[serde(flatten)]
+  pub fru_info: HashMap<String, Value>,
+}
+
+impl Into<FrontEndHWInventoryByFRU> for HWInventoryByFRU {
+  fn into(self) -> FrontEndHWInventoryByFRU {
+    FrontEndHWInventoryByFRU {
+      fru_id: self.fru_id,
+      r#type: self.r#type,
+      fru_sub_type: self.fru_sub_type,
+      hw_inventory_by_fru_type: self.hw_inventory_by_fru_type,
+      fru_info: self.fru_info,
+    }
+  }
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -2759,3 +2779,48 @@ impl Into<FrontEndHWInventoryByLocationList> for HWInventoryByLocationList {
     }
   }
 }
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct HWInvHist {
+  #[serde(rename = "ID")]
+  pub id: String,
+  #[serde(rename = "FRUID")]
+  pub fru_id: String,
+  #[serde(rename = "Timestamp")]
+  pub timestamp: String,
+  #[serde(rename = "EventType")]
+  pub event_type: String,
+}
+
+impl Into<FrontEndHWInvHist> for HWInvHist {
+  fn into(self) -> FrontEndHWInvHist {
+    FrontEndHWInvHist {
+      id: self.id,
+      fru_id: self.fru_id,
+      timestamp: self.timestamp,
+      event_type: self.event_type,
+    }
+  }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct HWInvHistArray {
+  #[serde(rename = "ID")]
+  pub id: String,
+  #[serde(rename = "History")]
+  #[serde(default)]
+  pub history: Vec<HWInvHist>,
+}
+
+impl Into<FrontEndHWInvHistArray> for HWInvHistArray {
+  fn into(self) -> FrontEndHWInvHistArray {
+    FrontEndHWInvHistArray {
+      id: self.id,
+      history: self
+        .history
+        .into_iter()
+        .map(|hw_inv_hist| hw_inv_hist.into())
+        .collect(),
+    }
+  }
+}
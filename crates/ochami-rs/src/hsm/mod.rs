@@ -2,6 +2,7 @@ pub mod component;
 pub mod defaults;
 pub mod group;
 pub mod inventory;
+pub mod lock;
 pub mod memberships;
 pub mod node_map;
 pub mod partition;
@@ -1,25 +1,48 @@
+use manta_backend_dispatcher::types::hsm::node_map::NodeMap as FrontEndNodeMap;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct NodeMapArray {
   #[serde(skip_serializing_if = "Option::is_none")]
-  #[serde(rename(serialize = "NodeMaps"))]
-  pub node_maps: Option<NodeMap>,
+  #[serde(rename = "NodeMaps")]
+  pub node_maps: Option<Vec<NodeMap>>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct NodeMap {
   #[serde(skip_serializing_if = "Option::is_none")]
-  #[serde(rename(serialize = "ID"))]
+  #[serde(rename = "ID")]
   pub id: Option<String>,
   /// put http request payload does not use "ID" field since it is
   /// part of the URL
-  #[serde(rename(serialize = "NID"))]
-  pub nid: usize,
+  #[serde(rename = "NID")]
+  pub nid: u32,
   #[serde(skip_serializing_if = "Option::is_none")]
-  #[serde(rename(serialize = "Role"))]
+  #[serde(rename = "Role")]
   pub role: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
-  #[serde(rename(serialize = "SubRole"))]
+  #[serde(rename = "SubRole")]
   pub sub_role: Option<String>,
 }
+
+impl From<FrontEndNodeMap> for NodeMap {
+  fn from(value: FrontEndNodeMap) -> Self {
+    NodeMap {
+      id: Some(value.id),
+      nid: value.nid,
+      role: value.role,
+      sub_role: value.sub_role,
+    }
+  }
+}
+
+impl Into<FrontEndNodeMap> for NodeMap {
+  fn into(self) -> FrontEndNodeMap {
+    FrontEndNodeMap {
+      id: self.id.unwrap_or_default(),
+      nid: self.nid,
+      role: self.role,
+      sub_role: self.sub_role,
+    }
+  }
+}
@@ -1,3 +1,6 @@
+use manta_backend_dispatcher::types::{
+  hsm::partition::Partition as FrontEndPartition, Member as FrontEndMember,
+};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -20,3 +23,31 @@ pub struct Partition {
   #[serde(skip_serializing_if = "Option::is_none")]
   pub members: Option<Members>,
 }
+
+impl From<FrontEndPartition> for Partition {
+  fn from(value: FrontEndPartition) -> Self {
+    let members = value.members.map(|members| Members { ids: members.ids });
+
+    Partition {
+      name: value.name,
+      description: value.description,
+      tags: value.tags,
+      members,
+    }
+  }
+}
+
+impl Into<FrontEndPartition> for Partition {
+  fn into(self) -> FrontEndPartition {
+    let members = self
+      .members
+      .map(|members| FrontEndMember { ids: members.ids });
+
+    FrontEndPartition {
+      name: self.name,
+      description: self.description,
+      tags: self.tags,
+      members,
+    }
+  }
+}
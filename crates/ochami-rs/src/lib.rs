@@ -1,6 +1,7 @@
 pub mod authentication;
 pub mod backend_connector;
 pub mod bss;
+pub mod cloud_init;
 pub mod error;
 pub mod hsm;
 pub mod node;
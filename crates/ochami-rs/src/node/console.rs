@@ -0,0 +1,140 @@
+use std::{
+  pin::Pin,
+  process::Stdio,
+  task::{Context, Poll},
+};
+
+use tokio::{
+  io::{AsyncBufReadExt, AsyncRead, BufReader, ReadBuf},
+  process::{Child, ChildStdin, ChildStdout, Command},
+};
+
+use crate::{
+  error::Error, hsm::inventory::redfish_endpoint::types::RedfishEndpoint,
+};
+
+/// Escape character of the serial over LAN session, same key combination
+/// ('&.') as conman to close the console
+const SOL_ESCAPE_CHAR: &str = "&";
+
+/// Output of a serial over LAN session. Keeps 'ipmitool' running while the
+/// console is read, the process is killed once this is dropped
+pub struct SolOutput {
+  stdout: ChildStdout,
+  _child: Child,
+}
+
+impl AsyncRead for SolOutput {
+  fn poll_read(
+    mut self: Pin<&mut Self>,
+    cx: &mut Context<'_>,
+    buf: &mut ReadBuf<'_>,
+  ) -> Poll<std::io::Result<()>> {
+    Pin::new(&mut self.stdout).poll_read(cx, buf)
+  }
+}
+
+/// BMC of a node eg 'x1000c1s7b0n0' -> 'x1000c1s7b0'
+pub fn get_bmc_xname(xname: &str) -> Option<&str> {
+  let (bmc_xname, node_number) = xname.rsplit_once('n')?;
+
+  if bmc_xname.is_empty()
+    || node_number.is_empty()
+    || !node_number.chars().all(|c| c.is_ascii_digit())
+  {
+    return None;
+  }
+
+  Some(bmc_xname)
+}
+
+/// Opens an IPMI serial over LAN session to a node's console with the
+/// credentials of its BMC Redfish endpoint. Needs 'ipmitool' in the PATH.
+/// Terminal size can't be changed through serial over LAN
+pub async fn attach_to_sol(
+  redfish_endpoint: &RedfishEndpoint,
+) -> Result<(ChildStdin, SolOutput), Error> {
+  let bmc_host = [
+    &redfish_endpoint.fqdn,
+    &redfish_endpoint.hostname,
+    &redfish_endpoint.ip_address,
+  ]
+  .into_iter()
+  .flatten()
+  .find(|bmc_host| !bmc_host.is_empty())
+  .ok_or_else(|| {
+    Error::Message(format!(
+      "Redfish endpoint '{}' has no FQDN, hostname or IP address",
+      redfish_endpoint.id
+    ))
+  })?;
+
+  // HSM only returns the password to clients allowed to read it
+  let (Some(user), Some(password)) = (
+    redfish_endpoint.user.as_ref(),
+    redfish_endpoint
+      .password
+      .as_ref()
+      .filter(|password| !password.is_empty()),
+  ) else {
+    return Err(Error::Message(format!(
+      "Redfish endpoint '{}' has no credentials",
+      redfish_endpoint.id
+    )));
+  };
+
+  log::info!("Opening serial over LAN session to BMC '{}'", bmc_host);
+
+  // Password is passed through the environment so it does not show up in the
+  // process list
+  let mut child = Command::new("ipmitool")
+    .args([
+      "-I",
+      "lanplus",
+      "-H",
+      bmc_host.as_str(),
+      "-U",
+      user.as_str(),
+    ])
+    .arg("-E")
+    .args(["-e", SOL_ESCAPE_CHAR, "sol", "activate"])
+    .env("IPMI_PASSWORD", password)
+    .stdin(Stdio::piped())
+    .stdout(Stdio::piped())
+    .stderr(Stdio::piped())
+    .kill_on_drop(true)
+    .spawn()
+    .map_err(|e| {
+      Error::Message(format!(
+        "Could not run 'ipmitool' to open the console of '{}'. Reason:\n{}",
+        redfish_endpoint.id, e
+      ))
+    })?;
+
+  let (Some(stdin), Some(stdout), Some(stderr)) =
+    (child.stdin.take(), child.stdout.take(), child.stderr.take())
+  else {
+    return Err(Error::Message(
+      "Could not attach to 'ipmitool' stdin and stdout".to_string(),
+    ));
+  };
+
+  // ipmitool reports session errors in stderr, keep them in the logs
+  let bmc_id = redfish_endpoint.id.clone();
+  tokio::spawn(async move {
+    let mut line_stream = BufReader::new(stderr).lines();
+    while let Ok(Some(line)) = line_stream.next_line().await {
+      log::warn!("ipmitool '{}': {}", bmc_id, line);
+    }
+
+    log::info!("Serial over LAN session to '{}' closed", bmc_id);
+  });
+
+  Ok((
+    stdin,
+    SolOutput {
+      stdout,
+      _child: child,
+    },
+  ))
+}
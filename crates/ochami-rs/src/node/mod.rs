@@ -1 +1,4 @@
+pub mod console;
+#[cfg(test)]
+pub mod tests;
 pub mod utils;
@@ -0,0 +1,13 @@
+use crate::node::console::get_bmc_xname;
+
+#[test]
+fn test_get_bmc_xname() {
+  assert_eq!(get_bmc_xname("x1000c1s7b0n0"), Some("x1000c1s7b0"));
+  assert_eq!(get_bmc_xname("x3000c0s17b1n12"), Some("x3000c0s17b1"));
+
+  // Not a node
+  assert_eq!(get_bmc_xname("x1000c1s7b0"), None);
+  assert_eq!(get_bmc_xname("x1000c1s7b0n"), None);
+  assert_eq!(get_bmc_xname("n0"), None);
+  assert_eq!(get_bmc_xname("nid001313"), None);
+}
@@ -1,3 +1,13 @@
+use manta_backend_dispatcher::types::pcs::power_cap::types::{
+  Limit as FrontEndLimit, PowerCapComponent as FrontEndPowerCapComponent,
+  PowerCapControl as FrontEndPowerCapControl,
+  PowerCapLimit as FrontEndPowerCapLimit,
+  PowerCapPatch as FrontEndPowerCapPatch,
+  PowerCapPatchComponent as FrontEndPowerCapPatchComponent,
+  PowerCapTaskInfo as FrontEndPowerCapTaskInfo,
+  PowerCapTaskList as FrontEndPowerCapTaskList,
+  TaskCounts as FrontEndTaskCounts,
+};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -5,29 +15,62 @@ pub struct PowerCapTaskList {
   pub tasks: Vec<PowerCapTaskInfo>,
 }
 
+impl Into<FrontEndPowerCapTaskList> for PowerCapTaskList {
+  fn into(self) -> FrontEndPowerCapTaskList {
+    FrontEndPowerCapTaskList {
+      tasks: self.tasks.into_iter().map(|task| task.into()).collect(),
+    }
+  }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct TaskCounts {
   pub total: usize,
   pub new: usize,
+  #[serde(rename = "in-progress")]
   pub in_progress: usize,
   pub failed: usize,
   pub succeeded: usize,
+  #[serde(rename = "un-supported")]
   pub un_supported: usize,
 }
 
+impl Into<FrontEndTaskCounts> for TaskCounts {
+  fn into(self) -> FrontEndTaskCounts {
+    FrontEndTaskCounts {
+      total: self.total,
+      new: self.new,
+      in_progress: self.in_progress,
+      failed: self.failed,
+      succeeded: self.succeeded,
+      un_supported: self.un_supported,
+    }
+  }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Limit {
   #[serde(skip_serializing_if = "Option::is_none")]
-  #[serde(rename = "hostsLimitMax")]
-  pub hosts_limit_max: Option<usize>,
+  #[serde(rename = "hostLimitMax")]
+  pub host_limit_max: Option<usize>,
   #[serde(skip_serializing_if = "Option::is_none")]
-  #[serde(rename = "hostsLimitMin")]
-  pub hosts_limit_min: Option<usize>,
+  #[serde(rename = "hostLimitMin")]
+  pub host_limit_min: Option<usize>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[serde(rename = "powerupPower")]
   pub powerup_power: Option<usize>,
 }
 
+impl Into<FrontEndLimit> for Limit {
+  fn into(self) -> FrontEndLimit {
+    FrontEndLimit {
+      host_limit_max: self.host_limit_max,
+      host_limit_min: self.host_limit_min,
+      powerup_power: self.powerup_power,
+    }
+  }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct PowerCapLimit {
   #[serde(skip_serializing_if = "Option::is_none")]
@@ -36,11 +79,22 @@ pub struct PowerCapLimit {
   #[serde(rename = "currentValue")]
   pub current_value: Option<usize>,
   #[serde(skip_serializing_if = "Option::is_none")]
-  #[serde(rename = "mamximumValue")]
+  #[serde(rename = "maximumValue")]
   pub maximum_value: Option<usize>,
   #[serde(skip_serializing_if = "Option::is_none")]
   #[serde(rename = "minimumValue")]
-  pub mnimum_value: Option<usize>,
+  pub minimum_value: Option<usize>,
+}
+
+impl Into<FrontEndPowerCapLimit> for PowerCapLimit {
+  fn into(self) -> FrontEndPowerCapLimit {
+    FrontEndPowerCapLimit {
+      name: self.name,
+      current_value: self.current_value,
+      maximum_value: self.maximum_value,
+      minimum_value: self.minimum_value,
+    }
+  }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -52,14 +106,30 @@ pub struct PowerCapComponent {
   #[serde(skip_serializing_if = "Option::is_none")]
   pub limits: Option<Limit>,
   #[serde(skip_serializing_if = "Option::is_none")]
-  #[serde(rename = "power_cap_limits")]
-  pub power_cap_limits: Option<PowerCapLimit>,
+  #[serde(rename = "powerCapLimits")]
+  pub power_cap_limits: Option<Vec<PowerCapLimit>>,
+}
+
+impl Into<FrontEndPowerCapComponent> for PowerCapComponent {
+  fn into(self) -> FrontEndPowerCapComponent {
+    FrontEndPowerCapComponent {
+      xname: self.xname,
+      error: self.error,
+      limits: self.limits.map(|limit| limit.into()),
+      power_cap_limits: self.power_cap_limits.map(|power_cap_limit_vec| {
+        power_cap_limit_vec
+          .into_iter()
+          .map(|power_cap_limit| power_cap_limit.into())
+          .collect()
+      }),
+    }
+  }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct PowerCapTaskInfo {
   #[serde(skip_serializing_if = "Option::is_none")]
-  #[serde(rename = "taskId")]
+  #[serde(rename = "taskID", alias = "taskId")]
   pub task_id: Option<String>,
   #[serde(skip_serializing_if = "Option::is_none")]
   pub r#type: Option<String>, // TODO: convert to enum. Valid values are `snapshot` and `patch`
@@ -78,3 +148,73 @@ pub struct PowerCapTaskInfo {
   #[serde(skip_serializing_if = "Option::is_none")]
   pub components: Option<Vec<PowerCapComponent>>,
 }
+
+impl Into<FrontEndPowerCapTaskInfo> for PowerCapTaskInfo {
+  fn into(self) -> FrontEndPowerCapTaskInfo {
+    FrontEndPowerCapTaskInfo {
+      task_id: self.task_id,
+      r#type: self.r#type,
+      task_create_time: self.task_create_time,
+      automatic_expiration_time: self.automatic_expiration_time,
+      task_status: self.task_status,
+      task_counts: self.task_counts.map(|task_counts| task_counts.into()),
+      components: self.components.map(|component_vec| {
+        component_vec
+          .into_iter()
+          .map(|component| component.into())
+          .collect()
+      }),
+    }
+  }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PowerCapControl {
+  pub name: String,
+  pub value: usize,
+}
+
+impl From<FrontEndPowerCapControl> for PowerCapControl {
+  fn from(value: FrontEndPowerCapControl) -> Self {
+    PowerCapControl {
+      name: value.name,
+      value: value.value,
+    }
+  }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PowerCapPatchComponent {
+  pub xname: String,
+  pub controls: Vec<PowerCapControl>,
+}
+
+impl From<FrontEndPowerCapPatchComponent> for PowerCapPatchComponent {
+  fn from(value: FrontEndPowerCapPatchComponent) -> Self {
+    PowerCapPatchComponent {
+      xname: value.xname,
+      controls: value
+        .controls
+        .into_iter()
+        .map(|control| control.into())
+        .collect(),
+    }
+  }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PowerCapPatch {
+  pub components: Vec<PowerCapPatchComponent>,
+}
+
+impl From<FrontEndPowerCapPatch> for PowerCapPatch {
+  fn from(value: FrontEndPowerCapPatch) -> Self {
+    PowerCapPatch {
+      components: value
+        .components
+        .into_iter()
+        .map(|component| component.into())
+        .collect(),
+    }
+  }
+}
@@ -1,3 +1,4 @@
+use manta_backend_dispatcher::error::Service;
 use serde_json::{json, Value};
 
 use crate::error::Error;
@@ -55,10 +56,16 @@ pub async fn post(
       .map_err(|error| Error::NetError(error))
   } else {
     println!("response is failure");
+    let status = response.status().as_u16();
     let payload = response
       .json::<Value>()
       .await
       .map_err(|error| Error::NetError(error))?;
-    Err(Error::OchamiError(payload))
+    Err(Error::HttpError {
+      status,
+      service: Service::Pcs,
+      resource_id: None,
+      payload: payload.to_string(),
+    })
   }
 }
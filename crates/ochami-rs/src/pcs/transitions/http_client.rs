@@ -1,5 +1,6 @@
 use std::time;
 
+use manta_backend_dispatcher::error::Service;
 use serde_json::Value;
 
 use crate::{
@@ -52,12 +53,18 @@ pub async fn get(
     serde_json::from_value::<Vec<Value>>(resp_payload["transitions"].clone())
       .map_err(|error| Error::SerdeError(error))
   } else {
+    let status = response.status().as_u16();
     let payload = response
       .text()
       .await
       .map_err(|error| Error::NetError(error))?;
 
-    Err(Error::Message(payload))
+    Err(Error::HttpError {
+      status,
+      service: Service::Pcs,
+      resource_id: None,
+      payload,
+    })
   }
 }
 
@@ -105,12 +112,18 @@ pub async fn get_by_id(
 
     payload
   } else {
+    let status = response.status().as_u16();
     let payload = response
       .text()
       .await
       .map_err(|error| Error::NetError(error))?;
 
-    Err(Error::Message(payload))
+    Err(Error::HttpError {
+      status,
+      service: Service::Pcs,
+      resource_id: Some(id.to_string()),
+      payload,
+    })
   }
 }
 
@@ -174,9 +187,15 @@ pub async fn post(
   if response.status().is_success() {
     Ok(response.json::<Value>().await.unwrap())
   } else {
+    let status = response.status().as_u16();
     let payload = response.text().await.map_err(|e| Error::NetError(e))?;
 
-    Err(Error::Message(payload))
+    Err(Error::HttpError {
+      status,
+      service: Service::Pcs,
+      resource_id: None,
+      payload,
+    })
   }
 }
 